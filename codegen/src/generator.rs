@@ -22,15 +22,66 @@ impl Module {
 
             use serde_json;
 
-            use ::requests::SlackWebRequestSender;
+            use ::requests::{{{senders}}};
 
-            {methods}",
-            docs = self.description.as_ref().map(|d| format_docs("//!", &d)).unwrap_or(String::new()),
+            {methods}
+
+            {methods_struct}",
+            docs = self.description
+                .as_ref()
+                .map(|d| if ["channels", "groups", "im", "mpim"].contains(&&self.name[..]) {
+                    format!("{}\n\nSlack has retired these methods, so their calls are sent to the `conversations` methods \
+                             that replaced them, and the responses translated back.", d)
+                } else {
+                    d.clone()
+                })
+                .map(|d| format_docs("//!", &d))
+                .unwrap_or(String::new()),
             methods = self.methods
                 .iter()
                 .map(Method::generate)
                 .collect::<Vec<String>>()
-                .join("\n")
+                .join("\n"),
+            senders = if self.methods.iter().any(|m| m.file_param().is_some()) {
+                "SlackMultipartSender, SlackWebRequestSender"
+            } else {
+                "SlackWebRequestSender"
+            },
+            methods_struct = self.methods_struct()
+        )
+    }
+
+    /// The `Methods` struct, calling the methods of the module with a client and a token bound once.
+    /// The retired modules have none, and neither do those whose methods are called without a token.
+    fn methods_struct(&self) -> String {
+        let legacy = ["channels", "groups", "im", "mpim"].contains(&&self.name[..]);
+        if legacy || !self.methods.iter().any(Method::has_token) {
+            return String::new();
+        }
+        format!("\
+            /// The `{name}.*` methods, bound to a client and a token, as returned by
+            /// [`SlackClient::{safe_name}`](../client/struct.SlackClient.html#method.{safe_name}).
+            #[derive(Debug)]
+            pub struct Methods<'a, R: 'a> {{
+                client: &'a R,
+                token: ::tokens::MethodToken<'a>,
+            }}
+
+            impl<'a, R: SlackWebRequestSender> Methods<'a, R> {{
+                pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {{
+                    Methods {{ client: client, token: token.into() }}
+                }}
+
+                {methods}
+            }}",
+            name = self.name,
+            safe_name = self.get_safe_name(),
+            methods = self.methods
+                .iter()
+                .map(Method::bound_fn)
+                .filter(|f| !f.is_empty())
+                .collect::<Vec<String>>()
+                .join("\n\n")
         )
     }
 
@@ -51,40 +102,171 @@ pub struct Method {
 
 impl Method {
     pub fn generate(&self) -> String {
-        // HACK: these methods requires multipart support, which is not yet supported by this library
-        if self.name == "files.upload" || self.name == "users.setPhoto" {
-            return String::new();
-        }
-
         let fn_name = self.name.split('.').last().unwrap().to_snake_case();
         let type_prefix = self.name.split('.').last().unwrap().to_pascal_case();
         let request_struct_name = type_prefix.clone() + "Request";
         let response_struct_name = type_prefix.clone() + "Response";
         let error_enum_name = type_prefix.clone() + "Error";
-        let response = self.response.generate(&response_struct_name, &error_enum_name);
+        let response = self.response.generate(&self.name, &response_struct_name, &error_enum_name);
         let response_type = self.response.get_response_type(&response_struct_name);
 
-        let send_call = {
-            let mut base_call = format!("\
-                let url = ::get_slack_url_for_method(\"{name}\");
-                client.send(&url, &params[..])
-                    .map_err(|err| {error_type}::Client(err))
-                    .and_then(|result| {{
-                        serde_json::from_str::<{response_type}>(&result)
-                            .map_err(|e| {error_type}::MalformedResponse(e))
-                    }})",
-                name = self.name,
-                response_type = response_struct_name,
-                error_type = error_enum_name
-            );
+        // Responses with an `errors` array report the failure of each item of the call in it.
+        let item_errors = match response_type {
+            PropType::Obj(ref o) => o.item_errors(),
+            _ => None,
+        };
 
-            match response_type {
-                PropType::Obj(ref o) => if o.has_ok() { base_call.push_str(".and_then(|o| o.into())") },
-                PropType::Enum(ref e) => if e.has_ok() { base_call.push_str(".and_then(|o| o.into())") },
-                _ => panic!("Top-level response for {} is not an object or enum.", fn_name)
+        // Slack retired the legacy channel methods, so their calls go to `conversations.*` instead.
+        let legacy = ["channels.", "groups.", "im.", "mpim."].iter().any(|module| self.name.starts_with(module));
+        let documentation = format_docs("///", &[
+            &self.description,
+            "",
+            &format!("Wraps {}", self.documentation_url)
+        ].join("\n"));
+        let documentation = if self.name == "files.upload" {
+            format!("{}\n#[deprecated(note = \"files.upload was retired by Slack: use upload::upload_and_share\")]",
+                    documentation)
+        } else if !legacy {
+            documentation
+        } else if self.name == "groups.createChild" {
+            format!("{}\n#[deprecated(note = \"{} was retired by Slack and has no replacement\")]",
+                    documentation,
+                    self.name)
+        } else {
+            format!("{}\n#[deprecated(note = \"{} was retired by Slack: use conversations::{}\")]",
+                    documentation,
+                    self.name,
+                    fn_name)
+        };
+
+        // Methods that take a file are POSTed as `multipart/form-data`, the file as a part of its own.
+        let file = self.file_param();
+        // Methods that change something are POSTed as JSON, with the token in a header rather than in
+        // the URL, which keeps long texts clear of URL length limits and tokens out of logged URLs.
+        let post = !legacy && self.has_token() && !self.is_read_only() && file.is_none();
+        // Methods exchanging credentials, like `oauth.v2.access`, are POSTed as a form, which keeps the
+        // client secret and the codes and tokens out of logged URLs.
+        let form = !legacy && self.params.iter().any(|p| p.is_secret());
+        let token_only = self.params.len() == 1 && self.has_token();
+        let send = if legacy {
+            format!("::legacy::send_bytes(client, \"{}\", &params[..])", self.name)
+        } else if let Some(file) = file {
+            let part = format!("::requests::FilePart {{ name: \"{name}\", filename: {filename}, content: {content} }}",
+                               name = file.name,
+                               filename = if self.params.iter().any(|p| p.name == "filename") {
+                                   format!("request.filename.unwrap_or(\"{}\")", file.name)
+                               } else {
+                                   format!("\"{}\"", file.name)
+                               },
+                               content = if file.optional { "content".to_owned() } else { format!("request.{}", file.name) });
+            format!("let file = {file};
+                    let url = ::get_slack_url_for_method(\"{method}\");
+                    client.send_multipart(&url, token, &params[..], file.as_ref())",
+                    file = if file.optional {
+                        format!("request.{}.map(|content| {{ {} }})", file.name, part)
+                    } else {
+                        format!("Some({})", part)
+                    },
+                    method = self.name)
+        } else if post {
+            if token_only {
+                format!("let url = ::get_slack_url_for_method(\"{}\");\nclient.send_json(&url, token, \"{{}}\")",
+                        self.name)
+            } else {
+                format!("let url = ::get_slack_url_for_method(\"{}\");
+                        ::requests::with_buffer(|buffer| {{
+                            let body = buffer.json(&body).expect(\"a JSON object always serializes\");
+                            client.send_json(&url, token, body)
+                        }})",
+                        self.name)
             }
+        } else if form {
+            format!("let url = ::get_slack_url_for_method(\"{}\");\nclient.send_form(&url, &params[..])", self.name)
+        } else {
+            format!("let url = ::get_slack_url_for_method(\"{}\");\nclient.send_bytes(&url, &params[..])", self.name)
+        };
+        let send_call = format!("\
+            {send}
+                .map_err(|err| {error_type}::Client(err))
+                .and_then(|result| {{
+                    parse_{method_name}_response({body}).map_err(|err| {into_error})
+                }})",
+            send = send,
+            body = if post || form || file.is_some() { "result.body.as_bytes()" } else { "&result" },
+            method_name = fn_name,
+            error_type = error_enum_name,
+            into_error = if item_errors.is_some() {
+                format!("err.into_error_with({0}::MalformedResponse, {0}::PartialFailure)", error_enum_name)
+            } else {
+                format!("err.into_error({}::MalformedResponse)", error_enum_name)
+            }
+        );
 
-            base_call
+        // Parsing is kept out of the generic method so that it is compiled once, in this crate.
+        let parse_fn = {
+            let ok_check = match response_type {
+                PropType::Obj(ref o) => if o.has_ok() && item_errors.is_some() {
+                    "if response.ok {
+                        Ok(response)
+                    } else if response.errors.as_ref().map(|errors| !errors.is_empty()).unwrap_or(false) {
+                        Err(::ResponseError::Items(response.errors.unwrap_or_default()))
+                    } else {
+                        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+                    }".to_owned()
+                } else if o.has_ok() {
+                    "if response.ok {
+                        Ok(response)
+                    } else {
+                        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+                    }".to_owned()
+                } else {
+                    String::new()
+                },
+                PropType::Enum(ref e) => if e.has_ok() {
+                    format!("let error = match response {{
+                            {matches}
+                            _ => return Ok(response),
+                        }};
+                        Err(::ResponseError::Api(error.unwrap_or_default()))",
+                        matches = e.variants.iter()
+                            .map(|v| format!("{}(ref inner) if !inner.ok => inner.error.clone(),", v.qualified_name))
+                            .collect::<Vec<_>>()
+                            .join("\n"))
+                } else {
+                    String::new()
+                },
+                _ => panic!("Top-level response for {} is not an object or enum.", fn_name)
+            };
+            let lenient_arrays = match response_type {
+                PropType::Obj(ref o) if o.has_ok() => o.lenient_arrays(),
+                _ => vec![],
+            };
+            let body = if !lenient_arrays.is_empty() {
+                format!("let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<{}>(body, &[{}])
+                        .map_err(::ResponseError::Malformed)?;
+                    response.parse_warnings = parse_warnings;
+                    {}",
+                    response_struct_name,
+                    lenient_arrays.iter()
+                        .map(|&(ref field, ref item)| format!("(\"{}\", ::item_error::<{}>)", field, item))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    ok_check)
+            } else if ok_check.is_empty() {
+                "serde_json::from_slice(body).map_err(::ResponseError::Malformed)".to_owned()
+            } else {
+                format!("let response = serde_json::from_slice::<{}>(body).map_err(::ResponseError::Malformed)?;\n{}",
+                    response_struct_name, ok_check)
+            };
+            format!("\
+                fn parse_{method_name}_response(body: &[u8]) -> Result<{response_type}, ::ResponseError{items}> {{
+                    {body}
+                }}",
+                method_name = fn_name,
+                response_type = response_struct_name,
+                items = item_errors.as_ref().map(|item| format!("<{}>", item)).unwrap_or_default(),
+                body = body
+            )
         };
 
         if self.params.is_empty() {
@@ -97,18 +279,17 @@ impl Method {
                     {send_call}
                 }}
 
+                {parse_fn}
+
                 {response}
                 ",
-                documentation = format_docs("///", &[
-                    &self.description,
-                    "",
-                    &format!("Wraps {}", self.documentation_url)
-                ].join("\n")),
+                documentation = documentation,
                 method_name = fn_name,
                 response_type = response_struct_name,
                 error_type = error_enum_name,
                 response = response,
-                send_call = send_call
+                send_call = send_call,
+                parse_fn = parse_fn
             )
         } else if self.params.len() == 1 && self.params[0].ty == "auth_token" {
             format!("\
@@ -116,25 +297,30 @@ impl Method {
                 pub fn {method_name}<R>(client: &R, token: &str) -> Result<{response_type}, {error_type}<R::Error>>
                     where R: SlackWebRequestSender
                 {{
-                    let params = &[(\"token\", token)];
+                    {params}
                     {send_call}
                 }}
 
+                {parse_fn}
+
                 {response}
                 ",
-                documentation = format_docs("///", &[
-                    &self.description,
-                    "",
-                    &format!("Wraps {}", self.documentation_url)
-                ].join("\n")),
+                documentation = documentation,
                 method_name = fn_name,
                 response_type = response_struct_name,
                 error_type = error_enum_name,
                 response = response,
-                send_call = send_call
+                params = if post { "" } else { "let params = &[(\"token\", token)];" },
+                send_call = send_call,
+                parse_fn = parse_fn
             )
         } else {
             let has_token = self.params.iter().find(|p| p.ty == "auth_token").is_some();
+            let request_params = self.params.iter()
+                .filter(|p| p.ty != "auth_token") // passed in method params instead
+                .filter(|p| p.ty != "file") // sent as a part of its own
+                .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
+                .collect::<Vec<_>>();
             let method_params = if has_token {
                 format!("client: &R, token: &str, request: &{}", request_struct_name)
             } else {
@@ -143,64 +329,306 @@ impl Method {
             format!("\
                 {documentation}
                 pub fn {method_name}<R>({method_params}) -> Result<{response_type}, {error_type}<R::Error>>
-                    where R: SlackWebRequestSender
+                    where R: {sender}
                 {{
                     {local_vars}
-                    let params = vec![
-                        {token}
-                        {param_pairs}
-                    ];
-                    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+                    {params}
                     {send_call}
                 }}
 
+                {all_fn}
+
+                {parse_fn}
+
                 {request}
 
                 {response}
                 ",
-                documentation = format_docs("///", &[
-                    &self.description,
-                    "",
-                    &format!("Wraps {}", self.documentation_url)
-                ].join("\n")),
+                documentation = documentation,
                 method_name = fn_name,
                 response_type = response_struct_name,
                 error_type = error_enum_name,
                 response = response,
                 request = self.get_request_struct(&request_struct_name),
+                all_fn = self.cursor_all_fn(&response_type),
                 method_params = method_params,
-                token = if has_token { "Some((\"token\", token))," } else { "" },
-                local_vars = self.params.iter()
-                    .filter(|p| p.ty != "auth_token") // passed in method params instead
-                    .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
-                    .filter_map(|p| p.lifted())
+                sender = if file.is_some() { "SlackMultipartSender" } else { "SlackWebRequestSender" },
+                local_vars = self.text_from_blocks()
+                    .into_iter()
+                    .chain(request_params.iter().filter(|_| !post).filter_map(|p| p.lifted()))
                     .collect::<Vec<_>>()
                     .join("\n"),
-                param_pairs = self.params.iter()
-                    .filter(|p| p.ty != "auth_token") // passed in method params instead
-                    .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
-                    .map(Param::get_pair)
-                    .collect::<Vec<String>>()
-                    .join(",\n"),
-                send_call = send_call
+                params = if post {
+                    format!("let mut body = ::serde_json::Map::new();\n{inserts}",
+                        inserts = request_params.iter().map(|p| {
+                            if p.name == "text" && self.text_from_blocks().is_some() {
+                                "body.insert(\"text\".to_owned(), text.into());".to_owned()
+                            } else if (p.name == "blocks" || p.name == "attachments") && self.text_from_blocks().is_some() {
+                                format!("if let Some({0}) = {0} {{\nbody.insert(\"{0}\".to_owned(), ::json_param({0}));\n}}",
+                                        p.name)
+                            } else {
+                                p.get_insert()
+                            }
+                        }).collect::<Vec<_>>().join("\n"))
+                } else if request_params.iter().all(|p| !p.optional) {
+                    // Without optional parameters, the parameters fit in an array.
+                    format!("let params = [{token}{pairs}];",
+                        token = if has_token && file.is_none() { "(\"token\", token), " } else { "" },
+                        pairs = request_params.iter().map(|p| p.get_pair()).collect::<Vec<_>>().join(", "))
+                } else {
+                    format!("let mut params = Vec::with_capacity({capacity});\n{token}{pushes}",
+                        capacity = request_params.len() + if has_token && file.is_none() { 1 } else { 0 },
+                        token = if has_token && file.is_none() { "params.push((\"token\", token));\n" } else { "" },
+                        pushes = request_params.iter().map(|p| {
+                            if p.name == "text" && self.text_from_blocks().is_some() {
+                                "params.push((\"text\", &text[..]));".to_owned()
+                            } else if (p.name == "blocks" || p.name == "attachments") && self.text_from_blocks().is_some() {
+                                // Sent from the local variable, which falls back to the typed field.
+                                format!("if let Some({0}) = {0} {{\nparams.push((\"{0}\", {0}));\n}}", p.name)
+                            } else {
+                                p.get_push()
+                            }
+                        }).collect::<Vec<_>>().join("\n"))
+                },
+                send_call = send_call,
+                parse_fn = parse_fn
             )
         }
     }
 
+    /// Whether the method only reads, going by its name, so that it is sent as a GET.
+    fn is_read_only(&self) -> bool {
+        let name = self.name.split('.').last().unwrap();
+        self.name.starts_with("search.") ||
+        ["conversations", "get", "history", "identity", "info", "list", "lookup", "members", "replies", "test"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix)) ||
+        ["accessLogs", "billableInfo", "connect", "integrationLogs", "start", "teamInfo", "userInfo"].contains(&name)
+    }
+
+    /// The parameter holding the file of the methods that take one, like `files.upload`.
+    fn file_param(&self) -> Option<&Param> {
+        self.params.iter().find(|p| p.ty == "file")
+    }
+
+    fn has_token(&self) -> bool {
+        self.params.iter().any(|p| p.ty == "auth_token")
+    }
+
+    /// The method of the module's `Methods` struct calling this method, followed by the one calling
+    /// its `_all` function, if it has one.
+    fn bound_fn(&self) -> String {
+        // Methods that take a file need a multipart sender, which the struct does not ask for.
+        if self.file_param().is_some() {
+            return String::new();
+        }
+        let fn_name = self.name.split('.').last().unwrap().to_snake_case();
+        let type_prefix = self.name.split('.').last().unwrap().to_pascal_case();
+        let summary = self.description.lines().take_while(|l| !l.trim().is_empty()).collect::<Vec<_>>().join(" ");
+        let summary = if summary.ends_with('.') { summary } else { summary + "." };
+        let token_only = self.params.len() == 1 && self.has_token();
+        // Each method is called with the token it needs, when the struct holds a `TokenSet`.
+        let token = format!("self.token.for_method(\"{}\")", self.name);
+        let (params, args) = match (self.params.is_empty() || token_only, self.has_token()) {
+            (true, true) => (String::new(), format!("self.client, {}", token)),
+            (true, false) => (String::new(), "self.client".to_owned()),
+            (false, true) => (format!(", request: &{}Request", type_prefix), format!("self.client, {}, request", token)),
+            (false, false) => (format!(", request: &{}Request", type_prefix), "self.client, request".to_owned()),
+        };
+        let bound = format!("\
+            /// {summary} See [`{method_name}`](fn.{method_name}.html).
+            pub fn {method_name}(&self{params}) -> Result<{prefix}Response, {prefix}Error<R::Error>> {{
+                {method_name}({args})
+            }}",
+            summary = summary,
+            method_name = fn_name,
+            prefix = type_prefix,
+            params = params,
+            args = args
+        );
+        let response_type = self.response.get_response_type(&(type_prefix.clone() + "Response"));
+        let items = match response_type {
+            PropType::Obj(ref o) if self.params.iter().any(|p| p.name == "cursor") => o.cursor_items(),
+            _ => None,
+        };
+        match items {
+            Some((field, item, _, next_cursor)) => format!("\
+                {bound}
+
+                /// Like [`{method_name}`](fn.{method_name}.html), but follows `{next_cursor}` from page to page,
+                /// yielding the `{field}` of every page in turn.
+                pub fn {method_name}_all(&self, request: &{prefix}Request<'a>)
+                    -> ::cursor::Items<'a, {item}, {prefix}Error<R::Error>> {{
+                    {method_name}_all(self.client, {token}, request)
+                }}",
+                bound = bound,
+                next_cursor = next_cursor,
+                token = token,
+                method_name = fn_name,
+                prefix = type_prefix,
+                field = field,
+                item = item
+            ),
+            None => bound,
+        }
+    }
+
+    /// For the methods that page with a cursor, the `_all` function following the cursor from
+    /// page to page.
+    fn cursor_all_fn(&self, response_type: &PropType) -> String {
+        let items = match *response_type {
+            PropType::Obj(ref o) if self.params.iter().any(|p| p.name == "cursor") => o.cursor_items(),
+            _ => None,
+        };
+        let (field, item, is_map, next_cursor) = match items {
+            Some(items) => items,
+            None => return String::new(),
+        };
+        let fn_name = self.name.split('.').last().unwrap().to_snake_case();
+        let type_prefix = self.name.split('.').last().unwrap().to_pascal_case();
+        let cursor = if next_cursor == "next_cursor" {
+            "response.next_cursor"
+        } else {
+            "response.response_metadata.and_then(|m| m.next_cursor)"
+        };
+        format!("\
+            /// Like [`{method_name}`](fn.{method_name}.html), but follows `{next_cursor}` from page to page,
+            /// yielding the `{field}` of every page in turn.
+            pub fn {method_name}_all<'a, R>(client: &'a R, token: &'a str, request: &{prefix}Request<'a>)
+                -> ::cursor::Items<'a, {item}, {prefix}Error<R::Error>>
+                where R: SlackWebRequestSender
+            {{
+                let request = request.clone();
+                ::cursor::Items::new(move |cursor| {{
+                    let request = {prefix}Request {{ cursor: cursor.or(request.cursor), ..request.clone() }};
+                    {method_name}(client, token, &request).map(|response| {{
+                        {items}
+                    }})
+                }})
+            }}",
+            method_name = fn_name,
+            next_cursor = next_cursor,
+            prefix = type_prefix,
+            field = field,
+            item = item,
+            items = if is_map {
+                format!("let {0} = response.{0}.unwrap_or_default().into_iter().collect();\n\
+                         ({0}, {1})", field, cursor)
+            } else {
+                format!("(response.{}.unwrap_or_default(), {})", field, cursor)
+            }
+        )
+    }
+
+    /// For the methods that take both a required `text` and `blocks`, the statements serializing the
+    /// typed blocks and attachments, and filling in an empty `text` from the blocks, unless the
+    /// request opts out with `text_from_blocks`.
+    fn text_from_blocks(&self) -> Option<String> {
+        let text = self.params.iter().any(|p| p.name == "text" && !p.optional);
+        let blocks = self.params.iter().any(|p| p.name == "blocks");
+        if text && blocks && (self.name == "chat.postMessage" || self.name == "chat.update") {
+            Some("\
+                let typed_blocks = request.typed_blocks.map(::blocks::to_json);
+                let blocks = request.blocks.or(typed_blocks.as_ref().map(String::as_str));
+                let typed_attachments = request.typed_attachments.map(::blocks::attachments_to_json);
+                let attachments = request.attachments.or(typed_attachments.as_ref().map(String::as_str));
+                let text = ::blocks::text_or_fallback(request.text, blocks, request.text_from_blocks);".to_owned())
+        } else {
+            None
+        }
+    }
+
     fn get_request_struct(&self, ty_name: &str) -> String {
+        let lifetime = if self.params.iter()
+            .filter(|p| p.ty != "auth_token")
+            .all(|p| p.ty == "integer" || p.ty == "boolean" || p.is_parse_mode()) { "" } else { "<'a>" };
+        let params = self.params.iter()
+            .filter(|p| p.ty != "auth_token") // passed in method params instead
+            .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
+            .collect::<Vec<_>>();
+
+        // The builder: `new` takes the required fields, and each optional one has a setter, so
+        // that adding an optional field does not break the callers that build requests this way.
+        let required = params.iter().filter(|p| !p.optional).collect::<Vec<_>>();
+        let new_body = if required.is_empty() {
+            format!("{}::default()", ty_name)
+        } else {
+            format!("{request_type} {{ {fields}, ..{request_type}::default() }}",
+                    request_type = ty_name,
+                    fields = required.iter()
+                        .map(|p| format!("{0}: {0}", p.field_name()))
+                        .collect::<Vec<_>>()
+                        .join(", "))
+        };
+        let setter = |name: &str, ty: &str| {
+            format!("pub fn {name}(mut self, {name}: {ty}) -> Self {{ self.{name} = Some({name}); self }}",
+                    name = name,
+                    ty = ty)
+        };
+        let setters = params.iter()
+            .filter(|p| p.optional)
+            .map(|p| setter(p.field_name(), p.get_value_type()))
+            .chain(self.text_from_blocks()
+                .into_iter()
+                .flat_map(|_| vec![setter("text_from_blocks", "bool"),
+                                   setter("typed_blocks", "&'a [::blocks::Block]"),
+                                   setter("typed_attachments", "&'a [::blocks::Attachment]")]))
+            .collect::<Vec<_>>();
+
+        // Requests carrying credentials, like `client_secret`, redact them in their `Debug` output
+        // the way `::secret::Secret` does, so that logging a request does not leak them.
+        let (derive, debug_impl) = if params.iter().any(|p| p.is_secret()) {
+            ("Clone, Default",
+             format!("\
+                impl{lifetime} fmt::Debug for {request_type}{lifetime} {{
+                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
+                        f.debug_struct(\"{request_type}\")
+                            {fields}
+                            .finish()
+                    }}
+                }}",
+                lifetime = lifetime,
+                request_type = ty_name,
+                fields = params.iter().map(|p| p.debug_field()).collect::<Vec<_>>().join("\n")))
+        } else {
+            ("Clone, Default, Debug", String::new())
+        };
+
         format!("\
-            #[derive(Clone, Default, Debug)]
+            #[derive({derive})]
             pub struct {request_type}{lifetime} {{
                 {request_params}
-            }}",
+            }}
+
+            impl{lifetime} {request_type}{lifetime} {{
+                pub fn new({new_params}) -> Self {{
+                    {new_body}
+                }}
+
+                {setters}
+            }}
+
+            {debug_impl}",
+            derive = derive,
+            debug_impl = debug_impl,
             request_type = ty_name,
-            request_params = self.params.iter()
-                .filter(|p| p.ty != "auth_token") // passed in method params instead
-                .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
-                .map(Param::generate).collect::<Vec<String>>().join("\n"),
-            lifetime = if self.params.iter()
-                .filter(|p| p.ty != "auth_token")
-                .all(|p| p.ty == "integer" || p.ty == "boolean") { "" } else { "<'a>" }
+            new_params = required.iter()
+                .map(|p| format!("{}: {}", p.field_name(), p.get_value_type()))
+                .collect::<Vec<_>>()
+                .join(", "),
+            new_body = new_body,
+            setters = setters.join("\n\n"),
+            request_params = params.iter()
+                .map(|p| p.generate())
+                .chain(self.text_from_blocks().map(|_| "\
+                    /// Not sent to Slack: whether to fill in an empty text from blocks, so that notifications show something. Defaults to true.
+                    pub text_from_blocks: Option<bool>,
+                    /// Not sent to Slack: blocks to send as `blocks`, unless it is set.
+                    pub typed_blocks: Option<&'a [::blocks::Block]>,
+                    /// Not sent to Slack: attachments to send as `attachments`, unless it is set.
+                    pub typed_attachments: Option<&'a [::blocks::Attachment]>,".to_owned()))
+                .collect::<Vec<String>>().join("\n"),
+            lifetime = lifetime
         )
     }
 }
@@ -300,8 +728,13 @@ pub struct Response {
 }
 
 impl Response {
-    pub fn generate(&self, ty_name: &str, error_ty: &str) -> String {
-        let (objs, to_result) = match PropType::from_schema(&self.schema, ty_name) {
+    pub fn generate(&self, method: &str, ty_name: &str, error_ty: &str) -> String {
+        let response_type = PropType::from_schema(&self.schema, ty_name);
+        let item_errors = match response_type {
+            PropType::Obj(ref o) => o.item_errors(),
+            _ => None,
+        };
+        let (objs, to_result) = match response_type {
             PropType::Obj(ref o) => {
                 let to_result = get_obj_to_response_impl(o, error_ty);
                 (o.to_code(), to_result)
@@ -322,7 +755,7 @@ impl Response {
             {errors}",
             objs = objs,
             slack_result = to_result.unwrap_or("".into()),
-            errors = self.get_error_enum(error_ty),
+            errors = self.get_error_enum(method, error_ty, item_errors.as_ref().map(String::as_str)),
         )
     }
 
@@ -330,11 +763,12 @@ impl Response {
         PropType::from_schema(&self.schema, ty_name)
     }
 
-    fn get_error_enum(&self, error_ty: &str) -> String {
+    fn get_error_enum(&self, method: &str, error_ty: &str, item_errors: Option<&str>) -> String {
         format!("\
             #[derive(Debug)]
             pub enum {error_type}<E: Error> {{
                 {variants}
+                {item_errors_variant}
                 /// The response was not parseable as the expected object
                 MalformedResponse(serde_json::error::Error),
                 /// The response returned an error that was unknown to the library
@@ -352,6 +786,19 @@ impl Response {
                 }}
             }}
 
+            impl<E: Error> From<{error_type}<E>> for ::Error<E> {{
+                fn from(err: {error_type}<E>) -> Self {{
+                    let code = match err {{
+                        {code_matches}
+                        {item_errors_code}
+                        {error_type}::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+                        {error_type}::Unknown(code) => return ::Error::from_code(\"{method}\", &code),
+                        {error_type}::Client(inner) => return ::Error::Client(inner)
+                    }};
+                    ::Error::from_code(\"{method}\", code)
+                }}
+            }}
+
             impl<E: Error> fmt::Display for {error_type}<E> {{
                 fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
                      write!(f, \"{{}}\", self.description())
@@ -362,6 +809,7 @@ impl Response {
                 fn description(&self) -> &str {{
                     match self {{
                         {description_matches}
+                        {item_errors_description}
                         &{error_type}::MalformedResponse(ref e) => e.description(),
                         &{error_type}::Unknown(ref s) => s,
                         &{error_type}::Client(ref inner) => inner.description()
@@ -377,6 +825,23 @@ impl Response {
                 }}
             }}",
             error_type = error_ty,
+            method = method,
+            // Slack reports a partial failure with the error of one of the items.
+            item_errors_code = item_errors.map(|_| format!(
+                "{}::PartialFailure(errors) => \
+                    return ::Error::from_code(\"{}\", errors.first().map(|e| &e.error[..]).unwrap_or(\"\")),",
+                error_ty,
+                method)).unwrap_or_default(),
+            code_matches = self.errors
+                .iter()
+                .map(|e| format!("{}::{} => \"{}\",", error_ty, e.name.to_pascal_case(), e.name))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            item_errors_variant = item_errors.map(|item| format!("\
+                /// Some of the items of the call failed, each for its own reason.
+                PartialFailure(Vec<{}>),", item)).unwrap_or_default(),
+            item_errors_description = item_errors.map(|_| format!(
+                "&{}::PartialFailure(_) => \"some of the items of the call failed\",", error_ty)).unwrap_or_default(),
             variants = self.errors
                 .iter()
                 .map(|e| {
@@ -430,60 +895,124 @@ impl Param {
         format!(
             "{documentation}\npub {name}: {ty},",
             documentation = format_docs("///", &self.description),
-            name = self.name,
+            name = self.field_name(),
             ty = self.get_rust_type()
         )
     }
 
     pub fn lifted(&self) -> Option<String> {
         match (&self.ty[..], self.optional) {
-            ("integer", true) => Some(format!("let {name} = request.{name}.map(|{name}| {name}.to_string());", name = self.name)),
-            ("integer", false) => Some(format!("let {name} = request.{name}.to_string();", name = self.name)),
+            ("integer", true) => Some(format!("let {name} = request.{name}.map(::IntParam::new);", name = self.name)),
+            ("integer", false) => Some(format!("let {name} = ::IntParam::new(request.{name});", name = self.name)),
             _ => None
         }
     }
 
+    /// The name of the request field for the parameter, which is its own unless that is a keyword.
+    fn field_name(&self) -> &str {
+        match &self.name[..] {
+            "type" => "ty",
+            name => name,
+        }
+    }
+
+    /// Whether the parameter is a credential, which `Debug` output redacts.
+    fn is_secret(&self) -> bool {
+        ["client_secret", "code", "refresh_token"].contains(&&self.name[..])
+    }
+
+    /// The `.field(..)` call formatting the parameter in the request's `Debug` output.
+    fn debug_field(&self) -> String {
+        let value = match (self.is_secret(), self.optional) {
+            (true, true) => format!("&self.{}.map(::secret::Secret::new)", self.field_name()),
+            (true, false) => format!("&::secret::Secret::new(self.{})", self.field_name()),
+            (false, _) => format!("&self.{}", self.field_name()),
+        };
+        format!(".field(\"{}\", {})", self.field_name(), value)
+    }
+
+    /// Whether this is the `parse` of the chat methods, typed as a `::message::Parse`.
+    fn is_parse_mode(&self) -> bool {
+        self.name == "parse"
+    }
+
+    /// The value of a parameter, for a required one, or once bound by `if let`, for an optional
+    /// one.
+    fn get_value(&self) -> String {
+        let bound = if self.optional { self.field_name().to_owned() } else { format!("request.{}", self.field_name()) };
+        if self.is_parse_mode() {
+            return format!("{}.as_str()", bound);
+        }
+        match &self.ty[..] {
+            "boolean" => format!("if {} {{ \"1\" }} else {{ \"0\" }}", bound),
+            // lifted into local variable, using {name} instead of request.{name}
+            "integer" => format!("{}.as_str()", self.name),
+            _ => bound,
+        }
+    }
+
+    /// The `(name, value)` pair of a required parameter.
     pub fn get_pair(&self) -> String {
+        format!("(\"{name}\", {value})", name = self.name, value = self.get_value())
+    }
+
+    /// The statement adding the parameter to `params`, if it is set.
+    pub fn get_push(&self) -> String {
+        let push = format!("params.push((\"{name}\", {value}));", name = self.name, value = self.get_value());
         match (&self.ty[..], self.optional) {
-            ("boolean", true) => {
-                format!("request.{name}.map(|{name}| (\"{name}\", if {name} {{ \"1\" }} else {{ \"0\" }}))", name = self.name)
-            },
-            ("boolean", false) => {
-                format!("Some((\"{name}\", if request.{name} {{ \"1\" }} else {{ \"0\" }}))", name = self.name)
-            },
-            ("integer", true) => {
-                // lifted into local variable, using {name} instead of request.{name}
-                format!("{name}.as_ref().map(|{name}| (\"{name}\", &{name}[..]))", name = self.name)
-            },
-            ("integer", false) => {
-                // lifted into local variable, using {name} instead of request.{name}
-                format!("Some((\"{name}\", &{name}[..]))", name = self.name)
-            },
-            (_, true) => {
-                format!("request.{name}.map(|{name}| (\"{name}\", {name}))", name = self.name)
-            },
-            (_, false) => {
-                format!("Some((\"{name}\", request.{name}))", name = self.name)
-            }
+            ("integer", true) => format!("if let Some(ref {name}) = {name} {{ {push} }}", name = self.name, push = push),
+            (_, true) => format!("if let Some({name}) = request.{name} {{ {push} }}", name = self.field_name(), push = push),
+            (_, false) => push,
         }
     }
 
-    fn get_rust_type(&self) -> String {
-        let ty = match &self.ty[..] {
-            "boolean" => "bool",
-            "integer" => "u32",
-            _ => "&'a str",
+    /// Whether this is a string parameter holding JSON, like `blocks`, which JSON bodies carry as
+    /// the JSON it holds.
+    fn is_json(&self) -> bool {
+        ["attachments", "blocks", "files", "metadata", "profile", "unfurls", "user_auth_blocks", "view"].contains(&&self.name[..])
+    }
+
+    /// The statement adding the parameter to the JSON `body`, if it is set.
+    pub fn get_insert(&self) -> String {
+        let bound = if self.optional { self.field_name().to_owned() } else { format!("request.{}", self.field_name()) };
+        let value = if self.is_parse_mode() {
+            format!("{}.as_str().into()", bound)
+        } else if self.is_json() {
+            format!("::json_param({})", bound)
+        } else {
+            format!("{}.into()", bound)
         };
+        let insert = format!("body.insert(\"{name}\".to_owned(), {value});", name = self.name, value = value);
+        if self.optional {
+            format!("if let Some({name}) = request.{name} {{ {insert} }}", name = self.field_name(), insert = insert)
+        } else {
+            insert
+        }
+    }
+
+    fn get_rust_type(&self) -> String {
+        let ty = self.get_value_type();
         if self.optional {
             return format!("Option<{}>", ty);
         } else {
             return ty.to_owned();
         }
     }
+
+    /// The type of the parameter's value, which optional parameters wrap in an `Option`.
+    fn get_value_type(&self) -> &'static str {
+        match &self.ty[..] {
+            _ if self.is_parse_mode() => "::message::Parse",
+            "boolean" => "bool",
+            "integer" => "u32",
+            "file" => "&'a [u8]",
+            _ => "&'a str",
+        }
+    }
 }
 
 impl JsonObjectFieldInfo {
-    pub fn to_code(&self) -> String {
+    pub fn to_code(&self, has_ok: bool) -> String {
         let mut prefix = String::new();
 
         if let Some(ref path) = self.deserialize_with {
@@ -494,9 +1023,13 @@ impl JsonObjectFieldInfo {
             prefix.push_str("#[serde(default)]\n");
         }
 
-        if self.name == "ok" {
+        if self.skip_deserializing {
+            prefix.push_str("#[serde(skip_deserializing)]\n");
+        }
+
+        if self.name == "ok" && has_ok {
             prefix.push_str("#[serde(default)]");
-        } else if self.name != "error" && self.name != "ok" {
+        } else if !has_ok || self.name != "error" {
             prefix.push_str("pub");
         };
         
@@ -526,6 +1059,15 @@ impl JsonEnumVariant {
 
 impl JsonEnum {
     pub fn to_code(&self) -> String {
+        self.code(false)
+    }
+
+    /// The code of a shared type, whose objects are declared with `model!`.
+    pub fn to_model_code(&self) -> String {
+        self.code(true)
+    }
+
+    fn code(&self, model: bool) -> String {
         // Hack to work around message having a different identifier here
         let (variant_field, on_missing_field) = if self.name == "Message" {
             ("subtype", "::serde_json::from_value::<MessageStandard>(value.clone()).map(|obj| {{
@@ -534,13 +1076,29 @@ impl JsonEnum {
         } else {
             ("type", "Err(D::Error::missing_field(\"type\"))")
         };
+        // Slack adds message subtypes now and then, so messages of unknown ones are kept as they are
+        // rather than failing the whole response.
+        let (unknown_variant, variant_names, on_unknown) = if self.name == "Message" {
+            ("/// A message of a subtype not listed here, kept as it was sent.\nUnknown(::serde_json::Value),".to_owned(),
+             String::new(),
+             "Ok(Message::Unknown(value.clone()))")
+        } else {
+            (String::new(),
+             format!("const VARIANTS: &'static [&'static str] = &[{}];",
+                     self.variants
+                         .iter()
+                         .map(|v| format!("\"{}\"", v.name.to_snake_case()))
+                         .collect::<Vec<_>>()
+                         .join(",")),
+             "Err(D::Error::unknown_variant(ty, VARIANTS))")
+        };
         
         let mut subobjs = self.variants.clone();
 
         subobjs.sort_by_key(|v| v.name.clone());
 
         let subobjs = subobjs.iter()
-            .flat_map(|v| obj_recur(&v.inner))
+            .flat_map(|v| obj_recur(&v.inner, model))
             .collect::<Vec<_>>()
             .join("\n");
 
@@ -548,6 +1106,7 @@ impl JsonEnum {
             #[derive(Clone, Debug)]
             pub enum {name} {{
                 {variants}
+                {unknown_variant}
             }}
 
             impl ::serde::Deserialize for {name} {{
@@ -556,14 +1115,14 @@ impl JsonEnum {
                 {{
                     use ::serde::de::Error as SerdeError;
 
-                    const VARIANTS: &'static [&'static str] = &[{variant_names}];
+                    {variant_names}
 
                     let value = ::serde_json::Value::deserialize(deserializer)?;
                     if let Some(ty_val) = value.get(\"{variant_field}\") {{
                         if let Some(ty) = ty_val.as_str() {{
                             match ty {{
                                 {variant_matches}
-                                _ => Err(D::Error::unknown_variant(ty, VARIANTS))
+                                _ => {on_unknown}
                             }}
                         }} else {{
                             Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &\"a string\"))
@@ -581,11 +1140,9 @@ impl JsonEnum {
                 .map(|v| v.to_code())
                 .collect::<Vec<_>>()
                 .join("\n"),
-            variant_names = self.variants
-                .iter()
-                .map(|v| format!("\"{}\"", v.name.to_snake_case()))
-                .collect::<Vec<_>>()
-                .join(","),
+            unknown_variant = unknown_variant,
+            variant_names = variant_names,
+            on_unknown = on_unknown,
             variant_matches = self.variants
                 .iter()
                 .map(|v| format!("\
@@ -607,45 +1164,158 @@ impl JsonEnum {
     }
 }
 
-fn obj_recur(prop: &PropType) -> Vec<String> {
+fn obj_recur(prop: &PropType, model: bool) -> Vec<String> {
     match prop {
-        &PropType::Obj(ref o) => vec![o.to_code()],
-        &PropType::Arr(ref prop) => obj_recur(prop),
-        &PropType::Map(ref prop) => obj_recur(prop),
-        &PropType::Optional(ref prop) => obj_recur(prop),
-        &PropType::Enum(ref e) => vec![e.to_code()],
+        &PropType::Obj(ref o) => vec![o.code(model)],
+        &PropType::Arr(ref prop) => obj_recur(prop, model),
+        &PropType::Map(ref prop) => obj_recur(prop, model),
+        &PropType::Optional(ref prop) => obj_recur(prop, model),
+        &PropType::Enum(ref e) => vec![e.code(model)],
         _ => vec![],
     }
 }
 
 impl JsonObject {
+    /// The type of the items of the `errors` array, if the object has one.
+    pub fn item_errors(&self) -> Option<String> {
+        self.fields.iter().find(|f| f.name == "errors").and_then(|f| match f.ty {
+            PropType::Arr(ref item) => Some(item.to_rs_type()),
+            PropType::Optional(ref inner) => match **inner {
+                PropType::Arr(ref item) => Some(item.to_rs_type()),
+                _ => None,
+            },
+            _ => None,
+        })
+    }
+
+    /// For the responses of cursor-paginated methods, the field holding the results of the page,
+    /// the type of each result and the path of the next page's cursor: `next_cursor` for the
+    /// methods that return it at the top level, like `admin.conversations.search`, otherwise
+    /// `response_metadata.next_cursor`. The results of a map are its pairs.
+    pub fn cursor_items(&self) -> Option<(String, String, bool, &'static str)> {
+        let next_cursor = if self.fields.iter().any(|f| f.name == "next_cursor") {
+            "next_cursor"
+        } else if self.fields.iter().any(|f| f.name == "response_metadata") {
+            "response_metadata.next_cursor"
+        } else {
+            return None;
+        };
+        self.fields.iter().filter(|f| f.name != "errors").filter_map(|f| {
+            let ty = match f.ty {
+                PropType::Optional(ref inner) => &**inner,
+                ref ty => ty,
+            };
+            match *ty {
+                PropType::Arr(ref item) => Some((f.name.clone(), item.to_rs_type(), false, next_cursor)),
+                PropType::Map(ref value) => {
+                    Some((f.name.clone(), format!("(String, {})", value.to_rs_type()), true, next_cursor))
+                }
+                _ => None,
+            }
+        }).next()
+    }
+
+    /// The arrays of conversations and messages of the response, whose items are left out if they
+    /// do not parse instead of failing the whole response, and the type of their items. Arrays in
+    /// an object of the response are named by their path, like `messages.matches`.
+    pub fn lenient_arrays(&self) -> Vec<(String, String)> {
+        const ITEMS: &'static [&'static str] = &["::Channel", "::Conversation", "::Group", "::Im", "::Message", "::Mpim"];
+
+        fn unwrap_optional(ty: &PropType) -> &PropType {
+            match *ty {
+                PropType::Optional(ref inner) => inner,
+                ref ty => ty,
+            }
+        }
+
+        self.fields.iter().flat_map(|f| match *unwrap_optional(&f.ty) {
+            PropType::Arr(ref item) if ITEMS.contains(&&item.to_rs_type()[..]) => {
+                vec![(f.name.clone(), item.to_rs_type())]
+            }
+            PropType::Obj(ref o) => o.lenient_arrays()
+                .into_iter()
+                .map(|(name, item)| (format!("{}.{}", f.name, name), item))
+                .collect(),
+            _ => vec![],
+        }).collect()
+    }
+
     pub fn to_code(&self) -> String {
-        let subobjs = self.fields
-            .iter()
-            .flat_map(|f| obj_recur(&f.ty))
-            .collect::<Vec<_>>();
+        self.code(false)
+    }
 
+    /// The code of a shared type, declared with `model!` so that it can keep the fields it does
+    /// not have with the `preserve-unknown` feature.
+    pub fn to_model_code(&self) -> String {
+        self.code(true)
+    }
+
+    fn code(&self, model: bool) -> String {
         let mut fields = self.fields.clone();
+        // Slack can warn about successful calls, in `warning` and `response_metadata.warnings`,
+        // whether the documentation mentions it or not, and the metadata of every response has
+        // the same shape, so it is one shared type instead of one per method.
+        if self.has_ok() {
+            if !fields.iter().any(|f| f.name == "warning") {
+                fields.push(optional_field("warning", PropType::Str));
+            }
+            fields.retain(|f| f.name != "response_metadata");
+            fields.push(optional_field("response_metadata", PropType::Ref("requests::ResponseMetadata".into())));
+            if !self.lenient_arrays().is_empty() {
+                fields.push(JsonObjectFieldInfo {
+                    name: "parse_warnings".into(),
+                    ty: PropType::Arr(Box::new(PropType::Ref("requests::ParseWarning".into()))),
+                    rename: None,
+                    deserialize_with: None,
+                    default: false,
+                    skip_deserializing: true,
+                });
+            }
+        }
         fields.sort_by_key(|f| f.name.clone());
 
+        let subobjs = fields
+            .iter()
+            .flat_map(|f| obj_recur(&f.ty, model))
+            .collect::<Vec<_>>();
+
         let fields = fields.iter()
-            .map(|f| f.to_code())
+            .map(|f| f.to_code(self.has_ok()))
             .collect::<Vec<_>>();
 
-        format!("\
-            #[derive(Clone, Debug, Deserialize)]
+        let code = format!("\
             pub struct {name} {{
                 {fields}
-            }}
+            }}",
+            name = self.name,
+            fields = fields.join("\n"));
+        let code = if model {
+            format!("model! {{\n{}\n}}", code)
+        } else {
+            format!("#[derive(Clone, Debug, Deserialize)]\n{}", code)
+        };
+
+        format!("\
+            {code}
 
             {subobjs}",
-            name = self.name,
-            fields = fields.join("\n"),
+            code = code,
             subobjs = subobjs.join("\n")
         )
     }
 }
 
+fn optional_field(name: &str, ty: PropType) -> JsonObjectFieldInfo {
+    JsonObjectFieldInfo {
+        name: name.to_owned(),
+        ty: PropType::Optional(Box::new(ty)),
+        rename: None,
+        deserialize_with: None,
+        default: false,
+        skip_deserializing: false,
+    }
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct ApiError {
     pub name: String,
@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::mem;
 use std::path::Path;
 
 use inflector::Inflector;
@@ -39,6 +40,7 @@ pub struct JsonObjectFieldInfo {
     pub rename: Option<String>,
     pub deserialize_with: Option<&'static str>,
     pub default: bool,
+    pub skip_deserializing: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -168,6 +170,7 @@ impl PropType {
                                         rename: rename,
                                         deserialize_with: deserialize_with,
                                         default: default,
+                                        skip_deserializing: false,
                                     }
                                 })
                                 .collect();
@@ -184,6 +187,44 @@ impl PropType {
         }
     }
 
+    /// This type with the string fields that hold IDs and timestamps, like `channel` and `ts`,
+    /// typed as the library's newtypes for them, e.g. `::ChannelId`, in it and its sub-objects.
+    pub fn with_id_types(self) -> Self {
+        match self {
+            PropType::Obj(mut obj) => {
+                for field in &mut obj.fields {
+                    let ty = mem::replace(&mut field.ty, PropType::Null);
+                    field.ty = match id_type(&obj.name, &field.name) {
+                        Some(id) => ty.with_strs_as(id),
+                        None => ty.with_id_types(),
+                    };
+                }
+                PropType::Obj(obj)
+            }
+            PropType::Enum(mut enm) => {
+                for variant in &mut enm.variants {
+                    let ty = mem::replace(&mut variant.inner, PropType::Null);
+                    variant.inner = ty.with_id_types();
+                }
+                PropType::Enum(enm)
+            }
+            PropType::Arr(inner) => PropType::Arr(Box::new(inner.with_id_types())),
+            PropType::Map(inner) => PropType::Map(Box::new(inner.with_id_types())),
+            PropType::Optional(inner) => PropType::Optional(Box::new(inner.with_id_types())),
+            ty => ty,
+        }
+    }
+
+    /// This type with its strings, or those of its arrays, as the newtype `name`.
+    fn with_strs_as(self, name: &str) -> Self {
+        match self {
+            PropType::Str => PropType::Ref(name.to_owned()),
+            PropType::Arr(inner) => PropType::Arr(Box::new(inner.with_strs_as(name))),
+            PropType::Optional(inner) => PropType::Optional(Box::new(inner.with_strs_as(name))),
+            ty => ty.with_id_types(),
+        }
+    }
+
     pub fn to_rs_type(&self) -> String {
         match *self {
             PropType::Str => "String".into(),
@@ -199,4 +240,26 @@ impl PropType {
             PropType::Enum(ref e) => e.name.clone(),
         }
     }
-}
\ No newline at end of file
+}
+
+/// The newtype of the field `field` of the object `object`, if it holds an ID or a timestamp. The
+/// `id` of an object is the ID of what the object is.
+fn id_type(object: &str, field: &str) -> Option<&'static str> {
+    match field {
+        "ts" | "thread_ts" | "latest_reply" | "last_read" | "event_ts" | "deleted_ts" => Some("Timestamp"),
+        "user" | "creator" | "parent_user_id" | "share_user_id" | "selected_user" | "created_by" |
+        "updated_by" | "deleted_by" | "members" | "reply_users" | "users" => Some("UserId"),
+        "channel" | "channel_id" | "selected_channel" | "selected_conversation" | "channels" | "groups" |
+        "ims" | "pinned_to" => Some("ChannelId"),
+        "team_id" | "app_installed_team_id" => Some("TeamId"),
+        "id" => {
+            match object {
+                "Channel" | "Conversation" | "Group" | "Im" | "Mpim" => Some("ChannelId"),
+                "User" => Some("UserId"),
+                "Team" => Some("TeamId"),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -48,9 +48,9 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
 
                 let ty_name = path.file_stem().unwrap().to_str().unwrap().to_pascal_case();
 
-                let ty = match PropType::from_schema(&schema, &ty_name) {
-                    PropType::Obj(ref o) => o.to_code(),
-                    PropType::Enum(ref e) => e.to_code(),
+                let ty = match PropType::from_schema(&schema, &ty_name).with_id_types() {
+                    PropType::Obj(ref o) => o.to_model_code(),
+                    PropType::Enum(ref e) => e.to_model_code(),
                     _ => panic!("Object schema is not an object."),
                 };
 
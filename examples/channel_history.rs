@@ -7,12 +7,10 @@ fn main() {
     let token = env::var("SLACK_API_TOKEN").expect("SLACK_API_TOKEN not set.");
     let client = reqwest::Client::new().unwrap();
 
-    let response = slack::channels::history(&client,
-                                            &token,
-                                            &slack::channels::HistoryRequest {
-                                                channel: &env::args().nth(1).unwrap(),
-                                                ..slack::channels::HistoryRequest::default()
-                                            });
+    let channel = env::args().nth(1).unwrap();
+    let response = slack::conversations::history(&client,
+                                                 &token,
+                                                 &slack::conversations::HistoryRequest::new(&channel).limit(100));
 
     if let Ok(response) = response {
         if let Some(messages) = response.messages {
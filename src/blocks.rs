@@ -0,0 +1,718 @@
+//! Working with [Block Kit](https://api.slack.com/block-kit) layouts, and the legacy attachments
+//! of messages.
+//!
+//! Layouts can be built from the [`Block`] types and passed to `chat.postMessage` and `chat.update`
+//! as their `typed_blocks`, which are serialized into `blocks`. The same goes for [`Attachment`]s,
+//! as `typed_attachments`.
+//!
+//! Modals and Home tabs are [`View`]s, whose JSON is what the `views` methods take as their `view`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use slack_api::blocks::{Block, Button, Element, SectionBlock, Text};
+//! let blocks = [Block::Header { text: Text::plain("Deploy finished") },
+//!               Block::Section(SectionBlock::text(Text::mrkdwn("*4f2a9c1* is live"))
+//!                   .accessory(Element::Button(Button::new(Text::plain("Roll back"), "rollback")))),
+//!               Block::Divider];
+//! let request = slack_api::chat::PostMessageRequest {
+//!     channel: "C024BE91L",
+//!     typed_blocks: Some(&blocks),
+//!     ..Default::default()
+//! };
+//! ```
+
+use std::borrow::Cow;
+
+use serde_json::{self, Value};
+
+/// A block of a layout.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Block {
+    #[serde(rename = "actions")]
+    Actions(ActionsBlock),
+    #[serde(rename = "context")]
+    Context(ContextBlock),
+    #[serde(rename = "divider")]
+    Divider,
+    #[serde(rename = "header")]
+    Header { text: Text },
+    #[serde(rename = "image")]
+    Image(ImageBlock),
+    /// Only allowed in modals.
+    #[serde(rename = "input")]
+    Input(InputBlock),
+    #[serde(rename = "section")]
+    Section(SectionBlock),
+}
+
+/// A text object, in plain text or in Slack's markdown.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Text {
+    #[serde(rename = "plain_text")]
+    Plain {
+        text: String,
+        /// Whether emoji codes like `:tada:` are shown as emoji. Slack defaults to true.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    #[serde(rename = "mrkdwn")]
+    Markdown {
+        text: String,
+        /// Whether URLs, mentions and channel names are left as they are, rather than linked.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl Text {
+    pub fn plain<S: Into<String>>(text: S) -> Self {
+        Text::Plain {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+
+    pub fn mrkdwn<S: Into<String>>(text: S) -> Self {
+        Text::Markdown {
+            text: text.into(),
+            verbatim: None,
+        }
+    }
+}
+
+/// A block of text, with optional fields shown in two columns and an element beside them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SectionBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<Text>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<Element>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl SectionBlock {
+    pub fn text(text: Text) -> Self {
+        SectionBlock { text: Some(text), ..SectionBlock::default() }
+    }
+
+    pub fn fields(fields: Vec<Text>) -> Self {
+        SectionBlock { fields: Some(fields), ..SectionBlock::default() }
+    }
+
+    /// Shows `element` beside the text.
+    pub fn accessory(mut self, element: Element) -> Self {
+        self.accessory = Some(element);
+        self
+    }
+}
+
+/// A row of interactive elements.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ActionsBlock {
+    pub elements: Vec<Element>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+/// A line of small text, such as who made a change or when.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ContextBlock {
+    pub elements: Vec<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ImageBlock {
+    pub image_url: String,
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+/// A field of a modal: a label, and the element the user fills it in with.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct InputBlock {
+    pub label: Text,
+    pub element: Element,
+    /// The key the value of the element is found under in the view's state, with its `action_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// Text shown below the element.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Text>,
+    /// Whether the view can be submitted with the element left empty. Slack defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+}
+
+impl InputBlock {
+    pub fn new(label: Text, element: Element) -> Self {
+        InputBlock {
+            label: label,
+            element: element,
+            block_id: None,
+            hint: None,
+            optional: None,
+        }
+    }
+
+    pub fn block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn hint(mut self, hint: Text) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = Some(optional);
+        self
+    }
+}
+
+/// An interactive element, in an actions block, beside a section's text or in an input block.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Element {
+    #[serde(rename = "button")]
+    Button(Button),
+    #[serde(rename = "datepicker")]
+    Datepicker(Datepicker),
+    #[serde(rename = "plain_text_input")]
+    PlainTextInput(PlainTextInput),
+    #[serde(rename = "static_select")]
+    StaticSelect(StaticSelect),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Button {
+    pub text: Text,
+    /// The ID the interaction payload reports the click with.
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+impl Button {
+    pub fn new<S: Into<String>>(text: Text, action_id: S) -> Self {
+        Button {
+            text: text,
+            action_id: action_id.into(),
+            url: None,
+            value: None,
+            style: None,
+        }
+    }
+
+    /// The value the interaction payload reports with the click.
+    pub fn value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum ButtonStyle {
+    /// Green, for the action to take.
+    #[serde(rename = "primary")]
+    Primary,
+    /// Red, for destructive actions.
+    #[serde(rename = "danger")]
+    Danger,
+}
+
+/// A text field.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PlainTextInput {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    /// Whether the field takes several lines of text. Slack defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+}
+
+impl PlainTextInput {
+    pub fn new<S: Into<String>>(action_id: S) -> Self {
+        PlainTextInput { action_id: action_id.into(), ..PlainTextInput::default() }
+    }
+
+    pub fn placeholder(mut self, placeholder: Text) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    pub fn initial_value<S: Into<String>>(mut self, initial_value: S) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = Some(multiline);
+        self
+    }
+}
+
+/// A menu of options listed in the layout.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StaticSelect {
+    pub action_id: String,
+    pub options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// The option selected to begin with, which must be one of `options`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+}
+
+impl StaticSelect {
+    pub fn new<S: Into<String>>(action_id: S, options: Vec<SelectOption>) -> Self {
+        StaticSelect {
+            action_id: action_id.into(),
+            options: options,
+            placeholder: None,
+            initial_option: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: Text) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    pub fn initial_option(mut self, initial_option: SelectOption) -> Self {
+        self.initial_option = Some(initial_option);
+        self
+    }
+}
+
+/// An option of a [`StaticSelect`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SelectOption {
+    /// What the menu shows, which must be plain text.
+    pub text: Text,
+    /// The value the interaction payload reports when the option is selected.
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+}
+
+impl SelectOption {
+    pub fn new<S: Into<String>>(text: Text, value: S) -> Self {
+        SelectOption {
+            text: text,
+            value: value.into(),
+            description: None,
+        }
+    }
+}
+
+/// A calendar to pick a date from.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Datepicker {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// The date selected to begin with, as `YYYY-MM-DD`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+}
+
+impl Datepicker {
+    pub fn new<S: Into<String>>(action_id: S) -> Self {
+        Datepicker { action_id: action_id.into(), ..Datepicker::default() }
+    }
+
+    pub fn initial_date<S: Into<String>>(mut self, initial_date: S) -> Self {
+        self.initial_date = Some(initial_date.into());
+        self
+    }
+}
+
+/// A modal or a Home tab, as opened, pushed, updated or published by the `views` methods.
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::blocks::{Block, Element, InputBlock, PlainTextInput, Text, View};
+/// let view = View::modal(Text::plain("Report a bug"),
+///                        vec![Block::Input(InputBlock::new(Text::plain("What happened?"),
+///                                                          Element::PlainTextInput(PlainTextInput::new("summary")
+///                                                              .multiline(true)))
+///                                              .block_id("summary"))])
+///     .submit(Text::plain("Send"))
+///     .callback_id("bug_report");
+///
+/// let json = view.to_json();
+/// let request = slack_api::views::OpenRequest::new(&json).trigger_id("13345224609.738474920.8088930838d88f008e0");
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct View {
+    #[serde(rename = "type")]
+    pub ty: ViewType,
+    /// The title of a modal, which modals require.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    pub blocks: Vec<Block>,
+    /// The label of the button closing a modal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<Text>,
+    /// The label of the button submitting a modal, which modals with input blocks require.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<Text>,
+    /// Text Slack keeps with the view and sends back in its interaction payloads, of up to 3000
+    /// characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    /// The ID the interaction payloads of the view report it with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    /// Whether submitting or closing the modal closes every view on its stack. Slack defaults to
+    /// false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_on_close: Option<bool>,
+    /// Whether Slack sends a `view_closed` payload when the user closes the modal. Slack defaults to
+    /// false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_close: Option<bool>,
+    /// An ID for the view unique among those of the app, to update it by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+impl View {
+    pub fn modal(title: Text, blocks: Vec<Block>) -> Self {
+        View {
+            ty: ViewType::Modal,
+            title: Some(title),
+            ..View::home(blocks)
+        }
+    }
+
+    pub fn home(blocks: Vec<Block>) -> Self {
+        View {
+            ty: ViewType::Home,
+            title: None,
+            blocks: blocks,
+            close: None,
+            submit: None,
+            private_metadata: None,
+            callback_id: None,
+            clear_on_close: None,
+            notify_on_close: None,
+            external_id: None,
+        }
+    }
+
+    pub fn submit(mut self, submit: Text) -> Self {
+        self.submit = Some(submit);
+        self
+    }
+
+    pub fn close(mut self, close: Text) -> Self {
+        self.close = Some(close);
+        self
+    }
+
+    pub fn private_metadata<S: Into<String>>(mut self, private_metadata: S) -> Self {
+        self.private_metadata = Some(private_metadata.into());
+        self
+    }
+
+    pub fn callback_id<S: Into<String>>(mut self, callback_id: S) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    pub fn notify_on_close(mut self, notify_on_close: bool) -> Self {
+        self.notify_on_close = Some(notify_on_close);
+        self
+    }
+
+    pub fn external_id<S: Into<String>>(mut self, external_id: S) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Serializes the view as the JSON the `view` parameters take.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("views serialize to JSON")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum ViewType {
+    #[serde(rename = "modal")]
+    Modal,
+    #[serde(rename = "home")]
+    Home,
+}
+
+/// A legacy attachment, shown below the message with a colored bar.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Attachment {
+    /// The color of the bar: `good`, `warning`, `danger` or a hex color like `#439FE0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The plain text shown where the attachment cannot be, e.g. in notifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    /// Text shown above the attachment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Field>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// A Unix timestamp shown in the footer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<i64>,
+}
+
+/// A field of an [`Attachment`], shown in a table.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Field {
+    pub title: String,
+    pub value: String,
+    /// Whether the field is short enough to be shown beside another.
+    pub short: bool,
+}
+
+impl Field {
+    pub fn new<S: Into<String>, T: Into<String>>(title: S, value: T, short: bool) -> Self {
+        Field {
+            title: title.into(),
+            value: value.into(),
+            short: short,
+        }
+    }
+}
+
+/// Serializes `blocks` as the JSON array `blocks` parameters take.
+pub fn to_json(blocks: &[Block]) -> String {
+    serde_json::to_string(blocks).expect("blocks serialize to JSON")
+}
+
+/// Serializes `attachments` as the JSON array `attachments` parameters take.
+pub fn attachments_to_json(attachments: &[Attachment]) -> String {
+    serde_json::to_string(attachments).expect("attachments serialize to JSON")
+}
+
+/// The plain text of a layout of blocks, for the `text` of a message that only has blocks, which
+/// notifications and screen readers show instead of the blocks.
+///
+/// Headers, sections and their fields, context, and rich text are kept, one block a line. Images
+/// are kept as their title or alt text. Dividers, actions and inputs have no text worth showing.
+/// Returns `None` if `blocks` is not a JSON array of blocks, or has no text.
+///
+/// # Examples
+///
+/// ```
+/// let blocks = r#"[
+///     {"type": "header", "text": {"type": "plain_text", "text": "Deploy finished"}},
+///     {"type": "section", "text": {"type": "mrkdwn", "text": "*4f2a9c1* is live"}},
+///     {"type": "divider"},
+///     {"type": "context", "elements": [{"type": "mrkdwn", "text": "by <@U024BE7LH>"}]}
+/// ]"#;
+/// let text = slack_api::blocks::fallback_text(blocks).unwrap();
+/// assert_eq!("Deploy finished\n*4f2a9c1* is live\nby <@U024BE7LH>", text);
+/// ```
+pub fn fallback_text(blocks: &str) -> Option<String> {
+    let blocks = match serde_json::from_str::<Value>(blocks) {
+        Ok(Value::Array(blocks)) => blocks,
+        _ => return None,
+    };
+    let lines: Vec<String> = blocks.iter().filter_map(block_text).filter(|line| !line.trim().is_empty()).collect();
+    if lines.is_empty() { None } else { Some(lines.join("\n")) }
+}
+
+/// The `text` to send with `blocks`: `text` itself, unless it is empty and `enabled` is not
+/// `Some(false)`, in which case it is the [`fallback_text`] of `blocks`.
+///
+/// This is what `chat.postMessage` and `chat.update` send, with their `text_from_blocks` as
+/// `enabled`.
+pub fn text_or_fallback<'a>(text: &'a str, blocks: Option<&str>, enabled: Option<bool>) -> Cow<'a, str> {
+    match blocks {
+        Some(blocks) if text.is_empty() && enabled != Some(false) => {
+            fallback_text(blocks).map(Cow::Owned).unwrap_or(Cow::Borrowed(text))
+        }
+        _ => Cow::Borrowed(text),
+    }
+}
+
+fn block_text(block: &Value) -> Option<String> {
+    let text = |value: &Value, pointer: &str| value.pointer(pointer).and_then(Value::as_str).map(str::to_owned);
+    match block.get("type").and_then(Value::as_str)? {
+        "header" => text(block, "/text/text"),
+        "section" => {
+            let mut lines: Vec<String> = text(block, "/text/text").into_iter().collect();
+            if let Some(fields) = block.get("fields").and_then(Value::as_array) {
+                lines.extend(fields.iter().filter_map(|field| text(field, "/text")));
+            }
+            Some(lines.join("\n"))
+        }
+        "context" => {
+            let elements = block.get("elements").and_then(Value::as_array)?;
+            let texts: Vec<String> = elements.iter().filter_map(|element| text(element, "/text")).collect();
+            Some(texts.join(" "))
+        }
+        "image" => text(block, "/title/text").or_else(|| text(block, "/alt_text")),
+        "rich_text" => {
+            let elements = block.get("elements").and_then(Value::as_array)?;
+            Some(elements.iter().map(rich_text).collect::<Vec<_>>().join("\n"))
+        }
+        _ => None,
+    }
+}
+
+/// The text of a section, list, quote or preformatted element of a `rich_text` block.
+fn rich_text(element: &Value) -> String {
+    let elements = element.get("elements").and_then(Value::as_array).map(Vec::as_slice).unwrap_or(&[]);
+    match element.get("type").and_then(Value::as_str) {
+        Some("rich_text_list") => elements.iter().map(|item| format!("• {}", rich_text(item))).collect::<Vec<_>>().join("\n"),
+        Some("rich_text_quote") => format!("> {}", inline_text(elements)),
+        Some("rich_text_preformatted") => format!("```{}```", inline_text(elements)),
+        _ => inline_text(elements),
+    }
+}
+
+fn inline_text(elements: &[Value]) -> String {
+    let field = |element: &Value, name| element.get(name).and_then(Value::as_str).unwrap_or("").to_owned();
+    elements.iter()
+        .map(|element| match element.get("type").and_then(Value::as_str) {
+                 Some("text") => field(element, "text"),
+                 Some("link") => {
+                     let text = field(element, "text");
+                     if text.is_empty() { field(element, "url") } else { text }
+                 }
+                 Some("user") => format!("<@{}>", field(element, "user_id")),
+                 Some("channel") => format!("<#{}>", field(element, "channel_id")),
+                 Some("usergroup") => format!("<!subteam^{}>", field(element, "usergroup_id")),
+                 Some("broadcast") => format!("<!{}>", field(element, "range")),
+                 Some("emoji") => format!(":{}:", field(element, "name")),
+                 _ => String::new(),
+             })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chat;
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_rich_text_and_fields() {
+        let blocks = r#"[
+            {"type": "section", "fields": [{"type": "mrkdwn", "text": "*Env*"}, {"type": "mrkdwn", "text": "prod"}],
+                "accessory": {"type": "button", "text": {"type": "plain_text", "text": "Roll back"}}},
+            {"type": "actions", "elements": [{"type": "button", "text": {"type": "plain_text", "text": "Ack"}}]},
+            {"type": "image", "image_url": "https://example.com/graph.png", "alt_text": "error rate graph"},
+            {"type": "rich_text", "elements": [
+                {"type": "rich_text_section", "elements": [
+                    {"type": "text", "text": "Paging "}, {"type": "user", "user_id": "U1"},
+                    {"type": "text", "text": " in "}, {"type": "channel", "channel_id": "C1"},
+                    {"type": "emoji", "name": "fire"}]},
+                {"type": "rich_text_list", "style": "bullet", "elements": [
+                    {"type": "rich_text_section", "elements": [{"type": "link", "url": "https://status.example.com"}]},
+                    {"type": "rich_text_section", "elements": [{"type": "text", "text": "runbook"}]}]}]}
+        ]"#;
+        assert_eq!(Some("*Env*\nprod\nerror rate graph\nPaging <@U1> in <#C1>:fire:\n• https://status.example.com\n• runbook"
+                           .to_owned()),
+                   fallback_text(blocks));
+        assert_eq!(None, fallback_text(r#"[{"type": "divider"}]"#));
+        assert_eq!(None, fallback_text("not json"));
+    }
+
+    #[test]
+    fn test_post_message_fills_in_text() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").returns(r#"{"ok": true, "ts": "1.0"}"#));
+        let blocks = r#"[{"type": "section", "text": {"type": "mrkdwn", "text": "Disk full"}}]"#;
+
+        let request = chat::PostMessageRequest { channel: "C1", blocks: Some(blocks), ..Default::default() };
+        chat::post_message(&mock, "xoxb-1", &request).unwrap();
+        let request = chat::PostMessageRequest { text_from_blocks: Some(false), ..request };
+        chat::post_message(&mock, "xoxb-1", &request).unwrap();
+        let request = chat::PostMessageRequest { text: "Disk full on db-1", ..Default::default() };
+        chat::post_message(&mock, "xoxb-1", &request).unwrap();
+
+        let texts: Vec<_> = mock.calls().iter().map(|call| call.param("text").unwrap().to_owned()).collect();
+        assert_eq!(vec!["Disk full", "", "Disk full on db-1"], texts);
+    }
+
+    #[test]
+    fn test_typed_blocks_serialize() {
+        let ack = Button::new(Text::plain("Ack"), "ack").style(ButtonStyle::Primary);
+        let blocks = [Block::Section(SectionBlock::fields(vec![Text::mrkdwn("*Env*"), Text::plain("prod")])
+                          .accessory(Element::Button(ack))),
+                      Block::Divider];
+        assert_eq!(concat!(r#"[{"type":"section","fields":[{"type":"mrkdwn","text":"*Env*"},{"type":"plain_text","text":"prod"}],"#,
+                           r#""accessory":{"type":"button","text":{"type":"plain_text","text":"Ack"},"action_id":"ack","#,
+                           r#""style":"primary"}},{"type":"divider"}]"#),
+                   to_json(&blocks));
+
+        let attachments = [Attachment {
+                               color: Some("danger".to_owned()),
+                               fields: vec![Field::new("Host", "db-1", true)],
+                               ..Attachment::default()
+                           }];
+        assert_eq!(r#"[{"color":"danger","fields":[{"title":"Host","value":"db-1","short":true}]}]"#,
+                   attachments_to_json(&attachments));
+    }
+
+    #[test]
+    fn test_post_message_sends_typed_blocks() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").returns(r#"{"ok": true, "ts": "1.0"}"#));
+        let blocks = [Block::Header { text: Text::plain("Disk full") }];
+
+        let request = chat::PostMessageRequest { channel: "C1", typed_blocks: Some(&blocks), ..Default::default() };
+        chat::post_message(&mock, "xoxb-1", &request).unwrap();
+        let request = chat::PostMessageRequest { blocks: Some("[]"), ..request };
+        chat::post_message(&mock, "xoxb-1", &request).unwrap();
+
+        let calls = mock.calls();
+        // Sent in a JSON body, as a JSON array, whose keys come back sorted.
+        assert_eq!((Some(r#"[{"text":{"text":"Disk full","type":"plain_text"},"type":"header"}]"#), Some("Disk full")),
+                   (calls[0].param("blocks"), calls[0].param("text")));
+        assert_eq!(Some("[]"), calls[1].param("blocks"));
+    }
+}
@@ -0,0 +1,325 @@
+//! Retrying failed calls within a budget, and a circuit breaker that stops calling Slack while it
+//! is down.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use metrics::Metrics;
+use requests::{SlackWebRequestSender, SlackWebResponse};
+
+/// The state of a [`CircuitBreaker`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BreakerState {
+    /// Calls are sent.
+    Closed,
+    /// Slack is considered down: calls are refused until the breaker has been open for a while.
+    Open,
+    /// One call is let through to probe whether Slack is back; the others are refused.
+    HalfOpen,
+}
+
+/// Wraps a request sender, retrying calls that fail and refusing to send any while Slack seems to
+/// be down.
+///
+/// A call fails if the sender returns an error, e.g. because the connection failed, or the
+/// response has a 5xx status. Failed reads are retried, but all the calls share a retry budget:
+/// each call adds a tenth of a retry to it, up to ten, and each retry takes one, so retries cannot
+/// multiply the load during an outage.
+///
+/// Calls that change something, which the API methods POST as JSON, are not retried: a failed one
+/// may have taken effect on Slack's side anyway, and sending it again would e.g. post a message
+/// twice. Their failures still count towards opening the breaker.
+///
+/// After five consecutive failures the breaker opens, and refuses calls with
+/// [`BreakerError::Open`] for thirty seconds. Then it lets one call through: the breaker closes if
+/// it succeeds, and opens again if not. Every change of state is reported to the [`Metrics`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use slack_api::breaker::CircuitBreaker;
+/// # use slack_api::metrics::NoMetrics;
+/// let client = CircuitBreaker::new(slack_api::requests::default_client().unwrap(), NoMetrics)
+///     .failure_threshold(3)
+///     .open_for(Duration::from_secs(60));
+///
+/// let response = slack_api::conversations::list(&client, "xoxb-...", &Default::default());
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker<R, M> {
+    sender: R,
+    metrics: M,
+    failure_threshold: u32,
+    open_for: Duration,
+    retries: u32,
+    retry_delay: Duration,
+    budget_ratio: f64,
+    state: Mutex<Breaker>,
+}
+
+#[derive(Debug)]
+struct Breaker {
+    state: BreakerState,
+    failures: u32,
+    opened_at: Option<Instant>,
+    budget: f64,
+}
+
+/// The most retries the budget can hold.
+const MAX_BUDGET: f64 = 10.0;
+
+impl<R: SlackWebRequestSender, M: Metrics> CircuitBreaker<R, M> {
+    pub fn new(sender: R, metrics: M) -> Self {
+        CircuitBreaker {
+            sender: sender,
+            metrics: metrics,
+            failure_threshold: 5,
+            open_for: Duration::from_secs(30),
+            retries: 2,
+            retry_delay: Duration::from_millis(500),
+            budget_ratio: 0.1,
+            state: Mutex::new(Breaker {
+                state: BreakerState::Closed,
+                failures: 0,
+                opened_at: None,
+                budget: MAX_BUDGET,
+            }),
+        }
+    }
+
+    /// How many consecutive failures open the breaker. Defaults to five.
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures;
+        self
+    }
+
+    /// How long the breaker stays open before probing. Defaults to thirty seconds.
+    pub fn open_for(mut self, open_for: Duration) -> Self {
+        self.open_for = open_for;
+        self
+    }
+
+    /// How many times one call is retried at most, budget permitting. Defaults to two.
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// How long to wait before the first retry of a call, doubling for each one after. Defaults to
+    /// half a second.
+    pub fn retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    /// How many retries each call adds to the budget. Defaults to a tenth, so that about one call
+    /// in ten can be retried in the long run.
+    pub fn budget_ratio(mut self, ratio: f64) -> Self {
+        self.budget_ratio = ratio;
+        self
+    }
+
+    /// The underlying request sender.
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+
+    pub fn state(&self) -> BreakerState {
+        self.state.lock().unwrap().state
+    }
+
+    fn set_state(&self, breaker: &mut Breaker, state: BreakerState) {
+        if breaker.state != state {
+            breaker.state = state;
+            self.metrics.breaker_state(state);
+        }
+    }
+
+    /// Whether a call may be sent now, moving an open breaker to half-open once it has been open
+    /// long enough.
+    fn admit(&self) -> bool {
+        let mut breaker = self.state.lock().unwrap();
+        match breaker.state {
+            BreakerState::Closed => {
+                breaker.budget = (breaker.budget + self.budget_ratio).min(MAX_BUDGET);
+                true
+            }
+            BreakerState::Open if breaker.opened_at.map(|at| at.elapsed() >= self.open_for).unwrap_or(true) => {
+                self.set_state(&mut breaker, BreakerState::HalfOpen);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Records the outcome of an attempt, returning whether it may be retried, if `retry`.
+    fn record(&self, failed: bool, retry: bool, retries: u32) -> bool {
+        let mut breaker = self.state.lock().unwrap();
+        if !failed {
+            breaker.failures = 0;
+            self.set_state(&mut breaker, BreakerState::Closed);
+            return false;
+        }
+        breaker.failures += 1;
+        if breaker.state == BreakerState::HalfOpen || breaker.failures >= self.failure_threshold {
+            breaker.opened_at = Some(Instant::now());
+            self.set_state(&mut breaker, BreakerState::Open);
+            return false;
+        }
+        if retry && retries < self.retries && breaker.budget >= 1.0 {
+            breaker.budget -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<R: SlackWebRequestSender, M: Metrics> SlackWebRequestSender for CircuitBreaker<R, M> {
+    type Error = BreakerError<R::Error>;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.guard(true, || self.sender.send_raw(method_url, params))
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.guard(false, || self.sender.send_json(method_url, token, body))
+    }
+
+    // Codes and refresh tokens can be exchanged only once, so forms are not retried either.
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.guard(false, || self.sender.send_form(method_url, params))
+    }
+}
+
+impl<R: SlackWebRequestSender, M: Metrics> CircuitBreaker<R, M> {
+    /// Sends a call if the breaker admits it, retrying it if `retry` and it fails.
+    fn guard<F>(&self, retry: bool, send: F) -> Result<SlackWebResponse, BreakerError<R::Error>>
+        where F: Fn() -> Result<SlackWebResponse, R::Error>
+    {
+        if !self.admit() {
+            return Err(BreakerError::Open);
+        }
+        let mut retries = 0;
+        let mut delay = self.retry_delay;
+        loop {
+            let result = send();
+            let failed = match result {
+                Ok(ref response) => response.status.map(|status| status >= 500).unwrap_or(false),
+                Err(_) => true,
+            };
+            if !self.record(failed, retry, retries) {
+                let mut response = result.map_err(BreakerError::Client)?;
+                response.retries += retries;
+                return Ok(response);
+            }
+            thread::sleep(delay);
+            delay *= 2;
+            retries += 1;
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BreakerError<E: Error> {
+    /// The breaker is open, so the call was not sent.
+    Open,
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for BreakerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for BreakerError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &BreakerError::Open => "Slack seems to be down, so the call was not sent",
+            &BreakerError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &BreakerError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use conversations;
+    use metrics::NoMetrics;
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct States(RefCell<Vec<BreakerState>>);
+
+    impl Metrics for States {
+        fn breaker_state(&self, state: BreakerState) {
+            self.0.borrow_mut().push(state);
+        }
+    }
+
+    #[test]
+    fn test_breaker_opens_and_probes() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.info").fails("connection reset").times(3))
+            .expect(Expectation::new("conversations.info").returns(r#"{"ok": true, "channel": {"id": "C1"}}"#));
+        let states = States::default();
+        let breaker = CircuitBreaker::new(mock, &states)
+            .failure_threshold(3)
+            .retries(1)
+            .retry_delay(Duration::from_millis(0))
+            .open_for(Duration::from_secs(60 * 60));
+        let request = conversations::InfoRequest { channel: "C1", ..Default::default() };
+
+        // The first call is retried once, the second fails for the third time in a row.
+        assert!(conversations::info(&breaker, "xoxb-1", &request).is_err());
+        assert!(conversations::info(&breaker, "xoxb-1", &request).is_err());
+        assert_eq!(BreakerState::Open, breaker.state());
+        match conversations::info(&breaker, "xoxb-1", &request) {
+            Err(conversations::InfoError::Client(BreakerError::Open)) => {}
+            other => panic!("expected the breaker to be open, got {:?}", other),
+        }
+        assert_eq!(3, breaker.sender().calls().len());
+
+        let breaker = breaker.open_for(Duration::from_millis(0));
+        conversations::info(&breaker, "xoxb-1", &request).unwrap();
+        assert_eq!(BreakerState::Closed, breaker.state());
+        assert_eq!(vec![BreakerState::Open, BreakerState::HalfOpen, BreakerState::Closed],
+                   *states.0.borrow());
+    }
+
+    #[test]
+    fn test_writes_are_not_retried() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.archive").fails("connection reset").times(2))
+            .expect(Expectation::new("conversations.archive"));
+        let breaker = CircuitBreaker::new(mock, NoMetrics)
+            .failure_threshold(2)
+            .retry_delay(Duration::from_millis(0));
+        let request = conversations::ArchiveRequest { channel: "C1" };
+
+        assert!(conversations::archive(&breaker, "xoxb-1", &request).is_err());
+        assert_eq!(1, breaker.sender().calls().len());
+        assert!(conversations::archive(&breaker, "xoxb-1", &request).is_err());
+        assert_eq!(BreakerState::Open, breaker.state());
+    }
+}
@@ -0,0 +1,167 @@
+//! Posting the same message to many channels.
+
+use std::error::Error;
+use std::thread;
+use std::time::Duration;
+
+use chat;
+use conversations;
+use requests::SlackWebRequestSender;
+
+/// How [`post_message`] goes through the channels.
+#[derive(Clone, Debug)]
+pub struct BroadcastOptions {
+    pace: Duration,
+    members_only: bool,
+}
+
+impl Default for BroadcastOptions {
+    fn default() -> Self {
+        BroadcastOptions {
+            pace: Duration::from_secs(1),
+            members_only: false,
+        }
+    }
+}
+
+impl BroadcastOptions {
+    pub fn new() -> Self {
+        BroadcastOptions::default()
+    }
+
+    /// How long to wait between two channels. Defaults to a second, which keeps a broadcast
+    /// within `chat.postMessage`'s rate limit.
+    pub fn pace(mut self, pace: Duration) -> Self {
+        self.pace = pace;
+        self
+    }
+
+    /// Skips the channels the bot is not a member of, instead of posting to them anyway (which
+    /// works in public channels with the `chat:write.public` scope). Off by default.
+    pub fn members_only(mut self, members_only: bool) -> Self {
+        self.members_only = members_only;
+        self
+    }
+}
+
+/// What happened in one channel of a broadcast.
+#[derive(Debug)]
+pub enum Delivery<E: Error> {
+    /// The message was posted, with this `ts`.
+    Posted(String),
+    /// The bot is not a member of the channel, and [`BroadcastOptions::members_only`] was set.
+    Skipped,
+    /// Checking whether the bot is a member of the channel failed.
+    MembershipUnknown(conversations::InfoError<E>),
+    /// `chat.postMessage` failed.
+    Failed(chat::PostMessageError<E>),
+}
+
+impl<E: Error> Delivery<E> {
+    /// The `ts` of the posted message, if it was posted.
+    pub fn ts(&self) -> Option<&str> {
+        match self {
+            &Delivery::Posted(ref ts) => Some(ts),
+            _ => None,
+        }
+    }
+}
+
+/// Posts `request` to each of `channels` in turn, ignoring its own `channel`.
+///
+/// A failure in one channel does not stop the broadcast; every channel gets a [`Delivery`], in
+/// the order given.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::broadcast::{self, BroadcastOptions};
+/// let client = slack_api::requests::default_client().unwrap();
+/// let request = slack_api::chat::PostMessageRequest { text: "Maintenance at 18:00 UTC", ..Default::default() };
+/// let options = BroadcastOptions::new().members_only(true);
+///
+/// for (channel, delivery) in broadcast::post_message(&client, "xoxb-...", &["C1", "C2"], &request, &options) {
+///     if let broadcast::Delivery::Failed(err) = delivery {
+///         println!("{}: {}", channel, err);
+///     }
+/// }
+/// ```
+pub fn post_message<R>(client: &R,
+                       token: &str,
+                       channels: &[&str],
+                       request: &chat::PostMessageRequest,
+                       options: &BroadcastOptions)
+                       -> Vec<(String, Delivery<R::Error>)>
+    where R: SlackWebRequestSender
+{
+    let mut deliveries = Vec::with_capacity(channels.len());
+    for (i, &channel) in channels.iter().enumerate() {
+        if i > 0 {
+            thread::sleep(options.pace);
+        }
+        let delivery = deliver(client, token, channel, request, options);
+        deliveries.push((channel.to_owned(), delivery));
+    }
+    deliveries
+}
+
+fn deliver<R>(client: &R,
+              token: &str,
+              channel: &str,
+              request: &chat::PostMessageRequest,
+              options: &BroadcastOptions)
+              -> Delivery<R::Error>
+    where R: SlackWebRequestSender
+{
+    if options.members_only {
+        let info = conversations::InfoRequest {
+            channel: channel,
+            ..conversations::InfoRequest::default()
+        };
+        match conversations::info(client, token, &info) {
+            Ok(response) => {
+                if response.channel.and_then(|c| c.is_member) != Some(true) {
+                    return Delivery::Skipped;
+                }
+            }
+            Err(err) => return Delivery::MembershipUnknown(err),
+        }
+    }
+
+    let request = chat::PostMessageRequest { channel: channel, ..request.clone() };
+    match chat::post_message(client, token, &request) {
+        Ok(response) => Delivery::Posted(response.ts.unwrap_or_default()),
+        Err(err) => Delivery::Failed(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_post_message_to_members_only() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.info")
+                .param("channel", "C1")
+                .returns(r#"{"ok": true, "channel": {"id": "C1", "is_member": true}}"#))
+            .expect(Expectation::new("conversations.info")
+                .param("channel", "C2")
+                .returns(r#"{"ok": true, "channel": {"id": "C2", "is_member": false}}"#))
+            .expect(Expectation::new("conversations.info").param("channel", "C3").returns_error("channel_not_found"))
+            .expect(Expectation::new("chat.postMessage").param("text", "hi").returns(r#"{"ok": true, "ts": "1.2"}"#));
+        let request = chat::PostMessageRequest { text: "hi", ..Default::default() };
+        let options = BroadcastOptions::new().pace(Duration::from_millis(0)).members_only(true);
+
+        let deliveries = post_message(&mock, "xoxb-1", &["C1", "C2", "C3"], &request, &options);
+
+        assert_eq!(Some("1.2"), deliveries[0].1.ts());
+        match (&deliveries[1].1, &deliveries[2].1) {
+            (&Delivery::Skipped, &Delivery::MembershipUnknown(conversations::InfoError::ChannelNotFound)) => {}
+            other => panic!("expected C2 to be skipped and C3 not to be found, got {:?}", other),
+        }
+        assert_eq!(Some("C1"), mock.calls_of("chat.postMessage")[0].param("channel"));
+    }
+}
@@ -0,0 +1,300 @@
+//! A cache of users and conversations, for bots that look the same ones up over and over.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use serde_json::{self, Value};
+
+use conversations;
+use requests::SlackWebRequestSender;
+use users;
+use {Conversation, User};
+
+/// Users and conversations by ID, each kept for a limited time.
+///
+/// The cache can be warmed in bulk with [`WorkspaceCache::warm`], which lists the whole
+/// workspace. Anything missing or stale is fetched on its own with `users.info` or
+/// `conversations.info`. Feeding it the events your app receives with
+/// [`WorkspaceCache::handle_event`] keeps it up to date between those calls.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use slack_api::cache::WorkspaceCache;
+/// let client = slack_api::requests::default_client().unwrap();
+/// let cache = WorkspaceCache::new().ttl(Duration::from_secs(15 * 60));
+/// cache.warm(&client, "xoxb-...").unwrap();
+///
+/// // Served from the cache
+/// let user = cache.user(&client, "xoxb-...", "U024BE7LH").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct WorkspaceCache {
+    ttl: Duration,
+    users: RwLock<HashMap<String, Entry<User>>>,
+    conversations: RwLock<HashMap<String, Entry<Conversation>>>,
+}
+
+#[derive(Debug)]
+struct Entry<T> {
+    value: T,
+    stored: Instant,
+}
+
+impl Default for WorkspaceCache {
+    fn default() -> Self {
+        WorkspaceCache {
+            ttl: Duration::from_secs(60 * 60),
+            users: RwLock::new(HashMap::new()),
+            conversations: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl WorkspaceCache {
+    pub fn new() -> Self {
+        WorkspaceCache::default()
+    }
+
+    /// How long an entry is served before it is fetched again. Defaults to an hour.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Lists every user and every public and private channel the token can see, replacing what
+    /// was cached.
+    pub fn warm<R>(&self, client: &R, token: &str) -> Result<(), WarmError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        self.warm_users(client, token).map_err(WarmError::Users)?;
+        self.warm_conversations(client, token).map_err(WarmError::Conversations)
+    }
+
+    /// Lists every user, replacing the cached ones.
+    pub fn warm_users<R>(&self, client: &R, token: &str) -> Result<(), users::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let mut users = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let request = users::ListRequest {
+                cursor: cursor.as_ref().map(String::as_str),
+                limit: Some(1000),
+                ..users::ListRequest::default()
+            };
+            let response = users::list(client, token, &request)?;
+            for user in response.members.unwrap_or_default() {
+                if let Some(id) = user.id.clone() {
+                    users.insert(id.into(), Entry::new(user));
+                }
+            }
+            cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+            if cursor.is_none() {
+                break;
+            }
+        }
+        *self.users.write().unwrap() = users;
+        Ok(())
+    }
+
+    /// Lists every public and private channel, replacing the cached conversations.
+    pub fn warm_conversations<R>(&self, client: &R, token: &str) -> Result<(), conversations::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let mut conversations = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let request = conversations::ListRequest {
+                cursor: cursor.as_ref().map(String::as_str),
+                limit: Some(1000),
+                types: Some("public_channel,private_channel"),
+                ..conversations::ListRequest::default()
+            };
+            let response = conversations::list(client, token, &request)?;
+            for conversation in response.channels.unwrap_or_default() {
+                if let Some(id) = conversation.id.clone() {
+                    conversations.insert(id.into(), Entry::new(conversation));
+                }
+            }
+            cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+            if cursor.is_none() {
+                break;
+            }
+        }
+        *self.conversations.write().unwrap() = conversations;
+        Ok(())
+    }
+
+    /// The user with this ID, calling `users.info` if it is not cached or has gone stale.
+    pub fn user<R>(&self, client: &R, token: &str, id: &str) -> Result<User, users::InfoError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        if let Some(user) = self.cached_user(id) {
+            return Ok(user);
+        }
+        let response = users::info(client, token, &users::InfoRequest { user: id })?;
+        let user = response.user.ok_or(users::InfoError::UserNotFound)?;
+        self.users.write().unwrap().insert(id.to_owned(), Entry::new(user.clone()));
+        Ok(user)
+    }
+
+    /// The conversation with this ID, calling `conversations.info` if it is not cached or has
+    /// gone stale.
+    pub fn conversation<R>(&self,
+                           client: &R,
+                           token: &str,
+                           id: &str)
+                           -> Result<Conversation, conversations::InfoError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        if let Some(conversation) = self.cached_conversation(id) {
+            return Ok(conversation);
+        }
+        let request = conversations::InfoRequest {
+            channel: id,
+            ..conversations::InfoRequest::default()
+        };
+        let response = conversations::info(client, token, &request)?;
+        let conversation = response.channel.ok_or(conversations::InfoError::ChannelNotFound)?;
+        self.conversations.write().unwrap().insert(id.to_owned(), Entry::new(conversation.clone()));
+        Ok(conversation)
+    }
+
+    /// The user with this ID if it is cached and fresh, without calling Slack.
+    pub fn cached_user(&self, id: &str) -> Option<User> {
+        fresh(&self.users, id, self.ttl)
+    }
+
+    /// The conversation with this ID if it is cached and fresh, without calling Slack.
+    pub fn cached_conversation(&self, id: &str) -> Option<Conversation> {
+        fresh(&self.conversations, id, self.ttl)
+    }
+
+    /// Updates the cache from an Events API payload, either the whole `event_callback` or just
+    /// its `event`.
+    ///
+    /// `user_change` and `team_join` replace the user they carry. Renaming, archiving,
+    /// unarchiving or deleting a channel evicts it, so it is fetched again on next use. Other
+    /// events are ignored.
+    pub fn handle_event(&self, payload: &Value) {
+        let event = match payload.get("event") {
+            Some(event) => event,
+            None => payload,
+        };
+        match event.get("type").and_then(Value::as_str) {
+            Some("user_change") | Some("team_join") => {
+                let user = event.get("user").and_then(|u| serde_json::from_value::<User>(u.clone()).ok());
+                if let Some(user) = user {
+                    if let Some(id) = user.id.clone() {
+                        self.users.write().unwrap().insert(id.into(), Entry::new(user));
+                    }
+                }
+            }
+            Some("channel_rename") | Some("group_rename") => {
+                if let Some(id) = event.pointer("/channel/id").and_then(Value::as_str) {
+                    self.conversations.write().unwrap().remove(id);
+                }
+            }
+            Some("channel_archive") | Some("channel_unarchive") | Some("channel_deleted") |
+            Some("group_archive") | Some("group_unarchive") | Some("group_deleted") => {
+                if let Some(id) = event.get("channel").and_then(Value::as_str) {
+                    self.conversations.write().unwrap().remove(id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Forgets everything cached.
+    pub fn invalidate(&self) {
+        self.users.write().unwrap().clear();
+        self.conversations.write().unwrap().clear();
+    }
+}
+
+impl<T> Entry<T> {
+    fn new(value: T) -> Self {
+        Entry {
+            value: value,
+            stored: Instant::now(),
+        }
+    }
+}
+
+fn fresh<T: Clone>(entries: &RwLock<HashMap<String, Entry<T>>>, id: &str, ttl: Duration) -> Option<T> {
+    entries.read()
+        .unwrap()
+        .get(id)
+        .filter(|entry| entry.stored.elapsed() < ttl)
+        .map(|entry| entry.value.clone())
+}
+
+#[derive(Debug)]
+pub enum WarmError<E: Error> {
+    /// Listing the users failed.
+    Users(users::ListError<E>),
+    /// Listing the conversations failed.
+    Conversations(conversations::ListError<E>),
+}
+
+impl<E: Error> fmt::Display for WarmError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for WarmError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &WarmError::Users(ref inner) => inner.description(),
+            &WarmError::Conversations(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &WarmError::Users(ref inner) => Some(inner),
+            &WarmError::Conversations(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_warm_then_invalidate_by_event() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("users.list").returns(r#"{"ok": true, "members": [{"id": "U1", "name": "alice"}]}"#))
+            .expect(Expectation::new("conversations.list")
+                .returns(r#"{"ok": true, "channels": [{"id": "C1", "name": "general"}]}"#))
+            .expect(Expectation::new("conversations.info")
+                .returns(r#"{"ok": true, "channel": {"id": "C1", "name": "lobby"}}"#)
+                .times(1));
+        let cache = WorkspaceCache::new();
+        cache.warm(&mock, "xoxb-1").unwrap();
+
+        assert_eq!(Some("alice".to_owned()), cache.user(&mock, "xoxb-1", "U1").unwrap().name);
+        assert_eq!(Some("general".to_owned()), cache.conversation(&mock, "xoxb-1", "C1").unwrap().name);
+
+        let renamed = r#"{
+            "type": "event_callback",
+            "event": {"type": "channel_rename", "channel": {"id": "C1", "name": "lobby"}}
+        }"#;
+        cache.handle_event(&serde_json::from_str(renamed).unwrap());
+        cache.handle_event(&serde_json::from_str(r#"{"type": "user_change", "user": {"id": "U1", "name": "alice2"}}"#)
+            .unwrap());
+
+        assert_eq!(Some("lobby".to_owned()), cache.conversation(&mock, "xoxb-1", "C1").unwrap().name);
+        assert_eq!(Some("alice2".to_owned()), cache.cached_user("U1").and_then(|u| u.name));
+        mock.verify();
+    }
+}
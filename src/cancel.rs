@@ -0,0 +1,220 @@
+//! Cancelling calls and giving them deadlines, for shutting long-running jobs down gracefully.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+use requests::{FilePart, SlackJsonSender, SlackMultipartSender, SlackUploadSender, SlackWebRequestSender, SlackWebResponse};
+
+/// A flag that cancels the calls of every [`Cancellable`] sender it was given to.
+///
+/// Clones share the flag, so a clone can be handed to e.g. a signal handler.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    /// Cancels the calls that have not been sent yet. There is no undoing it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Wraps a request sender, refusing to send calls once its [`CancelToken`] is cancelled or its
+/// [deadline](#method.deadline) has passed.
+///
+/// The check is made before each call, so a job that makes many calls, like an
+/// [`export`](../export/index.html), stops at its next call with a `Client(CancelError::Cancelled)`
+/// error. A call already in flight is not interrupted: the request may have taken effect on
+/// Slack's side, so its response is returned as usual.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// # use std::time::{Duration, Instant};
+/// # use slack_api::cancel::{CancelToken, Cancellable};
+/// # use slack_api::export::{self, ExportOptions};
+/// let token = CancelToken::new();
+/// let client = Cancellable::new(slack_api::requests::default_client().unwrap(), token.clone())
+///     .deadline(Instant::now() + Duration::from_secs(60 * 60));
+///
+/// // e.g. from a signal handler, on another thread
+/// token.cancel();
+///
+/// let result = export::channel_to_jsonl(&client, "xoxb-...", "C024BE91L", io::sink(), &ExportOptions::new());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cancellable<R> {
+    sender: R,
+    token: CancelToken,
+    deadline: Option<Instant>,
+}
+
+impl<R: SlackWebRequestSender> Cancellable<R> {
+    pub fn new(sender: R, token: CancelToken) -> Self {
+        Cancellable {
+            sender: sender,
+            token: token,
+            deadline: None,
+        }
+    }
+
+    /// Refuses to send calls after `deadline` too.
+    ///
+    /// Like cancellation, the deadline is only checked before each call: a call in flight when
+    /// it passes is not aborted, and runs for as long as the sender takes. The `reqwest` sender
+    /// has no request timeout the remaining time could be given to, so the deadline bounds when
+    /// a job's last call starts, not when the job ends.
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// The underlying request sender.
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+
+    fn check(&self) -> Result<(), CancelError<R::Error>> {
+        if self.token.is_cancelled() {
+            Err(CancelError::Cancelled)
+        } else if self.deadline.map(|deadline| Instant::now() >= deadline).unwrap_or(false) {
+            Err(CancelError::DeadlineExceeded)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<R: SlackWebRequestSender> SlackWebRequestSender for Cancellable<R> {
+    type Error = CancelError<R::Error>;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.check()?;
+        self.sender.send(method_url, params).map_err(CancelError::Client)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.check()?;
+        self.sender.send_raw(method_url, params).map_err(CancelError::Client)
+    }
+
+    fn send_bytes(&self, method_url: &str, params: &[(&str, &str)]) -> Result<Vec<u8>, Self::Error> {
+        self.check()?;
+        self.sender.send_bytes(method_url, params).map_err(CancelError::Client)
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.check()?;
+        self.sender.send_json(method_url, token, body).map_err(CancelError::Client)
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.check()?;
+        self.sender.send_form(method_url, params).map_err(CancelError::Client)
+    }
+}
+
+impl<R: SlackUploadSender> SlackUploadSender for Cancellable<R> {
+    fn upload(&self, url: &str, content: &[u8]) -> Result<SlackWebResponse, Self::Error> {
+        self.check()?;
+        self.sender.upload(url, content).map_err(CancelError::Client)
+    }
+}
+
+impl<R: SlackJsonSender> SlackJsonSender for Cancellable<R> {
+    fn post_json(&self, url: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.check()?;
+        self.sender.post_json(url, body).map_err(CancelError::Client)
+    }
+}
+
+impl<R: SlackMultipartSender> SlackMultipartSender for Cancellable<R> {
+    fn send_multipart(&self,
+                      method_url: &str,
+                      token: &str,
+                      fields: &[(&str, &str)],
+                      file: Option<&FilePart>)
+                      -> Result<SlackWebResponse, Self::Error> {
+        self.check()?;
+        self.sender.send_multipart(method_url, token, fields, file).map_err(CancelError::Client)
+    }
+}
+
+#[derive(Debug)]
+pub enum CancelError<E: Error> {
+    /// The token was cancelled, so the call was not sent.
+    Cancelled,
+    /// The deadline had passed, so the call was not sent.
+    DeadlineExceeded,
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for CancelError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CancelError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &CancelError::Cancelled => "the call was cancelled",
+            &CancelError::DeadlineExceeded => "the deadline for the call had passed",
+            &CancelError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &CancelError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use conversations;
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_cancel_stops_calls() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.archive").times(1));
+        let token = CancelToken::new();
+        let client = Cancellable::new(mock, token.clone());
+        let request = conversations::ArchiveRequest { channel: "C1" };
+
+        conversations::archive(&client, "xoxb-1", &request).unwrap();
+        token.cancel();
+        match conversations::archive(&client, "xoxb-1", &request) {
+            Err(conversations::ArchiveError::Client(CancelError::Cancelled)) => {}
+            other => panic!("expected the call to be cancelled, got {:?}", other),
+        }
+        client.sender().verify();
+
+        let late = Cancellable::new(MockSender::new(), CancelToken::new()).deadline(Instant::now() - Duration::from_secs(1));
+        match conversations::archive(&late, "xoxb-1", &request) {
+            Err(conversations::ArchiveError::Client(CancelError::DeadlineExceeded)) => {}
+            other => panic!("expected the deadline to have passed, got {:?}", other),
+        }
+    }
+}
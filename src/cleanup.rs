@@ -0,0 +1,346 @@
+//! Finding and archiving channels nobody uses any more.
+
+use std::error::Error;
+use std::fmt;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use conversations;
+use requests::SlackWebRequestSender;
+
+/// Which channels [`archive_channels`] archives, and how.
+///
+/// A channel is archived only if it matches every criterion that is set. At least one must be
+/// set: without any, every channel would match, so [`archive_channels`] refuses with
+/// [`CleanupError::NoCriteria`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::{Duration, SystemTime};
+/// # use slack_api::cleanup::{self, Cleanup};
+/// let client = slack_api::requests::default_client().unwrap();
+/// let half_a_year_ago = SystemTime::now() - Duration::from_secs(180 * 24 * 60 * 60);
+///
+/// let cleanup = Cleanup::new().name_prefix("proj-").inactive_since(half_a_year_ago).dry_run(true);
+/// let report = cleanup::archive_channels(&client, "xoxb-...", &cleanup).unwrap();
+/// for channel in &report.matched {
+///     println!("would archive #{}", channel.name);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cleanup {
+    name_prefix: Option<String>,
+    fewer_members_than: Option<u32>,
+    inactive_since: Option<SystemTime>,
+    pace: Duration,
+    dry_run: bool,
+}
+
+impl Default for Cleanup {
+    fn default() -> Self {
+        Cleanup {
+            name_prefix: None,
+            fewer_members_than: None,
+            inactive_since: None,
+            pace: Duration::from_secs(3),
+            dry_run: false,
+        }
+    }
+}
+
+impl Cleanup {
+    pub fn new() -> Self {
+        Cleanup::default()
+    }
+
+    /// Only channels whose name starts with `prefix`.
+    pub fn name_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.name_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Only channels with fewer than `members` members.
+    pub fn fewer_members_than(mut self, members: u32) -> Self {
+        self.fewer_members_than = Some(members);
+        self
+    }
+
+    /// Only channels without any message since `since`. Reading a channel's history needs the
+    /// bot to be in it.
+    pub fn inactive_since(mut self, since: SystemTime) -> Self {
+        self.inactive_since = Some(since);
+        self
+    }
+
+    /// How long to wait between two archivals. Defaults to three seconds, which keeps a cleanup
+    /// within `conversations.archive`'s rate limit.
+    pub fn pace(mut self, pace: Duration) -> Self {
+        self.pace = pace;
+        self
+    }
+
+    /// Only reports the matching channels, without archiving them. Off by default.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    fn has_criteria(&self) -> bool {
+        self.name_prefix.is_some() || self.fewer_members_than.is_some() || self.inactive_since.is_some()
+    }
+
+    fn matches_listing(&self, channel: &Channel) -> bool {
+        self.name_prefix.as_ref().map(|prefix| channel.name.starts_with(&prefix[..])).unwrap_or(true) &&
+        self.fewer_members_than.map(|max| channel.num_members.map(|n| n < max).unwrap_or(false)).unwrap_or(true)
+    }
+}
+
+/// A channel considered by [`archive_channels`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Channel {
+    pub id: String,
+    pub name: String,
+    pub num_members: Option<u32>,
+    /// The `ts` of the latest message, if the history was read and has any.
+    pub last_message_ts: Option<String>,
+}
+
+/// What [`archive_channels`] found and did.
+#[derive(Debug)]
+pub struct CleanupReport<E: Error> {
+    /// The channels that matched every criterion.
+    pub matched: Vec<Channel>,
+    /// The IDs of the matched channels that were archived. Empty in a dry run.
+    pub archived: Vec<String>,
+    /// The channels that could not be checked or archived, and why.
+    pub failed: Vec<(Channel, CleanupFailure<E>)>,
+}
+
+/// Why [`archive_channels`] could not deal with one channel.
+#[derive(Debug)]
+pub enum CleanupFailure<E: Error> {
+    /// Reading the history to check for activity failed, e.g. because the bot is not in the
+    /// channel.
+    History(conversations::HistoryError<E>),
+    /// Archiving failed.
+    Archive(conversations::ArchiveError<E>),
+}
+
+/// Archives the public and private channels that match `cleanup`, returning a report of what
+/// matched and what happened to it.
+///
+/// Channels are listed with `conversations.list`; the activity criterion then reads the latest
+/// message of each remaining channel with `conversations.history`. A failure with one channel is
+/// reported and does not stop the cleanup; only a failure to list the channels does.
+pub fn archive_channels<R>(client: &R,
+                           token: &str,
+                           cleanup: &Cleanup)
+                           -> Result<CleanupReport<R::Error>, CleanupError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    if !cleanup.has_criteria() {
+        return Err(CleanupError::NoCriteria);
+    }
+    let mut report = CleanupReport {
+        matched: Vec::new(),
+        archived: Vec::new(),
+        failed: Vec::new(),
+    };
+    for mut channel in list_channels(client, token).map_err(CleanupError::List)? {
+        if !cleanup.matches_listing(&channel) {
+            continue;
+        }
+        if let Some(since) = cleanup.inactive_since {
+            match last_message_ts(client, token, &channel.id) {
+                Ok(ts) => channel.last_message_ts = ts,
+                Err(err) => {
+                    report.failed.push((channel, CleanupFailure::History(err)));
+                    continue;
+                }
+            }
+            let since = since.duration_since(UNIX_EPOCH).map(|d| d.as_secs() as f64).unwrap_or(0.0);
+            let active = channel.last_message_ts
+                .as_ref()
+                .and_then(|ts| ts.parse::<f64>().ok())
+                .map(|ts| ts >= since)
+                .unwrap_or(false);
+            if active {
+                continue;
+            }
+        }
+        report.matched.push(channel);
+    }
+
+    if cleanup.dry_run {
+        return Ok(report);
+    }
+    for (i, channel) in report.matched.iter().enumerate() {
+        if i > 0 {
+            thread::sleep(cleanup.pace);
+        }
+        let request = conversations::ArchiveRequest { channel: &channel.id };
+        match conversations::archive(client, token, &request) {
+            Ok(_) => report.archived.push(channel.id.clone()),
+            Err(err) => report.failed.push((channel.clone(), CleanupFailure::Archive(err))),
+        }
+    }
+    Ok(report)
+}
+
+fn list_channels<R>(client: &R, token: &str) -> Result<Vec<Channel>, conversations::ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut channels = Vec::new();
+    let mut cursor = None;
+    loop {
+        let request = conversations::ListRequest {
+            cursor: cursor.as_ref().map(String::as_str),
+            exclude_archived: Some(true),
+            limit: Some(1000),
+            types: Some("public_channel,private_channel"),
+            ..conversations::ListRequest::default()
+        };
+        let response = conversations::list(client, token, &request)?;
+        for conversation in response.channels.unwrap_or_default() {
+            // The general channel cannot be archived.
+            if conversation.is_general == Some(true) {
+                continue;
+            }
+            if let (Some(id), Some(name)) = (conversation.id, conversation.name) {
+                channels.push(Channel {
+                    id: id.into(),
+                    name: name,
+                    num_members: conversation.num_members.map(|n| n as u32),
+                    last_message_ts: None,
+                });
+            }
+        }
+        cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            return Ok(channels);
+        }
+    }
+}
+
+fn last_message_ts<R>(client: &R,
+                      token: &str,
+                      channel: &str)
+                      -> Result<Option<String>, conversations::HistoryError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = conversations::HistoryRequest {
+        channel: channel,
+        limit: Some(1),
+        ..conversations::HistoryRequest::default()
+    };
+    let response = conversations::history(client, token, &request)?;
+    Ok(response.messages.unwrap_or_default().first().and_then(|m| m.ts()).map(str::to_owned))
+}
+
+#[derive(Debug)]
+pub enum CleanupError<E: Error> {
+    /// The cleanup has no criteria, so it would archive every channel.
+    NoCriteria,
+    /// Listing the channels failed.
+    List(conversations::ListError<E>),
+}
+
+impl<E: Error> fmt::Display for CleanupError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CleanupError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &CleanupError::NoCriteria => "the cleanup has no criteria, so it would archive every channel",
+            &CleanupError::List(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &CleanupError::NoCriteria => None,
+            &CleanupError::List(ref inner) => Some(inner),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for CleanupFailure<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CleanupFailure<E> {
+    fn description(&self) -> &str {
+        match self {
+            &CleanupFailure::History(ref inner) => inner.description(),
+            &CleanupFailure::Archive(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &CleanupFailure::History(ref inner) => Some(inner),
+            &CleanupFailure::Archive(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_archive_inactive_channels() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").returns(r#"{"ok": true, "channels": [
+                {"id": "C1", "name": "proj-old", "num_members": 2},
+                {"id": "C2", "name": "proj-busy", "num_members": 2},
+                {"id": "C3", "name": "proj-big", "num_members": 40},
+                {"id": "C4", "name": "proj-closed", "num_members": 1},
+                {"id": "C5", "name": "random", "num_members": 1}
+            ]}"#))
+            .expect(Expectation::new("conversations.history")
+                .param("channel", "C1")
+                .returns(r#"{"ok": true, "messages": [{"type": "message", "ts": "1000.000100"}]}"#))
+            .expect(Expectation::new("conversations.history")
+                .param("channel", "C2")
+                .returns(r#"{"ok": true, "messages": [{"type": "message", "ts": "3000.000100"}]}"#))
+            .expect(Expectation::new("conversations.history").param("channel", "C4").returns_error("not_in_channel"))
+            .expect(Expectation::new("conversations.archive").param("channel", "C1").times(1));
+        let cleanup = Cleanup::new()
+            .name_prefix("proj-")
+            .fewer_members_than(10)
+            .inactive_since(UNIX_EPOCH + Duration::from_secs(2000))
+            .pace(Duration::from_millis(0));
+
+        let report = archive_channels(&mock, "xoxb-1", &cleanup).unwrap();
+
+        assert_eq!(vec!["C1".to_owned()], report.archived);
+        assert_eq!(Some("1000.000100".to_owned()), report.matched[0].last_message_ts);
+        match report.failed[..] {
+            [(ref channel, CleanupFailure::History(conversations::HistoryError::NotInChannel))] => {
+                assert_eq!("C4", channel.id)
+            }
+            ref other => panic!("expected C4's history to fail, got {:?}", other),
+        }
+        mock.verify();
+    }
+
+    #[test]
+    fn test_cleanup_without_criteria_is_refused() {
+        let mock = MockSender::new();
+
+        match archive_channels(&mock, "xoxb-1", &Cleanup::new()) {
+            Err(CleanupError::NoCriteria) => {}
+            other => panic!("expected the cleanup to be refused, got {:?}", other),
+        }
+        assert!(mock.calls().is_empty());
+    }
+}
@@ -0,0 +1,1054 @@
+//! A client that owns its request sender and default token.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use chat;
+use conversations;
+use metrics::{self, MetaRecorder, WithMeta};
+use plan::{self, Plan, PlanError};
+use requests::SlackWebRequestSender;
+use resolve::Resolver;
+use secret::Secret;
+use team_billing;
+use tokens::{MethodToken, TokenProvider, TokenSet};
+use users;
+
+/// A request sender paired with the token calls are made with by default.
+///
+/// The client is a handle: its sender, token and caches are behind an [`Arc`], so clones are cheap
+/// and share them, including the sender's connection pool and any rate limit state it keeps. It
+/// is `Send` and `Sync` whenever the sender is, so it can be cloned into as many threads as needed.
+///
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::client::SlackClient;
+/// # let user_token = "xoxp-...";
+/// let slack = SlackClient::new(slack_api::requests::default_client().unwrap(), "xoxb-...");
+///
+/// // Called with the bot token
+/// let channels = slack.conversations().list(&Default::default());
+///
+/// // Called as a user, just this once
+/// let results = slack.with_token(user_token).search().all(&Default::default());
+///
+/// // Any function taking a sender and a token can be called too
+/// let channels = slack.call(|client, token| slack_api::conversations::list(client, token, &Default::default()));
+/// ```
+#[derive(Debug)]
+pub struct SlackClient<R> {
+    inner: Arc<Inner<R>>,
+}
+
+#[derive(Debug)]
+struct Inner<R> {
+    sender: R,
+    token: Secret,
+    tokens: Option<TokenSet>,
+    resolver: Resolver,
+    plan: RwLock<Option<Plan>>,
+}
+
+impl<R> Clone for SlackClient<R> {
+    fn clone(&self) -> Self {
+        SlackClient { inner: self.inner.clone() }
+    }
+}
+
+impl<R: SlackWebRequestSender> SlackClient<R> {
+    pub fn new<S: Into<Secret>>(sender: R, token: S) -> Self {
+        SlackClient {
+            inner: Arc::new(Inner {
+                sender: sender,
+                token: token.into(),
+                tokens: None,
+                resolver: Resolver::new(),
+                plan: RwLock::new(None),
+            }),
+        }
+    }
+
+    /// A client holding several kinds of token, whose methods by module (like
+    /// [`SlackClient::search`]) are each called with the kind of token they need: see
+    /// [`TokenSet::for_method`]. Everything else, like [`SlackClient::call`], uses the bot token, or
+    /// the user token if there is none.
+    ///
+    /// [`TokenSet::for_method`]: ../tokens/struct.TokenSet.html#method.for_method
+    pub fn from_token_set(sender: R, tokens: TokenSet) -> Self {
+        let token = tokens.bot.clone().or(tokens.user.clone()).or(tokens.app.clone()).unwrap_or_default();
+        SlackClient {
+            inner: Arc::new(Inner {
+                sender: sender,
+                token: token,
+                tokens: Some(tokens),
+                resolver: Resolver::new(),
+                plan: RwLock::new(None),
+            }),
+        }
+    }
+
+    /// The underlying request sender.
+    pub fn sender(&self) -> &R {
+        &self.inner.sender
+    }
+
+    /// The token calls are made with unless overridden.
+    pub fn token(&self) -> &str {
+        self.inner.token.expose()
+    }
+
+    /// The cache of names to IDs used by e.g. [`SlackClient::say`]. Clones of a client share it.
+    pub fn resolver(&self) -> &Resolver {
+        &self.inner.resolver
+    }
+
+    /// The workspace's plan, read with `team.billing.info` (which needs the `team.billing:read`
+    /// scope) the first time and remembered after that. Clones of a client share it.
+    pub fn plan(&self) -> Result<Plan, team_billing::InfoError<R::Error>> {
+        if let Some(ref plan) = *self.inner.plan.read().unwrap() {
+            return Ok(plan.clone());
+        }
+        let response = team_billing::info(&self.inner.sender, self.inner.token.expose())?;
+        let plan = Plan::from(response.plan.as_ref().map(String::as_str).unwrap_or(""));
+        *self.inner.plan.write().unwrap() = Some(plan.clone());
+        Ok(plan)
+    }
+
+    /// Checks that the workspace's plan includes `method`, like `"usergroups.create"`, so a call
+    /// that would fail with `paid_only` can be reported as such up front.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use slack_api::client::SlackClient;
+    /// let slack = SlackClient::new(slack_api::requests::default_client().unwrap(), "xoxb-...");
+    /// if let Err(err) = slack.check_plan("usergroups.create") {
+    ///     println!("skipping the on-call group: {}", err);
+    /// }
+    /// ```
+    pub fn check_plan(&self, method: &str) -> Result<(), PlanError<R::Error>> {
+        let plan = self.plan().map_err(PlanError::Info)?;
+        match plan::required_plan(method) {
+            Some(required) => {
+                if plan.allows(method) {
+                    Ok(())
+                } else {
+                    Err(PlanError::NotIncluded {
+                        method: method.to_owned(),
+                        plan: plan,
+                        required: required,
+                    })
+                }
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Calls an API method with the default token.
+    pub fn call<F, T>(&self, method: F) -> T
+        where F: FnOnce(&R, &str) -> T
+    {
+        method(&self.inner.sender, self.inner.token.expose())
+    }
+
+    /// Calls an API method with the default token, returning its result together with how long
+    /// it took, how many attempts it needed and the final HTTP status.
+    pub fn call_with_meta<F, T>(&self, method: F) -> WithMeta<T>
+        where F: FnOnce(&MetaRecorder<R>, &str) -> T
+    {
+        metrics::with_meta(&self.inner.sender, |sender| method(sender, self.inner.token.expose()))
+    }
+
+    /// Posts `text` to a channel given by name (`"#deploys"`) or ID.
+    ///
+    /// Names are resolved through the client's [`Resolver`]. If the bot is not in the channel yet,
+    /// it joins it and tries again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use slack_api::client::SlackClient;
+    /// let slack = SlackClient::new(slack_api::requests::default_client().unwrap(), "xoxb-...");
+    /// slack.say("#deploys", "release 1.2 done").unwrap();
+    /// ```
+    pub fn say(&self, channel: &str, text: &str) -> Result<chat::PostMessageResponse, SayError<R::Error>> {
+        let token = self.inner.token.expose();
+        let channel = if is_conversation_id(channel) {
+            channel.to_owned()
+        } else {
+            self.inner.resolver
+                .channel_id(&self.inner.sender, token, channel)
+                .map_err(SayError::Resolve)?
+                .ok_or_else(|| SayError::ChannelNotFound(channel.to_owned()))?
+        };
+
+        let request = chat::PostMessageRequest {
+            channel: &channel,
+            text: text,
+            ..chat::PostMessageRequest::default()
+        };
+        match chat::post_message(&self.inner.sender, token, &request) {
+            Err(chat::PostMessageError::NotInChannel) => {
+                let join = conversations::JoinRequest { channel: &channel };
+                conversations::join(&self.inner.sender, token, &join).map_err(SayError::Join)?;
+                chat::post_message(&self.inner.sender, token, &request).map_err(SayError::PostMessage)
+            }
+            result => result.map_err(SayError::PostMessage),
+        }
+    }
+
+    /// Sends `text` to a user in a direct message, returning the message's `ts`.
+    ///
+    /// The user is given by ID or by email address, which is resolved through the client's
+    /// [`Resolver`] (and needs the `users:read.email` scope). The conversation is opened the first
+    /// time and reused after that.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use slack_api::client::SlackClient;
+    /// let slack = SlackClient::new(slack_api::requests::default_client().unwrap(), "xoxb-...");
+    /// slack.send_dm("alice@example.com", "Your export is ready").unwrap();
+    /// ```
+    pub fn send_dm(&self, user: &str, text: &str) -> Result<String, SendDmError<R::Error>> {
+        let request = chat::PostMessageRequest { text: text, ..chat::PostMessageRequest::default() };
+        self.send_dm_with(user, &request)
+    }
+
+    /// Sends a message of your own making to a user in a direct message, e.g. with `blocks` set.
+    /// Its `channel` is filled in. See [`SlackClient::send_dm`].
+    pub fn send_dm_with(&self,
+                        user: &str,
+                        request: &chat::PostMessageRequest)
+                        -> Result<String, SendDmError<R::Error>> {
+        let token = self.inner.token.expose();
+        let user_id = if user.contains('@') {
+            self.inner.resolver
+                .user_id_by_email(&self.inner.sender, token, user)
+                .map_err(SendDmError::Resolve)?
+                .ok_or_else(|| SendDmError::UserNotFound(user.to_owned()))?
+        } else {
+            user.to_owned()
+        };
+        let channel = self.inner.resolver
+            .dm_channel_id(&self.inner.sender, token, &user_id)
+            .map_err(SendDmError::Open)?
+            .ok_or_else(|| SendDmError::UserNotFound(user.to_owned()))?;
+
+        let request = chat::PostMessageRequest { channel: &channel, ..request.clone() };
+        let response = chat::post_message(&self.inner.sender, token, &request).map_err(SendDmError::PostMessage)?;
+        Ok(response.ts.unwrap_or_default())
+    }
+
+    /// The tokens the methods by module pick from, if the client was made from a [`TokenSet`].
+    pub fn token_set(&self) -> Option<&TokenSet> {
+        self.inner.tokens.as_ref()
+    }
+
+    /// The token the methods by module are called with.
+    fn method_token<'a>(&'a self) -> MethodToken<'a> {
+        match self.inner.tokens {
+            Some(ref tokens) => MethodToken::Set(tokens),
+            None => MethodToken::Token(self.inner.token.expose()),
+        }
+    }
+
+    /// A view of this client that makes calls with `token` instead of the default token, e.g. to
+    /// act as a specific user or on another workspace.
+    pub fn with_token<'a>(&'a self, token: &'a str) -> TokenOverride<'a, R> {
+        TokenOverride {
+            sender: &self.inner.sender,
+            token: Secret::new(Cow::Borrowed(token)),
+        }
+    }
+}
+
+/// The API methods, by module, called with the client's default token, or with the token of
+/// its [`TokenSet`] that each method needs.
+impl<R: SlackWebRequestSender> SlackClient<R> {
+    /// The `admin.apps.*` methods.
+    pub fn admin_apps<'a>(&'a self) -> ::admin_apps::Methods<'a, R> {
+        ::admin_apps::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `admin.conversations.*` methods.
+    pub fn admin_conversations<'a>(&'a self) -> ::admin_conversations::Methods<'a, R> {
+        ::admin_conversations::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `admin.emoji.*` methods.
+    pub fn admin_emoji<'a>(&'a self) -> ::admin_emoji::Methods<'a, R> {
+        ::admin_emoji::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `admin.teams.*` methods.
+    pub fn admin_teams<'a>(&'a self) -> ::admin_teams::Methods<'a, R> {
+        ::admin_teams::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `admin.users.*` methods.
+    pub fn admin_users<'a>(&'a self) -> ::admin_users::Methods<'a, R> {
+        ::admin_users::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `apps.connections.*` methods.
+    pub fn apps_connections<'a>(&'a self) -> ::apps_connections::Methods<'a, R> {
+        ::apps_connections::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `apps.event.authorizations.*` methods.
+    pub fn apps_event_authorizations<'a>(&'a self) -> ::apps_event_authorizations::Methods<'a, R> {
+        ::apps_event_authorizations::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `auth.*` methods.
+    pub fn auth<'a>(&'a self) -> ::auth::Methods<'a, R> {
+        ::auth::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `bookmarks.*` methods.
+    pub fn bookmarks<'a>(&'a self) -> ::bookmarks::Methods<'a, R> {
+        ::bookmarks::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `bots.*` methods.
+    pub fn bots<'a>(&'a self) -> ::bots::Methods<'a, R> {
+        ::bots::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `calls.*` methods.
+    pub fn calls<'a>(&'a self) -> ::calls::Methods<'a, R> {
+        ::calls::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `calls.participants.*` methods.
+    pub fn calls_participants<'a>(&'a self) -> ::calls_participants::Methods<'a, R> {
+        ::calls_participants::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `chat.*` methods.
+    pub fn chat<'a>(&'a self) -> ::chat::Methods<'a, R> {
+        ::chat::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `chat.scheduledMessages.*` methods.
+    pub fn chat_scheduled_messages<'a>(&'a self) -> ::chat_scheduled_messages::Methods<'a, R> {
+        ::chat_scheduled_messages::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `conversations.*` methods.
+    pub fn conversations<'a>(&'a self) -> ::conversations::Methods<'a, R> {
+        ::conversations::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `dnd.*` methods.
+    pub fn dnd<'a>(&'a self) -> ::dnd::Methods<'a, R> {
+        ::dnd::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `emoji.*` methods.
+    pub fn emoji<'a>(&'a self) -> ::emoji::Methods<'a, R> {
+        ::emoji::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `files.*` methods.
+    pub fn files<'a>(&'a self) -> ::files::Methods<'a, R> {
+        ::files::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `files.comments.*` methods.
+    pub fn files_comments<'a>(&'a self) -> ::files_comments::Methods<'a, R> {
+        ::files_comments::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `oauth.v2.*` methods.
+    pub fn oauth_v2<'a>(&'a self) -> ::oauth_v2::Methods<'a, R> {
+        ::oauth_v2::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `openid.connect.*` methods.
+    pub fn openid_connect<'a>(&'a self) -> ::openid_connect::Methods<'a, R> {
+        ::openid_connect::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `pins.*` methods.
+    pub fn pins<'a>(&'a self) -> ::pins::Methods<'a, R> {
+        ::pins::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `reactions.*` methods.
+    pub fn reactions<'a>(&'a self) -> ::reactions::Methods<'a, R> {
+        ::reactions::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `reminders.*` methods.
+    pub fn reminders<'a>(&'a self) -> ::reminders::Methods<'a, R> {
+        ::reminders::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `rtm.*` methods.
+    pub fn rtm<'a>(&'a self) -> ::rtm::Methods<'a, R> {
+        ::rtm::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `search.*` methods.
+    pub fn search<'a>(&'a self) -> ::search::Methods<'a, R> {
+        ::search::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `stars.*` methods.
+    pub fn stars<'a>(&'a self) -> ::stars::Methods<'a, R> {
+        ::stars::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `team.*` methods.
+    pub fn team<'a>(&'a self) -> ::team::Methods<'a, R> {
+        ::team::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `team.billing.*` methods.
+    pub fn team_billing<'a>(&'a self) -> ::team_billing::Methods<'a, R> {
+        ::team_billing::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `team.profile.*` methods.
+    pub fn team_profile<'a>(&'a self) -> ::team_profile::Methods<'a, R> {
+        ::team_profile::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `usergroups.*` methods.
+    pub fn usergroups<'a>(&'a self) -> ::usergroups::Methods<'a, R> {
+        ::usergroups::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `usergroups.users.*` methods.
+    pub fn usergroups_users<'a>(&'a self) -> ::usergroups_users::Methods<'a, R> {
+        ::usergroups_users::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `users.*` methods.
+    pub fn users<'a>(&'a self) -> ::users::Methods<'a, R> {
+        ::users::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `users.profile.*` methods.
+    pub fn users_profile<'a>(&'a self) -> ::users_profile::Methods<'a, R> {
+        ::users_profile::Methods::new(&self.inner.sender, self.method_token())
+    }
+
+    /// The `views.*` methods.
+    pub fn views<'a>(&'a self) -> ::views::Methods<'a, R> {
+        ::views::Methods::new(&self.inner.sender, self.method_token())
+    }
+}
+
+/// A client with its token overridden, as returned by [`SlackClient::with_token`] and
+/// [`TeamClients::team`].
+#[derive(Debug)]
+pub struct TokenOverride<'a, R: 'a> {
+    sender: &'a R,
+    token: Secret<Cow<'a, str>>,
+}
+
+impl<'a, R: SlackWebRequestSender> TokenOverride<'a, R> {
+    /// The token calls are made with.
+    pub fn token(&self) -> &str {
+        self.token.expose()
+    }
+
+    /// Calls an API method with the overriding token.
+    pub fn call<F, T>(&self, method: F) -> T
+        where F: FnOnce(&R, &str) -> T
+    {
+        method(self.sender, self.token.expose())
+    }
+}
+
+/// The API methods, by module, called with the overriding token.
+impl<'a, R: SlackWebRequestSender> TokenOverride<'a, R> {
+    /// The `admin.apps.*` methods.
+    pub fn admin_apps<'b>(&'b self) -> ::admin_apps::Methods<'b, R> {
+        ::admin_apps::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `admin.conversations.*` methods.
+    pub fn admin_conversations<'b>(&'b self) -> ::admin_conversations::Methods<'b, R> {
+        ::admin_conversations::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `admin.emoji.*` methods.
+    pub fn admin_emoji<'b>(&'b self) -> ::admin_emoji::Methods<'b, R> {
+        ::admin_emoji::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `admin.teams.*` methods.
+    pub fn admin_teams<'b>(&'b self) -> ::admin_teams::Methods<'b, R> {
+        ::admin_teams::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `admin.users.*` methods.
+    pub fn admin_users<'b>(&'b self) -> ::admin_users::Methods<'b, R> {
+        ::admin_users::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `apps.connections.*` methods.
+    pub fn apps_connections<'b>(&'b self) -> ::apps_connections::Methods<'b, R> {
+        ::apps_connections::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `apps.event.authorizations.*` methods.
+    pub fn apps_event_authorizations<'b>(&'b self) -> ::apps_event_authorizations::Methods<'b, R> {
+        ::apps_event_authorizations::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `auth.*` methods.
+    pub fn auth<'b>(&'b self) -> ::auth::Methods<'b, R> {
+        ::auth::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `bookmarks.*` methods.
+    pub fn bookmarks<'b>(&'b self) -> ::bookmarks::Methods<'b, R> {
+        ::bookmarks::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `bots.*` methods.
+    pub fn bots<'b>(&'b self) -> ::bots::Methods<'b, R> {
+        ::bots::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `calls.*` methods.
+    pub fn calls<'b>(&'b self) -> ::calls::Methods<'b, R> {
+        ::calls::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `calls.participants.*` methods.
+    pub fn calls_participants<'b>(&'b self) -> ::calls_participants::Methods<'b, R> {
+        ::calls_participants::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `chat.*` methods.
+    pub fn chat<'b>(&'b self) -> ::chat::Methods<'b, R> {
+        ::chat::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `chat.scheduledMessages.*` methods.
+    pub fn chat_scheduled_messages<'b>(&'b self) -> ::chat_scheduled_messages::Methods<'b, R> {
+        ::chat_scheduled_messages::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `conversations.*` methods.
+    pub fn conversations<'b>(&'b self) -> ::conversations::Methods<'b, R> {
+        ::conversations::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `dnd.*` methods.
+    pub fn dnd<'b>(&'b self) -> ::dnd::Methods<'b, R> {
+        ::dnd::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `emoji.*` methods.
+    pub fn emoji<'b>(&'b self) -> ::emoji::Methods<'b, R> {
+        ::emoji::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `files.*` methods.
+    pub fn files<'b>(&'b self) -> ::files::Methods<'b, R> {
+        ::files::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `files.comments.*` methods.
+    pub fn files_comments<'b>(&'b self) -> ::files_comments::Methods<'b, R> {
+        ::files_comments::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `oauth.v2.*` methods.
+    pub fn oauth_v2<'b>(&'b self) -> ::oauth_v2::Methods<'b, R> {
+        ::oauth_v2::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `openid.connect.*` methods.
+    pub fn openid_connect<'b>(&'b self) -> ::openid_connect::Methods<'b, R> {
+        ::openid_connect::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `pins.*` methods.
+    pub fn pins<'b>(&'b self) -> ::pins::Methods<'b, R> {
+        ::pins::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `reactions.*` methods.
+    pub fn reactions<'b>(&'b self) -> ::reactions::Methods<'b, R> {
+        ::reactions::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `reminders.*` methods.
+    pub fn reminders<'b>(&'b self) -> ::reminders::Methods<'b, R> {
+        ::reminders::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `rtm.*` methods.
+    pub fn rtm<'b>(&'b self) -> ::rtm::Methods<'b, R> {
+        ::rtm::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `search.*` methods.
+    pub fn search<'b>(&'b self) -> ::search::Methods<'b, R> {
+        ::search::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `stars.*` methods.
+    pub fn stars<'b>(&'b self) -> ::stars::Methods<'b, R> {
+        ::stars::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `team.*` methods.
+    pub fn team<'b>(&'b self) -> ::team::Methods<'b, R> {
+        ::team::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `team.billing.*` methods.
+    pub fn team_billing<'b>(&'b self) -> ::team_billing::Methods<'b, R> {
+        ::team_billing::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `team.profile.*` methods.
+    pub fn team_profile<'b>(&'b self) -> ::team_profile::Methods<'b, R> {
+        ::team_profile::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `usergroups.*` methods.
+    pub fn usergroups<'b>(&'b self) -> ::usergroups::Methods<'b, R> {
+        ::usergroups::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `usergroups.users.*` methods.
+    pub fn usergroups_users<'b>(&'b self) -> ::usergroups_users::Methods<'b, R> {
+        ::usergroups_users::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `users.*` methods.
+    pub fn users<'b>(&'b self) -> ::users::Methods<'b, R> {
+        ::users::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `users.profile.*` methods.
+    pub fn users_profile<'b>(&'b self) -> ::users_profile::Methods<'b, R> {
+        ::users_profile::Methods::new(self.sender, self.token.expose())
+    }
+
+    /// The `views.*` methods.
+    pub fn views<'b>(&'b self) -> ::views::Methods<'b, R> {
+        ::views::Methods::new(self.sender, self.token.expose())
+    }
+}
+
+/// A request sender shared by all the workspaces an app is installed to, which calls the API on
+/// behalf of each with the token its [`TokenProvider`] has for it, so that a multi-workspace app
+/// needs neither a client per workspace nor to pass tokens around.
+///
+/// Like [`SlackClient`], it is a cheap handle to state shared by its clones. It has no
+/// [`Resolver`]: names resolve differently in each workspace.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::client::TeamClients;
+/// # use slack_api::tokens::MemoryTokenStore;
+/// # let team_id = "T024BE7LD";
+/// let slack = TeamClients::new(slack_api::requests::default_client().unwrap(), MemoryTokenStore::new());
+///
+/// // Called with the token of the workspace an event came from
+/// let channels = slack.team(None, team_id).unwrap().conversations().list(&Default::default());
+///
+/// // Called with another token, just this once
+/// let user = slack.with_token("xoxp-...").users().identity();
+/// ```
+#[derive(Debug)]
+pub struct TeamClients<R, P> {
+    inner: Arc<TeamInner<R, P>>,
+}
+
+#[derive(Debug)]
+struct TeamInner<R, P> {
+    sender: R,
+    tokens: P,
+}
+
+impl<R, P> Clone for TeamClients<R, P> {
+    fn clone(&self) -> Self {
+        TeamClients { inner: self.inner.clone() }
+    }
+}
+
+impl<R: SlackWebRequestSender, P: TokenProvider<R>> TeamClients<R, P> {
+    pub fn new(sender: R, tokens: P) -> Self {
+        TeamClients {
+            inner: Arc::new(TeamInner {
+                sender: sender,
+                tokens: tokens,
+            }),
+        }
+    }
+
+    /// The underlying request sender.
+    pub fn sender(&self) -> &R {
+        &self.inner.sender
+    }
+
+    /// Where the tokens of the workspaces come from.
+    pub fn tokens(&self) -> &P {
+        &self.inner.tokens
+    }
+
+    /// A view of the client that makes calls with the token of the workspace `team_id`, as the
+    /// token provider has it now.
+    ///
+    /// Pass the `enterprise_id` of the request, if it has one, so that workspaces of an
+    /// Enterprise Grid organization the app was installed to org-wide are found too.
+    pub fn team<'a>(&'a self,
+                    enterprise_id: Option<&str>,
+                    team_id: &str)
+                    -> Result<TokenOverride<'a, R>, TeamTokenError<P::Error>> {
+        match self.inner.tokens.token(&self.inner.sender, enterprise_id, team_id) {
+            Ok(Some(token)) => {
+                Ok(TokenOverride {
+                    sender: &self.inner.sender,
+                    token: Secret::new(Cow::Owned(token)),
+                })
+            }
+            Ok(None) => Err(TeamTokenError::NotInstalled(team_id.to_owned())),
+            Err(err) => Err(TeamTokenError::Provider(err)),
+        }
+    }
+
+    /// A view of the client that makes calls with `token`, for the calls made with another token
+    /// than a workspace's, e.g. a user token.
+    pub fn with_token<'a>(&'a self, token: &'a str) -> TokenOverride<'a, R> {
+        TokenOverride {
+            sender: &self.inner.sender,
+            token: Secret::new(Cow::Borrowed(token)),
+        }
+    }
+}
+
+/// Whether `channel` is a conversation ID rather than a name. Channel names are always lower
+/// case, IDs never are.
+fn is_conversation_id(channel: &str) -> bool {
+    channel.starts_with(|c| c == 'C' || c == 'G' || c == 'D') &&
+    channel.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+#[derive(Debug)]
+pub enum SayError<E: Error> {
+    /// Listing the channels to resolve the name failed.
+    Resolve(conversations::ListError<E>),
+    /// There is no channel by this name that the token can see.
+    ChannelNotFound(String),
+    /// The bot was not in the channel and could not join it.
+    Join(conversations::JoinError<E>),
+    /// `chat.postMessage` failed.
+    PostMessage(chat::PostMessageError<E>),
+}
+
+impl<E: Error> fmt::Display for SayError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &SayError::ChannelNotFound(ref name) => write!(f, "{}: {}", self.description(), name),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: Error> Error for SayError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SayError::Resolve(ref inner) => inner.description(),
+            &SayError::ChannelNotFound(_) => "no channel by this name was found",
+            &SayError::Join(ref inner) => inner.description(),
+            &SayError::PostMessage(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SayError::Resolve(ref inner) => Some(inner),
+            &SayError::ChannelNotFound(_) => None,
+            &SayError::Join(ref inner) => Some(inner),
+            &SayError::PostMessage(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SendDmError<E: Error> {
+    /// Listing the users to resolve the email address failed.
+    Resolve(users::ListError<E>),
+    /// There is no user with this email address that the token can see.
+    UserNotFound(String),
+    /// The direct message conversation could not be opened.
+    Open(conversations::OpenError<E>),
+    /// `chat.postMessage` failed.
+    PostMessage(chat::PostMessageError<E>),
+}
+
+impl<E: Error> fmt::Display for SendDmError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &SendDmError::UserNotFound(ref user) => write!(f, "{}: {}", self.description(), user),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: Error> Error for SendDmError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SendDmError::Resolve(ref inner) => inner.description(),
+            &SendDmError::UserNotFound(_) => "no such user was found",
+            &SendDmError::Open(ref inner) => inner.description(),
+            &SendDmError::PostMessage(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SendDmError::Resolve(ref inner) => Some(inner),
+            &SendDmError::UserNotFound(_) => None,
+            &SendDmError::Open(ref inner) => Some(inner),
+            &SendDmError::PostMessage(ref inner) => Some(inner),
+        }
+    }
+}
+
+/// Why [`TeamClients::team`] has no token for a workspace.
+#[derive(Debug)]
+pub enum TeamTokenError<E: Error> {
+    /// The app is not installed to this workspace.
+    NotInstalled(String),
+    /// The token provider failed.
+    Provider(E),
+}
+
+impl<E: Error> fmt::Display for TeamTokenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &TeamTokenError::NotInstalled(ref team_id) => write!(f, "{}: {}", self.description(), team_id),
+            &TeamTokenError::Provider(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error> Error for TeamTokenError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &TeamTokenError::NotInstalled(_) => "the app is not installed to this workspace",
+            &TeamTokenError::Provider(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &TeamTokenError::NotInstalled(_) => None,
+            &TeamTokenError::Provider(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_clones_share_state() {
+        assert_send_sync::<SlackClient<MockSender>>();
+
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("team.billing.info").returns(r#"{"ok": true, "plan": "std"}"#).times(1));
+        let slack = SlackClient::new(mock, "xoxb-1");
+        slack.plan().unwrap();
+
+        // The clones find the plan in the cache they share with the original.
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let slack = slack.clone();
+                thread::spawn(move || slack.plan().unwrap())
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(Plan::Pro, handle.join().unwrap());
+        }
+        slack.sender().verify();
+    }
+
+    #[test]
+    fn test_say_resolves_and_joins() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list")
+                .returns(r#"{"ok": true, "channels": [{"id": "C024BE91L", "name": "deploys"}]}"#))
+            .expect(Expectation::new("chat.postMessage").returns_error("not_in_channel").times(1))
+            .expect(Expectation::new("conversations.join").param("channel", "C024BE91L").times(1))
+            .expect(Expectation::new("chat.postMessage").returns(r#"{"ok": true, "ts": "1.2"}"#));
+        let slack = SlackClient::new(mock, "xoxb-1");
+
+        assert_eq!(Some("1.2".to_owned()), slack.say("#deploys", "done").unwrap().ts);
+        assert_eq!(Some("C024BE91L"), slack.sender().calls_of("chat.postMessage")[1].param("channel"));
+        slack.sender().verify();
+
+        match slack.say("#nope", "done") {
+            Err(SayError::ChannelNotFound(name)) => assert_eq!("#nope", name),
+            other => panic!("expected the channel not to be found, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_send_dm_opens_once() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("users.list")
+                .returns(r#"{"ok": true, "members": [{"id": "U1", "profile": {"email": "alice@example.com"}}]}"#))
+            .expect(Expectation::new("conversations.open")
+                .param("users", "U1")
+                .returns(r#"{"ok": true, "channel": {"id": "D1"}}"#)
+                .times(1))
+            .expect(Expectation::new("chat.postMessage").param("channel", "D1").returns(r#"{"ok": true, "ts": "1.2"}"#));
+        let slack = SlackClient::new(mock, "xoxb-1");
+
+        assert_eq!("1.2", slack.send_dm("alice@example.com", "ready").unwrap());
+        assert_eq!("1.2", slack.send_dm("U1", "still ready").unwrap());
+        slack.sender().verify();
+    }
+
+    #[test]
+    fn test_check_plan() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("team.billing.info").returns(r#"{"ok": true, "plan": ""}"#).times(1));
+        let slack = SlackClient::new(mock, "xoxb-1");
+
+        assert!(slack.check_plan("chat.postMessage").is_ok());
+        match slack.check_plan("usergroups.create") {
+            Err(PlanError::NotIncluded { plan: Plan::Free, required: Plan::Pro, .. }) => {}
+            other => panic!("expected user groups to need a paid plan, got {:?}", other),
+        }
+        slack.sender().verify();
+    }
+
+    #[test]
+    fn test_namespaced_methods() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").returns(r#"{"ok": true, "channels": [{"id": "C1"}]}"#))
+            .expect(Expectation::new("users.info").returns(r#"{"ok": true, "user": {"id": "U1"}}"#));
+        let slack = SlackClient::new(mock, "xoxb-1");
+
+        assert_eq!(1, slack.conversations().list(&Default::default()).unwrap().channels.unwrap().len());
+        let request = ::users::InfoRequest { user: "U1" };
+        slack.with_token("xoxp-2").users().info(&request).unwrap();
+        let calls = slack.sender().calls();
+        assert_eq!(vec![Some("xoxb-1"), Some("xoxp-2")],
+                   calls.iter().map(|call| call.param("token")).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_token_set_routing() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").returns(r#"{"ok": true, "channels": []}"#))
+            .expect(Expectation::new("search.all").returns(r#"{"ok": true}"#))
+            .expect(Expectation::new("admin.users.list").returns(r#"{"ok": true, "users": []}"#))
+            .expect(Expectation::new("apps.connections.open").returns(r#"{"ok": true, "url": "wss://x"}"#));
+        let tokens = TokenSet {
+            bot: Some("xoxb-1".into()),
+            user: Some("xoxp-1".into()),
+            app: Some("xapp-1".into()),
+        };
+        let slack = SlackClient::from_token_set(mock, tokens);
+        assert_eq!("xoxb-1", slack.token());
+
+        slack.conversations().list(&Default::default()).unwrap();
+        slack.search().all(&::search::AllRequest::new("deploy")).unwrap();
+        slack.admin_users().list(&::admin_users::ListRequest::new("T1")).unwrap();
+        slack.apps_connections().open().unwrap();
+        let calls = slack.sender().calls();
+        assert_eq!(vec![Some("xoxb-1"), Some("xoxp-1"), Some("xoxp-1"), Some("xapp-1")],
+                   calls.iter().map(|call| call.param("token")).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_team_clients() {
+        use std::collections::HashMap;
+        use tokens::MemoryTokenStoreError;
+
+        struct Tokens(HashMap<&'static str, &'static str>);
+
+        impl<R: SlackWebRequestSender> TokenProvider<R> for Tokens {
+            type Error = MemoryTokenStoreError;
+
+            fn token(&self, _: &R, _: Option<&str>, team_id: &str) -> Result<Option<String>, MemoryTokenStoreError> {
+                Ok(self.0.get(team_id).map(|token| token.to_string()))
+            }
+        }
+
+        assert_send_sync::<TeamClients<MockSender, ::tokens::MemoryTokenStore>>();
+
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").returns(r#"{"ok": true, "channels": []}"#));
+        let tokens = Tokens(vec![("T1", "xoxb-1"), ("T2", "xoxb-2")].into_iter().collect());
+        let slack = TeamClients::new(mock, tokens);
+
+        slack.team(None, "T2").unwrap().conversations().list(&Default::default()).unwrap();
+        slack.team(None, "T1").unwrap().conversations().list(&Default::default()).unwrap();
+        slack.with_token("xoxp-3").conversations().list(&Default::default()).unwrap();
+        match slack.team(None, "T3") {
+            Err(TeamTokenError::NotInstalled(team_id)) => assert_eq!("T3", team_id),
+            other => panic!("expected T3 not to be installed, got {:?}", other),
+        }
+        let calls = slack.sender().calls();
+        assert_eq!(vec![Some("xoxb-2"), Some("xoxb-1"), Some("xoxp-3")],
+                   calls.iter().map(|call| call.param("token")).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_team_clients_org_wide_install() {
+        use install::{Installation, InstallationEnterprise};
+        use tokens::{MemoryTokenStore, TokenStore};
+
+        let store = MemoryTokenStore::new();
+        store.save(&Installation {
+                app_id: None,
+                team: None,
+                enterprise: Some(InstallationEnterprise { id: "E1".to_owned(), name: None }),
+                is_enterprise_install: true,
+                bot_token: Some("xoxb-org".into()),
+                bot_user_id: None,
+                bot_refresh_token: None,
+                bot_token_expires_at: None,
+                scopes: vec![],
+                user_id: None,
+                user_token: None,
+                user_refresh_token: None,
+                user_token_expires_at: None,
+                user_scopes: vec![],
+                incoming_webhook: None,
+            })
+            .unwrap();
+
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").returns(r#"{"ok": true, "channels": []}"#));
+        let slack = TeamClients::new(mock, store);
+
+        slack.team(Some("E1"), "T1").unwrap().conversations().list(&Default::default()).unwrap();
+        assert_eq!(Some("xoxb-org"), slack.sender().calls()[0].param("token"));
+        match slack.team(None, "T1") {
+            Err(TeamTokenError::NotInstalled(team_id)) => assert_eq!("T1", team_id),
+            other => panic!("expected T1 not to be found without its enterprise, got {:?}", other),
+        }
+    }
+}
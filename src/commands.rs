@@ -0,0 +1,178 @@
+//! Replying to slash commands.
+
+use std::error::Error;
+use std::fmt;
+
+use serde_json::{self, Value};
+
+use requests::SlackJsonSender;
+
+/// Who sees a reply to a slash command.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum ResponseType {
+    /// Only the user who ran the command.
+    #[serde(rename = "ephemeral")]
+    Ephemeral,
+    /// Everyone in the channel the command was run in.
+    #[serde(rename = "in_channel")]
+    InChannel,
+}
+
+/// A reply to a slash command.
+///
+/// Either return its [`to_json`](#method.to_json) as the body of the HTTP response to the
+/// command, or send it later with [`respond`].
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::commands::CommandResponse;
+/// let response = CommandResponse::in_channel("Deploying *main* to production").replace_original(true);
+/// assert_eq!(r#"{"replace_original":true,"response_type":"in_channel","text":"Deploying *main* to production"}"#,
+///            response.to_json());
+/// ```
+#[derive(Clone, Debug, Serialize)]
+pub struct CommandResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blocks: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_original: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replace_original: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_type: Option<ResponseType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl CommandResponse {
+    /// A reply only the user who ran the command sees.
+    pub fn ephemeral<S: Into<String>>(text: S) -> Self {
+        CommandResponse::new(ResponseType::Ephemeral, text)
+    }
+
+    /// A reply everyone in the channel sees.
+    pub fn in_channel<S: Into<String>>(text: S) -> Self {
+        CommandResponse::new(ResponseType::InChannel, text)
+    }
+
+    fn new<S: Into<String>>(response_type: ResponseType, text: S) -> Self {
+        CommandResponse {
+            blocks: None,
+            delete_original: None,
+            replace_original: None,
+            response_type: Some(response_type),
+            text: Some(text.into()),
+        }
+    }
+
+    /// Deletes the message that an earlier reply posted, through the command's `response_url`.
+    pub fn delete_original() -> Self {
+        CommandResponse {
+            blocks: None,
+            delete_original: Some(true),
+            replace_original: None,
+            response_type: None,
+            text: None,
+        }
+    }
+
+    /// Lays the reply out with these blocks, a JSON array. The text becomes the fallback shown in
+    /// notifications.
+    pub fn blocks(mut self, blocks: Value) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    /// Whether the reply replaces the message an earlier reply posted, when sent through the
+    /// command's `response_url`.
+    pub fn replace_original(mut self, replace_original: bool) -> Self {
+        self.replace_original = Some(replace_original);
+        self
+    }
+
+    /// Who sees the reply.
+    pub fn response_type(&self) -> Option<ResponseType> {
+        self.response_type
+    }
+
+    /// The reply as Slack expects it, e.g. as the body of the HTTP response to the command.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("command responses always serialize")
+    }
+}
+
+/// Sends `response` to a command's `response_url`, which takes up to five replies within half an
+/// hour of the command.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::commands::{self, CommandResponse};
+/// # let response_url = "https://hooks.slack.com/commands/T1/1/abc";
+/// let client = slack_api::requests::default_client().unwrap();
+/// commands::respond(&client, response_url, &CommandResponse::ephemeral("Done!")).unwrap();
+/// ```
+pub fn respond<R>(client: &R, response_url: &str, response: &CommandResponse) -> Result<(), RespondError<R::Error>>
+    where R: SlackJsonSender
+{
+    let result = client.post_json(response_url, &response.to_json()).map_err(RespondError::Client)?;
+    match result.status {
+        Some(status) if status >= 300 => Err(RespondError::Rejected(status, result.body)),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug)]
+pub enum RespondError<E: Error> {
+    /// The response URL answered with this HTTP status and body, e.g. `404` and `expired_url`.
+    Rejected(u16, String),
+    /// The response could not be sent.
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for RespondError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &RespondError::Rejected(status, ref body) => write!(f, "{}: HTTP {} {}", self.description(), status, body),
+            &RespondError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error> Error for RespondError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RespondError::Rejected(..) => "the response was rejected",
+            &RespondError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RespondError::Rejected(..) => None,
+            &RespondError::Client(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_respond() {
+        let url = "https://hooks.slack.com/commands/T1/1/abc";
+        let mock = MockSender::new();
+        mock.expect(Expectation::new(url).times(1));
+        let blocks = serde_json::from_str(r#"[{"type": "divider"}]"#).unwrap();
+
+        respond(&mock, url, &CommandResponse::ephemeral("hi").blocks(blocks)).unwrap();
+        assert_eq!(Some(r#"{"blocks":[{"type":"divider"}],"response_type":"ephemeral","text":"hi"}"#),
+                   mock.calls()[0].param("body"));
+        assert_eq!(r#"{"delete_original":true}"#, CommandResponse::delete_original().to_json());
+        mock.verify();
+    }
+}
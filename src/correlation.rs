@@ -0,0 +1,127 @@
+//! Tying Slack calls back to the operation that made them.
+//!
+//! Calls made inside [`scope`] carry its correlation ID: it is added to the [`Traced`] spans,
+//! the [`Logged`] log lines and the [`CallMetrics`] of those calls, and to the errors of a
+//! [`Correlated`] sender.
+//!
+//! [`Traced`]: ../requests/struct.Traced.html
+//! [`Logged`]: ../logging/struct.Logged.html
+//! [`CallMetrics`]: ../metrics/struct.CallMetrics.html
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+
+use requests::{SlackWebRequestSender, SlackWebResponse};
+
+thread_local!(static CURRENT: RefCell<Option<String>> = RefCell::new(None));
+
+/// Runs `f` with `id` as the correlation ID of the calls it makes on this thread.
+///
+/// Scopes nest: the previous ID is restored when `f` returns.
+///
+/// # Examples
+///
+/// ```no_run
+/// # let order_id = "order-1234";
+/// let client = slack_api::requests::default_client().unwrap();
+/// let request = slack_api::chat::PostMessageRequest { channel: "#orders", text: "Shipped!", ..Default::default() };
+///
+/// slack_api::correlation::scope(order_id, || slack_api::chat::post_message(&client, "xoxb-...", &request));
+/// ```
+pub fn scope<S: Into<String>, F: FnOnce() -> T, T>(id: S, f: F) -> T {
+    struct Restore(Option<String>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let _restore = Restore(CURRENT.with(|current| current.borrow_mut().replace(id.into())));
+    f()
+}
+
+/// The correlation ID of the innermost [`scope`] on this thread, if any.
+pub fn current() -> Option<String> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Wraps a request sender, attaching the current correlation ID to its errors.
+#[derive(Clone, Debug)]
+pub struct Correlated<R>(pub R);
+
+impl<R: SlackWebRequestSender> SlackWebRequestSender for Correlated<R> {
+    type Error = CorrelatedError<R::Error>;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.0.send(method_url, params).map_err(CorrelatedError::new)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.0.send_raw(method_url, params).map_err(CorrelatedError::new)
+    }
+
+    fn send_bytes(&self, method_url: &str, params: &[(&str, &str)]) -> Result<Vec<u8>, Self::Error> {
+        self.0.send_bytes(method_url, params).map_err(CorrelatedError::new)
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.0.send_json(method_url, token, body).map_err(CorrelatedError::new)
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.0.send_form(method_url, params).map_err(CorrelatedError::new)
+    }
+}
+
+/// A sender error, with the correlation ID of the call that caused it.
+#[derive(Debug)]
+pub struct CorrelatedError<E> {
+    pub correlation_id: Option<String>,
+    pub error: E,
+}
+
+impl<E> CorrelatedError<E> {
+    fn new(error: E) -> Self {
+        CorrelatedError {
+            correlation_id: current(),
+            error: error,
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for CorrelatedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.correlation_id {
+            Some(ref id) => write!(f, "{} (correlation ID {})", self.error, id),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+impl<E: Error> Error for CorrelatedError<E> {
+    fn description(&self) -> &str {
+        self.error.description()
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scopes_nest() {
+        assert_eq!(None, current());
+        scope("outer", || {
+            scope("inner", || assert_eq!(Some("inner".to_owned()), current()));
+            assert_eq!(Some("outer".to_owned()), current());
+        });
+        assert_eq!(None, current());
+    }
+}
@@ -0,0 +1,130 @@
+//! Following the cursors of the methods that page with `cursor` and
+//! `response_metadata.next_cursor`, or a top-level `next_cursor` for a few like
+//! `admin.conversations.search`.
+//!
+//! Each of those methods has an `_all` version, like [`conversations::list_all`], which returns
+//! the [`Items`] of every page, fetching the next page when the last one runs out.
+//!
+//! [`conversations::list_all`]: ../conversations/fn.list_all.html
+
+use std::vec;
+
+/// Fetches the page after `cursor`, or the first one for `None`, returning its items and the
+/// cursor of the next page.
+type Fetch<'a, T, E> = Box<FnMut(Option<&str>) -> Result<(Vec<T>, Option<String>), E> + 'a>;
+
+/// The items of every page of a method's results, fetched one page at a time.
+///
+/// The items end after the first error, or on the page without a next cursor.
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+/// let request = slack_api::conversations::ListRequest { limit: Some(200), ..Default::default() };
+///
+/// for channel in slack_api::conversations::list_all(&client, "xoxb-...", &request) {
+///     println!("{:?}", channel.unwrap().name);
+/// }
+/// ```
+pub struct Items<'a, T, E> {
+    fetch: Fetch<'a, T, E>,
+    cursor: Option<String>,
+    items: vec::IntoIter<T>,
+    done: bool,
+}
+
+impl<'a, T, E> Items<'a, T, E> {
+    /// The items of the pages `fetch` returns. `fetch` is called with `None` for the first page,
+    /// and then with the cursor it returned, until it returns none.
+    pub fn new<F>(fetch: F) -> Self
+        where F: FnMut(Option<&str>) -> Result<(Vec<T>, Option<String>), E> + 'a
+    {
+        Items {
+            fetch: Box::new(fetch),
+            cursor: None,
+            items: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+}
+
+impl<'a, T, E> Iterator for Items<'a, T, E> {
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+            if self.done {
+                return None;
+            }
+            match (self.fetch)(self.cursor.as_ref().map(String::as_str)) {
+                Ok((items, cursor)) => {
+                    self.items = items.into_iter();
+                    self.cursor = cursor.filter(|c| !c.is_empty());
+                    self.done = self.cursor.is_none();
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use admin_conversations;
+    use conversations;
+    use testing::{Expectation, MockSender};
+
+    #[test]
+    fn test_list_all() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").param("cursor", "page2")
+                .returns(r#"{"ok": true, "channels": [{"id": "C3"}], "response_metadata": {"next_cursor": ""}}"#))
+            .expect(Expectation::new("conversations.list").param("cursor", "start")
+                .returns(r#"{"ok": true, "channels": [{"id": "C1"}, {"id": "C2"}],
+                    "response_metadata": {"next_cursor": "page2"}}"#));
+        let request = conversations::ListRequest {
+            cursor: Some("start"),
+            limit: Some(2),
+            ..Default::default()
+        };
+
+        let ids: Vec<_> = conversations::list_all(&mock, "xoxb-1", &request).map(|c| c.unwrap().id.unwrap()).collect();
+        assert_eq!(vec!["C1", "C2", "C3"], ids);
+        assert_eq!(vec![Some("2"), Some("2")],
+                   mock.calls().iter().map(|c| c.param("limit")).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_search_all_follows_top_level_cursor() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("admin.conversations.search").param("cursor", "page2")
+                .returns(r#"{"ok": true, "conversations": [{"id": "C3"}], "next_cursor": ""}"#))
+            .expect(Expectation::new("admin.conversations.search")
+                .returns(r#"{"ok": true, "conversations": [{"id": "C1"}, {"id": "C2"}], "next_cursor": "page2"}"#));
+        let request = admin_conversations::SearchRequest::new().query("deploys");
+
+        let ids: Vec<_> = admin_conversations::search_all(&mock, "xoxp-1", &request)
+            .map(|c| c.unwrap().id.unwrap())
+            .collect();
+        assert_eq!(vec!["C1", "C2", "C3"], ids);
+        assert_eq!(2, mock.calls().len());
+    }
+
+    #[test]
+    fn test_items_end_after_error() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.members").returns_error("channel_not_found"));
+        let request = conversations::MembersRequest { channel: "C1", ..Default::default() };
+
+        let mut members = conversations::members_all(&mock, "xoxb-1", &request);
+        assert!(members.next().unwrap().is_err());
+        assert!(members.next().is_none());
+    }
+}
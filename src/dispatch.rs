@@ -0,0 +1,501 @@
+//! Routing Events API payloads to handlers registered by event type.
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::sync::Mutex;
+
+use serde_json::{self, Value};
+
+use events::{self, verify_slack_signature, AppMention, EventCallback, ReactionEvent};
+use Message;
+
+/// How many event IDs a [`Dispatcher`] remembers to recognize redeliveries.
+const SEEN_EVENTS: usize = 1000;
+
+/// How old a request's timestamp may be, in seconds, before it is taken for a replay.
+const MAX_REQUEST_AGE: u64 = 5 * 60;
+
+/// An event as passed to the handlers of a [`Dispatcher`].
+#[derive(Clone, Copy, Debug)]
+pub struct Event<'a> {
+    payload: &'a Value,
+    raw: &'a Value,
+    event: &'a events::Event,
+    callback: Option<&'a EventCallback>,
+}
+
+impl<'a> Event<'a> {
+    /// The inner event, deserialized. Events that did not deserialize as their type, and
+    /// payloads without an inner event, like `app_rate_limited`, are `Other`.
+    pub fn event(&self) -> &'a events::Event {
+        self.event
+    }
+
+    /// The `event_callback` envelope, with the team, the app and the authorizations the event
+    /// was delivered for. `None` for payloads of other types.
+    pub fn callback(&self) -> Option<&'a EventCallback> {
+        self.callback
+    }
+
+    /// The inner event as it was sent, like `{"type": "app_mention", ...}`. For payloads without
+    /// one, this is the payload itself.
+    pub fn raw(&self) -> &'a Value {
+        self.raw
+    }
+
+    /// The whole payload as it was sent.
+    pub fn payload(&self) -> &'a Value {
+        self.payload
+    }
+
+    pub fn ty(&self) -> &'a str {
+        self.field("type").unwrap_or("")
+    }
+
+    pub fn subtype(&self) -> Option<&'a str> {
+        self.field("subtype")
+    }
+
+    pub fn channel(&self) -> Option<&'a str> {
+        self.field("channel")
+    }
+
+    pub fn user(&self) -> Option<&'a str> {
+        self.field("user")
+    }
+
+    pub fn text(&self) -> Option<&'a str> {
+        self.field("text")
+    }
+
+    pub fn ts(&self) -> Option<&'a str> {
+        self.field("ts")
+    }
+
+    fn field(&self, name: &str) -> Option<&'a str> {
+        self.raw.get(name).and_then(Value::as_str)
+    }
+}
+
+/// A handler, returning whether it handled the event. The typed handlers skip the events that
+/// did not deserialize as their type.
+type Handler = Box<Fn(&Event) -> bool + Send + Sync>;
+
+/// Which events a handler is called for.
+enum Filter {
+    Type(String),
+    /// Messages with this subtype, or without one for `None`.
+    Message(Option<String>),
+    /// Events no other handler was called for.
+    Fallback,
+}
+
+impl Filter {
+    fn matches(&self, event: &Event) -> bool {
+        match self {
+            &Filter::Type(ref ty) => event.ty() == ty,
+            &Filter::Message(ref subtype) => {
+                event.ty() == "message" && event.subtype() == subtype.as_ref().map(String::as_str)
+            }
+            &Filter::Fallback => false,
+        }
+    }
+}
+
+/// What a [`Dispatcher`] did with a payload.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    /// The payload was a `url_verification`, to be answered with this challenge.
+    Challenge(String),
+    /// The event was passed to this many handlers, which may be none.
+    Handled(usize),
+    /// The event had already been handled. Slack delivers events again when it does not get
+    /// an answer in time.
+    Duplicate,
+}
+
+/// The HTTP response to send Slack for a request, as returned by
+/// [`Dispatcher::handle_request`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ack {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Ack {
+    fn new<S: Into<String>>(status: u16, body: S) -> Self {
+        Ack {
+            status: status,
+            body: body.into(),
+        }
+    }
+}
+
+/// Calls the handlers registered for the type of each event it is given.
+///
+/// Events come in through [`handle_request`](#method.handle_request), with the headers and body
+/// of the requests Slack sends the app's Request URL, or through [`dispatch`](#method.dispatch)
+/// with payloads received some other way. Either way, each event is only handled once: Slack
+/// delivers an event again when its request is not answered in time, and those repeats are
+/// recognized by their event ID.
+///
+/// Handlers are plain functions, called in turn on the thread that dispatches the event; there
+/// are no asynchronous ones. They run before the request is answered, and Slack expects an answer
+/// within three seconds, so a handler with slow work to do should hand it off to another thread.
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::dispatch::Dispatcher;
+/// let dispatcher = Dispatcher::new()
+///     .signing_secret("8f742231b10e8888abcd99yyyzzz85a5")
+///     .on_app_mention(|mention, _| println!("mentioned by {:?}: {:?}", mention.user, mention.text))
+///     .on_message(|_, event| println!("message in {:?}", event.channel()))
+///     .on_message_subtype("message_changed", |_, event| println!("edited in {:?}", event.channel()))
+///     .on_reaction_added(|reaction, event| {
+///         let team = event.callback().and_then(|callback| callback.team_id.as_ref());
+///         println!("reaction in {:?}: {:?}", team, reaction.reaction)
+///     });
+///
+/// // In the HTTP handler for the Request URL:
+/// # let (timestamp, signature, body) = (None, None, &b""[..]);
+/// let ack = dispatcher.handle_request(timestamp, signature, body);
+/// // respond with ack.status and ack.body
+/// ```
+pub struct Dispatcher {
+    handlers: Vec<(Filter, Handler)>,
+    signing_secret: Option<Vec<u8>>,
+    seen: Mutex<Seen>,
+}
+
+#[derive(Default)]
+struct Seen {
+    ids: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl Seen {
+    /// Remembers `id`, returning whether it was new.
+    fn insert(&mut self, id: &str) -> bool {
+        if !self.ids.insert(id.to_owned()) {
+            return false;
+        }
+        self.order.push_back(id.to_owned());
+        if self.order.len() > SEEN_EVENTS {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+impl Default for Dispatcher {
+    fn default() -> Self {
+        Dispatcher {
+            handlers: Vec::new(),
+            signing_secret: None,
+            seen: Mutex::new(Seen::default()),
+        }
+    }
+}
+
+impl Dispatcher {
+    pub fn new() -> Self {
+        Dispatcher::default()
+    }
+
+    /// The app's signing secret, from its Basic Information page, which
+    /// [`handle_request`](#method.handle_request) checks requests were signed with.
+    pub fn signing_secret<S: AsRef<[u8]>>(mut self, secret: S) -> Self {
+        self.signing_secret = Some(secret.as_ref().to_vec());
+        self
+    }
+
+    /// Calls `handler` for every event of type `event_type`, like `"team_join"`, or
+    /// `"message"` for messages of every subtype.
+    pub fn on<S, F>(self, event_type: S, handler: F) -> Self
+        where S: Into<String>,
+              F: Fn(&Event) + Send + Sync + 'static
+    {
+        self.handle(Filter::Type(event_type.into()), move |event| {
+            handler(event);
+            true
+        })
+    }
+
+    pub fn on_app_mention<F>(self, handler: F) -> Self
+        where F: Fn(&AppMention, &Event) + Send + Sync + 'static
+    {
+        self.handle(Filter::Type("app_mention".to_owned()), move |event| match event.event() {
+            &events::Event::AppMention(ref mention) => {
+                handler(mention, event);
+                true
+            }
+            _ => false,
+        })
+    }
+
+    pub fn on_reaction_added<F>(self, handler: F) -> Self
+        where F: Fn(&ReactionEvent, &Event) + Send + Sync + 'static
+    {
+        self.handle(Filter::Type("reaction_added".to_owned()), move |event| match event.event() {
+            &events::Event::ReactionAdded(ref reaction) => {
+                handler(reaction, event);
+                true
+            }
+            _ => false,
+        })
+    }
+
+    /// Calls `handler` for plain messages, the ones without a subtype.
+    pub fn on_message<F>(self, handler: F) -> Self
+        where F: Fn(&Message, &Event) + Send + Sync + 'static
+    {
+        self.push(Filter::Message(None), message_handler(handler))
+    }
+
+    /// Calls `handler` for messages with the subtype `subtype`, like `"message_changed"` or
+    /// `"bot_message"`.
+    pub fn on_message_subtype<S, F>(self, subtype: S, handler: F) -> Self
+        where S: Into<String>,
+              F: Fn(&Message, &Event) + Send + Sync + 'static
+    {
+        self.push(Filter::Message(Some(subtype.into())), message_handler(handler))
+    }
+
+    /// Calls `handler` for the events no other handler is registered for.
+    pub fn fallback<F: Fn(&Event) + Send + Sync + 'static>(self, handler: F) -> Self {
+        self.handle(Filter::Fallback, move |event| {
+            handler(event);
+            true
+        })
+    }
+
+    fn handle<F: Fn(&Event) -> bool + Send + Sync + 'static>(self, filter: Filter, handler: F) -> Self {
+        self.push(filter, Box::new(handler))
+    }
+
+    fn push(mut self, filter: Filter, handler: Handler) -> Self {
+        self.handlers.push((filter, handler));
+        self
+    }
+
+    /// Handles a request Slack sent the Request URL, given its `X-Slack-Request-Timestamp` and
+    /// `X-Slack-Signature` headers and its body, and returns the response to send back.
+    ///
+    /// Requests that are not signed with the [signing secret](#method.signing_secret), or are
+    /// more than five minutes old, are answered with a 401 without being handled. So is every
+    /// request until a signing secret is set. Bodies that are not JSON get a 400.
+    pub fn handle_request(&self, timestamp: Option<&str>, signature: Option<&str>, body: &[u8]) -> Ack {
+        let verified = match (&self.signing_secret, timestamp, signature) {
+            (&Some(ref secret), Some(timestamp), Some(signature)) => {
+                is_fresh(timestamp) && verify_slack_signature(secret, timestamp, body, signature)
+            }
+            _ => false,
+        };
+        if !verified {
+            return Ack::new(401, "invalid request signature");
+        }
+        match serde_json::from_slice::<Value>(body) {
+            Ok(payload) => {
+                match self.dispatch(&payload) {
+                    Outcome::Challenge(challenge) => Ack::new(200, challenge),
+                    _ => Ack::new(200, ""),
+                }
+            }
+            Err(_) => Ack::new(400, "malformed payload"),
+        }
+    }
+
+    /// Handles a payload, like the body of an Events API request or the payload of a Socket Mode
+    /// envelope, without checking where it came from.
+    pub fn dispatch(&self, payload: &Value) -> Outcome {
+        if payload.get("type").and_then(Value::as_str) == Some("url_verification") {
+            let challenge = payload.get("challenge").and_then(Value::as_str).unwrap_or("");
+            return Outcome::Challenge(challenge.to_owned());
+        }
+        if let Some(id) = payload.get("event_id").and_then(Value::as_str) {
+            if !self.seen.lock().unwrap().insert(id) {
+                return Outcome::Duplicate;
+            }
+        }
+
+        let raw = payload.get("event").unwrap_or(payload);
+        let callback = match payload.get("type").and_then(Value::as_str) {
+            Some("event_callback") => serde_json::from_value::<EventCallback>(payload.clone()).ok(),
+            _ => None,
+        };
+        let other;
+        let typed = match callback {
+            Some(ref callback) => &callback.event,
+            None => {
+                other = events::Event::Other(raw.clone());
+                &other
+            }
+        };
+        let event = Event {
+            payload: payload,
+            raw: raw,
+            event: typed,
+            callback: callback.as_ref(),
+        };
+        let mut handled = 0;
+        for &(ref filter, ref handler) in &self.handlers {
+            if filter.matches(&event) && handler(&event) {
+                handled += 1;
+            }
+        }
+        if handled == 0 {
+            for &(ref filter, ref handler) in &self.handlers {
+                if let &Filter::Fallback = filter {
+                    handler(&event);
+                    handled += 1;
+                }
+            }
+        }
+        Outcome::Handled(handled)
+    }
+}
+
+impl fmt::Debug for Dispatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dispatcher").field("handlers", &self.handlers.len()).finish()
+    }
+}
+
+fn message_handler<F>(handler: F) -> Handler
+    where F: Fn(&Message, &Event) + Send + Sync + 'static
+{
+    Box::new(move |event| match event.event() {
+        &events::Event::Message(ref message) => {
+            handler(message, event);
+            true
+        }
+        _ => false,
+    })
+}
+
+fn is_fresh(timestamp: &str) -> bool {
+    match timestamp.trim().parse::<u64>() {
+        Ok(timestamp) => {
+            let now = ::unix_timestamp();
+            timestamp.max(now) - timestamp.min(now) <= MAX_REQUEST_AGE
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    use testing::fixtures;
+
+    use super::*;
+
+    fn sign(secret: &[u8], timestamp: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret).unwrap();
+        mac.update(format!("v0:{}:", timestamp).as_bytes());
+        mac.update(body);
+        let bytes = mac.finalize().into_bytes();
+        format!("v0={}", bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>())
+    }
+
+    #[test]
+    fn test_handlers_by_type() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let record = |name: &'static str| {
+            let calls = calls.clone();
+            move |event: &Event| calls.lock().unwrap().push(format!("{} {}", name, event.ty()))
+        };
+        let (mention, reaction, message, edit) = (record("mention"), record("reaction"), record("message"), record("edit"));
+        let dispatcher = Dispatcher::new()
+            .on_app_mention(move |_, event| mention(event))
+            .on_reaction_added(move |_, event| reaction(event))
+            .on_message(move |_, event| message(event))
+            .on_message_subtype("message_changed", move |_, event| edit(event))
+            .on("message", record("any message"))
+            .fallback(record("fallback"));
+
+        for payload in fixtures::events() {
+            dispatcher.dispatch(&payload);
+        }
+        let calls = calls.lock().unwrap();
+        let count = |call: &str| calls.iter().filter(|c| *c == call).count();
+        assert_eq!(1, count("mention app_mention"));
+        assert_eq!(1, count("reaction reaction_added"));
+        assert_eq!(1, count("edit message"));
+        assert_eq!(fixtures::events_of_type("message").len(), count("any message message"));
+        assert!(count("message message") > 0);
+        assert_eq!(0, count("fallback message"));
+        assert_eq!(1, count("fallback team_join"));
+    }
+
+    #[test]
+    fn test_typed_events() {
+        let mentions = Arc::new(Mutex::new(Vec::new()));
+        let seen = mentions.clone();
+        let edits = Arc::new(Mutex::new(0));
+        let counter = edits.clone();
+        let dispatcher = Dispatcher::new()
+            .on_app_mention(move |mention, event| {
+                let team = event.callback().and_then(|callback| callback.team_id.clone());
+                seen.lock().unwrap().push((mention.text.clone(), team));
+            })
+            .on_message_subtype("message_changed", move |message, _| match message {
+                &Message::MessageChanged(_) => *counter.lock().unwrap() += 1,
+                other => panic!("expected an edit, got {:?}", other),
+            });
+
+        for payload in fixtures::events() {
+            dispatcher.dispatch(&payload);
+        }
+        let mentions = mentions.lock().unwrap();
+        assert_eq!(1, mentions.len());
+        assert_eq!(Some("<@U0BOTPAGER> status"), mentions[0].0.as_ref().map(String::as_str));
+        assert!(mentions[0].1.is_some());
+        assert_eq!(1, *edits.lock().unwrap());
+
+        // An event that does not deserialize as its type is left to the fallback.
+        let malformed = serde_json::from_str::<Value>(r#"{"type": "event_callback", "event_id": "Ev9",
+            "event": {"type": "app_mention", "text": 42}}"#).unwrap();
+        let dispatcher = Dispatcher::new().on_app_mention(|_, _| panic!("malformed mention handled")).fallback(|event| {
+            assert!(event.callback().is_none());
+            assert_eq!(Some("app_mention"), event.raw().get("type").and_then(Value::as_str));
+        });
+        assert_eq!(Outcome::Handled(1), dispatcher.dispatch(&malformed));
+    }
+
+    #[test]
+    fn test_handle_request() {
+        let handled = Arc::new(Mutex::new(0));
+        let counter = handled.clone();
+        let dispatcher = Dispatcher::new()
+            .signing_secret("secret")
+            .on_app_mention(move |_, _| *counter.lock().unwrap() += 1);
+        let now = ::unix_timestamp().to_string();
+        let events = fixtures::events_of_type("app_mention");
+        let body = serde_json::to_vec(&events[0]).unwrap();
+        let signature = sign(b"secret", &now, &body);
+
+        let ack = dispatcher.handle_request(Some(&now), Some(&signature), &body);
+        assert_eq!(Ack::new(200, ""), ack);
+        assert_eq!(Ack::new(200, ""),
+                   dispatcher.handle_request(Some(&now), Some(&signature), &body));
+        assert_eq!(1, *handled.lock().unwrap());
+
+        assert_eq!(401, dispatcher.handle_request(Some(&now), Some(&sign(b"other", &now, &body)), &body).status);
+        let old = (::unix_timestamp() - 600).to_string();
+        assert_eq!(401, dispatcher.handle_request(Some(&old), Some(&sign(b"secret", &old, &body)), &body).status);
+        assert_eq!(401, dispatcher.handle_request(Some(&now), None, &body).status);
+        assert_eq!(401, Dispatcher::new().handle_request(Some(&now), Some(&signature), &body).status);
+
+        let body = serde_json::to_vec(&fixtures::events_of_type("url_verification")[0]).unwrap();
+        let ack = dispatcher.handle_request(Some(&now), Some(&sign(b"secret", &now, &body)), &body);
+        assert_eq!(Ack::new(200, "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"), ack);
+    }
+}
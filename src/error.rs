@@ -0,0 +1,100 @@
+//! An error type shared by every API method.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use serde_json;
+
+/// The error of any API method, which every method's own error converts into.
+///
+/// The errors of each method, like `conversations::ListError`, tell apart every error code Slack
+/// documents for it, which is what code calling one method wants. Code that handles the errors
+/// of many methods alike, like reporting or retrying them, can convert them into this instead:
+/// the codes that any method can fail with get variants of their own, and the others are kept
+/// with the name of the method they came from.
+///
+/// `E` is the error type of the request sender, as in `Client(E)` of the method errors.
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::conversations::{self, InfoRequest};
+/// # use slack_api::testing::{Expectation, MockSender};
+/// let mock = MockSender::new();
+/// mock.expect(Expectation::new("conversations.info").returns_error("channel_not_found"));
+///
+/// let error: slack_api::Error<_> = conversations::info(&mock, "xoxb-1", &InfoRequest::new("C1")).unwrap_err().into();
+/// assert_eq!("conversations.info: channel_not_found", error.to_string());
+/// assert_eq!(Some("channel_not_found"), error.code());
+/// ```
+#[derive(Debug)]
+pub enum Error<E: StdError> {
+    /// No token was sent.
+    NotAuthed,
+    /// The token was not valid, or has been revoked.
+    InvalidAuth,
+    /// The call was refused with a `rate_limited` error code. Calls refused with an HTTP 429 are
+    /// errors of the sender, e.g. of a [`Retrying`](retry/struct.Retrying.html) one.
+    RateLimited,
+    /// The response was not parseable as the expected object.
+    MalformedResponse(serde_json::Error),
+    /// Any other error code: the method, like `"chat.postMessage"`, and the code, like
+    /// `"channel_not_found"`.
+    MethodSpecific(&'static str, String),
+    /// The client had an error sending the request to Slack.
+    Client(E),
+}
+
+impl<E: StdError> Error<E> {
+    /// The error for the error code `code`, returned by `method`.
+    pub fn from_code(method: &'static str, code: &str) -> Self {
+        match code {
+            "not_authed" => Error::NotAuthed,
+            "invalid_auth" => Error::InvalidAuth,
+            "rate_limited" | "ratelimited" => Error::RateLimited,
+            _ => Error::MethodSpecific(method, code.to_owned()),
+        }
+    }
+
+    /// The error code Slack returned, if it returned one.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            &Error::NotAuthed => Some("not_authed"),
+            &Error::InvalidAuth => Some("invalid_auth"),
+            &Error::RateLimited => Some("rate_limited"),
+            &Error::MethodSpecific(_, ref code) => Some(code),
+            &Error::MalformedResponse(_) |
+            &Error::Client(_) => None,
+        }
+    }
+}
+
+impl<E: StdError> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Error::MethodSpecific(method, ref code) => write!(f, "{}: {}", method, code),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: StdError> StdError for Error<E> {
+    fn description(&self) -> &str {
+        match self {
+            &Error::NotAuthed => "not_authed: No authentication token provided.",
+            &Error::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &Error::RateLimited => "rate_limited: The call was rate limited.",
+            &Error::MalformedResponse(ref e) => e.description(),
+            &Error::MethodSpecific(_, ref code) => code,
+            &Error::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match self {
+            &Error::MalformedResponse(ref e) => Some(e),
+            &Error::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
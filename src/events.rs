@@ -0,0 +1,348 @@
+//! Types for the payloads the [Events API](https://api.slack.com/apis/connections/events-api)
+//! sends to an app's Request URL, and checking that a request really comes from Slack.
+//!
+//! The [`Dispatcher`](../dispatch/struct.Dispatcher.html) hands its handlers the payloads
+//! deserialized as these types; they are also for apps that would rather match on them.
+//!
+//! # Examples
+//!
+//! ```
+//! # use slack_api::events::{Event, EventPayload};
+//! let body = r#"{"type": "event_callback", "event_id": "Ev1", "team_id": "T1",
+//!                "event": {"type": "reaction_added", "user": "U1", "reaction": "tada",
+//!                          "item": {"type": "message", "channel": "C1", "ts": "1.2"}}}"#;
+//!
+//! match EventPayload::from_slice(body.as_bytes()).unwrap() {
+//!     EventPayload::UrlVerification(verification) => println!("answer with {}", verification.challenge),
+//!     EventPayload::EventCallback(callback) => {
+//!         if let Event::ReactionAdded(reaction) = callback.event {
+//!             assert_eq!(Some("tada".to_owned()), reaction.reaction);
+//!         }
+//!     }
+//!     EventPayload::AppRateLimited(_) => println!("Slack is dropping events"),
+//! }
+//! ```
+
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use serde_json::{self, Value};
+use sha2::Sha256;
+
+/// Whether `signature`, the request's `X-Slack-Signature` header, is the HMAC Slack computes
+/// from the `signing_secret` of the app, the request's `X-Slack-Request-Timestamp` header and its
+/// `body`, following Slack's `v0` scheme.
+///
+/// This does not check the timestamp itself: requests more than a few minutes old should be
+/// rejected too, so that a request that was intercepted cannot be replayed.
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::events::verify_slack_signature;
+/// assert!(!verify_slack_signature("8f742231b10e8888abcd99yyyzzz85a5", "1531420618", b"token=xyzz0WbapA4vBCDEFasx0q6G",
+///                                 "v0=0000000000000000000000000000000000000000000000000000000000000000"));
+/// ```
+pub fn verify_slack_signature<S: AsRef<[u8]>>(signing_secret: S,
+                                              timestamp: &str,
+                                              body: &[u8],
+                                              signature: &str)
+                                              -> bool {
+    let signature = signature.trim();
+    let expected = if signature.starts_with("v0=") { decode_hex(&signature[3..]) } else { None };
+    let mut mac = Hmac::<Sha256>::new_from_slice(signing_secret.as_ref()).expect("HMAC accepts keys of any length");
+    mac.update(b"v0:");
+    mac.update(timestamp.trim().as_bytes());
+    mac.update(b":");
+    mac.update(body);
+    expected.map(|expected| mac.verify_slice(&expected).is_ok()).unwrap_or(false)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()).collect()
+}
+
+/// A payload sent to the Request URL, told apart by its `type`.
+#[derive(Clone, Debug)]
+pub enum EventPayload {
+    /// An event the app subscribed to.
+    EventCallback(EventCallback),
+    /// The challenge Slack sends when the Request URL is set, to be answered with its `challenge`.
+    UrlVerification(UrlVerification),
+    /// Slack is dropping events for the app, which were sent faster than it allows.
+    AppRateLimited(AppRateLimited),
+}
+
+impl EventPayload {
+    /// Parses the body of a request to the Request URL.
+    pub fn from_slice(body: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(body)
+    }
+}
+
+impl Deserialize for EventPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::Deserializer
+    {
+        use serde::de::Error as SerdeError;
+
+        const VARIANTS: &'static [&'static str] = &["event_callback", "url_verification", "app_rate_limited"];
+
+        let value = Value::deserialize(deserializer)?;
+        match value.get("type").and_then(Value::as_str) {
+            Some("event_callback") => from_value(value, EventPayload::EventCallback),
+            Some("url_verification") => from_value(value, EventPayload::UrlVerification),
+            Some("app_rate_limited") => from_value(value, EventPayload::AppRateLimited),
+            Some(ty) => Err(D::Error::unknown_variant(ty, VARIANTS)),
+            None => Err(D::Error::missing_field("type")),
+        }
+    }
+}
+
+/// The envelope of an event, with the workspace and app it was sent for.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventCallback {
+    pub event: Event,
+    /// The ID of the event, the same on every retry of its delivery.
+    pub event_id: Option<String>,
+    pub event_time: Option<i64>,
+    pub team_id: Option<String>,
+    pub api_app_id: Option<String>,
+    /// The installations the event is visible to.
+    pub authorizations: Option<Vec<Authorization>>,
+    pub event_context: Option<String>,
+    pub is_ext_shared_channel: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Authorization {
+    pub enterprise_id: Option<String>,
+    pub team_id: Option<String>,
+    pub user_id: Option<String>,
+    pub is_bot: Option<bool>,
+    pub is_enterprise_install: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UrlVerification {
+    pub challenge: String,
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AppRateLimited {
+    pub team_id: Option<String>,
+    pub api_app_id: Option<String>,
+    /// The minute, as a Unix timestamp, whose events are being dropped.
+    pub minute_rate_limited: Option<i64>,
+}
+
+/// The event inside an [`EventCallback`], told apart by its `type`.
+///
+/// Events of other types are kept as `Other`, so that subscribing to a new event does not break
+/// deserializing the envelope.
+#[derive(Clone, Debug)]
+pub enum Event {
+    Message(::Message),
+    AppMention(AppMention),
+    ReactionAdded(ReactionEvent),
+    ReactionRemoved(ReactionEvent),
+    MemberJoinedChannel(MemberChannelEvent),
+    MemberLeftChannel(MemberChannelEvent),
+    ChannelCreated(ChannelCreated),
+    TeamJoin(UserEvent),
+    UserChange(UserEvent),
+    AppHomeOpened(AppHomeOpened),
+    FileShared(FileShared),
+    AppUninstalled,
+    TokensRevoked(TokensRevoked),
+    Other(Value),
+}
+
+impl Deserialize for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::Deserializer
+    {
+        let value = Value::deserialize(deserializer)?;
+        let ty = value.get("type").and_then(Value::as_str).unwrap_or("").to_owned();
+        match &ty[..] {
+            "message" => from_value(value, Event::Message),
+            "app_mention" => from_value(value, Event::AppMention),
+            "reaction_added" => from_value(value, Event::ReactionAdded),
+            "reaction_removed" => from_value(value, Event::ReactionRemoved),
+            "member_joined_channel" => from_value(value, Event::MemberJoinedChannel),
+            "member_left_channel" => from_value(value, Event::MemberLeftChannel),
+            "channel_created" => from_value(value, Event::ChannelCreated),
+            "team_join" => from_value(value, Event::TeamJoin),
+            "user_change" => from_value(value, Event::UserChange),
+            "app_home_opened" => from_value(value, Event::AppHomeOpened),
+            "file_shared" => from_value(value, Event::FileShared),
+            "app_uninstalled" => Ok(Event::AppUninstalled),
+            "tokens_revoked" => from_value(value, Event::TokensRevoked),
+            _ => Ok(Event::Other(value)),
+        }
+    }
+}
+
+fn from_value<T, U, F, E>(value: Value, variant: F) -> Result<U, E>
+    where T: Deserialize,
+          F: FnOnce(T) -> U,
+          E: ::serde::de::Error
+{
+    serde_json::from_value(value).map(variant).map_err(|e| E::custom(&format!("{}", e)))
+}
+
+/// A message mentioning the app's bot user.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AppMention {
+    pub user: Option<String>,
+    pub text: Option<String>,
+    pub ts: Option<String>,
+    pub channel: Option<String>,
+    pub thread_ts: Option<String>,
+    pub event_ts: Option<String>,
+}
+
+/// A `reaction_added` or `reaction_removed` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReactionEvent {
+    /// Who reacted.
+    pub user: Option<String>,
+    /// The name of the emoji, without colons.
+    pub reaction: Option<String>,
+    /// Who posted the item reacted to.
+    pub item_user: Option<String>,
+    pub item: Option<ReactionEventItem>,
+    pub event_ts: Option<String>,
+}
+
+/// What a [`ReactionEvent`] is about: a message, a file or a file comment.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReactionEventItem {
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub channel: Option<String>,
+    pub ts: Option<String>,
+    pub file: Option<String>,
+    pub file_comment: Option<String>,
+}
+
+/// A `member_joined_channel` or `member_left_channel` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MemberChannelEvent {
+    pub user: Option<String>,
+    pub channel: Option<String>,
+    /// `C` for public channels, `G` for private ones.
+    pub channel_type: Option<String>,
+    pub team: Option<String>,
+    /// Who added the user, if someone did.
+    pub inviter: Option<String>,
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChannelCreated {
+    pub channel: Option<ChannelCreatedChannel>,
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChannelCreatedChannel {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub created: Option<i64>,
+    pub creator: Option<String>,
+}
+
+/// A `team_join` or `user_change` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct UserEvent {
+    pub user: Option<::User>,
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AppHomeOpened {
+    pub user: Option<String>,
+    pub channel: Option<String>,
+    /// `home` or `messages`.
+    pub tab: Option<String>,
+    /// The view currently published on the Home tab, if any.
+    pub view: Option<Value>,
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FileShared {
+    pub file_id: Option<String>,
+    pub user_id: Option<String>,
+    pub channel_id: Option<String>,
+    pub event_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TokensRevoked {
+    pub tokens: Option<TokensRevokedTokens>,
+    pub event_ts: Option<String>,
+}
+
+/// The users whose tokens were revoked, by kind of token.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TokensRevokedTokens {
+    pub oauth: Option<Vec<String>>,
+    pub bot: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::fixtures;
+
+    use super::*;
+
+    #[test]
+    fn test_fixtures_deserialize() {
+        let payloads: Vec<EventPayload> = fixtures::load(fixtures::EVENTS);
+        let mut kinds = Vec::new();
+        for payload in payloads {
+            match payload {
+                EventPayload::UrlVerification(verification) => assert!(!verification.challenge.is_empty()),
+                EventPayload::AppRateLimited(limited) => assert_eq!(Some(1609460400), limited.minute_rate_limited),
+                EventPayload::EventCallback(callback) => {
+                    kinds.push(match callback.event {
+                                   Event::Message(::Message::MessageChanged(_)) => "message_changed",
+                                   Event::Message(_) => "message",
+                                   Event::Other(_) => "other",
+                                   _ => "typed",
+                               })
+                }
+            }
+        }
+        assert!(kinds.len() > 10);
+        assert_eq!(vec!["message", "message_changed"], kinds[..2].to_vec());
+        assert!(!kinds.contains(&"other"));
+
+        let event: Event = serde_json::from_str(r#"{"type": "emoji_changed", "subtype": "add"}"#).unwrap();
+        match event {
+            Event::Other(value) => assert_eq!(Some("add"), value.get("subtype").and_then(Value::as_str)),
+            other => panic!("expected an unknown event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_verify_slack_signature() {
+        // The example from https://api.slack.com/authentication/verifying-requests-from-slack
+        let body = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&\
+                     channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&\
+                     response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&\
+                     trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+        let signature = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+        let secret = "8f742231b10e8888abcd99yyyzzz85a5";
+
+        assert!(verify_slack_signature(secret, "1531420618", body, signature));
+        assert!(!verify_slack_signature(secret, "1531420619", body, signature));
+        assert!(!verify_slack_signature("another secret", "1531420618", body, signature));
+        assert!(!verify_slack_signature(secret, "1531420618", body, "a2114d57b48eac39b9ad189dd8316235"));
+    }
+}
@@ -0,0 +1,292 @@
+//! Exporting a channel's history as newline-delimited JSON, for backups and e-discovery.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+
+use serde_json::{self, Value};
+
+use conversations;
+use requests::{ResponseMetadata, SlackWebRequestSender};
+
+/// Which part of a channel's history [`channel_to_jsonl`] exports.
+#[derive(Clone, Debug)]
+pub struct ExportOptions<'a> {
+    oldest: Option<&'a str>,
+    latest: Option<&'a str>,
+    threads: bool,
+}
+
+impl<'a> Default for ExportOptions<'a> {
+    fn default() -> Self {
+        ExportOptions {
+            oldest: None,
+            latest: None,
+            threads: true,
+        }
+    }
+}
+
+impl<'a> ExportOptions<'a> {
+    pub fn new() -> Self {
+        ExportOptions::default()
+    }
+
+    /// Only messages posted at or after the timestamp `oldest`.
+    pub fn oldest(mut self, oldest: &'a str) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    /// Only messages posted at or before the timestamp `latest`.
+    pub fn latest(mut self, latest: &'a str) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    /// Whether to export the replies in threads too. On by default.
+    pub fn threads(mut self, threads: bool) -> Self {
+        self.threads = threads;
+        self
+    }
+}
+
+/// How much [`channel_to_jsonl`] exported.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExportStats {
+    /// Messages in the channel itself.
+    pub messages: usize,
+    /// Replies in threads.
+    pub replies: usize,
+}
+
+#[derive(Deserialize)]
+struct Page {
+    #[serde(default)]
+    ok: bool,
+    error: Option<String>,
+    #[serde(default)]
+    messages: Vec<Value>,
+    response_metadata: Option<ResponseMetadata>,
+}
+
+/// Writes every message in `channel` to `out`, one JSON object per line.
+///
+/// Each message is written exactly as Slack returned it, so it keeps every field, including its
+/// reactions and the metadata of its files, and has the same shape as the messages in Slack's own
+/// exports. Messages are written as they are fetched, newest first, each followed by the
+/// replies in its thread, oldest first.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::fs::File;
+/// # use std::io::BufWriter;
+/// # use slack_api::export::{self, ExportOptions};
+/// let client = slack_api::requests::default_client().unwrap();
+/// let out = BufWriter::new(File::create("general.jsonl").unwrap());
+///
+/// let stats = export::channel_to_jsonl(&client, "xoxb-...", "C024BE91L", out, &ExportOptions::new()).unwrap();
+/// println!("{} messages, {} replies", stats.messages, stats.replies);
+/// ```
+pub fn channel_to_jsonl<R, W>(client: &R,
+                              token: &str,
+                              channel: &str,
+                              mut out: W,
+                              options: &ExportOptions)
+                              -> Result<ExportStats, ExportError<R::Error>>
+    where R: SlackWebRequestSender,
+          W: Write
+{
+    let mut stats = ExportStats::default();
+    let mut cursor = None;
+    loop {
+        let mut params = vec![("token", token), ("channel", channel), ("limit", "200")];
+        params.extend(options.oldest.map(|oldest| ("oldest", oldest)));
+        params.extend(options.latest.map(|latest| ("latest", latest)));
+        if options.oldest.is_some() || options.latest.is_some() {
+            // Without it, the messages exactly at `oldest` or `latest` are left out.
+            params.push(("inclusive", "1"));
+        }
+        params.extend(cursor.as_ref().map(|cursor: &String| ("cursor", &cursor[..])));
+        let page = fetch(client, "conversations.history", &params).map_err(ExportError::History)?;
+
+        for message in &page.messages {
+            write_line(&mut out, message)?;
+            stats.messages += 1;
+            let thread_ts = message.get("thread_ts").and_then(Value::as_str);
+            let has_replies = message.get("reply_count").and_then(Value::as_u64).unwrap_or(0) > 0;
+            if let (true, true, Some(thread_ts)) = (options.threads, has_replies, thread_ts) {
+                stats.replies += write_thread(client, token, channel, thread_ts, &mut out)?;
+            }
+        }
+
+        cursor = page.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            break;
+        }
+    }
+    out.flush().map_err(ExportError::Io)?;
+    Ok(stats)
+}
+
+/// Writes the replies in the thread `thread_ts`, without its parent, returning how many there
+/// were.
+fn write_thread<R, W>(client: &R,
+                      token: &str,
+                      channel: &str,
+                      thread_ts: &str,
+                      out: &mut W)
+                      -> Result<usize, ExportError<R::Error>>
+    where R: SlackWebRequestSender,
+          W: Write
+{
+    let mut replies = 0;
+    let mut cursor = None;
+    loop {
+        let mut params = vec![("token", token), ("channel", channel), ("ts", thread_ts), ("limit", "200")];
+        params.extend(cursor.as_ref().map(|cursor: &String| ("cursor", &cursor[..])));
+        let page = fetch(client, "conversations.replies", &params).map_err(ExportError::Replies)?;
+
+        for message in &page.messages {
+            if message.get("ts").and_then(Value::as_str) == Some(thread_ts) {
+                continue;
+            }
+            write_line(out, message)?;
+            replies += 1;
+        }
+
+        cursor = page.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            return Ok(replies);
+        }
+    }
+}
+
+/// The error types of the methods `fetch` calls.
+trait PageError<E>: for<'a> From<&'a str> {
+    fn client(err: E) -> Self;
+    fn malformed(err: serde_json::Error) -> Self;
+}
+
+impl<E: Error> PageError<E> for conversations::HistoryError<E> {
+    fn client(err: E) -> Self {
+        conversations::HistoryError::Client(err)
+    }
+
+    fn malformed(err: serde_json::Error) -> Self {
+        conversations::HistoryError::MalformedResponse(err)
+    }
+}
+
+impl<E: Error> PageError<E> for conversations::RepliesError<E> {
+    fn client(err: E) -> Self {
+        conversations::RepliesError::Client(err)
+    }
+
+    fn malformed(err: serde_json::Error) -> Self {
+        conversations::RepliesError::MalformedResponse(err)
+    }
+}
+
+/// Calls `method`, keeping its messages as they are instead of parsing them into
+/// [`Message`](../enum.Message.html)s, which would drop the fields they do not know.
+fn fetch<R, T>(client: &R, method: &str, params: &[(&str, &str)]) -> Result<Page, T>
+    where R: SlackWebRequestSender,
+          T: PageError<R::Error>
+{
+    let body = client.send_bytes(&::get_slack_url_for_method(method), params).map_err(T::client)?;
+    let page = serde_json::from_slice::<Page>(&body).map_err(T::malformed)?;
+    if page.ok {
+        Ok(page)
+    } else {
+        Err(page.error.as_ref().map(String::as_str).unwrap_or("").into())
+    }
+}
+
+fn write_line<W: Write, E: Error>(out: &mut W, message: &Value) -> Result<(), ExportError<E>> {
+    serde_json::to_writer(&mut *out, message)
+        .map_err(|err| ExportError::Io(io::Error::new(io::ErrorKind::Other, err)))?;
+    out.write_all(b"\n").map_err(ExportError::Io)
+}
+
+#[derive(Debug)]
+pub enum ExportError<E: Error> {
+    /// Reading the channel's history failed.
+    History(conversations::HistoryError<E>),
+    /// Reading a thread failed.
+    Replies(conversations::RepliesError<E>),
+    /// Writing the export failed.
+    Io(io::Error),
+}
+
+impl<E: Error> fmt::Display for ExportError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ExportError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ExportError::History(ref inner) => inner.description(),
+            &ExportError::Replies(ref inner) => inner.description(),
+            &ExportError::Io(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ExportError::History(ref inner) => Some(inner),
+            &ExportError::Replies(ref inner) => Some(inner),
+            &ExportError::Io(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_channel_to_jsonl() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.history").returns(r#"{"ok": true, "messages": [
+                {"type": "message", "ts": "2.0", "text": "later", "files": [{"id": "F1", "name": "a.png"}]},
+                {"type": "message", "ts": "1.0", "thread_ts": "1.0", "reply_count": 1, "text": "first",
+                 "reactions": [{"name": "eyes", "count": 1, "users": ["U1"]}]}
+            ], "response_metadata": {"next_cursor": ""}}"#))
+            .expect(Expectation::new("conversations.replies").param("ts", "1.0").returns(r#"{"ok": true, "messages": [
+                {"type": "message", "ts": "1.0", "thread_ts": "1.0", "text": "first"},
+                {"type": "message", "ts": "1.5", "thread_ts": "1.0", "text": "reply"}
+            ]}"#));
+
+        let mut out = Vec::new();
+        let stats = channel_to_jsonl(&mock, "xoxb-1", "C1", &mut out, &ExportOptions::new()).unwrap();
+
+        assert_eq!(ExportStats { messages: 2, replies: 1 }, stats);
+        let lines: Vec<Value> = String::from_utf8(out).unwrap().lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(vec!["2.0", "1.0", "1.5"], lines.iter().map(|l| l["ts"].as_str().unwrap()).collect::<Vec<_>>());
+        assert_eq!("a.png", lines[0]["files"][0]["name"]);
+        assert_eq!("eyes", lines[1]["reactions"][0]["name"]);
+        assert_eq!(None, mock.calls_of("conversations.history")[0].param("inclusive"));
+    }
+
+    #[test]
+    fn test_time_range_is_inclusive() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.history")
+            .param("oldest", "1.0")
+            .param("latest", "2.0")
+            .param("inclusive", "1")
+            .returns(r#"{"ok": true, "messages": [{"type": "message", "ts": "2.0"}, {"type": "message", "ts": "1.0"}]}"#)
+            .times(1));
+
+        let options = ExportOptions::new().oldest("1.0").latest("2.0");
+        let stats = channel_to_jsonl(&mock, "xoxb-1", "C1", Vec::new(), &options).unwrap();
+        assert_eq!(2, stats.messages);
+        mock.verify();
+    }
+}
@@ -0,0 +1,391 @@
+//! Reading a conversation's history: one message at a time, for channels too big to hold in
+//! memory, or a thread or time range at once.
+
+use serde::de::Error as SerdeError;
+use serde_json;
+
+use conversations::{self, HistoryError, HistoryRequest, RepliesError, RepliesRequest};
+use requests::{ResponseMetadata, SlackWebRequestSender};
+use Message;
+
+/// The messages of a conversation, newest first, as returned by [`stream`].
+///
+/// Pages are fetched one at a time, as the messages of the previous one run out, and each
+/// message is parsed only when it is reached. At most one page of raw JSON is held at a time, so
+/// memory stays flat however long the history is.
+pub struct HistoryStream<'a, R: 'a> {
+    client: &'a R,
+    token: &'a str,
+    request: HistoryRequest<'a>,
+    cursor: Option<String>,
+    page: Vec<u8>,
+    messages: Vec<(usize, usize)>,
+    next: usize,
+    fetched: bool,
+    done: bool,
+}
+
+/// The parts of a page besides its messages, which are skipped without being parsed.
+#[derive(Deserialize)]
+struct PageHead {
+    #[serde(default)]
+    ok: bool,
+    error: Option<String>,
+    response_metadata: Option<ResponseMetadata>,
+}
+
+/// Streams the messages in the conversation and time range of `request`, following the cursor
+/// from page to page. `request.cursor` is where to start, and `request.limit` the page size.
+///
+/// The stream ends after the first error.
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+/// let request = slack_api::conversations::HistoryRequest { channel: "C024BE91L", limit: Some(1000), ..Default::default() };
+///
+/// let mut count = 0;
+/// for message in slack_api::history::stream(&client, "xoxb-...", &request) {
+///     message.unwrap();
+///     count += 1;
+/// }
+/// println!("{} messages", count);
+/// ```
+pub fn stream<'a, R>(client: &'a R, token: &'a str, request: &HistoryRequest<'a>) -> HistoryStream<'a, R>
+    where R: SlackWebRequestSender
+{
+    HistoryStream {
+        client: client,
+        token: token,
+        request: request.clone(),
+        cursor: request.cursor.map(str::to_owned),
+        page: Vec::new(),
+        messages: Vec::new(),
+        next: 0,
+        fetched: false,
+        done: false,
+    }
+}
+
+impl<'a, R: SlackWebRequestSender> HistoryStream<'a, R> {
+    /// Fetches the page at `self.cursor`, finding where its messages are.
+    fn fetch(&mut self) -> Result<(), HistoryError<R::Error>> {
+        let limit = self.request.limit.map(::IntParam::new);
+        let mut params = Vec::with_capacity(8);
+        params.push(("token", self.token));
+        params.push(("channel", self.request.channel));
+        if let Some(ref cursor) = self.cursor {
+            params.push(("cursor", cursor));
+        }
+        if let Some(include_all_metadata) = self.request.include_all_metadata {
+            params.push(("include_all_metadata", if include_all_metadata { "1" } else { "0" }));
+        }
+        if let Some(inclusive) = self.request.inclusive {
+            params.push(("inclusive", if inclusive { "1" } else { "0" }));
+        }
+        if let Some(latest) = self.request.latest {
+            params.push(("latest", latest));
+        }
+        if let Some(ref limit) = limit {
+            params.push(("limit", limit.as_str()));
+        }
+        if let Some(oldest) = self.request.oldest {
+            params.push(("oldest", oldest));
+        }
+        let url = ::get_slack_url_for_method("conversations.history");
+        let page = self.client.send_bytes(&url, &params[..]).map_err(HistoryError::Client)?;
+
+        let head = serde_json::from_slice::<PageHead>(&page).map_err(HistoryError::MalformedResponse)?;
+        if !head.ok {
+            return Err(head.error.as_ref().map(String::as_str).unwrap_or("").into());
+        }
+        self.messages = array_elements(&page, "messages")
+            .ok_or_else(|| HistoryError::MalformedResponse(serde_json::Error::custom("unreadable messages")))?;
+        self.next = 0;
+        self.page = page;
+        self.fetched = true;
+        self.cursor = head.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        Ok(())
+    }
+}
+
+impl<'a, R: SlackWebRequestSender> Iterator for HistoryStream<'a, R> {
+    type Item = Result<Message, HistoryError<R::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(&(start, end)) = self.messages.get(self.next) {
+                self.next += 1;
+                let message = serde_json::from_slice(&self.page[start..end]).map_err(HistoryError::MalformedResponse);
+                self.done = message.is_err();
+                return Some(message);
+            }
+            if self.done {
+                return None;
+            }
+            if self.fetched && self.cursor.is_none() {
+                self.done = true;
+                self.page = Vec::new();
+                return None;
+            }
+            if let Err(err) = self.fetch() {
+                self.done = true;
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+/// Every message of the thread started by the message at `thread_ts` in `channel`, following the
+/// cursor from page to page: the parent message first, then the replies in chronological order.
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+///
+/// let thread = slack_api::history::fetch_thread(&client, "xoxb-...", "C024BE91L", "1503435956.000247").unwrap();
+/// println!("{} replies", thread.len() - 1);
+/// ```
+pub fn fetch_thread<R>(client: &R,
+                       token: &str,
+                       channel: &str,
+                       thread_ts: &str)
+                       -> Result<Vec<Message>, RepliesError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = RepliesRequest::new(channel, thread_ts).limit(PAGE_SIZE);
+    conversations::replies_all(client, token, &request).collect()
+}
+
+/// Every message posted to `channel` from `oldest` to `latest`, both timestamps included, in
+/// chronological order. Either bound may be `None`, for the start or the end of the history.
+///
+/// The pages are fetched as [`stream`] does, following the cursor for as long as Slack reports
+/// having more, and all of them are held until the last one arrives, to return the messages
+/// oldest first. Use `stream` for ranges too long for that.
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+///
+/// let day = slack_api::history::history_between(&client, "xoxb-...", "C024BE91L", Some("1503360000"), Some("1503446400"))
+///     .unwrap();
+/// for message in day {
+///     println!("{:?}", message.ts());
+/// }
+/// ```
+pub fn history_between<R>(client: &R,
+                          token: &str,
+                          channel: &str,
+                          oldest: Option<&str>,
+                          latest: Option<&str>)
+                          -> Result<Vec<Message>, HistoryError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut request = HistoryRequest::new(channel).inclusive(true).limit(PAGE_SIZE);
+    request.oldest = oldest;
+    request.latest = latest;
+    let mut messages = stream(client, token, &request).collect::<Result<Vec<_>, _>>()?;
+    messages.reverse();
+    Ok(messages)
+}
+
+/// The page size of the helpers that read a whole thread or range, the largest Slack recommends.
+const PAGE_SIZE: u32 = 200;
+
+/// The byte ranges of the elements of the array at `key` in the JSON object `json`, or `None` if
+/// `json` is not such an object. A missing key has no elements.
+fn array_elements(json: &[u8], key: &str) -> Option<Vec<(usize, usize)>> {
+    let mut pos = skip_whitespace(json, 0);
+    if json.get(pos) != Some(&b'{') {
+        return None;
+    }
+    pos += 1;
+    loop {
+        pos = skip_whitespace(json, pos);
+        match *json.get(pos)? {
+            b'}' => return Some(Vec::new()),
+            b',' => {
+                pos += 1;
+                continue;
+            }
+            _ => {}
+        }
+        let key_start = pos;
+        let key_end = skip_value(json, pos)?;
+        pos = skip_whitespace(json, key_end);
+        if json.get(pos) != Some(&b':') {
+            return None;
+        }
+        pos = skip_whitespace(json, pos + 1);
+        let is_key = key_end - key_start == key.len() + 2 && &json[key_start + 1..key_end - 1] == key.as_bytes();
+        if is_key {
+            return elements(json, pos);
+        }
+        pos = skip_value(json, pos)?;
+    }
+}
+
+fn elements(json: &[u8], mut pos: usize) -> Option<Vec<(usize, usize)>> {
+    if json.get(pos) != Some(&b'[') {
+        return None;
+    }
+    pos += 1;
+    let mut ranges = Vec::new();
+    loop {
+        pos = skip_whitespace(json, pos);
+        match *json.get(pos)? {
+            b']' => return Some(ranges),
+            b',' => {
+                pos += 1;
+                continue;
+            }
+            _ => {}
+        }
+        let end = skip_value(json, pos)?;
+        ranges.push((pos, end));
+        pos = end;
+    }
+}
+
+fn skip_whitespace(json: &[u8], mut pos: usize) -> usize {
+    while json.get(pos).map(|b| b.is_ascii_whitespace()).unwrap_or(false) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Where the value starting at `pos` ends. Values are not validated, only delimited.
+fn skip_value(json: &[u8], mut pos: usize) -> Option<usize> {
+    match *json.get(pos)? {
+        b'"' => skip_string(json, pos),
+        b'{' | b'[' => {
+            let mut depth = 0;
+            loop {
+                match *json.get(pos)? {
+                    b'"' => {
+                        pos = skip_string(json, pos)?;
+                        continue;
+                    }
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(pos + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                pos += 1;
+            }
+        }
+        _ => {
+            while let Some(&b) = json.get(pos) {
+                if b == b',' || b == b'}' || b == b']' || b.is_ascii_whitespace() {
+                    break;
+                }
+                pos += 1;
+            }
+            Some(pos)
+        }
+    }
+}
+
+fn skip_string(json: &[u8], mut pos: usize) -> Option<usize> {
+    pos += 1;
+    loop {
+        match *json.get(pos)? {
+            b'\\' => pos += 2,
+            b'"' => return Some(pos + 1),
+            _ => pos += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_array_elements() {
+        let json = br#"{"ok": true, "pins": [{"a": "]"}], "messages" : [ {"text": "a \"}\" b"}, 1 ,[2, {}] ], "x": {}}"#;
+        let ranges = array_elements(json, "messages").unwrap();
+        let elements: Vec<_> = ranges.iter().map(|&(start, end)| &json[start..end]).collect();
+        assert_eq!(vec![&br#"{"text": "a \"}\" b"}"#[..], b"1", b"[2, {}]"], elements);
+        assert_eq!(Some(vec![]), array_elements(br#"{"ok": true}"#, "messages"));
+        assert_eq!(None, array_elements(br#"{"messages": [{"#, "messages"));
+    }
+
+    #[test]
+    fn test_stream_follows_cursor() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.history").param("cursor", "c2").returns(r#"{"ok": true,
+                "messages": [{"type": "message", "ts": "1.0", "text": "oldest"}]}"#))
+            .expect(Expectation::new("conversations.history").returns(r#"{"ok": true, "messages": [
+                    {"type": "message", "ts": "3.0", "text": "newest"},
+                    {"type": "message", "ts": "2.0", "text": "middle"}
+                ], "has_more": true, "response_metadata": {"next_cursor": "c2"}}"#));
+        let request = HistoryRequest { channel: "C1", ..HistoryRequest::default() };
+
+        let messages = stream(&mock, "xoxb-1", &request).collect::<Result<Vec<_>, _>>().unwrap();
+
+        let ts: Vec<_> = messages.iter().map(|m| m.ts().unwrap()).collect();
+        assert_eq!(vec!["3.0", "2.0", "1.0"], ts);
+        assert_eq!(2, mock.calls().len());
+    }
+
+    #[test]
+    fn test_fetch_thread() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.replies").param("cursor", "c2").returns(r#"{"ok": true,
+                "messages": [{"type": "message", "ts": "3.0", "thread_ts": "1.0", "text": "second reply"}]}"#))
+            .expect(Expectation::new("conversations.replies").param("ts", "1.0").returns(r#"{"ok": true, "messages": [
+                    {"type": "message", "ts": "1.0", "thread_ts": "1.0", "text": "parent"},
+                    {"type": "message", "ts": "2.0", "thread_ts": "1.0", "text": "first reply"}
+                ], "has_more": true, "response_metadata": {"next_cursor": "c2"}}"#));
+
+        let thread = fetch_thread(&mock, "xoxb-1", "C1", "1.0").unwrap();
+
+        let ts: Vec<_> = thread.iter().map(|m| m.ts().unwrap()).collect();
+        assert_eq!(vec!["1.0", "2.0", "3.0"], ts);
+    }
+
+    #[test]
+    fn test_history_between() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.history").param("cursor", "c2").returns(r#"{"ok": true,
+                "messages": [{"type": "message", "ts": "2.0", "text": "oldest"}]}"#))
+            .expect(Expectation::new("conversations.history")
+                .param("oldest", "2.0")
+                .param("latest", "4.0")
+                .param("inclusive", "1")
+                .returns(r#"{"ok": true, "messages": [
+                    {"type": "message", "ts": "4.0", "text": "newest"},
+                    {"type": "message", "ts": "3.0", "text": "middle"}
+                ], "has_more": true, "response_metadata": {"next_cursor": "c2"}}"#));
+
+        let messages = history_between(&mock, "xoxb-1", "C1", Some("2.0"), Some("4.0")).unwrap();
+
+        let ts: Vec<_> = messages.iter().map(|m| m.ts().unwrap()).collect();
+        assert_eq!(vec!["2.0", "3.0", "4.0"], ts);
+    }
+
+    #[test]
+    fn test_stream_ends_after_error() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.history").returns_error("channel_not_found"));
+        let request = HistoryRequest { channel: "C1", ..HistoryRequest::default() };
+
+        let mut messages = stream(&mock, "xoxb-1", &request);
+        match messages.next() {
+            Some(Err(HistoryError::ChannelNotFound)) => {}
+            other => panic!("expected the channel not to be found, got {:?}", other),
+        }
+        assert!(messages.next().is_none());
+    }
+}
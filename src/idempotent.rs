@@ -0,0 +1,413 @@
+//! Posting a message at most once, however often the post is retried.
+//!
+//! `chat.postMessage` cannot be told that a call is a retry, so a call that times out after Slack
+//! posted the message posts it twice when it is retried. Here the caller names each message with
+//! a key, which is stamped into the message's metadata. Before a message is posted again after a
+//! failure that may have posted it, its channel is searched for a message with that key.
+//!
+//! Only posted messages are searched. Scheduled messages are not: the poster never schedules
+//! any, and `chat.scheduledMessages.list` does not return the metadata the key is stamped in.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{self, Map, Value};
+
+use chat;
+use conversations;
+use requests::SlackWebRequestSender;
+use resolve::Resolver;
+use Message;
+
+/// The `event_type` of the metadata stamped on messages that had none of their own.
+pub const EVENT_TYPE: &'static str = "slack_api_idempotency";
+
+/// The field of the metadata's `event_payload` holding the key.
+pub const KEY_FIELD: &'static str = "idempotency_key";
+
+/// How far before the first attempt the channel is searched, in seconds, in case the local clock
+/// is ahead of Slack's.
+const CLOCK_SKEW: u64 = 60;
+
+impl<E: Error> chat::PostMessageError<E> {
+    /// Whether the call may have posted the message despite failing: the request was sent, but
+    /// its response was lost or could not be read.
+    pub fn may_have_posted(&self) -> bool {
+        match self {
+            &chat::PostMessageError::Client(_) |
+            &chat::PostMessageError::MalformedResponse(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the same call could succeed later: Slack could not be reached, or it was
+    /// rate limited or failed on its side, rather than refusing the message.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            &chat::PostMessageError::Client(_) |
+            &chat::PostMessageError::MalformedResponse(_) |
+            &chat::PostMessageError::RateLimited |
+            &chat::PostMessageError::RequestTimeout |
+            &chat::PostMessageError::TeamAddedToOrg => true,
+            &chat::PostMessageError::Unknown(ref code) => {
+                ["internal_error", "fatal_error", "service_unavailable"].contains(&&code[..])
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Adds `key` to the message metadata `metadata`, a JSON object with `event_type` and
+/// `event_payload` fields, or makes metadata holding only the key if there is none.
+pub fn stamp(metadata: Option<&str>, key: &str) -> Result<String, serde_json::Error> {
+    let mut metadata = match metadata {
+        Some(metadata) => serde_json::from_str::<Map<String, Value>>(metadata)?,
+        None => {
+            let mut metadata = Map::new();
+            metadata.insert("event_type".to_owned(), Value::String(EVENT_TYPE.to_owned()));
+            metadata
+        }
+    };
+    if !metadata.get("event_payload").map(Value::is_object).unwrap_or(false) {
+        metadata.insert("event_payload".to_owned(), Value::Object(Map::new()));
+    }
+    if let Some(&mut Value::Object(ref mut payload)) = metadata.get_mut("event_payload") {
+        payload.insert(KEY_FIELD.to_owned(), Value::String(key.to_owned()));
+    }
+    serde_json::to_string(&metadata)
+}
+
+/// The key stamped on `message`, if it has one.
+pub fn key_of(message: &Message) -> Option<&str> {
+    let metadata = match message {
+        &Message::Standard(ref m) => m.metadata.as_ref(),
+        &Message::BotMessage(ref m) => m.metadata.as_ref(),
+        _ => None,
+    };
+    metadata.and_then(|m| m.event_payload.as_ref())
+        .and_then(|payload| payload.get(KEY_FIELD))
+        .and_then(Value::as_str)
+}
+
+/// Looks for the message stamped with `key` among the latest messages of `channel`, or of the
+/// thread `thread_ts`, posted since `oldest` (in seconds since the Unix epoch).
+///
+/// `channel` must be the ID of the conversation, which `conversations.history` needs, not a
+/// channel name or a user ID like `chat.postMessage` takes; [`conversation_id`] finds it. Only
+/// the first page of messages is searched, so `oldest` should be shortly before the message was
+/// first posted.
+pub fn find<R>(client: &R,
+               token: &str,
+               channel: &str,
+               thread_ts: Option<&str>,
+               key: &str,
+               oldest: u64)
+               -> Result<Option<Message>, IdempotentError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let oldest = oldest.saturating_sub(CLOCK_SKEW).to_string();
+    let messages = match thread_ts {
+        Some(thread_ts) => {
+            let request = conversations::RepliesRequest {
+                channel: channel,
+                ts: thread_ts,
+                include_all_metadata: Some(true),
+                oldest: Some(&oldest),
+                ..conversations::RepliesRequest::default()
+            };
+            conversations::replies(client, token, &request).map_err(IdempotentError::Replies)?.messages
+        }
+        None => {
+            let request = conversations::HistoryRequest {
+                channel: channel,
+                include_all_metadata: Some(true),
+                oldest: Some(&oldest),
+                ..conversations::HistoryRequest::default()
+            };
+            conversations::history(client, token, &request).map_err(IdempotentError::History)?.messages
+        }
+    };
+    Ok(messages.unwrap_or_default().into_iter().find(|message| key_of(message) == Some(key)))
+}
+
+/// The ID of the conversation `channel` names, as `chat.postMessage` accepts it: a conversation
+/// ID, a channel name with its leading `#`, or the ID of a user to message directly.
+///
+/// Names are looked up in the workspace's channels and direct messages are opened through
+/// `resolver`, which caches them, so `None` is returned for names that are not found, or direct
+/// messages that cannot be opened.
+pub fn conversation_id<R>(client: &R,
+                          token: &str,
+                          resolver: &Resolver,
+                          channel: &str)
+                          -> Result<Option<String>, IdempotentError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    if channel.starts_with('#') {
+        resolver.channel_id(client, token, channel).map_err(IdempotentError::List)
+    } else if channel.starts_with('U') || channel.starts_with('W') {
+        resolver.dm_channel_id(client, token, channel).map_err(IdempotentError::Open)
+    } else {
+        Ok(Some(channel.to_owned()))
+    }
+}
+
+/// A message posted by [`IdempotentPoster::post`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sent {
+    /// The ID of the conversation the message is in.
+    pub channel: Option<String>,
+    pub ts: Option<String>,
+    /// Whether an earlier attempt had posted the message, which was then found in the channel
+    /// instead of being posted again.
+    pub found: bool,
+}
+
+/// Posts messages with `chat.postMessage`, retrying failures that are likely to pass without
+/// posting any message twice.
+///
+/// The channel names it looks up are cached, and shared by its clones, so a poster should only
+/// be used with the tokens of one workspace.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::idempotent::IdempotentPoster;
+/// let client = slack_api::requests::default_client().unwrap();
+/// let request = slack_api::chat::PostMessageRequest {
+///     channel: "C024BE91L",
+///     text: "Disk full on db-1",
+///     ..Default::default()
+/// };
+///
+/// IdempotentPoster::new().post(&client, "xoxb-...", &request, "alert-4821").unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct IdempotentPoster {
+    attempts: u32,
+    backoff: Duration,
+    resolver: Arc<Resolver>,
+}
+
+impl IdempotentPoster {
+    pub fn new() -> Self {
+        IdempotentPoster {
+            attempts: 3,
+            backoff: Duration::from_secs(1),
+            resolver: Arc::new(Resolver::new()),
+        }
+    }
+
+    /// How many times a message is tried, at most. Defaults to 3.
+    pub fn attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /// How long to wait after the first failed attempt. The wait doubles after every attempt.
+    /// Defaults to a second.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Posts `request`, stamped with `key`, retrying it while it fails in a way that is likely to
+    /// pass. `key` should name the message itself, e.g. the alert it is about, so that it is the
+    /// same when the caller retries too.
+    pub fn post<R>(&self,
+                   client: &R,
+                   token: &str,
+                   request: &chat::PostMessageRequest,
+                   key: &str)
+                   -> Result<Sent, IdempotentError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let metadata = stamp(request.metadata, key).map_err(IdempotentError::Metadata)?;
+        let mut stamped = request.clone();
+        stamped.metadata = Some(&metadata);
+
+        let started = ::unix_timestamp();
+        let mut backoff = self.backoff;
+        let mut may_have_posted = false;
+        let mut attempt = 1;
+        loop {
+            if may_have_posted {
+                let found = conversation_id(client, token, &self.resolver, request.channel).and_then(|channel| match channel {
+                    Some(channel) => {
+                        find(client, token, &channel, request.thread_ts, key, started)
+                            .map(|message| message.map(|message| (channel, message)))
+                    }
+                    None => Ok(None),
+                });
+                match found {
+                    Ok(Some((channel, message))) => {
+                        return Ok(Sent {
+                                      channel: Some(channel),
+                                      ts: message.ts().map(str::to_owned),
+                                      found: true,
+                                  })
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        if attempt >= self.attempts {
+                            return Err(err);
+                        }
+                        attempt += 1;
+                        thread::sleep(backoff);
+                        backoff = backoff * 2;
+                        continue;
+                    }
+                }
+            }
+
+            match chat::post_message(client, token, &stamped) {
+                Ok(response) => {
+                    return Ok(Sent {
+                                  channel: response.channel,
+                                  ts: response.ts,
+                                  found: false,
+                              })
+                }
+                Err(err) => {
+                    may_have_posted |= err.may_have_posted();
+                    if !err.is_transient() || attempt >= self.attempts {
+                        return Err(IdempotentError::Post(err));
+                    }
+                }
+            }
+            attempt += 1;
+            thread::sleep(backoff);
+            backoff = backoff * 2;
+        }
+    }
+}
+
+impl Default for IdempotentPoster {
+    fn default() -> Self {
+        IdempotentPoster::new()
+    }
+}
+
+#[derive(Debug)]
+pub enum IdempotentError<E: Error> {
+    /// The request's metadata is not a JSON object.
+    Metadata(serde_json::Error),
+    /// Posting the message failed.
+    Post(chat::PostMessageError<E>),
+    /// Looking for the message in its channel failed.
+    History(conversations::HistoryError<E>),
+    /// Looking for the message in its thread failed.
+    Replies(conversations::RepliesError<E>),
+    /// Listing the channels to find the ID of the channel named in the request failed.
+    List(conversations::ListError<E>),
+    /// Opening the direct message with the user named in the request failed.
+    Open(conversations::OpenError<E>),
+}
+
+impl<E: Error> fmt::Display for IdempotentError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for IdempotentError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &IdempotentError::Metadata(ref inner) => inner.description(),
+            &IdempotentError::Post(ref inner) => inner.description(),
+            &IdempotentError::History(ref inner) => inner.description(),
+            &IdempotentError::Replies(ref inner) => inner.description(),
+            &IdempotentError::List(ref inner) => inner.description(),
+            &IdempotentError::Open(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &IdempotentError::Metadata(ref inner) => Some(inner),
+            &IdempotentError::Post(ref inner) => Some(inner),
+            &IdempotentError::History(ref inner) => Some(inner),
+            &IdempotentError::Replies(ref inner) => Some(inner),
+            &IdempotentError::List(ref inner) => Some(inner),
+            &IdempotentError::Open(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_stamp() {
+        let stamped: Value = serde_json::from_str(&stamp(None, "k1").unwrap()).unwrap();
+        assert_eq!(Some(EVENT_TYPE), stamped.get("event_type").and_then(Value::as_str));
+        assert_eq!(Some("k1"), stamped.pointer("/event_payload/idempotency_key").and_then(Value::as_str));
+
+        let own = r#"{"event_type": "deploy", "event_payload": {"sha": "4f2a9c1"}}"#;
+        let stamped: Value = serde_json::from_str(&stamp(Some(own), "k2").unwrap()).unwrap();
+        assert_eq!(Some("deploy"), stamped.get("event_type").and_then(Value::as_str));
+        assert_eq!(Some("4f2a9c1"), stamped.pointer("/event_payload/sha").and_then(Value::as_str));
+        assert_eq!(Some("k2"), stamped.pointer("/event_payload/idempotency_key").and_then(Value::as_str));
+
+        assert!(stamp(Some("[1]"), "k3").is_err());
+    }
+
+    #[test]
+    fn test_retry_finds_posted_message() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").fails("connection reset").times(1))
+            .expect(Expectation::new("conversations.history").param("include_all_metadata", "1")
+                .returns(r#"{"ok": true, "messages": [
+                    {"type": "message", "ts": "2.0", "text": "other",
+                        "metadata": {"event_type": "slack_api_idempotency", "event_payload": {"idempotency_key": "k0"}}},
+                    {"type": "message", "ts": "1.0", "text": "Disk full",
+                        "metadata": {"event_type": "slack_api_idempotency", "event_payload": {"idempotency_key": "k1"}}}]}"#));
+        let request = chat::PostMessageRequest { channel: "C1", text: "Disk full", ..Default::default() };
+
+        let poster = IdempotentPoster::new().backoff(Duration::from_millis(0));
+        let sent = poster.post(&mock, "xoxb-1", &request, "k1").unwrap();
+
+        assert_eq!((Some("1.0"), true), (sent.ts.as_ref().map(String::as_str), sent.found));
+        assert_eq!(1, mock.calls_of("chat.postMessage").len());
+        let metadata = mock.calls()[0].param("metadata").unwrap().to_owned();
+        assert!(metadata.contains(r#""idempotency_key":"k1""#), "{}", metadata);
+    }
+
+    #[test]
+    fn test_retry_searches_the_named_channel_by_id() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").fails("connection reset").times(1))
+            .expect(Expectation::new("conversations.list")
+                .returns(r#"{"ok": true, "channels": [{"id": "C9", "name": "alerts"}]}"#))
+            .expect(Expectation::new("conversations.history").param("channel", "C9")
+                .returns(r#"{"ok": true, "messages": [{"type": "message", "ts": "1.0", "text": "Disk full",
+                    "metadata": {"event_type": "slack_api_idempotency", "event_payload": {"idempotency_key": "k1"}}}]}"#));
+        let request = chat::PostMessageRequest { channel: "#alerts", text: "Disk full", ..Default::default() };
+
+        let poster = IdempotentPoster::new().backoff(Duration::from_millis(0));
+        let sent = poster.post(&mock, "xoxb-1", &request, "k1").unwrap();
+
+        assert_eq!((Some("C9"), Some("1.0"), true),
+                   (sent.channel.as_ref().map(String::as_str), sent.ts.as_ref().map(String::as_str), sent.found));
+        assert_eq!(1, mock.calls_of("conversations.history").len());
+    }
+
+    #[test]
+    fn test_refused_message_is_not_retried() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").returns_error("channel_not_found"));
+        let request = chat::PostMessageRequest { channel: "C1", text: "Disk full", ..Default::default() };
+
+        match IdempotentPoster::new().post(&mock, "xoxb-1", &request, "k1") {
+            Err(IdempotentError::Post(chat::PostMessageError::ChannelNotFound)) => {}
+            other => panic!("expected the channel not to be found, got {:?}", other),
+        }
+        assert_eq!(1, mock.calls().len());
+    }
+}
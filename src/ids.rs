@@ -0,0 +1,218 @@
+//! Newtypes for the IDs and timestamps Slack identifies things with, so that a user ID cannot be
+//! passed where a channel ID is expected.
+//!
+//! Each one (de)serializes as the string it wraps, converts from `String` and `&str`, and derefs
+//! to `str`, so that code reading them as strings keeps working.
+
+#[cfg(feature = "chrono")]
+extern crate chrono;
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! string_newtype {
+    ($(#[$attr:meta])* pub struct $name:ident;) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new<S: Into<String>>(value: S) -> Self {
+                $name(value.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $name(value)
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(value: &'a str) -> Self {
+                $name(value.to_owned())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl<'a> PartialEq<&'a str> for $name {
+            fn eq(&self, other: &&'a str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$name> for str {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl<'a> PartialEq<$name> for &'a str {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl PartialEq<$name> for String {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl Deserialize for $name {
+            fn deserialize<D: Deserializer>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map($name)
+            }
+        }
+    }
+}
+
+string_newtype! {
+    /// The ID of a conversation, like `C024BE91L` for a channel or `D024BE91L` for a direct
+    /// message.
+    pub struct ChannelId;
+}
+
+string_newtype! {
+    /// The ID of a user, like `U024BE7LH`, or of an Enterprise Grid user, like `W024BE7LH`.
+    pub struct UserId;
+}
+
+string_newtype! {
+    /// The ID of a workspace, like `T024BE7LD`.
+    pub struct TeamId;
+}
+
+string_newtype! {
+    /// The timestamp of a message, like `1503435956.000247`: when it was posted, which is also
+    /// its ID in its conversation.
+    pub struct Timestamp;
+}
+
+impl Timestamp {
+    /// The seconds and microseconds since the Unix epoch the timestamp is made of, or `None` if it
+    /// is not a timestamp.
+    pub fn to_parts(&self) -> Option<(i64, u32)> {
+        let mut parts = self.0.splitn(2, '.');
+        let seconds = parts.next()?.parse().ok()?;
+        let micros = match parts.next() {
+            Some(micros) if !micros.is_empty() && micros.len() <= 6 => {
+                micros.parse::<u32>().ok()? * 10u32.pow(6 - micros.len() as u32)
+            }
+            Some(_) => return None,
+            None => 0,
+        };
+        Some((seconds, micros))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp {
+    /// When the message was posted, or `None` if this is not a timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate chrono;
+    /// # extern crate slack_api;
+    /// # fn main() {
+    /// # use chrono::{TimeZone, Utc};
+    /// let ts = slack_api::Timestamp::new("1503435956.000247");
+    /// assert_eq!(Some(Utc.timestamp_opt(1503435956, 247_000).unwrap()), ts.to_datetime());
+    /// # }
+    /// ```
+    pub fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use self::chrono::TimeZone;
+
+        let (seconds, micros) = self.to_parts()?;
+        chrono::Utc.timestamp_opt(seconds, micros * 1000).single()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    fn from(time: chrono::DateTime<Tz>) -> Self {
+        Timestamp(format!("{}.{:06}", time.timestamp(), time.timestamp_subsec_micros()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn test_newtypes() {
+        let channel: ChannelId = serde_json::from_str(r#""C1""#).unwrap();
+        assert_eq!("C1", channel);
+        assert_eq!(r#""C1""#, serde_json::to_string(&channel).unwrap());
+        assert!(channel.starts_with('C'));
+
+        assert_eq!(Some((1503435956, 247)), Timestamp::new("1503435956.000247").to_parts());
+        assert_eq!(Some((1503435956, 500000)), Timestamp::new("1503435956.5").to_parts());
+        assert_eq!(Some((1503435956, 0)), Timestamp::new("1503435956").to_parts());
+        assert_eq!(None, Timestamp::new("not a ts").to_parts());
+        assert!(Timestamp::new("1503435956.000247") < Timestamp::new("1503435957.000001"));
+    }
+}
@@ -0,0 +1,357 @@
+//! The OAuth v2 installation flow.
+//!
+//! [`InstallFlow`] builds the URL that starts an installation, wraps `oauth.v2.access`, checks
+//! that Slack actually handed back something usable, and flattens the response into an
+//! [`Installation`] that can be persisted as-is.
+
+use std::error::Error;
+use std::fmt;
+
+use url::form_urlencoded;
+
+use oauth_v2;
+use requests::SlackWebRequestSender;
+use secret::Secret;
+use tokens::RotatingToken;
+
+const AUTHORIZE_URL: &'static str = "https://slack.com/oauth/v2/authorize";
+
+/// The credentials needed to exchange OAuth redirect codes for an [`Installation`].
+#[derive(Clone, Default, Debug)]
+pub struct InstallFlow<'a> {
+    /// Issued when you created your application.
+    pub client_id: &'a str,
+    /// Issued when you created your application.
+    pub client_secret: Secret<&'a str>,
+    /// This must match the redirect URI the authorize request was started with (if one was sent).
+    pub redirect_uri: Option<&'a str>,
+}
+
+impl<'a> InstallFlow<'a> {
+    pub fn new(client_id: &'a str, client_secret: &'a str) -> Self {
+        InstallFlow {
+            client_id: client_id,
+            client_secret: Secret::new(client_secret),
+            redirect_uri: None,
+        }
+    }
+
+    /// Builds the URL to send the installing user to, asking for the bot scopes `scopes` and the
+    /// user scopes `user_scopes`.
+    ///
+    /// `state` is passed back untouched on the redirect and should be used to guard against CSRF,
+    /// e.g. with an [`oauth_state::StateSigner`](../oauth_state/struct.StateSigner.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slack_api::install::InstallFlow;
+    /// let flow = InstallFlow::new("1234.5678", "secret");
+    /// assert_eq!("https://slack.com/oauth/v2/authorize?client_id=1234.5678&scope=chat%3Awrite%2Ccommands\
+    ///             &user_scope=search%3Aread&state=s1",
+    ///            flow.authorize_url(&["chat:write", "commands"], &["search:read"], "s1"));
+    /// ```
+    pub fn authorize_url(&self, scopes: &[&str], user_scopes: &[&str], state: &str) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        query.append_pair("client_id", self.client_id)
+            .append_pair("scope", &scopes.join(","));
+        if !user_scopes.is_empty() {
+            query.append_pair("user_scope", &user_scopes.join(","));
+        }
+        query.append_pair("state", state);
+        if let Some(redirect_uri) = self.redirect_uri {
+            query.append_pair("redirect_uri", redirect_uri);
+        }
+        format!("{}?{}", AUTHORIZE_URL, query.finish())
+    }
+
+    /// Exchanges the `code` from the OAuth redirect for an [`Installation`].
+    pub fn exchange<R>(&self, client: &R, code: &str) -> Result<Installation, InstallError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let request = oauth_v2::AccessRequest {
+            client_id: self.client_id,
+            client_secret: self.client_secret.expose(),
+            code: Some(code),
+            redirect_uri: self.redirect_uri,
+            ..oauth_v2::AccessRequest::default()
+        };
+        oauth_v2::access(client, &request)
+            .map_err(InstallError::Access)
+            .and_then(Installation::from_response)
+    }
+}
+
+/// Everything an app needs to keep from a successful installation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Installation {
+    pub app_id: Option<String>,
+    /// The workspace the app was installed to. `None` for org-wide Enterprise Grid installs.
+    pub team: Option<InstallationTeam>,
+    pub enterprise: Option<InstallationEnterprise>,
+    pub is_enterprise_install: bool,
+    pub bot_token: Option<Secret>,
+    pub bot_user_id: Option<String>,
+    /// Only set when token rotation is enabled for the app.
+    pub bot_refresh_token: Option<Secret>,
+    /// When `bot_token` expires, in seconds since the Unix epoch. Only set when token rotation
+    /// is enabled for the app.
+    pub bot_token_expires_at: Option<u64>,
+    /// The bot scopes that were granted.
+    pub scopes: Vec<String>,
+    pub user_id: Option<String>,
+    pub user_token: Option<Secret>,
+    pub user_refresh_token: Option<Secret>,
+    pub user_token_expires_at: Option<u64>,
+    /// The user scopes that were granted.
+    pub user_scopes: Vec<String>,
+    pub incoming_webhook: Option<IncomingWebhook>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InstallationTeam {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InstallationEnterprise {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IncomingWebhook {
+    pub channel: Option<String>,
+    pub channel_id: Option<String>,
+    pub configuration_url: Option<String>,
+    pub url: Secret,
+}
+
+impl Installation {
+    /// Validates an `oauth.v2.access` response and converts it into an `Installation`.
+    pub fn from_response<E: Error>(response: oauth_v2::AccessResponse) -> Result<Installation, InstallError<E>> {
+        if let Some(ref token_type) = response.token_type {
+            if token_type != "bot" {
+                return Err(InstallError::UnexpectedTokenType(token_type.clone()));
+            }
+        }
+
+        let is_enterprise_install = response.is_enterprise_install.unwrap_or(false);
+        let team = response.team.and_then(|team| {
+            let name = team.name;
+            team.id.map(|id| {
+                InstallationTeam {
+                    id: id,
+                    name: name,
+                }
+            })
+        });
+        let enterprise = response.enterprise.and_then(|enterprise| {
+            let name = enterprise.name;
+            enterprise.id.map(|id| {
+                InstallationEnterprise {
+                    id: id,
+                    name: name,
+                }
+            })
+        });
+        if team.is_none() && !(is_enterprise_install && enterprise.is_some()) {
+            return Err(InstallError::MissingTeam);
+        }
+
+        let (user_id, user_token, user_refresh_token, user_token_expires_at, user_scopes) = match response.authed_user {
+            Some(user) => {
+                (user.id,
+                 user.access_token.map(Secret::new),
+                 user.refresh_token.map(Secret::new),
+                 user.expires_in.map(expires_at),
+                 split_scopes(user.scope))
+            }
+            None => (None, None, None, None, Vec::new()),
+        };
+        if response.access_token.is_none() && user_token.is_none() {
+            return Err(InstallError::MissingToken);
+        }
+
+        let incoming_webhook = response.incoming_webhook.and_then(|hook| {
+            let oauth_v2::AccessResponseIncomingWebhook { channel, channel_id, configuration_url, url } = hook;
+            url.map(|url| {
+                IncomingWebhook {
+                    channel: channel,
+                    channel_id: channel_id,
+                    configuration_url: configuration_url,
+                    url: Secret::new(url),
+                }
+            })
+        });
+
+        Ok(Installation {
+            app_id: response.app_id,
+            team: team,
+            enterprise: enterprise,
+            is_enterprise_install: is_enterprise_install,
+            bot_token: response.access_token.map(Secret::new),
+            bot_user_id: response.bot_user_id,
+            bot_refresh_token: response.refresh_token.map(Secret::new),
+            bot_token_expires_at: response.expires_in.map(expires_at),
+            scopes: split_scopes(response.scope),
+            user_id: user_id,
+            user_token: user_token,
+            user_refresh_token: user_refresh_token,
+            user_token_expires_at: user_token_expires_at,
+            user_scopes: user_scopes,
+            incoming_webhook: incoming_webhook,
+        })
+    }
+
+    /// The bot token as a [`RotatingToken`], if token rotation is enabled for the app.
+    pub fn bot_rotating_token(&self) -> Option<RotatingToken> {
+        match (&self.bot_token, &self.bot_refresh_token, self.bot_token_expires_at) {
+            (&Some(ref access_token), &Some(ref refresh_token), Some(expires_at)) => {
+                Some(RotatingToken {
+                    access_token: access_token.clone(),
+                    refresh_token: refresh_token.clone(),
+                    expires_at: expires_at,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The ID that installations should be keyed by: the enterprise for org-wide installs,
+    /// otherwise the workspace.
+    pub fn installation_id(&self) -> Option<&str> {
+        if self.is_enterprise_install {
+            self.enterprise.as_ref().map(|e| &e.id[..])
+        } else {
+            self.team.as_ref().map(|t| &t.id[..])
+        }
+    }
+}
+
+fn expires_at(expires_in: i32) -> u64 {
+    // A negative `expires_in` would wrap around to a time far in the future.
+    ::unix_timestamp() + expires_in.max(0) as u64
+}
+
+fn split_scopes(scope: Option<String>) -> Vec<String> {
+    scope.map(|s| s.split(',').filter(|s| !s.is_empty()).map(str::to_owned).collect())
+        .unwrap_or_default()
+}
+
+#[derive(Debug)]
+pub enum InstallError<E: Error> {
+    /// `oauth.v2.access` itself failed.
+    Access(oauth_v2::AccessError<E>),
+    /// Slack returned neither a bot nor a user token.
+    MissingToken,
+    /// The response did not say which workspace or organization the app was installed to.
+    MissingTeam,
+    /// The top-level token was not a bot token.
+    UnexpectedTokenType(String),
+}
+
+impl<E: Error> fmt::Display for InstallError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &InstallError::UnexpectedTokenType(ref ty) => write!(f, "{}: {}", self.description(), ty),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: Error> Error for InstallError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &InstallError::Access(ref inner) => inner.description(),
+            &InstallError::MissingToken => "the installation response did not contain an access token",
+            &InstallError::MissingTeam => "the installation response did not contain a team or enterprise",
+            &InstallError::UnexpectedTokenType(_) => "the installation response contained an unexpected token type",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &InstallError::Access(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    fn exchange(response: &str) -> Result<Installation, InstallError<::testing::MockError>> {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("oauth.v2.access").param("code", "c1").returns(response));
+        let installation = InstallFlow::new("1234.5678", "secret").exchange(&mock, "c1");
+        let call = &mock.calls_of("oauth.v2.access")[0];
+        assert!(call.form);
+        assert_eq!(Some("secret"), call.param("client_secret"));
+        installation
+    }
+
+    #[test]
+    fn test_exchange() {
+        let installation = exchange(r##"{"ok": true, "app_id": "A1", "token_type": "bot", "access_token": "xoxb-1",
+            "bot_user_id": "U0BOT", "scope": "chat:write,commands", "team": {"id": "T1", "name": "Acme"},
+            "enterprise": null, "is_enterprise_install": false,
+            "authed_user": {"id": "U1", "scope": "search:read", "access_token": "xoxp-1", "token_type": "user"},
+            "incoming_webhook": {"channel": "#general", "channel_id": "C1",
+                "configuration_url": "https://acme.slack.com/services/B1", "url": "https://hooks.slack.com/services/T1/B1/x"}}"##)
+            .unwrap();
+
+        assert_eq!(Some("T1"), installation.installation_id());
+        assert_eq!(Some(InstallationTeam { id: "T1".to_owned(), name: Some("Acme".to_owned()) }), installation.team);
+        assert_eq!(Some("xoxb-1"), installation.bot_token.as_ref().map(Secret::expose));
+        assert_eq!(vec!["chat:write", "commands"], installation.scopes);
+        assert_eq!((Some("U1"), Some("xoxp-1")),
+                   (installation.user_id.as_ref().map(String::as_str), installation.user_token.as_ref().map(Secret::expose)));
+        assert_eq!(vec!["search:read"], installation.user_scopes);
+        assert_eq!(Some("C1"),
+                   installation.incoming_webhook.as_ref().and_then(|hook| hook.channel_id.as_ref()).map(String::as_str));
+        assert!(installation.bot_rotating_token().is_none());
+    }
+
+    #[test]
+    fn test_exchange_org_wide_install() {
+        let installation = exchange(r#"{"ok": true, "token_type": "bot", "access_token": "xoxb-1",
+            "refresh_token": "xoxe-1", "expires_in": 43200, "team": null,
+            "enterprise": {"id": "E1", "name": "Acme Corp"}, "is_enterprise_install": true}"#)
+            .unwrap();
+
+        assert_eq!(Some("E1"), installation.installation_id());
+        assert!(installation.team.is_none());
+        let token = installation.bot_rotating_token().unwrap();
+        assert_eq!("xoxe-1", token.refresh_token.expose());
+    }
+
+    #[test]
+    fn test_missing_team() {
+        match exchange(r#"{"ok": true, "token_type": "bot", "access_token": "xoxb-1", "team": null,
+            "enterprise": {"id": "E1"}, "is_enterprise_install": false}"#) {
+            Err(InstallError::MissingTeam) => {}
+            other => panic!("expected MissingTeam, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_missing_token() {
+        match exchange(r#"{"ok": true, "token_type": "bot", "team": {"id": "T1"}, "authed_user": {"id": "U1"}}"#) {
+            Err(InstallError::MissingToken) => {}
+            other => panic!("expected MissingToken, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unexpected_token_type() {
+        match exchange(r#"{"ok": true, "token_type": "user", "access_token": "xoxp-1", "team": {"id": "T1"}}"#) {
+            Err(InstallError::UnexpectedTokenType(ref ty)) if ty == "user" => {}
+            other => panic!("expected UnexpectedTokenType, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,482 @@
+//! Types for the payloads Slack sends an app when users interact with it: the slash commands
+//! POSTed to a command's Request URL, and the block actions, shortcuts, message actions and view
+//! submissions POSTed to the app's Interactivity Request URL.
+//!
+//! Slack POSTs slash commands as a plain form, which [`SlashCommand::from_form`] reads, and the
+//! other interactions as a form with a single `payload` field holding the JSON, which
+//! [`Interaction::from_form`] reads.
+//!
+//! # Examples
+//!
+//! ```
+//! # use slack_api::interaction::Interaction;
+//! let body = "payload=%7B%22type%22%3A%22view_submission%22%2C%22user%22%3A%7B%22id%22%3A%22U1%22%7D%2C\
+//!             %22view%22%3A%7B%22callback_id%22%3A%22bug_report%22%2C%22state%22%3A%7B%22values%22%3A\
+//!             %7B%22summary%22%3A%7B%22summary%22%3A%7B%22type%22%3A%22plain_text_input%22%2C\
+//!             %22value%22%3A%22It+crashed%22%7D%7D%7D%7D%7D%7D";
+//!
+//! match Interaction::from_form(body.as_bytes()).unwrap() {
+//!     Interaction::ViewSubmission(submission) => {
+//!         let summary = submission.value("summary", "summary").and_then(|v| v.value.clone());
+//!         assert_eq!(Some("It crashed".to_owned()), summary);
+//!     }
+//!     _ => {}
+//! }
+//! ```
+
+use serde::Deserialize;
+use serde::de::Error as SerdeError;
+use serde_json::{self, Map, Value};
+use url::form_urlencoded;
+
+use commands::{self, CommandResponse, RespondError};
+use requests::SlackJsonSender;
+use {Message, View, ViewState, ViewStateValue, ViewStateValueOption, ViewText};
+
+/// A payload sent to the Interactivity Request URL, told apart by its `type`.
+#[derive(Clone, Debug)]
+pub enum Interaction {
+    /// A user clicked a button, picked an option or otherwise used an interactive element in a
+    /// message, a modal or the Home tab.
+    BlockActions(BlockActions),
+    /// A user ran one of the app's global shortcuts.
+    Shortcut(Shortcut),
+    /// A user ran one of the app's message shortcuts on a message.
+    MessageAction(MessageAction),
+    /// A modal was submitted. Answering the request with an empty body closes it.
+    ViewSubmission(ViewSubmission),
+    /// A modal opened with `notify_on_close` was closed without being submitted.
+    ViewClosed(ViewClosed),
+}
+
+impl Interaction {
+    /// Parses the body of a request to the Interactivity Request URL, a form with the JSON payload
+    /// in its `payload` field.
+    pub fn from_form(body: &[u8]) -> Result<Self, serde_json::Error> {
+        form_urlencoded::parse(body)
+            .find(|&(ref name, _)| name == "payload")
+            .ok_or_else(|| serde_json::Error::missing_field("payload"))
+            .and_then(|(_, payload)| Interaction::from_slice(payload.as_bytes()))
+    }
+
+    /// Parses a JSON payload, as Socket Mode delivers them.
+    pub fn from_slice(payload: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(payload)
+    }
+
+    /// The URL to send replies to, for the interactions that come with one.
+    pub fn response_url(&self) -> Option<&str> {
+        let response_url = match self {
+            &Interaction::BlockActions(ref actions) => &actions.response_url,
+            &Interaction::MessageAction(ref action) => &action.response_url,
+            _ => return None,
+        };
+        response_url.as_ref().map(String::as_str)
+    }
+}
+
+impl Deserialize for Interaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::Deserializer
+    {
+        const VARIANTS: &'static [&'static str] =
+            &["block_actions", "shortcut", "message_action", "view_submission", "view_closed"];
+
+        let value = Value::deserialize(deserializer)?;
+        match value.get("type").and_then(Value::as_str) {
+            Some("block_actions") => from_value(value, Interaction::BlockActions),
+            Some("shortcut") => from_value(value, Interaction::Shortcut),
+            Some("message_action") => from_value(value, Interaction::MessageAction),
+            Some("view_submission") => from_value(value, Interaction::ViewSubmission),
+            Some("view_closed") => from_value(value, Interaction::ViewClosed),
+            Some(ty) => Err(D::Error::unknown_variant(ty, VARIANTS)),
+            None => Err(D::Error::missing_field("type")),
+        }
+    }
+}
+
+fn from_value<T, U, E>(value: Value, variant: fn(T) -> U) -> Result<U, E>
+    where T: Deserialize,
+          E: SerdeError
+{
+    serde_json::from_value(value).map(variant).map_err(|e| E::custom(e.to_string()))
+}
+
+/// Sends `response` to the `response_url` of a slash command, a block action or a message action,
+/// as an ephemeral or an in-channel message. See [`commands::respond`], which this calls.
+///
+/// [`commands::respond`]: ../commands/fn.respond.html
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::commands::CommandResponse;
+/// # use slack_api::interaction::{self, SlashCommand};
+/// # let body = b"command=%2Fdeploy&text=main&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1%2F1%2Fabc";
+/// let client = slack_api::requests::default_client().unwrap();
+/// let command = SlashCommand::from_form(body).unwrap();
+/// let response_url = command.response_url.as_ref().unwrap();
+/// interaction::respond_to_response_url(&client, response_url, &CommandResponse::in_channel("Deploying main")).unwrap();
+/// ```
+pub fn respond_to_response_url<R>(client: &R,
+                                  response_url: &str,
+                                  response: &CommandResponse)
+                                  -> Result<(), RespondError<R::Error>>
+    where R: SlackJsonSender
+{
+    commands::respond(client, response_url, response)
+}
+
+/// A slash command, as POSTed to the command's Request URL.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SlashCommand {
+    /// The command that was run, like `/deploy`.
+    pub command: String,
+    /// What the user typed after the command.
+    #[serde(default)]
+    pub text: String,
+    /// A URL to send up to five replies to within half an hour, with
+    /// [`respond_to_response_url`](fn.respond_to_response_url.html).
+    pub response_url: Option<String>,
+    /// A trigger to open a modal with, which expires three seconds after the command.
+    pub trigger_id: Option<String>,
+    pub user_id: Option<String>,
+    pub user_name: Option<String>,
+    pub channel_id: Option<String>,
+    pub channel_name: Option<String>,
+    pub team_id: Option<String>,
+    pub team_domain: Option<String>,
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
+    pub api_app_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    pub is_enterprise_install: Option<bool>,
+}
+
+impl SlashCommand {
+    /// Parses the body of a request to a command's Request URL, a form of the command's fields.
+    pub fn from_form(body: &[u8]) -> Result<Self, serde_json::Error> {
+        let fields = form_urlencoded::parse(body)
+            .map(|(name, value)| (name.into_owned(), Value::String(value.into_owned())))
+            .collect::<Map<String, Value>>();
+        serde_json::from_value(Value::Object(fields))
+    }
+
+    /// Parses a JSON payload, as Socket Mode delivers them.
+    pub fn from_slice(payload: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(payload)
+    }
+}
+
+/// Reads a flag sent either as a JSON boolean or, in forms, as `"true"` or `"false"`.
+fn deserialize_flag<D: ::serde::Deserializer>(deserializer: D) -> Result<Option<bool>, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Bool(flag) => Ok(Some(flag)),
+        Value::String(ref flag) if flag == "true" => Ok(Some(true)),
+        Value::String(ref flag) if flag == "false" => Ok(Some(false)),
+        Value::Null => Ok(None),
+        _ => Err(D::Error::custom("expected a boolean")),
+    }
+}
+
+/// The workspace an interaction happened in.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Team {
+    pub id: Option<String>,
+    pub domain: Option<String>,
+    pub enterprise_id: Option<String>,
+}
+
+/// The user who interacted.
+#[derive(Clone, Debug, Deserialize)]
+pub struct User {
+    pub id: Option<String>,
+    pub username: Option<String>,
+    pub name: Option<String>,
+    pub team_id: Option<String>,
+}
+
+/// The conversation an interaction happened in.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Channel {
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+/// Where the element a user interacted with is: a message, or a view.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Container {
+    /// `message` or `view`.
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub message_ts: Option<String>,
+    pub channel_id: Option<String>,
+    pub is_ephemeral: Option<bool>,
+    pub view_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BlockActions {
+    pub team: Option<Team>,
+    pub user: Option<User>,
+    pub api_app_id: Option<String>,
+    /// A trigger to open a modal with, which expires three seconds after the action.
+    pub trigger_id: Option<String>,
+    /// A URL to reply to the message the action happened in, absent for actions in views.
+    pub response_url: Option<String>,
+    pub container: Option<Container>,
+    pub channel: Option<Channel>,
+    /// The message the action happened in, as it was when the user interacted with it.
+    pub message: Option<Message>,
+    /// The view the action happened in.
+    pub view: Option<View>,
+    /// The values of the message's input blocks, for actions in messages.
+    pub state: Option<ViewState>,
+    /// The actions, usually a single one.
+    #[serde(default)]
+    pub actions: Vec<Action>,
+}
+
+impl BlockActions {
+    /// The action of the element with `action_id`.
+    pub fn action(&self, action_id: &str) -> Option<&Action> {
+        self.actions.iter().find(|action| action.action_id.as_ref().map(String::as_str) == Some(action_id))
+    }
+}
+
+/// A user's interaction with one interactive element.
+///
+/// Which of the fields are sent depends on the element: buttons send their `value`, selects the
+/// `selected_` field of what they pick.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Action {
+    /// The element type, like `button` or `static_select`.
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub action_id: Option<String>,
+    pub block_id: Option<String>,
+    pub action_ts: Option<String>,
+    /// The text of the button that was clicked.
+    pub text: Option<ViewText>,
+    /// The value of the button that was clicked.
+    pub value: Option<String>,
+    /// The style of the button that was clicked, `primary` or `danger`.
+    pub style: Option<String>,
+    pub selected_option: Option<ViewStateValueOption>,
+    pub selected_options: Option<Vec<ViewStateValueOption>>,
+    pub selected_user: Option<String>,
+    pub selected_users: Option<Vec<String>>,
+    pub selected_channel: Option<String>,
+    pub selected_channels: Option<Vec<String>>,
+    pub selected_conversation: Option<String>,
+    pub selected_conversations: Option<Vec<String>>,
+    /// The date picked in a datepicker, as `YYYY-MM-DD`.
+    pub selected_date: Option<String>,
+    /// The time picked in a timepicker, as `HH:mm`.
+    pub selected_time: Option<String>,
+}
+
+/// A global shortcut, run from the shortcuts menu or the search bar.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Shortcut {
+    pub team: Option<Team>,
+    pub user: Option<User>,
+    pub api_app_id: Option<String>,
+    /// The `callback_id` the shortcut was set up with, which tells the app's shortcuts apart.
+    pub callback_id: Option<String>,
+    /// A trigger to open a modal with, which expires three seconds after the shortcut.
+    pub trigger_id: Option<String>,
+    pub action_ts: Option<String>,
+    pub is_enterprise_install: Option<bool>,
+}
+
+/// A message shortcut, run from the menu of a message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageAction {
+    pub team: Option<Team>,
+    pub user: Option<User>,
+    pub api_app_id: Option<String>,
+    /// The `callback_id` the shortcut was set up with, which tells the app's shortcuts apart.
+    pub callback_id: Option<String>,
+    /// A trigger to open a modal with, which expires three seconds after the shortcut.
+    pub trigger_id: Option<String>,
+    /// A URL to post replies to the message's conversation with.
+    pub response_url: Option<String>,
+    pub channel: Option<Channel>,
+    pub message_ts: Option<String>,
+    /// The message the shortcut was run on.
+    pub message: Option<Message>,
+    pub action_ts: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewSubmission {
+    pub team: Option<Team>,
+    pub user: Option<User>,
+    pub api_app_id: Option<String>,
+    /// The view as it was submitted, with the values of its input blocks in its `state`.
+    pub view: View,
+    /// A trigger to open another view with, which expires three seconds after the submission.
+    pub trigger_id: Option<String>,
+    /// The response URLs of the conversations picked in the view, for blocks that asked for one.
+    #[serde(default)]
+    pub response_urls: Vec<ResponseUrl>,
+    pub is_enterprise_install: Option<bool>,
+}
+
+impl ViewSubmission {
+    /// The value the user gave the element with `action_id`, in the input block with `block_id`.
+    pub fn value(&self, block_id: &str, action_id: &str) -> Option<&ViewStateValue> {
+        self.view
+            .state
+            .as_ref()
+            .and_then(|state| state.values.as_ref())
+            .and_then(|values| values.get(block_id))
+            .and_then(|block| block.get(action_id))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewClosed {
+    pub team: Option<Team>,
+    pub user: Option<User>,
+    pub api_app_id: Option<String>,
+    pub view: View,
+    /// Whether every view on the stack was closed, rather than only this one.
+    pub is_cleared: Option<bool>,
+}
+
+/// A URL to post to the conversation the user picked in an input block of a submitted view.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResponseUrl {
+    pub block_id: Option<String>,
+    pub action_id: Option<String>,
+    pub channel_id: Option<String>,
+    pub response_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use blocks::{Block, Datepicker, Element, InputBlock, SelectOption, StaticSelect, Text, View as Layout};
+    use testing::{Expectation, MockSender};
+    use views;
+
+    use super::*;
+
+    #[test]
+    fn test_open_view() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("views.open").param("trigger_id", "1.2.3").returns(r#"{"ok": true,
+                "view": {"id": "V1", "type": "modal", "callback_id": "deploy", "hash": "1.abc"}}"#));
+        let view = Layout::modal(Text::plain("Deploy"),
+                                 vec![Block::Input(InputBlock::new(Text::plain("Environment"),
+                                                                   Element::StaticSelect(StaticSelect::new("env",
+                                                                       vec![SelectOption::new(Text::plain("Production"), "prod")])))
+                                          .block_id("env")),
+                                      Block::Input(InputBlock::new(Text::plain("When"),
+                                                                   Element::Datepicker(Datepicker::new("date")))
+                                          .optional(true))])
+            .submit(Text::plain("Deploy"))
+            .callback_id("deploy");
+        let json = view.to_json();
+
+        let response = views::open(&mock, "xoxb-1", &views::OpenRequest::new(&json).trigger_id("1.2.3")).unwrap();
+
+        assert_eq!(Some("V1".to_owned()), response.view.unwrap().id);
+        let sent: Value = serde_json::from_str(mock.calls()[0].param("view").unwrap()).unwrap();
+        assert_eq!("modal", sent["type"]);
+        assert_eq!("static_select", sent["blocks"][0]["element"]["type"]);
+        assert_eq!("prod", sent["blocks"][0]["element"]["options"][0]["value"]);
+        assert_eq!("datepicker", sent["blocks"][1]["element"]["type"]);
+        assert_eq!(Some(true), sent["blocks"][1]["optional"].as_bool());
+        assert_eq!("Deploy", sent["submit"]["text"]);
+    }
+
+    #[test]
+    fn test_payloads() {
+        let submission = r#"{"type": "view_submission", "team": {"id": "T1", "domain": "acme"},
+            "user": {"id": "U1", "username": "ada"}, "api_app_id": "A1", "trigger_id": "1.2.3",
+            "view": {"id": "V1", "type": "modal", "callback_id": "deploy", "private_metadata": "C1",
+                "state": {"values": {
+                    "env": {"env": {"type": "static_select", "selected_option": {"text": {"type": "plain_text", "text": "Production"}, "value": "prod"}}},
+                    "when": {"date": {"type": "datepicker", "selected_date": "2024-05-01"}}}}},
+            "response_urls": []}"#;
+        match Interaction::from_slice(submission.as_bytes()).unwrap() {
+            Interaction::ViewSubmission(submission) => {
+                let env = submission.value("env", "env").and_then(|v| v.selected_option.as_ref()).unwrap();
+                assert_eq!(Some("prod".to_owned()), env.value);
+                assert_eq!(Some("2024-05-01".to_owned()), submission.value("when", "date").unwrap().selected_date);
+                assert!(submission.value("when", "time").is_none());
+                assert_eq!(Some("C1".to_owned()), submission.view.private_metadata);
+            }
+            other => panic!("expected a view submission, got {:?}", other),
+        }
+
+        let closed = r#"{"type": "view_closed", "user": {"id": "U1"}, "view": {"id": "V1"}, "is_cleared": true}"#;
+        match Interaction::from_slice(closed.as_bytes()).unwrap() {
+            Interaction::ViewClosed(closed) => assert_eq!(Some(true), closed.is_cleared),
+            other => panic!("expected a closed view, got {:?}", other),
+        }
+
+        assert!(Interaction::from_slice(br#"{"type": "dialog_submission"}"#).is_err());
+        assert!(Interaction::from_form(b"token=x").is_err());
+    }
+
+    #[test]
+    fn test_actions_and_shortcuts() {
+        let actions = r#"{"type": "block_actions", "user": {"id": "U1"}, "trigger_id": "1.2.3",
+            "response_url": "https://hooks.slack.com/actions/T1/1/abc",
+            "container": {"type": "message", "message_ts": "1.2", "channel_id": "C1", "is_ephemeral": false},
+            "channel": {"id": "C1", "name": "deploys"},
+            "message": {"type": "message", "user": "U2", "ts": "1.2", "text": "Deploy?"},
+            "actions": [
+                {"type": "button", "action_id": "approve", "block_id": "b1", "value": "4f2a9c1", "style": "primary",
+                 "text": {"type": "plain_text", "text": "Approve"}, "action_ts": "1.3"},
+                {"type": "users_select", "action_id": "reviewer", "block_id": "b1", "selected_user": "U3"}]}"#;
+        match Interaction::from_slice(actions.as_bytes()).unwrap() {
+            Interaction::BlockActions(ref actions) => {
+                assert_eq!(Some("4f2a9c1".to_owned()), actions.action("approve").unwrap().value);
+                assert_eq!(Some("U3".to_owned()), actions.action("reviewer").unwrap().selected_user);
+                assert!(actions.action("reject").is_none());
+                assert_eq!(Some("1.2"), actions.message.as_ref().unwrap().ts());
+                assert_eq!(Some("C1".to_owned()), actions.container.as_ref().unwrap().channel_id);
+            }
+            ref other => panic!("expected block actions, got {:?}", other),
+        }
+
+        let shortcut = r#"{"type": "shortcut", "callback_id": "new_incident", "trigger_id": "1.2.3", "team": {"id": "T1"}}"#;
+        match Interaction::from_slice(shortcut.as_bytes()).unwrap() {
+            Interaction::Shortcut(shortcut) => assert_eq!(Some("new_incident".to_owned()), shortcut.callback_id),
+            other => panic!("expected a shortcut, got {:?}", other),
+        }
+
+        let action = r#"{"type": "message_action", "callback_id": "file_bug", "message_ts": "1.2",
+            "response_url": "https://hooks.slack.com/app/T1/1/abc", "channel": {"id": "C1"},
+            "message": {"type": "message", "ts": "1.2", "text": "It crashed"}}"#;
+        let action = Interaction::from_slice(action.as_bytes()).unwrap();
+        assert_eq!(Some("https://hooks.slack.com/app/T1/1/abc"), action.response_url());
+        match action {
+            Interaction::MessageAction(action) => assert_eq!(Some("1.2".to_owned()), action.message_ts),
+            other => panic!("expected a message action, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_slash_command() {
+        let body = b"token=x&team_id=T1&team_domain=acme&channel_id=C1&channel_name=deploys&user_id=U1\
+                     &user_name=ada&command=%2Fdeploy&text=main+to+prod&api_app_id=A1&is_enterprise_install=false\
+                     &response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1%2F1%2Fabc&trigger_id=1.2.3";
+        let command = SlashCommand::from_form(body).unwrap();
+        assert_eq!(("/deploy", "main to prod"), (&command.command[..], &command.text[..]));
+        assert_eq!(Some("C1".to_owned()), command.channel_id);
+        assert_eq!(Some(false), command.is_enterprise_install);
+        assert!(SlashCommand::from_form(b"text=main").is_err());
+
+        let response_url = command.response_url.unwrap();
+        let mock = MockSender::new();
+        mock.expect(Expectation::new(&response_url[..]).times(1));
+        respond_to_response_url(&mock, &response_url, &CommandResponse::in_channel("Deploying main")).unwrap();
+        assert_eq!(Some(r#"{"response_type":"in_channel","text":"Deploying main"}"#), mock.calls()[0].param("body"));
+        mock.verify();
+    }
+}
@@ -0,0 +1,265 @@
+//! Calling the retired `channels.*`, `groups.*`, `im.*` and `mpim.*` methods through the
+//! `conversations.*` family that replaced them.
+//!
+//! The legacy modules keep their requests, responses and errors. Their calls are translated here
+//! on the wire: the parameters are renamed for the `conversations.*` method, and the fields of its
+//! response are renamed back, so the legacy parsers read it as if the retired method had answered.
+
+use serde_json::{self, Value};
+
+use requests::SlackWebRequestSender;
+
+/// How a legacy method maps onto its `conversations.*` replacement.
+struct Shim {
+    /// The method called instead.
+    method: &'static str,
+    /// The parameters renamed, from their legacy name to the new one. Those renamed to `""` are
+    /// dropped, as the new method has nothing like them.
+    params: &'static [(&'static str, &'static str)],
+    /// The parameters added, unless the call already has them.
+    extra: &'static [(&'static str, &'static str)],
+    /// The fields of the response renamed, from their new name to the legacy one.
+    fields: &'static [(&'static str, &'static str)],
+}
+
+const NONE: &'static [(&'static str, &'static str)] = &[];
+const HISTORY: &'static [(&'static str, &'static str)] = &[("count", "limit"), ("unreads", "")];
+const REPLIES: &'static [(&'static str, &'static str)] = &[("thread_ts", "ts")];
+const VALIDATE: &'static [(&'static str, &'static str)] = &[("validate", "")];
+const GROUP: &'static [(&'static str, &'static str)] = &[("channel", "group")];
+
+/// Legacy lists were not paginated, so they are listed in as few pages as possible.
+const PAGE: (&'static str, &'static str) = ("limit", "1000");
+
+fn shim(legacy: &str) -> Option<Shim> {
+    let shim = |method, params, extra, fields| {
+        Some(Shim {
+                 method: method,
+                 params: params,
+                 extra: extra,
+                 fields: fields,
+             })
+    };
+    match legacy {
+        "channels.archive" | "groups.archive" => shim("conversations.archive", NONE, NONE, NONE),
+        "channels.create" => shim("conversations.create", VALIDATE, NONE, NONE),
+        "channels.history" | "groups.history" | "im.history" | "mpim.history" => {
+            shim("conversations.history", HISTORY, NONE, NONE)
+        }
+        "channels.info" => shim("conversations.info", NONE, NONE, NONE),
+        "channels.invite" => shim("conversations.invite", &[("user", "users")], NONE, NONE),
+        "channels.join" => shim("conversations.join", VALIDATE, NONE, NONE),
+        "channels.kick" | "groups.kick" => shim("conversations.kick", NONE, NONE, NONE),
+        "channels.leave" | "groups.leave" => shim("conversations.leave", NONE, NONE, NONE),
+        "channels.list" => {
+            shim("conversations.list",
+                 &[("exclude_members", "")],
+                 &[("types", "public_channel"), PAGE],
+                 NONE)
+        }
+        "channels.mark" | "groups.mark" | "im.mark" | "mpim.mark" => shim("conversations.mark", NONE, NONE, NONE),
+        "channels.rename" | "groups.rename" => shim("conversations.rename", VALIDATE, NONE, NONE),
+        "channels.replies" | "groups.replies" | "im.replies" | "mpim.replies" => {
+            shim("conversations.replies", REPLIES, NONE, NONE)
+        }
+        "channels.setPurpose" | "groups.setPurpose" => shim("conversations.setPurpose", NONE, NONE, NONE),
+        "channels.setTopic" | "groups.setTopic" => shim("conversations.setTopic", NONE, NONE, NONE),
+        "channels.unarchive" | "groups.unarchive" => shim("conversations.unarchive", NONE, NONE, NONE),
+        "groups.close" | "im.close" | "mpim.close" => shim("conversations.close", NONE, NONE, NONE),
+        "groups.create" => shim("conversations.create", VALIDATE, &[("is_private", "1")], GROUP),
+        "groups.info" => shim("conversations.info", NONE, NONE, GROUP),
+        "groups.invite" => shim("conversations.invite", &[("user", "users")], NONE, GROUP),
+        "groups.list" => {
+            shim("conversations.list",
+                 NONE,
+                 &[("types", "private_channel"), PAGE],
+                 &[("channels", "groups")])
+        }
+        "groups.open" => shim("conversations.open", NONE, NONE, NONE),
+        "im.list" => shim("conversations.list", NONE, &[("types", "im"), PAGE], &[("channels", "ims")]),
+        "im.open" => shim("conversations.open", &[("user", "users")], NONE, NONE),
+        "mpim.list" => shim("conversations.list", NONE, &[("types", "mpim"), PAGE], &[("channels", "groups")]),
+        "mpim.open" => shim("conversations.open", NONE, NONE, GROUP),
+        // groups.createChild has no replacement, so it still calls the retired method.
+        _ => None,
+    }
+}
+
+/// Sends the call of the legacy method `legacy` to its replacement, returning the response as the
+/// legacy method would have.
+pub fn send_bytes<R>(client: &R, legacy: &str, params: &[(&str, &str)]) -> Result<Vec<u8>, R::Error>
+    where R: SlackWebRequestSender
+{
+    let shim = match shim(legacy) {
+        Some(shim) => shim,
+        None => return client.send_bytes(&::get_slack_url_for_method(legacy), params),
+    };
+
+    let mut translated = Vec::with_capacity(params.len() + shim.extra.len());
+    for &(name, value) in params {
+        match shim.params.iter().find(|&&(from, _)| from == name) {
+            Some(&(_, "")) => {}
+            Some(&(_, to)) => translated.push((to, value)),
+            None => translated.push((name, value)),
+        }
+    }
+    for &(name, value) in shim.extra {
+        if !translated.iter().any(|&(n, _)| n == name) {
+            translated.push((name, value));
+        }
+    }
+
+    let body = if legacy == "channels.join" {
+        join_by_name(client, &translated)?
+    } else if shim.method == "conversations.list" {
+        list_all(client, &translated)?
+    } else {
+        client.send_bytes(&::get_slack_url_for_method(shim.method), &translated)?
+    };
+    Ok(rename_fields(body, &shim))
+}
+
+fn rename_fields(body: Vec<u8>, shim: &Shim) -> Vec<u8> {
+    let mut response = match serde_json::from_slice(&body) {
+        Ok(Value::Object(response)) => response,
+        // Leave it to the legacy parser to report it.
+        _ => return body,
+    };
+    for &(from, to) in shim.fields {
+        if let Some(value) = response.remove(from) {
+            response.insert(to.to_owned(), value);
+        }
+    }
+    // The legacy methods answered with the new purpose or topic only.
+    for &(method, field) in &[("conversations.setPurpose", "purpose"), ("conversations.setTopic", "topic")] {
+        if shim.method == method && !response.contains_key(field) {
+            let value = response.get("channel").and_then(|channel| channel.pointer(&format!("/{}/value", field))).cloned();
+            if let Some(value) = value {
+                response.insert(field.to_owned(), value);
+            }
+        }
+    }
+    serde_json::to_vec(&Value::Object(response)).unwrap_or(body)
+}
+
+/// Lists every page of `conversations.list`, as one response holding all the channels, unless
+/// the call asked for a page of its own.
+fn list_all<R>(client: &R, params: &[(&str, &str)]) -> Result<Vec<u8>, R::Error>
+    where R: SlackWebRequestSender
+{
+    let url = ::get_slack_url_for_method("conversations.list");
+    if params.iter().any(|&(name, _)| name == "cursor") {
+        return client.send_bytes(&url, params);
+    }
+
+    let mut all: Option<Value> = None;
+    let mut cursor = None;
+    loop {
+        let mut page_params = params.to_vec();
+        page_params.extend(cursor.as_ref().map(|cursor: &String| ("cursor", &cursor[..])));
+        let body = client.send_bytes(&url, &page_params)?;
+        let page = match serde_json::from_slice::<Value>(&body) {
+            Ok(page) => page,
+            Err(_) => return Ok(body),
+        };
+        if page.get("ok").and_then(Value::as_bool) != Some(true) {
+            return Ok(body);
+        }
+
+        cursor = page.pointer("/response_metadata/next_cursor")
+            .and_then(Value::as_str)
+            .filter(|cursor| !cursor.is_empty())
+            .map(str::to_owned);
+        match all {
+            None => all = Some(page),
+            Some(ref mut all) => {
+                if let (Some(&mut Value::Array(ref mut channels)), Some(&Value::Array(ref more))) =
+                    (all.get_mut("channels"), page.get("channels")) {
+                    channels.extend(more.iter().cloned());
+                }
+            }
+        }
+        if cursor.is_none() {
+            return Ok(serde_json::to_vec(&all).expect("JSON values always serialize"));
+        }
+    }
+}
+
+/// `channels.join` took the name of the channel, while `conversations.join` takes its ID.
+fn join_by_name<R>(client: &R, params: &[(&str, &str)]) -> Result<Vec<u8>, R::Error>
+    where R: SlackWebRequestSender
+{
+    let param = |name| params.iter().find(|&&(n, _)| n == name).map(|&(_, value)| value).unwrap_or("");
+    let (token, name) = (param("token"), param("name").trim_start_matches('#'));
+
+    let body = list_all(client,
+                        &[("token", token), ("types", "public_channel"), ("exclude_archived", "1"), PAGE])?;
+    let channels = match serde_json::from_slice::<Value>(&body) {
+        Ok(ref list) if list.get("ok").and_then(Value::as_bool) == Some(true) => list.get("channels").cloned(),
+        _ => return Ok(body),
+    };
+    let id = channels.as_ref()
+        .and_then(Value::as_array)
+        .and_then(|channels| channels.iter().find(|channel| channel.get("name").and_then(Value::as_str) == Some(name)))
+        .and_then(|channel| channel.get("id"))
+        .and_then(Value::as_str);
+    match id {
+        Some(id) => {
+            client.send_bytes(&::get_slack_url_for_method("conversations.join"),
+                              &[("token", token), ("channel", id)])
+        }
+        None => Ok(br#"{"ok": false, "error": "channel_not_found"}"#.to_vec()),
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use channels;
+    use groups;
+    use testing::{Expectation, MockSender};
+    use ChannelId;
+
+    #[test]
+    fn test_history_through_conversations() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.history").param("channel", "C1").param("limit", "10")
+            .returns(r#"{"ok": true, "messages": [{"type": "message", "ts": "1.0", "text": "hi"}], "has_more": false}"#));
+
+        let request = channels::HistoryRequest { channel: "C1", count: Some(10), unreads: Some(true), ..Default::default() };
+        let response = channels::history(&mock, "xoxb-1", &request).unwrap();
+        assert_eq!(1, response.messages.unwrap().len());
+        assert_eq!((None, None), (mock.calls()[0].param("count"), mock.calls()[0].param("unreads")));
+    }
+
+    #[test]
+    fn test_list_pages_translated() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").param("types", "private_channel").param("cursor", "next")
+                .returns(r#"{"ok": true, "channels": [{"id": "G2", "name": "ops"}]}"#))
+            .expect(Expectation::new("conversations.list").param("types", "private_channel").param("limit", "1000")
+                .returns(r#"{"ok": true, "channels": [{"id": "G1", "name": "hr"}],
+                    "response_metadata": {"next_cursor": "next"}}"#));
+
+        let groups = groups::list(&mock, "xoxb-1", &Default::default()).unwrap().groups.unwrap();
+        assert_eq!(vec![Some("G1"), Some("G2")],
+                   groups.iter().map(|g| g.id.as_ref().map(ChannelId::as_str)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_join_by_name() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list")
+                .returns(r#"{"ok": true, "channels": [{"id": "C1", "name": "general"}, {"id": "C2", "name": "deploys"}]}"#))
+            .expect(Expectation::new("conversations.join").param("channel", "C2")
+                .returns(r#"{"ok": true, "channel": {"id": "C2", "name": "deploys"}}"#).times(1));
+
+        let response = channels::join(&mock, "xoxb-1", &channels::JoinRequest { name: "#deploys", ..Default::default() });
+        assert_eq!(Some("C2"), response.unwrap().channel.unwrap().id.as_ref().map(ChannelId::as_str));
+        match channels::join(&mock, "xoxb-1", &channels::JoinRequest { name: "#nope", ..Default::default() }) {
+            Err(channels::JoinError::ChannelNotFound) => {}
+            other => panic!("expected channel_not_found, got {:?}", other),
+        }
+        mock.verify();
+    }
+}
@@ -15,10 +15,19 @@
 //! Low-level, direct interface for the [Slack Web
 //! API](https://api.slack.com/methods).
 
+extern crate base64;
+extern crate hmac;
+extern crate rand;
+#[cfg_attr(feature = "preserve-unknown", macro_use)]
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
+extern crate sha2;
+extern crate url;
+
+#[macro_use]
+mod model;
 
 mod mods;
 pub use mods::*;
@@ -26,13 +35,250 @@ pub use mods::*;
 mod types;
 pub use types::*;
 
+mod error;
+pub use error::Error;
+
+mod ids;
+pub use ids::{ChannelId, TeamId, Timestamp, UserId};
+
 pub mod requests;
 
+pub mod blocks;
+
+pub mod breaker;
+
+pub mod broadcast;
+
+pub mod cache;
+
+pub mod cancel;
+
+pub mod cleanup;
+
+pub mod client;
+
+pub mod commands;
+
+pub mod correlation;
+
+pub mod cursor;
+
+pub mod dispatch;
+
+pub mod events;
+
+pub mod export;
+
+pub mod history;
+
+pub mod idempotent;
+
+pub mod install;
+
+pub mod interaction;
+
+mod legacy;
+
+#[cfg(feature = "log")]
+pub mod logging;
+
+pub mod membership;
+
+pub mod message;
+
+pub mod metrics;
+
+pub mod oauth_state;
+
+pub mod outbox;
+
+pub mod paging;
+
+pub mod permalink;
+
+pub mod plan;
+
+pub mod provision;
+
+pub mod rate_limit;
+
+pub mod resolve;
+
+pub mod retry;
+
+#[cfg(feature = "chrono")]
+pub mod schedule;
+
+pub mod scopes;
+
+pub mod secret;
+
+pub mod sign_in;
+
+pub mod socket_mode;
+
+pub mod status;
+
+pub mod sync;
+
+pub mod testing;
+
+pub mod tokens;
+
+pub mod upload;
+
+pub mod webhook;
+
+pub mod workspace;
+
 #[cfg(feature = "reqwest")]
 pub use requests::default_client;
 
+const SLACK_API_URL: &'static str = "https://slack.com/api/";
+
 fn get_slack_url_for_method(method: &str) -> String {
-    format!("https://slack.com/api/{}", method)
+    format!("{}{}", SLACK_API_URL, method)
+}
+
+/// A parameter holding JSON, like `blocks`, as the JSON bodies of POSTed calls carry it: as the JSON
+/// it holds, or as a string if it holds none.
+fn json_param(value: &str) -> serde_json::Value {
+    serde_json::from_str(value).unwrap_or_else(|_| serde_json::Value::String(value.to_owned()))
+}
+
+/// An integer parameter, formatted on the stack so that building a call allocates nothing for it.
+struct IntParam {
+    digits: [u8; 10],
+    start: usize,
+}
+
+impl IntParam {
+    fn new(mut value: u32) -> Self {
+        let mut digits = [0; 10];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        IntParam {
+            digits: digits,
+            start: start,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        ::std::str::from_utf8(&self.digits[self.start..]).expect("digits are ASCII")
+    }
+}
+
+/// Why a response body could not be returned, before it is turned into its method's error type.
+///
+/// Each method parses its response in a function that is not generic over the sender, so the
+/// parsing is compiled once in this crate instead of once for every sender a program uses; only
+/// this conversion is left to the generic part.
+///
+/// `I` is the type of the items of the `errors` array of the methods that report the failure of
+/// each item of a call in one.
+enum ResponseError<I = ()> {
+    Malformed(serde_json::Error),
+    Api(String),
+    Items(Vec<I>),
+}
+
+impl ResponseError {
+    fn into_error<T>(self, malformed: fn(serde_json::Error) -> T) -> T
+        where T: for<'a> From<&'a str>
+    {
+        self.into_error_with(malformed, |_| unreachable!("only methods with an errors array report items"))
+    }
+}
+
+impl<I> ResponseError<I> {
+    fn into_error_with<T>(self, malformed: fn(serde_json::Error) -> T, items: fn(Vec<I>) -> T) -> T
+        where T: for<'a> From<&'a str>
+    {
+        match self {
+            ResponseError::Malformed(err) => malformed(err),
+            ResponseError::Api(code) => T::from(&code[..]),
+            ResponseError::Items(errors) => items(errors),
+        }
+    }
+}
+
+/// Parses a response, and if it does not parse, parses it again without the items of `arrays` that
+/// do not, which are returned as warnings instead.
+///
+/// Each array is given by its field, dot-separated if it is nested in an object, and a function
+/// telling why an item of it does not parse: a conversation or message of an unexpected shape
+/// should not cost the rest of the page. The response is only parsed twice if it has to be.
+fn from_slice_skipping_invalid<T>(body: &[u8],
+                                  arrays: &[(&str, ItemError)])
+                                  -> Result<(T, Vec<requests::ParseWarning>), serde_json::Error>
+    where T: serde::Deserialize
+{
+    let err = match serde_json::from_slice(body) {
+        Ok(response) => return Ok((response, Vec::new())),
+        Err(err) => err,
+    };
+    let mut value = match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(value) => value,
+        Err(_) => return Err(err),
+    };
+
+    let mut warnings = Vec::new();
+    for &(field, item_error) in arrays {
+        let pointer = format!("/{}", field.replace('.', "/"));
+        let items = match value.pointer_mut(&pointer).and_then(serde_json::Value::as_array_mut) {
+            Some(items) => items,
+            None => continue,
+        };
+        let mut index = 0;
+        items.retain(|item| {
+            let error = item_error(item);
+            let valid = error.is_none();
+            if let Some(error) = error {
+                warnings.push(requests::ParseWarning {
+                    field: field.to_owned(),
+                    index: index,
+                    error: error,
+                    item: item.clone(),
+                });
+            }
+            index += 1;
+            valid
+        });
+    }
+
+    match serde_json::from_value(value) {
+        Ok(response) => Ok((response, warnings)),
+        Err(_) => Err(err),
+    }
+}
+
+/// Why an item of an array is not valid, if it is not.
+type ItemError = fn(&serde_json::Value) -> Option<String>;
+
+/// Why `item` is not a valid `I`, for `from_slice_skipping_invalid`.
+fn item_error<I: serde::Deserialize>(item: &serde_json::Value) -> Option<String> {
+    I::deserialize(item).err().map(|err| err.to_string())
+}
+
+/// `len` random bytes from the OS, encoded as URL-safe base64.
+fn random_string(len: usize) -> String {
+    use rand::Rng;
+
+    let bytes: Vec<u8> = rand::OsRng::new().expect("No OS random number generator").gen_iter().take(len).collect();
+    base64::encode_config(&bytes, base64::URL_SAFE_NO_PAD)
+}
+
+fn unix_timestamp() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
 }
 
 fn optional_struct_or_empty_array<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
@@ -81,7 +327,14 @@ fn optional_struct_or_empty_array<'de, T, D>(deserializer: D) -> Result<Option<T
 #[cfg(test)]
 mod tests {
     use serde_json;
-    use super::UserProfile;
+    use super::{IntParam, Message, UserId, UserProfile};
+
+    #[test]
+    fn test_int_param() {
+        assert_eq!("0", IntParam::new(0).as_str());
+        assert_eq!("200", IntParam::new(200).as_str());
+        assert_eq!("4294967295", IntParam::new(u32::max_value()).as_str());
+    }
 
     #[test]
     fn test_user_profile_fields_empty_array_deserialize() {
@@ -112,4 +365,192 @@ mod tests {
         let user_profile: UserProfile = serde_json::from_str(r#"{}"#).unwrap();
         assert!(user_profile.fields.is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_message_bot_profile_deserialize() {
+        let message: Message = serde_json::from_str(r#"{
+            "type": "message",
+            "text": "deployed",
+            "ts": "1500000000.000100",
+            "bot_id": "B0123",
+            "bot_profile": {"id": "B0123", "app_id": "A0123", "name": "deploybot", "deleted": false,
+                            "icons": {"image_36": "https://example.com/36.png"}}
+        }"#).unwrap();
+        match message {
+            Message::Standard(msg) => {
+                let profile = msg.bot_profile.unwrap();
+                assert_eq!(Some("deploybot"), profile.name.as_ref().map(String::as_str));
+                assert_eq!(Some("A0123"), profile.app_id.as_ref().map(String::as_str));
+                assert!(profile.icons.unwrap().image_36.is_some());
+            }
+            _ => panic!("expected a standard message"),
+        }
+    }
+
+    #[test]
+    fn test_message_thread_metadata_deserialize() {
+        let message: Message = serde_json::from_str(r#"{
+            "type": "message",
+            "user": "U0123",
+            "text": "parent",
+            "ts": "1500000000.000100",
+            "thread_ts": "1500000000.000100",
+            "reply_count": 2,
+            "reply_users": ["U0456", "U0789"],
+            "reply_users_count": 2,
+            "latest_reply": "1500000100.000200",
+            "edited": {"user": "U0123", "ts": "1500000050.000000"}
+        }"#).unwrap();
+        match message {
+            Message::Standard(msg) => {
+                assert_eq!(Some(2), msg.reply_count);
+                assert_eq!(2, msg.reply_users.unwrap().len());
+                assert_eq!(msg.ts, msg.thread_ts);
+                assert_eq!(Some("U0123"), msg.edited.unwrap().user.as_ref().map(UserId::as_str));
+            }
+            _ => panic!("expected a standard message"),
+        }
+    }
+
+    #[test]
+    fn test_message_subtypes_deserialize() {
+        let messages: Vec<Message> = serde_json::from_str(r#"[
+            {"type": "message", "subtype": "thread_broadcast", "user": "U0123", "text": "also in the channel",
+             "ts": "1500000100.000200", "thread_ts": "1500000000.000100"},
+            {"type": "message", "subtype": "tombstone", "hidden": true, "text": "This message was deleted.",
+             "ts": "1500000000.000100", "thread_ts": "1500000000.000100", "reply_count": 3},
+            {"type": "message", "subtype": "assistant_app_thread", "ts": "1500000200.000300", "new_field": [1, 2]}
+        ]"#).unwrap();
+        match (&messages[0], &messages[1], &messages[2]) {
+            (&Message::ThreadBroadcast(_), &Message::Tombstone(ref tombstone), &Message::Unknown(ref value)) => {
+                assert_eq!(Some(3), tombstone.reply_count);
+                assert_eq!(Some(2), value.get("new_field").and_then(|field| field.as_array()).map(Vec::len));
+            }
+            other => panic!("expected a broadcast, a tombstone and an unknown message, got {:?}", other),
+        }
+        assert_eq!(Some("1500000000.000100"), messages[0].thread_ts());
+        assert_eq!(Some("1500000200.000300"), messages[2].ts());
+    }
+
+    #[test]
+    fn test_request_builders() {
+        use conversations::{HistoryRequest, ListRequest};
+
+        let request = ListRequest::new().limit(200).types("public_channel,private_channel");
+        assert_eq!((Some(200), Some("public_channel,private_channel"), None), (request.limit, request.types, request.cursor));
+        let request = HistoryRequest::new("C1").inclusive(true);
+        assert_eq!(("C1", Some(true), None), (request.channel, request.inclusive, request.latest));
+    }
+
+    #[test]
+    fn test_partial_failure() {
+        use conversations::{self, InviteError, InviteRequest};
+        use testing::{Expectation, MockSender};
+
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.invite").returns(r#"{"ok": false, "error": "already_in_channel",
+            "errors": [{"user": "U1", "ok": false, "error": "already_in_channel"},
+                       {"user": "U2", "ok": false, "error": "user_not_found"}]}"#).times(1))
+            .expect(Expectation::new("conversations.invite").returns_error("is_archived"));
+        let request = InviteRequest { channel: "C1", users: "U1,U2", ..Default::default() };
+
+        match conversations::invite(&mock, "xoxb-1", &request) {
+            Err(InviteError::PartialFailure(errors)) => {
+                assert_eq!(vec![(Some("U1"), "already_in_channel"), (Some("U2"), "user_not_found")],
+                           errors.iter().map(|e| (e.user.as_ref().map(String::as_str), &e.error[..])).collect::<Vec<_>>());
+            }
+            other => panic!("expected a partial failure, got {:?}", other),
+        }
+        match conversations::invite(&mock, "xoxb-1", &request) {
+            Err(InviteError::IsArchived) => {}
+            other => panic!("expected is_archived, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_items_skipped() {
+        use conversations::{self, HistoryRequest, ListRequest};
+        use testing::{Expectation, MockSender};
+
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").returns(r#"{"ok": true, "channels": [
+                {"id": "C1", "name": "general"},
+                {"id": "C2", "name": "random", "is_channel": "yes"},
+                {"id": "C3", "name": "dev"}]}"#))
+            .expect(Expectation::new("conversations.history").returns(r#"{"ok": true, "messages": [
+                {"type": "message", "ts": 1503435956}, {"type": "message", "ts": "1503435957.000002", "text": "hi"}]}"#))
+            .expect(Expectation::new("conversations.info").returns(r#"{"ok": true, "channel": {"id": 1}}"#));
+
+        let list = conversations::list(&mock, "xoxb-1", &ListRequest::default()).unwrap();
+        assert_eq!(vec!["C1", "C3"],
+                   list.channels.unwrap().iter().map(|c| c.id.as_ref().unwrap().as_str()).collect::<Vec<_>>());
+        assert_eq!(1, list.parse_warnings.len());
+        assert_eq!(("channels", 1), (&list.parse_warnings[0].field[..], list.parse_warnings[0].index));
+        assert_eq!(Some("C2"), list.parse_warnings[0].item["id"].as_str());
+
+        let history = conversations::history(&mock, "xoxb-1", &HistoryRequest { channel: "C1", ..Default::default() })
+            .unwrap();
+        assert_eq!(1, history.messages.unwrap().len());
+        assert_eq!(("messages", 0), (&history.parse_warnings[0].field[..], history.parse_warnings[0].index));
+
+        // Only the items of lists are left out: anything else still fails the response.
+        match conversations::info(&mock, "xoxb-1", &conversations::InfoRequest { channel: "C1", ..Default::default() }) {
+            Err(conversations::InfoError::MalformedResponse(_)) => {}
+            other => panic!("expected a malformed response, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_items() {
+        use super::Item;
+        use {pins, reactions};
+        use testing::{Expectation, MockSender};
+
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("reactions.get").returns(r#"{"ok": true, "type": "message", "channel": "C1",
+                "message": {"type": "message", "ts": "1503435956.000247", "text": "hi",
+                            "reactions": [{"name": "tada", "count": 1, "users": ["U1"]}]}}"#).times(1))
+            .expect(Expectation::new("reactions.get").returns_error("message_not_found"))
+            .expect(Expectation::new("pins.list").returns(r#"{"ok": true, "items": [
+                {"type": "file", "created": 1503435956, "created_by": "U1", "file": {"id": "F1"}},
+                {"type": "file_comment", "file": {"id": "F1"}, "comment": {"id": "Fc1"}}]}"#));
+
+        let request = reactions::GetRequest::new().channel("C1").timestamp("1503435956.000247");
+        match reactions::get(&mock, "xoxb-1", &request).unwrap().item {
+            Some(Item::Message(ref item)) => {
+                assert_eq!("C1", item.channel);
+                assert_eq!(Some("1503435956.000247"), item.message.ts());
+                match item.message {
+                    Message::Standard(ref message) => {
+                        assert_eq!(Some("tada"), message.reactions.as_ref().and_then(|r| r[0].name.as_ref()).map(String::as_str));
+                    }
+                    ref other => panic!("expected a standard message, got {:?}", other),
+                }
+            }
+            other => panic!("expected a message, got {:?}", other),
+        }
+        match reactions::get(&mock, "xoxb-1", &request) {
+            Err(reactions::GetError::MessageNotFound) => {}
+            other => panic!("expected message_not_found, got {:?}", other),
+        }
+
+        let items = pins::list(&mock, "xoxb-1", &pins::ListRequest::new("C1")).unwrap().items.unwrap();
+        match (&items[0], &items[1]) {
+            (&Item::File(ref pin), &Item::FileComment(ref comment)) => {
+                assert_eq!((Some(1503435956), Some("U1")), (pin.created, pin.created_by.as_ref().map(|u| u.as_str())));
+                assert_eq!(Some("Fc1"), comment.comment.id.as_ref().map(String::as_str));
+            }
+            other => panic!("expected a file and a comment, got {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "preserve-unknown")]
+    fn test_unknown_fields_preserved() {
+        let bookmark: super::Bookmark = serde_json::from_str(r#"{"id": "Bk1", "type": "link", "shiny": {"new": true}}"#)
+            .unwrap();
+        assert_eq!(Some("link"), bookmark.ty.as_ref().map(String::as_str));
+        assert_eq!(vec!["shiny"], bookmark.extra.keys().collect::<Vec<_>>());
+        assert_eq!(Some(true), bookmark.extra["shiny"]["new"].as_bool());
+    }
+}
@@ -0,0 +1,118 @@
+//! Debug logging of requests and responses, enabled by the `log` feature.
+
+extern crate log;
+
+use requests::{SlackWebRequestSender, SlackWebResponse};
+
+/// Wraps a request sender, logging each request and response at `DEBUG` level under the
+/// `slack_api` target.
+///
+/// Lines are tagged with the [correlation ID](../correlation/index.html) of the call, if any.
+/// Tokens and other credentials are redacted from both the request parameters and the response
+/// body, and response bodies are truncated to [`Logged::max_body`] bytes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::logging::Logged;
+/// let client = Logged::new(slack_api::requests::default_client().unwrap()).max_body(4096);
+/// let response = slack_api::conversations::list(&client, "xoxb-...", &Default::default());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Logged<R> {
+    sender: R,
+    enabled: bool,
+    max_body: usize,
+}
+
+impl<R: SlackWebRequestSender> Logged<R> {
+    pub fn new(sender: R) -> Self {
+        Logged {
+            sender: sender,
+            enabled: true,
+            max_body: 1024,
+        }
+    }
+
+    /// Turns logging on or off for this sender. On by default.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// How many bytes of each response body to log. Defaults to 1024.
+    pub fn max_body(mut self, max_body: usize) -> Self {
+        self.max_body = max_body;
+        self
+    }
+
+    /// The wrapped request sender.
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+}
+
+impl<R: SlackWebRequestSender> SlackWebRequestSender for Logged<R> {
+    type Error = R::Error;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        if !self.enabled {
+            return self.sender.send_raw(method_url, params);
+        }
+        self.log(method_url, params, || self.sender.send_raw(method_url, params))
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        if !self.enabled {
+            return self.sender.send_json(method_url, token, body);
+        }
+        let params = ::requests::json_params(token, body);
+        let params = params.iter().map(|&(ref name, ref value)| (&name[..], &value[..])).collect::<Vec<_>>();
+        self.log(method_url, &params, || self.sender.send_json(method_url, token, body))
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        if !self.enabled {
+            return self.sender.send_form(method_url, params);
+        }
+        self.log(method_url, params, || self.sender.send_form(method_url, params))
+    }
+}
+
+impl<R: SlackWebRequestSender> Logged<R> {
+    fn log<F>(&self, method_url: &str, params: &[(&str, &str)], send: F) -> Result<SlackWebResponse, R::Error>
+        where F: FnOnce() -> Result<SlackWebResponse, R::Error>
+    {
+        let method = method_url.rsplit('/').next().unwrap_or(method_url);
+        let method = match ::correlation::current() {
+            Some(id) => format!("{} [{}]", method, id),
+            None => method.to_owned(),
+        };
+        log::debug!(target: "slack_api", "-> {} {}", method, ::secret::redact_params(params));
+        let result = send();
+        match result {
+            Ok(ref response) => {
+                let body = ::secret::redact_json(&response.body);
+                let status = response.status.map(|s| s.to_string()).unwrap_or_else(|| "-".to_owned());
+                log::debug!(target: "slack_api", "<- {} {} {}", method, status, truncate(&body, self.max_body));
+            }
+            Err(ref err) => log::debug!(target: "slack_api", "<- {} failed: {}", method, err),
+        }
+        result
+    }
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.len() <= max {
+        return s.to_owned();
+    }
+    let mut end = max;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}... ({} bytes)", &s[..end], s.len())
+}
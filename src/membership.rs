@@ -0,0 +1,285 @@
+//! Watching who joins and leaves channels, for tools that keep access control in sync with them.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::error::Error;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use conversations::{self, MembersError, MembersRequest};
+use requests::SlackWebRequestSender;
+
+/// A user joining or leaving a watched channel, as reported by a [`MembershipWatcher`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MembershipChange {
+    Joined { channel: String, user: String },
+    Left { channel: String, user: String },
+}
+
+/// What [`MembershipWatcher::poll`] found.
+#[derive(Debug)]
+pub struct PollReport<E: Error> {
+    /// Who joined and left the channels that could be polled.
+    pub changes: Vec<MembershipChange>,
+    /// The channels whose members could not be listed, and why. Their changes are reported by the
+    /// next poll that lists them.
+    pub failed: Vec<(String, MembersError<E>)>,
+}
+
+/// Keeps track of the members of a few channels, reporting who joined and left them.
+///
+/// Members are learned by polling `conversations.members`, with [`poll`](#method.poll) or the
+/// [`changes`](#method.changes) stream. Apps that receive `member_joined_channel` and
+/// `member_left_channel` events can pass them to [`event`](#method.event) too, to learn of
+/// changes between polls: a change is only reported once, whichever way it is learned first.
+///
+/// The first poll of a channel only learns its members, so it reports no changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use slack_api::membership::{MembershipChange, MembershipWatcher};
+/// let client = slack_api::requests::default_client().unwrap();
+/// let watcher = MembershipWatcher::new(&["C024BE91L", "G0A1B2C3D"]).interval(Duration::from_secs(300));
+///
+/// for change in watcher.changes(&client, "xoxb-...") {
+///     match change {
+///         Ok(MembershipChange::Joined { channel, user }) => println!("{} joined {}", user, channel),
+///         Ok(MembershipChange::Left { channel, user }) => println!("{} left {}", user, channel),
+///         Err((channel, err)) => println!("polling {} failed: {}", channel, err),
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MembershipWatcher {
+    channels: Vec<String>,
+    interval: Duration,
+    members: Mutex<HashMap<String, BTreeSet<String>>>,
+}
+
+impl MembershipWatcher {
+    /// Watches `channels`, a list of channel IDs.
+    pub fn new<S: AsRef<str>>(channels: &[S]) -> Self {
+        MembershipWatcher {
+            channels: channels.iter().map(|channel| channel.as_ref().to_owned()).collect(),
+            interval: Duration::from_secs(60),
+            members: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// How long [`changes`](#method.changes) waits between two polls. Defaults to a minute.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The members of `channel` as last learned, sorted, or `None` if it has not been polled yet.
+    pub fn members(&self, channel: &str) -> Option<Vec<String>> {
+        self.members.lock().unwrap().get(channel).map(|members| members.iter().cloned().collect())
+    }
+
+    /// Lists the members of every watched channel, returning who joined and left each since it
+    /// was last polled.
+    ///
+    /// A channel whose members cannot be listed does not stop the others from being polled: it is
+    /// reported among the failures, and keeps the members it was last known to have.
+    pub fn poll<R>(&self, client: &R, token: &str) -> PollReport<R::Error>
+        where R: SlackWebRequestSender
+    {
+        let mut changes = Vec::new();
+        let mut failed = Vec::new();
+        for channel in &self.channels {
+            let current = match list_members(client, token, channel) {
+                Ok(current) => current,
+                Err(err) => {
+                    failed.push((channel.clone(), err));
+                    continue;
+                }
+            };
+            let mut members = self.members.lock().unwrap();
+            if let Some(known) = members.get(channel) {
+                for user in current.difference(known) {
+                    changes.push(MembershipChange::Joined {
+                                     channel: channel.clone(),
+                                     user: user.clone(),
+                                 });
+                }
+                for user in known.difference(&current) {
+                    changes.push(MembershipChange::Left {
+                                     channel: channel.clone(),
+                                     user: user.clone(),
+                                 });
+                }
+            }
+            members.insert(channel.clone(), current);
+        }
+        PollReport {
+            changes: changes,
+            failed: failed,
+        }
+    }
+
+    /// Learns of a change from a `member_joined_channel` or `member_left_channel` event, returning
+    /// it unless it was already known. Other events, and events about channels that are not
+    /// watched or not polled yet, are ignored.
+    pub fn event(&self, event: &Value) -> Option<MembershipChange> {
+        let field = |name| event.get(name).and_then(Value::as_str);
+        let (channel, user) = (field("channel")?, field("user")?);
+        let mut members = self.members.lock().unwrap();
+        let known = members.get_mut(channel)?;
+        match field("type")? {
+            "member_joined_channel" if known.insert(user.to_owned()) => {
+                Some(MembershipChange::Joined {
+                         channel: channel.to_owned(),
+                         user: user.to_owned(),
+                     })
+            }
+            "member_left_channel" if known.remove(user) => {
+                Some(MembershipChange::Left {
+                         channel: channel.to_owned(),
+                         user: user.to_owned(),
+                     })
+            }
+            _ => None,
+        }
+    }
+
+    /// Polls the watched channels every [`interval`](#method.interval), forever, yielding each
+    /// change as it is found. A channel that cannot be polled yields its ID and the error, after
+    /// the changes of the others, and is tried again at the next interval.
+    pub fn changes<'a, R>(&'a self, client: &'a R, token: &'a str) -> Changes<'a, R>
+        where R: SlackWebRequestSender
+    {
+        Changes {
+            watcher: self,
+            client: client,
+            token: token,
+            pending: VecDeque::new(),
+            polled: false,
+        }
+    }
+}
+
+/// The changes found by polling a [`MembershipWatcher`], as returned by
+/// [`MembershipWatcher::changes`].
+pub struct Changes<'a, R: 'a + SlackWebRequestSender> {
+    watcher: &'a MembershipWatcher,
+    client: &'a R,
+    token: &'a str,
+    pending: VecDeque<Result<MembershipChange, (String, MembersError<R::Error>)>>,
+    polled: bool,
+}
+
+impl<'a, R: SlackWebRequestSender> Iterator for Changes<'a, R> {
+    type Item = Result<MembershipChange, (String, MembersError<R::Error>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Some(item);
+            }
+            if self.polled {
+                thread::sleep(self.watcher.interval);
+            }
+            self.polled = true;
+            let report = self.watcher.poll(self.client, self.token);
+            self.pending.extend(report.changes.into_iter().map(Ok));
+            self.pending.extend(report.failed.into_iter().map(Err));
+        }
+    }
+}
+
+/// Lists every page of the members of `channel`.
+fn list_members<R>(client: &R, token: &str, channel: &str) -> Result<BTreeSet<String>, MembersError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut members = BTreeSet::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let request = MembersRequest {
+            channel: channel,
+            cursor: cursor.as_ref().map(String::as_str),
+            limit: Some(1000),
+        };
+        let response = conversations::members(client, token, &request)?;
+        members.extend(response.members.unwrap_or_default());
+        cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            return Ok(members);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    fn joined(user: &str) -> MembershipChange {
+        MembershipChange::Joined {
+            channel: "C1".to_owned(),
+            user: user.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_poll_diffs_members() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.members").returns(r#"{"ok": true, "members": ["U1", "U2"]}"#).times(1))
+            .expect(Expectation::new("conversations.members").param("cursor", "next")
+                .returns(r#"{"ok": true, "members": ["U3"], "response_metadata": {"next_cursor": ""}}"#))
+            .expect(Expectation::new("conversations.members")
+                .returns(r#"{"ok": true, "members": ["U2"], "response_metadata": {"next_cursor": "next"}}"#));
+        let watcher = MembershipWatcher::new(&["C1"]);
+
+        assert_eq!(Vec::<MembershipChange>::new(), watcher.poll(&mock, "xoxb-1").changes);
+        let changes = watcher.poll(&mock, "xoxb-1").changes;
+        assert_eq!(vec![joined("U3"),
+                        MembershipChange::Left {
+                            channel: "C1".to_owned(),
+                            user: "U1".to_owned(),
+                        }],
+                   changes);
+        assert_eq!(Some(vec!["U2".to_owned(), "U3".to_owned()]), watcher.members("C1"));
+    }
+
+    #[test]
+    fn test_poll_goes_on_after_a_failing_channel() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.members").param("channel", "C0").returns_error("channel_not_found"))
+            .expect(Expectation::new("conversations.members").param("channel", "C1")
+                .returns(r#"{"ok": true, "members": ["U1"]}"#)
+                .times(1))
+            .expect(Expectation::new("conversations.members").param("channel", "C1")
+                .returns(r#"{"ok": true, "members": ["U1", "U2"]}"#));
+        let watcher = MembershipWatcher::new(&["C0", "C1"]);
+
+        assert_eq!(1, watcher.poll(&mock, "xoxb-1").failed.len());
+        let report = watcher.poll(&mock, "xoxb-1");
+        assert_eq!(vec![joined("U2")], report.changes);
+        assert_eq!(vec!["C0"], report.failed.iter().map(|&(ref channel, _)| &channel[..]).collect::<Vec<_>>());
+        assert_eq!(None, watcher.members("C0"));
+    }
+
+    #[test]
+    fn test_events_are_reported_once() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.members").returns(r#"{"ok": true, "members": ["U1"]}"#).times(1))
+            .expect(Expectation::new("conversations.members").returns(r#"{"ok": true, "members": ["U1", "U2"]}"#));
+        let watcher = MembershipWatcher::new(&["C1"]);
+        let event: Value = serde_json::from_str(r#"{"type": "member_joined_channel", "user": "U2", "channel": "C1"}"#)
+            .unwrap();
+
+        assert_eq!(None, watcher.event(&event));
+        watcher.poll(&mock, "xoxb-1");
+        assert_eq!(Some(joined("U2")), watcher.event(&event));
+        assert_eq!(None, watcher.event(&event));
+        assert!(watcher.poll(&mock, "xoxb-1").changes.is_empty());
+    }
+}
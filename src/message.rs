@@ -0,0 +1,430 @@
+//! Replying and reacting to messages.
+
+use std::error::Error;
+use std::fmt;
+
+use serde_json::Value;
+
+use chat;
+use client::SlackClient;
+use reactions;
+use requests::SlackWebRequestSender;
+use {ChannelId, Message, Timestamp};
+
+impl Message {
+    /// The message's own timestamp, which is also its ID within its channel.
+    ///
+    /// For edits and new replies this is the timestamp of the edited message or the thread's
+    /// parent, not of the event.
+    pub fn ts(&self) -> Option<&str> {
+        let ts = match self {
+            &Message::Standard(ref m) => &m.ts,
+            &Message::BotAdd(ref m) => &m.ts,
+            &Message::BotMessage(ref m) => &m.ts,
+            &Message::BotRemove(ref m) => &m.ts,
+            &Message::ChannelArchive(ref m) => &m.ts,
+            &Message::ChannelConvertToPrivate(ref m) => &m.ts,
+            &Message::ChannelJoin(ref m) => &m.ts,
+            &Message::ChannelLeave(ref m) => &m.ts,
+            &Message::ChannelName(ref m) => &m.ts,
+            &Message::ChannelPostingPermissions(ref m) => &m.ts,
+            &Message::ChannelPurpose(ref m) => &m.ts,
+            &Message::ChannelTopic(ref m) => &m.ts,
+            &Message::ChannelUnarchive(ref m) => &m.ts,
+            &Message::EkmAccessDenied(ref m) => &m.ts,
+            &Message::FileComment(ref m) => &m.ts,
+            &Message::FileMention(ref m) => &m.ts,
+            &Message::FileShare(ref m) => &m.ts,
+            &Message::GroupArchive(ref m) => &m.ts,
+            &Message::GroupJoin(ref m) => &m.ts,
+            &Message::GroupLeave(ref m) => &m.ts,
+            &Message::GroupName(ref m) => &m.ts,
+            &Message::GroupPurpose(ref m) => &m.ts,
+            &Message::GroupTopic(ref m) => &m.ts,
+            &Message::GroupUnarchive(ref m) => &m.ts,
+            &Message::HuddleThread(ref m) => &m.ts,
+            &Message::MeMessage(ref m) => &m.ts,
+            &Message::MessageChanged(ref m) => {
+                match m.message {
+                    Some(ref message) => &message.ts,
+                    None => &m.ts,
+                }
+            }
+            &Message::MessageDeleted(ref m) => &m.ts,
+            &Message::MessageReplied(ref m) => {
+                match m.message {
+                    Some(ref message) => &message.ts,
+                    None => &m.ts,
+                }
+            }
+            &Message::PinnedItem(ref m) => &m.ts,
+            &Message::ReminderAdd(ref m) => &m.ts,
+            &Message::ReplyBroadcast(ref m) => &m.ts,
+            &Message::SlackbotResponse(ref m) => &m.ts,
+            &Message::ThreadBroadcast(ref m) => &m.ts,
+            &Message::Tombstone(ref m) => &m.ts,
+            &Message::UnpinnedItem(ref m) => &m.ts,
+            &Message::Unknown(ref value) => return value.get("ts").and_then(Value::as_str),
+        };
+        ts.as_ref().map(Timestamp::as_str)
+    }
+
+    /// The channel the message was posted in, if Slack sent it along. Messages received as events
+    /// have it; messages read from a channel's history do not.
+    pub fn channel(&self) -> Option<&str> {
+        let channel = match self {
+            &Message::Standard(ref m) => &m.channel,
+            &Message::MeMessage(ref m) => &m.channel,
+            &Message::MessageChanged(ref m) => &m.channel,
+            &Message::MessageDeleted(ref m) => &m.channel,
+            &Message::MessageReplied(ref m) => &m.channel,
+            &Message::PinnedItem(ref m) => &m.channel,
+            &Message::UnpinnedItem(ref m) => &m.channel,
+            &Message::BotAdd(ref m) => &m.channel,
+            &Message::BotRemove(ref m) => &m.channel,
+            &Message::ChannelConvertToPrivate(ref m) => &m.channel,
+            &Message::HuddleThread(ref m) => &m.channel,
+            &Message::SlackbotResponse(ref m) => &m.channel,
+            &Message::ThreadBroadcast(ref m) => &m.channel,
+            &Message::Unknown(ref value) => return value.get("channel").and_then(Value::as_str),
+            _ => return None,
+        };
+        channel.as_ref().map(ChannelId::as_str)
+    }
+
+    /// The timestamp of the thread's parent, if the message is in a thread.
+    pub fn thread_ts(&self) -> Option<&str> {
+        let thread_ts = match self {
+            &Message::Standard(ref m) => &m.thread_ts,
+            &Message::BotMessage(ref m) => &m.thread_ts,
+            &Message::ThreadBroadcast(ref m) => &m.thread_ts,
+            &Message::Tombstone(ref m) => &m.thread_ts,
+            &Message::Unknown(ref value) => return value.get("thread_ts").and_then(Value::as_str),
+            &Message::MessageReplied(ref m) => {
+                match m.message {
+                    Some(ref message) => &message.thread_ts,
+                    None => return None,
+                }
+            }
+            _ => return None,
+        };
+        thread_ts.as_ref().map(Timestamp::as_str)
+    }
+
+    /// The message, for replying and reacting to it in `channel`. Needed for messages that
+    /// don't carry their [`channel`](#method.channel).
+    pub fn in_channel<'a>(&'a self, channel: &'a str) -> InChannel<'a> {
+        InChannel {
+            message: self,
+            channel: channel,
+        }
+    }
+
+    /// Replies to the message in its thread, starting one if it is not in a thread yet.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use slack_api::client::SlackClient;
+    /// # fn handle(message: slack_api::Message) {
+    /// let slack = SlackClient::new(slack_api::requests::default_client().unwrap(), "xoxb-...");
+    /// message.reply(&slack, "On it!").unwrap();
+    /// message.react(&slack, "eyes").unwrap();
+    /// # }
+    /// ```
+    pub fn reply<R>(&self,
+                    client: &SlackClient<R>,
+                    text: &str)
+                    -> Result<chat::PostMessageResponse, MessageError<chat::PostMessageError<R::Error>>>
+        where R: SlackWebRequestSender
+    {
+        let request = chat::PostMessageRequest { text: text, ..chat::PostMessageRequest::default() };
+        self.reply_with(client, &request)
+    }
+
+    /// Replies to the message in its thread with a message of your own making, e.g. with
+    /// `blocks` or `reply_broadcast` set. Its `channel` and `thread_ts` are filled in.
+    pub fn reply_with<R>(&self,
+                         client: &SlackClient<R>,
+                         request: &chat::PostMessageRequest)
+                         -> Result<chat::PostMessageResponse, MessageError<chat::PostMessageError<R::Error>>>
+        where R: SlackWebRequestSender
+    {
+        let channel = self.channel().ok_or(MessageError::NoChannel)?;
+        self.in_channel(channel).reply_with(client, request)
+    }
+
+    /// Adds the reaction `name` to the message, with or without its surrounding colons.
+    pub fn react<R>(&self,
+                    client: &SlackClient<R>,
+                    name: &str)
+                    -> Result<reactions::AddResponse, MessageError<reactions::AddError<R::Error>>>
+        where R: SlackWebRequestSender
+    {
+        let channel = self.channel().ok_or(MessageError::NoChannel)?;
+        self.in_channel(channel).react(client, name)
+    }
+}
+
+/// How Slack treats the text of a message it is sent, as the `parse` of e.g.
+/// [`chat::PostMessageRequest`](../chat/struct.PostMessageRequest.html).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Parse {
+    /// Finds and links the channel names, usernames and URLs in the text, and ignores its markup.
+    Full,
+    /// Takes the text as it is, markup included.
+    None,
+}
+
+impl Parse {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            &Parse::Full => "full",
+            &Parse::None => "none",
+        }
+    }
+}
+
+/// A [`Message`] and the channel it is in, as returned by [`Message::in_channel`].
+#[derive(Clone, Copy, Debug)]
+pub struct InChannel<'a> {
+    message: &'a Message,
+    channel: &'a str,
+}
+
+impl<'a> InChannel<'a> {
+    /// Replies to the message in its thread, starting one if it is not in a thread yet.
+    pub fn reply<R>(&self,
+                    client: &SlackClient<R>,
+                    text: &str)
+                    -> Result<chat::PostMessageResponse, MessageError<chat::PostMessageError<R::Error>>>
+        where R: SlackWebRequestSender
+    {
+        let request = chat::PostMessageRequest { text: text, ..chat::PostMessageRequest::default() };
+        self.reply_with(client, &request)
+    }
+
+    /// Replies to the message in its thread with `request`, filling in its `channel` and
+    /// `thread_ts`.
+    pub fn reply_with<R>(&self,
+                         client: &SlackClient<R>,
+                         request: &chat::PostMessageRequest)
+                         -> Result<chat::PostMessageResponse, MessageError<chat::PostMessageError<R::Error>>>
+        where R: SlackWebRequestSender
+    {
+        let thread_ts = self.message.thread_ts().or(self.message.ts()).ok_or(MessageError::NoTs)?;
+        let request = chat::PostMessageRequest {
+            channel: self.channel,
+            thread_ts: Some(thread_ts),
+            ..request.clone()
+        };
+        client.call(|sender, token| chat::post_message(sender, token, &request)).map_err(MessageError::Api)
+    }
+
+    /// Adds the reaction `name` to the message, with or without its surrounding colons.
+    pub fn react<R>(&self,
+                    client: &SlackClient<R>,
+                    name: &str)
+                    -> Result<reactions::AddResponse, MessageError<reactions::AddError<R::Error>>>
+        where R: SlackWebRequestSender
+    {
+        let request = reactions::AddRequest {
+            name: name.trim_matches(':'),
+            channel: Some(self.channel),
+            timestamp: Some(self.message.ts().ok_or(MessageError::NoTs)?),
+            ..reactions::AddRequest::default()
+        };
+        client.call(|sender, token| reactions::add(sender, token, &request)).map_err(MessageError::Api)
+    }
+}
+
+/// What [`toggle_reaction`] did.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Toggled {
+    Added,
+    Removed,
+}
+
+/// Adds the reaction `name` to the message `ts` in `channel`, or removes it if the calling user
+/// has reacted with it already, e.g. to approve and unapprove by reaction.
+///
+/// `name` may be given with or without its surrounding colons.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::message::{self, Toggled};
+/// let client = slack_api::requests::default_client().unwrap();
+/// match message::toggle_reaction(&client, "xoxb-...", "C1", "1503435956.000247", "white_check_mark").unwrap() {
+///     Toggled::Added => println!("approved"),
+///     Toggled::Removed => println!("unapproved"),
+/// }
+/// ```
+pub fn toggle_reaction<R>(client: &R,
+                          token: &str,
+                          channel: &str,
+                          ts: &str,
+                          name: &str)
+                          -> Result<Toggled, ToggleError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let name = name.trim_matches(':');
+    let request = reactions::AddRequest {
+        name: name,
+        channel: Some(channel),
+        timestamp: Some(ts),
+        ..reactions::AddRequest::default()
+    };
+    match reactions::add(client, token, &request) {
+        Ok(_) => return Ok(Toggled::Added),
+        Err(reactions::AddError::AlreadyReacted) => {}
+        Err(err) => return Err(ToggleError::Add(err)),
+    }
+
+    let request = reactions::RemoveRequest {
+        name: name,
+        channel: Some(channel),
+        timestamp: Some(ts),
+        ..reactions::RemoveRequest::default()
+    };
+    match reactions::remove(client, token, &request) {
+        // Someone removed it in between; it is gone either way.
+        Ok(_) |
+        Err(reactions::RemoveError::NoReaction) => Ok(Toggled::Removed),
+        Err(err) => Err(ToggleError::Remove(err)),
+    }
+}
+
+#[derive(Debug)]
+pub enum ToggleError<E: Error> {
+    /// Adding the reaction failed.
+    Add(reactions::AddError<E>),
+    /// The reaction was there already, and removing it failed.
+    Remove(reactions::RemoveError<E>),
+}
+
+impl<E: Error> fmt::Display for ToggleError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ToggleError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ToggleError::Add(ref inner) => inner.description(),
+            &ToggleError::Remove(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ToggleError::Add(ref inner) => Some(inner),
+            &ToggleError::Remove(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MessageError<E: Error> {
+    /// The message does not say which channel it is in; use [`Message::in_channel`].
+    NoChannel,
+    /// The message has no timestamp to refer to it by.
+    NoTs,
+    /// The API call failed.
+    Api(E),
+}
+
+impl<E: Error> fmt::Display for MessageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for MessageError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &MessageError::NoChannel => "the message does not say which channel it is in",
+            &MessageError::NoTs => "the message has no timestamp",
+            &MessageError::Api(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &MessageError::NoChannel => None,
+            &MessageError::NoTs => None,
+            &MessageError::Api(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_reply_and_react() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage")
+                .param("channel", "C1")
+                .param("thread_ts", "1.1")
+                .returns(r#"{"ok": true, "ts": "1.3"}"#)
+                .times(1))
+            .expect(Expectation::new("reactions.add").param("name", "eyes").param("timestamp", "1.2").times(1));
+        let slack = SlackClient::new(mock, "xoxb-1");
+        let message: Message =
+            serde_json::from_str(r#"{"type": "message", "channel": "C1", "ts": "1.2", "thread_ts": "1.1", "text": "help"}"#)
+                .unwrap();
+
+        assert_eq!(Some("1.3".to_owned()), message.reply(&slack, "On it!").unwrap().ts);
+        message.react(&slack, ":eyes:").unwrap();
+        slack.sender().verify();
+
+        let history: Message = serde_json::from_str(r#"{"type": "message", "ts": "1.2", "text": "help"}"#).unwrap();
+        match history.react(&slack, "eyes") {
+            Err(MessageError::NoChannel) => {}
+            other => panic!("expected the channel to be missing, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_toggle_reaction() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("reactions.add").times(1))
+            .expect(Expectation::new("reactions.add").returns_error("already_reacted"))
+            .expect(Expectation::new("reactions.remove").param("name", "ok").times(1));
+
+        assert_eq!(Toggled::Added, toggle_reaction(&mock, "xoxb-1", "C1", "1.2", ":ok:").unwrap());
+        assert_eq!(Toggled::Removed, toggle_reaction(&mock, "xoxb-1", "C1", "1.2", ":ok:").unwrap());
+        mock.verify();
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postEphemeral")
+                .param("parse", "full")
+                .param("link_names", "1")
+                .returns(r#"{"ok": true, "message_ts": "1.3"}"#)
+                .times(1))
+            .expect(Expectation::new("chat.update").param("parse", "none").returns(r#"{"ok": true}"#).times(1));
+
+        let ephemeral = chat::PostEphemeralRequest {
+            channel: "C1",
+            text: "@alice see #deploys",
+            user: "U1",
+            parse: Some(Parse::Full),
+            link_names: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(Some("1.3".to_owned()), chat::post_ephemeral(&mock, "xoxb-1", &ephemeral).unwrap().message_ts);
+        let update = chat::UpdateRequest { channel: "C1", ts: "1.2", text: "*as is*", parse: Some(Parse::None), ..Default::default() };
+        chat::update(&mock, "xoxb-1", &update).unwrap();
+        mock.verify();
+    }
+}
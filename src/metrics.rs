@@ -0,0 +1,246 @@
+//! Hooks for exporting per-call metrics, e.g. to Prometheus or StatsD.
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+use breaker::BreakerState;
+use rate_limit::RateLimitEvent;
+use requests::{SlackWebRequestSender, SlackWebResponse};
+
+/// What happened during one API call.
+#[derive(Clone, Debug)]
+pub struct CallMetrics<'a> {
+    /// The API method, e.g. `chat.postMessage`.
+    pub method: &'a str,
+    /// The [correlation ID](../correlation/index.html) of the call, if any.
+    pub correlation_id: Option<&'a str>,
+    /// How long the call took, including any time spent waiting on rate limits.
+    pub latency: Duration,
+    /// The HTTP status, if the sender reports it.
+    pub status: Option<u16>,
+    /// Whether the call reached Slack and Slack reported success.
+    pub ok: bool,
+    /// The error code Slack reported, if any.
+    pub error: Option<&'a str>,
+    /// How long the call waited because of rate limiting, if the sender waits on rate limits.
+    pub rate_limit_wait: Duration,
+    /// The size of the encoded request parameters, in bytes.
+    pub request_bytes: usize,
+    /// The size of the response body, in bytes. Zero if the request failed to send.
+    pub response_bytes: usize,
+}
+
+/// Receives the metrics of every call made through a [`Metered`] sender.
+///
+/// Implementations must be cheap, as they run on the calling thread.
+pub trait Metrics {
+    /// Called once a call has completed, successfully or not.
+    fn record(&self, call: &CallMetrics) {
+        let _ = call;
+    }
+
+    /// Called when a [`CircuitBreaker`](../breaker/struct.CircuitBreaker.html) changes state.
+    fn breaker_state(&self, state: BreakerState) {
+        let _ = state;
+    }
+
+    /// Called when the rate limiting of a method by a
+    /// [`RateLimiter`](../rate_limit/struct.RateLimiter.html) changes.
+    fn rate_limit(&self, event: &RateLimitEvent) {
+        let _ = event;
+    }
+}
+
+/// Metrics that are discarded.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoMetrics;
+
+impl Metrics for NoMetrics {}
+
+impl<'a, M: Metrics + ?Sized> Metrics for &'a M {
+    fn record(&self, call: &CallMetrics) {
+        (**self).record(call)
+    }
+
+    fn breaker_state(&self, state: BreakerState) {
+        (**self).breaker_state(state)
+    }
+
+    fn rate_limit(&self, event: &RateLimitEvent) {
+        (**self).rate_limit(event)
+    }
+}
+
+/// Wraps a request sender, reporting every call it makes to a [`Metrics`] implementation.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::metrics::{CallMetrics, Metered, Metrics};
+/// struct Log;
+///
+/// impl Metrics for Log {
+///     fn record(&self, call: &CallMetrics) {
+///         println!("{} took {:?} ({:?})", call.method, call.latency, call.error);
+///     }
+/// }
+///
+/// let client = Metered::new(slack_api::requests::default_client().unwrap(), Log);
+/// let response = slack_api::conversations::list(&client, "xoxb-...", &Default::default());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Metered<R, M> {
+    sender: R,
+    metrics: M,
+}
+
+impl<R: SlackWebRequestSender, M: Metrics> Metered<R, M> {
+    pub fn new(sender: R, metrics: M) -> Self {
+        Metered {
+            sender: sender,
+            metrics: metrics,
+        }
+    }
+
+    /// The wrapped request sender.
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+}
+
+impl<R: SlackWebRequestSender, M: Metrics> SlackWebRequestSender for Metered<R, M> {
+    type Error = R::Error;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        let request_bytes = params.iter().map(|&(name, value)| name.len() + value.len() + 2).sum();
+        self.meter(method_url, request_bytes, || self.sender.send_raw(method_url, params))
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.meter(method_url, body.len(), || self.sender.send_json(method_url, token, body))
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        let request_bytes = params.iter().map(|&(name, value)| name.len() + value.len() + 2).sum();
+        self.meter(method_url, request_bytes, || self.sender.send_form(method_url, params))
+    }
+}
+
+impl<R: SlackWebRequestSender, M: Metrics> Metered<R, M> {
+    fn meter<F>(&self, method_url: &str, request_bytes: usize, send: F) -> Result<SlackWebResponse, R::Error>
+        where F: FnOnce() -> Result<SlackWebResponse, R::Error>
+    {
+        let start = Instant::now();
+        let result = send();
+        let latency = start.elapsed();
+
+        let outcome = result.as_ref().ok().and_then(SlackWebResponse::outcome);
+        let correlation_id = ::correlation::current();
+        self.metrics.record(&CallMetrics {
+            method: method_url.rsplit('/').next().unwrap_or(method_url),
+            correlation_id: correlation_id.as_ref().map(|id| &id[..]),
+            latency: latency,
+            status: result.as_ref().ok().and_then(|response| response.status),
+            ok: outcome.as_ref().map(|o| o.ok).unwrap_or(false),
+            error: outcome.as_ref().and_then(|o| o.error.as_ref()).map(|e| &e[..]),
+            rate_limit_wait: result.as_ref().map(|response| response.rate_limit_wait).unwrap_or_default(),
+            request_bytes: request_bytes,
+            response_bytes: result.as_ref().map(|response| response.body.len()).unwrap_or(0),
+        });
+        result
+    }
+}
+
+/// The result of a call, with metadata about how it was made. See [`with_meta`].
+#[derive(Clone, Debug)]
+pub struct WithMeta<T> {
+    pub value: T,
+    pub meta: CallMeta,
+}
+
+/// How a call was made, e.g. for SLO accounting.
+#[derive(Clone, Debug, Default)]
+pub struct CallMeta {
+    /// How long the call took in total.
+    pub duration: Duration,
+    /// How many HTTP requests were made, including retries.
+    pub attempts: u32,
+    /// How long was spent waiting on rate limits, if the sender waits on rate limits.
+    pub rate_limit_wait: Duration,
+    /// The HTTP status of the last response, if the sender reports it.
+    pub status: Option<u16>,
+}
+
+/// Makes a call through `sender`, returning its result together with its [`CallMeta`].
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+/// let response = slack_api::metrics::with_meta(&client, |client| {
+///     slack_api::conversations::list(client, "xoxb-...", &Default::default())
+/// });
+/// println!("channels.list took {:?} over {} attempts", response.meta.duration, response.meta.attempts);
+/// ```
+pub fn with_meta<R, F, T>(sender: &R, call: F) -> WithMeta<T>
+    where R: SlackWebRequestSender,
+          F: FnOnce(&MetaRecorder<R>) -> T
+{
+    let recorder = MetaRecorder {
+        sender: sender,
+        meta: RefCell::new(CallMeta::default()),
+    };
+    let start = Instant::now();
+    let value = call(&recorder);
+    let mut meta = recorder.meta.into_inner();
+    meta.duration = start.elapsed();
+    WithMeta {
+        value: value,
+        meta: meta,
+    }
+}
+
+/// The sender [`with_meta`] hands to its call, collecting the [`CallMeta`] of the requests made
+/// through it.
+#[derive(Debug)]
+pub struct MetaRecorder<'a, R: 'a> {
+    sender: &'a R,
+    meta: RefCell<CallMeta>,
+}
+
+impl<'a, R: SlackWebRequestSender> SlackWebRequestSender for MetaRecorder<'a, R> {
+    type Error = R::Error;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.record(self.sender.send_raw(method_url, params))
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.record(self.sender.send_json(method_url, token, body))
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.record(self.sender.send_form(method_url, params))
+    }
+}
+
+impl<'a, R: SlackWebRequestSender> MetaRecorder<'a, R> {
+    fn record(&self, result: Result<SlackWebResponse, R::Error>) -> Result<SlackWebResponse, R::Error> {
+        let mut meta = self.meta.borrow_mut();
+        meta.attempts += 1;
+        if let Ok(ref response) = result {
+            meta.attempts += response.retries;
+            meta.rate_limit_wait += response.rate_limit_wait;
+            meta.status = response.status;
+        }
+        result
+    }
+}
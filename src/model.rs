@@ -0,0 +1,98 @@
+//! The `model!` macro the shared types are declared with.
+//!
+//! Without the `preserve-unknown` feature it only derives what the types always derived. With
+//! it, each type also gets an `extra` map holding the fields Slack sent that the type does not
+//! have, so that a program can read a field Slack added before this crate knows about it.
+
+#[cfg(not(feature = "preserve-unknown"))]
+macro_rules! model {
+    ($(#[$attr:meta])* pub struct $name:ident {
+        $($(#[$field_attr:meta])* pub $field:ident: $ty:ty,)*
+    }) => {
+        #[derive(Clone, Debug, Deserialize)]
+        $(#[$attr])*
+        pub struct $name {
+            $($(#[$field_attr])* pub $field: $ty,)*
+        }
+    }
+}
+
+#[cfg(feature = "preserve-unknown")]
+macro_rules! model {
+    ($(#[$attr:meta])* pub struct $name:ident {
+        $($(#[$field_attr:meta])* pub $field:ident: $ty:ty,)*
+    }) => {
+        #[derive(Clone, Debug)]
+        $(#[$attr])*
+        pub struct $name {
+            $(pub $field: $ty,)*
+            /// The fields Slack sent that this type does not have, as they were sent.
+            pub extra: ::std::collections::HashMap<String, ::serde_json::Value>,
+        }
+
+        impl ::serde::Deserialize for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where D: ::serde::Deserializer
+            {
+                use serde::de::Error as SerdeError;
+
+                #[derive(Deserialize)]
+                struct Fields {
+                    $($(#[$field_attr])* $field: $ty,)*
+                }
+
+                let mut object = match ::serde_json::Value::deserialize(deserializer)? {
+                    ::serde_json::Value::Object(object) => object,
+                    _ => return Err(D::Error::invalid_type(::serde::de::Unexpected::Other("not an object"), &"an object")),
+                };
+                #[allow(unused_variables)]
+                let fields = ::serde_json::from_value::<Fields>(::serde_json::Value::Object(object.clone()))
+                    .map_err(|e| D::Error::custom(&format!("{}", e)))?;
+                for name in ::model::field_names::<Fields>() {
+                    object.remove(*name);
+                }
+                Ok($name {
+                    $($field: fields.$field,)*
+                    extra: object.into_iter().collect(),
+                })
+            }
+        }
+    }
+}
+
+/// The names of the fields of `T` in the JSON it is deserialized from, which its derived
+/// implementation hands to the deserializer.
+#[cfg(feature = "preserve-unknown")]
+pub fn field_names<T: ::serde::Deserialize>() -> &'static [&'static str] {
+    use std::cell::Cell;
+    use serde::de::{self, Deserializer, Visitor};
+
+    struct FieldNames<'a>(&'a Cell<&'static [&'static str]>);
+
+    impl<'a> Deserializer for FieldNames<'a> {
+        type Error = de::value::Error;
+
+        fn deserialize<V: Visitor>(self, _: V) -> Result<V::Value, Self::Error> {
+            Err(de::Error::custom("only structs have field names"))
+        }
+
+        fn deserialize_struct<V: Visitor>(self,
+                                          _: &'static str,
+                                          fields: &'static [&'static str],
+                                          _: V)
+                                          -> Result<V::Value, Self::Error> {
+            self.0.set(fields);
+            Err(de::Error::custom("only the field names are read"))
+        }
+
+        forward_to_deserialize! {
+            bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string unit option seq
+            seq_fixed_size bytes byte_buf map unit_struct newtype_struct tuple_struct
+            struct_field tuple enum ignored_any
+        }
+    }
+
+    let fields = Cell::new(&[][..]);
+    let _ = T::deserialize(FieldNames(&fields));
+    fields.get()
+}
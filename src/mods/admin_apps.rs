@@ -0,0 +1,1039 @@
+//! Manage the apps installed in an Enterprise Grid organization.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Approve an app for installation on a workspace.
+///
+/// Wraps https://api.slack.com/methods/admin.apps.approve
+
+pub fn approve<R>(client: &R,
+                  token: &str,
+                  request: &ApproveRequest)
+                  -> Result<ApproveResponse, ApproveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(app_id) = request.app_id {
+        body.insert("app_id".to_owned(), app_id.into());
+    }
+    if let Some(request_id) = request.request_id {
+        body.insert("request_id".to_owned(), request_id.into());
+    }
+    if let Some(team_id) = request.team_id {
+        body.insert("team_id".to_owned(), team_id.into());
+    }
+    let url = ::get_slack_url_for_method("admin.apps.approve");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| ApproveError::Client(err))
+        .and_then(|result| {
+                      parse_approve_response(result.body.as_bytes()).map_err(|err| err.into_error(ApproveError::MalformedResponse))
+                  })
+}
+
+fn parse_approve_response(body: &[u8]) -> Result<ApproveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ApproveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ApproveRequest<'a> {
+    /// The id of the app to act on.
+    pub app_id: Option<&'a str>,
+    /// The id of the request to act on.
+    pub request_id: Option<&'a str>,
+    /// The workspace to act in. Required for workspace-level tokens.
+    pub team_id: Option<&'a str>,
+}
+
+impl<'a> ApproveRequest<'a> {
+    pub fn new() -> Self {
+        ApproveRequest::default()
+    }
+
+    pub fn app_id(mut self, app_id: &'a str) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    pub fn request_id(mut self, request_id: &'a str) -> Self {
+        self.request_id = Some(request_id);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ApproveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ApproveResponse, ApproveError<E>>> for ApproveResponse {
+    fn into(self) -> Result<ApproveResponse, ApproveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ApproveError<E: Error> {
+    /// The app was not found.
+    AppNotFound,
+    /// The request was not found.
+    RequestNotFound,
+    /// The app id is not valid.
+    InvalidAppId,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ApproveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "app_not_found" => ApproveError::AppNotFound,
+            "request_not_found" => ApproveError::RequestNotFound,
+            "invalid_app_id" => ApproveError::InvalidAppId,
+            "feature_not_enabled" => ApproveError::FeatureNotEnabled,
+            "not_an_admin" => ApproveError::NotAnAdmin,
+            "not_an_enterprise" => ApproveError::NotAnEnterprise,
+            "team_not_found" => ApproveError::TeamNotFound,
+            "missing_scope" => ApproveError::MissingScope,
+            "not_allowed_token_type" => ApproveError::NotAllowedTokenType,
+            "not_authed" => ApproveError::NotAuthed,
+            "invalid_auth" => ApproveError::InvalidAuth,
+            "account_inactive" => ApproveError::AccountInactive,
+            "invalid_arg_name" => ApproveError::InvalidArgName,
+            "invalid_array_arg" => ApproveError::InvalidArrayArg,
+            "invalid_charset" => ApproveError::InvalidCharset,
+            "invalid_form_data" => ApproveError::InvalidFormData,
+            "invalid_post_type" => ApproveError::InvalidPostType,
+            "missing_post_type" => ApproveError::MissingPostType,
+            "team_added_to_org" => ApproveError::TeamAddedToOrg,
+            "request_timeout" => ApproveError::RequestTimeout,
+            _ => ApproveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ApproveError<E>> for ::Error<E> {
+    fn from(err: ApproveError<E>) -> Self {
+        let code = match err {
+            ApproveError::AppNotFound => "app_not_found",
+            ApproveError::RequestNotFound => "request_not_found",
+            ApproveError::InvalidAppId => "invalid_app_id",
+            ApproveError::FeatureNotEnabled => "feature_not_enabled",
+            ApproveError::NotAnAdmin => "not_an_admin",
+            ApproveError::NotAnEnterprise => "not_an_enterprise",
+            ApproveError::TeamNotFound => "team_not_found",
+            ApproveError::MissingScope => "missing_scope",
+            ApproveError::NotAllowedTokenType => "not_allowed_token_type",
+            ApproveError::NotAuthed => "not_authed",
+            ApproveError::InvalidAuth => "invalid_auth",
+            ApproveError::AccountInactive => "account_inactive",
+            ApproveError::InvalidArgName => "invalid_arg_name",
+            ApproveError::InvalidArrayArg => "invalid_array_arg",
+            ApproveError::InvalidCharset => "invalid_charset",
+            ApproveError::InvalidFormData => "invalid_form_data",
+            ApproveError::InvalidPostType => "invalid_post_type",
+            ApproveError::MissingPostType => "missing_post_type",
+            ApproveError::TeamAddedToOrg => "team_added_to_org",
+            ApproveError::RequestTimeout => "request_timeout",
+            ApproveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ApproveError::Unknown(code) => return ::Error::from_code("admin.apps.approve", &code),
+            ApproveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.apps.approve", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ApproveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ApproveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ApproveError::AppNotFound => "app_not_found: The app was not found.",
+            &ApproveError::RequestNotFound => "request_not_found: The request was not found.",
+            &ApproveError::InvalidAppId => "invalid_app_id: The app id is not valid.",
+            &ApproveError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &ApproveError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &ApproveError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &ApproveError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &ApproveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ApproveError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &ApproveError::NotAuthed => "not_authed: No authentication token provided.",
+            &ApproveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ApproveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ApproveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ApproveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ApproveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ApproveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ApproveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ApproveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ApproveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ApproveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ApproveError::MalformedResponse(ref e) => e.description(),
+            &ApproveError::Unknown(ref s) => s,
+            &ApproveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ApproveError::MalformedResponse(ref e) => Some(e),
+            &ApproveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Clear an app resolution
+///
+/// Wraps https://api.slack.com/methods/admin.apps.clearResolution
+
+pub fn clear_resolution<R>(client: &R,
+                           token: &str,
+                           request: &ClearResolutionRequest)
+                           -> Result<ClearResolutionResponse, ClearResolutionError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("app_id".to_owned(), request.app_id.into());
+    if let Some(enterprise_id) = request.enterprise_id {
+        body.insert("enterprise_id".to_owned(), enterprise_id.into());
+    }
+    if let Some(team_id) = request.team_id {
+        body.insert("team_id".to_owned(), team_id.into());
+    }
+    let url = ::get_slack_url_for_method("admin.apps.clearResolution");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| ClearResolutionError::Client(err))
+        .and_then(|result| {
+                      parse_clear_resolution_response(result.body.as_bytes()).map_err(|err| err.into_error(ClearResolutionError::MalformedResponse))
+                  })
+}
+
+fn parse_clear_resolution_response(body: &[u8]) -> Result<ClearResolutionResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ClearResolutionResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ClearResolutionRequest<'a> {
+    /// The id of the app to act on.
+    pub app_id: &'a str,
+    /// The enterprise to act in.
+    pub enterprise_id: Option<&'a str>,
+    /// The workspace to act in. Required for workspace-level tokens.
+    pub team_id: Option<&'a str>,
+}
+
+impl<'a> ClearResolutionRequest<'a> {
+    pub fn new(app_id: &'a str) -> Self {
+        ClearResolutionRequest {
+            app_id: app_id,
+            ..ClearResolutionRequest::default()
+        }
+    }
+
+    pub fn enterprise_id(mut self, enterprise_id: &'a str) -> Self {
+        self.enterprise_id = Some(enterprise_id);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ClearResolutionResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ClearResolutionResponse, ClearResolutionError<E>>> for ClearResolutionResponse {
+    fn into(self) -> Result<ClearResolutionResponse, ClearResolutionError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ClearResolutionError<E: Error> {
+    /// The app was not found.
+    AppNotFound,
+    /// The request was not found.
+    RequestNotFound,
+    /// The app id is not valid.
+    InvalidAppId,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ClearResolutionError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "app_not_found" => ClearResolutionError::AppNotFound,
+            "request_not_found" => ClearResolutionError::RequestNotFound,
+            "invalid_app_id" => ClearResolutionError::InvalidAppId,
+            "feature_not_enabled" => ClearResolutionError::FeatureNotEnabled,
+            "not_an_admin" => ClearResolutionError::NotAnAdmin,
+            "not_an_enterprise" => ClearResolutionError::NotAnEnterprise,
+            "team_not_found" => ClearResolutionError::TeamNotFound,
+            "missing_scope" => ClearResolutionError::MissingScope,
+            "not_allowed_token_type" => ClearResolutionError::NotAllowedTokenType,
+            "not_authed" => ClearResolutionError::NotAuthed,
+            "invalid_auth" => ClearResolutionError::InvalidAuth,
+            "account_inactive" => ClearResolutionError::AccountInactive,
+            "invalid_arg_name" => ClearResolutionError::InvalidArgName,
+            "invalid_array_arg" => ClearResolutionError::InvalidArrayArg,
+            "invalid_charset" => ClearResolutionError::InvalidCharset,
+            "invalid_form_data" => ClearResolutionError::InvalidFormData,
+            "invalid_post_type" => ClearResolutionError::InvalidPostType,
+            "missing_post_type" => ClearResolutionError::MissingPostType,
+            "team_added_to_org" => ClearResolutionError::TeamAddedToOrg,
+            "request_timeout" => ClearResolutionError::RequestTimeout,
+            _ => ClearResolutionError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ClearResolutionError<E>> for ::Error<E> {
+    fn from(err: ClearResolutionError<E>) -> Self {
+        let code = match err {
+            ClearResolutionError::AppNotFound => "app_not_found",
+            ClearResolutionError::RequestNotFound => "request_not_found",
+            ClearResolutionError::InvalidAppId => "invalid_app_id",
+            ClearResolutionError::FeatureNotEnabled => "feature_not_enabled",
+            ClearResolutionError::NotAnAdmin => "not_an_admin",
+            ClearResolutionError::NotAnEnterprise => "not_an_enterprise",
+            ClearResolutionError::TeamNotFound => "team_not_found",
+            ClearResolutionError::MissingScope => "missing_scope",
+            ClearResolutionError::NotAllowedTokenType => "not_allowed_token_type",
+            ClearResolutionError::NotAuthed => "not_authed",
+            ClearResolutionError::InvalidAuth => "invalid_auth",
+            ClearResolutionError::AccountInactive => "account_inactive",
+            ClearResolutionError::InvalidArgName => "invalid_arg_name",
+            ClearResolutionError::InvalidArrayArg => "invalid_array_arg",
+            ClearResolutionError::InvalidCharset => "invalid_charset",
+            ClearResolutionError::InvalidFormData => "invalid_form_data",
+            ClearResolutionError::InvalidPostType => "invalid_post_type",
+            ClearResolutionError::MissingPostType => "missing_post_type",
+            ClearResolutionError::TeamAddedToOrg => "team_added_to_org",
+            ClearResolutionError::RequestTimeout => "request_timeout",
+            ClearResolutionError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ClearResolutionError::Unknown(code) => return ::Error::from_code("admin.apps.clearResolution", &code),
+            ClearResolutionError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.apps.clearResolution", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ClearResolutionError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ClearResolutionError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ClearResolutionError::AppNotFound => "app_not_found: The app was not found.",
+            &ClearResolutionError::RequestNotFound => "request_not_found: The request was not found.",
+            &ClearResolutionError::InvalidAppId => "invalid_app_id: The app id is not valid.",
+            &ClearResolutionError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &ClearResolutionError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &ClearResolutionError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &ClearResolutionError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &ClearResolutionError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ClearResolutionError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &ClearResolutionError::NotAuthed => "not_authed: No authentication token provided.",
+            &ClearResolutionError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ClearResolutionError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ClearResolutionError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ClearResolutionError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ClearResolutionError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ClearResolutionError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ClearResolutionError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ClearResolutionError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ClearResolutionError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ClearResolutionError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ClearResolutionError::MalformedResponse(ref e) => e.description(),
+            &ClearResolutionError::Unknown(ref s) => s,
+            &ClearResolutionError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ClearResolutionError::MalformedResponse(ref e) => Some(e),
+            &ClearResolutionError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Restrict an app for installation on a workspace.
+///
+/// Wraps https://api.slack.com/methods/admin.apps.restrict
+
+pub fn restrict<R>(client: &R,
+                   token: &str,
+                   request: &RestrictRequest)
+                   -> Result<RestrictResponse, RestrictError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(app_id) = request.app_id {
+        body.insert("app_id".to_owned(), app_id.into());
+    }
+    if let Some(request_id) = request.request_id {
+        body.insert("request_id".to_owned(), request_id.into());
+    }
+    if let Some(team_id) = request.team_id {
+        body.insert("team_id".to_owned(), team_id.into());
+    }
+    let url = ::get_slack_url_for_method("admin.apps.restrict");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| RestrictError::Client(err))
+        .and_then(|result| {
+                      parse_restrict_response(result.body.as_bytes()).map_err(|err| err.into_error(RestrictError::MalformedResponse))
+                  })
+}
+
+fn parse_restrict_response(body: &[u8]) -> Result<RestrictResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RestrictResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RestrictRequest<'a> {
+    /// The id of the app to act on.
+    pub app_id: Option<&'a str>,
+    /// The id of the request to act on.
+    pub request_id: Option<&'a str>,
+    /// The workspace to act in. Required for workspace-level tokens.
+    pub team_id: Option<&'a str>,
+}
+
+impl<'a> RestrictRequest<'a> {
+    pub fn new() -> Self {
+        RestrictRequest::default()
+    }
+
+    pub fn app_id(mut self, app_id: &'a str) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    pub fn request_id(mut self, request_id: &'a str) -> Self {
+        self.request_id = Some(request_id);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RestrictResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RestrictResponse, RestrictError<E>>> for RestrictResponse {
+    fn into(self) -> Result<RestrictResponse, RestrictError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RestrictError<E: Error> {
+    /// The app was not found.
+    AppNotFound,
+    /// The request was not found.
+    RequestNotFound,
+    /// The app id is not valid.
+    InvalidAppId,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RestrictError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "app_not_found" => RestrictError::AppNotFound,
+            "request_not_found" => RestrictError::RequestNotFound,
+            "invalid_app_id" => RestrictError::InvalidAppId,
+            "feature_not_enabled" => RestrictError::FeatureNotEnabled,
+            "not_an_admin" => RestrictError::NotAnAdmin,
+            "not_an_enterprise" => RestrictError::NotAnEnterprise,
+            "team_not_found" => RestrictError::TeamNotFound,
+            "missing_scope" => RestrictError::MissingScope,
+            "not_allowed_token_type" => RestrictError::NotAllowedTokenType,
+            "not_authed" => RestrictError::NotAuthed,
+            "invalid_auth" => RestrictError::InvalidAuth,
+            "account_inactive" => RestrictError::AccountInactive,
+            "invalid_arg_name" => RestrictError::InvalidArgName,
+            "invalid_array_arg" => RestrictError::InvalidArrayArg,
+            "invalid_charset" => RestrictError::InvalidCharset,
+            "invalid_form_data" => RestrictError::InvalidFormData,
+            "invalid_post_type" => RestrictError::InvalidPostType,
+            "missing_post_type" => RestrictError::MissingPostType,
+            "team_added_to_org" => RestrictError::TeamAddedToOrg,
+            "request_timeout" => RestrictError::RequestTimeout,
+            _ => RestrictError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RestrictError<E>> for ::Error<E> {
+    fn from(err: RestrictError<E>) -> Self {
+        let code = match err {
+            RestrictError::AppNotFound => "app_not_found",
+            RestrictError::RequestNotFound => "request_not_found",
+            RestrictError::InvalidAppId => "invalid_app_id",
+            RestrictError::FeatureNotEnabled => "feature_not_enabled",
+            RestrictError::NotAnAdmin => "not_an_admin",
+            RestrictError::NotAnEnterprise => "not_an_enterprise",
+            RestrictError::TeamNotFound => "team_not_found",
+            RestrictError::MissingScope => "missing_scope",
+            RestrictError::NotAllowedTokenType => "not_allowed_token_type",
+            RestrictError::NotAuthed => "not_authed",
+            RestrictError::InvalidAuth => "invalid_auth",
+            RestrictError::AccountInactive => "account_inactive",
+            RestrictError::InvalidArgName => "invalid_arg_name",
+            RestrictError::InvalidArrayArg => "invalid_array_arg",
+            RestrictError::InvalidCharset => "invalid_charset",
+            RestrictError::InvalidFormData => "invalid_form_data",
+            RestrictError::InvalidPostType => "invalid_post_type",
+            RestrictError::MissingPostType => "missing_post_type",
+            RestrictError::TeamAddedToOrg => "team_added_to_org",
+            RestrictError::RequestTimeout => "request_timeout",
+            RestrictError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RestrictError::Unknown(code) => return ::Error::from_code("admin.apps.restrict", &code),
+            RestrictError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.apps.restrict", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RestrictError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RestrictError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RestrictError::AppNotFound => "app_not_found: The app was not found.",
+            &RestrictError::RequestNotFound => "request_not_found: The request was not found.",
+            &RestrictError::InvalidAppId => "invalid_app_id: The app id is not valid.",
+            &RestrictError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &RestrictError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &RestrictError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &RestrictError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &RestrictError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RestrictError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &RestrictError::NotAuthed => "not_authed: No authentication token provided.",
+            &RestrictError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RestrictError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RestrictError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RestrictError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RestrictError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RestrictError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RestrictError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RestrictError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RestrictError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RestrictError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RestrictError::MalformedResponse(ref e) => e.description(),
+            &RestrictError::Unknown(ref s) => s,
+            &RestrictError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RestrictError::MalformedResponse(ref e) => Some(e),
+            &RestrictError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Uninstall an app from one or many workspaces, or an entire enterprise organization.
+///
+/// Wraps https://api.slack.com/methods/admin.apps.uninstall
+
+pub fn uninstall<R>(client: &R,
+                    token: &str,
+                    request: &UninstallRequest)
+                    -> Result<UninstallResponse, UninstallError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("app_id".to_owned(), request.app_id.into());
+    if let Some(enterprise_id) = request.enterprise_id {
+        body.insert("enterprise_id".to_owned(), enterprise_id.into());
+    }
+    if let Some(team_ids) = request.team_ids {
+        body.insert("team_ids".to_owned(), team_ids.into());
+    }
+    let url = ::get_slack_url_for_method("admin.apps.uninstall");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| UninstallError::Client(err))
+        .and_then(|result| {
+                      parse_uninstall_response(result.body.as_bytes()).map_err(|err| err.into_error(UninstallError::MalformedResponse))
+                  })
+}
+
+fn parse_uninstall_response(body: &[u8]) -> Result<UninstallResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UninstallResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UninstallRequest<'a> {
+    /// The id of the app to act on.
+    pub app_id: &'a str,
+    /// The enterprise to act in.
+    pub enterprise_id: Option<&'a str>,
+    /// The workspaces to uninstall the app from, as a comma-separated list.
+    pub team_ids: Option<&'a str>,
+}
+
+impl<'a> UninstallRequest<'a> {
+    pub fn new(app_id: &'a str) -> Self {
+        UninstallRequest {
+            app_id: app_id,
+            ..UninstallRequest::default()
+        }
+    }
+
+    pub fn enterprise_id(mut self, enterprise_id: &'a str) -> Self {
+        self.enterprise_id = Some(enterprise_id);
+        self
+    }
+
+    pub fn team_ids(mut self, team_ids: &'a str) -> Self {
+        self.team_ids = Some(team_ids);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UninstallResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<UninstallResponse, UninstallError<E>>> for UninstallResponse {
+    fn into(self) -> Result<UninstallResponse, UninstallError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UninstallError<E: Error> {
+    /// The app was not found.
+    AppNotFound,
+    /// The request was not found.
+    RequestNotFound,
+    /// The app id is not valid.
+    InvalidAppId,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UninstallError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "app_not_found" => UninstallError::AppNotFound,
+            "request_not_found" => UninstallError::RequestNotFound,
+            "invalid_app_id" => UninstallError::InvalidAppId,
+            "feature_not_enabled" => UninstallError::FeatureNotEnabled,
+            "not_an_admin" => UninstallError::NotAnAdmin,
+            "not_an_enterprise" => UninstallError::NotAnEnterprise,
+            "team_not_found" => UninstallError::TeamNotFound,
+            "missing_scope" => UninstallError::MissingScope,
+            "not_allowed_token_type" => UninstallError::NotAllowedTokenType,
+            "not_authed" => UninstallError::NotAuthed,
+            "invalid_auth" => UninstallError::InvalidAuth,
+            "account_inactive" => UninstallError::AccountInactive,
+            "invalid_arg_name" => UninstallError::InvalidArgName,
+            "invalid_array_arg" => UninstallError::InvalidArrayArg,
+            "invalid_charset" => UninstallError::InvalidCharset,
+            "invalid_form_data" => UninstallError::InvalidFormData,
+            "invalid_post_type" => UninstallError::InvalidPostType,
+            "missing_post_type" => UninstallError::MissingPostType,
+            "team_added_to_org" => UninstallError::TeamAddedToOrg,
+            "request_timeout" => UninstallError::RequestTimeout,
+            _ => UninstallError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<UninstallError<E>> for ::Error<E> {
+    fn from(err: UninstallError<E>) -> Self {
+        let code = match err {
+            UninstallError::AppNotFound => "app_not_found",
+            UninstallError::RequestNotFound => "request_not_found",
+            UninstallError::InvalidAppId => "invalid_app_id",
+            UninstallError::FeatureNotEnabled => "feature_not_enabled",
+            UninstallError::NotAnAdmin => "not_an_admin",
+            UninstallError::NotAnEnterprise => "not_an_enterprise",
+            UninstallError::TeamNotFound => "team_not_found",
+            UninstallError::MissingScope => "missing_scope",
+            UninstallError::NotAllowedTokenType => "not_allowed_token_type",
+            UninstallError::NotAuthed => "not_authed",
+            UninstallError::InvalidAuth => "invalid_auth",
+            UninstallError::AccountInactive => "account_inactive",
+            UninstallError::InvalidArgName => "invalid_arg_name",
+            UninstallError::InvalidArrayArg => "invalid_array_arg",
+            UninstallError::InvalidCharset => "invalid_charset",
+            UninstallError::InvalidFormData => "invalid_form_data",
+            UninstallError::InvalidPostType => "invalid_post_type",
+            UninstallError::MissingPostType => "missing_post_type",
+            UninstallError::TeamAddedToOrg => "team_added_to_org",
+            UninstallError::RequestTimeout => "request_timeout",
+            UninstallError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UninstallError::Unknown(code) => return ::Error::from_code("admin.apps.uninstall", &code),
+            UninstallError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.apps.uninstall", code)
+    }
+}
+
+impl<E: Error> fmt::Display for UninstallError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UninstallError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &UninstallError::AppNotFound => "app_not_found: The app was not found.",
+            &UninstallError::RequestNotFound => "request_not_found: The request was not found.",
+            &UninstallError::InvalidAppId => "invalid_app_id: The app id is not valid.",
+            &UninstallError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &UninstallError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &UninstallError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &UninstallError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &UninstallError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &UninstallError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &UninstallError::NotAuthed => "not_authed: No authentication token provided.",
+            &UninstallError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &UninstallError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &UninstallError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &UninstallError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &UninstallError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &UninstallError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &UninstallError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &UninstallError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &UninstallError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &UninstallError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &UninstallError::MalformedResponse(ref e) => e.description(),
+            &UninstallError::Unknown(ref s) => s,
+            &UninstallError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &UninstallError::MalformedResponse(ref e) => Some(e),
+            &UninstallError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `admin.apps.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::admin_apps`](../client/struct.SlackClient.html#method.admin_apps).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Approve an app for installation on a workspace. See [`approve`](fn.approve.html).
+    pub fn approve(&self, request: &ApproveRequest) -> Result<ApproveResponse, ApproveError<R::Error>> {
+        approve(self.client, self.token.for_method("admin.apps.approve"), request)
+    }
+
+    /// Clear an app resolution. See [`clear_resolution`](fn.clear_resolution.html).
+    pub fn clear_resolution(&self, request: &ClearResolutionRequest)
+                            -> Result<ClearResolutionResponse, ClearResolutionError<R::Error>> {
+        clear_resolution(self.client, self.token.for_method("admin.apps.clearResolution"), request)
+    }
+
+    /// Restrict an app for installation on a workspace. See [`restrict`](fn.restrict.html).
+    pub fn restrict(&self, request: &RestrictRequest) -> Result<RestrictResponse, RestrictError<R::Error>> {
+        restrict(self.client, self.token.for_method("admin.apps.restrict"), request)
+    }
+
+    /// Uninstall an app from one or many workspaces, or an entire enterprise organization. See
+    /// [`uninstall`](fn.uninstall.html).
+    pub fn uninstall(&self, request: &UninstallRequest) -> Result<UninstallResponse, UninstallError<R::Error>> {
+        uninstall(self.client, self.token.for_method("admin.apps.uninstall"), request)
+    }
+}
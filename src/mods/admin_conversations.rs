@@ -0,0 +1,2323 @@
+//! Manage the channels of an Enterprise Grid organization.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Archive a public or private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.archive
+
+pub fn archive<R>(client: &R,
+                  token: &str,
+                  request: &ArchiveRequest)
+                  -> Result<ArchiveResponse, ArchiveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    let url = ::get_slack_url_for_method("admin.conversations.archive");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| ArchiveError::Client(err))
+        .and_then(|result| {
+                      parse_archive_response(result.body.as_bytes()).map_err(|err| err.into_error(ArchiveError::MalformedResponse))
+                  })
+}
+
+fn parse_archive_response(body: &[u8]) -> Result<ArchiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ArchiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ArchiveRequest<'a> {
+    /// The channel to act on.
+    pub channel_id: &'a str,
+}
+
+impl<'a> ArchiveRequest<'a> {
+    pub fn new(channel_id: &'a str) -> Self {
+        ArchiveRequest {
+            channel_id: channel_id,
+            ..ArchiveRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ArchiveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveResponse {
+    fn into(self) -> Result<ArchiveResponse, ArchiveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ArchiveError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// The channel is already archived.
+    AlreadyArchived,
+    /// The general channel cannot be archived.
+    CantArchiveGeneral,
+    /// The workspace or organization settings do not allow this action.
+    RestrictedAction,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => ArchiveError::ChannelNotFound,
+            "already_archived" => ArchiveError::AlreadyArchived,
+            "cant_archive_general" => ArchiveError::CantArchiveGeneral,
+            "restricted_action" => ArchiveError::RestrictedAction,
+            "feature_not_enabled" => ArchiveError::FeatureNotEnabled,
+            "not_an_admin" => ArchiveError::NotAnAdmin,
+            "not_an_enterprise" => ArchiveError::NotAnEnterprise,
+            "team_not_found" => ArchiveError::TeamNotFound,
+            "missing_scope" => ArchiveError::MissingScope,
+            "not_allowed_token_type" => ArchiveError::NotAllowedTokenType,
+            "not_authed" => ArchiveError::NotAuthed,
+            "invalid_auth" => ArchiveError::InvalidAuth,
+            "account_inactive" => ArchiveError::AccountInactive,
+            "invalid_arg_name" => ArchiveError::InvalidArgName,
+            "invalid_array_arg" => ArchiveError::InvalidArrayArg,
+            "invalid_charset" => ArchiveError::InvalidCharset,
+            "invalid_form_data" => ArchiveError::InvalidFormData,
+            "invalid_post_type" => ArchiveError::InvalidPostType,
+            "missing_post_type" => ArchiveError::MissingPostType,
+            "team_added_to_org" => ArchiveError::TeamAddedToOrg,
+            "request_timeout" => ArchiveError::RequestTimeout,
+            _ => ArchiveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ArchiveError<E>> for ::Error<E> {
+    fn from(err: ArchiveError<E>) -> Self {
+        let code = match err {
+            ArchiveError::ChannelNotFound => "channel_not_found",
+            ArchiveError::AlreadyArchived => "already_archived",
+            ArchiveError::CantArchiveGeneral => "cant_archive_general",
+            ArchiveError::RestrictedAction => "restricted_action",
+            ArchiveError::FeatureNotEnabled => "feature_not_enabled",
+            ArchiveError::NotAnAdmin => "not_an_admin",
+            ArchiveError::NotAnEnterprise => "not_an_enterprise",
+            ArchiveError::TeamNotFound => "team_not_found",
+            ArchiveError::MissingScope => "missing_scope",
+            ArchiveError::NotAllowedTokenType => "not_allowed_token_type",
+            ArchiveError::NotAuthed => "not_authed",
+            ArchiveError::InvalidAuth => "invalid_auth",
+            ArchiveError::AccountInactive => "account_inactive",
+            ArchiveError::InvalidArgName => "invalid_arg_name",
+            ArchiveError::InvalidArrayArg => "invalid_array_arg",
+            ArchiveError::InvalidCharset => "invalid_charset",
+            ArchiveError::InvalidFormData => "invalid_form_data",
+            ArchiveError::InvalidPostType => "invalid_post_type",
+            ArchiveError::MissingPostType => "missing_post_type",
+            ArchiveError::TeamAddedToOrg => "team_added_to_org",
+            ArchiveError::RequestTimeout => "request_timeout",
+            ArchiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ArchiveError::Unknown(code) => return ::Error::from_code("admin.conversations.archive", &code),
+            ArchiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.archive", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ArchiveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ArchiveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ArchiveError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &ArchiveError::AlreadyArchived => "already_archived: The channel is already archived.",
+            &ArchiveError::CantArchiveGeneral => "cant_archive_general: The general channel cannot be archived.",
+            &ArchiveError::RestrictedAction => "restricted_action: The workspace or organization settings do not allow this action.",
+            &ArchiveError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &ArchiveError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &ArchiveError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &ArchiveError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &ArchiveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ArchiveError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &ArchiveError::NotAuthed => "not_authed: No authentication token provided.",
+            &ArchiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ArchiveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ArchiveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ArchiveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ArchiveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ArchiveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ArchiveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ArchiveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ArchiveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ArchiveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ArchiveError::MalformedResponse(ref e) => e.description(),
+            &ArchiveError::Unknown(ref s) => s,
+            &ArchiveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ArchiveError::MalformedResponse(ref e) => Some(e),
+            &ArchiveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Create a public or private channel-based conversation.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.create
+
+pub fn create<R>(client: &R,
+                 token: &str,
+                 request: &CreateRequest)
+                 -> Result<CreateResponse, CreateError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(description) = request.description {
+        body.insert("description".to_owned(), description.into());
+    }
+    body.insert("is_private".to_owned(), if request.is_private { "1" } else { "0" }.into());
+    body.insert("name".to_owned(), request.name.into());
+    if let Some(org_wide) = request.org_wide {
+        body.insert("org_wide".to_owned(), org_wide.into());
+    }
+    if let Some(team_id) = request.team_id {
+        body.insert("team_id".to_owned(), team_id.into());
+    }
+    let url = ::get_slack_url_for_method("admin.conversations.create");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| CreateError::Client(err))
+        .and_then(|result| {
+                      parse_create_response(result.body.as_bytes()).map_err(|err| err.into_error(CreateError::MalformedResponse))
+                  })
+}
+
+fn parse_create_response(body: &[u8]) -> Result<CreateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CreateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequest<'a> {
+    /// Description of the public or private channel to create.
+    pub description: Option<&'a str>,
+    /// When true, creates a private channel instead of a public channel
+    pub is_private: bool,
+    /// Name of the public or private channel to create.
+    pub name: &'a str,
+    /// When true, the channel will be available org-wide. Note: if the channel is not org_wide=true, you must specify a team_id for this channel
+    pub org_wide: Option<bool>,
+    /// The workspace to create the channel in. Note: this argument is required unless you set org_wide=true.
+    pub team_id: Option<&'a str>,
+}
+
+impl<'a> CreateRequest<'a> {
+    pub fn new(is_private: bool, name: &'a str) -> Self {
+        CreateRequest {
+            is_private: is_private,
+            name: name,
+            ..CreateRequest::default()
+        }
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn org_wide(mut self, org_wide: bool) -> Self {
+        self.org_wide = Some(org_wide);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateResponse {
+    pub channel_id: Option<String>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
+    fn into(self) -> Result<CreateResponse, CreateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CreateError<E: Error> {
+    /// A channel cannot be created with the given name.
+    NameTaken,
+    /// The name of the channel is not valid.
+    InvalidName,
+    /// A team_id is required unless org_wide is true.
+    TeamIdRequired,
+    /// The workspace or organization settings do not allow this action.
+    RestrictedAction,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CreateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "name_taken" => CreateError::NameTaken,
+            "invalid_name" => CreateError::InvalidName,
+            "team_id_required" => CreateError::TeamIdRequired,
+            "restricted_action" => CreateError::RestrictedAction,
+            "feature_not_enabled" => CreateError::FeatureNotEnabled,
+            "not_an_admin" => CreateError::NotAnAdmin,
+            "not_an_enterprise" => CreateError::NotAnEnterprise,
+            "team_not_found" => CreateError::TeamNotFound,
+            "missing_scope" => CreateError::MissingScope,
+            "not_allowed_token_type" => CreateError::NotAllowedTokenType,
+            "not_authed" => CreateError::NotAuthed,
+            "invalid_auth" => CreateError::InvalidAuth,
+            "account_inactive" => CreateError::AccountInactive,
+            "invalid_arg_name" => CreateError::InvalidArgName,
+            "invalid_array_arg" => CreateError::InvalidArrayArg,
+            "invalid_charset" => CreateError::InvalidCharset,
+            "invalid_form_data" => CreateError::InvalidFormData,
+            "invalid_post_type" => CreateError::InvalidPostType,
+            "missing_post_type" => CreateError::MissingPostType,
+            "team_added_to_org" => CreateError::TeamAddedToOrg,
+            "request_timeout" => CreateError::RequestTimeout,
+            _ => CreateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<CreateError<E>> for ::Error<E> {
+    fn from(err: CreateError<E>) -> Self {
+        let code = match err {
+            CreateError::NameTaken => "name_taken",
+            CreateError::InvalidName => "invalid_name",
+            CreateError::TeamIdRequired => "team_id_required",
+            CreateError::RestrictedAction => "restricted_action",
+            CreateError::FeatureNotEnabled => "feature_not_enabled",
+            CreateError::NotAnAdmin => "not_an_admin",
+            CreateError::NotAnEnterprise => "not_an_enterprise",
+            CreateError::TeamNotFound => "team_not_found",
+            CreateError::MissingScope => "missing_scope",
+            CreateError::NotAllowedTokenType => "not_allowed_token_type",
+            CreateError::NotAuthed => "not_authed",
+            CreateError::InvalidAuth => "invalid_auth",
+            CreateError::AccountInactive => "account_inactive",
+            CreateError::InvalidArgName => "invalid_arg_name",
+            CreateError::InvalidArrayArg => "invalid_array_arg",
+            CreateError::InvalidCharset => "invalid_charset",
+            CreateError::InvalidFormData => "invalid_form_data",
+            CreateError::InvalidPostType => "invalid_post_type",
+            CreateError::MissingPostType => "missing_post_type",
+            CreateError::TeamAddedToOrg => "team_added_to_org",
+            CreateError::RequestTimeout => "request_timeout",
+            CreateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CreateError::Unknown(code) => return ::Error::from_code("admin.conversations.create", &code),
+            CreateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.create", code)
+    }
+}
+
+impl<E: Error> fmt::Display for CreateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CreateError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &CreateError::NameTaken => "name_taken: A channel cannot be created with the given name.",
+            &CreateError::InvalidName => "invalid_name: The name of the channel is not valid.",
+            &CreateError::TeamIdRequired => "team_id_required: A team_id is required unless org_wide is true.",
+            &CreateError::RestrictedAction => "restricted_action: The workspace or organization settings do not allow this action.",
+            &CreateError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &CreateError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &CreateError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &CreateError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &CreateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &CreateError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &CreateError::NotAuthed => "not_authed: No authentication token provided.",
+            &CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &CreateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &CreateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &CreateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &CreateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &CreateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &CreateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &CreateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &CreateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &CreateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &CreateError::MalformedResponse(ref e) => e.description(),
+            &CreateError::Unknown(ref s) => s,
+            &CreateError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &CreateError::MalformedResponse(ref e) => Some(e),
+            &CreateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Delete a public or private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.delete
+
+pub fn delete<R>(client: &R,
+                 token: &str,
+                 request: &DeleteRequest)
+                 -> Result<DeleteResponse, DeleteError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    let url = ::get_slack_url_for_method("admin.conversations.delete");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| DeleteError::Client(err))
+        .and_then(|result| {
+                      parse_delete_response(result.body.as_bytes()).map_err(|err| err.into_error(DeleteError::MalformedResponse))
+                  })
+}
+
+fn parse_delete_response(body: &[u8]) -> Result<DeleteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<DeleteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct DeleteRequest<'a> {
+    /// The channel to act on.
+    pub channel_id: &'a str,
+}
+
+impl<'a> DeleteRequest<'a> {
+    pub fn new(channel_id: &'a str) -> Self {
+        DeleteRequest {
+            channel_id: channel_id,
+            ..DeleteRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeleteResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
+    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum DeleteError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// The general channel cannot be deleted.
+    CantDeleteGeneral,
+    /// The workspace or organization settings do not allow this action.
+    RestrictedAction,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for DeleteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => DeleteError::ChannelNotFound,
+            "cant_delete_general" => DeleteError::CantDeleteGeneral,
+            "restricted_action" => DeleteError::RestrictedAction,
+            "feature_not_enabled" => DeleteError::FeatureNotEnabled,
+            "not_an_admin" => DeleteError::NotAnAdmin,
+            "not_an_enterprise" => DeleteError::NotAnEnterprise,
+            "team_not_found" => DeleteError::TeamNotFound,
+            "missing_scope" => DeleteError::MissingScope,
+            "not_allowed_token_type" => DeleteError::NotAllowedTokenType,
+            "not_authed" => DeleteError::NotAuthed,
+            "invalid_auth" => DeleteError::InvalidAuth,
+            "account_inactive" => DeleteError::AccountInactive,
+            "invalid_arg_name" => DeleteError::InvalidArgName,
+            "invalid_array_arg" => DeleteError::InvalidArrayArg,
+            "invalid_charset" => DeleteError::InvalidCharset,
+            "invalid_form_data" => DeleteError::InvalidFormData,
+            "invalid_post_type" => DeleteError::InvalidPostType,
+            "missing_post_type" => DeleteError::MissingPostType,
+            "team_added_to_org" => DeleteError::TeamAddedToOrg,
+            "request_timeout" => DeleteError::RequestTimeout,
+            _ => DeleteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<DeleteError<E>> for ::Error<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        let code = match err {
+            DeleteError::ChannelNotFound => "channel_not_found",
+            DeleteError::CantDeleteGeneral => "cant_delete_general",
+            DeleteError::RestrictedAction => "restricted_action",
+            DeleteError::FeatureNotEnabled => "feature_not_enabled",
+            DeleteError::NotAnAdmin => "not_an_admin",
+            DeleteError::NotAnEnterprise => "not_an_enterprise",
+            DeleteError::TeamNotFound => "team_not_found",
+            DeleteError::MissingScope => "missing_scope",
+            DeleteError::NotAllowedTokenType => "not_allowed_token_type",
+            DeleteError::NotAuthed => "not_authed",
+            DeleteError::InvalidAuth => "invalid_auth",
+            DeleteError::AccountInactive => "account_inactive",
+            DeleteError::InvalidArgName => "invalid_arg_name",
+            DeleteError::InvalidArrayArg => "invalid_array_arg",
+            DeleteError::InvalidCharset => "invalid_charset",
+            DeleteError::InvalidFormData => "invalid_form_data",
+            DeleteError::InvalidPostType => "invalid_post_type",
+            DeleteError::MissingPostType => "missing_post_type",
+            DeleteError::TeamAddedToOrg => "team_added_to_org",
+            DeleteError::RequestTimeout => "request_timeout",
+            DeleteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            DeleteError::Unknown(code) => return ::Error::from_code("admin.conversations.delete", &code),
+            DeleteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.delete", code)
+    }
+}
+
+impl<E: Error> fmt::Display for DeleteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for DeleteError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &DeleteError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &DeleteError::CantDeleteGeneral => "cant_delete_general: The general channel cannot be deleted.",
+            &DeleteError::RestrictedAction => "restricted_action: The workspace or organization settings do not allow this action.",
+            &DeleteError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &DeleteError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &DeleteError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &DeleteError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &DeleteError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &DeleteError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &DeleteError::NotAuthed => "not_authed: No authentication token provided.",
+            &DeleteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &DeleteError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &DeleteError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &DeleteError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &DeleteError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &DeleteError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &DeleteError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &DeleteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &DeleteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &DeleteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &DeleteError::MalformedResponse(ref e) => e.description(),
+            &DeleteError::Unknown(ref s) => s,
+            &DeleteError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &DeleteError::MalformedResponse(ref e) => Some(e),
+            &DeleteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Get all the workspaces a given public or private channel is connected to within this Enterprise org.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.getTeams
+
+pub fn get_teams<R>(client: &R,
+                    token: &str,
+                    request: &GetTeamsRequest)
+                    -> Result<GetTeamsResponse, GetTeamsError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    params.push(("channel_id", request.channel_id));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    let url = ::get_slack_url_for_method("admin.conversations.getTeams");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| GetTeamsError::Client(err))
+        .and_then(|result| {
+                      parse_get_teams_response(&result).map_err(|err| err.into_error(GetTeamsError::MalformedResponse))
+                  })
+}
+
+/// Like [`get_teams`](fn.get_teams.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `team_ids` of every page in turn.
+pub fn get_teams_all<'a, R>(client: &'a R,
+                            token: &'a str,
+                            request: &GetTeamsRequest<'a>)
+                            -> ::cursor::Items<'a, String, GetTeamsError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = GetTeamsRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        get_teams(client, token, &request).map(|response| {
+            (response.team_ids.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_get_teams_response(body: &[u8]) -> Result<GetTeamsResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<GetTeamsResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct GetTeamsRequest<'a> {
+    /// The channel to act on.
+    pub channel_id: &'a str,
+    /// Set cursor to next_cursor returned by the previous call to list items in the next page.
+    pub cursor: Option<&'a str>,
+    /// The maximum number of items to return. Must be between 1 - 1000 both inclusive.
+    pub limit: Option<u32>,
+}
+
+impl<'a> GetTeamsRequest<'a> {
+    pub fn new(channel_id: &'a str) -> Self {
+        GetTeamsRequest {
+            channel_id: channel_id,
+            ..GetTeamsRequest::default()
+        }
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct GetTeamsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub team_ids: Option<Vec<String>>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<GetTeamsResponse, GetTeamsError<E>>> for GetTeamsResponse {
+    fn into(self) -> Result<GetTeamsResponse, GetTeamsError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum GetTeamsError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for GetTeamsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => GetTeamsError::ChannelNotFound,
+            "invalid_cursor" => GetTeamsError::InvalidCursor,
+            "feature_not_enabled" => GetTeamsError::FeatureNotEnabled,
+            "not_an_admin" => GetTeamsError::NotAnAdmin,
+            "not_an_enterprise" => GetTeamsError::NotAnEnterprise,
+            "team_not_found" => GetTeamsError::TeamNotFound,
+            "missing_scope" => GetTeamsError::MissingScope,
+            "not_allowed_token_type" => GetTeamsError::NotAllowedTokenType,
+            "not_authed" => GetTeamsError::NotAuthed,
+            "invalid_auth" => GetTeamsError::InvalidAuth,
+            "account_inactive" => GetTeamsError::AccountInactive,
+            "invalid_arg_name" => GetTeamsError::InvalidArgName,
+            "invalid_array_arg" => GetTeamsError::InvalidArrayArg,
+            "invalid_charset" => GetTeamsError::InvalidCharset,
+            "invalid_form_data" => GetTeamsError::InvalidFormData,
+            "invalid_post_type" => GetTeamsError::InvalidPostType,
+            "missing_post_type" => GetTeamsError::MissingPostType,
+            "team_added_to_org" => GetTeamsError::TeamAddedToOrg,
+            "request_timeout" => GetTeamsError::RequestTimeout,
+            _ => GetTeamsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<GetTeamsError<E>> for ::Error<E> {
+    fn from(err: GetTeamsError<E>) -> Self {
+        let code = match err {
+            GetTeamsError::ChannelNotFound => "channel_not_found",
+            GetTeamsError::InvalidCursor => "invalid_cursor",
+            GetTeamsError::FeatureNotEnabled => "feature_not_enabled",
+            GetTeamsError::NotAnAdmin => "not_an_admin",
+            GetTeamsError::NotAnEnterprise => "not_an_enterprise",
+            GetTeamsError::TeamNotFound => "team_not_found",
+            GetTeamsError::MissingScope => "missing_scope",
+            GetTeamsError::NotAllowedTokenType => "not_allowed_token_type",
+            GetTeamsError::NotAuthed => "not_authed",
+            GetTeamsError::InvalidAuth => "invalid_auth",
+            GetTeamsError::AccountInactive => "account_inactive",
+            GetTeamsError::InvalidArgName => "invalid_arg_name",
+            GetTeamsError::InvalidArrayArg => "invalid_array_arg",
+            GetTeamsError::InvalidCharset => "invalid_charset",
+            GetTeamsError::InvalidFormData => "invalid_form_data",
+            GetTeamsError::InvalidPostType => "invalid_post_type",
+            GetTeamsError::MissingPostType => "missing_post_type",
+            GetTeamsError::TeamAddedToOrg => "team_added_to_org",
+            GetTeamsError::RequestTimeout => "request_timeout",
+            GetTeamsError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            GetTeamsError::Unknown(code) => return ::Error::from_code("admin.conversations.getTeams", &code),
+            GetTeamsError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.getTeams", code)
+    }
+}
+
+impl<E: Error> fmt::Display for GetTeamsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for GetTeamsError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &GetTeamsError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &GetTeamsError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &GetTeamsError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &GetTeamsError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &GetTeamsError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &GetTeamsError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &GetTeamsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &GetTeamsError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &GetTeamsError::NotAuthed => "not_authed: No authentication token provided.",
+            &GetTeamsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &GetTeamsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &GetTeamsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &GetTeamsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &GetTeamsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &GetTeamsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &GetTeamsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &GetTeamsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &GetTeamsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &GetTeamsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &GetTeamsError::MalformedResponse(ref e) => e.description(),
+            &GetTeamsError::Unknown(ref s) => s,
+            &GetTeamsError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &GetTeamsError::MalformedResponse(ref e) => Some(e),
+            &GetTeamsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Invite a user to a public or private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.invite
+
+pub fn invite<R>(client: &R,
+                 token: &str,
+                 request: &InviteRequest)
+                 -> Result<InviteResponse, InviteError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    body.insert("user_ids".to_owned(), request.user_ids.into());
+    let url = ::get_slack_url_for_method("admin.conversations.invite");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| InviteError::Client(err))
+        .and_then(|result| {
+                      parse_invite_response(result.body.as_bytes()).map_err(|err| err.into_error(InviteError::MalformedResponse))
+                  })
+}
+
+fn parse_invite_response(body: &[u8]) -> Result<InviteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InviteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InviteRequest<'a> {
+    /// The channel to act on.
+    pub channel_id: &'a str,
+    /// The users to invite, as a comma-separated list.
+    pub user_ids: &'a str,
+}
+
+impl<'a> InviteRequest<'a> {
+    pub fn new(channel_id: &'a str, user_ids: &'a str) -> Self {
+        InviteRequest {
+            channel_id: channel_id,
+            user_ids: user_ids,
+            ..InviteRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InviteResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
+    fn into(self) -> Result<InviteResponse, InviteError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InviteError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// One of the users was not found.
+    UserNotFound,
+    /// The invite failed for some of the users.
+    FailedForSomeUsers,
+    /// The workspace or organization settings do not allow this action.
+    RestrictedAction,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InviteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => InviteError::ChannelNotFound,
+            "user_not_found" => InviteError::UserNotFound,
+            "failed_for_some_users" => InviteError::FailedForSomeUsers,
+            "restricted_action" => InviteError::RestrictedAction,
+            "feature_not_enabled" => InviteError::FeatureNotEnabled,
+            "not_an_admin" => InviteError::NotAnAdmin,
+            "not_an_enterprise" => InviteError::NotAnEnterprise,
+            "team_not_found" => InviteError::TeamNotFound,
+            "missing_scope" => InviteError::MissingScope,
+            "not_allowed_token_type" => InviteError::NotAllowedTokenType,
+            "not_authed" => InviteError::NotAuthed,
+            "invalid_auth" => InviteError::InvalidAuth,
+            "account_inactive" => InviteError::AccountInactive,
+            "invalid_arg_name" => InviteError::InvalidArgName,
+            "invalid_array_arg" => InviteError::InvalidArrayArg,
+            "invalid_charset" => InviteError::InvalidCharset,
+            "invalid_form_data" => InviteError::InvalidFormData,
+            "invalid_post_type" => InviteError::InvalidPostType,
+            "missing_post_type" => InviteError::MissingPostType,
+            "team_added_to_org" => InviteError::TeamAddedToOrg,
+            "request_timeout" => InviteError::RequestTimeout,
+            _ => InviteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<InviteError<E>> for ::Error<E> {
+    fn from(err: InviteError<E>) -> Self {
+        let code = match err {
+            InviteError::ChannelNotFound => "channel_not_found",
+            InviteError::UserNotFound => "user_not_found",
+            InviteError::FailedForSomeUsers => "failed_for_some_users",
+            InviteError::RestrictedAction => "restricted_action",
+            InviteError::FeatureNotEnabled => "feature_not_enabled",
+            InviteError::NotAnAdmin => "not_an_admin",
+            InviteError::NotAnEnterprise => "not_an_enterprise",
+            InviteError::TeamNotFound => "team_not_found",
+            InviteError::MissingScope => "missing_scope",
+            InviteError::NotAllowedTokenType => "not_allowed_token_type",
+            InviteError::NotAuthed => "not_authed",
+            InviteError::InvalidAuth => "invalid_auth",
+            InviteError::AccountInactive => "account_inactive",
+            InviteError::InvalidArgName => "invalid_arg_name",
+            InviteError::InvalidArrayArg => "invalid_array_arg",
+            InviteError::InvalidCharset => "invalid_charset",
+            InviteError::InvalidFormData => "invalid_form_data",
+            InviteError::InvalidPostType => "invalid_post_type",
+            InviteError::MissingPostType => "missing_post_type",
+            InviteError::TeamAddedToOrg => "team_added_to_org",
+            InviteError::RequestTimeout => "request_timeout",
+            InviteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InviteError::Unknown(code) => return ::Error::from_code("admin.conversations.invite", &code),
+            InviteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.invite", code)
+    }
+}
+
+impl<E: Error> fmt::Display for InviteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InviteError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &InviteError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &InviteError::UserNotFound => "user_not_found: One of the users was not found.",
+            &InviteError::FailedForSomeUsers => "failed_for_some_users: The invite failed for some of the users.",
+            &InviteError::RestrictedAction => "restricted_action: The workspace or organization settings do not allow this action.",
+            &InviteError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &InviteError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &InviteError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &InviteError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &InviteError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &InviteError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &InviteError::NotAuthed => "not_authed: No authentication token provided.",
+            &InviteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &InviteError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &InviteError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &InviteError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &InviteError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &InviteError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &InviteError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &InviteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &InviteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &InviteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &InviteError::MalformedResponse(ref e) => e.description(),
+            &InviteError::Unknown(ref s) => s,
+            &InviteError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &InviteError::MalformedResponse(ref e) => Some(e),
+            &InviteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Rename a public or private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.rename
+
+pub fn rename<R>(client: &R,
+                 token: &str,
+                 request: &RenameRequest)
+                 -> Result<RenameResponse, RenameError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    body.insert("name".to_owned(), request.name.into());
+    let url = ::get_slack_url_for_method("admin.conversations.rename");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| RenameError::Client(err))
+        .and_then(|result| {
+                      parse_rename_response(result.body.as_bytes()).map_err(|err| err.into_error(RenameError::MalformedResponse))
+                  })
+}
+
+fn parse_rename_response(body: &[u8]) -> Result<RenameResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RenameResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RenameRequest<'a> {
+    /// The channel to act on.
+    pub channel_id: &'a str,
+    /// The new name of the channel.
+    pub name: &'a str,
+}
+
+impl<'a> RenameRequest<'a> {
+    pub fn new(channel_id: &'a str, name: &'a str) -> Self {
+        RenameRequest {
+            channel_id: channel_id,
+            name: name,
+            ..RenameRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RenameResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
+    fn into(self) -> Result<RenameResponse, RenameError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RenameError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// A channel cannot be renamed to the given name.
+    NameTaken,
+    /// The name of the channel is not valid.
+    InvalidName,
+    /// The workspace or organization settings do not allow this action.
+    RestrictedAction,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RenameError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => RenameError::ChannelNotFound,
+            "name_taken" => RenameError::NameTaken,
+            "invalid_name" => RenameError::InvalidName,
+            "restricted_action" => RenameError::RestrictedAction,
+            "feature_not_enabled" => RenameError::FeatureNotEnabled,
+            "not_an_admin" => RenameError::NotAnAdmin,
+            "not_an_enterprise" => RenameError::NotAnEnterprise,
+            "team_not_found" => RenameError::TeamNotFound,
+            "missing_scope" => RenameError::MissingScope,
+            "not_allowed_token_type" => RenameError::NotAllowedTokenType,
+            "not_authed" => RenameError::NotAuthed,
+            "invalid_auth" => RenameError::InvalidAuth,
+            "account_inactive" => RenameError::AccountInactive,
+            "invalid_arg_name" => RenameError::InvalidArgName,
+            "invalid_array_arg" => RenameError::InvalidArrayArg,
+            "invalid_charset" => RenameError::InvalidCharset,
+            "invalid_form_data" => RenameError::InvalidFormData,
+            "invalid_post_type" => RenameError::InvalidPostType,
+            "missing_post_type" => RenameError::MissingPostType,
+            "team_added_to_org" => RenameError::TeamAddedToOrg,
+            "request_timeout" => RenameError::RequestTimeout,
+            _ => RenameError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RenameError<E>> for ::Error<E> {
+    fn from(err: RenameError<E>) -> Self {
+        let code = match err {
+            RenameError::ChannelNotFound => "channel_not_found",
+            RenameError::NameTaken => "name_taken",
+            RenameError::InvalidName => "invalid_name",
+            RenameError::RestrictedAction => "restricted_action",
+            RenameError::FeatureNotEnabled => "feature_not_enabled",
+            RenameError::NotAnAdmin => "not_an_admin",
+            RenameError::NotAnEnterprise => "not_an_enterprise",
+            RenameError::TeamNotFound => "team_not_found",
+            RenameError::MissingScope => "missing_scope",
+            RenameError::NotAllowedTokenType => "not_allowed_token_type",
+            RenameError::NotAuthed => "not_authed",
+            RenameError::InvalidAuth => "invalid_auth",
+            RenameError::AccountInactive => "account_inactive",
+            RenameError::InvalidArgName => "invalid_arg_name",
+            RenameError::InvalidArrayArg => "invalid_array_arg",
+            RenameError::InvalidCharset => "invalid_charset",
+            RenameError::InvalidFormData => "invalid_form_data",
+            RenameError::InvalidPostType => "invalid_post_type",
+            RenameError::MissingPostType => "missing_post_type",
+            RenameError::TeamAddedToOrg => "team_added_to_org",
+            RenameError::RequestTimeout => "request_timeout",
+            RenameError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RenameError::Unknown(code) => return ::Error::from_code("admin.conversations.rename", &code),
+            RenameError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.rename", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RenameError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RenameError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RenameError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &RenameError::NameTaken => "name_taken: A channel cannot be renamed to the given name.",
+            &RenameError::InvalidName => "invalid_name: The name of the channel is not valid.",
+            &RenameError::RestrictedAction => "restricted_action: The workspace or organization settings do not allow this action.",
+            &RenameError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &RenameError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &RenameError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &RenameError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &RenameError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RenameError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &RenameError::NotAuthed => "not_authed: No authentication token provided.",
+            &RenameError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RenameError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RenameError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RenameError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RenameError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RenameError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RenameError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RenameError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RenameError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RenameError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RenameError::MalformedResponse(ref e) => e.description(),
+            &RenameError::Unknown(ref s) => s,
+            &RenameError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RenameError::MalformedResponse(ref e) => Some(e),
+            &RenameError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Search for public or private channels in an Enterprise organization.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.search
+
+pub fn search<R>(client: &R,
+                 token: &str,
+                 request: &SearchRequest)
+                 -> Result<SearchResponse, SearchError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(cursor) = request.cursor {
+        body.insert("cursor".to_owned(), cursor.into());
+    }
+    if let Some(limit) = request.limit {
+        body.insert("limit".to_owned(), limit.into());
+    }
+    if let Some(query) = request.query {
+        body.insert("query".to_owned(), query.into());
+    }
+    if let Some(search_channel_types) = request.search_channel_types {
+        body.insert("search_channel_types".to_owned(), search_channel_types.into());
+    }
+    if let Some(sort) = request.sort {
+        body.insert("sort".to_owned(), sort.into());
+    }
+    if let Some(sort_dir) = request.sort_dir {
+        body.insert("sort_dir".to_owned(), sort_dir.into());
+    }
+    if let Some(team_ids) = request.team_ids {
+        body.insert("team_ids".to_owned(), team_ids.into());
+    }
+    let url = ::get_slack_url_for_method("admin.conversations.search");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| SearchError::Client(err))
+        .and_then(|result| {
+                      parse_search_response(result.body.as_bytes()).map_err(|err| err.into_error(SearchError::MalformedResponse))
+                  })
+}
+
+/// Like [`search`](fn.search.html), but follows `next_cursor` from page to page,
+/// yielding the `conversations` of every page in turn.
+pub fn search_all<'a, R>(client: &'a R,
+                         token: &'a str,
+                         request: &SearchRequest<'a>)
+                         -> ::cursor::Items<'a, SearchResponseConversation, SearchError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = SearchRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        search(client, token, &request).map(|response| {
+            (response.conversations.unwrap_or_default(), response.next_cursor)
+        })
+    })
+}
+
+fn parse_search_response(body: &[u8]) -> Result<SearchResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SearchResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SearchRequest<'a> {
+    /// Set cursor to next_cursor returned by the previous call to list items in the next page.
+    pub cursor: Option<&'a str>,
+    /// Maximum number of items to be returned. Must be between 1 - 20 both inclusive. Default is 10.
+    pub limit: Option<u32>,
+    /// Name of the the channel to query by.
+    pub query: Option<&'a str>,
+    /// The type of channel to include or exclude in the search, as a comma-separated list. For example private will search private channels, while private_exclude will exclude them.
+    pub search_channel_types: Option<&'a str>,
+    /// Possible values are relevant (search ranking based on what we think is closest), name (alphabetical), member_count (number of users in the channel), and created (date channel was created). You can optionally pair this with the sort_dir arg to change how it is sorted
+    pub sort: Option<&'a str>,
+    /// Sort direction. Possible values are asc for ascending order like (1, 2, 3) or (a, b, c), and desc for descending order like (3, 2, 1) or (c, b, a)
+    pub sort_dir: Option<&'a str>,
+    /// The workspaces to search in, as a comma-separated list. Empty for all workspaces.
+    pub team_ids: Option<&'a str>,
+}
+
+impl<'a> SearchRequest<'a> {
+    pub fn new() -> Self {
+        SearchRequest::default()
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    pub fn search_channel_types(mut self, search_channel_types: &'a str) -> Self {
+        self.search_channel_types = Some(search_channel_types);
+        self
+    }
+
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: &'a str) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn team_ids(mut self, team_ids: &'a str) -> Self {
+        self.team_ids = Some(team_ids);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SearchResponse {
+    pub conversations: Option<Vec<SearchResponseConversation>>,
+    error: Option<String>,
+    pub next_cursor: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SearchResponseConversation {
+    pub created: Option<i64>,
+    pub id: Option<String>,
+    pub internal_team_ids: Option<Vec<String>>,
+    pub is_archived: Option<bool>,
+    pub is_ext_shared: Option<bool>,
+    pub is_general: Option<bool>,
+    pub is_org_shared: Option<bool>,
+    pub is_private: Option<bool>,
+    pub member_count: Option<i64>,
+    pub name: Option<String>,
+    pub purpose: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SearchResponse, SearchError<E>>> for SearchResponse {
+    fn into(self) -> Result<SearchResponse, SearchError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SearchError<E: Error> {
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SearchError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_cursor" => SearchError::InvalidCursor,
+            "feature_not_enabled" => SearchError::FeatureNotEnabled,
+            "not_an_admin" => SearchError::NotAnAdmin,
+            "not_an_enterprise" => SearchError::NotAnEnterprise,
+            "team_not_found" => SearchError::TeamNotFound,
+            "missing_scope" => SearchError::MissingScope,
+            "not_allowed_token_type" => SearchError::NotAllowedTokenType,
+            "not_authed" => SearchError::NotAuthed,
+            "invalid_auth" => SearchError::InvalidAuth,
+            "account_inactive" => SearchError::AccountInactive,
+            "invalid_arg_name" => SearchError::InvalidArgName,
+            "invalid_array_arg" => SearchError::InvalidArrayArg,
+            "invalid_charset" => SearchError::InvalidCharset,
+            "invalid_form_data" => SearchError::InvalidFormData,
+            "invalid_post_type" => SearchError::InvalidPostType,
+            "missing_post_type" => SearchError::MissingPostType,
+            "team_added_to_org" => SearchError::TeamAddedToOrg,
+            "request_timeout" => SearchError::RequestTimeout,
+            _ => SearchError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SearchError<E>> for ::Error<E> {
+    fn from(err: SearchError<E>) -> Self {
+        let code = match err {
+            SearchError::InvalidCursor => "invalid_cursor",
+            SearchError::FeatureNotEnabled => "feature_not_enabled",
+            SearchError::NotAnAdmin => "not_an_admin",
+            SearchError::NotAnEnterprise => "not_an_enterprise",
+            SearchError::TeamNotFound => "team_not_found",
+            SearchError::MissingScope => "missing_scope",
+            SearchError::NotAllowedTokenType => "not_allowed_token_type",
+            SearchError::NotAuthed => "not_authed",
+            SearchError::InvalidAuth => "invalid_auth",
+            SearchError::AccountInactive => "account_inactive",
+            SearchError::InvalidArgName => "invalid_arg_name",
+            SearchError::InvalidArrayArg => "invalid_array_arg",
+            SearchError::InvalidCharset => "invalid_charset",
+            SearchError::InvalidFormData => "invalid_form_data",
+            SearchError::InvalidPostType => "invalid_post_type",
+            SearchError::MissingPostType => "missing_post_type",
+            SearchError::TeamAddedToOrg => "team_added_to_org",
+            SearchError::RequestTimeout => "request_timeout",
+            SearchError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SearchError::Unknown(code) => return ::Error::from_code("admin.conversations.search", &code),
+            SearchError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.search", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SearchError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SearchError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SearchError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &SearchError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &SearchError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &SearchError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &SearchError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &SearchError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &SearchError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &SearchError::NotAuthed => "not_authed: No authentication token provided.",
+            &SearchError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SearchError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SearchError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SearchError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SearchError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SearchError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SearchError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SearchError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SearchError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SearchError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SearchError::MalformedResponse(ref e) => e.description(),
+            &SearchError::Unknown(ref s) => s,
+            &SearchError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SearchError::MalformedResponse(ref e) => Some(e),
+            &SearchError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Set the workspaces in an Enterprise grid org that connect to a public or private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.setTeams
+
+pub fn set_teams<R>(client: &R,
+                    token: &str,
+                    request: &SetTeamsRequest)
+                    -> Result<SetTeamsResponse, SetTeamsError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    if let Some(org_channel) = request.org_channel {
+        body.insert("org_channel".to_owned(), org_channel.into());
+    }
+    if let Some(target_team_ids) = request.target_team_ids {
+        body.insert("target_team_ids".to_owned(), target_team_ids.into());
+    }
+    if let Some(team_id) = request.team_id {
+        body.insert("team_id".to_owned(), team_id.into());
+    }
+    let url = ::get_slack_url_for_method("admin.conversations.setTeams");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| SetTeamsError::Client(err))
+        .and_then(|result| {
+                      parse_set_teams_response(result.body.as_bytes()).map_err(|err| err.into_error(SetTeamsError::MalformedResponse))
+                  })
+}
+
+fn parse_set_teams_response(body: &[u8]) -> Result<SetTeamsResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetTeamsResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetTeamsRequest<'a> {
+    /// The channel to act on.
+    pub channel_id: &'a str,
+    /// True if channel has to be converted to an org channel
+    pub org_channel: Option<bool>,
+    /// The workspaces to which the channel should be shared, as a comma-separated list. Not required if the channel is being shared org-wide.
+    pub target_team_ids: Option<&'a str>,
+    /// The workspace to which the channel belongs. Omit this argument if the channel is a cross-workspace shared channel.
+    pub team_id: Option<&'a str>,
+}
+
+impl<'a> SetTeamsRequest<'a> {
+    pub fn new(channel_id: &'a str) -> Self {
+        SetTeamsRequest {
+            channel_id: channel_id,
+            ..SetTeamsRequest::default()
+        }
+    }
+
+    pub fn org_channel(mut self, org_channel: bool) -> Self {
+        self.org_channel = Some(org_channel);
+        self
+    }
+
+    pub fn target_team_ids(mut self, target_team_ids: &'a str) -> Self {
+        self.target_team_ids = Some(target_team_ids);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetTeamsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SetTeamsResponse, SetTeamsError<E>>> for SetTeamsResponse {
+    fn into(self) -> Result<SetTeamsResponse, SetTeamsError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetTeamsError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// The workspace or organization settings do not allow this action.
+    RestrictedAction,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetTeamsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => SetTeamsError::ChannelNotFound,
+            "restricted_action" => SetTeamsError::RestrictedAction,
+            "feature_not_enabled" => SetTeamsError::FeatureNotEnabled,
+            "not_an_admin" => SetTeamsError::NotAnAdmin,
+            "not_an_enterprise" => SetTeamsError::NotAnEnterprise,
+            "team_not_found" => SetTeamsError::TeamNotFound,
+            "missing_scope" => SetTeamsError::MissingScope,
+            "not_allowed_token_type" => SetTeamsError::NotAllowedTokenType,
+            "not_authed" => SetTeamsError::NotAuthed,
+            "invalid_auth" => SetTeamsError::InvalidAuth,
+            "account_inactive" => SetTeamsError::AccountInactive,
+            "invalid_arg_name" => SetTeamsError::InvalidArgName,
+            "invalid_array_arg" => SetTeamsError::InvalidArrayArg,
+            "invalid_charset" => SetTeamsError::InvalidCharset,
+            "invalid_form_data" => SetTeamsError::InvalidFormData,
+            "invalid_post_type" => SetTeamsError::InvalidPostType,
+            "missing_post_type" => SetTeamsError::MissingPostType,
+            "team_added_to_org" => SetTeamsError::TeamAddedToOrg,
+            "request_timeout" => SetTeamsError::RequestTimeout,
+            _ => SetTeamsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SetTeamsError<E>> for ::Error<E> {
+    fn from(err: SetTeamsError<E>) -> Self {
+        let code = match err {
+            SetTeamsError::ChannelNotFound => "channel_not_found",
+            SetTeamsError::RestrictedAction => "restricted_action",
+            SetTeamsError::FeatureNotEnabled => "feature_not_enabled",
+            SetTeamsError::NotAnAdmin => "not_an_admin",
+            SetTeamsError::NotAnEnterprise => "not_an_enterprise",
+            SetTeamsError::TeamNotFound => "team_not_found",
+            SetTeamsError::MissingScope => "missing_scope",
+            SetTeamsError::NotAllowedTokenType => "not_allowed_token_type",
+            SetTeamsError::NotAuthed => "not_authed",
+            SetTeamsError::InvalidAuth => "invalid_auth",
+            SetTeamsError::AccountInactive => "account_inactive",
+            SetTeamsError::InvalidArgName => "invalid_arg_name",
+            SetTeamsError::InvalidArrayArg => "invalid_array_arg",
+            SetTeamsError::InvalidCharset => "invalid_charset",
+            SetTeamsError::InvalidFormData => "invalid_form_data",
+            SetTeamsError::InvalidPostType => "invalid_post_type",
+            SetTeamsError::MissingPostType => "missing_post_type",
+            SetTeamsError::TeamAddedToOrg => "team_added_to_org",
+            SetTeamsError::RequestTimeout => "request_timeout",
+            SetTeamsError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetTeamsError::Unknown(code) => return ::Error::from_code("admin.conversations.setTeams", &code),
+            SetTeamsError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.setTeams", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SetTeamsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetTeamsError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SetTeamsError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &SetTeamsError::RestrictedAction => "restricted_action: The workspace or organization settings do not allow this action.",
+            &SetTeamsError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &SetTeamsError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &SetTeamsError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &SetTeamsError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &SetTeamsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &SetTeamsError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &SetTeamsError::NotAuthed => "not_authed: No authentication token provided.",
+            &SetTeamsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SetTeamsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SetTeamsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SetTeamsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SetTeamsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SetTeamsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SetTeamsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SetTeamsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SetTeamsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SetTeamsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SetTeamsError::MalformedResponse(ref e) => e.description(),
+            &SetTeamsError::Unknown(ref s) => s,
+            &SetTeamsError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SetTeamsError::MalformedResponse(ref e) => Some(e),
+            &SetTeamsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Unarchive a public or private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.unarchive
+
+pub fn unarchive<R>(client: &R,
+                    token: &str,
+                    request: &UnarchiveRequest)
+                    -> Result<UnarchiveResponse, UnarchiveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    let url = ::get_slack_url_for_method("admin.conversations.unarchive");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| UnarchiveError::Client(err))
+        .and_then(|result| {
+                      parse_unarchive_response(result.body.as_bytes()).map_err(|err| err.into_error(UnarchiveError::MalformedResponse))
+                  })
+}
+
+fn parse_unarchive_response(body: &[u8]) -> Result<UnarchiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UnarchiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UnarchiveRequest<'a> {
+    /// The channel to act on.
+    pub channel_id: &'a str,
+}
+
+impl<'a> UnarchiveRequest<'a> {
+    pub fn new(channel_id: &'a str) -> Self {
+        UnarchiveRequest {
+            channel_id: channel_id,
+            ..UnarchiveRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UnarchiveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveResponse {
+    fn into(self) -> Result<UnarchiveResponse, UnarchiveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UnarchiveError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// The channel is not archived.
+    ChannelNotArchived,
+    /// The workspace or organization settings do not allow this action.
+    RestrictedAction,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => UnarchiveError::ChannelNotFound,
+            "channel_not_archived" => UnarchiveError::ChannelNotArchived,
+            "restricted_action" => UnarchiveError::RestrictedAction,
+            "feature_not_enabled" => UnarchiveError::FeatureNotEnabled,
+            "not_an_admin" => UnarchiveError::NotAnAdmin,
+            "not_an_enterprise" => UnarchiveError::NotAnEnterprise,
+            "team_not_found" => UnarchiveError::TeamNotFound,
+            "missing_scope" => UnarchiveError::MissingScope,
+            "not_allowed_token_type" => UnarchiveError::NotAllowedTokenType,
+            "not_authed" => UnarchiveError::NotAuthed,
+            "invalid_auth" => UnarchiveError::InvalidAuth,
+            "account_inactive" => UnarchiveError::AccountInactive,
+            "invalid_arg_name" => UnarchiveError::InvalidArgName,
+            "invalid_array_arg" => UnarchiveError::InvalidArrayArg,
+            "invalid_charset" => UnarchiveError::InvalidCharset,
+            "invalid_form_data" => UnarchiveError::InvalidFormData,
+            "invalid_post_type" => UnarchiveError::InvalidPostType,
+            "missing_post_type" => UnarchiveError::MissingPostType,
+            "team_added_to_org" => UnarchiveError::TeamAddedToOrg,
+            "request_timeout" => UnarchiveError::RequestTimeout,
+            _ => UnarchiveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<UnarchiveError<E>> for ::Error<E> {
+    fn from(err: UnarchiveError<E>) -> Self {
+        let code = match err {
+            UnarchiveError::ChannelNotFound => "channel_not_found",
+            UnarchiveError::ChannelNotArchived => "channel_not_archived",
+            UnarchiveError::RestrictedAction => "restricted_action",
+            UnarchiveError::FeatureNotEnabled => "feature_not_enabled",
+            UnarchiveError::NotAnAdmin => "not_an_admin",
+            UnarchiveError::NotAnEnterprise => "not_an_enterprise",
+            UnarchiveError::TeamNotFound => "team_not_found",
+            UnarchiveError::MissingScope => "missing_scope",
+            UnarchiveError::NotAllowedTokenType => "not_allowed_token_type",
+            UnarchiveError::NotAuthed => "not_authed",
+            UnarchiveError::InvalidAuth => "invalid_auth",
+            UnarchiveError::AccountInactive => "account_inactive",
+            UnarchiveError::InvalidArgName => "invalid_arg_name",
+            UnarchiveError::InvalidArrayArg => "invalid_array_arg",
+            UnarchiveError::InvalidCharset => "invalid_charset",
+            UnarchiveError::InvalidFormData => "invalid_form_data",
+            UnarchiveError::InvalidPostType => "invalid_post_type",
+            UnarchiveError::MissingPostType => "missing_post_type",
+            UnarchiveError::TeamAddedToOrg => "team_added_to_org",
+            UnarchiveError::RequestTimeout => "request_timeout",
+            UnarchiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UnarchiveError::Unknown(code) => return ::Error::from_code("admin.conversations.unarchive", &code),
+            UnarchiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.conversations.unarchive", code)
+    }
+}
+
+impl<E: Error> fmt::Display for UnarchiveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UnarchiveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &UnarchiveError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &UnarchiveError::ChannelNotArchived => "channel_not_archived: The channel is not archived.",
+            &UnarchiveError::RestrictedAction => "restricted_action: The workspace or organization settings do not allow this action.",
+            &UnarchiveError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &UnarchiveError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &UnarchiveError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &UnarchiveError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &UnarchiveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &UnarchiveError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &UnarchiveError::NotAuthed => "not_authed: No authentication token provided.",
+            &UnarchiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &UnarchiveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &UnarchiveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &UnarchiveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &UnarchiveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &UnarchiveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &UnarchiveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &UnarchiveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &UnarchiveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &UnarchiveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &UnarchiveError::MalformedResponse(ref e) => e.description(),
+            &UnarchiveError::Unknown(ref s) => s,
+            &UnarchiveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &UnarchiveError::MalformedResponse(ref e) => Some(e),
+            &UnarchiveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `admin.conversations.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::admin_conversations`](../client/struct.SlackClient.html#method.admin_conversations).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Archive a public or private channel. See [`archive`](fn.archive.html).
+    pub fn archive(&self, request: &ArchiveRequest) -> Result<ArchiveResponse, ArchiveError<R::Error>> {
+        archive(self.client, self.token.for_method("admin.conversations.archive"), request)
+    }
+
+    /// Create a public or private channel-based conversation. See [`create`](fn.create.html).
+    pub fn create(&self, request: &CreateRequest) -> Result<CreateResponse, CreateError<R::Error>> {
+        create(self.client, self.token.for_method("admin.conversations.create"), request)
+    }
+
+    /// Delete a public or private channel. See [`delete`](fn.delete.html).
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<R::Error>> {
+        delete(self.client, self.token.for_method("admin.conversations.delete"), request)
+    }
+
+    /// Get all the workspaces a given public or private channel is connected to within this
+    /// Enterprise org. See [`get_teams`](fn.get_teams.html).
+    pub fn get_teams(&self, request: &GetTeamsRequest) -> Result<GetTeamsResponse, GetTeamsError<R::Error>> {
+        get_teams(self.client, self.token.for_method("admin.conversations.getTeams"), request)
+    }
+
+    /// Like [`get_teams`](fn.get_teams.html), but follows `response_metadata.next_cursor` from page
+    /// to page, yielding the `team_ids` of every page in turn.
+    pub fn get_teams_all(&self, request: &GetTeamsRequest<'a>) -> ::cursor::Items<'a, String, GetTeamsError<R::Error>> {
+        get_teams_all(self.client, self.token.for_method("admin.conversations.getTeams"), request)
+    }
+
+    /// Invite a user to a public or private channel. See [`invite`](fn.invite.html).
+    pub fn invite(&self, request: &InviteRequest) -> Result<InviteResponse, InviteError<R::Error>> {
+        invite(self.client, self.token.for_method("admin.conversations.invite"), request)
+    }
+
+    /// Rename a public or private channel. See [`rename`](fn.rename.html).
+    pub fn rename(&self, request: &RenameRequest) -> Result<RenameResponse, RenameError<R::Error>> {
+        rename(self.client, self.token.for_method("admin.conversations.rename"), request)
+    }
+
+    /// Search for public or private channels in an Enterprise organization. See
+    /// [`search`](fn.search.html).
+    pub fn search(&self, request: &SearchRequest) -> Result<SearchResponse, SearchError<R::Error>> {
+        search(self.client, self.token.for_method("admin.conversations.search"), request)
+    }
+
+    /// Like [`search`](fn.search.html), but follows `next_cursor` from page to page, yielding the
+    /// `conversations` of every page in turn.
+    pub fn search_all(&self, request: &SearchRequest<'a>)
+                      -> ::cursor::Items<'a, SearchResponseConversation, SearchError<R::Error>> {
+        search_all(self.client, self.token.for_method("admin.conversations.search"), request)
+    }
+
+    /// Set the workspaces in an Enterprise grid org that connect to a public or private channel.
+    /// See [`set_teams`](fn.set_teams.html).
+    pub fn set_teams(&self, request: &SetTeamsRequest) -> Result<SetTeamsResponse, SetTeamsError<R::Error>> {
+        set_teams(self.client, self.token.for_method("admin.conversations.setTeams"), request)
+    }
+
+    /// Unarchive a public or private channel. See [`unarchive`](fn.unarchive.html).
+    pub fn unarchive(&self, request: &UnarchiveRequest) -> Result<UnarchiveResponse, UnarchiveError<R::Error>> {
+        unarchive(self.client, self.token.for_method("admin.conversations.unarchive"), request)
+    }
+}
@@ -0,0 +1,1222 @@
+//! Manage the custom emoji of an Enterprise Grid organization.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Add an emoji.
+///
+/// Wraps https://api.slack.com/methods/admin.emoji.add
+
+pub fn add<R>(client: &R,
+              token: &str,
+              request: &AddRequest)
+              -> Result<AddResponse, AddError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("name".to_owned(), request.name.into());
+    body.insert("url".to_owned(), request.url.into());
+    let url = ::get_slack_url_for_method("admin.emoji.add");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| AddError::Client(err))
+        .and_then(|result| {
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
+                  })
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AddRequest<'a> {
+    /// The name of the emoji to be added. Colons (:myemoji:) around the value are not required, although they may be included.
+    pub name: &'a str,
+    /// The URL of a file to use as an image for the emoji. Square images under 128KB and with transparent backgrounds work best.
+    pub url: &'a str,
+}
+
+impl<'a> AddRequest<'a> {
+    pub fn new(name: &'a str, url: &'a str) -> Self {
+        AddRequest {
+            name: name,
+            url: url,
+            ..AddRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
+    fn into(self) -> Result<AddResponse, AddError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddError<E: Error> {
+    /// The name is already used by another emoji.
+    ErrorNameTaken,
+    /// The name is not a valid emoji name.
+    ErrorBadNameI18n,
+    /// The URL did not point at a usable image.
+    ErrorNoImage,
+    /// The image is larger than 128KB.
+    ErrorTooBig,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "error_name_taken" => AddError::ErrorNameTaken,
+            "error_bad_name_i18n" => AddError::ErrorBadNameI18n,
+            "error_no_image" => AddError::ErrorNoImage,
+            "error_too_big" => AddError::ErrorTooBig,
+            "feature_not_enabled" => AddError::FeatureNotEnabled,
+            "not_an_admin" => AddError::NotAnAdmin,
+            "not_an_enterprise" => AddError::NotAnEnterprise,
+            "team_not_found" => AddError::TeamNotFound,
+            "missing_scope" => AddError::MissingScope,
+            "not_allowed_token_type" => AddError::NotAllowedTokenType,
+            "not_authed" => AddError::NotAuthed,
+            "invalid_auth" => AddError::InvalidAuth,
+            "account_inactive" => AddError::AccountInactive,
+            "invalid_arg_name" => AddError::InvalidArgName,
+            "invalid_array_arg" => AddError::InvalidArrayArg,
+            "invalid_charset" => AddError::InvalidCharset,
+            "invalid_form_data" => AddError::InvalidFormData,
+            "invalid_post_type" => AddError::InvalidPostType,
+            "missing_post_type" => AddError::MissingPostType,
+            "team_added_to_org" => AddError::TeamAddedToOrg,
+            "request_timeout" => AddError::RequestTimeout,
+            _ => AddError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::ErrorNameTaken => "error_name_taken",
+            AddError::ErrorBadNameI18n => "error_bad_name_i18n",
+            AddError::ErrorNoImage => "error_no_image",
+            AddError::ErrorTooBig => "error_too_big",
+            AddError::FeatureNotEnabled => "feature_not_enabled",
+            AddError::NotAnAdmin => "not_an_admin",
+            AddError::NotAnEnterprise => "not_an_enterprise",
+            AddError::TeamNotFound => "team_not_found",
+            AddError::MissingScope => "missing_scope",
+            AddError::NotAllowedTokenType => "not_allowed_token_type",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("admin.emoji.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.emoji.add", code)
+    }
+}
+
+impl<E: Error> fmt::Display for AddError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AddError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &AddError::ErrorNameTaken => "error_name_taken: The name is already used by another emoji.",
+            &AddError::ErrorBadNameI18n => "error_bad_name_i18n: The name is not a valid emoji name.",
+            &AddError::ErrorNoImage => "error_no_image: The URL did not point at a usable image.",
+            &AddError::ErrorTooBig => "error_too_big: The image is larger than 128KB.",
+            &AddError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &AddError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &AddError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &AddError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &AddError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &AddError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &AddError::NotAuthed => "not_authed: No authentication token provided.",
+            &AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &AddError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &AddError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &AddError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &AddError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &AddError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &AddError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &AddError::MalformedResponse(ref e) => e.description(),
+            &AddError::Unknown(ref s) => s,
+            &AddError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &AddError::MalformedResponse(ref e) => Some(e),
+            &AddError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Add an emoji alias.
+///
+/// Wraps https://api.slack.com/methods/admin.emoji.addAlias
+
+pub fn add_alias<R>(client: &R,
+                    token: &str,
+                    request: &AddAliasRequest)
+                    -> Result<AddAliasResponse, AddAliasError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("alias_for".to_owned(), request.alias_for.into());
+    body.insert("name".to_owned(), request.name.into());
+    let url = ::get_slack_url_for_method("admin.emoji.addAlias");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| AddAliasError::Client(err))
+        .and_then(|result| {
+                      parse_add_alias_response(result.body.as_bytes()).map_err(|err| err.into_error(AddAliasError::MalformedResponse))
+                  })
+}
+
+fn parse_add_alias_response(body: &[u8]) -> Result<AddAliasResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddAliasResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AddAliasRequest<'a> {
+    /// The alias of the emoji.
+    pub alias_for: &'a str,
+    /// The name of the emoji to be aliased. Colons (:myemoji:) around the value are not required, although they may be included.
+    pub name: &'a str,
+}
+
+impl<'a> AddAliasRequest<'a> {
+    pub fn new(alias_for: &'a str, name: &'a str) -> Self {
+        AddAliasRequest {
+            alias_for: alias_for,
+            name: name,
+            ..AddAliasRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddAliasResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<AddAliasResponse, AddAliasError<E>>> for AddAliasResponse {
+    fn into(self) -> Result<AddAliasResponse, AddAliasError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddAliasError<E: Error> {
+    /// No emoji with that name exists.
+    EmojiNotFound,
+    /// The name is already used by another emoji.
+    ErrorNameTaken,
+    /// The name is not a valid emoji name.
+    ErrorBadNameI18n,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddAliasError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "emoji_not_found" => AddAliasError::EmojiNotFound,
+            "error_name_taken" => AddAliasError::ErrorNameTaken,
+            "error_bad_name_i18n" => AddAliasError::ErrorBadNameI18n,
+            "feature_not_enabled" => AddAliasError::FeatureNotEnabled,
+            "not_an_admin" => AddAliasError::NotAnAdmin,
+            "not_an_enterprise" => AddAliasError::NotAnEnterprise,
+            "team_not_found" => AddAliasError::TeamNotFound,
+            "missing_scope" => AddAliasError::MissingScope,
+            "not_allowed_token_type" => AddAliasError::NotAllowedTokenType,
+            "not_authed" => AddAliasError::NotAuthed,
+            "invalid_auth" => AddAliasError::InvalidAuth,
+            "account_inactive" => AddAliasError::AccountInactive,
+            "invalid_arg_name" => AddAliasError::InvalidArgName,
+            "invalid_array_arg" => AddAliasError::InvalidArrayArg,
+            "invalid_charset" => AddAliasError::InvalidCharset,
+            "invalid_form_data" => AddAliasError::InvalidFormData,
+            "invalid_post_type" => AddAliasError::InvalidPostType,
+            "missing_post_type" => AddAliasError::MissingPostType,
+            "team_added_to_org" => AddAliasError::TeamAddedToOrg,
+            "request_timeout" => AddAliasError::RequestTimeout,
+            _ => AddAliasError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<AddAliasError<E>> for ::Error<E> {
+    fn from(err: AddAliasError<E>) -> Self {
+        let code = match err {
+            AddAliasError::EmojiNotFound => "emoji_not_found",
+            AddAliasError::ErrorNameTaken => "error_name_taken",
+            AddAliasError::ErrorBadNameI18n => "error_bad_name_i18n",
+            AddAliasError::FeatureNotEnabled => "feature_not_enabled",
+            AddAliasError::NotAnAdmin => "not_an_admin",
+            AddAliasError::NotAnEnterprise => "not_an_enterprise",
+            AddAliasError::TeamNotFound => "team_not_found",
+            AddAliasError::MissingScope => "missing_scope",
+            AddAliasError::NotAllowedTokenType => "not_allowed_token_type",
+            AddAliasError::NotAuthed => "not_authed",
+            AddAliasError::InvalidAuth => "invalid_auth",
+            AddAliasError::AccountInactive => "account_inactive",
+            AddAliasError::InvalidArgName => "invalid_arg_name",
+            AddAliasError::InvalidArrayArg => "invalid_array_arg",
+            AddAliasError::InvalidCharset => "invalid_charset",
+            AddAliasError::InvalidFormData => "invalid_form_data",
+            AddAliasError::InvalidPostType => "invalid_post_type",
+            AddAliasError::MissingPostType => "missing_post_type",
+            AddAliasError::TeamAddedToOrg => "team_added_to_org",
+            AddAliasError::RequestTimeout => "request_timeout",
+            AddAliasError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddAliasError::Unknown(code) => return ::Error::from_code("admin.emoji.addAlias", &code),
+            AddAliasError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.emoji.addAlias", code)
+    }
+}
+
+impl<E: Error> fmt::Display for AddAliasError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AddAliasError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &AddAliasError::EmojiNotFound => "emoji_not_found: No emoji with that name exists.",
+            &AddAliasError::ErrorNameTaken => "error_name_taken: The name is already used by another emoji.",
+            &AddAliasError::ErrorBadNameI18n => "error_bad_name_i18n: The name is not a valid emoji name.",
+            &AddAliasError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &AddAliasError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &AddAliasError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &AddAliasError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &AddAliasError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &AddAliasError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &AddAliasError::NotAuthed => "not_authed: No authentication token provided.",
+            &AddAliasError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &AddAliasError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &AddAliasError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &AddAliasError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &AddAliasError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &AddAliasError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &AddAliasError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &AddAliasError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &AddAliasError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &AddAliasError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &AddAliasError::MalformedResponse(ref e) => e.description(),
+            &AddAliasError::Unknown(ref s) => s,
+            &AddAliasError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &AddAliasError::MalformedResponse(ref e) => Some(e),
+            &AddAliasError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// List emoji for an Enterprise Grid organization.
+///
+/// Wraps https://api.slack.com/methods/admin.emoji.list
+
+pub fn list<R>(client: &R,
+               token: &str,
+               request: &ListRequest)
+               -> Result<ListResponse, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    let url = ::get_slack_url_for_method("admin.emoji.list");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| ListError::Client(err))
+        .and_then(|result| {
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
+                  })
+}
+
+/// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `emoji` of every page in turn.
+pub fn list_all<'a, R>(client: &'a R,
+                       token: &'a str,
+                       request: &ListRequest<'a>)
+                       -> ::cursor::Items<'a, (String, ListResponseEmoji), ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = ListRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        list(client, token, &request).map(|response| {
+            let emoji = response.emoji.unwrap_or_default().into_iter().collect();
+            (emoji, response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Set cursor to next_cursor returned by the previous call to list items in the next page.
+    pub cursor: Option<&'a str>,
+    /// The maximum number of items to return. Must be between 1 - 1000 both inclusive.
+    pub limit: Option<u32>,
+}
+
+impl<'a> ListRequest<'a> {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    pub emoji: Option<HashMap<String, ListResponseEmoji>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponseEmoji {
+    pub date_created: Option<i64>,
+    pub uploaded_by: Option<String>,
+    pub url: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_cursor" => ListError::InvalidCursor,
+            "feature_not_enabled" => ListError::FeatureNotEnabled,
+            "not_an_admin" => ListError::NotAnAdmin,
+            "not_an_enterprise" => ListError::NotAnEnterprise,
+            "team_not_found" => ListError::TeamNotFound,
+            "missing_scope" => ListError::MissingScope,
+            "not_allowed_token_type" => ListError::NotAllowedTokenType,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::InvalidCursor => "invalid_cursor",
+            ListError::FeatureNotEnabled => "feature_not_enabled",
+            ListError::NotAnAdmin => "not_an_admin",
+            ListError::NotAnEnterprise => "not_an_enterprise",
+            ListError::TeamNotFound => "team_not_found",
+            ListError::MissingScope => "missing_scope",
+            ListError::NotAllowedTokenType => "not_allowed_token_type",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("admin.emoji.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.emoji.list", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ListError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &ListError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &ListError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &ListError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &ListError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ListError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &ListError::NotAuthed => "not_authed: No authentication token provided.",
+            &ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ListError::MalformedResponse(ref e) => e.description(),
+            &ListError::Unknown(ref s) => s,
+            &ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ListError::MalformedResponse(ref e) => Some(e),
+            &ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Remove an emoji across an Enterprise Grid organization
+///
+/// Wraps https://api.slack.com/methods/admin.emoji.remove
+
+pub fn remove<R>(client: &R,
+                 token: &str,
+                 request: &RemoveRequest)
+                 -> Result<RemoveResponse, RemoveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("name".to_owned(), request.name.into());
+    let url = ::get_slack_url_for_method("admin.emoji.remove");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| RemoveError::Client(err))
+        .and_then(|result| {
+                      parse_remove_response(result.body.as_bytes()).map_err(|err| err.into_error(RemoveError::MalformedResponse))
+                  })
+}
+
+fn parse_remove_response(body: &[u8]) -> Result<RemoveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RemoveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveRequest<'a> {
+    /// The name of the emoji to be removed. Colons (:myemoji:) around the value are not required, although they may be included.
+    pub name: &'a str,
+}
+
+impl<'a> RemoveRequest<'a> {
+    pub fn new(name: &'a str) -> Self {
+        RemoveRequest {
+            name: name,
+            ..RemoveRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
+    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveError<E: Error> {
+    /// No emoji with that name exists.
+    EmojiNotFound,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "emoji_not_found" => RemoveError::EmojiNotFound,
+            "feature_not_enabled" => RemoveError::FeatureNotEnabled,
+            "not_an_admin" => RemoveError::NotAnAdmin,
+            "not_an_enterprise" => RemoveError::NotAnEnterprise,
+            "team_not_found" => RemoveError::TeamNotFound,
+            "missing_scope" => RemoveError::MissingScope,
+            "not_allowed_token_type" => RemoveError::NotAllowedTokenType,
+            "not_authed" => RemoveError::NotAuthed,
+            "invalid_auth" => RemoveError::InvalidAuth,
+            "account_inactive" => RemoveError::AccountInactive,
+            "invalid_arg_name" => RemoveError::InvalidArgName,
+            "invalid_array_arg" => RemoveError::InvalidArrayArg,
+            "invalid_charset" => RemoveError::InvalidCharset,
+            "invalid_form_data" => RemoveError::InvalidFormData,
+            "invalid_post_type" => RemoveError::InvalidPostType,
+            "missing_post_type" => RemoveError::MissingPostType,
+            "team_added_to_org" => RemoveError::TeamAddedToOrg,
+            "request_timeout" => RemoveError::RequestTimeout,
+            _ => RemoveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RemoveError<E>> for ::Error<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        let code = match err {
+            RemoveError::EmojiNotFound => "emoji_not_found",
+            RemoveError::FeatureNotEnabled => "feature_not_enabled",
+            RemoveError::NotAnAdmin => "not_an_admin",
+            RemoveError::NotAnEnterprise => "not_an_enterprise",
+            RemoveError::TeamNotFound => "team_not_found",
+            RemoveError::MissingScope => "missing_scope",
+            RemoveError::NotAllowedTokenType => "not_allowed_token_type",
+            RemoveError::NotAuthed => "not_authed",
+            RemoveError::InvalidAuth => "invalid_auth",
+            RemoveError::AccountInactive => "account_inactive",
+            RemoveError::InvalidArgName => "invalid_arg_name",
+            RemoveError::InvalidArrayArg => "invalid_array_arg",
+            RemoveError::InvalidCharset => "invalid_charset",
+            RemoveError::InvalidFormData => "invalid_form_data",
+            RemoveError::InvalidPostType => "invalid_post_type",
+            RemoveError::MissingPostType => "missing_post_type",
+            RemoveError::TeamAddedToOrg => "team_added_to_org",
+            RemoveError::RequestTimeout => "request_timeout",
+            RemoveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RemoveError::Unknown(code) => return ::Error::from_code("admin.emoji.remove", &code),
+            RemoveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.emoji.remove", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RemoveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RemoveError::EmojiNotFound => "emoji_not_found: No emoji with that name exists.",
+            &RemoveError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &RemoveError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &RemoveError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &RemoveError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &RemoveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RemoveError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &RemoveError::NotAuthed => "not_authed: No authentication token provided.",
+            &RemoveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RemoveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RemoveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RemoveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RemoveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RemoveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RemoveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RemoveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RemoveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RemoveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RemoveError::MalformedResponse(ref e) => e.description(),
+            &RemoveError::Unknown(ref s) => s,
+            &RemoveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RemoveError::MalformedResponse(ref e) => Some(e),
+            &RemoveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Rename an emoji.
+///
+/// Wraps https://api.slack.com/methods/admin.emoji.rename
+
+pub fn rename<R>(client: &R,
+                 token: &str,
+                 request: &RenameRequest)
+                 -> Result<RenameResponse, RenameError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("name".to_owned(), request.name.into());
+    body.insert("new_name".to_owned(), request.new_name.into());
+    let url = ::get_slack_url_for_method("admin.emoji.rename");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| RenameError::Client(err))
+        .and_then(|result| {
+                      parse_rename_response(result.body.as_bytes()).map_err(|err| err.into_error(RenameError::MalformedResponse))
+                  })
+}
+
+fn parse_rename_response(body: &[u8]) -> Result<RenameResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RenameResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RenameRequest<'a> {
+    /// The name of the emoji to be renamed. Colons (:myemoji:) around the value are not required, although they may be included.
+    pub name: &'a str,
+    /// The new name of the emoji.
+    pub new_name: &'a str,
+}
+
+impl<'a> RenameRequest<'a> {
+    pub fn new(name: &'a str, new_name: &'a str) -> Self {
+        RenameRequest {
+            name: name,
+            new_name: new_name,
+            ..RenameRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RenameResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
+    fn into(self) -> Result<RenameResponse, RenameError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RenameError<E: Error> {
+    /// No emoji with that name exists.
+    EmojiNotFound,
+    /// The name is already used by another emoji.
+    ErrorNameTaken,
+    /// The name is not a valid emoji name.
+    ErrorBadNameI18n,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RenameError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "emoji_not_found" => RenameError::EmojiNotFound,
+            "error_name_taken" => RenameError::ErrorNameTaken,
+            "error_bad_name_i18n" => RenameError::ErrorBadNameI18n,
+            "feature_not_enabled" => RenameError::FeatureNotEnabled,
+            "not_an_admin" => RenameError::NotAnAdmin,
+            "not_an_enterprise" => RenameError::NotAnEnterprise,
+            "team_not_found" => RenameError::TeamNotFound,
+            "missing_scope" => RenameError::MissingScope,
+            "not_allowed_token_type" => RenameError::NotAllowedTokenType,
+            "not_authed" => RenameError::NotAuthed,
+            "invalid_auth" => RenameError::InvalidAuth,
+            "account_inactive" => RenameError::AccountInactive,
+            "invalid_arg_name" => RenameError::InvalidArgName,
+            "invalid_array_arg" => RenameError::InvalidArrayArg,
+            "invalid_charset" => RenameError::InvalidCharset,
+            "invalid_form_data" => RenameError::InvalidFormData,
+            "invalid_post_type" => RenameError::InvalidPostType,
+            "missing_post_type" => RenameError::MissingPostType,
+            "team_added_to_org" => RenameError::TeamAddedToOrg,
+            "request_timeout" => RenameError::RequestTimeout,
+            _ => RenameError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RenameError<E>> for ::Error<E> {
+    fn from(err: RenameError<E>) -> Self {
+        let code = match err {
+            RenameError::EmojiNotFound => "emoji_not_found",
+            RenameError::ErrorNameTaken => "error_name_taken",
+            RenameError::ErrorBadNameI18n => "error_bad_name_i18n",
+            RenameError::FeatureNotEnabled => "feature_not_enabled",
+            RenameError::NotAnAdmin => "not_an_admin",
+            RenameError::NotAnEnterprise => "not_an_enterprise",
+            RenameError::TeamNotFound => "team_not_found",
+            RenameError::MissingScope => "missing_scope",
+            RenameError::NotAllowedTokenType => "not_allowed_token_type",
+            RenameError::NotAuthed => "not_authed",
+            RenameError::InvalidAuth => "invalid_auth",
+            RenameError::AccountInactive => "account_inactive",
+            RenameError::InvalidArgName => "invalid_arg_name",
+            RenameError::InvalidArrayArg => "invalid_array_arg",
+            RenameError::InvalidCharset => "invalid_charset",
+            RenameError::InvalidFormData => "invalid_form_data",
+            RenameError::InvalidPostType => "invalid_post_type",
+            RenameError::MissingPostType => "missing_post_type",
+            RenameError::TeamAddedToOrg => "team_added_to_org",
+            RenameError::RequestTimeout => "request_timeout",
+            RenameError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RenameError::Unknown(code) => return ::Error::from_code("admin.emoji.rename", &code),
+            RenameError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.emoji.rename", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RenameError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RenameError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RenameError::EmojiNotFound => "emoji_not_found: No emoji with that name exists.",
+            &RenameError::ErrorNameTaken => "error_name_taken: The name is already used by another emoji.",
+            &RenameError::ErrorBadNameI18n => "error_bad_name_i18n: The name is not a valid emoji name.",
+            &RenameError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &RenameError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &RenameError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &RenameError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &RenameError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RenameError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &RenameError::NotAuthed => "not_authed: No authentication token provided.",
+            &RenameError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RenameError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RenameError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RenameError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RenameError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RenameError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RenameError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RenameError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RenameError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RenameError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RenameError::MalformedResponse(ref e) => e.description(),
+            &RenameError::Unknown(ref s) => s,
+            &RenameError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RenameError::MalformedResponse(ref e) => Some(e),
+            &RenameError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `admin.emoji.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::admin_emoji`](../client/struct.SlackClient.html#method.admin_emoji).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Add an emoji. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("admin.emoji.add"), request)
+    }
+
+    /// Add an emoji alias. See [`add_alias`](fn.add_alias.html).
+    pub fn add_alias(&self, request: &AddAliasRequest) -> Result<AddAliasResponse, AddAliasError<R::Error>> {
+        add_alias(self.client, self.token.for_method("admin.emoji.addAlias"), request)
+    }
+
+    /// List emoji for an Enterprise Grid organization. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("admin.emoji.list"), request)
+    }
+
+    /// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+    /// yielding the `emoji` of every page in turn.
+    pub fn list_all(&self, request: &ListRequest<'a>)
+                    -> ::cursor::Items<'a, (String, ListResponseEmoji), ListError<R::Error>> {
+        list_all(self.client, self.token.for_method("admin.emoji.list"), request)
+    }
+
+    /// Remove an emoji across an Enterprise Grid organization. See [`remove`](fn.remove.html).
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<R::Error>> {
+        remove(self.client, self.token.for_method("admin.emoji.remove"), request)
+    }
+
+    /// Rename an emoji. See [`rename`](fn.rename.html).
+    pub fn rename(&self, request: &RenameRequest) -> Result<RenameResponse, RenameError<R::Error>> {
+        rename(self.client, self.token.for_method("admin.emoji.rename"), request)
+    }
+}
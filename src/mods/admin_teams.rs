@@ -0,0 +1,551 @@
+//! Manage the workspaces of an Enterprise Grid organization.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Create an Enterprise team.
+///
+/// Wraps https://api.slack.com/methods/admin.teams.create
+
+pub fn create<R>(client: &R,
+                 token: &str,
+                 request: &CreateRequest)
+                 -> Result<CreateResponse, CreateError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(team_description) = request.team_description {
+        body.insert("team_description".to_owned(), team_description.into());
+    }
+    if let Some(team_discoverability) = request.team_discoverability {
+        body.insert("team_discoverability".to_owned(), team_discoverability.into());
+    }
+    body.insert("team_domain".to_owned(), request.team_domain.into());
+    body.insert("team_name".to_owned(), request.team_name.into());
+    let url = ::get_slack_url_for_method("admin.teams.create");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| CreateError::Client(err))
+        .and_then(|result| {
+                      parse_create_response(result.body.as_bytes()).map_err(|err| err.into_error(CreateError::MalformedResponse))
+                  })
+}
+
+fn parse_create_response(body: &[u8]) -> Result<CreateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CreateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequest<'a> {
+    /// Description for the team.
+    pub team_description: Option<&'a str>,
+    /// Who can join the team. A team's discoverability can be open, closed, invite_only, or unlisted.
+    pub team_discoverability: Option<&'a str>,
+    /// Team domain (for example, slacksoftballteam).
+    pub team_domain: &'a str,
+    /// Team name (for example, Slack Softball Team).
+    pub team_name: &'a str,
+}
+
+impl<'a> CreateRequest<'a> {
+    pub fn new(team_domain: &'a str, team_name: &'a str) -> Self {
+        CreateRequest {
+            team_domain: team_domain,
+            team_name: team_name,
+            ..CreateRequest::default()
+        }
+    }
+
+    pub fn team_description(mut self, team_description: &'a str) -> Self {
+        self.team_description = Some(team_description);
+        self
+    }
+
+    pub fn team_discoverability(mut self, team_discoverability: &'a str) -> Self {
+        self.team_discoverability = Some(team_discoverability);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub team: Option<String>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
+    fn into(self) -> Result<CreateResponse, CreateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CreateError<E: Error> {
+    /// The team domain is already taken.
+    DomainTaken,
+    /// The team domain is not valid.
+    InvalidDomain,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CreateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "domain_taken" => CreateError::DomainTaken,
+            "invalid_domain" => CreateError::InvalidDomain,
+            "feature_not_enabled" => CreateError::FeatureNotEnabled,
+            "not_an_admin" => CreateError::NotAnAdmin,
+            "not_an_enterprise" => CreateError::NotAnEnterprise,
+            "team_not_found" => CreateError::TeamNotFound,
+            "missing_scope" => CreateError::MissingScope,
+            "not_allowed_token_type" => CreateError::NotAllowedTokenType,
+            "not_authed" => CreateError::NotAuthed,
+            "invalid_auth" => CreateError::InvalidAuth,
+            "account_inactive" => CreateError::AccountInactive,
+            "invalid_arg_name" => CreateError::InvalidArgName,
+            "invalid_array_arg" => CreateError::InvalidArrayArg,
+            "invalid_charset" => CreateError::InvalidCharset,
+            "invalid_form_data" => CreateError::InvalidFormData,
+            "invalid_post_type" => CreateError::InvalidPostType,
+            "missing_post_type" => CreateError::MissingPostType,
+            "team_added_to_org" => CreateError::TeamAddedToOrg,
+            "request_timeout" => CreateError::RequestTimeout,
+            _ => CreateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<CreateError<E>> for ::Error<E> {
+    fn from(err: CreateError<E>) -> Self {
+        let code = match err {
+            CreateError::DomainTaken => "domain_taken",
+            CreateError::InvalidDomain => "invalid_domain",
+            CreateError::FeatureNotEnabled => "feature_not_enabled",
+            CreateError::NotAnAdmin => "not_an_admin",
+            CreateError::NotAnEnterprise => "not_an_enterprise",
+            CreateError::TeamNotFound => "team_not_found",
+            CreateError::MissingScope => "missing_scope",
+            CreateError::NotAllowedTokenType => "not_allowed_token_type",
+            CreateError::NotAuthed => "not_authed",
+            CreateError::InvalidAuth => "invalid_auth",
+            CreateError::AccountInactive => "account_inactive",
+            CreateError::InvalidArgName => "invalid_arg_name",
+            CreateError::InvalidArrayArg => "invalid_array_arg",
+            CreateError::InvalidCharset => "invalid_charset",
+            CreateError::InvalidFormData => "invalid_form_data",
+            CreateError::InvalidPostType => "invalid_post_type",
+            CreateError::MissingPostType => "missing_post_type",
+            CreateError::TeamAddedToOrg => "team_added_to_org",
+            CreateError::RequestTimeout => "request_timeout",
+            CreateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CreateError::Unknown(code) => return ::Error::from_code("admin.teams.create", &code),
+            CreateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.teams.create", code)
+    }
+}
+
+impl<E: Error> fmt::Display for CreateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CreateError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &CreateError::DomainTaken => "domain_taken: The team domain is already taken.",
+            &CreateError::InvalidDomain => "invalid_domain: The team domain is not valid.",
+            &CreateError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &CreateError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &CreateError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &CreateError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &CreateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &CreateError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &CreateError::NotAuthed => "not_authed: No authentication token provided.",
+            &CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &CreateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &CreateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &CreateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &CreateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &CreateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &CreateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &CreateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &CreateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &CreateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &CreateError::MalformedResponse(ref e) => e.description(),
+            &CreateError::Unknown(ref s) => s,
+            &CreateError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &CreateError::MalformedResponse(ref e) => Some(e),
+            &CreateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// List all teams on an Enterprise organization
+///
+/// Wraps https://api.slack.com/methods/admin.teams.list
+
+pub fn list<R>(client: &R,
+               token: &str,
+               request: &ListRequest)
+               -> Result<ListResponse, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    let url = ::get_slack_url_for_method("admin.teams.list");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| ListError::Client(err))
+        .and_then(|result| {
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
+                  })
+}
+
+/// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `teams` of every page in turn.
+pub fn list_all<'a, R>(client: &'a R,
+                       token: &'a str,
+                       request: &ListRequest<'a>)
+                       -> ::cursor::Items<'a, ListResponseTeam, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = ListRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        list(client, token, &request).map(|response| {
+            (response.teams.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Set cursor to next_cursor returned by the previous call to list items in the next page.
+    pub cursor: Option<&'a str>,
+    /// The maximum number of items to return. Must be between 1 - 100 both inclusive.
+    pub limit: Option<u32>,
+}
+
+impl<'a> ListRequest<'a> {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub teams: Option<Vec<ListResponseTeam>>,
+    pub warning: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponseTeam {
+    pub discoverability: Option<String>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub primary_owner: Option<ListResponseTeamPrimaryOwner>,
+    pub team_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponseTeamPrimaryOwner {
+    pub email: Option<String>,
+    pub user_id: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_cursor" => ListError::InvalidCursor,
+            "feature_not_enabled" => ListError::FeatureNotEnabled,
+            "not_an_admin" => ListError::NotAnAdmin,
+            "not_an_enterprise" => ListError::NotAnEnterprise,
+            "team_not_found" => ListError::TeamNotFound,
+            "missing_scope" => ListError::MissingScope,
+            "not_allowed_token_type" => ListError::NotAllowedTokenType,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::InvalidCursor => "invalid_cursor",
+            ListError::FeatureNotEnabled => "feature_not_enabled",
+            ListError::NotAnAdmin => "not_an_admin",
+            ListError::NotAnEnterprise => "not_an_enterprise",
+            ListError::TeamNotFound => "team_not_found",
+            ListError::MissingScope => "missing_scope",
+            ListError::NotAllowedTokenType => "not_allowed_token_type",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("admin.teams.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.teams.list", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ListError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &ListError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &ListError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &ListError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &ListError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ListError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &ListError::NotAuthed => "not_authed: No authentication token provided.",
+            &ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ListError::MalformedResponse(ref e) => e.description(),
+            &ListError::Unknown(ref s) => s,
+            &ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ListError::MalformedResponse(ref e) => Some(e),
+            &ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `admin.teams.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::admin_teams`](../client/struct.SlackClient.html#method.admin_teams).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Create an Enterprise team. See [`create`](fn.create.html).
+    pub fn create(&self, request: &CreateRequest) -> Result<CreateResponse, CreateError<R::Error>> {
+        create(self.client, self.token.for_method("admin.teams.create"), request)
+    }
+
+    /// List all teams on an Enterprise organization. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("admin.teams.list"), request)
+    }
+
+    /// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+    /// yielding the `teams` of every page in turn.
+    pub fn list_all(&self, request: &ListRequest<'a>) -> ::cursor::Items<'a, ListResponseTeam, ListError<R::Error>> {
+        list_all(self.client, self.token.for_method("admin.teams.list"), request)
+    }
+}
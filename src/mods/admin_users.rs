@@ -0,0 +1,2029 @@
+//! Manage the members of the workspaces of an Enterprise Grid organization.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Add an Enterprise user to a workspace.
+///
+/// Wraps https://api.slack.com/methods/admin.users.assign
+
+pub fn assign<R>(client: &R,
+                 token: &str,
+                 request: &AssignRequest)
+                 -> Result<AssignResponse, AssignError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(channel_ids) = request.channel_ids {
+        body.insert("channel_ids".to_owned(), channel_ids.into());
+    }
+    if let Some(is_restricted) = request.is_restricted {
+        body.insert("is_restricted".to_owned(), is_restricted.into());
+    }
+    if let Some(is_ultra_restricted) = request.is_ultra_restricted {
+        body.insert("is_ultra_restricted".to_owned(), is_ultra_restricted.into());
+    }
+    body.insert("team_id".to_owned(), request.team_id.into());
+    body.insert("user_id".to_owned(), request.user_id.into());
+    let url = ::get_slack_url_for_method("admin.users.assign");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| AssignError::Client(err))
+        .and_then(|result| {
+                      parse_assign_response(result.body.as_bytes()).map_err(|err| err.into_error(AssignError::MalformedResponse))
+                  })
+}
+
+fn parse_assign_response(body: &[u8]) -> Result<AssignResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AssignResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AssignRequest<'a> {
+    /// The channels to add the user to, as a comma-separated list.
+    pub channel_ids: Option<&'a str>,
+    /// True if the user being added is a multi-channel guest.
+    pub is_restricted: Option<bool>,
+    /// True if the user being added is a single-channel guest.
+    pub is_ultra_restricted: Option<bool>,
+    /// The workspace to act in.
+    pub team_id: &'a str,
+    /// The user to act on.
+    pub user_id: &'a str,
+}
+
+impl<'a> AssignRequest<'a> {
+    pub fn new(team_id: &'a str, user_id: &'a str) -> Self {
+        AssignRequest {
+            team_id: team_id,
+            user_id: user_id,
+            ..AssignRequest::default()
+        }
+    }
+
+    pub fn channel_ids(mut self, channel_ids: &'a str) -> Self {
+        self.channel_ids = Some(channel_ids);
+        self
+    }
+
+    pub fn is_restricted(mut self, is_restricted: bool) -> Self {
+        self.is_restricted = Some(is_restricted);
+        self
+    }
+
+    pub fn is_ultra_restricted(mut self, is_ultra_restricted: bool) -> Self {
+        self.is_ultra_restricted = Some(is_ultra_restricted);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AssignResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<AssignResponse, AssignError<E>>> for AssignResponse {
+    fn into(self) -> Result<AssignResponse, AssignError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AssignError<E: Error> {
+    /// The user_id was not found.
+    UserNotFound,
+    /// The user is already a member of the workspace.
+    UserAlreadyTeamMember,
+    /// One of the channels was not found.
+    ChannelNotFound,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AssignError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "user_not_found" => AssignError::UserNotFound,
+            "user_already_team_member" => AssignError::UserAlreadyTeamMember,
+            "channel_not_found" => AssignError::ChannelNotFound,
+            "feature_not_enabled" => AssignError::FeatureNotEnabled,
+            "not_an_admin" => AssignError::NotAnAdmin,
+            "not_an_enterprise" => AssignError::NotAnEnterprise,
+            "team_not_found" => AssignError::TeamNotFound,
+            "missing_scope" => AssignError::MissingScope,
+            "not_allowed_token_type" => AssignError::NotAllowedTokenType,
+            "not_authed" => AssignError::NotAuthed,
+            "invalid_auth" => AssignError::InvalidAuth,
+            "account_inactive" => AssignError::AccountInactive,
+            "invalid_arg_name" => AssignError::InvalidArgName,
+            "invalid_array_arg" => AssignError::InvalidArrayArg,
+            "invalid_charset" => AssignError::InvalidCharset,
+            "invalid_form_data" => AssignError::InvalidFormData,
+            "invalid_post_type" => AssignError::InvalidPostType,
+            "missing_post_type" => AssignError::MissingPostType,
+            "team_added_to_org" => AssignError::TeamAddedToOrg,
+            "request_timeout" => AssignError::RequestTimeout,
+            _ => AssignError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<AssignError<E>> for ::Error<E> {
+    fn from(err: AssignError<E>) -> Self {
+        let code = match err {
+            AssignError::UserNotFound => "user_not_found",
+            AssignError::UserAlreadyTeamMember => "user_already_team_member",
+            AssignError::ChannelNotFound => "channel_not_found",
+            AssignError::FeatureNotEnabled => "feature_not_enabled",
+            AssignError::NotAnAdmin => "not_an_admin",
+            AssignError::NotAnEnterprise => "not_an_enterprise",
+            AssignError::TeamNotFound => "team_not_found",
+            AssignError::MissingScope => "missing_scope",
+            AssignError::NotAllowedTokenType => "not_allowed_token_type",
+            AssignError::NotAuthed => "not_authed",
+            AssignError::InvalidAuth => "invalid_auth",
+            AssignError::AccountInactive => "account_inactive",
+            AssignError::InvalidArgName => "invalid_arg_name",
+            AssignError::InvalidArrayArg => "invalid_array_arg",
+            AssignError::InvalidCharset => "invalid_charset",
+            AssignError::InvalidFormData => "invalid_form_data",
+            AssignError::InvalidPostType => "invalid_post_type",
+            AssignError::MissingPostType => "missing_post_type",
+            AssignError::TeamAddedToOrg => "team_added_to_org",
+            AssignError::RequestTimeout => "request_timeout",
+            AssignError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AssignError::Unknown(code) => return ::Error::from_code("admin.users.assign", &code),
+            AssignError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.users.assign", code)
+    }
+}
+
+impl<E: Error> fmt::Display for AssignError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AssignError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &AssignError::UserNotFound => "user_not_found: The user_id was not found.",
+            &AssignError::UserAlreadyTeamMember => "user_already_team_member: The user is already a member of the workspace.",
+            &AssignError::ChannelNotFound => "channel_not_found: One of the channels was not found.",
+            &AssignError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &AssignError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &AssignError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &AssignError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &AssignError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &AssignError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &AssignError::NotAuthed => "not_authed: No authentication token provided.",
+            &AssignError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &AssignError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &AssignError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &AssignError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &AssignError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &AssignError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &AssignError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &AssignError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &AssignError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &AssignError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &AssignError::MalformedResponse(ref e) => e.description(),
+            &AssignError::Unknown(ref s) => s,
+            &AssignError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &AssignError::MalformedResponse(ref e) => Some(e),
+            &AssignError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Invite a user to a workspace.
+///
+/// Wraps https://api.slack.com/methods/admin.users.invite
+
+pub fn invite<R>(client: &R,
+                 token: &str,
+                 request: &InviteRequest)
+                 -> Result<InviteResponse, InviteError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel_ids".to_owned(), request.channel_ids.into());
+    if let Some(custom_message) = request.custom_message {
+        body.insert("custom_message".to_owned(), custom_message.into());
+    }
+    body.insert("email".to_owned(), request.email.into());
+    if let Some(guest_expiration_ts) = request.guest_expiration_ts {
+        body.insert("guest_expiration_ts".to_owned(), guest_expiration_ts.into());
+    }
+    if let Some(is_restricted) = request.is_restricted {
+        body.insert("is_restricted".to_owned(), is_restricted.into());
+    }
+    if let Some(is_ultra_restricted) = request.is_ultra_restricted {
+        body.insert("is_ultra_restricted".to_owned(), is_ultra_restricted.into());
+    }
+    if let Some(real_name) = request.real_name {
+        body.insert("real_name".to_owned(), real_name.into());
+    }
+    if let Some(resend) = request.resend {
+        body.insert("resend".to_owned(), resend.into());
+    }
+    body.insert("team_id".to_owned(), request.team_id.into());
+    let url = ::get_slack_url_for_method("admin.users.invite");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| InviteError::Client(err))
+        .and_then(|result| {
+                      parse_invite_response(result.body.as_bytes()).map_err(|err| err.into_error(InviteError::MalformedResponse))
+                  })
+}
+
+fn parse_invite_response(body: &[u8]) -> Result<InviteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InviteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InviteRequest<'a> {
+    /// The channels to invite the user to, as a comma-separated list. At least one channel is required.
+    pub channel_ids: &'a str,
+    /// An optional message to send to the user in the invite email.
+    pub custom_message: Option<&'a str>,
+    /// The email address of the person to invite.
+    pub email: &'a str,
+    /// Timestamp when guest account should be disabled. Only include this timestamp if you are inviting a guest user and you want their account to expire on a certain date.
+    pub guest_expiration_ts: Option<&'a str>,
+    /// True if the user being added is a multi-channel guest.
+    pub is_restricted: Option<bool>,
+    /// True if the user being added is a single-channel guest.
+    pub is_ultra_restricted: Option<bool>,
+    /// Full name of the user.
+    pub real_name: Option<&'a str>,
+    /// Allow this invite to be resent in the future if a user has not signed up yet. (default: false)
+    pub resend: Option<bool>,
+    /// The workspace to act in.
+    pub team_id: &'a str,
+}
+
+impl<'a> InviteRequest<'a> {
+    pub fn new(channel_ids: &'a str, email: &'a str, team_id: &'a str) -> Self {
+        InviteRequest {
+            channel_ids: channel_ids,
+            email: email,
+            team_id: team_id,
+            ..InviteRequest::default()
+        }
+    }
+
+    pub fn custom_message(mut self, custom_message: &'a str) -> Self {
+        self.custom_message = Some(custom_message);
+        self
+    }
+
+    pub fn guest_expiration_ts(mut self, guest_expiration_ts: &'a str) -> Self {
+        self.guest_expiration_ts = Some(guest_expiration_ts);
+        self
+    }
+
+    pub fn is_restricted(mut self, is_restricted: bool) -> Self {
+        self.is_restricted = Some(is_restricted);
+        self
+    }
+
+    pub fn is_ultra_restricted(mut self, is_ultra_restricted: bool) -> Self {
+        self.is_ultra_restricted = Some(is_ultra_restricted);
+        self
+    }
+
+    pub fn real_name(mut self, real_name: &'a str) -> Self {
+        self.real_name = Some(real_name);
+        self
+    }
+
+    pub fn resend(mut self, resend: bool) -> Self {
+        self.resend = Some(resend);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InviteResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
+    fn into(self) -> Result<InviteResponse, InviteError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InviteError<E: Error> {
+    /// The user is already a member of the workspace.
+    AlreadyInTeam,
+    /// The user has already been invited.
+    AlreadyInvited,
+    /// The email address is not valid.
+    InvalidEmail,
+    /// One of the channels was not found.
+    ChannelNotFound,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InviteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "already_in_team" => InviteError::AlreadyInTeam,
+            "already_invited" => InviteError::AlreadyInvited,
+            "invalid_email" => InviteError::InvalidEmail,
+            "channel_not_found" => InviteError::ChannelNotFound,
+            "feature_not_enabled" => InviteError::FeatureNotEnabled,
+            "not_an_admin" => InviteError::NotAnAdmin,
+            "not_an_enterprise" => InviteError::NotAnEnterprise,
+            "team_not_found" => InviteError::TeamNotFound,
+            "missing_scope" => InviteError::MissingScope,
+            "not_allowed_token_type" => InviteError::NotAllowedTokenType,
+            "not_authed" => InviteError::NotAuthed,
+            "invalid_auth" => InviteError::InvalidAuth,
+            "account_inactive" => InviteError::AccountInactive,
+            "invalid_arg_name" => InviteError::InvalidArgName,
+            "invalid_array_arg" => InviteError::InvalidArrayArg,
+            "invalid_charset" => InviteError::InvalidCharset,
+            "invalid_form_data" => InviteError::InvalidFormData,
+            "invalid_post_type" => InviteError::InvalidPostType,
+            "missing_post_type" => InviteError::MissingPostType,
+            "team_added_to_org" => InviteError::TeamAddedToOrg,
+            "request_timeout" => InviteError::RequestTimeout,
+            _ => InviteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<InviteError<E>> for ::Error<E> {
+    fn from(err: InviteError<E>) -> Self {
+        let code = match err {
+            InviteError::AlreadyInTeam => "already_in_team",
+            InviteError::AlreadyInvited => "already_invited",
+            InviteError::InvalidEmail => "invalid_email",
+            InviteError::ChannelNotFound => "channel_not_found",
+            InviteError::FeatureNotEnabled => "feature_not_enabled",
+            InviteError::NotAnAdmin => "not_an_admin",
+            InviteError::NotAnEnterprise => "not_an_enterprise",
+            InviteError::TeamNotFound => "team_not_found",
+            InviteError::MissingScope => "missing_scope",
+            InviteError::NotAllowedTokenType => "not_allowed_token_type",
+            InviteError::NotAuthed => "not_authed",
+            InviteError::InvalidAuth => "invalid_auth",
+            InviteError::AccountInactive => "account_inactive",
+            InviteError::InvalidArgName => "invalid_arg_name",
+            InviteError::InvalidArrayArg => "invalid_array_arg",
+            InviteError::InvalidCharset => "invalid_charset",
+            InviteError::InvalidFormData => "invalid_form_data",
+            InviteError::InvalidPostType => "invalid_post_type",
+            InviteError::MissingPostType => "missing_post_type",
+            InviteError::TeamAddedToOrg => "team_added_to_org",
+            InviteError::RequestTimeout => "request_timeout",
+            InviteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InviteError::Unknown(code) => return ::Error::from_code("admin.users.invite", &code),
+            InviteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.users.invite", code)
+    }
+}
+
+impl<E: Error> fmt::Display for InviteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InviteError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &InviteError::AlreadyInTeam => "already_in_team: The user is already a member of the workspace.",
+            &InviteError::AlreadyInvited => "already_invited: The user has already been invited.",
+            &InviteError::InvalidEmail => "invalid_email: The email address is not valid.",
+            &InviteError::ChannelNotFound => "channel_not_found: One of the channels was not found.",
+            &InviteError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &InviteError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &InviteError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &InviteError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &InviteError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &InviteError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &InviteError::NotAuthed => "not_authed: No authentication token provided.",
+            &InviteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &InviteError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &InviteError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &InviteError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &InviteError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &InviteError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &InviteError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &InviteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &InviteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &InviteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &InviteError::MalformedResponse(ref e) => e.description(),
+            &InviteError::Unknown(ref s) => s,
+            &InviteError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &InviteError::MalformedResponse(ref e) => Some(e),
+            &InviteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// List users on a workspace
+///
+/// Wraps https://api.slack.com/methods/admin.users.list
+
+pub fn list<R>(client: &R,
+               token: &str,
+               request: &ListRequest)
+               -> Result<ListResponse, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    params.push(("team_id", request.team_id));
+    let url = ::get_slack_url_for_method("admin.users.list");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| ListError::Client(err))
+        .and_then(|result| {
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
+                  })
+}
+
+/// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `users` of every page in turn.
+pub fn list_all<'a, R>(client: &'a R,
+                       token: &'a str,
+                       request: &ListRequest<'a>)
+                       -> ::cursor::Items<'a, ListResponseUser, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = ListRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        list(client, token, &request).map(|response| {
+            (response.users.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Set cursor to next_cursor returned by the previous call to list items in the next page.
+    pub cursor: Option<&'a str>,
+    /// Limit for how many users to be retrieved per page
+    pub limit: Option<u32>,
+    /// The workspace to act in.
+    pub team_id: &'a str,
+}
+
+impl<'a> ListRequest<'a> {
+    pub fn new(team_id: &'a str) -> Self {
+        ListRequest {
+            team_id: team_id,
+            ..ListRequest::default()
+        }
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub users: Option<Vec<ListResponseUser>>,
+    pub warning: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponseUser {
+    pub email: Option<String>,
+    pub id: Option<String>,
+    pub is_admin: Option<bool>,
+    pub is_bot: Option<bool>,
+    pub is_owner: Option<bool>,
+    pub is_primary_owner: Option<bool>,
+    pub is_restricted: Option<bool>,
+    pub is_ultra_restricted: Option<bool>,
+}
+
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_cursor" => ListError::InvalidCursor,
+            "feature_not_enabled" => ListError::FeatureNotEnabled,
+            "not_an_admin" => ListError::NotAnAdmin,
+            "not_an_enterprise" => ListError::NotAnEnterprise,
+            "team_not_found" => ListError::TeamNotFound,
+            "missing_scope" => ListError::MissingScope,
+            "not_allowed_token_type" => ListError::NotAllowedTokenType,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::InvalidCursor => "invalid_cursor",
+            ListError::FeatureNotEnabled => "feature_not_enabled",
+            ListError::NotAnAdmin => "not_an_admin",
+            ListError::NotAnEnterprise => "not_an_enterprise",
+            ListError::TeamNotFound => "team_not_found",
+            ListError::MissingScope => "missing_scope",
+            ListError::NotAllowedTokenType => "not_allowed_token_type",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("admin.users.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.users.list", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ListError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &ListError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &ListError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &ListError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &ListError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ListError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &ListError::NotAuthed => "not_authed: No authentication token provided.",
+            &ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ListError::MalformedResponse(ref e) => e.description(),
+            &ListError::Unknown(ref s) => s,
+            &ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ListError::MalformedResponse(ref e) => Some(e),
+            &ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Remove a user from a workspace.
+///
+/// Wraps https://api.slack.com/methods/admin.users.remove
+
+pub fn remove<R>(client: &R,
+                 token: &str,
+                 request: &RemoveRequest)
+                 -> Result<RemoveResponse, RemoveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("team_id".to_owned(), request.team_id.into());
+    body.insert("user_id".to_owned(), request.user_id.into());
+    let url = ::get_slack_url_for_method("admin.users.remove");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| RemoveError::Client(err))
+        .and_then(|result| {
+                      parse_remove_response(result.body.as_bytes()).map_err(|err| err.into_error(RemoveError::MalformedResponse))
+                  })
+}
+
+fn parse_remove_response(body: &[u8]) -> Result<RemoveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RemoveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveRequest<'a> {
+    /// The workspace to act in.
+    pub team_id: &'a str,
+    /// The user to act on.
+    pub user_id: &'a str,
+}
+
+impl<'a> RemoveRequest<'a> {
+    pub fn new(team_id: &'a str, user_id: &'a str) -> Self {
+        RemoveRequest {
+            team_id: team_id,
+            user_id: user_id,
+            ..RemoveRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
+    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveError<E: Error> {
+    /// The user_id was not found.
+    UserNotFound,
+    /// The primary owner of the organization cannot be changed.
+    CannotModifyPrimaryOwner,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "user_not_found" => RemoveError::UserNotFound,
+            "cannot_modify_primary_owner" => RemoveError::CannotModifyPrimaryOwner,
+            "feature_not_enabled" => RemoveError::FeatureNotEnabled,
+            "not_an_admin" => RemoveError::NotAnAdmin,
+            "not_an_enterprise" => RemoveError::NotAnEnterprise,
+            "team_not_found" => RemoveError::TeamNotFound,
+            "missing_scope" => RemoveError::MissingScope,
+            "not_allowed_token_type" => RemoveError::NotAllowedTokenType,
+            "not_authed" => RemoveError::NotAuthed,
+            "invalid_auth" => RemoveError::InvalidAuth,
+            "account_inactive" => RemoveError::AccountInactive,
+            "invalid_arg_name" => RemoveError::InvalidArgName,
+            "invalid_array_arg" => RemoveError::InvalidArrayArg,
+            "invalid_charset" => RemoveError::InvalidCharset,
+            "invalid_form_data" => RemoveError::InvalidFormData,
+            "invalid_post_type" => RemoveError::InvalidPostType,
+            "missing_post_type" => RemoveError::MissingPostType,
+            "team_added_to_org" => RemoveError::TeamAddedToOrg,
+            "request_timeout" => RemoveError::RequestTimeout,
+            _ => RemoveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RemoveError<E>> for ::Error<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        let code = match err {
+            RemoveError::UserNotFound => "user_not_found",
+            RemoveError::CannotModifyPrimaryOwner => "cannot_modify_primary_owner",
+            RemoveError::FeatureNotEnabled => "feature_not_enabled",
+            RemoveError::NotAnAdmin => "not_an_admin",
+            RemoveError::NotAnEnterprise => "not_an_enterprise",
+            RemoveError::TeamNotFound => "team_not_found",
+            RemoveError::MissingScope => "missing_scope",
+            RemoveError::NotAllowedTokenType => "not_allowed_token_type",
+            RemoveError::NotAuthed => "not_authed",
+            RemoveError::InvalidAuth => "invalid_auth",
+            RemoveError::AccountInactive => "account_inactive",
+            RemoveError::InvalidArgName => "invalid_arg_name",
+            RemoveError::InvalidArrayArg => "invalid_array_arg",
+            RemoveError::InvalidCharset => "invalid_charset",
+            RemoveError::InvalidFormData => "invalid_form_data",
+            RemoveError::InvalidPostType => "invalid_post_type",
+            RemoveError::MissingPostType => "missing_post_type",
+            RemoveError::TeamAddedToOrg => "team_added_to_org",
+            RemoveError::RequestTimeout => "request_timeout",
+            RemoveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RemoveError::Unknown(code) => return ::Error::from_code("admin.users.remove", &code),
+            RemoveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.users.remove", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RemoveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RemoveError::UserNotFound => "user_not_found: The user_id was not found.",
+            &RemoveError::CannotModifyPrimaryOwner => "cannot_modify_primary_owner: The primary owner of the organization cannot be changed.",
+            &RemoveError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &RemoveError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &RemoveError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &RemoveError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &RemoveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RemoveError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &RemoveError::NotAuthed => "not_authed: No authentication token provided.",
+            &RemoveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RemoveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RemoveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RemoveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RemoveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RemoveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RemoveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RemoveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RemoveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RemoveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RemoveError::MalformedResponse(ref e) => e.description(),
+            &RemoveError::Unknown(ref s) => s,
+            &RemoveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RemoveError::MalformedResponse(ref e) => Some(e),
+            &RemoveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Set an existing guest, regular user, or owner to be an admin user.
+///
+/// Wraps https://api.slack.com/methods/admin.users.setAdmin
+
+pub fn set_admin<R>(client: &R,
+                    token: &str,
+                    request: &SetAdminRequest)
+                    -> Result<SetAdminResponse, SetAdminError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("team_id".to_owned(), request.team_id.into());
+    body.insert("user_id".to_owned(), request.user_id.into());
+    let url = ::get_slack_url_for_method("admin.users.setAdmin");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| SetAdminError::Client(err))
+        .and_then(|result| {
+                      parse_set_admin_response(result.body.as_bytes()).map_err(|err| err.into_error(SetAdminError::MalformedResponse))
+                  })
+}
+
+fn parse_set_admin_response(body: &[u8]) -> Result<SetAdminResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetAdminResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetAdminRequest<'a> {
+    /// The workspace to act in.
+    pub team_id: &'a str,
+    /// The user to act on.
+    pub user_id: &'a str,
+}
+
+impl<'a> SetAdminRequest<'a> {
+    pub fn new(team_id: &'a str, user_id: &'a str) -> Self {
+        SetAdminRequest {
+            team_id: team_id,
+            user_id: user_id,
+            ..SetAdminRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetAdminResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SetAdminResponse, SetAdminError<E>>> for SetAdminResponse {
+    fn into(self) -> Result<SetAdminResponse, SetAdminError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetAdminError<E: Error> {
+    /// The user_id was not found.
+    UserNotFound,
+    /// The primary owner of the organization cannot be changed.
+    CannotModifyPrimaryOwner,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetAdminError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "user_not_found" => SetAdminError::UserNotFound,
+            "cannot_modify_primary_owner" => SetAdminError::CannotModifyPrimaryOwner,
+            "feature_not_enabled" => SetAdminError::FeatureNotEnabled,
+            "not_an_admin" => SetAdminError::NotAnAdmin,
+            "not_an_enterprise" => SetAdminError::NotAnEnterprise,
+            "team_not_found" => SetAdminError::TeamNotFound,
+            "missing_scope" => SetAdminError::MissingScope,
+            "not_allowed_token_type" => SetAdminError::NotAllowedTokenType,
+            "not_authed" => SetAdminError::NotAuthed,
+            "invalid_auth" => SetAdminError::InvalidAuth,
+            "account_inactive" => SetAdminError::AccountInactive,
+            "invalid_arg_name" => SetAdminError::InvalidArgName,
+            "invalid_array_arg" => SetAdminError::InvalidArrayArg,
+            "invalid_charset" => SetAdminError::InvalidCharset,
+            "invalid_form_data" => SetAdminError::InvalidFormData,
+            "invalid_post_type" => SetAdminError::InvalidPostType,
+            "missing_post_type" => SetAdminError::MissingPostType,
+            "team_added_to_org" => SetAdminError::TeamAddedToOrg,
+            "request_timeout" => SetAdminError::RequestTimeout,
+            _ => SetAdminError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SetAdminError<E>> for ::Error<E> {
+    fn from(err: SetAdminError<E>) -> Self {
+        let code = match err {
+            SetAdminError::UserNotFound => "user_not_found",
+            SetAdminError::CannotModifyPrimaryOwner => "cannot_modify_primary_owner",
+            SetAdminError::FeatureNotEnabled => "feature_not_enabled",
+            SetAdminError::NotAnAdmin => "not_an_admin",
+            SetAdminError::NotAnEnterprise => "not_an_enterprise",
+            SetAdminError::TeamNotFound => "team_not_found",
+            SetAdminError::MissingScope => "missing_scope",
+            SetAdminError::NotAllowedTokenType => "not_allowed_token_type",
+            SetAdminError::NotAuthed => "not_authed",
+            SetAdminError::InvalidAuth => "invalid_auth",
+            SetAdminError::AccountInactive => "account_inactive",
+            SetAdminError::InvalidArgName => "invalid_arg_name",
+            SetAdminError::InvalidArrayArg => "invalid_array_arg",
+            SetAdminError::InvalidCharset => "invalid_charset",
+            SetAdminError::InvalidFormData => "invalid_form_data",
+            SetAdminError::InvalidPostType => "invalid_post_type",
+            SetAdminError::MissingPostType => "missing_post_type",
+            SetAdminError::TeamAddedToOrg => "team_added_to_org",
+            SetAdminError::RequestTimeout => "request_timeout",
+            SetAdminError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetAdminError::Unknown(code) => return ::Error::from_code("admin.users.setAdmin", &code),
+            SetAdminError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.users.setAdmin", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SetAdminError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetAdminError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SetAdminError::UserNotFound => "user_not_found: The user_id was not found.",
+            &SetAdminError::CannotModifyPrimaryOwner => "cannot_modify_primary_owner: The primary owner of the organization cannot be changed.",
+            &SetAdminError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &SetAdminError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &SetAdminError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &SetAdminError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &SetAdminError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &SetAdminError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &SetAdminError::NotAuthed => "not_authed: No authentication token provided.",
+            &SetAdminError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SetAdminError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SetAdminError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SetAdminError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SetAdminError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SetAdminError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SetAdminError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SetAdminError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SetAdminError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SetAdminError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SetAdminError::MalformedResponse(ref e) => e.description(),
+            &SetAdminError::Unknown(ref s) => s,
+            &SetAdminError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SetAdminError::MalformedResponse(ref e) => Some(e),
+            &SetAdminError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Set an expiration for a guest user
+///
+/// Wraps https://api.slack.com/methods/admin.users.setExpiration
+
+pub fn set_expiration<R>(client: &R,
+                         token: &str,
+                         request: &SetExpirationRequest)
+                         -> Result<SetExpirationResponse, SetExpirationError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("expiration_ts".to_owned(), request.expiration_ts.into());
+    body.insert("team_id".to_owned(), request.team_id.into());
+    body.insert("user_id".to_owned(), request.user_id.into());
+    let url = ::get_slack_url_for_method("admin.users.setExpiration");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| SetExpirationError::Client(err))
+        .and_then(|result| {
+                      parse_set_expiration_response(result.body.as_bytes()).map_err(|err| err.into_error(SetExpirationError::MalformedResponse))
+                  })
+}
+
+fn parse_set_expiration_response(body: &[u8]) -> Result<SetExpirationResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetExpirationResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetExpirationRequest<'a> {
+    /// Timestamp when guest account should be disabled.
+    pub expiration_ts: u32,
+    /// The workspace to act in.
+    pub team_id: &'a str,
+    /// The user to act on.
+    pub user_id: &'a str,
+}
+
+impl<'a> SetExpirationRequest<'a> {
+    pub fn new(expiration_ts: u32, team_id: &'a str, user_id: &'a str) -> Self {
+        SetExpirationRequest {
+            expiration_ts: expiration_ts,
+            team_id: team_id,
+            user_id: user_id,
+            ..SetExpirationRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetExpirationResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SetExpirationResponse, SetExpirationError<E>>> for SetExpirationResponse {
+    fn into(self) -> Result<SetExpirationResponse, SetExpirationError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetExpirationError<E: Error> {
+    /// The user_id was not found.
+    UserNotFound,
+    /// The user is not a guest.
+    UserNotGuest,
+    /// The expiration_ts is not in the future.
+    InvalidExpirationTs,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetExpirationError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "user_not_found" => SetExpirationError::UserNotFound,
+            "user_not_guest" => SetExpirationError::UserNotGuest,
+            "invalid_expiration_ts" => SetExpirationError::InvalidExpirationTs,
+            "feature_not_enabled" => SetExpirationError::FeatureNotEnabled,
+            "not_an_admin" => SetExpirationError::NotAnAdmin,
+            "not_an_enterprise" => SetExpirationError::NotAnEnterprise,
+            "team_not_found" => SetExpirationError::TeamNotFound,
+            "missing_scope" => SetExpirationError::MissingScope,
+            "not_allowed_token_type" => SetExpirationError::NotAllowedTokenType,
+            "not_authed" => SetExpirationError::NotAuthed,
+            "invalid_auth" => SetExpirationError::InvalidAuth,
+            "account_inactive" => SetExpirationError::AccountInactive,
+            "invalid_arg_name" => SetExpirationError::InvalidArgName,
+            "invalid_array_arg" => SetExpirationError::InvalidArrayArg,
+            "invalid_charset" => SetExpirationError::InvalidCharset,
+            "invalid_form_data" => SetExpirationError::InvalidFormData,
+            "invalid_post_type" => SetExpirationError::InvalidPostType,
+            "missing_post_type" => SetExpirationError::MissingPostType,
+            "team_added_to_org" => SetExpirationError::TeamAddedToOrg,
+            "request_timeout" => SetExpirationError::RequestTimeout,
+            _ => SetExpirationError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SetExpirationError<E>> for ::Error<E> {
+    fn from(err: SetExpirationError<E>) -> Self {
+        let code = match err {
+            SetExpirationError::UserNotFound => "user_not_found",
+            SetExpirationError::UserNotGuest => "user_not_guest",
+            SetExpirationError::InvalidExpirationTs => "invalid_expiration_ts",
+            SetExpirationError::FeatureNotEnabled => "feature_not_enabled",
+            SetExpirationError::NotAnAdmin => "not_an_admin",
+            SetExpirationError::NotAnEnterprise => "not_an_enterprise",
+            SetExpirationError::TeamNotFound => "team_not_found",
+            SetExpirationError::MissingScope => "missing_scope",
+            SetExpirationError::NotAllowedTokenType => "not_allowed_token_type",
+            SetExpirationError::NotAuthed => "not_authed",
+            SetExpirationError::InvalidAuth => "invalid_auth",
+            SetExpirationError::AccountInactive => "account_inactive",
+            SetExpirationError::InvalidArgName => "invalid_arg_name",
+            SetExpirationError::InvalidArrayArg => "invalid_array_arg",
+            SetExpirationError::InvalidCharset => "invalid_charset",
+            SetExpirationError::InvalidFormData => "invalid_form_data",
+            SetExpirationError::InvalidPostType => "invalid_post_type",
+            SetExpirationError::MissingPostType => "missing_post_type",
+            SetExpirationError::TeamAddedToOrg => "team_added_to_org",
+            SetExpirationError::RequestTimeout => "request_timeout",
+            SetExpirationError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetExpirationError::Unknown(code) => return ::Error::from_code("admin.users.setExpiration", &code),
+            SetExpirationError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.users.setExpiration", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SetExpirationError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetExpirationError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SetExpirationError::UserNotFound => "user_not_found: The user_id was not found.",
+            &SetExpirationError::UserNotGuest => "user_not_guest: The user is not a guest.",
+            &SetExpirationError::InvalidExpirationTs => "invalid_expiration_ts: The expiration_ts is not in the future.",
+            &SetExpirationError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &SetExpirationError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &SetExpirationError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &SetExpirationError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &SetExpirationError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &SetExpirationError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &SetExpirationError::NotAuthed => "not_authed: No authentication token provided.",
+            &SetExpirationError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SetExpirationError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SetExpirationError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SetExpirationError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SetExpirationError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SetExpirationError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SetExpirationError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SetExpirationError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SetExpirationError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SetExpirationError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SetExpirationError::MalformedResponse(ref e) => e.description(),
+            &SetExpirationError::Unknown(ref s) => s,
+            &SetExpirationError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SetExpirationError::MalformedResponse(ref e) => Some(e),
+            &SetExpirationError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Set an existing guest, regular user, or admin user to be a workspace owner.
+///
+/// Wraps https://api.slack.com/methods/admin.users.setOwner
+
+pub fn set_owner<R>(client: &R,
+                    token: &str,
+                    request: &SetOwnerRequest)
+                    -> Result<SetOwnerResponse, SetOwnerError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("team_id".to_owned(), request.team_id.into());
+    body.insert("user_id".to_owned(), request.user_id.into());
+    let url = ::get_slack_url_for_method("admin.users.setOwner");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| SetOwnerError::Client(err))
+        .and_then(|result| {
+                      parse_set_owner_response(result.body.as_bytes()).map_err(|err| err.into_error(SetOwnerError::MalformedResponse))
+                  })
+}
+
+fn parse_set_owner_response(body: &[u8]) -> Result<SetOwnerResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetOwnerResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetOwnerRequest<'a> {
+    /// The workspace to act in.
+    pub team_id: &'a str,
+    /// The user to act on.
+    pub user_id: &'a str,
+}
+
+impl<'a> SetOwnerRequest<'a> {
+    pub fn new(team_id: &'a str, user_id: &'a str) -> Self {
+        SetOwnerRequest {
+            team_id: team_id,
+            user_id: user_id,
+            ..SetOwnerRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetOwnerResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SetOwnerResponse, SetOwnerError<E>>> for SetOwnerResponse {
+    fn into(self) -> Result<SetOwnerResponse, SetOwnerError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetOwnerError<E: Error> {
+    /// The user_id was not found.
+    UserNotFound,
+    /// The primary owner of the organization cannot be changed.
+    CannotModifyPrimaryOwner,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetOwnerError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "user_not_found" => SetOwnerError::UserNotFound,
+            "cannot_modify_primary_owner" => SetOwnerError::CannotModifyPrimaryOwner,
+            "feature_not_enabled" => SetOwnerError::FeatureNotEnabled,
+            "not_an_admin" => SetOwnerError::NotAnAdmin,
+            "not_an_enterprise" => SetOwnerError::NotAnEnterprise,
+            "team_not_found" => SetOwnerError::TeamNotFound,
+            "missing_scope" => SetOwnerError::MissingScope,
+            "not_allowed_token_type" => SetOwnerError::NotAllowedTokenType,
+            "not_authed" => SetOwnerError::NotAuthed,
+            "invalid_auth" => SetOwnerError::InvalidAuth,
+            "account_inactive" => SetOwnerError::AccountInactive,
+            "invalid_arg_name" => SetOwnerError::InvalidArgName,
+            "invalid_array_arg" => SetOwnerError::InvalidArrayArg,
+            "invalid_charset" => SetOwnerError::InvalidCharset,
+            "invalid_form_data" => SetOwnerError::InvalidFormData,
+            "invalid_post_type" => SetOwnerError::InvalidPostType,
+            "missing_post_type" => SetOwnerError::MissingPostType,
+            "team_added_to_org" => SetOwnerError::TeamAddedToOrg,
+            "request_timeout" => SetOwnerError::RequestTimeout,
+            _ => SetOwnerError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SetOwnerError<E>> for ::Error<E> {
+    fn from(err: SetOwnerError<E>) -> Self {
+        let code = match err {
+            SetOwnerError::UserNotFound => "user_not_found",
+            SetOwnerError::CannotModifyPrimaryOwner => "cannot_modify_primary_owner",
+            SetOwnerError::FeatureNotEnabled => "feature_not_enabled",
+            SetOwnerError::NotAnAdmin => "not_an_admin",
+            SetOwnerError::NotAnEnterprise => "not_an_enterprise",
+            SetOwnerError::TeamNotFound => "team_not_found",
+            SetOwnerError::MissingScope => "missing_scope",
+            SetOwnerError::NotAllowedTokenType => "not_allowed_token_type",
+            SetOwnerError::NotAuthed => "not_authed",
+            SetOwnerError::InvalidAuth => "invalid_auth",
+            SetOwnerError::AccountInactive => "account_inactive",
+            SetOwnerError::InvalidArgName => "invalid_arg_name",
+            SetOwnerError::InvalidArrayArg => "invalid_array_arg",
+            SetOwnerError::InvalidCharset => "invalid_charset",
+            SetOwnerError::InvalidFormData => "invalid_form_data",
+            SetOwnerError::InvalidPostType => "invalid_post_type",
+            SetOwnerError::MissingPostType => "missing_post_type",
+            SetOwnerError::TeamAddedToOrg => "team_added_to_org",
+            SetOwnerError::RequestTimeout => "request_timeout",
+            SetOwnerError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetOwnerError::Unknown(code) => return ::Error::from_code("admin.users.setOwner", &code),
+            SetOwnerError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.users.setOwner", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SetOwnerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetOwnerError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SetOwnerError::UserNotFound => "user_not_found: The user_id was not found.",
+            &SetOwnerError::CannotModifyPrimaryOwner => "cannot_modify_primary_owner: The primary owner of the organization cannot be changed.",
+            &SetOwnerError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &SetOwnerError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &SetOwnerError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &SetOwnerError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &SetOwnerError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &SetOwnerError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &SetOwnerError::NotAuthed => "not_authed: No authentication token provided.",
+            &SetOwnerError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SetOwnerError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SetOwnerError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SetOwnerError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SetOwnerError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SetOwnerError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SetOwnerError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SetOwnerError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SetOwnerError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SetOwnerError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SetOwnerError::MalformedResponse(ref e) => e.description(),
+            &SetOwnerError::Unknown(ref s) => s,
+            &SetOwnerError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SetOwnerError::MalformedResponse(ref e) => Some(e),
+            &SetOwnerError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Set an existing guest user, admin user, or owner to be a regular user.
+///
+/// Wraps https://api.slack.com/methods/admin.users.setRegular
+
+pub fn set_regular<R>(client: &R,
+                      token: &str,
+                      request: &SetRegularRequest)
+                      -> Result<SetRegularResponse, SetRegularError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("team_id".to_owned(), request.team_id.into());
+    body.insert("user_id".to_owned(), request.user_id.into());
+    let url = ::get_slack_url_for_method("admin.users.setRegular");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| SetRegularError::Client(err))
+        .and_then(|result| {
+                      parse_set_regular_response(result.body.as_bytes()).map_err(|err| err.into_error(SetRegularError::MalformedResponse))
+                  })
+}
+
+fn parse_set_regular_response(body: &[u8]) -> Result<SetRegularResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetRegularResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetRegularRequest<'a> {
+    /// The workspace to act in.
+    pub team_id: &'a str,
+    /// The user to act on.
+    pub user_id: &'a str,
+}
+
+impl<'a> SetRegularRequest<'a> {
+    pub fn new(team_id: &'a str, user_id: &'a str) -> Self {
+        SetRegularRequest {
+            team_id: team_id,
+            user_id: user_id,
+            ..SetRegularRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetRegularResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SetRegularResponse, SetRegularError<E>>> for SetRegularResponse {
+    fn into(self) -> Result<SetRegularResponse, SetRegularError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetRegularError<E: Error> {
+    /// The user_id was not found.
+    UserNotFound,
+    /// The primary owner of the organization cannot be changed.
+    CannotModifyPrimaryOwner,
+    /// This feature is not enabled for this workspace.
+    FeatureNotEnabled,
+    /// This method is only accessible by org owners and Admins.
+    NotAnAdmin,
+    /// This method can only be called by an Enterprise organization.
+    NotAnEnterprise,
+    /// The team associated with this token could not be found.
+    TeamNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The token type used in this call is not allowed.
+    NotAllowedTokenType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetRegularError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "user_not_found" => SetRegularError::UserNotFound,
+            "cannot_modify_primary_owner" => SetRegularError::CannotModifyPrimaryOwner,
+            "feature_not_enabled" => SetRegularError::FeatureNotEnabled,
+            "not_an_admin" => SetRegularError::NotAnAdmin,
+            "not_an_enterprise" => SetRegularError::NotAnEnterprise,
+            "team_not_found" => SetRegularError::TeamNotFound,
+            "missing_scope" => SetRegularError::MissingScope,
+            "not_allowed_token_type" => SetRegularError::NotAllowedTokenType,
+            "not_authed" => SetRegularError::NotAuthed,
+            "invalid_auth" => SetRegularError::InvalidAuth,
+            "account_inactive" => SetRegularError::AccountInactive,
+            "invalid_arg_name" => SetRegularError::InvalidArgName,
+            "invalid_array_arg" => SetRegularError::InvalidArrayArg,
+            "invalid_charset" => SetRegularError::InvalidCharset,
+            "invalid_form_data" => SetRegularError::InvalidFormData,
+            "invalid_post_type" => SetRegularError::InvalidPostType,
+            "missing_post_type" => SetRegularError::MissingPostType,
+            "team_added_to_org" => SetRegularError::TeamAddedToOrg,
+            "request_timeout" => SetRegularError::RequestTimeout,
+            _ => SetRegularError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SetRegularError<E>> for ::Error<E> {
+    fn from(err: SetRegularError<E>) -> Self {
+        let code = match err {
+            SetRegularError::UserNotFound => "user_not_found",
+            SetRegularError::CannotModifyPrimaryOwner => "cannot_modify_primary_owner",
+            SetRegularError::FeatureNotEnabled => "feature_not_enabled",
+            SetRegularError::NotAnAdmin => "not_an_admin",
+            SetRegularError::NotAnEnterprise => "not_an_enterprise",
+            SetRegularError::TeamNotFound => "team_not_found",
+            SetRegularError::MissingScope => "missing_scope",
+            SetRegularError::NotAllowedTokenType => "not_allowed_token_type",
+            SetRegularError::NotAuthed => "not_authed",
+            SetRegularError::InvalidAuth => "invalid_auth",
+            SetRegularError::AccountInactive => "account_inactive",
+            SetRegularError::InvalidArgName => "invalid_arg_name",
+            SetRegularError::InvalidArrayArg => "invalid_array_arg",
+            SetRegularError::InvalidCharset => "invalid_charset",
+            SetRegularError::InvalidFormData => "invalid_form_data",
+            SetRegularError::InvalidPostType => "invalid_post_type",
+            SetRegularError::MissingPostType => "missing_post_type",
+            SetRegularError::TeamAddedToOrg => "team_added_to_org",
+            SetRegularError::RequestTimeout => "request_timeout",
+            SetRegularError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetRegularError::Unknown(code) => return ::Error::from_code("admin.users.setRegular", &code),
+            SetRegularError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("admin.users.setRegular", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SetRegularError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetRegularError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SetRegularError::UserNotFound => "user_not_found: The user_id was not found.",
+            &SetRegularError::CannotModifyPrimaryOwner => "cannot_modify_primary_owner: The primary owner of the organization cannot be changed.",
+            &SetRegularError::FeatureNotEnabled => "feature_not_enabled: This feature is not enabled for this workspace.",
+            &SetRegularError::NotAnAdmin => "not_an_admin: This method is only accessible by org owners and Admins.",
+            &SetRegularError::NotAnEnterprise => "not_an_enterprise: This method can only be called by an Enterprise organization.",
+            &SetRegularError::TeamNotFound => "team_not_found: The team associated with this token could not be found.",
+            &SetRegularError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &SetRegularError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed.",
+            &SetRegularError::NotAuthed => "not_authed: No authentication token provided.",
+            &SetRegularError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SetRegularError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SetRegularError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SetRegularError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SetRegularError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SetRegularError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SetRegularError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SetRegularError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SetRegularError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SetRegularError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SetRegularError::MalformedResponse(ref e) => e.description(),
+            &SetRegularError::Unknown(ref s) => s,
+            &SetRegularError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SetRegularError::MalformedResponse(ref e) => Some(e),
+            &SetRegularError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `admin.users.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::admin_users`](../client/struct.SlackClient.html#method.admin_users).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Add an Enterprise user to a workspace. See [`assign`](fn.assign.html).
+    pub fn assign(&self, request: &AssignRequest) -> Result<AssignResponse, AssignError<R::Error>> {
+        assign(self.client, self.token.for_method("admin.users.assign"), request)
+    }
+
+    /// Invite a user to a workspace. See [`invite`](fn.invite.html).
+    pub fn invite(&self, request: &InviteRequest) -> Result<InviteResponse, InviteError<R::Error>> {
+        invite(self.client, self.token.for_method("admin.users.invite"), request)
+    }
+
+    /// List users on a workspace. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("admin.users.list"), request)
+    }
+
+    /// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+    /// yielding the `users` of every page in turn.
+    pub fn list_all(&self, request: &ListRequest<'a>) -> ::cursor::Items<'a, ListResponseUser, ListError<R::Error>> {
+        list_all(self.client, self.token.for_method("admin.users.list"), request)
+    }
+
+    /// Remove a user from a workspace. See [`remove`](fn.remove.html).
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<R::Error>> {
+        remove(self.client, self.token.for_method("admin.users.remove"), request)
+    }
+
+    /// Set an existing guest, regular user, or owner to be an admin user. See
+    /// [`set_admin`](fn.set_admin.html).
+    pub fn set_admin(&self, request: &SetAdminRequest) -> Result<SetAdminResponse, SetAdminError<R::Error>> {
+        set_admin(self.client, self.token.for_method("admin.users.setAdmin"), request)
+    }
+
+    /// Set an expiration for a guest user. See [`set_expiration`](fn.set_expiration.html).
+    pub fn set_expiration(&self, request: &SetExpirationRequest)
+                          -> Result<SetExpirationResponse, SetExpirationError<R::Error>> {
+        set_expiration(self.client, self.token.for_method("admin.users.setExpiration"), request)
+    }
+
+    /// Set an existing guest, regular user, or admin user to be a workspace owner. See
+    /// [`set_owner`](fn.set_owner.html).
+    pub fn set_owner(&self, request: &SetOwnerRequest) -> Result<SetOwnerResponse, SetOwnerError<R::Error>> {
+        set_owner(self.client, self.token.for_method("admin.users.setOwner"), request)
+    }
+
+    /// Set an existing guest user, admin user, or owner to be a regular user. See
+    /// [`set_regular`](fn.set_regular.html).
+    pub fn set_regular(&self, request: &SetRegularRequest) -> Result<SetRegularResponse, SetRegularError<R::Error>> {
+        set_regular(self.client, self.token.for_method("admin.users.setRegular"), request)
+    }
+}
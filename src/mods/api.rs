@@ -18,18 +18,29 @@ pub fn test<R>(client: &R, request: &TestRequest) -> Result<TestResponse, TestEr
     where R: SlackWebRequestSender
 {
 
-    let params = vec![request.error.map(|error| ("error", error)),
-                      request.foo.map(|foo| ("foo", foo))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(2);
+    if let Some(error) = request.error {
+        params.push(("error", error));
+    }
+    if let Some(foo) = request.foo {
+        params.push(("foo", foo));
+    }
     let url = ::get_slack_url_for_method("api.test");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| TestError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<TestResponse>(&result)
-                            .map_err(|e| TestError::MalformedResponse(e))
+                      parse_test_response(&result).map_err(|err| err.into_error(TestError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_test_response(body: &[u8]) -> Result<TestResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<TestResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -40,12 +51,30 @@ pub struct TestRequest<'a> {
     pub foo: Option<&'a str>,
 }
 
+impl<'a> TestRequest<'a> {
+    pub fn new() -> Self {
+        TestRequest::default()
+    }
+
+    pub fn error(mut self, error: &'a str) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    pub fn foo(mut self, foo: &'a str) -> Self {
+        self.foo = Some(foo);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct TestResponse {
     pub args: Option<HashMap<String, bool>>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -104,6 +133,25 @@ impl<'a, E: Error> From<&'a str> for TestError<E> {
     }
 }
 
+impl<E: Error> From<TestError<E>> for ::Error<E> {
+    fn from(err: TestError<E>) -> Self {
+        let code = match err {
+            TestError::InvalidArgName => "invalid_arg_name",
+            TestError::InvalidArrayArg => "invalid_array_arg",
+            TestError::InvalidCharset => "invalid_charset",
+            TestError::InvalidFormData => "invalid_form_data",
+            TestError::InvalidPostType => "invalid_post_type",
+            TestError::MissingPostType => "missing_post_type",
+            TestError::TeamAddedToOrg => "team_added_to_org",
+            TestError::RequestTimeout => "request_timeout",
+            TestError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            TestError::Unknown(code) => return ::Error::from_code("api.test", &code),
+            TestError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("api.test", code)
+    }
+}
+
 impl<E: Error> fmt::Display for TestError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -0,0 +1,205 @@
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Generate a temporary Socket Mode WebSocket URL that your app can connect to in order to receive events and interactive payloads over.
+///
+/// This method requires an app-level token.
+///
+/// Wraps https://api.slack.com/methods/apps.connections.open
+
+pub fn open<R>(client: &R, token: &str) -> Result<OpenResponse, OpenError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let url = ::get_slack_url_for_method("apps.connections.open");
+    client
+        .send_json(&url, token, "{}")
+        .map_err(|err| OpenError::Client(err))
+        .and_then(|result| {
+                      parse_open_response(result.body.as_bytes()).map_err(|err| err.into_error(OpenError::MalformedResponse))
+                  })
+}
+
+fn parse_open_response(body: &[u8]) -> Result<OpenResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<OpenResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OpenResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub url: Option<String>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
+    fn into(self) -> Result<OpenResponse, OpenError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum OpenError<E: Error> {
+    /// The token type used in this call is not allowed. This method requires an app-level token.
+    NotAllowedTokenType,
+    /// The token used is not valid.
+    InvalidToken,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for OpenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "not_allowed_token_type" => OpenError::NotAllowedTokenType,
+            "invalid_token" => OpenError::InvalidToken,
+            "not_authed" => OpenError::NotAuthed,
+            "invalid_auth" => OpenError::InvalidAuth,
+            "account_inactive" => OpenError::AccountInactive,
+            "invalid_arg_name" => OpenError::InvalidArgName,
+            "invalid_array_arg" => OpenError::InvalidArrayArg,
+            "invalid_charset" => OpenError::InvalidCharset,
+            "invalid_form_data" => OpenError::InvalidFormData,
+            "invalid_post_type" => OpenError::InvalidPostType,
+            "missing_post_type" => OpenError::MissingPostType,
+            "team_added_to_org" => OpenError::TeamAddedToOrg,
+            "request_timeout" => OpenError::RequestTimeout,
+            _ => OpenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<OpenError<E>> for ::Error<E> {
+    fn from(err: OpenError<E>) -> Self {
+        let code = match err {
+            OpenError::NotAllowedTokenType => "not_allowed_token_type",
+            OpenError::InvalidToken => "invalid_token",
+            OpenError::NotAuthed => "not_authed",
+            OpenError::InvalidAuth => "invalid_auth",
+            OpenError::AccountInactive => "account_inactive",
+            OpenError::InvalidArgName => "invalid_arg_name",
+            OpenError::InvalidArrayArg => "invalid_array_arg",
+            OpenError::InvalidCharset => "invalid_charset",
+            OpenError::InvalidFormData => "invalid_form_data",
+            OpenError::InvalidPostType => "invalid_post_type",
+            OpenError::MissingPostType => "missing_post_type",
+            OpenError::TeamAddedToOrg => "team_added_to_org",
+            OpenError::RequestTimeout => "request_timeout",
+            OpenError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            OpenError::Unknown(code) => return ::Error::from_code("apps.connections.open", &code),
+            OpenError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("apps.connections.open", code)
+    }
+}
+
+impl<E: Error> fmt::Display for OpenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for OpenError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &OpenError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed. This method requires an app-level token.",
+            &OpenError::InvalidToken => "invalid_token: The token used is not valid.",
+            &OpenError::NotAuthed => "not_authed: No authentication token provided.",
+            &OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &OpenError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &OpenError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &OpenError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &OpenError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &OpenError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &OpenError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &OpenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &OpenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &OpenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &OpenError::MalformedResponse(ref e) => e.description(),
+            &OpenError::Unknown(ref s) => s,
+            &OpenError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &OpenError::MalformedResponse(ref e) => Some(e),
+            &OpenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `apps.connections.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::apps_connections`](../client/struct.SlackClient.html#method.apps_connections).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Generate a temporary Socket Mode WebSocket URL that your app can connect to in order to
+    /// receive events and interactive payloads over. See [`open`](fn.open.html).
+    pub fn open(&self) -> Result<OpenResponse, OpenError<R::Error>> {
+        open(self.client, self.token.for_method("apps.connections.open"))
+    }
+}
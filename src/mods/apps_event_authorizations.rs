@@ -0,0 +1,279 @@
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Get a list of authorizations for the given event context. Each authorization represents an app installation that the event is visible to.
+///
+/// This method requires an app-level token.
+///
+/// Wraps https://api.slack.com/methods/apps.event.authorizations.list
+
+pub fn list<R>(client: &R,
+               token: &str,
+               request: &ListRequest)
+               -> Result<ListResponse, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    params.push(("event_context", request.event_context));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    let url = ::get_slack_url_for_method("apps.event.authorizations.list");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| ListError::Client(err))
+        .and_then(|result| {
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
+                  })
+}
+
+/// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `authorizations` of every page in turn.
+pub fn list_all<'a, R>(client: &'a R,
+                       token: &'a str,
+                       request: &ListRequest<'a>)
+                       -> ::cursor::Items<'a, ListResponseAuthorization, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = ListRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        list(client, token, &request).map(|response| {
+            (response.authorizations.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// The event_context value delivered with the event.
+    pub event_context: &'a str,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata.
+    pub cursor: Option<&'a str>,
+    /// The maximum number of items to return.
+    pub limit: Option<u32>,
+}
+
+impl<'a> ListRequest<'a> {
+    pub fn new(event_context: &'a str) -> Self {
+        ListRequest {
+            event_context: event_context,
+            ..ListRequest::default()
+        }
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    pub authorizations: Option<Vec<ListResponseAuthorization>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponseAuthorization {
+    pub enterprise_id: Option<String>,
+    pub is_bot: Option<bool>,
+    pub is_enterprise_install: Option<bool>,
+    pub team_id: Option<String>,
+    pub user_id: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// The token type used in this call is not allowed. This method requires an app-level token.
+    NotAllowedTokenType,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "not_allowed_token_type" => ListError::NotAllowedTokenType,
+            "invalid_cursor" => ListError::InvalidCursor,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAllowedTokenType => "not_allowed_token_type",
+            ListError::InvalidCursor => "invalid_cursor",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("apps.event.authorizations.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("apps.event.authorizations.list", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ListError::NotAllowedTokenType => "not_allowed_token_type: The token type used in this call is not allowed. This method requires an app-level token.",
+            &ListError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &ListError::NotAuthed => "not_authed: No authentication token provided.",
+            &ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ListError::MalformedResponse(ref e) => e.description(),
+            &ListError::Unknown(ref s) => s,
+            &ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ListError::MalformedResponse(ref e) => Some(e),
+            &ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `apps.event.authorizations.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::apps_event_authorizations`](../client/struct.SlackClient.html#method.apps_event_authorizations).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Get a list of authorizations for the given event context. Each authorization represents an
+    /// app installation that the event is visible to. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("apps.event.authorizations.list"), request)
+    }
+
+    /// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+    /// yielding the `authorizations` of every page in turn.
+    pub fn list_all(&self, request: &ListRequest<'a>)
+                    -> ::cursor::Items<'a, ListResponseAuthorization, ListError<R::Error>> {
+        list_all(self.client, self.token.for_method("apps.event.authorizations.list"), request)
+    }
+}
@@ -21,20 +21,28 @@ pub fn revoke<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request
-                          .test
-                          .map(|test| ("test", if test { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    if let Some(test) = request.test {
+        body.insert("test".to_owned(), test.into());
+    }
     let url = ::get_slack_url_for_method("auth.revoke");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| RevokeError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RevokeResponse>(&result)
-                            .map_err(|e| RevokeError::MalformedResponse(e))
+                      parse_revoke_response(result.body.as_bytes()).map_err(|err| err.into_error(RevokeError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_revoke_response(body: &[u8]) -> Result<RevokeResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RevokeResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -43,12 +51,25 @@ pub struct RevokeRequest {
     pub test: Option<bool>,
 }
 
+impl RevokeRequest {
+    pub fn new() -> Self {
+        RevokeRequest::default()
+    }
+
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RevokeResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub revoked: Option<bool>,
+    pub warning: Option<String>,
 }
 
 
@@ -116,6 +137,28 @@ impl<'a, E: Error> From<&'a str> for RevokeError<E> {
     }
 }
 
+impl<E: Error> From<RevokeError<E>> for ::Error<E> {
+    fn from(err: RevokeError<E>) -> Self {
+        let code = match err {
+            RevokeError::NotAuthed => "not_authed",
+            RevokeError::InvalidAuth => "invalid_auth",
+            RevokeError::AccountInactive => "account_inactive",
+            RevokeError::InvalidArgName => "invalid_arg_name",
+            RevokeError::InvalidArrayArg => "invalid_array_arg",
+            RevokeError::InvalidCharset => "invalid_charset",
+            RevokeError::InvalidFormData => "invalid_form_data",
+            RevokeError::InvalidPostType => "invalid_post_type",
+            RevokeError::MissingPostType => "missing_post_type",
+            RevokeError::TeamAddedToOrg => "team_added_to_org",
+            RevokeError::RequestTimeout => "request_timeout",
+            RevokeError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RevokeError::Unknown(code) => return ::Error::from_code("auth.revoke", &code),
+            RevokeError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("auth.revoke", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RevokeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -161,25 +204,37 @@ pub fn test<R>(client: &R, token: &str) -> Result<TestResponse, TestError<R::Err
     let params = &[("token", token)];
     let url = ::get_slack_url_for_method("auth.test");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| TestError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<TestResponse>(&result)
-                            .map_err(|e| TestError::MalformedResponse(e))
+                      parse_test_response(&result).map_err(|err| err.into_error(TestError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_test_response(body: &[u8]) -> Result<TestResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<TestResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct TestResponse {
+    pub bot_id: Option<String>,
+    pub enterprise_id: Option<String>,
     error: Option<String>,
+    pub is_enterprise_install: Option<bool>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub team: Option<String>,
     pub team_id: Option<String>,
     pub url: Option<String>,
     pub user: Option<String>,
     pub user_id: Option<String>,
+    pub warning: Option<String>,
 }
 
 
@@ -247,6 +302,28 @@ impl<'a, E: Error> From<&'a str> for TestError<E> {
     }
 }
 
+impl<E: Error> From<TestError<E>> for ::Error<E> {
+    fn from(err: TestError<E>) -> Self {
+        let code = match err {
+            TestError::NotAuthed => "not_authed",
+            TestError::InvalidAuth => "invalid_auth",
+            TestError::AccountInactive => "account_inactive",
+            TestError::InvalidArgName => "invalid_arg_name",
+            TestError::InvalidArrayArg => "invalid_array_arg",
+            TestError::InvalidCharset => "invalid_charset",
+            TestError::InvalidFormData => "invalid_form_data",
+            TestError::InvalidPostType => "invalid_post_type",
+            TestError::MissingPostType => "missing_post_type",
+            TestError::TeamAddedToOrg => "team_added_to_org",
+            TestError::RequestTimeout => "request_timeout",
+            TestError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            TestError::Unknown(code) => return ::Error::from_code("auth.test", &code),
+            TestError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("auth.test", code)
+    }
+}
+
 impl<E: Error> fmt::Display for TestError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -281,3 +358,30 @@ impl<E: Error> Error for TestError<E> {
         }
     }
 }
+
+/// The `auth.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::auth`](../client/struct.SlackClient.html#method.auth).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Revokes a token. See [`revoke`](fn.revoke.html).
+    pub fn revoke(&self, request: &RevokeRequest) -> Result<RevokeResponse, RevokeError<R::Error>> {
+        revoke(self.client, self.token.for_method("auth.revoke"), request)
+    }
+
+    /// Checks authentication & identity. See [`test`](fn.test.html).
+    pub fn test(&self) -> Result<TestResponse, TestError<R::Error>> {
+        test(self.client, self.token.for_method("auth.test"))
+    }
+}
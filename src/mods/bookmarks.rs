@@ -0,0 +1,955 @@
+//! Bookmarks are the links, files and messages pinned to the top of a channel.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Add bookmark to a channel.
+///
+/// Wraps https://api.slack.com/methods/bookmarks.add
+
+pub fn add<R>(client: &R,
+              token: &str,
+              request: &AddRequest)
+              -> Result<AddResponse, AddError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    body.insert("title".to_owned(), request.title.into());
+    body.insert("type".to_owned(), request.ty.into());
+    if let Some(link) = request.link {
+        body.insert("link".to_owned(), link.into());
+    }
+    if let Some(emoji) = request.emoji {
+        body.insert("emoji".to_owned(), emoji.into());
+    }
+    if let Some(entity_id) = request.entity_id {
+        body.insert("entity_id".to_owned(), entity_id.into());
+    }
+    if let Some(parent_id) = request.parent_id {
+        body.insert("parent_id".to_owned(), parent_id.into());
+    }
+    let url = ::get_slack_url_for_method("bookmarks.add");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| AddError::Client(err))
+        .and_then(|result| {
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
+                  })
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AddRequest<'a> {
+    /// Channel to add bookmark in.
+    pub channel_id: &'a str,
+    /// Title for the bookmark.
+    pub title: &'a str,
+    /// Type of the bookmark i.e link.
+    pub ty: &'a str,
+    /// Link to bookmark.
+    pub link: Option<&'a str>,
+    /// Emoji tag to apply to the link.
+    pub emoji: Option<&'a str>,
+    /// ID of the entity being bookmarked. Only applies to message and file types.
+    pub entity_id: Option<&'a str>,
+    /// Id of this bookmark's parent.
+    pub parent_id: Option<&'a str>,
+}
+
+impl<'a> AddRequest<'a> {
+    pub fn new(channel_id: &'a str, title: &'a str, ty: &'a str) -> Self {
+        AddRequest {
+            channel_id: channel_id,
+            title: title,
+            ty: ty,
+            ..AddRequest::default()
+        }
+    }
+
+    pub fn link(mut self, link: &'a str) -> Self {
+        self.link = Some(link);
+        self
+    }
+
+    pub fn emoji(mut self, emoji: &'a str) -> Self {
+        self.emoji = Some(emoji);
+        self
+    }
+
+    pub fn entity_id(mut self, entity_id: &'a str) -> Self {
+        self.entity_id = Some(entity_id);
+        self
+    }
+
+    pub fn parent_id(mut self, parent_id: &'a str) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddResponse {
+    pub bookmark: Option<::Bookmark>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
+    fn into(self) -> Result<AddResponse, AddError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// The token is not a member of the channel.
+    NotInChannel,
+    /// Value passed for link was invalid.
+    InvalidLink,
+    /// Value passed for type was invalid.
+    InvalidType,
+    /// The channel already has as many bookmarks as it may have.
+    TooManyBookmarks,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => AddError::ChannelNotFound,
+            "not_in_channel" => AddError::NotInChannel,
+            "invalid_link" => AddError::InvalidLink,
+            "invalid_type" => AddError::InvalidType,
+            "too_many_bookmarks" => AddError::TooManyBookmarks,
+            "missing_scope" => AddError::MissingScope,
+            "not_authed" => AddError::NotAuthed,
+            "invalid_auth" => AddError::InvalidAuth,
+            "account_inactive" => AddError::AccountInactive,
+            "invalid_arg_name" => AddError::InvalidArgName,
+            "invalid_array_arg" => AddError::InvalidArrayArg,
+            "invalid_charset" => AddError::InvalidCharset,
+            "invalid_form_data" => AddError::InvalidFormData,
+            "invalid_post_type" => AddError::InvalidPostType,
+            "missing_post_type" => AddError::MissingPostType,
+            "team_added_to_org" => AddError::TeamAddedToOrg,
+            "request_timeout" => AddError::RequestTimeout,
+            _ => AddError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::ChannelNotFound => "channel_not_found",
+            AddError::NotInChannel => "not_in_channel",
+            AddError::InvalidLink => "invalid_link",
+            AddError::InvalidType => "invalid_type",
+            AddError::TooManyBookmarks => "too_many_bookmarks",
+            AddError::MissingScope => "missing_scope",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("bookmarks.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("bookmarks.add", code)
+    }
+}
+
+impl<E: Error> fmt::Display for AddError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AddError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &AddError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &AddError::NotInChannel => "not_in_channel: The token is not a member of the channel.",
+            &AddError::InvalidLink => "invalid_link: Value passed for link was invalid.",
+            &AddError::InvalidType => "invalid_type: Value passed for type was invalid.",
+            &AddError::TooManyBookmarks => "too_many_bookmarks: The channel already has as many bookmarks as it may have.",
+            &AddError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &AddError::NotAuthed => "not_authed: No authentication token provided.",
+            &AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &AddError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &AddError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &AddError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &AddError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &AddError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &AddError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &AddError::MalformedResponse(ref e) => e.description(),
+            &AddError::Unknown(ref s) => s,
+            &AddError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &AddError::MalformedResponse(ref e) => Some(e),
+            &AddError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Edit bookmark.
+///
+/// Wraps https://api.slack.com/methods/bookmarks.edit
+
+pub fn edit<R>(client: &R,
+               token: &str,
+               request: &EditRequest)
+               -> Result<EditResponse, EditError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("bookmark_id".to_owned(), request.bookmark_id.into());
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    if let Some(title) = request.title {
+        body.insert("title".to_owned(), title.into());
+    }
+    if let Some(link) = request.link {
+        body.insert("link".to_owned(), link.into());
+    }
+    if let Some(emoji) = request.emoji {
+        body.insert("emoji".to_owned(), emoji.into());
+    }
+    let url = ::get_slack_url_for_method("bookmarks.edit");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| EditError::Client(err))
+        .and_then(|result| {
+                      parse_edit_response(result.body.as_bytes()).map_err(|err| err.into_error(EditError::MalformedResponse))
+                  })
+}
+
+fn parse_edit_response(body: &[u8]) -> Result<EditResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<EditResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct EditRequest<'a> {
+    /// Bookmark to update.
+    pub bookmark_id: &'a str,
+    /// Channel to update bookmark in.
+    pub channel_id: &'a str,
+    /// Title for the bookmark.
+    pub title: Option<&'a str>,
+    /// Link to bookmark.
+    pub link: Option<&'a str>,
+    /// Emoji tag to apply to the link.
+    pub emoji: Option<&'a str>,
+}
+
+impl<'a> EditRequest<'a> {
+    pub fn new(bookmark_id: &'a str, channel_id: &'a str) -> Self {
+        EditRequest {
+            bookmark_id: bookmark_id,
+            channel_id: channel_id,
+            ..EditRequest::default()
+        }
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn link(mut self, link: &'a str) -> Self {
+        self.link = Some(link);
+        self
+    }
+
+    pub fn emoji(mut self, emoji: &'a str) -> Self {
+        self.emoji = Some(emoji);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EditResponse {
+    pub bookmark: Option<::Bookmark>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<EditResponse, EditError<E>>> for EditResponse {
+    fn into(self) -> Result<EditResponse, EditError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum EditError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// Value passed for bookmark_id was invalid.
+    InvalidBookmarkId,
+    /// The token is not a member of the channel.
+    NotInChannel,
+    /// Value passed for link was invalid.
+    InvalidLink,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for EditError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => EditError::ChannelNotFound,
+            "invalid_bookmark_id" => EditError::InvalidBookmarkId,
+            "not_in_channel" => EditError::NotInChannel,
+            "invalid_link" => EditError::InvalidLink,
+            "missing_scope" => EditError::MissingScope,
+            "not_authed" => EditError::NotAuthed,
+            "invalid_auth" => EditError::InvalidAuth,
+            "account_inactive" => EditError::AccountInactive,
+            "invalid_arg_name" => EditError::InvalidArgName,
+            "invalid_array_arg" => EditError::InvalidArrayArg,
+            "invalid_charset" => EditError::InvalidCharset,
+            "invalid_form_data" => EditError::InvalidFormData,
+            "invalid_post_type" => EditError::InvalidPostType,
+            "missing_post_type" => EditError::MissingPostType,
+            "team_added_to_org" => EditError::TeamAddedToOrg,
+            "request_timeout" => EditError::RequestTimeout,
+            _ => EditError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<EditError<E>> for ::Error<E> {
+    fn from(err: EditError<E>) -> Self {
+        let code = match err {
+            EditError::ChannelNotFound => "channel_not_found",
+            EditError::InvalidBookmarkId => "invalid_bookmark_id",
+            EditError::NotInChannel => "not_in_channel",
+            EditError::InvalidLink => "invalid_link",
+            EditError::MissingScope => "missing_scope",
+            EditError::NotAuthed => "not_authed",
+            EditError::InvalidAuth => "invalid_auth",
+            EditError::AccountInactive => "account_inactive",
+            EditError::InvalidArgName => "invalid_arg_name",
+            EditError::InvalidArrayArg => "invalid_array_arg",
+            EditError::InvalidCharset => "invalid_charset",
+            EditError::InvalidFormData => "invalid_form_data",
+            EditError::InvalidPostType => "invalid_post_type",
+            EditError::MissingPostType => "missing_post_type",
+            EditError::TeamAddedToOrg => "team_added_to_org",
+            EditError::RequestTimeout => "request_timeout",
+            EditError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            EditError::Unknown(code) => return ::Error::from_code("bookmarks.edit", &code),
+            EditError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("bookmarks.edit", code)
+    }
+}
+
+impl<E: Error> fmt::Display for EditError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for EditError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &EditError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &EditError::InvalidBookmarkId => "invalid_bookmark_id: Value passed for bookmark_id was invalid.",
+            &EditError::NotInChannel => "not_in_channel: The token is not a member of the channel.",
+            &EditError::InvalidLink => "invalid_link: Value passed for link was invalid.",
+            &EditError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &EditError::NotAuthed => "not_authed: No authentication token provided.",
+            &EditError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &EditError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &EditError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &EditError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &EditError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &EditError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &EditError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &EditError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &EditError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &EditError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &EditError::MalformedResponse(ref e) => e.description(),
+            &EditError::Unknown(ref s) => s,
+            &EditError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &EditError::MalformedResponse(ref e) => Some(e),
+            &EditError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// List bookmark for the channel.
+///
+/// Wraps https://api.slack.com/methods/bookmarks.list
+
+pub fn list<R>(client: &R,
+               token: &str,
+               request: &ListRequest)
+               -> Result<ListResponse, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let params = [("token", token), ("channel_id", request.channel_id)];
+    let url = ::get_slack_url_for_method("bookmarks.list");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| ListError::Client(err))
+        .and_then(|result| {
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
+                  })
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Channel to list bookmarks in.
+    pub channel_id: &'a str,
+}
+
+impl<'a> ListRequest<'a> {
+    pub fn new(channel_id: &'a str) -> Self {
+        ListRequest {
+            channel_id: channel_id,
+            ..ListRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    pub bookmarks: Option<Vec<::Bookmark>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// The token is not a member of the channel.
+    NotInChannel,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => ListError::ChannelNotFound,
+            "not_in_channel" => ListError::NotInChannel,
+            "missing_scope" => ListError::MissingScope,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::ChannelNotFound => "channel_not_found",
+            ListError::NotInChannel => "not_in_channel",
+            ListError::MissingScope => "missing_scope",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("bookmarks.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("bookmarks.list", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ListError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &ListError::NotInChannel => "not_in_channel: The token is not a member of the channel.",
+            &ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ListError::NotAuthed => "not_authed: No authentication token provided.",
+            &ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ListError::MalformedResponse(ref e) => e.description(),
+            &ListError::Unknown(ref s) => s,
+            &ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ListError::MalformedResponse(ref e) => Some(e),
+            &ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Remove bookmark from the channel.
+///
+/// Wraps https://api.slack.com/methods/bookmarks.remove
+
+pub fn remove<R>(client: &R,
+                 token: &str,
+                 request: &RemoveRequest)
+                 -> Result<RemoveResponse, RemoveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("bookmark_id".to_owned(), request.bookmark_id.into());
+    body.insert("channel_id".to_owned(), request.channel_id.into());
+    if let Some(quip_section_id) = request.quip_section_id {
+        body.insert("quip_section_id".to_owned(), quip_section_id.into());
+    }
+    let url = ::get_slack_url_for_method("bookmarks.remove");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| RemoveError::Client(err))
+        .and_then(|result| {
+                      parse_remove_response(result.body.as_bytes()).map_err(|err| err.into_error(RemoveError::MalformedResponse))
+                  })
+}
+
+fn parse_remove_response(body: &[u8]) -> Result<RemoveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RemoveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveRequest<'a> {
+    /// Bookmark to remove.
+    pub bookmark_id: &'a str,
+    /// Channel to remove bookmark.
+    pub channel_id: &'a str,
+    /// Quip section ID to unbookmark.
+    pub quip_section_id: Option<&'a str>,
+}
+
+impl<'a> RemoveRequest<'a> {
+    pub fn new(bookmark_id: &'a str, channel_id: &'a str) -> Self {
+        RemoveRequest {
+            bookmark_id: bookmark_id,
+            channel_id: channel_id,
+            ..RemoveRequest::default()
+        }
+    }
+
+    pub fn quip_section_id(mut self, quip_section_id: &'a str) -> Self {
+        self.quip_section_id = Some(quip_section_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
+    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// Value passed for bookmark_id was invalid.
+    InvalidBookmarkId,
+    /// The token is not a member of the channel.
+    NotInChannel,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => RemoveError::ChannelNotFound,
+            "invalid_bookmark_id" => RemoveError::InvalidBookmarkId,
+            "not_in_channel" => RemoveError::NotInChannel,
+            "missing_scope" => RemoveError::MissingScope,
+            "not_authed" => RemoveError::NotAuthed,
+            "invalid_auth" => RemoveError::InvalidAuth,
+            "account_inactive" => RemoveError::AccountInactive,
+            "invalid_arg_name" => RemoveError::InvalidArgName,
+            "invalid_array_arg" => RemoveError::InvalidArrayArg,
+            "invalid_charset" => RemoveError::InvalidCharset,
+            "invalid_form_data" => RemoveError::InvalidFormData,
+            "invalid_post_type" => RemoveError::InvalidPostType,
+            "missing_post_type" => RemoveError::MissingPostType,
+            "team_added_to_org" => RemoveError::TeamAddedToOrg,
+            "request_timeout" => RemoveError::RequestTimeout,
+            _ => RemoveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RemoveError<E>> for ::Error<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        let code = match err {
+            RemoveError::ChannelNotFound => "channel_not_found",
+            RemoveError::InvalidBookmarkId => "invalid_bookmark_id",
+            RemoveError::NotInChannel => "not_in_channel",
+            RemoveError::MissingScope => "missing_scope",
+            RemoveError::NotAuthed => "not_authed",
+            RemoveError::InvalidAuth => "invalid_auth",
+            RemoveError::AccountInactive => "account_inactive",
+            RemoveError::InvalidArgName => "invalid_arg_name",
+            RemoveError::InvalidArrayArg => "invalid_array_arg",
+            RemoveError::InvalidCharset => "invalid_charset",
+            RemoveError::InvalidFormData => "invalid_form_data",
+            RemoveError::InvalidPostType => "invalid_post_type",
+            RemoveError::MissingPostType => "missing_post_type",
+            RemoveError::TeamAddedToOrg => "team_added_to_org",
+            RemoveError::RequestTimeout => "request_timeout",
+            RemoveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RemoveError::Unknown(code) => return ::Error::from_code("bookmarks.remove", &code),
+            RemoveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("bookmarks.remove", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RemoveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RemoveError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &RemoveError::InvalidBookmarkId => "invalid_bookmark_id: Value passed for bookmark_id was invalid.",
+            &RemoveError::NotInChannel => "not_in_channel: The token is not a member of the channel.",
+            &RemoveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RemoveError::NotAuthed => "not_authed: No authentication token provided.",
+            &RemoveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RemoveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RemoveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RemoveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RemoveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RemoveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RemoveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RemoveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RemoveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RemoveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RemoveError::MalformedResponse(ref e) => e.description(),
+            &RemoveError::Unknown(ref s) => s,
+            &RemoveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RemoveError::MalformedResponse(ref e) => Some(e),
+            &RemoveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `bookmarks.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::bookmarks`](../client/struct.SlackClient.html#method.bookmarks).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Add bookmark to a channel. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("bookmarks.add"), request)
+    }
+
+    /// Edit bookmark. See [`edit`](fn.edit.html).
+    pub fn edit(&self, request: &EditRequest) -> Result<EditResponse, EditError<R::Error>> {
+        edit(self.client, self.token.for_method("bookmarks.edit"), request)
+    }
+
+    /// List bookmark for the channel. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("bookmarks.list"), request)
+    }
+
+    /// Remove bookmark from the channel. See [`remove`](fn.remove.html).
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<R::Error>> {
+        remove(self.client, self.token.for_method("bookmarks.remove"), request)
+    }
+}
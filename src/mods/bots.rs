@@ -21,17 +21,27 @@ pub fn info<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), request.bot.map(|bot| ("bot", bot))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(2);
+    params.push(("token", token));
+    if let Some(bot) = request.bot {
+        params.push(("bot", bot));
+    }
     let url = ::get_slack_url_for_method("bots.info");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| InfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InfoResponse>(&result)
-                            .map_err(|e| InfoError::MalformedResponse(e))
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -40,12 +50,25 @@ pub struct InfoRequest<'a> {
     pub bot: Option<&'a str>,
 }
 
+impl<'a> InfoRequest<'a> {
+    pub fn new() -> Self {
+        InfoRequest::default()
+    }
+
+    pub fn bot(mut self, bot: &'a str) -> Self {
+        self.bot = Some(bot);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InfoResponse {
     pub bot: Option<InfoResponseBot>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -135,6 +158,30 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::BotNotFound => "bot_not_found",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::UserIsBot => "user_is_bot",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("bots.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("bots.info", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -171,3 +218,25 @@ impl<E: Error> Error for InfoError<E> {
         }
     }
 }
+
+/// The `bots.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::bots`](../client/struct.SlackClient.html#method.bots).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Gets information about a bot user. See [`info`](fn.info.html).
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<R::Error>> {
+        info(self.client, self.token.for_method("bots.info"), request)
+    }
+}
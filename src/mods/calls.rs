@@ -0,0 +1,943 @@
+//! Calls made with 3rd-party providers, shown in Slack.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Registers a new Call.
+///
+/// Wraps https://api.slack.com/methods/calls.add
+
+pub fn add<R>(client: &R,
+              token: &str,
+              request: &AddRequest)
+              -> Result<AddResponse, AddError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("external_unique_id".to_owned(), request.external_unique_id.into());
+    body.insert("join_url".to_owned(), request.join_url.into());
+    if let Some(created_by) = request.created_by {
+        body.insert("created_by".to_owned(), created_by.into());
+    }
+    if let Some(date_start) = request.date_start {
+        body.insert("date_start".to_owned(), date_start.into());
+    }
+    if let Some(desktop_app_join_url) = request.desktop_app_join_url {
+        body.insert("desktop_app_join_url".to_owned(), desktop_app_join_url.into());
+    }
+    if let Some(external_display_id) = request.external_display_id {
+        body.insert("external_display_id".to_owned(), external_display_id.into());
+    }
+    if let Some(title) = request.title {
+        body.insert("title".to_owned(), title.into());
+    }
+    if let Some(users) = request.users {
+        body.insert("users".to_owned(), users.into());
+    }
+    let url = ::get_slack_url_for_method("calls.add");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| AddError::Client(err))
+        .and_then(|result| {
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
+                  })
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AddRequest<'a> {
+    /// An ID supplied by the 3rd-party Call provider. It must be unique across all Calls from that service.
+    pub external_unique_id: &'a str,
+    /// The URL required for a client to join the Call.
+    pub join_url: &'a str,
+    /// The valid Slack user ID of the user who created this Call. When this method is called with a user token, the created_by field is optional and defaults to the authed user of the token. Otherwise, the field is required.
+    pub created_by: Option<&'a str>,
+    /// Unix timestamp of the call start time
+    pub date_start: Option<u32>,
+    /// When supplied, available Slack clients will attempt to directly launch the 3rd-party Call with this URL.
+    pub desktop_app_join_url: Option<&'a str>,
+    /// An optional, human-readable ID supplied by the 3rd-party Call provider. If supplied, this ID will be displayed in the Call object.
+    pub external_display_id: Option<&'a str>,
+    /// The name of the Call.
+    pub title: Option<&'a str>,
+    /// The list of users to register as participants in the Call.
+    pub users: Option<&'a str>,
+}
+
+impl<'a> AddRequest<'a> {
+    pub fn new(external_unique_id: &'a str, join_url: &'a str) -> Self {
+        AddRequest {
+            external_unique_id: external_unique_id,
+            join_url: join_url,
+            ..AddRequest::default()
+        }
+    }
+
+    pub fn created_by(mut self, created_by: &'a str) -> Self {
+        self.created_by = Some(created_by);
+        self
+    }
+
+    pub fn date_start(mut self, date_start: u32) -> Self {
+        self.date_start = Some(date_start);
+        self
+    }
+
+    pub fn desktop_app_join_url(mut self, desktop_app_join_url: &'a str) -> Self {
+        self.desktop_app_join_url = Some(desktop_app_join_url);
+        self
+    }
+
+    pub fn external_display_id(mut self, external_display_id: &'a str) -> Self {
+        self.external_display_id = Some(external_display_id);
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn users(mut self, users: &'a str) -> Self {
+        self.users = Some(users);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddResponse {
+    pub call: Option<::Call>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
+    fn into(self) -> Result<AddResponse, AddError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddError<E: Error> {
+    /// Value passed for created_by was invalid.
+    InvalidCreatedBy,
+    /// Value passed for date_start was invalid.
+    InvalidStartDate,
+    /// Value passed for users was invalid.
+    InvalidUsers,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_created_by" => AddError::InvalidCreatedBy,
+            "invalid_start_date" => AddError::InvalidStartDate,
+            "invalid_users" => AddError::InvalidUsers,
+            "missing_scope" => AddError::MissingScope,
+            "not_authed" => AddError::NotAuthed,
+            "invalid_auth" => AddError::InvalidAuth,
+            "account_inactive" => AddError::AccountInactive,
+            "invalid_arg_name" => AddError::InvalidArgName,
+            "invalid_array_arg" => AddError::InvalidArrayArg,
+            "invalid_charset" => AddError::InvalidCharset,
+            "invalid_form_data" => AddError::InvalidFormData,
+            "invalid_post_type" => AddError::InvalidPostType,
+            "missing_post_type" => AddError::MissingPostType,
+            "team_added_to_org" => AddError::TeamAddedToOrg,
+            "request_timeout" => AddError::RequestTimeout,
+            _ => AddError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::InvalidCreatedBy => "invalid_created_by",
+            AddError::InvalidStartDate => "invalid_start_date",
+            AddError::InvalidUsers => "invalid_users",
+            AddError::MissingScope => "missing_scope",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("calls.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("calls.add", code)
+    }
+}
+
+impl<E: Error> fmt::Display for AddError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AddError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &AddError::InvalidCreatedBy => "invalid_created_by: Value passed for created_by was invalid.",
+            &AddError::InvalidStartDate => "invalid_start_date: Value passed for date_start was invalid.",
+            &AddError::InvalidUsers => "invalid_users: Value passed for users was invalid.",
+            &AddError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &AddError::NotAuthed => "not_authed: No authentication token provided.",
+            &AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &AddError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &AddError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &AddError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &AddError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &AddError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &AddError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &AddError::MalformedResponse(ref e) => e.description(),
+            &AddError::Unknown(ref s) => s,
+            &AddError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &AddError::MalformedResponse(ref e) => Some(e),
+            &AddError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Ends a Call.
+///
+/// Wraps https://api.slack.com/methods/calls.end
+
+pub fn end<R>(client: &R,
+              token: &str,
+              request: &EndRequest)
+              -> Result<EndResponse, EndError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("id".to_owned(), request.id.into());
+    if let Some(duration) = request.duration {
+        body.insert("duration".to_owned(), duration.into());
+    }
+    let url = ::get_slack_url_for_method("calls.end");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| EndError::Client(err))
+        .and_then(|result| {
+                      parse_end_response(result.body.as_bytes()).map_err(|err| err.into_error(EndError::MalformedResponse))
+                  })
+}
+
+fn parse_end_response(body: &[u8]) -> Result<EndResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<EndResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct EndRequest<'a> {
+    /// id returned when registering the call using the calls.add method.
+    pub id: &'a str,
+    /// Call duration in seconds
+    pub duration: Option<u32>,
+}
+
+impl<'a> EndRequest<'a> {
+    pub fn new(id: &'a str) -> Self {
+        EndRequest {
+            id: id,
+            ..EndRequest::default()
+        }
+    }
+
+    pub fn duration(mut self, duration: u32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EndResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<EndResponse, EndError<E>>> for EndResponse {
+    fn into(self) -> Result<EndResponse, EndError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum EndError<E: Error> {
+    /// Value passed for id was invalid.
+    InvalidCallId,
+    /// The call does not exist.
+    NotFound,
+    /// Value passed for duration was invalid.
+    InvalidDuration,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for EndError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_call_id" => EndError::InvalidCallId,
+            "not_found" => EndError::NotFound,
+            "invalid_duration" => EndError::InvalidDuration,
+            "missing_scope" => EndError::MissingScope,
+            "not_authed" => EndError::NotAuthed,
+            "invalid_auth" => EndError::InvalidAuth,
+            "account_inactive" => EndError::AccountInactive,
+            "invalid_arg_name" => EndError::InvalidArgName,
+            "invalid_array_arg" => EndError::InvalidArrayArg,
+            "invalid_charset" => EndError::InvalidCharset,
+            "invalid_form_data" => EndError::InvalidFormData,
+            "invalid_post_type" => EndError::InvalidPostType,
+            "missing_post_type" => EndError::MissingPostType,
+            "team_added_to_org" => EndError::TeamAddedToOrg,
+            "request_timeout" => EndError::RequestTimeout,
+            _ => EndError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<EndError<E>> for ::Error<E> {
+    fn from(err: EndError<E>) -> Self {
+        let code = match err {
+            EndError::InvalidCallId => "invalid_call_id",
+            EndError::NotFound => "not_found",
+            EndError::InvalidDuration => "invalid_duration",
+            EndError::MissingScope => "missing_scope",
+            EndError::NotAuthed => "not_authed",
+            EndError::InvalidAuth => "invalid_auth",
+            EndError::AccountInactive => "account_inactive",
+            EndError::InvalidArgName => "invalid_arg_name",
+            EndError::InvalidArrayArg => "invalid_array_arg",
+            EndError::InvalidCharset => "invalid_charset",
+            EndError::InvalidFormData => "invalid_form_data",
+            EndError::InvalidPostType => "invalid_post_type",
+            EndError::MissingPostType => "missing_post_type",
+            EndError::TeamAddedToOrg => "team_added_to_org",
+            EndError::RequestTimeout => "request_timeout",
+            EndError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            EndError::Unknown(code) => return ::Error::from_code("calls.end", &code),
+            EndError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("calls.end", code)
+    }
+}
+
+impl<E: Error> fmt::Display for EndError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for EndError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &EndError::InvalidCallId => "invalid_call_id: Value passed for id was invalid.",
+            &EndError::NotFound => "not_found: The call does not exist.",
+            &EndError::InvalidDuration => "invalid_duration: Value passed for duration was invalid.",
+            &EndError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &EndError::NotAuthed => "not_authed: No authentication token provided.",
+            &EndError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &EndError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &EndError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &EndError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &EndError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &EndError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &EndError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &EndError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &EndError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &EndError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &EndError::MalformedResponse(ref e) => e.description(),
+            &EndError::Unknown(ref s) => s,
+            &EndError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &EndError::MalformedResponse(ref e) => Some(e),
+            &EndError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Returns information about a Call.
+///
+/// Wraps https://api.slack.com/methods/calls.info
+
+pub fn info<R>(client: &R,
+               token: &str,
+               request: &InfoRequest)
+               -> Result<InfoResponse, InfoError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let params = [("token", token), ("id", request.id)];
+    let url = ::get_slack_url_for_method("calls.info");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| InfoError::Client(err))
+        .and_then(|result| {
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
+                  })
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequest<'a> {
+    /// id of the Call returned by the calls.add method.
+    pub id: &'a str,
+}
+
+impl<'a> InfoRequest<'a> {
+    pub fn new(id: &'a str) -> Self {
+        InfoRequest {
+            id: id,
+            ..InfoRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InfoResponse {
+    pub call: Option<::Call>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
+    fn into(self) -> Result<InfoResponse, InfoError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InfoError<E: Error> {
+    /// Value passed for id was invalid.
+    InvalidCallId,
+    /// The call does not exist.
+    NotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InfoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_call_id" => InfoError::InvalidCallId,
+            "not_found" => InfoError::NotFound,
+            "missing_scope" => InfoError::MissingScope,
+            "not_authed" => InfoError::NotAuthed,
+            "invalid_auth" => InfoError::InvalidAuth,
+            "account_inactive" => InfoError::AccountInactive,
+            "invalid_arg_name" => InfoError::InvalidArgName,
+            "invalid_array_arg" => InfoError::InvalidArrayArg,
+            "invalid_charset" => InfoError::InvalidCharset,
+            "invalid_form_data" => InfoError::InvalidFormData,
+            "invalid_post_type" => InfoError::InvalidPostType,
+            "missing_post_type" => InfoError::MissingPostType,
+            "team_added_to_org" => InfoError::TeamAddedToOrg,
+            "request_timeout" => InfoError::RequestTimeout,
+            _ => InfoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::InvalidCallId => "invalid_call_id",
+            InfoError::NotFound => "not_found",
+            InfoError::MissingScope => "missing_scope",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("calls.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("calls.info", code)
+    }
+}
+
+impl<E: Error> fmt::Display for InfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InfoError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &InfoError::InvalidCallId => "invalid_call_id: Value passed for id was invalid.",
+            &InfoError::NotFound => "not_found: The call does not exist.",
+            &InfoError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &InfoError::NotAuthed => "not_authed: No authentication token provided.",
+            &InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &InfoError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &InfoError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &InfoError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &InfoError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &InfoError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &InfoError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &InfoError::MalformedResponse(ref e) => e.description(),
+            &InfoError::Unknown(ref s) => s,
+            &InfoError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &InfoError::MalformedResponse(ref e) => Some(e),
+            &InfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Updates information about a Call.
+///
+/// Wraps https://api.slack.com/methods/calls.update
+
+pub fn update<R>(client: &R,
+                 token: &str,
+                 request: &UpdateRequest)
+                 -> Result<UpdateResponse, UpdateError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("id".to_owned(), request.id.into());
+    if let Some(desktop_app_join_url) = request.desktop_app_join_url {
+        body.insert("desktop_app_join_url".to_owned(), desktop_app_join_url.into());
+    }
+    if let Some(join_url) = request.join_url {
+        body.insert("join_url".to_owned(), join_url.into());
+    }
+    if let Some(title) = request.title {
+        body.insert("title".to_owned(), title.into());
+    }
+    let url = ::get_slack_url_for_method("calls.update");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| UpdateError::Client(err))
+        .and_then(|result| {
+                      parse_update_response(result.body.as_bytes()).map_err(|err| err.into_error(UpdateError::MalformedResponse))
+                  })
+}
+
+fn parse_update_response(body: &[u8]) -> Result<UpdateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UpdateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UpdateRequest<'a> {
+    /// id returned by the calls.add method.
+    pub id: &'a str,
+    /// When supplied, available Slack clients will attempt to directly launch the 3rd-party Call with this URL.
+    pub desktop_app_join_url: Option<&'a str>,
+    /// The URL required for a client to join the Call.
+    pub join_url: Option<&'a str>,
+    /// The name of the Call.
+    pub title: Option<&'a str>,
+}
+
+impl<'a> UpdateRequest<'a> {
+    pub fn new(id: &'a str) -> Self {
+        UpdateRequest {
+            id: id,
+            ..UpdateRequest::default()
+        }
+    }
+
+    pub fn desktop_app_join_url(mut self, desktop_app_join_url: &'a str) -> Self {
+        self.desktop_app_join_url = Some(desktop_app_join_url);
+        self
+    }
+
+    pub fn join_url(mut self, join_url: &'a str) -> Self {
+        self.join_url = Some(join_url);
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateResponse {
+    pub call: Option<::Call>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
+    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UpdateError<E: Error> {
+    /// Value passed for id was invalid.
+    InvalidCallId,
+    /// The call does not exist.
+    NotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UpdateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_call_id" => UpdateError::InvalidCallId,
+            "not_found" => UpdateError::NotFound,
+            "missing_scope" => UpdateError::MissingScope,
+            "not_authed" => UpdateError::NotAuthed,
+            "invalid_auth" => UpdateError::InvalidAuth,
+            "account_inactive" => UpdateError::AccountInactive,
+            "invalid_arg_name" => UpdateError::InvalidArgName,
+            "invalid_array_arg" => UpdateError::InvalidArrayArg,
+            "invalid_charset" => UpdateError::InvalidCharset,
+            "invalid_form_data" => UpdateError::InvalidFormData,
+            "invalid_post_type" => UpdateError::InvalidPostType,
+            "missing_post_type" => UpdateError::MissingPostType,
+            "team_added_to_org" => UpdateError::TeamAddedToOrg,
+            "request_timeout" => UpdateError::RequestTimeout,
+            _ => UpdateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<UpdateError<E>> for ::Error<E> {
+    fn from(err: UpdateError<E>) -> Self {
+        let code = match err {
+            UpdateError::InvalidCallId => "invalid_call_id",
+            UpdateError::NotFound => "not_found",
+            UpdateError::MissingScope => "missing_scope",
+            UpdateError::NotAuthed => "not_authed",
+            UpdateError::InvalidAuth => "invalid_auth",
+            UpdateError::AccountInactive => "account_inactive",
+            UpdateError::InvalidArgName => "invalid_arg_name",
+            UpdateError::InvalidArrayArg => "invalid_array_arg",
+            UpdateError::InvalidCharset => "invalid_charset",
+            UpdateError::InvalidFormData => "invalid_form_data",
+            UpdateError::InvalidPostType => "invalid_post_type",
+            UpdateError::MissingPostType => "missing_post_type",
+            UpdateError::TeamAddedToOrg => "team_added_to_org",
+            UpdateError::RequestTimeout => "request_timeout",
+            UpdateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UpdateError::Unknown(code) => return ::Error::from_code("calls.update", &code),
+            UpdateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("calls.update", code)
+    }
+}
+
+impl<E: Error> fmt::Display for UpdateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UpdateError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &UpdateError::InvalidCallId => "invalid_call_id: Value passed for id was invalid.",
+            &UpdateError::NotFound => "not_found: The call does not exist.",
+            &UpdateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &UpdateError::NotAuthed => "not_authed: No authentication token provided.",
+            &UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &UpdateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &UpdateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &UpdateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &UpdateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &UpdateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &UpdateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &UpdateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &UpdateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &UpdateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &UpdateError::MalformedResponse(ref e) => e.description(),
+            &UpdateError::Unknown(ref s) => s,
+            &UpdateError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &UpdateError::MalformedResponse(ref e) => Some(e),
+            &UpdateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `calls.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::calls`](../client/struct.SlackClient.html#method.calls).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Registers a new Call. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("calls.add"), request)
+    }
+
+    /// Ends a Call. See [`end`](fn.end.html).
+    pub fn end(&self, request: &EndRequest) -> Result<EndResponse, EndError<R::Error>> {
+        end(self.client, self.token.for_method("calls.end"), request)
+    }
+
+    /// Returns information about a Call. See [`info`](fn.info.html).
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<R::Error>> {
+        info(self.client, self.token.for_method("calls.info"), request)
+    }
+
+    /// Updates information about a Call. See [`update`](fn.update.html).
+    pub fn update(&self, request: &UpdateRequest) -> Result<UpdateResponse, UpdateError<R::Error>> {
+        update(self.client, self.token.for_method("calls.update"), request)
+    }
+}
@@ -0,0 +1,447 @@
+//! The participants of Calls made with 3rd-party providers.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Registers new participants added to a Call.
+///
+/// Wraps https://api.slack.com/methods/calls.participants.add
+
+pub fn add<R>(client: &R,
+              token: &str,
+              request: &AddRequest)
+              -> Result<AddResponse, AddError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("id".to_owned(), request.id.into());
+    body.insert("users".to_owned(), request.users.into());
+    let url = ::get_slack_url_for_method("calls.participants.add");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| AddError::Client(err))
+        .and_then(|result| {
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
+                  })
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AddRequest<'a> {
+    /// id returned by the calls.add method.
+    pub id: &'a str,
+    /// The list of users to add as participants in the Call.
+    pub users: &'a str,
+}
+
+impl<'a> AddRequest<'a> {
+    pub fn new(id: &'a str, users: &'a str) -> Self {
+        AddRequest {
+            id: id,
+            users: users,
+            ..AddRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
+    fn into(self) -> Result<AddResponse, AddError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddError<E: Error> {
+    /// Value passed for id was invalid.
+    InvalidCallId,
+    /// The call does not exist.
+    NotFound,
+    /// Value passed for users was invalid.
+    InvalidUsers,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_call_id" => AddError::InvalidCallId,
+            "not_found" => AddError::NotFound,
+            "invalid_users" => AddError::InvalidUsers,
+            "missing_scope" => AddError::MissingScope,
+            "not_authed" => AddError::NotAuthed,
+            "invalid_auth" => AddError::InvalidAuth,
+            "account_inactive" => AddError::AccountInactive,
+            "invalid_arg_name" => AddError::InvalidArgName,
+            "invalid_array_arg" => AddError::InvalidArrayArg,
+            "invalid_charset" => AddError::InvalidCharset,
+            "invalid_form_data" => AddError::InvalidFormData,
+            "invalid_post_type" => AddError::InvalidPostType,
+            "missing_post_type" => AddError::MissingPostType,
+            "team_added_to_org" => AddError::TeamAddedToOrg,
+            "request_timeout" => AddError::RequestTimeout,
+            _ => AddError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::InvalidCallId => "invalid_call_id",
+            AddError::NotFound => "not_found",
+            AddError::InvalidUsers => "invalid_users",
+            AddError::MissingScope => "missing_scope",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("calls.participants.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("calls.participants.add", code)
+    }
+}
+
+impl<E: Error> fmt::Display for AddError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AddError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &AddError::InvalidCallId => "invalid_call_id: Value passed for id was invalid.",
+            &AddError::NotFound => "not_found: The call does not exist.",
+            &AddError::InvalidUsers => "invalid_users: Value passed for users was invalid.",
+            &AddError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &AddError::NotAuthed => "not_authed: No authentication token provided.",
+            &AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &AddError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &AddError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &AddError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &AddError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &AddError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &AddError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &AddError::MalformedResponse(ref e) => e.description(),
+            &AddError::Unknown(ref s) => s,
+            &AddError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &AddError::MalformedResponse(ref e) => Some(e),
+            &AddError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Registers participants removed from a Call.
+///
+/// Wraps https://api.slack.com/methods/calls.participants.remove
+
+pub fn remove<R>(client: &R,
+                 token: &str,
+                 request: &RemoveRequest)
+                 -> Result<RemoveResponse, RemoveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("id".to_owned(), request.id.into());
+    body.insert("users".to_owned(), request.users.into());
+    let url = ::get_slack_url_for_method("calls.participants.remove");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| RemoveError::Client(err))
+        .and_then(|result| {
+                      parse_remove_response(result.body.as_bytes()).map_err(|err| err.into_error(RemoveError::MalformedResponse))
+                  })
+}
+
+fn parse_remove_response(body: &[u8]) -> Result<RemoveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RemoveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveRequest<'a> {
+    /// id returned by the calls.add method.
+    pub id: &'a str,
+    /// The list of users to remove as participants in the Call.
+    pub users: &'a str,
+}
+
+impl<'a> RemoveRequest<'a> {
+    pub fn new(id: &'a str, users: &'a str) -> Self {
+        RemoveRequest {
+            id: id,
+            users: users,
+            ..RemoveRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
+    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveError<E: Error> {
+    /// Value passed for id was invalid.
+    InvalidCallId,
+    /// The call does not exist.
+    NotFound,
+    /// Value passed for users was invalid.
+    InvalidUsers,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_call_id" => RemoveError::InvalidCallId,
+            "not_found" => RemoveError::NotFound,
+            "invalid_users" => RemoveError::InvalidUsers,
+            "missing_scope" => RemoveError::MissingScope,
+            "not_authed" => RemoveError::NotAuthed,
+            "invalid_auth" => RemoveError::InvalidAuth,
+            "account_inactive" => RemoveError::AccountInactive,
+            "invalid_arg_name" => RemoveError::InvalidArgName,
+            "invalid_array_arg" => RemoveError::InvalidArrayArg,
+            "invalid_charset" => RemoveError::InvalidCharset,
+            "invalid_form_data" => RemoveError::InvalidFormData,
+            "invalid_post_type" => RemoveError::InvalidPostType,
+            "missing_post_type" => RemoveError::MissingPostType,
+            "team_added_to_org" => RemoveError::TeamAddedToOrg,
+            "request_timeout" => RemoveError::RequestTimeout,
+            _ => RemoveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RemoveError<E>> for ::Error<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        let code = match err {
+            RemoveError::InvalidCallId => "invalid_call_id",
+            RemoveError::NotFound => "not_found",
+            RemoveError::InvalidUsers => "invalid_users",
+            RemoveError::MissingScope => "missing_scope",
+            RemoveError::NotAuthed => "not_authed",
+            RemoveError::InvalidAuth => "invalid_auth",
+            RemoveError::AccountInactive => "account_inactive",
+            RemoveError::InvalidArgName => "invalid_arg_name",
+            RemoveError::InvalidArrayArg => "invalid_array_arg",
+            RemoveError::InvalidCharset => "invalid_charset",
+            RemoveError::InvalidFormData => "invalid_form_data",
+            RemoveError::InvalidPostType => "invalid_post_type",
+            RemoveError::MissingPostType => "missing_post_type",
+            RemoveError::TeamAddedToOrg => "team_added_to_org",
+            RemoveError::RequestTimeout => "request_timeout",
+            RemoveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RemoveError::Unknown(code) => return ::Error::from_code("calls.participants.remove", &code),
+            RemoveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("calls.participants.remove", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RemoveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RemoveError::InvalidCallId => "invalid_call_id: Value passed for id was invalid.",
+            &RemoveError::NotFound => "not_found: The call does not exist.",
+            &RemoveError::InvalidUsers => "invalid_users: Value passed for users was invalid.",
+            &RemoveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RemoveError::NotAuthed => "not_authed: No authentication token provided.",
+            &RemoveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RemoveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RemoveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RemoveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RemoveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RemoveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RemoveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RemoveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RemoveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RemoveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RemoveError::MalformedResponse(ref e) => e.description(),
+            &RemoveError::Unknown(ref s) => s,
+            &RemoveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RemoveError::MalformedResponse(ref e) => Some(e),
+            &RemoveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `calls.participants.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::calls_participants`](../client/struct.SlackClient.html#method.calls_participants).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Registers new participants added to a Call. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("calls.participants.add"), request)
+    }
+
+    /// Registers participants removed from a Call. See [`remove`](fn.remove.html).
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<R::Error>> {
+        remove(self.client, self.token.for_method("calls.participants.remove"), request)
+    }
+}
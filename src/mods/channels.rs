@@ -1,4 +1,7 @@
 //! Get info on your team's Slack channels, create or archive channels, invite users, set the topic and purpose, and mark a channel as read.
+//!
+//! Slack has retired these methods, so their calls are sent to the `conversations` methods that
+//! replaced them, and the responses translated back.
 
 
 #[allow(unused_imports)]
@@ -15,6 +18,7 @@ use requests::SlackWebRequestSender;
 ///
 /// Wraps https://api.slack.com/methods/channels.archive
 
+#[deprecated(note = "channels.archive was retired by Slack: use conversations::archive")]
 pub fn archive<R>(client: &R,
                   token: &str,
                   request: &ArchiveRequest)
@@ -22,17 +26,21 @@ pub fn archive<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.archive");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "channels.archive", &params[..])
         .map_err(|err| ArchiveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ArchiveResponse>(&result)
-                            .map_err(|e| ArchiveError::MalformedResponse(e))
+                      parse_archive_response(&result).map_err(|err| err.into_error(ArchiveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_archive_response(body: &[u8]) -> Result<ArchiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ArchiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -41,11 +49,22 @@ pub struct ArchiveRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> ArchiveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        ArchiveRequest {
+            channel: channel,
+            ..ArchiveRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ArchiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -131,6 +150,34 @@ impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
     }
 }
 
+impl<E: Error> From<ArchiveError<E>> for ::Error<E> {
+    fn from(err: ArchiveError<E>) -> Self {
+        let code = match err {
+            ArchiveError::ChannelNotFound => "channel_not_found",
+            ArchiveError::AlreadyArchived => "already_archived",
+            ArchiveError::CantArchiveGeneral => "cant_archive_general",
+            ArchiveError::RestrictedAction => "restricted_action",
+            ArchiveError::NotAuthed => "not_authed",
+            ArchiveError::InvalidAuth => "invalid_auth",
+            ArchiveError::AccountInactive => "account_inactive",
+            ArchiveError::UserIsBot => "user_is_bot",
+            ArchiveError::UserIsRestricted => "user_is_restricted",
+            ArchiveError::InvalidArgName => "invalid_arg_name",
+            ArchiveError::InvalidArrayArg => "invalid_array_arg",
+            ArchiveError::InvalidCharset => "invalid_charset",
+            ArchiveError::InvalidFormData => "invalid_form_data",
+            ArchiveError::InvalidPostType => "invalid_post_type",
+            ArchiveError::MissingPostType => "missing_post_type",
+            ArchiveError::TeamAddedToOrg => "team_added_to_org",
+            ArchiveError::RequestTimeout => "request_timeout",
+            ArchiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ArchiveError::Unknown(code) => return ::Error::from_code("channels.archive", &code),
+            ArchiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.archive", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ArchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -176,6 +223,7 @@ impl<E: Error> Error for ArchiveError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.create
 
+#[deprecated(note = "channels.create was retired by Slack: use conversations::create")]
 pub fn create<R>(client: &R,
                  token: &str,
                  request: &CreateRequest)
@@ -183,21 +231,26 @@ pub fn create<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("name", request.name)),
-                      request
-                          .validate
-                          .map(|validate| ("validate", if validate { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.create");
-    client
-        .send(&url, &params[..])
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    params.push(("name", request.name));
+    if let Some(validate) = request.validate {
+        params.push(("validate", if validate { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "channels.create", &params[..])
         .map_err(|err| CreateError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<CreateResponse>(&result)
-                            .map_err(|e| CreateError::MalformedResponse(e))
+                      parse_create_response(&result).map_err(|err| err.into_error(CreateError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_create_response(body: &[u8]) -> Result<CreateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CreateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -208,12 +261,28 @@ pub struct CreateRequest<'a> {
     pub validate: Option<bool>,
 }
 
+impl<'a> CreateRequest<'a> {
+    pub fn new(name: &'a str) -> Self {
+        CreateRequest {
+            name: name,
+            ..CreateRequest::default()
+        }
+    }
+
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateResponse {
     pub channel: Option<::Channel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -311,6 +380,38 @@ impl<'a, E: Error> From<&'a str> for CreateError<E> {
     }
 }
 
+impl<E: Error> From<CreateError<E>> for ::Error<E> {
+    fn from(err: CreateError<E>) -> Self {
+        let code = match err {
+            CreateError::NameTaken => "name_taken",
+            CreateError::RestrictedAction => "restricted_action",
+            CreateError::NoChannel => "no_channel",
+            CreateError::InvalidNameRequired => "invalid_name_required",
+            CreateError::InvalidNamePunctuation => "invalid_name_punctuation",
+            CreateError::InvalidNameMaxlength => "invalid_name_maxlength",
+            CreateError::InvalidNameSpecials => "invalid_name_specials",
+            CreateError::InvalidName => "invalid_name",
+            CreateError::NotAuthed => "not_authed",
+            CreateError::InvalidAuth => "invalid_auth",
+            CreateError::AccountInactive => "account_inactive",
+            CreateError::UserIsBot => "user_is_bot",
+            CreateError::UserIsRestricted => "user_is_restricted",
+            CreateError::InvalidArgName => "invalid_arg_name",
+            CreateError::InvalidArrayArg => "invalid_array_arg",
+            CreateError::InvalidCharset => "invalid_charset",
+            CreateError::InvalidFormData => "invalid_form_data",
+            CreateError::InvalidPostType => "invalid_post_type",
+            CreateError::MissingPostType => "missing_post_type",
+            CreateError::TeamAddedToOrg => "team_added_to_org",
+            CreateError::RequestTimeout => "request_timeout",
+            CreateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CreateError::Unknown(code) => return ::Error::from_code("channels.create", &code),
+            CreateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.create", code)
+    }
+}
+
 impl<E: Error> fmt::Display for CreateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -360,34 +461,48 @@ impl<E: Error> Error for CreateError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.history
 
+#[deprecated(note = "channels.history was retired by Slack: use conversations::history")]
 pub fn history<R>(client: &R,
                   token: &str,
                   request: &HistoryRequest)
                   -> Result<HistoryResponse, HistoryError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      request.latest.map(|latest| ("latest", latest)),
-                      request.oldest.map(|oldest| ("oldest", oldest)),
-                      request
-                          .inclusive
-                          .map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      request
-                          .unreads
-                          .map(|unreads| ("unreads", if unreads { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.history");
-    client
-        .send(&url, &params[..])
+    let count = request.count.map(::IntParam::new);
+    let mut params = Vec::with_capacity(7);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    if let Some(latest) = request.latest {
+        params.push(("latest", latest));
+    }
+    if let Some(oldest) = request.oldest {
+        params.push(("oldest", oldest));
+    }
+    if let Some(inclusive) = request.inclusive {
+        params.push(("inclusive", if inclusive { "1" } else { "0" }));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(unreads) = request.unreads {
+        params.push(("unreads", if unreads { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "channels.history", &params[..])
         .map_err(|err| HistoryError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<HistoryResponse>(&result)
-                            .map_err(|e| HistoryError::MalformedResponse(e))
+                      parse_history_response(&result).map_err(|err| err.into_error(HistoryError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_history_response(body: &[u8]) -> Result<HistoryResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<HistoryResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -406,6 +521,40 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
+impl<'a> HistoryRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        HistoryRequest {
+            channel: channel,
+            ..HistoryRequest::default()
+        }
+    }
+
+    pub fn latest(mut self, latest: &'a str) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: &'a str) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct HistoryResponse {
     error: Option<String>,
@@ -414,6 +563,10 @@ pub struct HistoryResponse {
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -490,6 +643,31 @@ impl<'a, E: Error> From<&'a str> for HistoryError<E> {
     }
 }
 
+impl<E: Error> From<HistoryError<E>> for ::Error<E> {
+    fn from(err: HistoryError<E>) -> Self {
+        let code = match err {
+            HistoryError::ChannelNotFound => "channel_not_found",
+            HistoryError::InvalidTsLatest => "invalid_ts_latest",
+            HistoryError::InvalidTsOldest => "invalid_ts_oldest",
+            HistoryError::NotAuthed => "not_authed",
+            HistoryError::InvalidAuth => "invalid_auth",
+            HistoryError::AccountInactive => "account_inactive",
+            HistoryError::InvalidArgName => "invalid_arg_name",
+            HistoryError::InvalidArrayArg => "invalid_array_arg",
+            HistoryError::InvalidCharset => "invalid_charset",
+            HistoryError::InvalidFormData => "invalid_form_data",
+            HistoryError::InvalidPostType => "invalid_post_type",
+            HistoryError::MissingPostType => "missing_post_type",
+            HistoryError::TeamAddedToOrg => "team_added_to_org",
+            HistoryError::RequestTimeout => "request_timeout",
+            HistoryError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            HistoryError::Unknown(code) => return ::Error::from_code("channels.history", &code),
+            HistoryError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.history", code)
+    }
+}
+
 impl<E: Error> fmt::Display for HistoryError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -532,6 +710,7 @@ impl<E: Error> Error for HistoryError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.info
 
+#[deprecated(note = "channels.info was retired by Slack: use conversations::info")]
 pub fn info<R>(client: &R,
                token: &str,
                request: &InfoRequest)
@@ -539,17 +718,21 @@ pub fn info<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.info");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "channels.info", &params[..])
         .map_err(|err| InfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InfoResponse>(&result)
-                            .map_err(|e| InfoError::MalformedResponse(e))
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -558,12 +741,23 @@ pub struct InfoRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> InfoRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        InfoRequest {
+            channel: channel,
+            ..InfoRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InfoResponse {
     pub channel: Option<::Channel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -634,6 +828,29 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::ChannelNotFound => "channel_not_found",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("channels.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.info", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -674,6 +891,7 @@ impl<E: Error> Error for InfoError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.invite
 
+#[deprecated(note = "channels.invite was retired by Slack: use conversations::invite")]
 pub fn invite<R>(client: &R,
                  token: &str,
                  request: &InviteRequest)
@@ -681,19 +899,21 @@ pub fn invite<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.invite");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("user", request.user)];
+    ::legacy::send_bytes(client, "channels.invite", &params[..])
         .map_err(|err| InviteError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InviteResponse>(&result)
-                            .map_err(|e| InviteError::MalformedResponse(e))
+                      parse_invite_response(&result).map_err(|err| err.into_error(InviteError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_invite_response(body: &[u8]) -> Result<InviteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InviteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -704,12 +924,24 @@ pub struct InviteRequest<'a> {
     pub user: &'a str,
 }
 
+impl<'a> InviteRequest<'a> {
+    pub fn new(channel: &'a str, user: &'a str) -> Self {
+        InviteRequest {
+            channel: channel,
+            user: user,
+            ..InviteRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InviteResponse {
     pub channel: Option<::Channel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -807,6 +1039,38 @@ impl<'a, E: Error> From<&'a str> for InviteError<E> {
     }
 }
 
+impl<E: Error> From<InviteError<E>> for ::Error<E> {
+    fn from(err: InviteError<E>) -> Self {
+        let code = match err {
+            InviteError::ChannelNotFound => "channel_not_found",
+            InviteError::UserNotFound => "user_not_found",
+            InviteError::CantInviteSelf => "cant_invite_self",
+            InviteError::NotInChannel => "not_in_channel",
+            InviteError::AlreadyInChannel => "already_in_channel",
+            InviteError::IsArchived => "is_archived",
+            InviteError::CantInvite => "cant_invite",
+            InviteError::UraMaxChannels => "ura_max_channels",
+            InviteError::NotAuthed => "not_authed",
+            InviteError::InvalidAuth => "invalid_auth",
+            InviteError::AccountInactive => "account_inactive",
+            InviteError::UserIsBot => "user_is_bot",
+            InviteError::UserIsUltraRestricted => "user_is_ultra_restricted",
+            InviteError::InvalidArgName => "invalid_arg_name",
+            InviteError::InvalidArrayArg => "invalid_array_arg",
+            InviteError::InvalidCharset => "invalid_charset",
+            InviteError::InvalidFormData => "invalid_form_data",
+            InviteError::InvalidPostType => "invalid_post_type",
+            InviteError::MissingPostType => "missing_post_type",
+            InviteError::TeamAddedToOrg => "team_added_to_org",
+            InviteError::RequestTimeout => "request_timeout",
+            InviteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InviteError::Unknown(code) => return ::Error::from_code("channels.invite", &code),
+            InviteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.invite", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InviteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -856,6 +1120,7 @@ impl<E: Error> Error for InviteError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.join
 
+#[deprecated(note = "channels.join was retired by Slack: use conversations::join")]
 pub fn join<R>(client: &R,
                token: &str,
                request: &JoinRequest)
@@ -863,21 +1128,26 @@ pub fn join<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("name", request.name)),
-                      request
-                          .validate
-                          .map(|validate| ("validate", if validate { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.join");
-    client
-        .send(&url, &params[..])
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    params.push(("name", request.name));
+    if let Some(validate) = request.validate {
+        params.push(("validate", if validate { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "channels.join", &params[..])
         .map_err(|err| JoinError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<JoinResponse>(&result)
-                            .map_err(|e| JoinError::MalformedResponse(e))
+                      parse_join_response(&result).map_err(|err| err.into_error(JoinError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_join_response(body: &[u8]) -> Result<JoinResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<JoinResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -888,12 +1158,28 @@ pub struct JoinRequest<'a> {
     pub validate: Option<bool>,
 }
 
+impl<'a> JoinRequest<'a> {
+    pub fn new(name: &'a str) -> Self {
+        JoinRequest {
+            name: name,
+            ..JoinRequest::default()
+        }
+    }
+
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct JoinResponse {
     pub channel: Option<::Channel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -997,6 +1283,40 @@ impl<'a, E: Error> From<&'a str> for JoinError<E> {
     }
 }
 
+impl<E: Error> From<JoinError<E>> for ::Error<E> {
+    fn from(err: JoinError<E>) -> Self {
+        let code = match err {
+            JoinError::ChannelNotFound => "channel_not_found",
+            JoinError::NameTaken => "name_taken",
+            JoinError::RestrictedAction => "restricted_action",
+            JoinError::NoChannel => "no_channel",
+            JoinError::IsArchived => "is_archived",
+            JoinError::InvalidNameRequired => "invalid_name_required",
+            JoinError::InvalidNamePunctuation => "invalid_name_punctuation",
+            JoinError::InvalidNameMaxlength => "invalid_name_maxlength",
+            JoinError::InvalidNameSpecials => "invalid_name_specials",
+            JoinError::InvalidName => "invalid_name",
+            JoinError::NotAuthed => "not_authed",
+            JoinError::InvalidAuth => "invalid_auth",
+            JoinError::AccountInactive => "account_inactive",
+            JoinError::UserIsBot => "user_is_bot",
+            JoinError::UserIsRestricted => "user_is_restricted",
+            JoinError::InvalidArgName => "invalid_arg_name",
+            JoinError::InvalidArrayArg => "invalid_array_arg",
+            JoinError::InvalidCharset => "invalid_charset",
+            JoinError::InvalidFormData => "invalid_form_data",
+            JoinError::InvalidPostType => "invalid_post_type",
+            JoinError::MissingPostType => "missing_post_type",
+            JoinError::TeamAddedToOrg => "team_added_to_org",
+            JoinError::RequestTimeout => "request_timeout",
+            JoinError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            JoinError::Unknown(code) => return ::Error::from_code("channels.join", &code),
+            JoinError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.join", code)
+    }
+}
+
 impl<E: Error> fmt::Display for JoinError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1048,6 +1368,7 @@ impl<E: Error> Error for JoinError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.kick
 
+#[deprecated(note = "channels.kick was retired by Slack: use conversations::kick")]
 pub fn kick<R>(client: &R,
                token: &str,
                request: &KickRequest)
@@ -1055,19 +1376,21 @@ pub fn kick<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.kick");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("user", request.user)];
+    ::legacy::send_bytes(client, "channels.kick", &params[..])
         .map_err(|err| KickError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<KickResponse>(&result)
-                            .map_err(|e| KickError::MalformedResponse(e))
+                      parse_kick_response(&result).map_err(|err| err.into_error(KickError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_kick_response(body: &[u8]) -> Result<KickResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<KickResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1078,11 +1401,23 @@ pub struct KickRequest<'a> {
     pub user: &'a str,
 }
 
+impl<'a> KickRequest<'a> {
+    pub fn new(channel: &'a str, user: &'a str) -> Self {
+        KickRequest {
+            channel: channel,
+            user: user,
+            ..KickRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct KickResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1174,6 +1509,36 @@ impl<'a, E: Error> From<&'a str> for KickError<E> {
     }
 }
 
+impl<E: Error> From<KickError<E>> for ::Error<E> {
+    fn from(err: KickError<E>) -> Self {
+        let code = match err {
+            KickError::ChannelNotFound => "channel_not_found",
+            KickError::UserNotFound => "user_not_found",
+            KickError::CantKickSelf => "cant_kick_self",
+            KickError::NotInChannel => "not_in_channel",
+            KickError::CantKickFromGeneral => "cant_kick_from_general",
+            KickError::RestrictedAction => "restricted_action",
+            KickError::NotAuthed => "not_authed",
+            KickError::InvalidAuth => "invalid_auth",
+            KickError::AccountInactive => "account_inactive",
+            KickError::UserIsBot => "user_is_bot",
+            KickError::UserIsRestricted => "user_is_restricted",
+            KickError::InvalidArgName => "invalid_arg_name",
+            KickError::InvalidArrayArg => "invalid_array_arg",
+            KickError::InvalidCharset => "invalid_charset",
+            KickError::InvalidFormData => "invalid_form_data",
+            KickError::InvalidPostType => "invalid_post_type",
+            KickError::MissingPostType => "missing_post_type",
+            KickError::TeamAddedToOrg => "team_added_to_org",
+            KickError::RequestTimeout => "request_timeout",
+            KickError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            KickError::Unknown(code) => return ::Error::from_code("channels.kick", &code),
+            KickError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.kick", code)
+    }
+}
+
 impl<E: Error> fmt::Display for KickError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1221,6 +1586,7 @@ impl<E: Error> Error for KickError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.leave
 
+#[deprecated(note = "channels.leave was retired by Slack: use conversations::leave")]
 pub fn leave<R>(client: &R,
                 token: &str,
                 request: &LeaveRequest)
@@ -1228,17 +1594,21 @@ pub fn leave<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.leave");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "channels.leave", &params[..])
         .map_err(|err| LeaveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<LeaveResponse>(&result)
-                            .map_err(|e| LeaveError::MalformedResponse(e))
+                      parse_leave_response(&result).map_err(|err| err.into_error(LeaveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_leave_response(body: &[u8]) -> Result<LeaveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<LeaveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1247,11 +1617,22 @@ pub struct LeaveRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> LeaveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        LeaveRequest {
+            channel: channel,
+            ..LeaveRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct LeaveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1334,6 +1715,33 @@ impl<'a, E: Error> From<&'a str> for LeaveError<E> {
     }
 }
 
+impl<E: Error> From<LeaveError<E>> for ::Error<E> {
+    fn from(err: LeaveError<E>) -> Self {
+        let code = match err {
+            LeaveError::ChannelNotFound => "channel_not_found",
+            LeaveError::IsArchived => "is_archived",
+            LeaveError::CantLeaveGeneral => "cant_leave_general",
+            LeaveError::NotAuthed => "not_authed",
+            LeaveError::InvalidAuth => "invalid_auth",
+            LeaveError::AccountInactive => "account_inactive",
+            LeaveError::UserIsBot => "user_is_bot",
+            LeaveError::UserIsRestricted => "user_is_restricted",
+            LeaveError::InvalidArgName => "invalid_arg_name",
+            LeaveError::InvalidArrayArg => "invalid_array_arg",
+            LeaveError::InvalidCharset => "invalid_charset",
+            LeaveError::InvalidFormData => "invalid_form_data",
+            LeaveError::InvalidPostType => "invalid_post_type",
+            LeaveError::MissingPostType => "missing_post_type",
+            LeaveError::TeamAddedToOrg => "team_added_to_org",
+            LeaveError::RequestTimeout => "request_timeout",
+            LeaveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            LeaveError::Unknown(code) => return ::Error::from_code("channels.leave", &code),
+            LeaveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.leave", code)
+    }
+}
+
 impl<E: Error> fmt::Display for LeaveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1378,6 +1786,7 @@ impl<E: Error> Error for LeaveError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.list
 
+#[deprecated(note = "channels.list was retired by Slack: use conversations::list")]
 pub fn list<R>(client: &R,
                token: &str,
                request: &ListRequest)
@@ -1385,27 +1794,30 @@ pub fn list<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request
-                          .exclude_archived
-                          .map(|exclude_archived| {
-                                   ("exclude_archived", if exclude_archived { "1" } else { "0" })
-                               }),
-                      request
-                          .exclude_members
-                          .map(|exclude_members| {
-                                   ("exclude_members", if exclude_members { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.list");
-    client
-        .send(&url, &params[..])
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    if let Some(exclude_archived) = request.exclude_archived {
+        params.push(("exclude_archived", if exclude_archived { "1" } else { "0" }));
+    }
+    if let Some(exclude_members) = request.exclude_members {
+        params.push(("exclude_members", if exclude_members { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "channels.list", &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<ListResponse>(body, &[("channels", ::item_error::<::Channel>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1416,12 +1828,32 @@ pub struct ListRequest {
     pub exclude_members: Option<bool>,
 }
 
+impl ListRequest {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    pub fn exclude_members(mut self, exclude_members: bool) -> Self {
+        self.exclude_members = Some(exclude_members);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     pub channels: Option<Vec<::Channel>>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1489,6 +1921,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("channels.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1528,6 +1982,7 @@ impl<E: Error> Error for ListError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.mark
 
+#[deprecated(note = "channels.mark was retired by Slack: use conversations::mark")]
 pub fn mark<R>(client: &R,
                token: &str,
                request: &MarkRequest)
@@ -1535,19 +1990,21 @@ pub fn mark<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("ts", request.ts))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.mark");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("ts", request.ts)];
+    ::legacy::send_bytes(client, "channels.mark", &params[..])
         .map_err(|err| MarkError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<MarkResponse>(&result)
-                            .map_err(|e| MarkError::MalformedResponse(e))
+                      parse_mark_response(&result).map_err(|err| err.into_error(MarkError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_mark_response(body: &[u8]) -> Result<MarkResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<MarkResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1558,11 +2015,23 @@ pub struct MarkRequest<'a> {
     pub ts: &'a str,
 }
 
+impl<'a> MarkRequest<'a> {
+    pub fn new(channel: &'a str, ts: &'a str) -> Self {
+        MarkRequest {
+            channel: channel,
+            ts: ts,
+            ..MarkRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MarkResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1639,6 +2108,31 @@ impl<'a, E: Error> From<&'a str> for MarkError<E> {
     }
 }
 
+impl<E: Error> From<MarkError<E>> for ::Error<E> {
+    fn from(err: MarkError<E>) -> Self {
+        let code = match err {
+            MarkError::ChannelNotFound => "channel_not_found",
+            MarkError::InvalidTimestamp => "invalid_timestamp",
+            MarkError::NotInChannel => "not_in_channel",
+            MarkError::NotAuthed => "not_authed",
+            MarkError::InvalidAuth => "invalid_auth",
+            MarkError::AccountInactive => "account_inactive",
+            MarkError::InvalidArgName => "invalid_arg_name",
+            MarkError::InvalidArrayArg => "invalid_array_arg",
+            MarkError::InvalidCharset => "invalid_charset",
+            MarkError::InvalidFormData => "invalid_form_data",
+            MarkError::InvalidPostType => "invalid_post_type",
+            MarkError::MissingPostType => "missing_post_type",
+            MarkError::TeamAddedToOrg => "team_added_to_org",
+            MarkError::RequestTimeout => "request_timeout",
+            MarkError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            MarkError::Unknown(code) => return ::Error::from_code("channels.mark", &code),
+            MarkError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.mark", code)
+    }
+}
+
 impl<E: Error> fmt::Display for MarkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1681,6 +2175,7 @@ impl<E: Error> Error for MarkError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.rename
 
+#[deprecated(note = "channels.rename was retired by Slack: use conversations::rename")]
 pub fn rename<R>(client: &R,
                  token: &str,
                  request: &RenameRequest)
@@ -1688,22 +2183,27 @@ pub fn rename<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("name", request.name)),
-                      request
-                          .validate
-                          .map(|validate| ("validate", if validate { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.rename");
-    client
-        .send(&url, &params[..])
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    params.push(("name", request.name));
+    if let Some(validate) = request.validate {
+        params.push(("validate", if validate { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "channels.rename", &params[..])
         .map_err(|err| RenameError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RenameResponse>(&result)
-                            .map_err(|e| RenameError::MalformedResponse(e))
+                      parse_rename_response(&result).map_err(|err| err.into_error(RenameError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_rename_response(body: &[u8]) -> Result<RenameResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RenameResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1716,12 +2216,29 @@ pub struct RenameRequest<'a> {
     pub validate: Option<bool>,
 }
 
+impl<'a> RenameRequest<'a> {
+    pub fn new(channel: &'a str, name: &'a str) -> Self {
+        RenameRequest {
+            channel: channel,
+            name: name,
+            ..RenameRequest::default()
+        }
+    }
+
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RenameResponse {
     pub channel: Option<RenameResponseChannel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1830,6 +2347,39 @@ impl<'a, E: Error> From<&'a str> for RenameError<E> {
     }
 }
 
+impl<E: Error> From<RenameError<E>> for ::Error<E> {
+    fn from(err: RenameError<E>) -> Self {
+        let code = match err {
+            RenameError::ChannelNotFound => "channel_not_found",
+            RenameError::NotInChannel => "not_in_channel",
+            RenameError::NotAuthorized => "not_authorized",
+            RenameError::InvalidName => "invalid_name",
+            RenameError::NameTaken => "name_taken",
+            RenameError::InvalidNameRequired => "invalid_name_required",
+            RenameError::InvalidNamePunctuation => "invalid_name_punctuation",
+            RenameError::InvalidNameMaxlength => "invalid_name_maxlength",
+            RenameError::InvalidNameSpecials => "invalid_name_specials",
+            RenameError::NotAuthed => "not_authed",
+            RenameError::InvalidAuth => "invalid_auth",
+            RenameError::AccountInactive => "account_inactive",
+            RenameError::UserIsBot => "user_is_bot",
+            RenameError::UserIsRestricted => "user_is_restricted",
+            RenameError::InvalidArgName => "invalid_arg_name",
+            RenameError::InvalidArrayArg => "invalid_array_arg",
+            RenameError::InvalidCharset => "invalid_charset",
+            RenameError::InvalidFormData => "invalid_form_data",
+            RenameError::InvalidPostType => "invalid_post_type",
+            RenameError::MissingPostType => "missing_post_type",
+            RenameError::TeamAddedToOrg => "team_added_to_org",
+            RenameError::RequestTimeout => "request_timeout",
+            RenameError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RenameError::Unknown(code) => return ::Error::from_code("channels.rename", &code),
+            RenameError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.rename", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RenameError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1880,6 +2430,7 @@ impl<E: Error> Error for RenameError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.replies
 
+#[deprecated(note = "channels.replies was retired by Slack: use conversations::replies")]
 pub fn replies<R>(client: &R,
                   token: &str,
                   request: &RepliesRequest)
@@ -1887,19 +2438,23 @@ pub fn replies<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("thread_ts", request.thread_ts))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.replies");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("thread_ts", request.thread_ts)];
+    ::legacy::send_bytes(client, "channels.replies", &params[..])
         .map_err(|err| RepliesError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RepliesResponse>(&result)
-                            .map_err(|e| RepliesError::MalformedResponse(e))
+                      parse_replies_response(&result).map_err(|err| err.into_error(RepliesError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_replies_response(body: &[u8]) -> Result<RepliesResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<RepliesResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1910,13 +2465,27 @@ pub struct RepliesRequest<'a> {
     pub thread_ts: &'a str,
 }
 
+impl<'a> RepliesRequest<'a> {
+    pub fn new(channel: &'a str, thread_ts: &'a str) -> Self {
+        RepliesRequest {
+            channel: channel,
+            thread_ts: thread_ts,
+            ..RepliesRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RepliesResponse {
     error: Option<String>,
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub thread_info: Option<::ThreadInfo>,
+    pub warning: Option<String>,
 }
 
 
@@ -1990,6 +2559,30 @@ impl<'a, E: Error> From<&'a str> for RepliesError<E> {
     }
 }
 
+impl<E: Error> From<RepliesError<E>> for ::Error<E> {
+    fn from(err: RepliesError<E>) -> Self {
+        let code = match err {
+            RepliesError::ChannelNotFound => "channel_not_found",
+            RepliesError::ThreadNotFound => "thread_not_found",
+            RepliesError::NotAuthed => "not_authed",
+            RepliesError::InvalidAuth => "invalid_auth",
+            RepliesError::AccountInactive => "account_inactive",
+            RepliesError::InvalidArgName => "invalid_arg_name",
+            RepliesError::InvalidArrayArg => "invalid_array_arg",
+            RepliesError::InvalidCharset => "invalid_charset",
+            RepliesError::InvalidFormData => "invalid_form_data",
+            RepliesError::InvalidPostType => "invalid_post_type",
+            RepliesError::MissingPostType => "missing_post_type",
+            RepliesError::TeamAddedToOrg => "team_added_to_org",
+            RepliesError::RequestTimeout => "request_timeout",
+            RepliesError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RepliesError::Unknown(code) => return ::Error::from_code("channels.replies", &code),
+            RepliesError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.replies", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RepliesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -2031,6 +2624,7 @@ impl<E: Error> Error for RepliesError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.setPurpose
 
+#[deprecated(note = "channels.setPurpose was retired by Slack: use conversations::set_purpose")]
 pub fn set_purpose<R>(client: &R,
                       token: &str,
                       request: &SetPurposeRequest)
@@ -2038,19 +2632,21 @@ pub fn set_purpose<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("purpose", request.purpose))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.setPurpose");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("purpose", request.purpose)];
+    ::legacy::send_bytes(client, "channels.setPurpose", &params[..])
         .map_err(|err| SetPurposeError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SetPurposeResponse>(&result)
-                            .map_err(|e| SetPurposeError::MalformedResponse(e))
+                      parse_set_purpose_response(&result).map_err(|err| err.into_error(SetPurposeError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_set_purpose_response(body: &[u8]) -> Result<SetPurposeResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetPurposeResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -2061,12 +2657,24 @@ pub struct SetPurposeRequest<'a> {
     pub purpose: &'a str,
 }
 
+impl<'a> SetPurposeRequest<'a> {
+    pub fn new(channel: &'a str, purpose: &'a str) -> Self {
+        SetPurposeRequest {
+            channel: channel,
+            purpose: purpose,
+            ..SetPurposeRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SetPurposeResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub purpose: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -2149,6 +2757,33 @@ impl<'a, E: Error> From<&'a str> for SetPurposeError<E> {
     }
 }
 
+impl<E: Error> From<SetPurposeError<E>> for ::Error<E> {
+    fn from(err: SetPurposeError<E>) -> Self {
+        let code = match err {
+            SetPurposeError::ChannelNotFound => "channel_not_found",
+            SetPurposeError::NotInChannel => "not_in_channel",
+            SetPurposeError::IsArchived => "is_archived",
+            SetPurposeError::TooLong => "too_long",
+            SetPurposeError::UserIsRestricted => "user_is_restricted",
+            SetPurposeError::NotAuthed => "not_authed",
+            SetPurposeError::InvalidAuth => "invalid_auth",
+            SetPurposeError::AccountInactive => "account_inactive",
+            SetPurposeError::InvalidArgName => "invalid_arg_name",
+            SetPurposeError::InvalidArrayArg => "invalid_array_arg",
+            SetPurposeError::InvalidCharset => "invalid_charset",
+            SetPurposeError::InvalidFormData => "invalid_form_data",
+            SetPurposeError::InvalidPostType => "invalid_post_type",
+            SetPurposeError::MissingPostType => "missing_post_type",
+            SetPurposeError::TeamAddedToOrg => "team_added_to_org",
+            SetPurposeError::RequestTimeout => "request_timeout",
+            SetPurposeError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetPurposeError::Unknown(code) => return ::Error::from_code("channels.setPurpose", &code),
+            SetPurposeError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.setPurpose", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SetPurposeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -2193,6 +2828,7 @@ impl<E: Error> Error for SetPurposeError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.setTopic
 
+#[deprecated(note = "channels.setTopic was retired by Slack: use conversations::set_topic")]
 pub fn set_topic<R>(client: &R,
                     token: &str,
                     request: &SetTopicRequest)
@@ -2200,19 +2836,21 @@ pub fn set_topic<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("topic", request.topic))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.setTopic");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("topic", request.topic)];
+    ::legacy::send_bytes(client, "channels.setTopic", &params[..])
         .map_err(|err| SetTopicError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SetTopicResponse>(&result)
-                            .map_err(|e| SetTopicError::MalformedResponse(e))
+                      parse_set_topic_response(&result).map_err(|err| err.into_error(SetTopicError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_set_topic_response(body: &[u8]) -> Result<SetTopicResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetTopicResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -2223,12 +2861,24 @@ pub struct SetTopicRequest<'a> {
     pub topic: &'a str,
 }
 
+impl<'a> SetTopicRequest<'a> {
+    pub fn new(channel: &'a str, topic: &'a str) -> Self {
+        SetTopicRequest {
+            channel: channel,
+            topic: topic,
+            ..SetTopicRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SetTopicResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub topic: Option<String>,
+    pub warning: Option<String>,
 }
 
 
@@ -2311,6 +2961,33 @@ impl<'a, E: Error> From<&'a str> for SetTopicError<E> {
     }
 }
 
+impl<E: Error> From<SetTopicError<E>> for ::Error<E> {
+    fn from(err: SetTopicError<E>) -> Self {
+        let code = match err {
+            SetTopicError::ChannelNotFound => "channel_not_found",
+            SetTopicError::NotInChannel => "not_in_channel",
+            SetTopicError::IsArchived => "is_archived",
+            SetTopicError::TooLong => "too_long",
+            SetTopicError::UserIsRestricted => "user_is_restricted",
+            SetTopicError::NotAuthed => "not_authed",
+            SetTopicError::InvalidAuth => "invalid_auth",
+            SetTopicError::AccountInactive => "account_inactive",
+            SetTopicError::InvalidArgName => "invalid_arg_name",
+            SetTopicError::InvalidArrayArg => "invalid_array_arg",
+            SetTopicError::InvalidCharset => "invalid_charset",
+            SetTopicError::InvalidFormData => "invalid_form_data",
+            SetTopicError::InvalidPostType => "invalid_post_type",
+            SetTopicError::MissingPostType => "missing_post_type",
+            SetTopicError::TeamAddedToOrg => "team_added_to_org",
+            SetTopicError::RequestTimeout => "request_timeout",
+            SetTopicError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetTopicError::Unknown(code) => return ::Error::from_code("channels.setTopic", &code),
+            SetTopicError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.setTopic", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SetTopicError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -2355,6 +3032,7 @@ impl<E: Error> Error for SetTopicError<E> {
 ///
 /// Wraps https://api.slack.com/methods/channels.unarchive
 
+#[deprecated(note = "channels.unarchive was retired by Slack: use conversations::unarchive")]
 pub fn unarchive<R>(client: &R,
                     token: &str,
                     request: &UnarchiveRequest)
@@ -2362,17 +3040,21 @@ pub fn unarchive<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.unarchive");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "channels.unarchive", &params[..])
         .map_err(|err| UnarchiveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<UnarchiveResponse>(&result)
-                            .map_err(|e| UnarchiveError::MalformedResponse(e))
+                      parse_unarchive_response(&result).map_err(|err| err.into_error(UnarchiveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_unarchive_response(body: &[u8]) -> Result<UnarchiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UnarchiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -2381,11 +3063,22 @@ pub struct UnarchiveRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> UnarchiveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        UnarchiveRequest {
+            channel: channel,
+            ..UnarchiveRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UnarchiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -2465,6 +3158,32 @@ impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
     }
 }
 
+impl<E: Error> From<UnarchiveError<E>> for ::Error<E> {
+    fn from(err: UnarchiveError<E>) -> Self {
+        let code = match err {
+            UnarchiveError::ChannelNotFound => "channel_not_found",
+            UnarchiveError::NotArchived => "not_archived",
+            UnarchiveError::NotAuthed => "not_authed",
+            UnarchiveError::InvalidAuth => "invalid_auth",
+            UnarchiveError::AccountInactive => "account_inactive",
+            UnarchiveError::UserIsBot => "user_is_bot",
+            UnarchiveError::UserIsRestricted => "user_is_restricted",
+            UnarchiveError::InvalidArgName => "invalid_arg_name",
+            UnarchiveError::InvalidArrayArg => "invalid_array_arg",
+            UnarchiveError::InvalidCharset => "invalid_charset",
+            UnarchiveError::InvalidFormData => "invalid_form_data",
+            UnarchiveError::InvalidPostType => "invalid_post_type",
+            UnarchiveError::MissingPostType => "missing_post_type",
+            UnarchiveError::TeamAddedToOrg => "team_added_to_org",
+            UnarchiveError::RequestTimeout => "request_timeout",
+            UnarchiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UnarchiveError::Unknown(code) => return ::Error::from_code("channels.unarchive", &code),
+            UnarchiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("channels.unarchive", code)
+    }
+}
+
 impl<E: Error> fmt::Display for UnarchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
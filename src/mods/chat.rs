@@ -22,22 +22,30 @@ pub fn delete<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("ts", request.ts)),
-                      Some(("channel", request.channel)),
-                      request
-                          .as_user
-                          .map(|as_user| ("as_user", if as_user { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("ts".to_owned(), request.ts.into());
+    body.insert("channel".to_owned(), request.channel.into());
+    if let Some(as_user) = request.as_user {
+        body.insert("as_user".to_owned(), as_user.into());
+    }
     let url = ::get_slack_url_for_method("chat.delete");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| DeleteError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<DeleteResponse>(&result)
-                            .map_err(|e| DeleteError::MalformedResponse(e))
+                      parse_delete_response(result.body.as_bytes()).map_err(|err| err.into_error(DeleteError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_delete_response(body: &[u8]) -> Result<DeleteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<DeleteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -50,13 +58,30 @@ pub struct DeleteRequest<'a> {
     pub as_user: Option<bool>,
 }
 
+impl<'a> DeleteRequest<'a> {
+    pub fn new(ts: &'a str, channel: &'a str) -> Self {
+        DeleteRequest {
+            ts: ts,
+            channel: channel,
+            ..DeleteRequest::default()
+        }
+    }
+
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct DeleteResponse {
     pub channel: Option<String>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub ts: Option<String>,
+    pub warning: Option<String>,
 }
 
 
@@ -136,6 +161,32 @@ impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     }
 }
 
+impl<E: Error> From<DeleteError<E>> for ::Error<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        let code = match err {
+            DeleteError::MessageNotFound => "message_not_found",
+            DeleteError::ChannelNotFound => "channel_not_found",
+            DeleteError::CantDeleteMessage => "cant_delete_message",
+            DeleteError::ComplianceExportsPreventDeletion => "compliance_exports_prevent_deletion",
+            DeleteError::NotAuthed => "not_authed",
+            DeleteError::InvalidAuth => "invalid_auth",
+            DeleteError::AccountInactive => "account_inactive",
+            DeleteError::InvalidArgName => "invalid_arg_name",
+            DeleteError::InvalidArrayArg => "invalid_array_arg",
+            DeleteError::InvalidCharset => "invalid_charset",
+            DeleteError::InvalidFormData => "invalid_form_data",
+            DeleteError::InvalidPostType => "invalid_post_type",
+            DeleteError::MissingPostType => "missing_post_type",
+            DeleteError::TeamAddedToOrg => "team_added_to_org",
+            DeleteError::RequestTimeout => "request_timeout",
+            DeleteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            DeleteError::Unknown(code) => return ::Error::from_code("chat.delete", &code),
+            DeleteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("chat.delete", code)
+    }
+}
+
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -175,6 +226,220 @@ impl<E: Error> Error for DeleteError<E> {
     }
 }
 
+/// Deletes a pending scheduled message from the queue.
+///
+/// Wraps https://api.slack.com/methods/chat.deleteScheduledMessage
+
+pub fn delete_scheduled_message<R>(client: &R,
+                                   token: &str,
+                                   request: &DeleteScheduledMessageRequest)
+                                   -> Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("scheduled_message_id".to_owned(), request.scheduled_message_id.into());
+    if let Some(as_user) = request.as_user {
+        body.insert("as_user".to_owned(), as_user.into());
+    }
+    let url = ::get_slack_url_for_method("chat.deleteScheduledMessage");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| DeleteScheduledMessageError::Client(err))
+        .and_then(|result| {
+                      parse_delete_scheduled_message_response(result.body.as_bytes()).map_err(|err| err.into_error(DeleteScheduledMessageError::MalformedResponse))
+                  })
+}
+
+fn parse_delete_scheduled_message_response(body: &[u8]) -> Result<DeleteScheduledMessageResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<DeleteScheduledMessageResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct DeleteScheduledMessageRequest<'a> {
+    /// The channel the scheduled_message is posting to
+    pub channel: &'a str,
+    /// scheduled_message_id returned from call to chat.scheduleMessage
+    pub scheduled_message_id: &'a str,
+    /// Pass true to delete the message as the authed user with chat:write:user scope. Bot users in this context are considered authed users. If unused or false, the message will be deleted with chat:write:bot scope.
+    pub as_user: Option<bool>,
+}
+
+impl<'a> DeleteScheduledMessageRequest<'a> {
+    pub fn new(channel: &'a str, scheduled_message_id: &'a str) -> Self {
+        DeleteScheduledMessageRequest {
+            channel: channel,
+            scheduled_message_id: scheduled_message_id,
+            ..DeleteScheduledMessageRequest::default()
+        }
+    }
+
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeleteScheduledMessageResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<E>>> for DeleteScheduledMessageResponse {
+    fn into(self) -> Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum DeleteScheduledMessageError<E: Error> {
+    /// The scheduled_message_id passed is either invalid, already posted or already deleted.
+    InvalidScheduledMessageId,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Too many concurrent requests to delete the scheduled message.
+    RestrictedTooMany,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for DeleteScheduledMessageError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_scheduled_message_id" => DeleteScheduledMessageError::InvalidScheduledMessageId,
+            "channel_not_found" => DeleteScheduledMessageError::ChannelNotFound,
+            "restricted_too_many" => DeleteScheduledMessageError::RestrictedTooMany,
+            "missing_scope" => DeleteScheduledMessageError::MissingScope,
+            "not_authed" => DeleteScheduledMessageError::NotAuthed,
+            "invalid_auth" => DeleteScheduledMessageError::InvalidAuth,
+            "account_inactive" => DeleteScheduledMessageError::AccountInactive,
+            "invalid_arg_name" => DeleteScheduledMessageError::InvalidArgName,
+            "invalid_array_arg" => DeleteScheduledMessageError::InvalidArrayArg,
+            "invalid_charset" => DeleteScheduledMessageError::InvalidCharset,
+            "invalid_form_data" => DeleteScheduledMessageError::InvalidFormData,
+            "invalid_post_type" => DeleteScheduledMessageError::InvalidPostType,
+            "missing_post_type" => DeleteScheduledMessageError::MissingPostType,
+            "team_added_to_org" => DeleteScheduledMessageError::TeamAddedToOrg,
+            "request_timeout" => DeleteScheduledMessageError::RequestTimeout,
+            _ => DeleteScheduledMessageError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<DeleteScheduledMessageError<E>> for ::Error<E> {
+    fn from(err: DeleteScheduledMessageError<E>) -> Self {
+        let code = match err {
+            DeleteScheduledMessageError::InvalidScheduledMessageId => "invalid_scheduled_message_id",
+            DeleteScheduledMessageError::ChannelNotFound => "channel_not_found",
+            DeleteScheduledMessageError::RestrictedTooMany => "restricted_too_many",
+            DeleteScheduledMessageError::MissingScope => "missing_scope",
+            DeleteScheduledMessageError::NotAuthed => "not_authed",
+            DeleteScheduledMessageError::InvalidAuth => "invalid_auth",
+            DeleteScheduledMessageError::AccountInactive => "account_inactive",
+            DeleteScheduledMessageError::InvalidArgName => "invalid_arg_name",
+            DeleteScheduledMessageError::InvalidArrayArg => "invalid_array_arg",
+            DeleteScheduledMessageError::InvalidCharset => "invalid_charset",
+            DeleteScheduledMessageError::InvalidFormData => "invalid_form_data",
+            DeleteScheduledMessageError::InvalidPostType => "invalid_post_type",
+            DeleteScheduledMessageError::MissingPostType => "missing_post_type",
+            DeleteScheduledMessageError::TeamAddedToOrg => "team_added_to_org",
+            DeleteScheduledMessageError::RequestTimeout => "request_timeout",
+            DeleteScheduledMessageError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            DeleteScheduledMessageError::Unknown(code) => return ::Error::from_code("chat.deleteScheduledMessage", &code),
+            DeleteScheduledMessageError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("chat.deleteScheduledMessage", code)
+    }
+}
+
+impl<E: Error> fmt::Display for DeleteScheduledMessageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for DeleteScheduledMessageError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &DeleteScheduledMessageError::InvalidScheduledMessageId => "invalid_scheduled_message_id: The scheduled_message_id passed is either invalid, already posted or already deleted.",
+            &DeleteScheduledMessageError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &DeleteScheduledMessageError::RestrictedTooMany => "restricted_too_many: Too many concurrent requests to delete the scheduled message.",
+            &DeleteScheduledMessageError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &DeleteScheduledMessageError::NotAuthed => "not_authed: No authentication token provided.",
+            &DeleteScheduledMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &DeleteScheduledMessageError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &DeleteScheduledMessageError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &DeleteScheduledMessageError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &DeleteScheduledMessageError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &DeleteScheduledMessageError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &DeleteScheduledMessageError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &DeleteScheduledMessageError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &DeleteScheduledMessageError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &DeleteScheduledMessageError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &DeleteScheduledMessageError::MalformedResponse(ref e) => e.description(),
+            &DeleteScheduledMessageError::Unknown(ref s) => s,
+            &DeleteScheduledMessageError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &DeleteScheduledMessageError::MalformedResponse(ref e) => Some(e),
+            &DeleteScheduledMessageError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 /// Share a me message into a channel.
 ///
 /// Wraps https://api.slack.com/methods/chat.meMessage
@@ -186,19 +451,27 @@ pub fn me_message<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("text", request.text))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("text".to_owned(), request.text.into());
     let url = ::get_slack_url_for_method("chat.meMessage");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| MeMessageError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<MeMessageResponse>(&result)
-                            .map_err(|e| MeMessageError::MalformedResponse(e))
+                      parse_me_message_response(result.body.as_bytes()).map_err(|err| err.into_error(MeMessageError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_me_message_response(body: &[u8]) -> Result<MeMessageResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<MeMessageResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -209,13 +482,25 @@ pub struct MeMessageRequest<'a> {
     pub text: &'a str,
 }
 
+impl<'a> MeMessageRequest<'a> {
+    pub fn new(channel: &'a str, text: &'a str) -> Self {
+        MeMessageRequest {
+            channel: channel,
+            text: text,
+            ..MeMessageRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MeMessageResponse {
     pub channel: Option<String>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub ts: Option<String>,
+    pub warning: Option<String>,
 }
 
 
@@ -301,6 +586,34 @@ impl<'a, E: Error> From<&'a str> for MeMessageError<E> {
     }
 }
 
+impl<E: Error> From<MeMessageError<E>> for ::Error<E> {
+    fn from(err: MeMessageError<E>) -> Self {
+        let code = match err {
+            MeMessageError::ChannelNotFound => "channel_not_found",
+            MeMessageError::NotInChannel => "not_in_channel",
+            MeMessageError::IsArchived => "is_archived",
+            MeMessageError::MsgTooLong => "msg_too_long",
+            MeMessageError::NoText => "no_text",
+            MeMessageError::RateLimited => "rate_limited",
+            MeMessageError::NotAuthed => "not_authed",
+            MeMessageError::InvalidAuth => "invalid_auth",
+            MeMessageError::AccountInactive => "account_inactive",
+            MeMessageError::InvalidArgName => "invalid_arg_name",
+            MeMessageError::InvalidArrayArg => "invalid_array_arg",
+            MeMessageError::InvalidCharset => "invalid_charset",
+            MeMessageError::InvalidFormData => "invalid_form_data",
+            MeMessageError::InvalidPostType => "invalid_post_type",
+            MeMessageError::MissingPostType => "missing_post_type",
+            MeMessageError::TeamAddedToOrg => "team_added_to_org",
+            MeMessageError::RequestTimeout => "request_timeout",
+            MeMessageError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            MeMessageError::Unknown(code) => return ::Error::from_code("chat.meMessage", &code),
+            MeMessageError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("chat.meMessage", code)
+    }
+}
+
 impl<E: Error> fmt::Display for MeMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -342,6 +655,330 @@ impl<E: Error> Error for MeMessageError<E> {
     }
 }
 
+/// Sends an ephemeral message to a user in a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.postEphemeral
+
+pub fn post_ephemeral<R>(client: &R,
+                         token: &str,
+                         request: &PostEphemeralRequest)
+                         -> Result<PostEphemeralResponse, PostEphemeralError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("text".to_owned(), request.text.into());
+    body.insert("user".to_owned(), request.user.into());
+    if let Some(as_user) = request.as_user {
+        body.insert("as_user".to_owned(), as_user.into());
+    }
+    if let Some(attachments) = request.attachments {
+        body.insert("attachments".to_owned(), ::json_param(attachments));
+    }
+    if let Some(blocks) = request.blocks {
+        body.insert("blocks".to_owned(), ::json_param(blocks));
+    }
+    if let Some(icon_emoji) = request.icon_emoji {
+        body.insert("icon_emoji".to_owned(), icon_emoji.into());
+    }
+    if let Some(icon_url) = request.icon_url {
+        body.insert("icon_url".to_owned(), icon_url.into());
+    }
+    if let Some(link_names) = request.link_names {
+        body.insert("link_names".to_owned(), link_names.into());
+    }
+    if let Some(parse) = request.parse {
+        body.insert("parse".to_owned(), parse.as_str().into());
+    }
+    if let Some(thread_ts) = request.thread_ts {
+        body.insert("thread_ts".to_owned(), thread_ts.into());
+    }
+    if let Some(username) = request.username {
+        body.insert("username".to_owned(), username.into());
+    }
+    let url = ::get_slack_url_for_method("chat.postEphemeral");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| PostEphemeralError::Client(err))
+        .and_then(|result| {
+                      parse_post_ephemeral_response(result.body.as_bytes()).map_err(|err| err.into_error(PostEphemeralError::MalformedResponse))
+                  })
+}
+
+fn parse_post_ephemeral_response(body: &[u8]) -> Result<PostEphemeralResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<PostEphemeralResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct PostEphemeralRequest<'a> {
+    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.
+    pub channel: &'a str,
+    /// How this field works and whether it is required depends on other fields you use in your API call.
+    pub text: &'a str,
+    /// id of the user who will receive the ephemeral message. The user should be in the channel specified by the channel argument.
+    pub user: &'a str,
+    /// Pass true to post the message as the authed user. Defaults to true if the chat:write:bot scope is not included. Otherwise, defaults to false.
+    pub as_user: Option<bool>,
+    /// A JSON-based array of structured attachments, presented as a URL-encoded string.
+    pub attachments: Option<&'a str>,
+    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
+    pub blocks: Option<&'a str>,
+    /// Emoji to use as the icon for this message. Overrides icon_url.
+    pub icon_emoji: Option<&'a str>,
+    /// URL to an image to use as the icon for this message.
+    pub icon_url: Option<&'a str>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// Change how messages are treated. Defaults to none.
+    pub parse: Option<::message::Parse>,
+    /// Provide another message's ts value to post this message in a thread. Avoid using a reply's ts value; use its parent's value instead. Ephemeral messages in threads are only shown if there is already an active thread.
+    pub thread_ts: Option<&'a str>,
+    /// Set your bot's user name.
+    pub username: Option<&'a str>,
+}
+
+impl<'a> PostEphemeralRequest<'a> {
+    pub fn new(channel: &'a str, text: &'a str, user: &'a str) -> Self {
+        PostEphemeralRequest {
+            channel: channel,
+            text: text,
+            user: user,
+            ..PostEphemeralRequest::default()
+        }
+    }
+
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    pub fn attachments(mut self, attachments: &'a str) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    pub fn blocks(mut self, blocks: &'a str) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    pub fn icon_emoji(mut self, icon_emoji: &'a str) -> Self {
+        self.icon_emoji = Some(icon_emoji);
+        self
+    }
+
+    pub fn icon_url(mut self, icon_url: &'a str) -> Self {
+        self.icon_url = Some(icon_url);
+        self
+    }
+
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    pub fn parse(mut self, parse: ::message::Parse) -> Self {
+        self.parse = Some(parse);
+        self
+    }
+
+    pub fn thread_ts(mut self, thread_ts: &'a str) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.username = Some(username);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PostEphemeralResponse {
+    error: Option<String>,
+    pub message_ts: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<PostEphemeralResponse, PostEphemeralError<E>>> for PostEphemeralResponse {
+    fn into(self) -> Result<PostEphemeralResponse, PostEphemeralError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PostEphemeralError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has been archived.
+    IsArchived,
+    /// Message text is too long.
+    MsgTooLong,
+    /// No message text provided.
+    NoText,
+    /// A workspace preference prevents the authenticated user from posting.
+    RestrictedAction,
+    /// Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.
+    TooManyAttachments,
+    /// Intended recipient is not in the specified channel.
+    UserNotInChannel,
+    /// Cannot post user messages to a channel they are not in.
+    NotInChannel,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PostEphemeralError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => PostEphemeralError::ChannelNotFound,
+            "is_archived" => PostEphemeralError::IsArchived,
+            "msg_too_long" => PostEphemeralError::MsgTooLong,
+            "no_text" => PostEphemeralError::NoText,
+            "restricted_action" => PostEphemeralError::RestrictedAction,
+            "too_many_attachments" => PostEphemeralError::TooManyAttachments,
+            "user_not_in_channel" => PostEphemeralError::UserNotInChannel,
+            "not_in_channel" => PostEphemeralError::NotInChannel,
+            "missing_scope" => PostEphemeralError::MissingScope,
+            "not_authed" => PostEphemeralError::NotAuthed,
+            "invalid_auth" => PostEphemeralError::InvalidAuth,
+            "account_inactive" => PostEphemeralError::AccountInactive,
+            "invalid_arg_name" => PostEphemeralError::InvalidArgName,
+            "invalid_array_arg" => PostEphemeralError::InvalidArrayArg,
+            "invalid_charset" => PostEphemeralError::InvalidCharset,
+            "invalid_form_data" => PostEphemeralError::InvalidFormData,
+            "invalid_post_type" => PostEphemeralError::InvalidPostType,
+            "missing_post_type" => PostEphemeralError::MissingPostType,
+            "team_added_to_org" => PostEphemeralError::TeamAddedToOrg,
+            "request_timeout" => PostEphemeralError::RequestTimeout,
+            _ => PostEphemeralError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<PostEphemeralError<E>> for ::Error<E> {
+    fn from(err: PostEphemeralError<E>) -> Self {
+        let code = match err {
+            PostEphemeralError::ChannelNotFound => "channel_not_found",
+            PostEphemeralError::IsArchived => "is_archived",
+            PostEphemeralError::MsgTooLong => "msg_too_long",
+            PostEphemeralError::NoText => "no_text",
+            PostEphemeralError::RestrictedAction => "restricted_action",
+            PostEphemeralError::TooManyAttachments => "too_many_attachments",
+            PostEphemeralError::UserNotInChannel => "user_not_in_channel",
+            PostEphemeralError::NotInChannel => "not_in_channel",
+            PostEphemeralError::MissingScope => "missing_scope",
+            PostEphemeralError::NotAuthed => "not_authed",
+            PostEphemeralError::InvalidAuth => "invalid_auth",
+            PostEphemeralError::AccountInactive => "account_inactive",
+            PostEphemeralError::InvalidArgName => "invalid_arg_name",
+            PostEphemeralError::InvalidArrayArg => "invalid_array_arg",
+            PostEphemeralError::InvalidCharset => "invalid_charset",
+            PostEphemeralError::InvalidFormData => "invalid_form_data",
+            PostEphemeralError::InvalidPostType => "invalid_post_type",
+            PostEphemeralError::MissingPostType => "missing_post_type",
+            PostEphemeralError::TeamAddedToOrg => "team_added_to_org",
+            PostEphemeralError::RequestTimeout => "request_timeout",
+            PostEphemeralError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            PostEphemeralError::Unknown(code) => return ::Error::from_code("chat.postEphemeral", &code),
+            PostEphemeralError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("chat.postEphemeral", code)
+    }
+}
+
+impl<E: Error> fmt::Display for PostEphemeralError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for PostEphemeralError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &PostEphemeralError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &PostEphemeralError::IsArchived => "is_archived: Channel has been archived.",
+            &PostEphemeralError::MsgTooLong => "msg_too_long: Message text is too long.",
+            &PostEphemeralError::NoText => "no_text: No message text provided.",
+            &PostEphemeralError::RestrictedAction => "restricted_action: A workspace preference prevents the authenticated user from posting.",
+            &PostEphemeralError::TooManyAttachments => "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.",
+            &PostEphemeralError::UserNotInChannel => "user_not_in_channel: Intended recipient is not in the specified channel.",
+            &PostEphemeralError::NotInChannel => "not_in_channel: Cannot post user messages to a channel they are not in.",
+            &PostEphemeralError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &PostEphemeralError::NotAuthed => "not_authed: No authentication token provided.",
+            &PostEphemeralError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &PostEphemeralError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &PostEphemeralError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &PostEphemeralError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &PostEphemeralError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &PostEphemeralError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &PostEphemeralError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &PostEphemeralError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &PostEphemeralError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &PostEphemeralError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &PostEphemeralError::MalformedResponse(ref e) => e.description(),
+            &PostEphemeralError::Unknown(ref s) => s,
+            &PostEphemeralError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &PostEphemeralError::MalformedResponse(ref e) => Some(e),
+            &PostEphemeralError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 /// Sends a message to a channel.
 ///
 /// Wraps https://api.slack.com/methods/chat.postMessage
@@ -352,80 +989,201 @@ pub fn post_message<R>(client: &R,
                        -> Result<PostMessageResponse, PostMessageError<R::Error>>
     where R: SlackWebRequestSender
 {
-
-    let params =
-        vec![Some(("token", token)),
-             Some(("channel", request.channel)),
-             Some(("text", request.text)),
-             request.parse.map(|parse| ("parse", parse)),
-             request
-                 .link_names
-                 .map(|link_names| ("link_names", if link_names { "1" } else { "0" })),
-             request
-                 .attachments
-                 .map(|attachments| ("attachments", attachments)),
-             request
-                 .unfurl_links
-                 .map(|unfurl_links| ("unfurl_links", if unfurl_links { "1" } else { "0" })),
-             request
-                 .unfurl_media
-                 .map(|unfurl_media| ("unfurl_media", if unfurl_media { "1" } else { "0" })),
-             request.username.map(|username| ("username", username)),
-             request
-                 .as_user
-                 .map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
-             request.icon_url.map(|icon_url| ("icon_url", icon_url)),
-             request
-                 .icon_emoji
-                 .map(|icon_emoji| ("icon_emoji", icon_emoji)),
-             request
-                 .thread_ts
-                 .map(|thread_ts| ("thread_ts", thread_ts)),
-             request
-                 .reply_broadcast
-                 .map(|reply_broadcast| {
-                          ("reply_broadcast", if reply_broadcast { "1" } else { "0" })
-                      })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let typed_blocks = request.typed_blocks.map(::blocks::to_json);
+    let blocks = request.blocks.or(typed_blocks.as_ref().map(String::as_str));
+    let typed_attachments = request.typed_attachments.map(::blocks::attachments_to_json);
+    let attachments = request.attachments.or(typed_attachments.as_ref().map(String::as_str));
+    let text = ::blocks::text_or_fallback(request.text, blocks, request.text_from_blocks);
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("text".to_owned(), text.into());
+    if let Some(parse) = request.parse {
+        body.insert("parse".to_owned(), parse.as_str().into());
+    }
+    if let Some(link_names) = request.link_names {
+        body.insert("link_names".to_owned(), link_names.into());
+    }
+    if let Some(attachments) = attachments {
+        body.insert("attachments".to_owned(), ::json_param(attachments));
+    }
+    if let Some(blocks) = blocks {
+        body.insert("blocks".to_owned(), ::json_param(blocks));
+    }
+    if let Some(unfurl_links) = request.unfurl_links {
+        body.insert("unfurl_links".to_owned(), unfurl_links.into());
+    }
+    if let Some(unfurl_media) = request.unfurl_media {
+        body.insert("unfurl_media".to_owned(), unfurl_media.into());
+    }
+    if let Some(username) = request.username {
+        body.insert("username".to_owned(), username.into());
+    }
+    if let Some(as_user) = request.as_user {
+        body.insert("as_user".to_owned(), as_user.into());
+    }
+    if let Some(icon_url) = request.icon_url {
+        body.insert("icon_url".to_owned(), icon_url.into());
+    }
+    if let Some(icon_emoji) = request.icon_emoji {
+        body.insert("icon_emoji".to_owned(), icon_emoji.into());
+    }
+    if let Some(thread_ts) = request.thread_ts {
+        body.insert("thread_ts".to_owned(), thread_ts.into());
+    }
+    if let Some(reply_broadcast) = request.reply_broadcast {
+        body.insert("reply_broadcast".to_owned(), reply_broadcast.into());
+    }
+    if let Some(metadata) = request.metadata {
+        body.insert("metadata".to_owned(), ::json_param(metadata));
+    }
     let url = ::get_slack_url_for_method("chat.postMessage");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| PostMessageError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<PostMessageResponse>(&result)
-                            .map_err(|e| PostMessageError::MalformedResponse(e))
+                      parse_post_message_response(result.body.as_bytes()).map_err(|err| err.into_error(PostMessageError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
-pub struct PostMessageRequest<'a> {
-    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See below for more details.
-    pub channel: &'a str,
-    /// Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments instead.
-    pub text: &'a str,
-    /// Change how messages are treated. Defaults to none. See below.
-    pub parse: Option<&'a str>,
-    /// Find and link channel names and usernames.
-    pub link_names: Option<bool>,
-    /// Structured message attachments.
-    pub attachments: Option<&'a str>,
-    /// Pass true to enable unfurling of primarily text-based content.
-    pub unfurl_links: Option<bool>,
-    /// Pass false to disable unfurling of media content.
-    pub unfurl_media: Option<bool>,
-    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub username: Option<&'a str>,
-    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.
-    pub as_user: Option<bool>,
-    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub icon_url: Option<&'a str>,
-    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub icon_emoji: Option<&'a str>,
-    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
-    pub thread_ts: Option<&'a str>,
-    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
-    pub reply_broadcast: Option<bool>,
+fn parse_post_message_response(body: &[u8]) -> Result<PostMessageResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<PostMessageResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct PostMessageRequest<'a> {
+    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See below for more details.
+    pub channel: &'a str,
+    /// Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments instead.
+    pub text: &'a str,
+    /// Change how messages are treated. Defaults to none. See below.
+    pub parse: Option<::message::Parse>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// Structured message attachments.
+    pub attachments: Option<&'a str>,
+    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
+    pub blocks: Option<&'a str>,
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub unfurl_links: Option<bool>,
+    /// Pass false to disable unfurling of media content.
+    pub unfurl_media: Option<bool>,
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub username: Option<&'a str>,
+    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.
+    pub as_user: Option<bool>,
+    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub icon_url: Option<&'a str>,
+    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub icon_emoji: Option<&'a str>,
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub thread_ts: Option<&'a str>,
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
+    pub reply_broadcast: Option<bool>,
+    /// JSON object with event_type and event_payload fields, presented as a URL-encoded string. Metadata you post to Slack is accessible to any app or user who is a member of that workspace.
+    pub metadata: Option<&'a str>,
+    /// Not sent to Slack: whether to fill in an empty text from blocks, so that notifications show something. Defaults to true.
+    pub text_from_blocks: Option<bool>,
+    /// Not sent to Slack: blocks to send as `blocks`, unless it is set.
+    pub typed_blocks: Option<&'a [::blocks::Block]>,
+    /// Not sent to Slack: attachments to send as `attachments`, unless it is set.
+    pub typed_attachments: Option<&'a [::blocks::Attachment]>,
+}
+
+impl<'a> PostMessageRequest<'a> {
+    pub fn new(channel: &'a str, text: &'a str) -> Self {
+        PostMessageRequest {
+            channel: channel,
+            text: text,
+            ..PostMessageRequest::default()
+        }
+    }
+
+    pub fn parse(mut self, parse: ::message::Parse) -> Self {
+        self.parse = Some(parse);
+        self
+    }
+
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    pub fn attachments(mut self, attachments: &'a str) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    pub fn blocks(mut self, blocks: &'a str) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+        self.unfurl_links = Some(unfurl_links);
+        self
+    }
+
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+        self.unfurl_media = Some(unfurl_media);
+        self
+    }
+
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.username = Some(username);
+        self
+    }
+
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    pub fn icon_url(mut self, icon_url: &'a str) -> Self {
+        self.icon_url = Some(icon_url);
+        self
+    }
+
+    pub fn icon_emoji(mut self, icon_emoji: &'a str) -> Self {
+        self.icon_emoji = Some(icon_emoji);
+        self
+    }
+
+    pub fn thread_ts(mut self, thread_ts: &'a str) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+
+    pub fn reply_broadcast(mut self, reply_broadcast: bool) -> Self {
+        self.reply_broadcast = Some(reply_broadcast);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: &'a str) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    pub fn text_from_blocks(mut self, text_from_blocks: bool) -> Self {
+        self.text_from_blocks = Some(text_from_blocks);
+        self
+    }
+
+    pub fn typed_blocks(mut self, typed_blocks: &'a [::blocks::Block]) -> Self {
+        self.typed_blocks = Some(typed_blocks);
+        self
+    }
+
+    pub fn typed_attachments(mut self, typed_attachments: &'a [::blocks::Attachment]) -> Self {
+        self.typed_attachments = Some(typed_attachments);
+        self
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -435,7 +1193,9 @@ pub struct PostMessageResponse {
     pub message: Option<::Message>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub ts: Option<String>,
+    pub warning: Option<String>,
 }
 
 
@@ -524,6 +1284,35 @@ impl<'a, E: Error> From<&'a str> for PostMessageError<E> {
     }
 }
 
+impl<E: Error> From<PostMessageError<E>> for ::Error<E> {
+    fn from(err: PostMessageError<E>) -> Self {
+        let code = match err {
+            PostMessageError::ChannelNotFound => "channel_not_found",
+            PostMessageError::NotInChannel => "not_in_channel",
+            PostMessageError::IsArchived => "is_archived",
+            PostMessageError::MsgTooLong => "msg_too_long",
+            PostMessageError::NoText => "no_text",
+            PostMessageError::TooManyAttachments => "too_many_attachments",
+            PostMessageError::RateLimited => "rate_limited",
+            PostMessageError::NotAuthed => "not_authed",
+            PostMessageError::InvalidAuth => "invalid_auth",
+            PostMessageError::AccountInactive => "account_inactive",
+            PostMessageError::InvalidArgName => "invalid_arg_name",
+            PostMessageError::InvalidArrayArg => "invalid_array_arg",
+            PostMessageError::InvalidCharset => "invalid_charset",
+            PostMessageError::InvalidFormData => "invalid_form_data",
+            PostMessageError::InvalidPostType => "invalid_post_type",
+            PostMessageError::MissingPostType => "missing_post_type",
+            PostMessageError::TeamAddedToOrg => "team_added_to_org",
+            PostMessageError::RequestTimeout => "request_timeout",
+            PostMessageError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            PostMessageError::Unknown(code) => return ::Error::from_code("chat.postMessage", &code),
+            PostMessageError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("chat.postMessage", code)
+    }
+}
+
 impl<E: Error> fmt::Display for PostMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -566,6 +1355,357 @@ impl<E: Error> Error for PostMessageError<E> {
     }
 }
 
+/// Schedules a message to be sent to a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.scheduleMessage
+
+pub fn schedule_message<R>(client: &R,
+                           token: &str,
+                           request: &ScheduleMessageRequest)
+                           -> Result<ScheduleMessageResponse, ScheduleMessageError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("post_at".to_owned(), request.post_at.into());
+    body.insert("text".to_owned(), request.text.into());
+    if let Some(as_user) = request.as_user {
+        body.insert("as_user".to_owned(), as_user.into());
+    }
+    if let Some(attachments) = request.attachments {
+        body.insert("attachments".to_owned(), ::json_param(attachments));
+    }
+    if let Some(blocks) = request.blocks {
+        body.insert("blocks".to_owned(), ::json_param(blocks));
+    }
+    if let Some(link_names) = request.link_names {
+        body.insert("link_names".to_owned(), link_names.into());
+    }
+    if let Some(metadata) = request.metadata {
+        body.insert("metadata".to_owned(), ::json_param(metadata));
+    }
+    if let Some(parse) = request.parse {
+        body.insert("parse".to_owned(), parse.as_str().into());
+    }
+    if let Some(reply_broadcast) = request.reply_broadcast {
+        body.insert("reply_broadcast".to_owned(), reply_broadcast.into());
+    }
+    if let Some(thread_ts) = request.thread_ts {
+        body.insert("thread_ts".to_owned(), thread_ts.into());
+    }
+    if let Some(unfurl_links) = request.unfurl_links {
+        body.insert("unfurl_links".to_owned(), unfurl_links.into());
+    }
+    if let Some(unfurl_media) = request.unfurl_media {
+        body.insert("unfurl_media".to_owned(), unfurl_media.into());
+    }
+    let url = ::get_slack_url_for_method("chat.scheduleMessage");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| ScheduleMessageError::Client(err))
+        .and_then(|result| {
+                      parse_schedule_message_response(result.body.as_bytes()).map_err(|err| err.into_error(ScheduleMessageError::MalformedResponse))
+                  })
+}
+
+fn parse_schedule_message_response(body: &[u8]) -> Result<ScheduleMessageResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ScheduleMessageResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ScheduleMessageRequest<'a> {
+    /// Channel, private group, or DM channel to send message to. Can be an encoded ID, or a name.
+    pub channel: &'a str,
+    /// Unix EPOCH timestamp of time in future to send the message.
+    pub post_at: u32,
+    /// How this field works and whether it is required depends on other fields you use in your API call.
+    pub text: &'a str,
+    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false.
+    pub as_user: Option<bool>,
+    /// A JSON-based array of structured attachments, presented as a URL-encoded string.
+    pub attachments: Option<&'a str>,
+    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
+    pub blocks: Option<&'a str>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// JSON object with event_type and event_payload fields, presented as a URL-encoded string. Metadata you post to Slack is accessible to any app or user who is a member of that workspace.
+    pub metadata: Option<&'a str>,
+    /// Change how messages are treated. Defaults to none.
+    pub parse: Option<::message::Parse>,
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
+    pub reply_broadcast: Option<bool>,
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub thread_ts: Option<&'a str>,
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub unfurl_links: Option<bool>,
+    /// Pass false to disable unfurling of media content.
+    pub unfurl_media: Option<bool>,
+}
+
+impl<'a> ScheduleMessageRequest<'a> {
+    pub fn new(channel: &'a str, post_at: u32, text: &'a str) -> Self {
+        ScheduleMessageRequest {
+            channel: channel,
+            post_at: post_at,
+            text: text,
+            ..ScheduleMessageRequest::default()
+        }
+    }
+
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    pub fn attachments(mut self, attachments: &'a str) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    pub fn blocks(mut self, blocks: &'a str) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: &'a str) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    pub fn parse(mut self, parse: ::message::Parse) -> Self {
+        self.parse = Some(parse);
+        self
+    }
+
+    pub fn reply_broadcast(mut self, reply_broadcast: bool) -> Self {
+        self.reply_broadcast = Some(reply_broadcast);
+        self
+    }
+
+    pub fn thread_ts(mut self, thread_ts: &'a str) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+        self.unfurl_links = Some(unfurl_links);
+        self
+    }
+
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+        self.unfurl_media = Some(unfurl_media);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ScheduleMessageResponse {
+    pub channel: Option<String>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub post_at: Option<i64>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub scheduled_message_id: Option<String>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ScheduleMessageResponse, ScheduleMessageError<E>>> for ScheduleMessageResponse {
+    fn into(self) -> Result<ScheduleMessageResponse, ScheduleMessageError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ScheduleMessageError<E: Error> {
+    /// Value passed for post_time was invalid.
+    InvalidTime,
+    /// Value passed for post_time was in the past.
+    TimeInPast,
+    /// Value passed for post_time was too far into the future.
+    TimeTooFar,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Cannot post user messages to a channel they are not in.
+    NotInChannel,
+    /// Channel has been archived.
+    IsArchived,
+    /// Message text is too long.
+    MsgTooLong,
+    /// No message text provided.
+    NoText,
+    /// Too many messages were scheduled in the channel for a short period of time.
+    RestrictedTooMany,
+    /// Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.
+    TooManyAttachments,
+    /// Application has posted too many messages, read the Rate Limit documentation for more information.
+    RateLimited,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ScheduleMessageError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_time" => ScheduleMessageError::InvalidTime,
+            "time_in_past" => ScheduleMessageError::TimeInPast,
+            "time_too_far" => ScheduleMessageError::TimeTooFar,
+            "channel_not_found" => ScheduleMessageError::ChannelNotFound,
+            "not_in_channel" => ScheduleMessageError::NotInChannel,
+            "is_archived" => ScheduleMessageError::IsArchived,
+            "msg_too_long" => ScheduleMessageError::MsgTooLong,
+            "no_text" => ScheduleMessageError::NoText,
+            "restricted_too_many" => ScheduleMessageError::RestrictedTooMany,
+            "too_many_attachments" => ScheduleMessageError::TooManyAttachments,
+            "rate_limited" => ScheduleMessageError::RateLimited,
+            "missing_scope" => ScheduleMessageError::MissingScope,
+            "not_authed" => ScheduleMessageError::NotAuthed,
+            "invalid_auth" => ScheduleMessageError::InvalidAuth,
+            "account_inactive" => ScheduleMessageError::AccountInactive,
+            "invalid_arg_name" => ScheduleMessageError::InvalidArgName,
+            "invalid_array_arg" => ScheduleMessageError::InvalidArrayArg,
+            "invalid_charset" => ScheduleMessageError::InvalidCharset,
+            "invalid_form_data" => ScheduleMessageError::InvalidFormData,
+            "invalid_post_type" => ScheduleMessageError::InvalidPostType,
+            "missing_post_type" => ScheduleMessageError::MissingPostType,
+            "team_added_to_org" => ScheduleMessageError::TeamAddedToOrg,
+            "request_timeout" => ScheduleMessageError::RequestTimeout,
+            _ => ScheduleMessageError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ScheduleMessageError<E>> for ::Error<E> {
+    fn from(err: ScheduleMessageError<E>) -> Self {
+        let code = match err {
+            ScheduleMessageError::InvalidTime => "invalid_time",
+            ScheduleMessageError::TimeInPast => "time_in_past",
+            ScheduleMessageError::TimeTooFar => "time_too_far",
+            ScheduleMessageError::ChannelNotFound => "channel_not_found",
+            ScheduleMessageError::NotInChannel => "not_in_channel",
+            ScheduleMessageError::IsArchived => "is_archived",
+            ScheduleMessageError::MsgTooLong => "msg_too_long",
+            ScheduleMessageError::NoText => "no_text",
+            ScheduleMessageError::RestrictedTooMany => "restricted_too_many",
+            ScheduleMessageError::TooManyAttachments => "too_many_attachments",
+            ScheduleMessageError::RateLimited => "rate_limited",
+            ScheduleMessageError::MissingScope => "missing_scope",
+            ScheduleMessageError::NotAuthed => "not_authed",
+            ScheduleMessageError::InvalidAuth => "invalid_auth",
+            ScheduleMessageError::AccountInactive => "account_inactive",
+            ScheduleMessageError::InvalidArgName => "invalid_arg_name",
+            ScheduleMessageError::InvalidArrayArg => "invalid_array_arg",
+            ScheduleMessageError::InvalidCharset => "invalid_charset",
+            ScheduleMessageError::InvalidFormData => "invalid_form_data",
+            ScheduleMessageError::InvalidPostType => "invalid_post_type",
+            ScheduleMessageError::MissingPostType => "missing_post_type",
+            ScheduleMessageError::TeamAddedToOrg => "team_added_to_org",
+            ScheduleMessageError::RequestTimeout => "request_timeout",
+            ScheduleMessageError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ScheduleMessageError::Unknown(code) => return ::Error::from_code("chat.scheduleMessage", &code),
+            ScheduleMessageError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("chat.scheduleMessage", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ScheduleMessageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ScheduleMessageError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ScheduleMessageError::InvalidTime => "invalid_time: Value passed for post_time was invalid.",
+            &ScheduleMessageError::TimeInPast => "time_in_past: Value passed for post_time was in the past.",
+            &ScheduleMessageError::TimeTooFar => "time_too_far: Value passed for post_time was too far into the future.",
+            &ScheduleMessageError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &ScheduleMessageError::NotInChannel => "not_in_channel: Cannot post user messages to a channel they are not in.",
+            &ScheduleMessageError::IsArchived => "is_archived: Channel has been archived.",
+            &ScheduleMessageError::MsgTooLong => "msg_too_long: Message text is too long.",
+            &ScheduleMessageError::NoText => "no_text: No message text provided.",
+            &ScheduleMessageError::RestrictedTooMany => "restricted_too_many: Too many messages were scheduled in the channel for a short period of time.",
+            &ScheduleMessageError::TooManyAttachments => "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.",
+            &ScheduleMessageError::RateLimited => "rate_limited: Application has posted too many messages, read the Rate Limit documentation for more information.",
+            &ScheduleMessageError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ScheduleMessageError::NotAuthed => "not_authed: No authentication token provided.",
+            &ScheduleMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ScheduleMessageError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ScheduleMessageError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ScheduleMessageError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ScheduleMessageError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ScheduleMessageError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ScheduleMessageError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ScheduleMessageError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ScheduleMessageError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ScheduleMessageError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ScheduleMessageError::MalformedResponse(ref e) => e.description(),
+            &ScheduleMessageError::Unknown(ref s) => s,
+            &ScheduleMessageError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ScheduleMessageError::MalformedResponse(ref e) => Some(e),
+            &ScheduleMessageError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 /// Unfurl a URL that a user posted
 ///
 /// Wraps https://api.slack.com/methods/chat.unfurl
@@ -577,26 +1717,31 @@ pub fn unfurl<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("ts", request.ts)),
-                      Some(("unfurls", request.unfurls)),
-                      request
-                          .user_auth_required
-                          .map(|user_auth_required| {
-                                   ("user_auth_required",
-                                    if user_auth_required { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("ts".to_owned(), request.ts.into());
+    body.insert("unfurls".to_owned(), ::json_param(request.unfurls));
+    if let Some(user_auth_required) = request.user_auth_required {
+        body.insert("user_auth_required".to_owned(), user_auth_required.into());
+    }
     let url = ::get_slack_url_for_method("chat.unfurl");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| UnfurlError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<UnfurlResponse>(&result)
-                            .map_err(|e| UnfurlError::MalformedResponse(e))
+                      parse_unfurl_response(result.body.as_bytes()).map_err(|err| err.into_error(UnfurlError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_unfurl_response(body: &[u8]) -> Result<UnfurlResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UnfurlResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -611,11 +1756,29 @@ pub struct UnfurlRequest<'a> {
     pub user_auth_required: Option<bool>,
 }
 
+impl<'a> UnfurlRequest<'a> {
+    pub fn new(channel: &'a str, ts: &'a str, unfurls: &'a str) -> Self {
+        UnfurlRequest {
+            channel: channel,
+            ts: ts,
+            unfurls: unfurls,
+            ..UnfurlRequest::default()
+        }
+    }
+
+    pub fn user_auth_required(mut self, user_auth_required: bool) -> Self {
+        self.user_auth_required = Some(user_auth_required);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UnfurlResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -686,6 +1849,29 @@ impl<'a, E: Error> From<&'a str> for UnfurlError<E> {
     }
 }
 
+impl<E: Error> From<UnfurlError<E>> for ::Error<E> {
+    fn from(err: UnfurlError<E>) -> Self {
+        let code = match err {
+            UnfurlError::NotAuthed => "not_authed",
+            UnfurlError::InvalidAuth => "invalid_auth",
+            UnfurlError::AccountInactive => "account_inactive",
+            UnfurlError::UserIsBot => "user_is_bot",
+            UnfurlError::InvalidArgName => "invalid_arg_name",
+            UnfurlError::InvalidArrayArg => "invalid_array_arg",
+            UnfurlError::InvalidCharset => "invalid_charset",
+            UnfurlError::InvalidFormData => "invalid_form_data",
+            UnfurlError::InvalidPostType => "invalid_post_type",
+            UnfurlError::MissingPostType => "missing_post_type",
+            UnfurlError::TeamAddedToOrg => "team_added_to_org",
+            UnfurlError::RequestTimeout => "request_timeout",
+            UnfurlError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UnfurlError::Unknown(code) => return ::Error::from_code("chat.unfurl", &code),
+            UnfurlError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("chat.unfurl", code)
+    }
+}
+
 impl<E: Error> fmt::Display for UnfurlError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -732,32 +1918,51 @@ pub fn update<R>(client: &R,
                  -> Result<UpdateResponse, UpdateError<R::Error>>
     where R: SlackWebRequestSender
 {
-
-    let params =
-        vec![Some(("token", token)),
-             Some(("ts", request.ts)),
-             Some(("channel", request.channel)),
-             Some(("text", request.text)),
-             request
-                 .attachments
-                 .map(|attachments| ("attachments", attachments)),
-             request.parse.map(|parse| ("parse", parse)),
-             request
-                 .link_names
-                 .map(|link_names| ("link_names", if link_names { "1" } else { "0" })),
-             request
-                 .as_user
-                 .map(|as_user| ("as_user", if as_user { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let typed_blocks = request.typed_blocks.map(::blocks::to_json);
+    let blocks = request.blocks.or(typed_blocks.as_ref().map(String::as_str));
+    let typed_attachments = request.typed_attachments.map(::blocks::attachments_to_json);
+    let attachments = request.attachments.or(typed_attachments.as_ref().map(String::as_str));
+    let text = ::blocks::text_or_fallback(request.text, blocks, request.text_from_blocks);
+    let mut body = ::serde_json::Map::new();
+    body.insert("ts".to_owned(), request.ts.into());
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("text".to_owned(), text.into());
+    if let Some(attachments) = attachments {
+        body.insert("attachments".to_owned(), ::json_param(attachments));
+    }
+    if let Some(blocks) = blocks {
+        body.insert("blocks".to_owned(), ::json_param(blocks));
+    }
+    if let Some(parse) = request.parse {
+        body.insert("parse".to_owned(), parse.as_str().into());
+    }
+    if let Some(link_names) = request.link_names {
+        body.insert("link_names".to_owned(), link_names.into());
+    }
+    if let Some(as_user) = request.as_user {
+        body.insert("as_user".to_owned(), as_user.into());
+    }
+    if let Some(metadata) = request.metadata {
+        body.insert("metadata".to_owned(), ::json_param(metadata));
+    }
     let url = ::get_slack_url_for_method("chat.update");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| UpdateError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<UpdateResponse>(&result)
-                            .map_err(|e| UpdateError::MalformedResponse(e))
+                      parse_update_response(result.body.as_bytes()).map_err(|err| err.into_error(UpdateError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_update_response(body: &[u8]) -> Result<UpdateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UpdateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -770,12 +1975,78 @@ pub struct UpdateRequest<'a> {
     pub text: &'a str,
     /// Structured message attachments.
     pub attachments: Option<&'a str>,
+    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
+    pub blocks: Option<&'a str>,
     /// Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.
-    pub parse: Option<&'a str>,
+    pub parse: Option<::message::Parse>,
     /// Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.
     pub link_names: Option<bool>,
     /// Pass true to update the message as the authed user. Bot users in this context are considered authed users.
     pub as_user: Option<bool>,
+    /// JSON object with event_type and event_payload fields, presented as a URL-encoded string. Metadata you post to Slack is accessible to any app or user who is a member of that workspace.
+    pub metadata: Option<&'a str>,
+    /// Not sent to Slack: whether to fill in an empty text from blocks, so that notifications show something. Defaults to true.
+    pub text_from_blocks: Option<bool>,
+    /// Not sent to Slack: blocks to send as `blocks`, unless it is set.
+    pub typed_blocks: Option<&'a [::blocks::Block]>,
+    /// Not sent to Slack: attachments to send as `attachments`, unless it is set.
+    pub typed_attachments: Option<&'a [::blocks::Attachment]>,
+}
+
+impl<'a> UpdateRequest<'a> {
+    pub fn new(ts: &'a str, channel: &'a str, text: &'a str) -> Self {
+        UpdateRequest {
+            ts: ts,
+            channel: channel,
+            text: text,
+            ..UpdateRequest::default()
+        }
+    }
+
+    pub fn attachments(mut self, attachments: &'a str) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    pub fn blocks(mut self, blocks: &'a str) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    pub fn parse(mut self, parse: ::message::Parse) -> Self {
+        self.parse = Some(parse);
+        self
+    }
+
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: &'a str) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    pub fn text_from_blocks(mut self, text_from_blocks: bool) -> Self {
+        self.text_from_blocks = Some(text_from_blocks);
+        self
+    }
+
+    pub fn typed_blocks(mut self, typed_blocks: &'a [::blocks::Block]) -> Self {
+        self.typed_blocks = Some(typed_blocks);
+        self
+    }
+
+    pub fn typed_attachments(mut self, typed_attachments: &'a [::blocks::Attachment]) -> Self {
+        self.typed_attachments = Some(typed_attachments);
+        self
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -784,8 +2055,10 @@ pub struct UpdateResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub text: Option<String>,
     pub ts: Option<String>,
+    pub warning: Option<String>,
 }
 
 
@@ -874,6 +2147,35 @@ impl<'a, E: Error> From<&'a str> for UpdateError<E> {
     }
 }
 
+impl<E: Error> From<UpdateError<E>> for ::Error<E> {
+    fn from(err: UpdateError<E>) -> Self {
+        let code = match err {
+            UpdateError::MessageNotFound => "message_not_found",
+            UpdateError::CantUpdateMessage => "cant_update_message",
+            UpdateError::ChannelNotFound => "channel_not_found",
+            UpdateError::EditWindowClosed => "edit_window_closed",
+            UpdateError::MsgTooLong => "msg_too_long",
+            UpdateError::TooManyAttachments => "too_many_attachments",
+            UpdateError::NoText => "no_text",
+            UpdateError::NotAuthed => "not_authed",
+            UpdateError::InvalidAuth => "invalid_auth",
+            UpdateError::AccountInactive => "account_inactive",
+            UpdateError::InvalidArgName => "invalid_arg_name",
+            UpdateError::InvalidArrayArg => "invalid_array_arg",
+            UpdateError::InvalidCharset => "invalid_charset",
+            UpdateError::InvalidFormData => "invalid_form_data",
+            UpdateError::InvalidPostType => "invalid_post_type",
+            UpdateError::MissingPostType => "missing_post_type",
+            UpdateError::TeamAddedToOrg => "team_added_to_org",
+            UpdateError::RequestTimeout => "request_timeout",
+            UpdateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UpdateError::Unknown(code) => return ::Error::from_code("chat.update", &code),
+            UpdateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("chat.update", code)
+    }
+}
+
 impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -915,3 +2217,67 @@ impl<E: Error> Error for UpdateError<E> {
         }
     }
 }
+
+/// The `chat.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::chat`](../client/struct.SlackClient.html#method.chat).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Deletes a message. See [`delete`](fn.delete.html).
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<R::Error>> {
+        delete(self.client, self.token.for_method("chat.delete"), request)
+    }
+
+    /// Deletes a pending scheduled message from the queue. See
+    /// [`delete_scheduled_message`](fn.delete_scheduled_message.html).
+    pub fn delete_scheduled_message(&self, request: &DeleteScheduledMessageRequest)
+                                    -> Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<R::Error>> {
+        delete_scheduled_message(self.client, self.token.for_method("chat.deleteScheduledMessage"), request)
+    }
+
+    /// Share a me message into a channel. See [`me_message`](fn.me_message.html).
+    pub fn me_message(&self, request: &MeMessageRequest) -> Result<MeMessageResponse, MeMessageError<R::Error>> {
+        me_message(self.client, self.token.for_method("chat.meMessage"), request)
+    }
+
+    /// Sends an ephemeral message to a user in a channel. See
+    /// [`post_ephemeral`](fn.post_ephemeral.html).
+    pub fn post_ephemeral(&self, request: &PostEphemeralRequest)
+                          -> Result<PostEphemeralResponse, PostEphemeralError<R::Error>> {
+        post_ephemeral(self.client, self.token.for_method("chat.postEphemeral"), request)
+    }
+
+    /// Sends a message to a channel. See [`post_message`](fn.post_message.html).
+    pub fn post_message(&self, request: &PostMessageRequest)
+                        -> Result<PostMessageResponse, PostMessageError<R::Error>> {
+        post_message(self.client, self.token.for_method("chat.postMessage"), request)
+    }
+
+    /// Schedules a message to be sent to a channel. See
+    /// [`schedule_message`](fn.schedule_message.html).
+    pub fn schedule_message(&self, request: &ScheduleMessageRequest)
+                            -> Result<ScheduleMessageResponse, ScheduleMessageError<R::Error>> {
+        schedule_message(self.client, self.token.for_method("chat.scheduleMessage"), request)
+    }
+
+    /// Unfurl a URL that a user posted. See [`unfurl`](fn.unfurl.html).
+    pub fn unfurl(&self, request: &UnfurlRequest) -> Result<UnfurlResponse, UnfurlError<R::Error>> {
+        unfurl(self.client, self.token.for_method("chat.unfurl"), request)
+    }
+
+    /// Updates a message. See [`update`](fn.update.html).
+    pub fn update(&self, request: &UpdateRequest) -> Result<UpdateResponse, UpdateError<R::Error>> {
+        update(self.client, self.token.for_method("chat.update"), request)
+    }
+}
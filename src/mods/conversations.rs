@@ -0,0 +1,4420 @@
+//! Manage and query every kind of conversation (public and private channels, direct messages and multi-person direct messages) through one set of methods.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Archives a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.archive
+
+pub fn archive<R>(client: &R,
+                  token: &str,
+                  request: &ArchiveRequest)
+                  -> Result<ArchiveResponse, ArchiveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    let url = ::get_slack_url_for_method("conversations.archive");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| ArchiveError::Client(err))
+        .and_then(|result| {
+                      parse_archive_response(result.body.as_bytes()).map_err(|err| err.into_error(ArchiveError::MalformedResponse))
+                  })
+}
+
+fn parse_archive_response(body: &[u8]) -> Result<ArchiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ArchiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ArchiveRequest<'a> {
+    /// ID of conversation to archive
+    pub channel: &'a str,
+}
+
+impl<'a> ArchiveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        ArchiveRequest {
+            channel: channel,
+            ..ArchiveRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ArchiveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveResponse {
+    fn into(self) -> Result<ArchiveResponse, ArchiveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ArchiveError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has already been archived.
+    AlreadyArchived,
+    /// You cannot archive the general channel
+    CantArchiveGeneral,
+    /// A team preference prevents the authenticated user from archiving.
+    RestrictedAction,
+    /// User is not a member of the channel.
+    NotInChannel,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => ArchiveError::MethodNotSupportedForChannelType,
+            "channel_not_found" => ArchiveError::ChannelNotFound,
+            "already_archived" => ArchiveError::AlreadyArchived,
+            "cant_archive_general" => ArchiveError::CantArchiveGeneral,
+            "restricted_action" => ArchiveError::RestrictedAction,
+            "not_in_channel" => ArchiveError::NotInChannel,
+            "user_is_restricted" => ArchiveError::UserIsRestricted,
+            "missing_scope" => ArchiveError::MissingScope,
+            "not_authed" => ArchiveError::NotAuthed,
+            "invalid_auth" => ArchiveError::InvalidAuth,
+            "account_inactive" => ArchiveError::AccountInactive,
+            "invalid_arg_name" => ArchiveError::InvalidArgName,
+            "invalid_array_arg" => ArchiveError::InvalidArrayArg,
+            "invalid_charset" => ArchiveError::InvalidCharset,
+            "invalid_form_data" => ArchiveError::InvalidFormData,
+            "invalid_post_type" => ArchiveError::InvalidPostType,
+            "missing_post_type" => ArchiveError::MissingPostType,
+            "team_added_to_org" => ArchiveError::TeamAddedToOrg,
+            "request_timeout" => ArchiveError::RequestTimeout,
+            _ => ArchiveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ArchiveError<E>> for ::Error<E> {
+    fn from(err: ArchiveError<E>) -> Self {
+        let code = match err {
+            ArchiveError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            ArchiveError::ChannelNotFound => "channel_not_found",
+            ArchiveError::AlreadyArchived => "already_archived",
+            ArchiveError::CantArchiveGeneral => "cant_archive_general",
+            ArchiveError::RestrictedAction => "restricted_action",
+            ArchiveError::NotInChannel => "not_in_channel",
+            ArchiveError::UserIsRestricted => "user_is_restricted",
+            ArchiveError::MissingScope => "missing_scope",
+            ArchiveError::NotAuthed => "not_authed",
+            ArchiveError::InvalidAuth => "invalid_auth",
+            ArchiveError::AccountInactive => "account_inactive",
+            ArchiveError::InvalidArgName => "invalid_arg_name",
+            ArchiveError::InvalidArrayArg => "invalid_array_arg",
+            ArchiveError::InvalidCharset => "invalid_charset",
+            ArchiveError::InvalidFormData => "invalid_form_data",
+            ArchiveError::InvalidPostType => "invalid_post_type",
+            ArchiveError::MissingPostType => "missing_post_type",
+            ArchiveError::TeamAddedToOrg => "team_added_to_org",
+            ArchiveError::RequestTimeout => "request_timeout",
+            ArchiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ArchiveError::Unknown(code) => return ::Error::from_code("conversations.archive", &code),
+            ArchiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.archive", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ArchiveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ArchiveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ArchiveError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &ArchiveError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &ArchiveError::AlreadyArchived => "already_archived: Channel has already been archived.",
+            &ArchiveError::CantArchiveGeneral => "cant_archive_general: You cannot archive the general channel",
+            &ArchiveError::RestrictedAction => "restricted_action: A team preference prevents the authenticated user from archiving.",
+            &ArchiveError::NotInChannel => "not_in_channel: User is not a member of the channel.",
+            &ArchiveError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &ArchiveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ArchiveError::NotAuthed => "not_authed: No authentication token provided.",
+            &ArchiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ArchiveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ArchiveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ArchiveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ArchiveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ArchiveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ArchiveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ArchiveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ArchiveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ArchiveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ArchiveError::MalformedResponse(ref e) => e.description(),
+            &ArchiveError::Unknown(ref s) => s,
+            &ArchiveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ArchiveError::MalformedResponse(ref e) => Some(e),
+            &ArchiveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Closes a direct message or multi-person direct message.
+///
+/// Wraps https://api.slack.com/methods/conversations.close
+
+pub fn close<R>(client: &R,
+                token: &str,
+                request: &CloseRequest)
+                -> Result<CloseResponse, CloseError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    let url = ::get_slack_url_for_method("conversations.close");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| CloseError::Client(err))
+        .and_then(|result| {
+                      parse_close_response(result.body.as_bytes()).map_err(|err| err.into_error(CloseError::MalformedResponse))
+                  })
+}
+
+fn parse_close_response(body: &[u8]) -> Result<CloseResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CloseResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CloseRequest<'a> {
+    /// Conversation to close.
+    pub channel: &'a str,
+}
+
+impl<'a> CloseRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        CloseRequest {
+            channel: channel,
+            ..CloseRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CloseResponse {
+    pub already_closed: Option<bool>,
+    error: Option<String>,
+    pub no_op: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<CloseResponse, CloseError<E>>> for CloseResponse {
+    fn into(self) -> Result<CloseResponse, CloseError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CloseError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Calling user does not own this DM channel.
+    UserDoesNotOwnChannel,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CloseError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => CloseError::MethodNotSupportedForChannelType,
+            "channel_not_found" => CloseError::ChannelNotFound,
+            "user_does_not_own_channel" => CloseError::UserDoesNotOwnChannel,
+            "missing_scope" => CloseError::MissingScope,
+            "not_authed" => CloseError::NotAuthed,
+            "invalid_auth" => CloseError::InvalidAuth,
+            "account_inactive" => CloseError::AccountInactive,
+            "invalid_arg_name" => CloseError::InvalidArgName,
+            "invalid_array_arg" => CloseError::InvalidArrayArg,
+            "invalid_charset" => CloseError::InvalidCharset,
+            "invalid_form_data" => CloseError::InvalidFormData,
+            "invalid_post_type" => CloseError::InvalidPostType,
+            "missing_post_type" => CloseError::MissingPostType,
+            "team_added_to_org" => CloseError::TeamAddedToOrg,
+            "request_timeout" => CloseError::RequestTimeout,
+            _ => CloseError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<CloseError<E>> for ::Error<E> {
+    fn from(err: CloseError<E>) -> Self {
+        let code = match err {
+            CloseError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            CloseError::ChannelNotFound => "channel_not_found",
+            CloseError::UserDoesNotOwnChannel => "user_does_not_own_channel",
+            CloseError::MissingScope => "missing_scope",
+            CloseError::NotAuthed => "not_authed",
+            CloseError::InvalidAuth => "invalid_auth",
+            CloseError::AccountInactive => "account_inactive",
+            CloseError::InvalidArgName => "invalid_arg_name",
+            CloseError::InvalidArrayArg => "invalid_array_arg",
+            CloseError::InvalidCharset => "invalid_charset",
+            CloseError::InvalidFormData => "invalid_form_data",
+            CloseError::InvalidPostType => "invalid_post_type",
+            CloseError::MissingPostType => "missing_post_type",
+            CloseError::TeamAddedToOrg => "team_added_to_org",
+            CloseError::RequestTimeout => "request_timeout",
+            CloseError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CloseError::Unknown(code) => return ::Error::from_code("conversations.close", &code),
+            CloseError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.close", code)
+    }
+}
+
+impl<E: Error> fmt::Display for CloseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CloseError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &CloseError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &CloseError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &CloseError::UserDoesNotOwnChannel => "user_does_not_own_channel: Calling user does not own this DM channel.",
+            &CloseError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &CloseError::NotAuthed => "not_authed: No authentication token provided.",
+            &CloseError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &CloseError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &CloseError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &CloseError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &CloseError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &CloseError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &CloseError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &CloseError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &CloseError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &CloseError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &CloseError::MalformedResponse(ref e) => e.description(),
+            &CloseError::Unknown(ref s) => s,
+            &CloseError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &CloseError::MalformedResponse(ref e) => Some(e),
+            &CloseError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Initiates a public or private channel-based conversation
+///
+/// Wraps https://api.slack.com/methods/conversations.create
+
+pub fn create<R>(client: &R,
+                 token: &str,
+                 request: &CreateRequest)
+                 -> Result<CreateResponse, CreateError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("name".to_owned(), request.name.into());
+    if let Some(is_private) = request.is_private {
+        body.insert("is_private".to_owned(), is_private.into());
+    }
+    if let Some(team_id) = request.team_id {
+        body.insert("team_id".to_owned(), team_id.into());
+    }
+    let url = ::get_slack_url_for_method("conversations.create");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| CreateError::Client(err))
+        .and_then(|result| {
+                      parse_create_response(result.body.as_bytes()).map_err(|err| err.into_error(CreateError::MalformedResponse))
+                  })
+}
+
+fn parse_create_response(body: &[u8]) -> Result<CreateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CreateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequest<'a> {
+    /// Name of the public or private channel to create
+    pub name: &'a str,
+    /// Create a private channel instead of a public one
+    pub is_private: Option<bool>,
+    /// encoded team id to create the channel in, required if org token is used
+    pub team_id: Option<&'a str>,
+}
+
+impl<'a> CreateRequest<'a> {
+    pub fn new(name: &'a str) -> Self {
+        CreateRequest {
+            name: name,
+            ..CreateRequest::default()
+        }
+    }
+
+    pub fn is_private(mut self, is_private: bool) -> Self {
+        self.is_private = Some(is_private);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
+    fn into(self) -> Result<CreateResponse, CreateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CreateError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// A channel cannot be created with the given name.
+    NameTaken,
+    /// A team preference prevents the authenticated user from creating channels.
+    RestrictedAction,
+    /// Value passed for name was empty.
+    NoChannel,
+    /// Value passed for name was empty.
+    InvalidNameRequired,
+    /// Value passed for name was only punctuation.
+    InvalidNamePunctuation,
+    /// Value passed for name exceeded max length.
+    InvalidNameMaxlength,
+    /// Value passed for name contained unallowed special characters or upper case characters.
+    InvalidNameSpecials,
+    /// Value passed for name was invalid.
+    InvalidName,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CreateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => CreateError::MethodNotSupportedForChannelType,
+            "name_taken" => CreateError::NameTaken,
+            "restricted_action" => CreateError::RestrictedAction,
+            "no_channel" => CreateError::NoChannel,
+            "invalid_name_required" => CreateError::InvalidNameRequired,
+            "invalid_name_punctuation" => CreateError::InvalidNamePunctuation,
+            "invalid_name_maxlength" => CreateError::InvalidNameMaxlength,
+            "invalid_name_specials" => CreateError::InvalidNameSpecials,
+            "invalid_name" => CreateError::InvalidName,
+            "user_is_restricted" => CreateError::UserIsRestricted,
+            "missing_scope" => CreateError::MissingScope,
+            "not_authed" => CreateError::NotAuthed,
+            "invalid_auth" => CreateError::InvalidAuth,
+            "account_inactive" => CreateError::AccountInactive,
+            "invalid_arg_name" => CreateError::InvalidArgName,
+            "invalid_array_arg" => CreateError::InvalidArrayArg,
+            "invalid_charset" => CreateError::InvalidCharset,
+            "invalid_form_data" => CreateError::InvalidFormData,
+            "invalid_post_type" => CreateError::InvalidPostType,
+            "missing_post_type" => CreateError::MissingPostType,
+            "team_added_to_org" => CreateError::TeamAddedToOrg,
+            "request_timeout" => CreateError::RequestTimeout,
+            _ => CreateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<CreateError<E>> for ::Error<E> {
+    fn from(err: CreateError<E>) -> Self {
+        let code = match err {
+            CreateError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            CreateError::NameTaken => "name_taken",
+            CreateError::RestrictedAction => "restricted_action",
+            CreateError::NoChannel => "no_channel",
+            CreateError::InvalidNameRequired => "invalid_name_required",
+            CreateError::InvalidNamePunctuation => "invalid_name_punctuation",
+            CreateError::InvalidNameMaxlength => "invalid_name_maxlength",
+            CreateError::InvalidNameSpecials => "invalid_name_specials",
+            CreateError::InvalidName => "invalid_name",
+            CreateError::UserIsRestricted => "user_is_restricted",
+            CreateError::MissingScope => "missing_scope",
+            CreateError::NotAuthed => "not_authed",
+            CreateError::InvalidAuth => "invalid_auth",
+            CreateError::AccountInactive => "account_inactive",
+            CreateError::InvalidArgName => "invalid_arg_name",
+            CreateError::InvalidArrayArg => "invalid_array_arg",
+            CreateError::InvalidCharset => "invalid_charset",
+            CreateError::InvalidFormData => "invalid_form_data",
+            CreateError::InvalidPostType => "invalid_post_type",
+            CreateError::MissingPostType => "missing_post_type",
+            CreateError::TeamAddedToOrg => "team_added_to_org",
+            CreateError::RequestTimeout => "request_timeout",
+            CreateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CreateError::Unknown(code) => return ::Error::from_code("conversations.create", &code),
+            CreateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.create", code)
+    }
+}
+
+impl<E: Error> fmt::Display for CreateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CreateError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &CreateError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &CreateError::NameTaken => "name_taken: A channel cannot be created with the given name.",
+            &CreateError::RestrictedAction => "restricted_action: A team preference prevents the authenticated user from creating channels.",
+            &CreateError::NoChannel => "no_channel: Value passed for name was empty.",
+            &CreateError::InvalidNameRequired => "invalid_name_required: Value passed for name was empty.",
+            &CreateError::InvalidNamePunctuation => "invalid_name_punctuation: Value passed for name was only punctuation.",
+            &CreateError::InvalidNameMaxlength => "invalid_name_maxlength: Value passed for name exceeded max length.",
+            &CreateError::InvalidNameSpecials => "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters.",
+            &CreateError::InvalidName => "invalid_name: Value passed for name was invalid.",
+            &CreateError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &CreateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &CreateError::NotAuthed => "not_authed: No authentication token provided.",
+            &CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &CreateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &CreateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &CreateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &CreateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &CreateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &CreateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &CreateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &CreateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &CreateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &CreateError::MalformedResponse(ref e) => e.description(),
+            &CreateError::Unknown(ref s) => s,
+            &CreateError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &CreateError::MalformedResponse(ref e) => Some(e),
+            &CreateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Fetches a conversation's history of messages and events.
+///
+/// Wraps https://api.slack.com/methods/conversations.history
+
+pub fn history<R>(client: &R,
+                  token: &str,
+                  request: &HistoryRequest)
+                  -> Result<HistoryResponse, HistoryError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(8);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(include_all_metadata) = request.include_all_metadata {
+        params.push(("include_all_metadata", if include_all_metadata { "1" } else { "0" }));
+    }
+    if let Some(inclusive) = request.inclusive {
+        params.push(("inclusive", if inclusive { "1" } else { "0" }));
+    }
+    if let Some(latest) = request.latest {
+        params.push(("latest", latest));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    if let Some(oldest) = request.oldest {
+        params.push(("oldest", oldest));
+    }
+    let url = ::get_slack_url_for_method("conversations.history");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| HistoryError::Client(err))
+        .and_then(|result| {
+                      parse_history_response(&result).map_err(|err| err.into_error(HistoryError::MalformedResponse))
+                  })
+}
+
+/// Like [`history`](fn.history.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `messages` of every page in turn.
+pub fn history_all<'a, R>(client: &'a R,
+                          token: &'a str,
+                          request: &HistoryRequest<'a>)
+                          -> ::cursor::Items<'a, ::Message, HistoryError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = HistoryRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        history(client, token, &request).map(|response| {
+            (response.messages.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_history_response(body: &[u8]) -> Result<HistoryResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<HistoryResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct HistoryRequest<'a> {
+    /// Conversation ID to fetch history for.
+    pub channel: &'a str,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<&'a str>,
+    /// Return all metadata associated with this message.
+    pub include_all_metadata: Option<bool>,
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results.
+    pub latest: Option<&'a str>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<&'a str>,
+}
+
+impl<'a> HistoryRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        HistoryRequest {
+            channel: channel,
+            ..HistoryRequest::default()
+        }
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn include_all_metadata(mut self, include_all_metadata: bool) -> Self {
+        self.include_all_metadata = Some(include_all_metadata);
+        self
+    }
+
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    pub fn latest(mut self, latest: &'a str) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: &'a str) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HistoryResponse {
+    error: Option<String>,
+    pub has_more: Option<bool>,
+    pub messages: Option<Vec<::Message>>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<HistoryResponse, HistoryError<E>>> for HistoryResponse {
+    fn into(self) -> Result<HistoryResponse, HistoryError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum HistoryError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The bot is not a member of the channel.
+    NotInChannel,
+    /// Value passed for latest was invalid
+    InvalidTsLatest,
+    /// Value passed for oldest was invalid
+    InvalidTsOldest,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for HistoryError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => HistoryError::ChannelNotFound,
+            "not_in_channel" => HistoryError::NotInChannel,
+            "invalid_ts_latest" => HistoryError::InvalidTsLatest,
+            "invalid_ts_oldest" => HistoryError::InvalidTsOldest,
+            "invalid_cursor" => HistoryError::InvalidCursor,
+            "missing_scope" => HistoryError::MissingScope,
+            "not_authed" => HistoryError::NotAuthed,
+            "invalid_auth" => HistoryError::InvalidAuth,
+            "account_inactive" => HistoryError::AccountInactive,
+            "invalid_arg_name" => HistoryError::InvalidArgName,
+            "invalid_array_arg" => HistoryError::InvalidArrayArg,
+            "invalid_charset" => HistoryError::InvalidCharset,
+            "invalid_form_data" => HistoryError::InvalidFormData,
+            "invalid_post_type" => HistoryError::InvalidPostType,
+            "missing_post_type" => HistoryError::MissingPostType,
+            "team_added_to_org" => HistoryError::TeamAddedToOrg,
+            "request_timeout" => HistoryError::RequestTimeout,
+            _ => HistoryError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<HistoryError<E>> for ::Error<E> {
+    fn from(err: HistoryError<E>) -> Self {
+        let code = match err {
+            HistoryError::ChannelNotFound => "channel_not_found",
+            HistoryError::NotInChannel => "not_in_channel",
+            HistoryError::InvalidTsLatest => "invalid_ts_latest",
+            HistoryError::InvalidTsOldest => "invalid_ts_oldest",
+            HistoryError::InvalidCursor => "invalid_cursor",
+            HistoryError::MissingScope => "missing_scope",
+            HistoryError::NotAuthed => "not_authed",
+            HistoryError::InvalidAuth => "invalid_auth",
+            HistoryError::AccountInactive => "account_inactive",
+            HistoryError::InvalidArgName => "invalid_arg_name",
+            HistoryError::InvalidArrayArg => "invalid_array_arg",
+            HistoryError::InvalidCharset => "invalid_charset",
+            HistoryError::InvalidFormData => "invalid_form_data",
+            HistoryError::InvalidPostType => "invalid_post_type",
+            HistoryError::MissingPostType => "missing_post_type",
+            HistoryError::TeamAddedToOrg => "team_added_to_org",
+            HistoryError::RequestTimeout => "request_timeout",
+            HistoryError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            HistoryError::Unknown(code) => return ::Error::from_code("conversations.history", &code),
+            HistoryError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.history", code)
+    }
+}
+
+impl<E: Error> fmt::Display for HistoryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for HistoryError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &HistoryError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &HistoryError::NotInChannel => "not_in_channel: The bot is not a member of the channel.",
+            &HistoryError::InvalidTsLatest => "invalid_ts_latest: Value passed for latest was invalid",
+            &HistoryError::InvalidTsOldest => "invalid_ts_oldest: Value passed for oldest was invalid",
+            &HistoryError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &HistoryError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &HistoryError::NotAuthed => "not_authed: No authentication token provided.",
+            &HistoryError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &HistoryError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &HistoryError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &HistoryError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &HistoryError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &HistoryError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &HistoryError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &HistoryError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &HistoryError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &HistoryError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &HistoryError::MalformedResponse(ref e) => e.description(),
+            &HistoryError::Unknown(ref s) => s,
+            &HistoryError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &HistoryError::MalformedResponse(ref e) => Some(e),
+            &HistoryError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve information about a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.info
+
+pub fn info<R>(client: &R,
+               token: &str,
+               request: &InfoRequest)
+               -> Result<InfoResponse, InfoError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    if let Some(include_locale) = request.include_locale {
+        params.push(("include_locale", if include_locale { "1" } else { "0" }));
+    }
+    if let Some(include_num_members) = request.include_num_members {
+        params.push(("include_num_members", if include_num_members { "1" } else { "0" }));
+    }
+    let url = ::get_slack_url_for_method("conversations.info");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| InfoError::Client(err))
+        .and_then(|result| {
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
+                  })
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequest<'a> {
+    /// Conversation ID to learn more about
+    pub channel: &'a str,
+    /// Set this to true to receive the locale for this conversation. Defaults to false
+    pub include_locale: Option<bool>,
+    /// Set to true to include the member count for the specified conversation. Defaults to false
+    pub include_num_members: Option<bool>,
+}
+
+impl<'a> InfoRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        InfoRequest {
+            channel: channel,
+            ..InfoRequest::default()
+        }
+    }
+
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+
+    pub fn include_num_members(mut self, include_num_members: bool) -> Self {
+        self.include_num_members = Some(include_num_members);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InfoResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
+    fn into(self) -> Result<InfoResponse, InfoError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InfoError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InfoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => InfoError::ChannelNotFound,
+            "missing_scope" => InfoError::MissingScope,
+            "not_authed" => InfoError::NotAuthed,
+            "invalid_auth" => InfoError::InvalidAuth,
+            "account_inactive" => InfoError::AccountInactive,
+            "invalid_arg_name" => InfoError::InvalidArgName,
+            "invalid_array_arg" => InfoError::InvalidArrayArg,
+            "invalid_charset" => InfoError::InvalidCharset,
+            "invalid_form_data" => InfoError::InvalidFormData,
+            "invalid_post_type" => InfoError::InvalidPostType,
+            "missing_post_type" => InfoError::MissingPostType,
+            "team_added_to_org" => InfoError::TeamAddedToOrg,
+            "request_timeout" => InfoError::RequestTimeout,
+            _ => InfoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::ChannelNotFound => "channel_not_found",
+            InfoError::MissingScope => "missing_scope",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("conversations.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.info", code)
+    }
+}
+
+impl<E: Error> fmt::Display for InfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InfoError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &InfoError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &InfoError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &InfoError::NotAuthed => "not_authed: No authentication token provided.",
+            &InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &InfoError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &InfoError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &InfoError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &InfoError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &InfoError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &InfoError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &InfoError::MalformedResponse(ref e) => e.description(),
+            &InfoError::Unknown(ref s) => s,
+            &InfoError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &InfoError::MalformedResponse(ref e) => Some(e),
+            &InfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Invites users to a channel.
+///
+/// Wraps https://api.slack.com/methods/conversations.invite
+
+pub fn invite<R>(client: &R,
+                 token: &str,
+                 request: &InviteRequest)
+                 -> Result<InviteResponse, InviteError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("users".to_owned(), request.users.into());
+    if let Some(force) = request.force {
+        body.insert("force".to_owned(), force.into());
+    }
+    let url = ::get_slack_url_for_method("conversations.invite");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| InviteError::Client(err))
+        .and_then(|result| {
+                      parse_invite_response(result.body.as_bytes())
+                          .map_err(|err| err.into_error_with(InviteError::MalformedResponse, InviteError::PartialFailure))
+                  })
+}
+
+fn parse_invite_response(body: &[u8]) -> Result<InviteResponse, ::ResponseError<InviteResponseError>> {
+    let response = serde_json::from_slice::<InviteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else if response.errors.as_ref().map(|errors| !errors.is_empty()).unwrap_or(false) {
+        Err(::ResponseError::Items(response.errors.unwrap_or_default()))
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InviteRequest<'a> {
+    /// The ID of the public or private channel to invite user(s) to.
+    pub channel: &'a str,
+    /// A comma separated list of user IDs. Up to 1000 users may be listed.
+    pub users: &'a str,
+    /// When set to true and multiple user IDs are provided, continue inviting the valid ones while disregarding invalid IDs. Defaults to false.
+    pub force: Option<bool>,
+}
+
+impl<'a> InviteRequest<'a> {
+    pub fn new(channel: &'a str, users: &'a str) -> Self {
+        InviteRequest {
+            channel: channel,
+            users: users,
+            ..InviteRequest::default()
+        }
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = Some(force);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InviteResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    pub errors: Option<Vec<InviteResponseError>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InviteResponseError {
+    pub error: String,
+    pub user: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
+    fn into(self) -> Result<InviteResponse, InviteError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InviteError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The authenticated user is not in the channel.
+    NotInChannel,
+    /// Value passed for users was invalid.
+    UserNotFound,
+    /// No value was passed for users.
+    NoUser,
+    /// Authenticated user cannot invite themselves to a channel.
+    CantInviteSelf,
+    /// Invited user is already in the channel.
+    AlreadyInChannel,
+    /// Channel has been archived.
+    IsArchived,
+    /// User cannot be invited to this channel.
+    CantInvite,
+    /// URA is already in the maximum number of channels.
+    UraMaxChannels,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// Some of the items of the call failed, each for its own reason.
+    PartialFailure(Vec<InviteResponseError>),
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InviteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => InviteError::MethodNotSupportedForChannelType,
+            "channel_not_found" => InviteError::ChannelNotFound,
+            "not_in_channel" => InviteError::NotInChannel,
+            "user_not_found" => InviteError::UserNotFound,
+            "no_user" => InviteError::NoUser,
+            "cant_invite_self" => InviteError::CantInviteSelf,
+            "already_in_channel" => InviteError::AlreadyInChannel,
+            "is_archived" => InviteError::IsArchived,
+            "cant_invite" => InviteError::CantInvite,
+            "ura_max_channels" => InviteError::UraMaxChannels,
+            "user_is_restricted" => InviteError::UserIsRestricted,
+            "missing_scope" => InviteError::MissingScope,
+            "not_authed" => InviteError::NotAuthed,
+            "invalid_auth" => InviteError::InvalidAuth,
+            "account_inactive" => InviteError::AccountInactive,
+            "invalid_arg_name" => InviteError::InvalidArgName,
+            "invalid_array_arg" => InviteError::InvalidArrayArg,
+            "invalid_charset" => InviteError::InvalidCharset,
+            "invalid_form_data" => InviteError::InvalidFormData,
+            "invalid_post_type" => InviteError::InvalidPostType,
+            "missing_post_type" => InviteError::MissingPostType,
+            "team_added_to_org" => InviteError::TeamAddedToOrg,
+            "request_timeout" => InviteError::RequestTimeout,
+            _ => InviteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<InviteError<E>> for ::Error<E> {
+    fn from(err: InviteError<E>) -> Self {
+        let code = match err {
+            InviteError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            InviteError::ChannelNotFound => "channel_not_found",
+            InviteError::NotInChannel => "not_in_channel",
+            InviteError::UserNotFound => "user_not_found",
+            InviteError::NoUser => "no_user",
+            InviteError::CantInviteSelf => "cant_invite_self",
+            InviteError::AlreadyInChannel => "already_in_channel",
+            InviteError::IsArchived => "is_archived",
+            InviteError::CantInvite => "cant_invite",
+            InviteError::UraMaxChannels => "ura_max_channels",
+            InviteError::UserIsRestricted => "user_is_restricted",
+            InviteError::MissingScope => "missing_scope",
+            InviteError::NotAuthed => "not_authed",
+            InviteError::InvalidAuth => "invalid_auth",
+            InviteError::AccountInactive => "account_inactive",
+            InviteError::InvalidArgName => "invalid_arg_name",
+            InviteError::InvalidArrayArg => "invalid_array_arg",
+            InviteError::InvalidCharset => "invalid_charset",
+            InviteError::InvalidFormData => "invalid_form_data",
+            InviteError::InvalidPostType => "invalid_post_type",
+            InviteError::MissingPostType => "missing_post_type",
+            InviteError::TeamAddedToOrg => "team_added_to_org",
+            InviteError::RequestTimeout => "request_timeout",
+            InviteError::PartialFailure(errors) => {
+                return ::Error::from_code("conversations.invite", errors.first().map(|e| &e.error[..]).unwrap_or(""))
+            }
+            InviteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InviteError::Unknown(code) => return ::Error::from_code("conversations.invite", &code),
+            InviteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.invite", code)
+    }
+}
+
+impl<E: Error> fmt::Display for InviteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InviteError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &InviteError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &InviteError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &InviteError::NotInChannel => "not_in_channel: The authenticated user is not in the channel.",
+            &InviteError::UserNotFound => "user_not_found: Value passed for users was invalid.",
+            &InviteError::NoUser => "no_user: No value was passed for users.",
+            &InviteError::CantInviteSelf => "cant_invite_self: Authenticated user cannot invite themselves to a channel.",
+            &InviteError::AlreadyInChannel => "already_in_channel: Invited user is already in the channel.",
+            &InviteError::IsArchived => "is_archived: Channel has been archived.",
+            &InviteError::CantInvite => "cant_invite: User cannot be invited to this channel.",
+            &InviteError::UraMaxChannels => "ura_max_channels: URA is already in the maximum number of channels.",
+            &InviteError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &InviteError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &InviteError::NotAuthed => "not_authed: No authentication token provided.",
+            &InviteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &InviteError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &InviteError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &InviteError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &InviteError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &InviteError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &InviteError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &InviteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &InviteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &InviteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &InviteError::PartialFailure(_) => "some of the items of the call failed",
+            &InviteError::MalformedResponse(ref e) => e.description(),
+            &InviteError::Unknown(ref s) => s,
+            &InviteError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &InviteError::MalformedResponse(ref e) => Some(e),
+            &InviteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Joins an existing conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.join
+
+pub fn join<R>(client: &R,
+               token: &str,
+               request: &JoinRequest)
+               -> Result<JoinResponse, JoinError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    let url = ::get_slack_url_for_method("conversations.join");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| JoinError::Client(err))
+        .and_then(|result| {
+                      parse_join_response(result.body.as_bytes()).map_err(|err| err.into_error(JoinError::MalformedResponse))
+                  })
+}
+
+fn parse_join_response(body: &[u8]) -> Result<JoinResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<JoinResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct JoinRequest<'a> {
+    /// ID of conversation to join
+    pub channel: &'a str,
+}
+
+impl<'a> JoinRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        JoinRequest {
+            channel: channel,
+            ..JoinRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct JoinResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<JoinResponse, JoinError<E>>> for JoinResponse {
+    fn into(self) -> Result<JoinResponse, JoinError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum JoinError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has been archived.
+    IsArchived,
+    /// Too many users in the conversation.
+    TooManyUsers,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for JoinError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => JoinError::MethodNotSupportedForChannelType,
+            "channel_not_found" => JoinError::ChannelNotFound,
+            "is_archived" => JoinError::IsArchived,
+            "too_many_users" => JoinError::TooManyUsers,
+            "user_is_restricted" => JoinError::UserIsRestricted,
+            "missing_scope" => JoinError::MissingScope,
+            "not_authed" => JoinError::NotAuthed,
+            "invalid_auth" => JoinError::InvalidAuth,
+            "account_inactive" => JoinError::AccountInactive,
+            "invalid_arg_name" => JoinError::InvalidArgName,
+            "invalid_array_arg" => JoinError::InvalidArrayArg,
+            "invalid_charset" => JoinError::InvalidCharset,
+            "invalid_form_data" => JoinError::InvalidFormData,
+            "invalid_post_type" => JoinError::InvalidPostType,
+            "missing_post_type" => JoinError::MissingPostType,
+            "team_added_to_org" => JoinError::TeamAddedToOrg,
+            "request_timeout" => JoinError::RequestTimeout,
+            _ => JoinError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<JoinError<E>> for ::Error<E> {
+    fn from(err: JoinError<E>) -> Self {
+        let code = match err {
+            JoinError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            JoinError::ChannelNotFound => "channel_not_found",
+            JoinError::IsArchived => "is_archived",
+            JoinError::TooManyUsers => "too_many_users",
+            JoinError::UserIsRestricted => "user_is_restricted",
+            JoinError::MissingScope => "missing_scope",
+            JoinError::NotAuthed => "not_authed",
+            JoinError::InvalidAuth => "invalid_auth",
+            JoinError::AccountInactive => "account_inactive",
+            JoinError::InvalidArgName => "invalid_arg_name",
+            JoinError::InvalidArrayArg => "invalid_array_arg",
+            JoinError::InvalidCharset => "invalid_charset",
+            JoinError::InvalidFormData => "invalid_form_data",
+            JoinError::InvalidPostType => "invalid_post_type",
+            JoinError::MissingPostType => "missing_post_type",
+            JoinError::TeamAddedToOrg => "team_added_to_org",
+            JoinError::RequestTimeout => "request_timeout",
+            JoinError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            JoinError::Unknown(code) => return ::Error::from_code("conversations.join", &code),
+            JoinError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.join", code)
+    }
+}
+
+impl<E: Error> fmt::Display for JoinError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for JoinError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &JoinError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &JoinError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &JoinError::IsArchived => "is_archived: Channel has been archived.",
+            &JoinError::TooManyUsers => "too_many_users: Too many users in the conversation.",
+            &JoinError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &JoinError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &JoinError::NotAuthed => "not_authed: No authentication token provided.",
+            &JoinError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &JoinError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &JoinError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &JoinError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &JoinError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &JoinError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &JoinError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &JoinError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &JoinError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &JoinError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &JoinError::MalformedResponse(ref e) => e.description(),
+            &JoinError::Unknown(ref s) => s,
+            &JoinError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &JoinError::MalformedResponse(ref e) => Some(e),
+            &JoinError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Removes a user from a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.kick
+
+pub fn kick<R>(client: &R,
+               token: &str,
+               request: &KickRequest)
+               -> Result<KickResponse, KickError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("user".to_owned(), request.user.into());
+    let url = ::get_slack_url_for_method("conversations.kick");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| KickError::Client(err))
+        .and_then(|result| {
+                      parse_kick_response(result.body.as_bytes()).map_err(|err| err.into_error(KickError::MalformedResponse))
+                  })
+}
+
+fn parse_kick_response(body: &[u8]) -> Result<KickResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<KickResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct KickRequest<'a> {
+    /// ID of conversation to remove user from.
+    pub channel: &'a str,
+    /// User ID to be removed.
+    pub user: &'a str,
+}
+
+impl<'a> KickRequest<'a> {
+    pub fn new(channel: &'a str, user: &'a str) -> Self {
+        KickRequest {
+            channel: channel,
+            user: user,
+            ..KickRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KickResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<KickResponse, KickError<E>>> for KickResponse {
+    fn into(self) -> Result<KickResponse, KickError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum KickError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for user was invalid.
+    UserNotFound,
+    /// You can't remove yourself from a group.
+    CantKickSelf,
+    /// User was not in the channel.
+    NotInChannel,
+    /// User cannot be removed from #general.
+    CantKickFromGeneral,
+    /// A team preference prevents the authenticated user from kicking.
+    RestrictedAction,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for KickError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => KickError::MethodNotSupportedForChannelType,
+            "channel_not_found" => KickError::ChannelNotFound,
+            "user_not_found" => KickError::UserNotFound,
+            "cant_kick_self" => KickError::CantKickSelf,
+            "not_in_channel" => KickError::NotInChannel,
+            "cant_kick_from_general" => KickError::CantKickFromGeneral,
+            "restricted_action" => KickError::RestrictedAction,
+            "missing_scope" => KickError::MissingScope,
+            "not_authed" => KickError::NotAuthed,
+            "invalid_auth" => KickError::InvalidAuth,
+            "account_inactive" => KickError::AccountInactive,
+            "invalid_arg_name" => KickError::InvalidArgName,
+            "invalid_array_arg" => KickError::InvalidArrayArg,
+            "invalid_charset" => KickError::InvalidCharset,
+            "invalid_form_data" => KickError::InvalidFormData,
+            "invalid_post_type" => KickError::InvalidPostType,
+            "missing_post_type" => KickError::MissingPostType,
+            "team_added_to_org" => KickError::TeamAddedToOrg,
+            "request_timeout" => KickError::RequestTimeout,
+            _ => KickError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<KickError<E>> for ::Error<E> {
+    fn from(err: KickError<E>) -> Self {
+        let code = match err {
+            KickError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            KickError::ChannelNotFound => "channel_not_found",
+            KickError::UserNotFound => "user_not_found",
+            KickError::CantKickSelf => "cant_kick_self",
+            KickError::NotInChannel => "not_in_channel",
+            KickError::CantKickFromGeneral => "cant_kick_from_general",
+            KickError::RestrictedAction => "restricted_action",
+            KickError::MissingScope => "missing_scope",
+            KickError::NotAuthed => "not_authed",
+            KickError::InvalidAuth => "invalid_auth",
+            KickError::AccountInactive => "account_inactive",
+            KickError::InvalidArgName => "invalid_arg_name",
+            KickError::InvalidArrayArg => "invalid_array_arg",
+            KickError::InvalidCharset => "invalid_charset",
+            KickError::InvalidFormData => "invalid_form_data",
+            KickError::InvalidPostType => "invalid_post_type",
+            KickError::MissingPostType => "missing_post_type",
+            KickError::TeamAddedToOrg => "team_added_to_org",
+            KickError::RequestTimeout => "request_timeout",
+            KickError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            KickError::Unknown(code) => return ::Error::from_code("conversations.kick", &code),
+            KickError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.kick", code)
+    }
+}
+
+impl<E: Error> fmt::Display for KickError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for KickError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &KickError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &KickError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &KickError::UserNotFound => "user_not_found: Value passed for user was invalid.",
+            &KickError::CantKickSelf => "cant_kick_self: You can't remove yourself from a group.",
+            &KickError::NotInChannel => "not_in_channel: User was not in the channel.",
+            &KickError::CantKickFromGeneral => "cant_kick_from_general: User cannot be removed from #general.",
+            &KickError::RestrictedAction => "restricted_action: A team preference prevents the authenticated user from kicking.",
+            &KickError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &KickError::NotAuthed => "not_authed: No authentication token provided.",
+            &KickError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &KickError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &KickError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &KickError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &KickError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &KickError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &KickError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &KickError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &KickError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &KickError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &KickError::MalformedResponse(ref e) => e.description(),
+            &KickError::Unknown(ref s) => s,
+            &KickError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &KickError::MalformedResponse(ref e) => Some(e),
+            &KickError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Leaves a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.leave
+
+pub fn leave<R>(client: &R,
+                token: &str,
+                request: &LeaveRequest)
+                -> Result<LeaveResponse, LeaveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    let url = ::get_slack_url_for_method("conversations.leave");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| LeaveError::Client(err))
+        .and_then(|result| {
+                      parse_leave_response(result.body.as_bytes()).map_err(|err| err.into_error(LeaveError::MalformedResponse))
+                  })
+}
+
+fn parse_leave_response(body: &[u8]) -> Result<LeaveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<LeaveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct LeaveRequest<'a> {
+    /// Conversation to leave
+    pub channel: &'a str,
+}
+
+impl<'a> LeaveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        LeaveRequest {
+            channel: channel,
+            ..LeaveRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LeaveResponse {
+    error: Option<String>,
+    pub not_in_channel: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<LeaveResponse, LeaveError<E>>> for LeaveResponse {
+    fn into(self) -> Result<LeaveResponse, LeaveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum LeaveError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has been archived.
+    IsArchived,
+    /// Authenticated user cannot leave the general channel
+    CantLeaveGeneral,
+    /// Authenticated user is the last member of a private channel and cannot leave it.
+    LastMember,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for LeaveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => LeaveError::MethodNotSupportedForChannelType,
+            "channel_not_found" => LeaveError::ChannelNotFound,
+            "is_archived" => LeaveError::IsArchived,
+            "cant_leave_general" => LeaveError::CantLeaveGeneral,
+            "last_member" => LeaveError::LastMember,
+            "user_is_restricted" => LeaveError::UserIsRestricted,
+            "missing_scope" => LeaveError::MissingScope,
+            "not_authed" => LeaveError::NotAuthed,
+            "invalid_auth" => LeaveError::InvalidAuth,
+            "account_inactive" => LeaveError::AccountInactive,
+            "invalid_arg_name" => LeaveError::InvalidArgName,
+            "invalid_array_arg" => LeaveError::InvalidArrayArg,
+            "invalid_charset" => LeaveError::InvalidCharset,
+            "invalid_form_data" => LeaveError::InvalidFormData,
+            "invalid_post_type" => LeaveError::InvalidPostType,
+            "missing_post_type" => LeaveError::MissingPostType,
+            "team_added_to_org" => LeaveError::TeamAddedToOrg,
+            "request_timeout" => LeaveError::RequestTimeout,
+            _ => LeaveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<LeaveError<E>> for ::Error<E> {
+    fn from(err: LeaveError<E>) -> Self {
+        let code = match err {
+            LeaveError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            LeaveError::ChannelNotFound => "channel_not_found",
+            LeaveError::IsArchived => "is_archived",
+            LeaveError::CantLeaveGeneral => "cant_leave_general",
+            LeaveError::LastMember => "last_member",
+            LeaveError::UserIsRestricted => "user_is_restricted",
+            LeaveError::MissingScope => "missing_scope",
+            LeaveError::NotAuthed => "not_authed",
+            LeaveError::InvalidAuth => "invalid_auth",
+            LeaveError::AccountInactive => "account_inactive",
+            LeaveError::InvalidArgName => "invalid_arg_name",
+            LeaveError::InvalidArrayArg => "invalid_array_arg",
+            LeaveError::InvalidCharset => "invalid_charset",
+            LeaveError::InvalidFormData => "invalid_form_data",
+            LeaveError::InvalidPostType => "invalid_post_type",
+            LeaveError::MissingPostType => "missing_post_type",
+            LeaveError::TeamAddedToOrg => "team_added_to_org",
+            LeaveError::RequestTimeout => "request_timeout",
+            LeaveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            LeaveError::Unknown(code) => return ::Error::from_code("conversations.leave", &code),
+            LeaveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.leave", code)
+    }
+}
+
+impl<E: Error> fmt::Display for LeaveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for LeaveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &LeaveError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &LeaveError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &LeaveError::IsArchived => "is_archived: Channel has been archived.",
+            &LeaveError::CantLeaveGeneral => "cant_leave_general: Authenticated user cannot leave the general channel",
+            &LeaveError::LastMember => "last_member: Authenticated user is the last member of a private channel and cannot leave it.",
+            &LeaveError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &LeaveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &LeaveError::NotAuthed => "not_authed: No authentication token provided.",
+            &LeaveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &LeaveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &LeaveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &LeaveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &LeaveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &LeaveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &LeaveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &LeaveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &LeaveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &LeaveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &LeaveError::MalformedResponse(ref e) => e.description(),
+            &LeaveError::Unknown(ref s) => s,
+            &LeaveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &LeaveError::MalformedResponse(ref e) => Some(e),
+            &LeaveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Lists all channels in a Slack team.
+///
+/// Wraps https://api.slack.com/methods/conversations.list
+
+pub fn list<R>(client: &R,
+               token: &str,
+               request: &ListRequest)
+               -> Result<ListResponse, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(6);
+    params.push(("token", token));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(exclude_archived) = request.exclude_archived {
+        params.push(("exclude_archived", if exclude_archived { "1" } else { "0" }));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    if let Some(team_id) = request.team_id {
+        params.push(("team_id", team_id));
+    }
+    if let Some(types) = request.types {
+        params.push(("types", types));
+    }
+    let url = ::get_slack_url_for_method("conversations.list");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| ListError::Client(err))
+        .and_then(|result| {
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
+                  })
+}
+
+/// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `channels` of every page in turn.
+pub fn list_all<'a, R>(client: &'a R,
+                       token: &'a str,
+                       request: &ListRequest<'a>)
+                       -> ::cursor::Items<'a, ::Conversation, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = ListRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        list(client, token, &request).map(|response| {
+            (response.channels.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<ListResponse>(body, &[("channels", ::item_error::<::Conversation>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<&'a str>,
+    /// Set to true to exclude archived channels from the list
+    pub exclude_archived: Option<bool>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+    /// encoded team id to list channels in, required if token belongs to org-wide app
+    pub team_id: Option<&'a str>,
+    /// Mix and match channel types by providing a comma-separated list of any combination of public_channel, private_channel, mpim, im
+    pub types: Option<&'a str>,
+}
+
+impl<'a> ListRequest<'a> {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    pub fn types(mut self, types: &'a str) -> Self {
+        self.types = Some(types);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    pub channels: Option<Vec<::Conversation>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// Value passed for limit is not valid.
+    InvalidLimit,
+    /// Value passed for type could not be used based on the method's capabilities or the permission scopes granted to the used token.
+    InvalidTypes,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "missing_scope" => ListError::MissingScope,
+            "invalid_limit" => ListError::InvalidLimit,
+            "invalid_types" => ListError::InvalidTypes,
+            "invalid_cursor" => ListError::InvalidCursor,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::MissingScope => "missing_scope",
+            ListError::InvalidLimit => "invalid_limit",
+            ListError::InvalidTypes => "invalid_types",
+            ListError::InvalidCursor => "invalid_cursor",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("conversations.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.list", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ListError::InvalidLimit => "invalid_limit: Value passed for limit is not valid.",
+            &ListError::InvalidTypes => "invalid_types: Value passed for type could not be used based on the method's capabilities or the permission scopes granted to the used token.",
+            &ListError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &ListError::NotAuthed => "not_authed: No authentication token provided.",
+            &ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ListError::MalformedResponse(ref e) => e.description(),
+            &ListError::Unknown(ref s) => s,
+            &ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ListError::MalformedResponse(ref e) => Some(e),
+            &ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Sets the read cursor in a channel.
+///
+/// Wraps https://api.slack.com/methods/conversations.mark
+
+pub fn mark<R>(client: &R,
+               token: &str,
+               request: &MarkRequest)
+               -> Result<MarkResponse, MarkError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("ts".to_owned(), request.ts.into());
+    let url = ::get_slack_url_for_method("conversations.mark");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| MarkError::Client(err))
+        .and_then(|result| {
+                      parse_mark_response(result.body.as_bytes()).map_err(|err| err.into_error(MarkError::MalformedResponse))
+                  })
+}
+
+fn parse_mark_response(body: &[u8]) -> Result<MarkResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<MarkResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct MarkRequest<'a> {
+    /// Channel or conversation to set the read cursor for.
+    pub channel: &'a str,
+    /// Unique identifier of message you want marked as most recently seen in this conversation.
+    pub ts: &'a str,
+}
+
+impl<'a> MarkRequest<'a> {
+    pub fn new(channel: &'a str, ts: &'a str) -> Self {
+        MarkRequest {
+            channel: channel,
+            ts: ts,
+            ..MarkRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarkResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<MarkResponse, MarkError<E>>> for MarkResponse {
+    fn into(self) -> Result<MarkResponse, MarkError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum MarkError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for timestamp was invalid.
+    InvalidTimestamp,
+    /// Caller is not a member of the channel.
+    NotInChannel,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for MarkError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => MarkError::MethodNotSupportedForChannelType,
+            "channel_not_found" => MarkError::ChannelNotFound,
+            "invalid_timestamp" => MarkError::InvalidTimestamp,
+            "not_in_channel" => MarkError::NotInChannel,
+            "missing_scope" => MarkError::MissingScope,
+            "not_authed" => MarkError::NotAuthed,
+            "invalid_auth" => MarkError::InvalidAuth,
+            "account_inactive" => MarkError::AccountInactive,
+            "invalid_arg_name" => MarkError::InvalidArgName,
+            "invalid_array_arg" => MarkError::InvalidArrayArg,
+            "invalid_charset" => MarkError::InvalidCharset,
+            "invalid_form_data" => MarkError::InvalidFormData,
+            "invalid_post_type" => MarkError::InvalidPostType,
+            "missing_post_type" => MarkError::MissingPostType,
+            "team_added_to_org" => MarkError::TeamAddedToOrg,
+            "request_timeout" => MarkError::RequestTimeout,
+            _ => MarkError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<MarkError<E>> for ::Error<E> {
+    fn from(err: MarkError<E>) -> Self {
+        let code = match err {
+            MarkError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            MarkError::ChannelNotFound => "channel_not_found",
+            MarkError::InvalidTimestamp => "invalid_timestamp",
+            MarkError::NotInChannel => "not_in_channel",
+            MarkError::MissingScope => "missing_scope",
+            MarkError::NotAuthed => "not_authed",
+            MarkError::InvalidAuth => "invalid_auth",
+            MarkError::AccountInactive => "account_inactive",
+            MarkError::InvalidArgName => "invalid_arg_name",
+            MarkError::InvalidArrayArg => "invalid_array_arg",
+            MarkError::InvalidCharset => "invalid_charset",
+            MarkError::InvalidFormData => "invalid_form_data",
+            MarkError::InvalidPostType => "invalid_post_type",
+            MarkError::MissingPostType => "missing_post_type",
+            MarkError::TeamAddedToOrg => "team_added_to_org",
+            MarkError::RequestTimeout => "request_timeout",
+            MarkError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            MarkError::Unknown(code) => return ::Error::from_code("conversations.mark", &code),
+            MarkError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.mark", code)
+    }
+}
+
+impl<E: Error> fmt::Display for MarkError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for MarkError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &MarkError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &MarkError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &MarkError::InvalidTimestamp => "invalid_timestamp: Value passed for timestamp was invalid.",
+            &MarkError::NotInChannel => "not_in_channel: Caller is not a member of the channel.",
+            &MarkError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &MarkError::NotAuthed => "not_authed: No authentication token provided.",
+            &MarkError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &MarkError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &MarkError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &MarkError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &MarkError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &MarkError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &MarkError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &MarkError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &MarkError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &MarkError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &MarkError::MalformedResponse(ref e) => e.description(),
+            &MarkError::Unknown(ref s) => s,
+            &MarkError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &MarkError::MalformedResponse(ref e) => Some(e),
+            &MarkError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve members of a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.members
+
+pub fn members<R>(client: &R,
+                  token: &str,
+                  request: &MembersRequest)
+                  -> Result<MembersResponse, MembersError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    let url = ::get_slack_url_for_method("conversations.members");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| MembersError::Client(err))
+        .and_then(|result| {
+                      parse_members_response(&result).map_err(|err| err.into_error(MembersError::MalformedResponse))
+                  })
+}
+
+/// Like [`members`](fn.members.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `members` of every page in turn.
+pub fn members_all<'a, R>(client: &'a R,
+                          token: &'a str,
+                          request: &MembersRequest<'a>)
+                          -> ::cursor::Items<'a, String, MembersError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = MembersRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        members(client, token, &request).map(|response| {
+            (response.members.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_members_response(body: &[u8]) -> Result<MembersResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<MembersResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct MembersRequest<'a> {
+    /// ID of the conversation to retrieve members for
+    pub channel: &'a str,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<&'a str>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+impl<'a> MembersRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        MembersRequest {
+            channel: channel,
+            ..MembersRequest::default()
+        }
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MembersResponse {
+    error: Option<String>,
+    pub members: Option<Vec<String>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<MembersResponse, MembersError<E>>> for MembersResponse {
+    fn into(self) -> Result<MembersResponse, MembersError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum MembersError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for limit is not valid.
+    InvalidLimit,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for MembersError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => MembersError::ChannelNotFound,
+            "invalid_limit" => MembersError::InvalidLimit,
+            "invalid_cursor" => MembersError::InvalidCursor,
+            "missing_scope" => MembersError::MissingScope,
+            "not_authed" => MembersError::NotAuthed,
+            "invalid_auth" => MembersError::InvalidAuth,
+            "account_inactive" => MembersError::AccountInactive,
+            "invalid_arg_name" => MembersError::InvalidArgName,
+            "invalid_array_arg" => MembersError::InvalidArrayArg,
+            "invalid_charset" => MembersError::InvalidCharset,
+            "invalid_form_data" => MembersError::InvalidFormData,
+            "invalid_post_type" => MembersError::InvalidPostType,
+            "missing_post_type" => MembersError::MissingPostType,
+            "team_added_to_org" => MembersError::TeamAddedToOrg,
+            "request_timeout" => MembersError::RequestTimeout,
+            _ => MembersError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<MembersError<E>> for ::Error<E> {
+    fn from(err: MembersError<E>) -> Self {
+        let code = match err {
+            MembersError::ChannelNotFound => "channel_not_found",
+            MembersError::InvalidLimit => "invalid_limit",
+            MembersError::InvalidCursor => "invalid_cursor",
+            MembersError::MissingScope => "missing_scope",
+            MembersError::NotAuthed => "not_authed",
+            MembersError::InvalidAuth => "invalid_auth",
+            MembersError::AccountInactive => "account_inactive",
+            MembersError::InvalidArgName => "invalid_arg_name",
+            MembersError::InvalidArrayArg => "invalid_array_arg",
+            MembersError::InvalidCharset => "invalid_charset",
+            MembersError::InvalidFormData => "invalid_form_data",
+            MembersError::InvalidPostType => "invalid_post_type",
+            MembersError::MissingPostType => "missing_post_type",
+            MembersError::TeamAddedToOrg => "team_added_to_org",
+            MembersError::RequestTimeout => "request_timeout",
+            MembersError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            MembersError::Unknown(code) => return ::Error::from_code("conversations.members", &code),
+            MembersError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.members", code)
+    }
+}
+
+impl<E: Error> fmt::Display for MembersError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for MembersError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &MembersError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &MembersError::InvalidLimit => "invalid_limit: Value passed for limit is not valid.",
+            &MembersError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &MembersError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &MembersError::NotAuthed => "not_authed: No authentication token provided.",
+            &MembersError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &MembersError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &MembersError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &MembersError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &MembersError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &MembersError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &MembersError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &MembersError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &MembersError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &MembersError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &MembersError::MalformedResponse(ref e) => e.description(),
+            &MembersError::Unknown(ref s) => s,
+            &MembersError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &MembersError::MalformedResponse(ref e) => Some(e),
+            &MembersError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Opens or resumes a direct message or multi-person direct message.
+///
+/// Wraps https://api.slack.com/methods/conversations.open
+
+pub fn open<R>(client: &R,
+               token: &str,
+               request: &OpenRequest)
+               -> Result<OpenResponse, OpenError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(channel) = request.channel {
+        body.insert("channel".to_owned(), channel.into());
+    }
+    if let Some(prevent_creation) = request.prevent_creation {
+        body.insert("prevent_creation".to_owned(), prevent_creation.into());
+    }
+    if let Some(return_im) = request.return_im {
+        body.insert("return_im".to_owned(), return_im.into());
+    }
+    if let Some(users) = request.users {
+        body.insert("users".to_owned(), users.into());
+    }
+    let url = ::get_slack_url_for_method("conversations.open");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| OpenError::Client(err))
+        .and_then(|result| {
+                      parse_open_response(result.body.as_bytes()).map_err(|err| err.into_error(OpenError::MalformedResponse))
+                  })
+}
+
+fn parse_open_response(body: &[u8]) -> Result<OpenResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<OpenResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct OpenRequest<'a> {
+    /// Resume a conversation by supplying an im or mpim's ID. Or provide the users field instead.
+    pub channel: Option<&'a str>,
+    /// Do not create a direct message or multi-person direct message. This is used to see if there is an existing dm or mpdm.
+    pub prevent_creation: Option<bool>,
+    /// Boolean, indicates you want the full IM channel definition in the response.
+    pub return_im: Option<bool>,
+    /// Comma separated lists of users. If only one user is included, this creates a 1:1 DM. The ordering of the users is preserved whenever a multi-person direct message is returned. Supply a channel when not supplying users.
+    pub users: Option<&'a str>,
+}
+
+impl<'a> OpenRequest<'a> {
+    pub fn new() -> Self {
+        OpenRequest::default()
+    }
+
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    pub fn prevent_creation(mut self, prevent_creation: bool) -> Self {
+        self.prevent_creation = Some(prevent_creation);
+        self
+    }
+
+    pub fn return_im(mut self, return_im: bool) -> Self {
+        self.return_im = Some(return_im);
+        self
+    }
+
+    pub fn users(mut self, users: &'a str) -> Self {
+        self.users = Some(users);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OpenResponse {
+    pub already_open: Option<bool>,
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    pub no_op: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
+    fn into(self) -> Result<OpenResponse, OpenError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum OpenError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// One of the users provided could not be found.
+    UserNotFound,
+    /// The calling user is restricted from seeing the requested user.
+    UserNotVisible,
+    /// The user has been disabled.
+    UserDisabled,
+    /// Missing users in the request.
+    UsersListNotSupplied,
+    /// Needs at least 2 users to open.
+    NotEnoughUsers,
+    /// Needs at most 8 users to open.
+    TooManyUsers,
+    /// Bots cannot be messaged directly.
+    CannotDmBot,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for OpenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => OpenError::MethodNotSupportedForChannelType,
+            "channel_not_found" => OpenError::ChannelNotFound,
+            "user_not_found" => OpenError::UserNotFound,
+            "user_not_visible" => OpenError::UserNotVisible,
+            "user_disabled" => OpenError::UserDisabled,
+            "users_list_not_supplied" => OpenError::UsersListNotSupplied,
+            "not_enough_users" => OpenError::NotEnoughUsers,
+            "too_many_users" => OpenError::TooManyUsers,
+            "cannot_dm_bot" => OpenError::CannotDmBot,
+            "missing_scope" => OpenError::MissingScope,
+            "not_authed" => OpenError::NotAuthed,
+            "invalid_auth" => OpenError::InvalidAuth,
+            "account_inactive" => OpenError::AccountInactive,
+            "invalid_arg_name" => OpenError::InvalidArgName,
+            "invalid_array_arg" => OpenError::InvalidArrayArg,
+            "invalid_charset" => OpenError::InvalidCharset,
+            "invalid_form_data" => OpenError::InvalidFormData,
+            "invalid_post_type" => OpenError::InvalidPostType,
+            "missing_post_type" => OpenError::MissingPostType,
+            "team_added_to_org" => OpenError::TeamAddedToOrg,
+            "request_timeout" => OpenError::RequestTimeout,
+            _ => OpenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<OpenError<E>> for ::Error<E> {
+    fn from(err: OpenError<E>) -> Self {
+        let code = match err {
+            OpenError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            OpenError::ChannelNotFound => "channel_not_found",
+            OpenError::UserNotFound => "user_not_found",
+            OpenError::UserNotVisible => "user_not_visible",
+            OpenError::UserDisabled => "user_disabled",
+            OpenError::UsersListNotSupplied => "users_list_not_supplied",
+            OpenError::NotEnoughUsers => "not_enough_users",
+            OpenError::TooManyUsers => "too_many_users",
+            OpenError::CannotDmBot => "cannot_dm_bot",
+            OpenError::MissingScope => "missing_scope",
+            OpenError::NotAuthed => "not_authed",
+            OpenError::InvalidAuth => "invalid_auth",
+            OpenError::AccountInactive => "account_inactive",
+            OpenError::InvalidArgName => "invalid_arg_name",
+            OpenError::InvalidArrayArg => "invalid_array_arg",
+            OpenError::InvalidCharset => "invalid_charset",
+            OpenError::InvalidFormData => "invalid_form_data",
+            OpenError::InvalidPostType => "invalid_post_type",
+            OpenError::MissingPostType => "missing_post_type",
+            OpenError::TeamAddedToOrg => "team_added_to_org",
+            OpenError::RequestTimeout => "request_timeout",
+            OpenError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            OpenError::Unknown(code) => return ::Error::from_code("conversations.open", &code),
+            OpenError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.open", code)
+    }
+}
+
+impl<E: Error> fmt::Display for OpenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for OpenError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &OpenError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &OpenError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &OpenError::UserNotFound => "user_not_found: One of the users provided could not be found.",
+            &OpenError::UserNotVisible => "user_not_visible: The calling user is restricted from seeing the requested user.",
+            &OpenError::UserDisabled => "user_disabled: The user has been disabled.",
+            &OpenError::UsersListNotSupplied => "users_list_not_supplied: Missing users in the request.",
+            &OpenError::NotEnoughUsers => "not_enough_users: Needs at least 2 users to open.",
+            &OpenError::TooManyUsers => "too_many_users: Needs at most 8 users to open.",
+            &OpenError::CannotDmBot => "cannot_dm_bot: Bots cannot be messaged directly.",
+            &OpenError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &OpenError::NotAuthed => "not_authed: No authentication token provided.",
+            &OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &OpenError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &OpenError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &OpenError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &OpenError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &OpenError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &OpenError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &OpenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &OpenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &OpenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &OpenError::MalformedResponse(ref e) => e.description(),
+            &OpenError::Unknown(ref s) => s,
+            &OpenError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &OpenError::MalformedResponse(ref e) => Some(e),
+            &OpenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Renames a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.rename
+
+pub fn rename<R>(client: &R,
+                 token: &str,
+                 request: &RenameRequest)
+                 -> Result<RenameResponse, RenameError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("name".to_owned(), request.name.into());
+    let url = ::get_slack_url_for_method("conversations.rename");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| RenameError::Client(err))
+        .and_then(|result| {
+                      parse_rename_response(result.body.as_bytes()).map_err(|err| err.into_error(RenameError::MalformedResponse))
+                  })
+}
+
+fn parse_rename_response(body: &[u8]) -> Result<RenameResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RenameResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RenameRequest<'a> {
+    /// ID of conversation to rename
+    pub channel: &'a str,
+    /// New name for conversation.
+    pub name: &'a str,
+}
+
+impl<'a> RenameRequest<'a> {
+    pub fn new(channel: &'a str, name: &'a str) -> Self {
+        RenameRequest {
+            channel: channel,
+            name: name,
+            ..RenameRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RenameResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
+    fn into(self) -> Result<RenameResponse, RenameError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RenameError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Caller is not a member of the channel.
+    NotInChannel,
+    /// Caller cannot rename this channel.
+    NotAuthorized,
+    /// New name is invalid
+    InvalidName,
+    /// New channel name is taken
+    NameTaken,
+    /// Value passed for name was empty.
+    InvalidNameRequired,
+    /// Value passed for name was only punctuation.
+    InvalidNamePunctuation,
+    /// Value passed for name exceeded max length.
+    InvalidNameMaxlength,
+    /// Value passed for name contained unallowed special characters or upper case characters.
+    InvalidNameSpecials,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RenameError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => RenameError::MethodNotSupportedForChannelType,
+            "channel_not_found" => RenameError::ChannelNotFound,
+            "not_in_channel" => RenameError::NotInChannel,
+            "not_authorized" => RenameError::NotAuthorized,
+            "invalid_name" => RenameError::InvalidName,
+            "name_taken" => RenameError::NameTaken,
+            "invalid_name_required" => RenameError::InvalidNameRequired,
+            "invalid_name_punctuation" => RenameError::InvalidNamePunctuation,
+            "invalid_name_maxlength" => RenameError::InvalidNameMaxlength,
+            "invalid_name_specials" => RenameError::InvalidNameSpecials,
+            "user_is_restricted" => RenameError::UserIsRestricted,
+            "missing_scope" => RenameError::MissingScope,
+            "not_authed" => RenameError::NotAuthed,
+            "invalid_auth" => RenameError::InvalidAuth,
+            "account_inactive" => RenameError::AccountInactive,
+            "invalid_arg_name" => RenameError::InvalidArgName,
+            "invalid_array_arg" => RenameError::InvalidArrayArg,
+            "invalid_charset" => RenameError::InvalidCharset,
+            "invalid_form_data" => RenameError::InvalidFormData,
+            "invalid_post_type" => RenameError::InvalidPostType,
+            "missing_post_type" => RenameError::MissingPostType,
+            "team_added_to_org" => RenameError::TeamAddedToOrg,
+            "request_timeout" => RenameError::RequestTimeout,
+            _ => RenameError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RenameError<E>> for ::Error<E> {
+    fn from(err: RenameError<E>) -> Self {
+        let code = match err {
+            RenameError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            RenameError::ChannelNotFound => "channel_not_found",
+            RenameError::NotInChannel => "not_in_channel",
+            RenameError::NotAuthorized => "not_authorized",
+            RenameError::InvalidName => "invalid_name",
+            RenameError::NameTaken => "name_taken",
+            RenameError::InvalidNameRequired => "invalid_name_required",
+            RenameError::InvalidNamePunctuation => "invalid_name_punctuation",
+            RenameError::InvalidNameMaxlength => "invalid_name_maxlength",
+            RenameError::InvalidNameSpecials => "invalid_name_specials",
+            RenameError::UserIsRestricted => "user_is_restricted",
+            RenameError::MissingScope => "missing_scope",
+            RenameError::NotAuthed => "not_authed",
+            RenameError::InvalidAuth => "invalid_auth",
+            RenameError::AccountInactive => "account_inactive",
+            RenameError::InvalidArgName => "invalid_arg_name",
+            RenameError::InvalidArrayArg => "invalid_array_arg",
+            RenameError::InvalidCharset => "invalid_charset",
+            RenameError::InvalidFormData => "invalid_form_data",
+            RenameError::InvalidPostType => "invalid_post_type",
+            RenameError::MissingPostType => "missing_post_type",
+            RenameError::TeamAddedToOrg => "team_added_to_org",
+            RenameError::RequestTimeout => "request_timeout",
+            RenameError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RenameError::Unknown(code) => return ::Error::from_code("conversations.rename", &code),
+            RenameError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.rename", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RenameError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RenameError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RenameError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &RenameError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &RenameError::NotInChannel => "not_in_channel: Caller is not a member of the channel.",
+            &RenameError::NotAuthorized => "not_authorized: Caller cannot rename this channel.",
+            &RenameError::InvalidName => "invalid_name: New name is invalid",
+            &RenameError::NameTaken => "name_taken: New channel name is taken",
+            &RenameError::InvalidNameRequired => "invalid_name_required: Value passed for name was empty.",
+            &RenameError::InvalidNamePunctuation => "invalid_name_punctuation: Value passed for name was only punctuation.",
+            &RenameError::InvalidNameMaxlength => "invalid_name_maxlength: Value passed for name exceeded max length.",
+            &RenameError::InvalidNameSpecials => "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters.",
+            &RenameError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &RenameError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RenameError::NotAuthed => "not_authed: No authentication token provided.",
+            &RenameError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RenameError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RenameError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RenameError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RenameError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RenameError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RenameError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RenameError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RenameError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RenameError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RenameError::MalformedResponse(ref e) => e.description(),
+            &RenameError::Unknown(ref s) => s,
+            &RenameError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RenameError::MalformedResponse(ref e) => Some(e),
+            &RenameError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve a thread of messages posted to a conversation
+///
+/// Wraps https://api.slack.com/methods/conversations.replies
+
+pub fn replies<R>(client: &R,
+                  token: &str,
+                  request: &RepliesRequest)
+                  -> Result<RepliesResponse, RepliesError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(9);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    params.push(("ts", request.ts));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(include_all_metadata) = request.include_all_metadata {
+        params.push(("include_all_metadata", if include_all_metadata { "1" } else { "0" }));
+    }
+    if let Some(inclusive) = request.inclusive {
+        params.push(("inclusive", if inclusive { "1" } else { "0" }));
+    }
+    if let Some(latest) = request.latest {
+        params.push(("latest", latest));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    if let Some(oldest) = request.oldest {
+        params.push(("oldest", oldest));
+    }
+    let url = ::get_slack_url_for_method("conversations.replies");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| RepliesError::Client(err))
+        .and_then(|result| {
+                      parse_replies_response(&result).map_err(|err| err.into_error(RepliesError::MalformedResponse))
+                  })
+}
+
+/// Like [`replies`](fn.replies.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `messages` of every page in turn.
+pub fn replies_all<'a, R>(client: &'a R,
+                          token: &'a str,
+                          request: &RepliesRequest<'a>)
+                          -> ::cursor::Items<'a, ::Message, RepliesError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = RepliesRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        replies(client, token, &request).map(|response| {
+            (response.messages.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_replies_response(body: &[u8]) -> Result<RepliesResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<RepliesResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RepliesRequest<'a> {
+    /// Conversation ID to fetch thread from.
+    pub channel: &'a str,
+    /// Unique identifier of either a thread's parent message or a message in the thread. ts must be the timestamp of an existing message with 0 or more replies. If there are no replies then just the single message referenced by ts will return - it is just an ordinary, unthreaded message.
+    pub ts: &'a str,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<&'a str>,
+    /// Return all metadata associated with this message.
+    pub include_all_metadata: Option<bool>,
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results.
+    pub latest: Option<&'a str>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<&'a str>,
+}
+
+impl<'a> RepliesRequest<'a> {
+    pub fn new(channel: &'a str, ts: &'a str) -> Self {
+        RepliesRequest {
+            channel: channel,
+            ts: ts,
+            ..RepliesRequest::default()
+        }
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn include_all_metadata(mut self, include_all_metadata: bool) -> Self {
+        self.include_all_metadata = Some(include_all_metadata);
+        self
+    }
+
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    pub fn latest(mut self, latest: &'a str) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: &'a str) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RepliesResponse {
+    error: Option<String>,
+    pub has_more: Option<bool>,
+    pub messages: Option<Vec<::Message>>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<RepliesResponse, RepliesError<E>>> for RepliesResponse {
+    fn into(self) -> Result<RepliesResponse, RepliesError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RepliesError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value for ts was missing or invalid.
+    ThreadNotFound,
+    /// The bot is not a member of the channel.
+    NotInChannel,
+    /// Value passed for latest was invalid
+    InvalidTsLatest,
+    /// Value passed for oldest was invalid
+    InvalidTsOldest,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RepliesError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => RepliesError::ChannelNotFound,
+            "thread_not_found" => RepliesError::ThreadNotFound,
+            "not_in_channel" => RepliesError::NotInChannel,
+            "invalid_ts_latest" => RepliesError::InvalidTsLatest,
+            "invalid_ts_oldest" => RepliesError::InvalidTsOldest,
+            "invalid_cursor" => RepliesError::InvalidCursor,
+            "missing_scope" => RepliesError::MissingScope,
+            "not_authed" => RepliesError::NotAuthed,
+            "invalid_auth" => RepliesError::InvalidAuth,
+            "account_inactive" => RepliesError::AccountInactive,
+            "invalid_arg_name" => RepliesError::InvalidArgName,
+            "invalid_array_arg" => RepliesError::InvalidArrayArg,
+            "invalid_charset" => RepliesError::InvalidCharset,
+            "invalid_form_data" => RepliesError::InvalidFormData,
+            "invalid_post_type" => RepliesError::InvalidPostType,
+            "missing_post_type" => RepliesError::MissingPostType,
+            "team_added_to_org" => RepliesError::TeamAddedToOrg,
+            "request_timeout" => RepliesError::RequestTimeout,
+            _ => RepliesError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<RepliesError<E>> for ::Error<E> {
+    fn from(err: RepliesError<E>) -> Self {
+        let code = match err {
+            RepliesError::ChannelNotFound => "channel_not_found",
+            RepliesError::ThreadNotFound => "thread_not_found",
+            RepliesError::NotInChannel => "not_in_channel",
+            RepliesError::InvalidTsLatest => "invalid_ts_latest",
+            RepliesError::InvalidTsOldest => "invalid_ts_oldest",
+            RepliesError::InvalidCursor => "invalid_cursor",
+            RepliesError::MissingScope => "missing_scope",
+            RepliesError::NotAuthed => "not_authed",
+            RepliesError::InvalidAuth => "invalid_auth",
+            RepliesError::AccountInactive => "account_inactive",
+            RepliesError::InvalidArgName => "invalid_arg_name",
+            RepliesError::InvalidArrayArg => "invalid_array_arg",
+            RepliesError::InvalidCharset => "invalid_charset",
+            RepliesError::InvalidFormData => "invalid_form_data",
+            RepliesError::InvalidPostType => "invalid_post_type",
+            RepliesError::MissingPostType => "missing_post_type",
+            RepliesError::TeamAddedToOrg => "team_added_to_org",
+            RepliesError::RequestTimeout => "request_timeout",
+            RepliesError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RepliesError::Unknown(code) => return ::Error::from_code("conversations.replies", &code),
+            RepliesError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.replies", code)
+    }
+}
+
+impl<E: Error> fmt::Display for RepliesError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RepliesError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RepliesError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &RepliesError::ThreadNotFound => "thread_not_found: Value for ts was missing or invalid.",
+            &RepliesError::NotInChannel => "not_in_channel: The bot is not a member of the channel.",
+            &RepliesError::InvalidTsLatest => "invalid_ts_latest: Value passed for latest was invalid",
+            &RepliesError::InvalidTsOldest => "invalid_ts_oldest: Value passed for oldest was invalid",
+            &RepliesError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+            &RepliesError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &RepliesError::NotAuthed => "not_authed: No authentication token provided.",
+            &RepliesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &RepliesError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &RepliesError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &RepliesError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &RepliesError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &RepliesError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &RepliesError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &RepliesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &RepliesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &RepliesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &RepliesError::MalformedResponse(ref e) => e.description(),
+            &RepliesError::Unknown(ref s) => s,
+            &RepliesError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RepliesError::MalformedResponse(ref e) => Some(e),
+            &RepliesError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Sets the purpose for a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.setPurpose
+
+pub fn set_purpose<R>(client: &R,
+                      token: &str,
+                      request: &SetPurposeRequest)
+                      -> Result<SetPurposeResponse, SetPurposeError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("purpose".to_owned(), request.purpose.into());
+    let url = ::get_slack_url_for_method("conversations.setPurpose");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| SetPurposeError::Client(err))
+        .and_then(|result| {
+                      parse_set_purpose_response(result.body.as_bytes()).map_err(|err| err.into_error(SetPurposeError::MalformedResponse))
+                  })
+}
+
+fn parse_set_purpose_response(body: &[u8]) -> Result<SetPurposeResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetPurposeResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetPurposeRequest<'a> {
+    /// Conversation to set the purpose of
+    pub channel: &'a str,
+    /// A new, specialer purpose
+    pub purpose: &'a str,
+}
+
+impl<'a> SetPurposeRequest<'a> {
+    pub fn new(channel: &'a str, purpose: &'a str) -> Self {
+        SetPurposeRequest {
+            channel: channel,
+            purpose: purpose,
+            ..SetPurposeRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetPurposeResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SetPurposeResponse, SetPurposeError<E>>> for SetPurposeResponse {
+    fn into(self) -> Result<SetPurposeResponse, SetPurposeError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetPurposeError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Authenticated user is not in the channel.
+    NotInChannel,
+    /// Channel has been archived.
+    IsArchived,
+    /// Purpose was longer than 250 characters.
+    TooLong,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetPurposeError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => SetPurposeError::MethodNotSupportedForChannelType,
+            "channel_not_found" => SetPurposeError::ChannelNotFound,
+            "not_in_channel" => SetPurposeError::NotInChannel,
+            "is_archived" => SetPurposeError::IsArchived,
+            "too_long" => SetPurposeError::TooLong,
+            "user_is_restricted" => SetPurposeError::UserIsRestricted,
+            "missing_scope" => SetPurposeError::MissingScope,
+            "not_authed" => SetPurposeError::NotAuthed,
+            "invalid_auth" => SetPurposeError::InvalidAuth,
+            "account_inactive" => SetPurposeError::AccountInactive,
+            "invalid_arg_name" => SetPurposeError::InvalidArgName,
+            "invalid_array_arg" => SetPurposeError::InvalidArrayArg,
+            "invalid_charset" => SetPurposeError::InvalidCharset,
+            "invalid_form_data" => SetPurposeError::InvalidFormData,
+            "invalid_post_type" => SetPurposeError::InvalidPostType,
+            "missing_post_type" => SetPurposeError::MissingPostType,
+            "team_added_to_org" => SetPurposeError::TeamAddedToOrg,
+            "request_timeout" => SetPurposeError::RequestTimeout,
+            _ => SetPurposeError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SetPurposeError<E>> for ::Error<E> {
+    fn from(err: SetPurposeError<E>) -> Self {
+        let code = match err {
+            SetPurposeError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            SetPurposeError::ChannelNotFound => "channel_not_found",
+            SetPurposeError::NotInChannel => "not_in_channel",
+            SetPurposeError::IsArchived => "is_archived",
+            SetPurposeError::TooLong => "too_long",
+            SetPurposeError::UserIsRestricted => "user_is_restricted",
+            SetPurposeError::MissingScope => "missing_scope",
+            SetPurposeError::NotAuthed => "not_authed",
+            SetPurposeError::InvalidAuth => "invalid_auth",
+            SetPurposeError::AccountInactive => "account_inactive",
+            SetPurposeError::InvalidArgName => "invalid_arg_name",
+            SetPurposeError::InvalidArrayArg => "invalid_array_arg",
+            SetPurposeError::InvalidCharset => "invalid_charset",
+            SetPurposeError::InvalidFormData => "invalid_form_data",
+            SetPurposeError::InvalidPostType => "invalid_post_type",
+            SetPurposeError::MissingPostType => "missing_post_type",
+            SetPurposeError::TeamAddedToOrg => "team_added_to_org",
+            SetPurposeError::RequestTimeout => "request_timeout",
+            SetPurposeError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetPurposeError::Unknown(code) => return ::Error::from_code("conversations.setPurpose", &code),
+            SetPurposeError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.setPurpose", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SetPurposeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetPurposeError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SetPurposeError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &SetPurposeError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &SetPurposeError::NotInChannel => "not_in_channel: Authenticated user is not in the channel.",
+            &SetPurposeError::IsArchived => "is_archived: Channel has been archived.",
+            &SetPurposeError::TooLong => "too_long: Purpose was longer than 250 characters.",
+            &SetPurposeError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &SetPurposeError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &SetPurposeError::NotAuthed => "not_authed: No authentication token provided.",
+            &SetPurposeError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SetPurposeError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SetPurposeError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SetPurposeError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SetPurposeError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SetPurposeError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SetPurposeError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SetPurposeError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SetPurposeError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SetPurposeError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SetPurposeError::MalformedResponse(ref e) => e.description(),
+            &SetPurposeError::Unknown(ref s) => s,
+            &SetPurposeError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SetPurposeError::MalformedResponse(ref e) => Some(e),
+            &SetPurposeError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Sets the topic for a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.setTopic
+
+pub fn set_topic<R>(client: &R,
+                    token: &str,
+                    request: &SetTopicRequest)
+                    -> Result<SetTopicResponse, SetTopicError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    body.insert("topic".to_owned(), request.topic.into());
+    let url = ::get_slack_url_for_method("conversations.setTopic");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| SetTopicError::Client(err))
+        .and_then(|result| {
+                      parse_set_topic_response(result.body.as_bytes()).map_err(|err| err.into_error(SetTopicError::MalformedResponse))
+                  })
+}
+
+fn parse_set_topic_response(body: &[u8]) -> Result<SetTopicResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetTopicResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetTopicRequest<'a> {
+    /// Conversation to set the topic of
+    pub channel: &'a str,
+    /// The new topic string. Does not support formatting or linkification.
+    pub topic: &'a str,
+}
+
+impl<'a> SetTopicRequest<'a> {
+    pub fn new(channel: &'a str, topic: &'a str) -> Self {
+        SetTopicRequest {
+            channel: channel,
+            topic: topic,
+            ..SetTopicRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetTopicResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SetTopicResponse, SetTopicError<E>>> for SetTopicResponse {
+    fn into(self) -> Result<SetTopicResponse, SetTopicError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetTopicError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Authenticated user is not in the channel.
+    NotInChannel,
+    /// Channel has been archived.
+    IsArchived,
+    /// Topic was longer than 250 characters.
+    TooLong,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetTopicError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => SetTopicError::MethodNotSupportedForChannelType,
+            "channel_not_found" => SetTopicError::ChannelNotFound,
+            "not_in_channel" => SetTopicError::NotInChannel,
+            "is_archived" => SetTopicError::IsArchived,
+            "too_long" => SetTopicError::TooLong,
+            "user_is_restricted" => SetTopicError::UserIsRestricted,
+            "missing_scope" => SetTopicError::MissingScope,
+            "not_authed" => SetTopicError::NotAuthed,
+            "invalid_auth" => SetTopicError::InvalidAuth,
+            "account_inactive" => SetTopicError::AccountInactive,
+            "invalid_arg_name" => SetTopicError::InvalidArgName,
+            "invalid_array_arg" => SetTopicError::InvalidArrayArg,
+            "invalid_charset" => SetTopicError::InvalidCharset,
+            "invalid_form_data" => SetTopicError::InvalidFormData,
+            "invalid_post_type" => SetTopicError::InvalidPostType,
+            "missing_post_type" => SetTopicError::MissingPostType,
+            "team_added_to_org" => SetTopicError::TeamAddedToOrg,
+            "request_timeout" => SetTopicError::RequestTimeout,
+            _ => SetTopicError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SetTopicError<E>> for ::Error<E> {
+    fn from(err: SetTopicError<E>) -> Self {
+        let code = match err {
+            SetTopicError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            SetTopicError::ChannelNotFound => "channel_not_found",
+            SetTopicError::NotInChannel => "not_in_channel",
+            SetTopicError::IsArchived => "is_archived",
+            SetTopicError::TooLong => "too_long",
+            SetTopicError::UserIsRestricted => "user_is_restricted",
+            SetTopicError::MissingScope => "missing_scope",
+            SetTopicError::NotAuthed => "not_authed",
+            SetTopicError::InvalidAuth => "invalid_auth",
+            SetTopicError::AccountInactive => "account_inactive",
+            SetTopicError::InvalidArgName => "invalid_arg_name",
+            SetTopicError::InvalidArrayArg => "invalid_array_arg",
+            SetTopicError::InvalidCharset => "invalid_charset",
+            SetTopicError::InvalidFormData => "invalid_form_data",
+            SetTopicError::InvalidPostType => "invalid_post_type",
+            SetTopicError::MissingPostType => "missing_post_type",
+            SetTopicError::TeamAddedToOrg => "team_added_to_org",
+            SetTopicError::RequestTimeout => "request_timeout",
+            SetTopicError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetTopicError::Unknown(code) => return ::Error::from_code("conversations.setTopic", &code),
+            SetTopicError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.setTopic", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SetTopicError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetTopicError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SetTopicError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &SetTopicError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &SetTopicError::NotInChannel => "not_in_channel: Authenticated user is not in the channel.",
+            &SetTopicError::IsArchived => "is_archived: Channel has been archived.",
+            &SetTopicError::TooLong => "too_long: Topic was longer than 250 characters.",
+            &SetTopicError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &SetTopicError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &SetTopicError::NotAuthed => "not_authed: No authentication token provided.",
+            &SetTopicError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SetTopicError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SetTopicError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SetTopicError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SetTopicError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SetTopicError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SetTopicError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SetTopicError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SetTopicError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SetTopicError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SetTopicError::MalformedResponse(ref e) => e.description(),
+            &SetTopicError::Unknown(ref s) => s,
+            &SetTopicError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SetTopicError::MalformedResponse(ref e) => Some(e),
+            &SetTopicError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Reverses conversation archival.
+///
+/// Wraps https://api.slack.com/methods/conversations.unarchive
+
+pub fn unarchive<R>(client: &R,
+                    token: &str,
+                    request: &UnarchiveRequest)
+                    -> Result<UnarchiveResponse, UnarchiveError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    let url = ::get_slack_url_for_method("conversations.unarchive");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| UnarchiveError::Client(err))
+        .and_then(|result| {
+                      parse_unarchive_response(result.body.as_bytes()).map_err(|err| err.into_error(UnarchiveError::MalformedResponse))
+                  })
+}
+
+fn parse_unarchive_response(body: &[u8]) -> Result<UnarchiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UnarchiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UnarchiveRequest<'a> {
+    /// ID of conversation to unarchive
+    pub channel: &'a str,
+}
+
+impl<'a> UnarchiveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        UnarchiveRequest {
+            channel: channel,
+            ..UnarchiveRequest::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UnarchiveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveResponse {
+    fn into(self) -> Result<UnarchiveResponse, UnarchiveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UnarchiveError<E: Error> {
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel is not archived.
+    NotArchived,
+    /// A team preference prevents the authenticated user from unarchiving channels.
+    RestrictedAction,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "method_not_supported_for_channel_type" => UnarchiveError::MethodNotSupportedForChannelType,
+            "channel_not_found" => UnarchiveError::ChannelNotFound,
+            "not_archived" => UnarchiveError::NotArchived,
+            "restricted_action" => UnarchiveError::RestrictedAction,
+            "user_is_restricted" => UnarchiveError::UserIsRestricted,
+            "missing_scope" => UnarchiveError::MissingScope,
+            "not_authed" => UnarchiveError::NotAuthed,
+            "invalid_auth" => UnarchiveError::InvalidAuth,
+            "account_inactive" => UnarchiveError::AccountInactive,
+            "invalid_arg_name" => UnarchiveError::InvalidArgName,
+            "invalid_array_arg" => UnarchiveError::InvalidArrayArg,
+            "invalid_charset" => UnarchiveError::InvalidCharset,
+            "invalid_form_data" => UnarchiveError::InvalidFormData,
+            "invalid_post_type" => UnarchiveError::InvalidPostType,
+            "missing_post_type" => UnarchiveError::MissingPostType,
+            "team_added_to_org" => UnarchiveError::TeamAddedToOrg,
+            "request_timeout" => UnarchiveError::RequestTimeout,
+            _ => UnarchiveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<UnarchiveError<E>> for ::Error<E> {
+    fn from(err: UnarchiveError<E>) -> Self {
+        let code = match err {
+            UnarchiveError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type",
+            UnarchiveError::ChannelNotFound => "channel_not_found",
+            UnarchiveError::NotArchived => "not_archived",
+            UnarchiveError::RestrictedAction => "restricted_action",
+            UnarchiveError::UserIsRestricted => "user_is_restricted",
+            UnarchiveError::MissingScope => "missing_scope",
+            UnarchiveError::NotAuthed => "not_authed",
+            UnarchiveError::InvalidAuth => "invalid_auth",
+            UnarchiveError::AccountInactive => "account_inactive",
+            UnarchiveError::InvalidArgName => "invalid_arg_name",
+            UnarchiveError::InvalidArrayArg => "invalid_array_arg",
+            UnarchiveError::InvalidCharset => "invalid_charset",
+            UnarchiveError::InvalidFormData => "invalid_form_data",
+            UnarchiveError::InvalidPostType => "invalid_post_type",
+            UnarchiveError::MissingPostType => "missing_post_type",
+            UnarchiveError::TeamAddedToOrg => "team_added_to_org",
+            UnarchiveError::RequestTimeout => "request_timeout",
+            UnarchiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UnarchiveError::Unknown(code) => return ::Error::from_code("conversations.unarchive", &code),
+            UnarchiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("conversations.unarchive", code)
+    }
+}
+
+impl<E: Error> fmt::Display for UnarchiveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UnarchiveError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &UnarchiveError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+            &UnarchiveError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+            &UnarchiveError::NotArchived => "not_archived: Channel is not archived.",
+            &UnarchiveError::RestrictedAction => "restricted_action: A team preference prevents the authenticated user from unarchiving channels.",
+            &UnarchiveError::UserIsRestricted => "user_is_restricted: This method cannot be called by a restricted user or single channel guest.",
+            &UnarchiveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &UnarchiveError::NotAuthed => "not_authed: No authentication token provided.",
+            &UnarchiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &UnarchiveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &UnarchiveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &UnarchiveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &UnarchiveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &UnarchiveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &UnarchiveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &UnarchiveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &UnarchiveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &UnarchiveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &UnarchiveError::MalformedResponse(ref e) => e.description(),
+            &UnarchiveError::Unknown(ref s) => s,
+            &UnarchiveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &UnarchiveError::MalformedResponse(ref e) => Some(e),
+            &UnarchiveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `conversations.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::conversations`](../client/struct.SlackClient.html#method.conversations).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Archives a conversation. See [`archive`](fn.archive.html).
+    pub fn archive(&self, request: &ArchiveRequest) -> Result<ArchiveResponse, ArchiveError<R::Error>> {
+        archive(self.client, self.token.for_method("conversations.archive"), request)
+    }
+
+    /// Closes a direct message or multi-person direct message. See [`close`](fn.close.html).
+    pub fn close(&self, request: &CloseRequest) -> Result<CloseResponse, CloseError<R::Error>> {
+        close(self.client, self.token.for_method("conversations.close"), request)
+    }
+
+    /// Initiates a public or private channel-based conversation. See [`create`](fn.create.html).
+    pub fn create(&self, request: &CreateRequest) -> Result<CreateResponse, CreateError<R::Error>> {
+        create(self.client, self.token.for_method("conversations.create"), request)
+    }
+
+    /// Fetches a conversation's history of messages and events. See [`history`](fn.history.html).
+    pub fn history(&self, request: &HistoryRequest) -> Result<HistoryResponse, HistoryError<R::Error>> {
+        history(self.client, self.token.for_method("conversations.history"), request)
+    }
+
+    /// Like [`history`](fn.history.html), but follows `response_metadata.next_cursor` from page to
+    /// page, yielding the `messages` of every page in turn.
+    pub fn history_all(&self, request: &HistoryRequest<'a>) -> ::cursor::Items<'a, ::Message, HistoryError<R::Error>> {
+        history_all(self.client, self.token.for_method("conversations.history"), request)
+    }
+
+    /// Retrieve information about a conversation. See [`info`](fn.info.html).
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<R::Error>> {
+        info(self.client, self.token.for_method("conversations.info"), request)
+    }
+
+    /// Invites users to a channel. See [`invite`](fn.invite.html).
+    pub fn invite(&self, request: &InviteRequest) -> Result<InviteResponse, InviteError<R::Error>> {
+        invite(self.client, self.token.for_method("conversations.invite"), request)
+    }
+
+    /// Joins an existing conversation. See [`join`](fn.join.html).
+    pub fn join(&self, request: &JoinRequest) -> Result<JoinResponse, JoinError<R::Error>> {
+        join(self.client, self.token.for_method("conversations.join"), request)
+    }
+
+    /// Removes a user from a conversation. See [`kick`](fn.kick.html).
+    pub fn kick(&self, request: &KickRequest) -> Result<KickResponse, KickError<R::Error>> {
+        kick(self.client, self.token.for_method("conversations.kick"), request)
+    }
+
+    /// Leaves a conversation. See [`leave`](fn.leave.html).
+    pub fn leave(&self, request: &LeaveRequest) -> Result<LeaveResponse, LeaveError<R::Error>> {
+        leave(self.client, self.token.for_method("conversations.leave"), request)
+    }
+
+    /// Lists all channels in a Slack team. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("conversations.list"), request)
+    }
+
+    /// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+    /// yielding the `channels` of every page in turn.
+    pub fn list_all(&self, request: &ListRequest<'a>) -> ::cursor::Items<'a, ::Conversation, ListError<R::Error>> {
+        list_all(self.client, self.token.for_method("conversations.list"), request)
+    }
+
+    /// Sets the read cursor in a channel. See [`mark`](fn.mark.html).
+    pub fn mark(&self, request: &MarkRequest) -> Result<MarkResponse, MarkError<R::Error>> {
+        mark(self.client, self.token.for_method("conversations.mark"), request)
+    }
+
+    /// Retrieve members of a conversation. See [`members`](fn.members.html).
+    pub fn members(&self, request: &MembersRequest) -> Result<MembersResponse, MembersError<R::Error>> {
+        members(self.client, self.token.for_method("conversations.members"), request)
+    }
+
+    /// Like [`members`](fn.members.html), but follows `response_metadata.next_cursor` from page to
+    /// page, yielding the `members` of every page in turn.
+    pub fn members_all(&self, request: &MembersRequest<'a>) -> ::cursor::Items<'a, String, MembersError<R::Error>> {
+        members_all(self.client, self.token.for_method("conversations.members"), request)
+    }
+
+    /// Opens or resumes a direct message or multi-person direct message. See
+    /// [`open`](fn.open.html).
+    pub fn open(&self, request: &OpenRequest) -> Result<OpenResponse, OpenError<R::Error>> {
+        open(self.client, self.token.for_method("conversations.open"), request)
+    }
+
+    /// Renames a conversation. See [`rename`](fn.rename.html).
+    pub fn rename(&self, request: &RenameRequest) -> Result<RenameResponse, RenameError<R::Error>> {
+        rename(self.client, self.token.for_method("conversations.rename"), request)
+    }
+
+    /// Retrieve a thread of messages posted to a conversation. See [`replies`](fn.replies.html).
+    pub fn replies(&self, request: &RepliesRequest) -> Result<RepliesResponse, RepliesError<R::Error>> {
+        replies(self.client, self.token.for_method("conversations.replies"), request)
+    }
+
+    /// Like [`replies`](fn.replies.html), but follows `response_metadata.next_cursor` from page to
+    /// page, yielding the `messages` of every page in turn.
+    pub fn replies_all(&self, request: &RepliesRequest<'a>) -> ::cursor::Items<'a, ::Message, RepliesError<R::Error>> {
+        replies_all(self.client, self.token.for_method("conversations.replies"), request)
+    }
+
+    /// Sets the purpose for a conversation. See [`set_purpose`](fn.set_purpose.html).
+    pub fn set_purpose(&self, request: &SetPurposeRequest) -> Result<SetPurposeResponse, SetPurposeError<R::Error>> {
+        set_purpose(self.client, self.token.for_method("conversations.setPurpose"), request)
+    }
+
+    /// Sets the topic for a conversation. See [`set_topic`](fn.set_topic.html).
+    pub fn set_topic(&self, request: &SetTopicRequest) -> Result<SetTopicResponse, SetTopicError<R::Error>> {
+        set_topic(self.client, self.token.for_method("conversations.setTopic"), request)
+    }
+
+    /// Reverses conversation archival. See [`unarchive`](fn.unarchive.html).
+    pub fn unarchive(&self, request: &UnarchiveRequest) -> Result<UnarchiveResponse, UnarchiveError<R::Error>> {
+        unarchive(self.client, self.token.for_method("conversations.unarchive"), request)
+    }
+}
@@ -18,16 +18,23 @@ use requests::SlackWebRequestSender;
 pub fn end_dnd<R>(client: &R, token: &str) -> Result<EndDndResponse, EndDndError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let params = &[("token", token)];
+
     let url = ::get_slack_url_for_method("dnd.endDnd");
     client
-        .send(&url, &params[..])
+        .send_json(&url, token, "{}")
         .map_err(|err| EndDndError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<EndDndResponse>(&result)
-                            .map_err(|e| EndDndError::MalformedResponse(e))
+                      parse_end_dnd_response(result.body.as_bytes()).map_err(|err| err.into_error(EndDndError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_end_dnd_response(body: &[u8]) -> Result<EndDndResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<EndDndResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -35,6 +42,8 @@ pub struct EndDndResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -108,6 +117,30 @@ impl<'a, E: Error> From<&'a str> for EndDndError<E> {
     }
 }
 
+impl<E: Error> From<EndDndError<E>> for ::Error<E> {
+    fn from(err: EndDndError<E>) -> Self {
+        let code = match err {
+            EndDndError::UnknownError => "unknown_error",
+            EndDndError::NotAuthed => "not_authed",
+            EndDndError::InvalidAuth => "invalid_auth",
+            EndDndError::AccountInactive => "account_inactive",
+            EndDndError::UserIsBot => "user_is_bot",
+            EndDndError::InvalidArgName => "invalid_arg_name",
+            EndDndError::InvalidArrayArg => "invalid_array_arg",
+            EndDndError::InvalidCharset => "invalid_charset",
+            EndDndError::InvalidFormData => "invalid_form_data",
+            EndDndError::InvalidPostType => "invalid_post_type",
+            EndDndError::MissingPostType => "missing_post_type",
+            EndDndError::TeamAddedToOrg => "team_added_to_org",
+            EndDndError::RequestTimeout => "request_timeout",
+            EndDndError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            EndDndError::Unknown(code) => return ::Error::from_code("dnd.endDnd", &code),
+            EndDndError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("dnd.endDnd", code)
+    }
+}
+
 impl<E: Error> fmt::Display for EndDndError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -152,16 +185,23 @@ impl<E: Error> Error for EndDndError<E> {
 pub fn end_snooze<R>(client: &R, token: &str) -> Result<EndSnoozeResponse, EndSnoozeError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let params = &[("token", token)];
+
     let url = ::get_slack_url_for_method("dnd.endSnooze");
     client
-        .send(&url, &params[..])
+        .send_json(&url, token, "{}")
         .map_err(|err| EndSnoozeError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<EndSnoozeResponse>(&result)
-                            .map_err(|e| EndSnoozeError::MalformedResponse(e))
+                      parse_end_snooze_response(result.body.as_bytes()).map_err(|err| err.into_error(EndSnoozeError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_end_snooze_response(body: &[u8]) -> Result<EndSnoozeResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<EndSnoozeResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -172,7 +212,9 @@ pub struct EndSnoozeResponse {
     pub next_dnd_start_ts: Option<f32>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub snooze_enabled: Option<bool>,
+    pub warning: Option<String>,
 }
 
 
@@ -249,6 +291,31 @@ impl<'a, E: Error> From<&'a str> for EndSnoozeError<E> {
     }
 }
 
+impl<E: Error> From<EndSnoozeError<E>> for ::Error<E> {
+    fn from(err: EndSnoozeError<E>) -> Self {
+        let code = match err {
+            EndSnoozeError::SnoozeNotActive => "snooze_not_active",
+            EndSnoozeError::SnoozeEndFailed => "snooze_end_failed",
+            EndSnoozeError::NotAuthed => "not_authed",
+            EndSnoozeError::InvalidAuth => "invalid_auth",
+            EndSnoozeError::AccountInactive => "account_inactive",
+            EndSnoozeError::UserIsBot => "user_is_bot",
+            EndSnoozeError::InvalidArgName => "invalid_arg_name",
+            EndSnoozeError::InvalidArrayArg => "invalid_array_arg",
+            EndSnoozeError::InvalidCharset => "invalid_charset",
+            EndSnoozeError::InvalidFormData => "invalid_form_data",
+            EndSnoozeError::InvalidPostType => "invalid_post_type",
+            EndSnoozeError::MissingPostType => "missing_post_type",
+            EndSnoozeError::TeamAddedToOrg => "team_added_to_org",
+            EndSnoozeError::RequestTimeout => "request_timeout",
+            EndSnoozeError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            EndSnoozeError::Unknown(code) => return ::Error::from_code("dnd.endSnooze", &code),
+            EndSnoozeError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("dnd.endSnooze", code)
+    }
+}
+
 impl<E: Error> fmt::Display for EndSnoozeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -298,18 +365,27 @@ pub fn info<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request.user.map(|user| ("user", user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(2);
+    params.push(("token", token));
+    if let Some(user) = request.user {
+        params.push(("user", user));
+    }
     let url = ::get_slack_url_for_method("dnd.info");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| InfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InfoResponse>(&result)
-                            .map_err(|e| InfoError::MalformedResponse(e))
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -318,6 +394,17 @@ pub struct InfoRequest<'a> {
     pub user: Option<&'a str>,
 }
 
+impl<'a> InfoRequest<'a> {
+    pub fn new() -> Self {
+        InfoRequest::default()
+    }
+
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InfoResponse {
     pub dnd_enabled: Option<bool>,
@@ -326,9 +413,11 @@ pub struct InfoResponse {
     pub next_dnd_start_ts: Option<f32>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub snooze_enabled: Option<bool>,
     pub snooze_endtime: Option<f32>,
     pub snooze_remaining: Option<f32>,
+    pub warning: Option<String>,
 }
 
 
@@ -399,6 +488,29 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::UserNotFound => "user_not_found",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("dnd.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("dnd.info", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -445,19 +557,27 @@ pub fn set_snooze<R>(client: &R,
                      -> Result<SetSnoozeResponse, SetSnoozeError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let num_minutes = request.num_minutes.to_string();
-    let params = vec![Some(("token", token)),
-                      Some(("num_minutes", &num_minutes[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("num_minutes".to_owned(), request.num_minutes.into());
     let url = ::get_slack_url_for_method("dnd.setSnooze");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| SetSnoozeError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SetSnoozeResponse>(&result)
-                            .map_err(|e| SetSnoozeError::MalformedResponse(e))
+                      parse_set_snooze_response(result.body.as_bytes()).map_err(|err| err.into_error(SetSnoozeError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_set_snooze_response(body: &[u8]) -> Result<SetSnoozeResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetSnoozeResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -466,14 +586,25 @@ pub struct SetSnoozeRequest {
     pub num_minutes: u32,
 }
 
+impl SetSnoozeRequest {
+    pub fn new(num_minutes: u32) -> Self {
+        SetSnoozeRequest {
+            num_minutes: num_minutes,
+            ..SetSnoozeRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SetSnoozeResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub snooze_enabled: Option<bool>,
     pub snooze_endtime: Option<f32>,
     pub snooze_remaining: Option<f32>,
+    pub warning: Option<String>,
 }
 
 
@@ -550,6 +681,31 @@ impl<'a, E: Error> From<&'a str> for SetSnoozeError<E> {
     }
 }
 
+impl<E: Error> From<SetSnoozeError<E>> for ::Error<E> {
+    fn from(err: SetSnoozeError<E>) -> Self {
+        let code = match err {
+            SetSnoozeError::MissingDuration => "missing_duration",
+            SetSnoozeError::SnoozeFailed => "snooze_failed",
+            SetSnoozeError::NotAuthed => "not_authed",
+            SetSnoozeError::InvalidAuth => "invalid_auth",
+            SetSnoozeError::AccountInactive => "account_inactive",
+            SetSnoozeError::UserIsBot => "user_is_bot",
+            SetSnoozeError::InvalidArgName => "invalid_arg_name",
+            SetSnoozeError::InvalidArrayArg => "invalid_array_arg",
+            SetSnoozeError::InvalidCharset => "invalid_charset",
+            SetSnoozeError::InvalidFormData => "invalid_form_data",
+            SetSnoozeError::InvalidPostType => "invalid_post_type",
+            SetSnoozeError::MissingPostType => "missing_post_type",
+            SetSnoozeError::TeamAddedToOrg => "team_added_to_org",
+            SetSnoozeError::RequestTimeout => "request_timeout",
+            SetSnoozeError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetSnoozeError::Unknown(code) => return ::Error::from_code("dnd.setSnooze", &code),
+            SetSnoozeError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("dnd.setSnooze", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SetSnoozeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -599,18 +755,27 @@ pub fn team_info<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request.users.map(|users| ("users", users))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(2);
+    params.push(("token", token));
+    if let Some(users) = request.users {
+        params.push(("users", users));
+    }
     let url = ::get_slack_url_for_method("dnd.teamInfo");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| TeamInfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<TeamInfoResponse>(&result)
-                            .map_err(|e| TeamInfoError::MalformedResponse(e))
+                      parse_team_info_response(&result).map_err(|err| err.into_error(TeamInfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_team_info_response(body: &[u8]) -> Result<TeamInfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<TeamInfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -619,12 +784,25 @@ pub struct TeamInfoRequest<'a> {
     pub users: Option<&'a str>,
 }
 
+impl<'a> TeamInfoRequest<'a> {
+    pub fn new() -> Self {
+        TeamInfoRequest::default()
+    }
+
+    pub fn users(mut self, users: &'a str) -> Self {
+        self.users = Some(users);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct TeamInfoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub users: Option<HashMap<String, bool>>,
+    pub warning: Option<String>,
 }
 
 
@@ -692,6 +870,28 @@ impl<'a, E: Error> From<&'a str> for TeamInfoError<E> {
     }
 }
 
+impl<E: Error> From<TeamInfoError<E>> for ::Error<E> {
+    fn from(err: TeamInfoError<E>) -> Self {
+        let code = match err {
+            TeamInfoError::NotAuthed => "not_authed",
+            TeamInfoError::InvalidAuth => "invalid_auth",
+            TeamInfoError::AccountInactive => "account_inactive",
+            TeamInfoError::InvalidArgName => "invalid_arg_name",
+            TeamInfoError::InvalidArrayArg => "invalid_array_arg",
+            TeamInfoError::InvalidCharset => "invalid_charset",
+            TeamInfoError::InvalidFormData => "invalid_form_data",
+            TeamInfoError::InvalidPostType => "invalid_post_type",
+            TeamInfoError::MissingPostType => "missing_post_type",
+            TeamInfoError::TeamAddedToOrg => "team_added_to_org",
+            TeamInfoError::RequestTimeout => "request_timeout",
+            TeamInfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            TeamInfoError::Unknown(code) => return ::Error::from_code("dnd.teamInfo", &code),
+            TeamInfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("dnd.teamInfo", code)
+    }
+}
+
 impl<E: Error> fmt::Display for TeamInfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -726,3 +926,48 @@ impl<E: Error> Error for TeamInfoError<E> {
         }
     }
 }
+
+/// The `dnd.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::dnd`](../client/struct.SlackClient.html#method.dnd).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Ends the current user's Do Not Disturb session immediately. See
+    /// [`end_dnd`](fn.end_dnd.html).
+    pub fn end_dnd(&self) -> Result<EndDndResponse, EndDndError<R::Error>> {
+        end_dnd(self.client, self.token.for_method("dnd.endDnd"))
+    }
+
+    /// Ends the current user's snooze mode immediately. See [`end_snooze`](fn.end_snooze.html).
+    pub fn end_snooze(&self) -> Result<EndSnoozeResponse, EndSnoozeError<R::Error>> {
+        end_snooze(self.client, self.token.for_method("dnd.endSnooze"))
+    }
+
+    /// Retrieves a user's current Do Not Disturb status. See [`info`](fn.info.html).
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<R::Error>> {
+        info(self.client, self.token.for_method("dnd.info"), request)
+    }
+
+    /// Turns on Do Not Disturb mode for the current user, or changes its duration. See
+    /// [`set_snooze`](fn.set_snooze.html).
+    pub fn set_snooze(&self, request: &SetSnoozeRequest) -> Result<SetSnoozeResponse, SetSnoozeError<R::Error>> {
+        set_snooze(self.client, self.token.for_method("dnd.setSnooze"), request)
+    }
+
+    /// Retrieves the Do Not Disturb status for users on a team. See
+    /// [`team_info`](fn.team_info.html).
+    pub fn team_info(&self, request: &TeamInfoRequest) -> Result<TeamInfoResponse, TeamInfoError<R::Error>> {
+        team_info(self.client, self.token.for_method("dnd.teamInfo"), request)
+    }
+}
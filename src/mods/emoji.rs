@@ -20,21 +20,30 @@ pub fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Err
     let params = &[("token", token)];
     let url = ::get_slack_url_for_method("emoji.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
-    pub emoji: Option<HashMap<String, bool>>,
+    pub emoji: Option<HashMap<String, String>>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -102,6 +111,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("emoji.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("emoji.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -136,3 +167,25 @@ impl<E: Error> Error for ListError<E> {
         }
     }
 }
+
+/// The `emoji.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::emoji`](../client/struct.SlackClient.html#method.emoji).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Lists custom emoji for a team. See [`list`](fn.list.html).
+    pub fn list(&self) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("emoji.list"))
+    }
+}
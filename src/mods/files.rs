@@ -9,7 +9,263 @@ use std::fmt;
 
 use serde_json;
 
-use requests::SlackWebRequestSender;
+use requests::{SlackMultipartSender, SlackWebRequestSender};
+
+/// Finishes an upload started with files.getUploadURLExternal.
+///
+/// Wraps https://api.slack.com/methods/files.completeUploadExternal
+
+pub fn complete_upload_external<R>(client: &R,
+                                   token: &str,
+                                   request: &CompleteUploadExternalRequest)
+                                   -> Result<CompleteUploadExternalResponse, CompleteUploadExternalError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("files".to_owned(), ::json_param(request.files));
+    if let Some(channel_id) = request.channel_id {
+        body.insert("channel_id".to_owned(), channel_id.into());
+    }
+    if let Some(channels) = request.channels {
+        body.insert("channels".to_owned(), channels.into());
+    }
+    if let Some(initial_comment) = request.initial_comment {
+        body.insert("initial_comment".to_owned(), initial_comment.into());
+    }
+    if let Some(thread_ts) = request.thread_ts {
+        body.insert("thread_ts".to_owned(), thread_ts.into());
+    }
+    let url = ::get_slack_url_for_method("files.completeUploadExternal");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| CompleteUploadExternalError::Client(err))
+        .and_then(|result| {
+                      parse_complete_upload_external_response(result.body.as_bytes()).map_err(|err| err.into_error(CompleteUploadExternalError::MalformedResponse))
+                  })
+}
+
+fn parse_complete_upload_external_response(body: &[u8]) -> Result<CompleteUploadExternalResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CompleteUploadExternalResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CompleteUploadExternalRequest<'a> {
+    /// Array of file ids and their corresponding (optional) titles.
+    pub files: &'a str,
+    /// Channel ID where the file will be shared. If not specified the file will be private.
+    pub channel_id: Option<&'a str>,
+    /// Comma-separated string of channel IDs where the file will be shared.
+    pub channels: Option<&'a str>,
+    /// The message text introducing the file in specified channels.
+    pub initial_comment: Option<&'a str>,
+    /// Provide another message's ts value to upload this file as a reply. Never use a reply's ts value; use its parent instead.
+    pub thread_ts: Option<&'a str>,
+}
+
+impl<'a> CompleteUploadExternalRequest<'a> {
+    pub fn new(files: &'a str) -> Self {
+        CompleteUploadExternalRequest {
+            files: files,
+            ..CompleteUploadExternalRequest::default()
+        }
+    }
+
+    pub fn channel_id(mut self, channel_id: &'a str) -> Self {
+        self.channel_id = Some(channel_id);
+        self
+    }
+
+    pub fn channels(mut self, channels: &'a str) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    pub fn initial_comment(mut self, initial_comment: &'a str) -> Self {
+        self.initial_comment = Some(initial_comment);
+        self
+    }
+
+    pub fn thread_ts(mut self, thread_ts: &'a str) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CompleteUploadExternalResponse {
+    error: Option<String>,
+    pub files: Option<Vec<::File>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<CompleteUploadExternalResponse, CompleteUploadExternalError<E>>> for CompleteUploadExternalResponse {
+    fn into(self) -> Result<CompleteUploadExternalResponse, CompleteUploadExternalError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CompleteUploadExternalError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    InvalidChannel,
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// Could not find the file from the upload ticket.
+    FileNotFound,
+    /// File uploads are disabled for this team.
+    FileUploadsDisabled,
+    /// The user is not in the channel.
+    NotInChannel,
+    /// The upload could not be found.
+    UploadNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CompleteUploadExternalError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_channel" => CompleteUploadExternalError::InvalidChannel,
+            "channel_not_found" => CompleteUploadExternalError::ChannelNotFound,
+            "file_not_found" => CompleteUploadExternalError::FileNotFound,
+            "file_uploads_disabled" => CompleteUploadExternalError::FileUploadsDisabled,
+            "not_in_channel" => CompleteUploadExternalError::NotInChannel,
+            "upload_not_found" => CompleteUploadExternalError::UploadNotFound,
+            "missing_scope" => CompleteUploadExternalError::MissingScope,
+            "not_authed" => CompleteUploadExternalError::NotAuthed,
+            "invalid_auth" => CompleteUploadExternalError::InvalidAuth,
+            "account_inactive" => CompleteUploadExternalError::AccountInactive,
+            "invalid_arg_name" => CompleteUploadExternalError::InvalidArgName,
+            "invalid_array_arg" => CompleteUploadExternalError::InvalidArrayArg,
+            "invalid_charset" => CompleteUploadExternalError::InvalidCharset,
+            "invalid_form_data" => CompleteUploadExternalError::InvalidFormData,
+            "invalid_post_type" => CompleteUploadExternalError::InvalidPostType,
+            "missing_post_type" => CompleteUploadExternalError::MissingPostType,
+            "team_added_to_org" => CompleteUploadExternalError::TeamAddedToOrg,
+            "request_timeout" => CompleteUploadExternalError::RequestTimeout,
+            _ => CompleteUploadExternalError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<CompleteUploadExternalError<E>> for ::Error<E> {
+    fn from(err: CompleteUploadExternalError<E>) -> Self {
+        let code = match err {
+            CompleteUploadExternalError::InvalidChannel => "invalid_channel",
+            CompleteUploadExternalError::ChannelNotFound => "channel_not_found",
+            CompleteUploadExternalError::FileNotFound => "file_not_found",
+            CompleteUploadExternalError::FileUploadsDisabled => "file_uploads_disabled",
+            CompleteUploadExternalError::NotInChannel => "not_in_channel",
+            CompleteUploadExternalError::UploadNotFound => "upload_not_found",
+            CompleteUploadExternalError::MissingScope => "missing_scope",
+            CompleteUploadExternalError::NotAuthed => "not_authed",
+            CompleteUploadExternalError::InvalidAuth => "invalid_auth",
+            CompleteUploadExternalError::AccountInactive => "account_inactive",
+            CompleteUploadExternalError::InvalidArgName => "invalid_arg_name",
+            CompleteUploadExternalError::InvalidArrayArg => "invalid_array_arg",
+            CompleteUploadExternalError::InvalidCharset => "invalid_charset",
+            CompleteUploadExternalError::InvalidFormData => "invalid_form_data",
+            CompleteUploadExternalError::InvalidPostType => "invalid_post_type",
+            CompleteUploadExternalError::MissingPostType => "missing_post_type",
+            CompleteUploadExternalError::TeamAddedToOrg => "team_added_to_org",
+            CompleteUploadExternalError::RequestTimeout => "request_timeout",
+            CompleteUploadExternalError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CompleteUploadExternalError::Unknown(code) => return ::Error::from_code("files.completeUploadExternal", &code),
+            CompleteUploadExternalError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.completeUploadExternal", code)
+    }
+}
+
+impl<E: Error> fmt::Display for CompleteUploadExternalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CompleteUploadExternalError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &CompleteUploadExternalError::InvalidChannel => "invalid_channel: Value passed for channel_id was invalid.",
+            &CompleteUploadExternalError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+            &CompleteUploadExternalError::FileNotFound => "file_not_found: Could not find the file from the upload ticket.",
+            &CompleteUploadExternalError::FileUploadsDisabled => "file_uploads_disabled: File uploads are disabled for this team.",
+            &CompleteUploadExternalError::NotInChannel => "not_in_channel: The user is not in the channel.",
+            &CompleteUploadExternalError::UploadNotFound => "upload_not_found: The upload could not be found.",
+            &CompleteUploadExternalError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &CompleteUploadExternalError::NotAuthed => "not_authed: No authentication token provided.",
+            &CompleteUploadExternalError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &CompleteUploadExternalError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &CompleteUploadExternalError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &CompleteUploadExternalError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &CompleteUploadExternalError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &CompleteUploadExternalError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &CompleteUploadExternalError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &CompleteUploadExternalError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &CompleteUploadExternalError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &CompleteUploadExternalError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &CompleteUploadExternalError::MalformedResponse(ref e) => e.description(),
+            &CompleteUploadExternalError::Unknown(ref s) => s,
+            &CompleteUploadExternalError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &CompleteUploadExternalError::MalformedResponse(ref e) => Some(e),
+            &CompleteUploadExternalError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
 
 /// Deletes a file.
 ///
@@ -22,17 +278,26 @@ pub fn delete<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("file".to_owned(), request.file.into());
     let url = ::get_slack_url_for_method("files.delete");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| DeleteError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<DeleteResponse>(&result)
-                            .map_err(|e| DeleteError::MalformedResponse(e))
+                      parse_delete_response(result.body.as_bytes()).map_err(|err| err.into_error(DeleteError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_delete_response(body: &[u8]) -> Result<DeleteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<DeleteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -41,11 +306,22 @@ pub struct DeleteRequest<'a> {
     pub file: &'a str,
 }
 
+impl<'a> DeleteRequest<'a> {
+    pub fn new(file: &'a str) -> Self {
+        DeleteRequest {
+            file: file,
+            ..DeleteRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -122,6 +398,31 @@ impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     }
 }
 
+impl<E: Error> From<DeleteError<E>> for ::Error<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        let code = match err {
+            DeleteError::FileNotFound => "file_not_found",
+            DeleteError::FileDeleted => "file_deleted",
+            DeleteError::CantDeleteFile => "cant_delete_file",
+            DeleteError::NotAuthed => "not_authed",
+            DeleteError::InvalidAuth => "invalid_auth",
+            DeleteError::AccountInactive => "account_inactive",
+            DeleteError::InvalidArgName => "invalid_arg_name",
+            DeleteError::InvalidArrayArg => "invalid_array_arg",
+            DeleteError::InvalidCharset => "invalid_charset",
+            DeleteError::InvalidFormData => "invalid_form_data",
+            DeleteError::InvalidPostType => "invalid_post_type",
+            DeleteError::MissingPostType => "missing_post_type",
+            DeleteError::TeamAddedToOrg => "team_added_to_org",
+            DeleteError::RequestTimeout => "request_timeout",
+            DeleteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            DeleteError::Unknown(code) => return ::Error::from_code("files.delete", &code),
+            DeleteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.delete", code)
+    }
+}
+
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -160,6 +461,226 @@ impl<E: Error> Error for DeleteError<E> {
     }
 }
 
+/// Gets a URL for an edge external file upload.
+///
+/// Wraps https://api.slack.com/methods/files.getUploadURLExternal
+
+pub fn get_upload_url_external<R>(client: &R,
+                                  token: &str,
+                                  request: &GetUploadURLExternalRequest)
+                                  -> Result<GetUploadURLExternalResponse, GetUploadURLExternalError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let length = ::IntParam::new(request.length);
+    let mut params = Vec::with_capacity(5);
+    params.push(("token", token));
+    params.push(("filename", request.filename));
+    params.push(("length", length.as_str()));
+    if let Some(alt_txt) = request.alt_txt {
+        params.push(("alt_txt", alt_txt));
+    }
+    if let Some(snippet_type) = request.snippet_type {
+        params.push(("snippet_type", snippet_type));
+    }
+    let url = ::get_slack_url_for_method("files.getUploadURLExternal");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| GetUploadURLExternalError::Client(err))
+        .and_then(|result| {
+                      parse_get_upload_url_external_response(&result).map_err(|err| err.into_error(GetUploadURLExternalError::MalformedResponse))
+                  })
+}
+
+fn parse_get_upload_url_external_response(body: &[u8]) -> Result<GetUploadURLExternalResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<GetUploadURLExternalResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct GetUploadURLExternalRequest<'a> {
+    /// Name of the file being uploaded.
+    pub filename: &'a str,
+    /// Size in bytes of the file being uploaded.
+    pub length: u32,
+    /// Description of image for screen-reader.
+    pub alt_txt: Option<&'a str>,
+    /// Syntax type of the snippet being uploaded.
+    pub snippet_type: Option<&'a str>,
+}
+
+impl<'a> GetUploadURLExternalRequest<'a> {
+    pub fn new(filename: &'a str, length: u32) -> Self {
+        GetUploadURLExternalRequest {
+            filename: filename,
+            length: length,
+            ..GetUploadURLExternalRequest::default()
+        }
+    }
+
+    pub fn alt_txt(mut self, alt_txt: &'a str) -> Self {
+        self.alt_txt = Some(alt_txt);
+        self
+    }
+
+    pub fn snippet_type(mut self, snippet_type: &'a str) -> Self {
+        self.snippet_type = Some(snippet_type);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct GetUploadURLExternalResponse {
+    error: Option<String>,
+    pub file_id: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub upload_url: Option<String>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<GetUploadURLExternalResponse, GetUploadURLExternalError<E>>> for GetUploadURLExternalResponse {
+    fn into(self) -> Result<GetUploadURLExternalResponse, GetUploadURLExternalError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum GetUploadURLExternalError<E: Error> {
+    /// The length passed was invalid.
+    InvalidLength,
+    /// File uploads are disabled for this team.
+    FileUploadsDisabled,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for GetUploadURLExternalError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_length" => GetUploadURLExternalError::InvalidLength,
+            "file_uploads_disabled" => GetUploadURLExternalError::FileUploadsDisabled,
+            "missing_scope" => GetUploadURLExternalError::MissingScope,
+            "not_authed" => GetUploadURLExternalError::NotAuthed,
+            "invalid_auth" => GetUploadURLExternalError::InvalidAuth,
+            "account_inactive" => GetUploadURLExternalError::AccountInactive,
+            "invalid_arg_name" => GetUploadURLExternalError::InvalidArgName,
+            "invalid_array_arg" => GetUploadURLExternalError::InvalidArrayArg,
+            "invalid_charset" => GetUploadURLExternalError::InvalidCharset,
+            "invalid_form_data" => GetUploadURLExternalError::InvalidFormData,
+            "invalid_post_type" => GetUploadURLExternalError::InvalidPostType,
+            "missing_post_type" => GetUploadURLExternalError::MissingPostType,
+            "team_added_to_org" => GetUploadURLExternalError::TeamAddedToOrg,
+            "request_timeout" => GetUploadURLExternalError::RequestTimeout,
+            _ => GetUploadURLExternalError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<GetUploadURLExternalError<E>> for ::Error<E> {
+    fn from(err: GetUploadURLExternalError<E>) -> Self {
+        let code = match err {
+            GetUploadURLExternalError::InvalidLength => "invalid_length",
+            GetUploadURLExternalError::FileUploadsDisabled => "file_uploads_disabled",
+            GetUploadURLExternalError::MissingScope => "missing_scope",
+            GetUploadURLExternalError::NotAuthed => "not_authed",
+            GetUploadURLExternalError::InvalidAuth => "invalid_auth",
+            GetUploadURLExternalError::AccountInactive => "account_inactive",
+            GetUploadURLExternalError::InvalidArgName => "invalid_arg_name",
+            GetUploadURLExternalError::InvalidArrayArg => "invalid_array_arg",
+            GetUploadURLExternalError::InvalidCharset => "invalid_charset",
+            GetUploadURLExternalError::InvalidFormData => "invalid_form_data",
+            GetUploadURLExternalError::InvalidPostType => "invalid_post_type",
+            GetUploadURLExternalError::MissingPostType => "missing_post_type",
+            GetUploadURLExternalError::TeamAddedToOrg => "team_added_to_org",
+            GetUploadURLExternalError::RequestTimeout => "request_timeout",
+            GetUploadURLExternalError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            GetUploadURLExternalError::Unknown(code) => return ::Error::from_code("files.getUploadURLExternal", &code),
+            GetUploadURLExternalError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.getUploadURLExternal", code)
+    }
+}
+
+impl<E: Error> fmt::Display for GetUploadURLExternalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for GetUploadURLExternalError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &GetUploadURLExternalError::InvalidLength => "invalid_length: The length passed was invalid.",
+            &GetUploadURLExternalError::FileUploadsDisabled => "file_uploads_disabled: File uploads are disabled for this team.",
+            &GetUploadURLExternalError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &GetUploadURLExternalError::NotAuthed => "not_authed: No authentication token provided.",
+            &GetUploadURLExternalError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &GetUploadURLExternalError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &GetUploadURLExternalError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &GetUploadURLExternalError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &GetUploadURLExternalError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &GetUploadURLExternalError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &GetUploadURLExternalError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &GetUploadURLExternalError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &GetUploadURLExternalError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &GetUploadURLExternalError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &GetUploadURLExternalError::MalformedResponse(ref e) => e.description(),
+            &GetUploadURLExternalError::Unknown(ref s) => s,
+            &GetUploadURLExternalError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &GetUploadURLExternalError::MalformedResponse(ref e) => Some(e),
+            &GetUploadURLExternalError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 /// Gets information about a team file.
 ///
 /// Wraps https://api.slack.com/methods/files.info
@@ -170,22 +691,33 @@ pub fn info<R>(client: &R,
                -> Result<InfoResponse, InfoError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let params = vec![Some(("token", token)),
-                      Some(("file", request.file)),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    params.push(("file", request.file));
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
     let url = ::get_slack_url_for_method("files.info");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| InfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InfoResponse>(&result)
-                            .map_err(|e| InfoError::MalformedResponse(e))
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -198,6 +730,25 @@ pub struct InfoRequest<'a> {
     pub page: Option<u32>,
 }
 
+impl<'a> InfoRequest<'a> {
+    pub fn new(file: &'a str) -> Self {
+        InfoRequest {
+            file: file,
+            ..InfoRequest::default()
+        }
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InfoResponse {
     pub comments: Option<Vec<::FileComment>>,
@@ -206,6 +757,8 @@ pub struct InfoResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -279,6 +832,30 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::FileNotFound => "file_not_found",
+            InfoError::FileDeleted => "file_deleted",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("files.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.info", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -326,28 +903,49 @@ pub fn list<R>(client: &R,
                -> Result<ListResponse, ListError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let ts_from = request.ts_from.map(|ts_from| ts_from.to_string());
-    let ts_to = request.ts_to.map(|ts_to| ts_to.to_string());
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let params = vec![Some(("token", token)),
-                      request.user.map(|user| ("user", user)),
-                      request.channel.map(|channel| ("channel", channel)),
-                      ts_from.as_ref().map(|ts_from| ("ts_from", &ts_from[..])),
-                      ts_to.as_ref().map(|ts_to| ("ts_to", &ts_to[..])),
-                      request.types.map(|types| ("types", types)),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let ts_from = request.ts_from.map(::IntParam::new);
+    let ts_to = request.ts_to.map(::IntParam::new);
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let mut params = Vec::with_capacity(8);
+    params.push(("token", token));
+    if let Some(user) = request.user {
+        params.push(("user", user));
+    }
+    if let Some(channel) = request.channel {
+        params.push(("channel", channel));
+    }
+    if let Some(ref ts_from) = ts_from {
+        params.push(("ts_from", ts_from.as_str()));
+    }
+    if let Some(ref ts_to) = ts_to {
+        params.push(("ts_to", ts_to.as_str()));
+    }
+    if let Some(types) = request.types {
+        params.push(("types", types));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
     let url = ::get_slack_url_for_method("files.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -380,6 +978,47 @@ pub struct ListRequest<'a> {
     pub page: Option<u32>,
 }
 
+impl<'a> ListRequest<'a> {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    pub fn ts_from(mut self, ts_from: u32) -> Self {
+        self.ts_from = Some(ts_from);
+        self
+    }
+
+    pub fn ts_to(mut self, ts_to: u32) -> Self {
+        self.ts_to = Some(ts_to);
+        self
+    }
+
+    pub fn types(mut self, types: &'a str) -> Self {
+        self.types = Some(types);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
@@ -387,6 +1026,8 @@ pub struct ListResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -463,6 +1104,31 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::UserNotFound => "user_not_found",
+            ListError::UnknownType => "unknown_type",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::UserIsBot => "user_is_bot",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("files.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -512,17 +1178,26 @@ pub fn revoke_public_url<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("file".to_owned(), request.file.into());
     let url = ::get_slack_url_for_method("files.revokePublicURL");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| RevokePublicURLError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RevokePublicURLResponse>(&result)
-                            .map_err(|e| RevokePublicURLError::MalformedResponse(e))
+                      parse_revoke_public_url_response(result.body.as_bytes()).map_err(|err| err.into_error(RevokePublicURLError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_revoke_public_url_response(body: &[u8]) -> Result<RevokePublicURLResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RevokePublicURLResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -531,12 +1206,23 @@ pub struct RevokePublicURLRequest<'a> {
     pub file: &'a str,
 }
 
+impl<'a> RevokePublicURLRequest<'a> {
+    pub fn new(file: &'a str) -> Self {
+        RevokePublicURLRequest {
+            file: file,
+            ..RevokePublicURLRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RevokePublicURLResponse {
     error: Option<String>,
     pub file: Option<::File>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -614,6 +1300,31 @@ impl<'a, E: Error> From<&'a str> for RevokePublicURLError<E> {
     }
 }
 
+impl<E: Error> From<RevokePublicURLError<E>> for ::Error<E> {
+    fn from(err: RevokePublicURLError<E>) -> Self {
+        let code = match err {
+            RevokePublicURLError::FileNotFound => "file_not_found",
+            RevokePublicURLError::NotAuthed => "not_authed",
+            RevokePublicURLError::InvalidAuth => "invalid_auth",
+            RevokePublicURLError::AccountInactive => "account_inactive",
+            RevokePublicURLError::UserIsBot => "user_is_bot",
+            RevokePublicURLError::UserIsRestricted => "user_is_restricted",
+            RevokePublicURLError::InvalidArgName => "invalid_arg_name",
+            RevokePublicURLError::InvalidArrayArg => "invalid_array_arg",
+            RevokePublicURLError::InvalidCharset => "invalid_charset",
+            RevokePublicURLError::InvalidFormData => "invalid_form_data",
+            RevokePublicURLError::InvalidPostType => "invalid_post_type",
+            RevokePublicURLError::MissingPostType => "missing_post_type",
+            RevokePublicURLError::TeamAddedToOrg => "team_added_to_org",
+            RevokePublicURLError::RequestTimeout => "request_timeout",
+            RevokePublicURLError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RevokePublicURLError::Unknown(code) => return ::Error::from_code("files.revokePublicURL", &code),
+            RevokePublicURLError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.revokePublicURL", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RevokePublicURLError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -663,17 +1374,26 @@ pub fn shared_public_url<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("file".to_owned(), request.file.into());
     let url = ::get_slack_url_for_method("files.sharedPublicURL");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| SharedPublicURLError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SharedPublicURLResponse>(&result)
-                            .map_err(|e| SharedPublicURLError::MalformedResponse(e))
+                      parse_shared_public_url_response(result.body.as_bytes()).map_err(|err| err.into_error(SharedPublicURLError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_shared_public_url_response(body: &[u8]) -> Result<SharedPublicURLResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SharedPublicURLResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -682,12 +1402,23 @@ pub struct SharedPublicURLRequest<'a> {
     pub file: &'a str,
 }
 
+impl<'a> SharedPublicURLRequest<'a> {
+    pub fn new(file: &'a str) -> Self {
+        SharedPublicURLRequest {
+            file: file,
+            ..SharedPublicURLRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SharedPublicURLResponse {
     error: Option<String>,
     pub file: Option<::File>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -768,6 +1499,32 @@ impl<'a, E: Error> From<&'a str> for SharedPublicURLError<E> {
     }
 }
 
+impl<E: Error> From<SharedPublicURLError<E>> for ::Error<E> {
+    fn from(err: SharedPublicURLError<E>) -> Self {
+        let code = match err {
+            SharedPublicURLError::FileNotFound => "file_not_found",
+            SharedPublicURLError::NotAllowed => "not_allowed",
+            SharedPublicURLError::NotAuthed => "not_authed",
+            SharedPublicURLError::InvalidAuth => "invalid_auth",
+            SharedPublicURLError::AccountInactive => "account_inactive",
+            SharedPublicURLError::UserIsBot => "user_is_bot",
+            SharedPublicURLError::UserIsRestricted => "user_is_restricted",
+            SharedPublicURLError::InvalidArgName => "invalid_arg_name",
+            SharedPublicURLError::InvalidArrayArg => "invalid_array_arg",
+            SharedPublicURLError::InvalidCharset => "invalid_charset",
+            SharedPublicURLError::InvalidFormData => "invalid_form_data",
+            SharedPublicURLError::InvalidPostType => "invalid_post_type",
+            SharedPublicURLError::MissingPostType => "missing_post_type",
+            SharedPublicURLError::TeamAddedToOrg => "team_added_to_org",
+            SharedPublicURLError::RequestTimeout => "request_timeout",
+            SharedPublicURLError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SharedPublicURLError::Unknown(code) => return ::Error::from_code("files.sharedPublicURL", &code),
+            SharedPublicURLError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.sharedPublicURL", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SharedPublicURLError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -806,3 +1563,356 @@ impl<E: Error> Error for SharedPublicURLError<E> {
         }
     }
 }
+
+/// Uploads or creates a file.
+///
+/// Wraps https://api.slack.com/methods/files.upload
+#[deprecated(note = "files.upload was retired by Slack: use upload::upload_and_share")]
+
+pub fn upload<R>(client: &R,
+                 token: &str,
+                 request: &UploadRequest)
+                 -> Result<UploadResponse, UploadError<R::Error>>
+    where R: SlackMultipartSender
+{
+
+    let mut params = Vec::with_capacity(7);
+    if let Some(channels) = request.channels {
+        params.push(("channels", channels));
+    }
+    if let Some(content) = request.content {
+        params.push(("content", content));
+    }
+    if let Some(filename) = request.filename {
+        params.push(("filename", filename));
+    }
+    if let Some(filetype) = request.filetype {
+        params.push(("filetype", filetype));
+    }
+    if let Some(initial_comment) = request.initial_comment {
+        params.push(("initial_comment", initial_comment));
+    }
+    if let Some(thread_ts) = request.thread_ts {
+        params.push(("thread_ts", thread_ts));
+    }
+    if let Some(title) = request.title {
+        params.push(("title", title));
+    }
+    let file = request.file.map(|content| {
+                                    ::requests::FilePart {
+                                        name: "file",
+                                        filename: request.filename.unwrap_or("file"),
+                                        content: content,
+                                    }
+                                });
+    let url = ::get_slack_url_for_method("files.upload");
+    client
+        .send_multipart(&url, token, &params[..], file.as_ref())
+        .map_err(|err| UploadError::Client(err))
+        .and_then(|result| {
+                      parse_upload_response(result.body.as_bytes()).map_err(|err| err.into_error(UploadError::MalformedResponse))
+                  })
+}
+
+fn parse_upload_response(body: &[u8]) -> Result<UploadResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UploadResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UploadRequest<'a> {
+    /// Comma-separated list of channel names or IDs where the file will be shared.
+    pub channels: Option<&'a str>,
+    /// File contents via a POST variable. If omitting this parameter, you must provide a `file`.
+    pub content: Option<&'a str>,
+    /// File contents via `multipart/form-data`. If omitting this parameter, you must submit `content`.
+    pub file: Option<&'a [u8]>,
+    /// Filename of file.
+    pub filename: Option<&'a str>,
+    /// A file type identifier.
+    pub filetype: Option<&'a str>,
+    /// The message text introducing the file in specified `channels`.
+    pub initial_comment: Option<&'a str>,
+    /// Provide another message's `ts` value to upload this file as a reply. Never use a reply's `ts` value; use its parent instead.
+    pub thread_ts: Option<&'a str>,
+    /// Title of file.
+    pub title: Option<&'a str>,
+}
+
+impl<'a> UploadRequest<'a> {
+    pub fn new() -> Self {
+        UploadRequest::default()
+    }
+
+    pub fn channels(mut self, channels: &'a str) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    pub fn content(mut self, content: &'a str) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn file(mut self, file: &'a [u8]) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn filename(mut self, filename: &'a str) -> Self {
+        self.filename = Some(filename);
+        self
+    }
+
+    pub fn filetype(mut self, filetype: &'a str) -> Self {
+        self.filetype = Some(filetype);
+        self
+    }
+
+    pub fn initial_comment(mut self, initial_comment: &'a str) -> Self {
+        self.initial_comment = Some(initial_comment);
+        self
+    }
+
+    pub fn thread_ts(mut self, thread_ts: &'a str) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UploadResponse {
+    error: Option<String>,
+    pub file: Option<::File>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<UploadResponse, UploadError<E>>> for UploadResponse {
+    fn into(self) -> Result<UploadResponse, UploadError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UploadError<E: Error> {
+    /// An admin has restricted posting to the #general channel.
+    PostingToGeneralChannelDenied,
+    /// One or more channels supplied are invalid.
+    InvalidChannel,
+    /// File uploads are disabled for this team.
+    FileUploadsDisabled,
+    /// File uploads are disabled except for images.
+    FileUploadsExceptImagesDisabled,
+    /// Storage limit reached for the team.
+    StorageLimitReached,
+    /// The method has been deprecated.
+    MethodDeprecated,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UploadError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "posting_to_general_channel_denied" => UploadError::PostingToGeneralChannelDenied,
+            "invalid_channel" => UploadError::InvalidChannel,
+            "file_uploads_disabled" => UploadError::FileUploadsDisabled,
+            "file_uploads_except_images_disabled" => UploadError::FileUploadsExceptImagesDisabled,
+            "storage_limit_reached" => UploadError::StorageLimitReached,
+            "method_deprecated" => UploadError::MethodDeprecated,
+            "missing_scope" => UploadError::MissingScope,
+            "not_authed" => UploadError::NotAuthed,
+            "invalid_auth" => UploadError::InvalidAuth,
+            "account_inactive" => UploadError::AccountInactive,
+            "invalid_arg_name" => UploadError::InvalidArgName,
+            "invalid_array_arg" => UploadError::InvalidArrayArg,
+            "invalid_charset" => UploadError::InvalidCharset,
+            "invalid_form_data" => UploadError::InvalidFormData,
+            "invalid_post_type" => UploadError::InvalidPostType,
+            "missing_post_type" => UploadError::MissingPostType,
+            "team_added_to_org" => UploadError::TeamAddedToOrg,
+            "request_timeout" => UploadError::RequestTimeout,
+            _ => UploadError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<UploadError<E>> for ::Error<E> {
+    fn from(err: UploadError<E>) -> Self {
+        let code = match err {
+            UploadError::PostingToGeneralChannelDenied => "posting_to_general_channel_denied",
+            UploadError::InvalidChannel => "invalid_channel",
+            UploadError::FileUploadsDisabled => "file_uploads_disabled",
+            UploadError::FileUploadsExceptImagesDisabled => "file_uploads_except_images_disabled",
+            UploadError::StorageLimitReached => "storage_limit_reached",
+            UploadError::MethodDeprecated => "method_deprecated",
+            UploadError::MissingScope => "missing_scope",
+            UploadError::NotAuthed => "not_authed",
+            UploadError::InvalidAuth => "invalid_auth",
+            UploadError::AccountInactive => "account_inactive",
+            UploadError::InvalidArgName => "invalid_arg_name",
+            UploadError::InvalidArrayArg => "invalid_array_arg",
+            UploadError::InvalidCharset => "invalid_charset",
+            UploadError::InvalidFormData => "invalid_form_data",
+            UploadError::InvalidPostType => "invalid_post_type",
+            UploadError::MissingPostType => "missing_post_type",
+            UploadError::TeamAddedToOrg => "team_added_to_org",
+            UploadError::RequestTimeout => "request_timeout",
+            UploadError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UploadError::Unknown(code) => return ::Error::from_code("files.upload", &code),
+            UploadError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.upload", code)
+    }
+}
+
+impl<E: Error> fmt::Display for UploadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UploadError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &UploadError::PostingToGeneralChannelDenied => "posting_to_general_channel_denied: An admin has restricted posting to the #general channel.",
+            &UploadError::InvalidChannel => "invalid_channel: One or more channels supplied are invalid.",
+            &UploadError::FileUploadsDisabled => "file_uploads_disabled: File uploads are disabled for this team.",
+            &UploadError::FileUploadsExceptImagesDisabled => "file_uploads_except_images_disabled: File uploads are disabled except for images.",
+            &UploadError::StorageLimitReached => "storage_limit_reached: Storage limit reached for the team.",
+            &UploadError::MethodDeprecated => "method_deprecated: The method has been deprecated.",
+            &UploadError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &UploadError::NotAuthed => "not_authed: No authentication token provided.",
+            &UploadError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &UploadError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &UploadError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &UploadError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &UploadError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &UploadError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &UploadError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &UploadError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &UploadError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &UploadError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &UploadError::MalformedResponse(ref e) => e.description(),
+            &UploadError::Unknown(ref s) => s,
+            &UploadError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &UploadError::MalformedResponse(ref e) => Some(e),
+            &UploadError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `files.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::files`](../client/struct.SlackClient.html#method.files).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Finishes an upload started with files.getUploadURLExternal. See
+    /// [`complete_upload_external`](fn.complete_upload_external.html).
+    pub fn complete_upload_external(&self, request: &CompleteUploadExternalRequest)
+                                    -> Result<CompleteUploadExternalResponse, CompleteUploadExternalError<R::Error>> {
+        complete_upload_external(self.client, self.token.for_method("files.completeUploadExternal"), request)
+    }
+
+    /// Deletes a file. See [`delete`](fn.delete.html).
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<R::Error>> {
+        delete(self.client, self.token.for_method("files.delete"), request)
+    }
+
+    /// Gets a URL for an edge external file upload. See
+    /// [`get_upload_url_external`](fn.get_upload_url_external.html).
+    pub fn get_upload_url_external(&self, request: &GetUploadURLExternalRequest)
+                                   -> Result<GetUploadURLExternalResponse, GetUploadURLExternalError<R::Error>> {
+        get_upload_url_external(self.client, self.token.for_method("files.getUploadURLExternal"), request)
+    }
+
+    /// Gets information about a team file. See [`info`](fn.info.html).
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<R::Error>> {
+        info(self.client, self.token.for_method("files.info"), request)
+    }
+
+    /// Lists & filters team files. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("files.list"), request)
+    }
+
+    /// Revokes public/external sharing access for a file. See
+    /// [`revoke_public_url`](fn.revoke_public_url.html).
+    pub fn revoke_public_url(&self, request: &RevokePublicURLRequest)
+                             -> Result<RevokePublicURLResponse, RevokePublicURLError<R::Error>> {
+        revoke_public_url(self.client, self.token.for_method("files.revokePublicURL"), request)
+    }
+
+    /// Enables a file for public/external sharing. See
+    /// [`shared_public_url`](fn.shared_public_url.html).
+    pub fn shared_public_url(&self, request: &SharedPublicURLRequest)
+                             -> Result<SharedPublicURLResponse, SharedPublicURLError<R::Error>> {
+        shared_public_url(self.client, self.token.for_method("files.sharedPublicURL"), request)
+    }
+}
@@ -21,19 +21,27 @@ pub fn add<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("file", request.file)),
-                      Some(("comment", request.comment))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("file".to_owned(), request.file.into());
+    body.insert("comment".to_owned(), request.comment.into());
     let url = ::get_slack_url_for_method("files.comments.add");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| AddError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<AddResponse>(&result)
-                            .map_err(|e| AddError::MalformedResponse(e))
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -44,12 +52,24 @@ pub struct AddRequest<'a> {
     pub comment: &'a str,
 }
 
+impl<'a> AddRequest<'a> {
+    pub fn new(file: &'a str, comment: &'a str) -> Self {
+        AddRequest {
+            file: file,
+            comment: comment,
+            ..AddRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AddResponse {
     pub comment: Option<::FileComment>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -126,6 +146,31 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::FileNotFound => "file_not_found",
+            AddError::FileDeleted => "file_deleted",
+            AddError::NoComment => "no_comment",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("files.comments.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.comments.add", code)
+    }
+}
+
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -175,19 +220,27 @@ pub fn delete<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("file", request.file)),
-                      Some(("id", request.id))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("file".to_owned(), request.file.into());
+    body.insert("id".to_owned(), request.id.into());
     let url = ::get_slack_url_for_method("files.comments.delete");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| DeleteError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<DeleteResponse>(&result)
-                            .map_err(|e| DeleteError::MalformedResponse(e))
+                      parse_delete_response(result.body.as_bytes()).map_err(|err| err.into_error(DeleteError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_delete_response(body: &[u8]) -> Result<DeleteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<DeleteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -198,11 +251,23 @@ pub struct DeleteRequest<'a> {
     pub id: &'a str,
 }
 
+impl<'a> DeleteRequest<'a> {
+    pub fn new(file: &'a str, id: &'a str) -> Self {
+        DeleteRequest {
+            file: file,
+            id: id,
+            ..DeleteRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -279,6 +344,31 @@ impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     }
 }
 
+impl<E: Error> From<DeleteError<E>> for ::Error<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        let code = match err {
+            DeleteError::FileNotFound => "file_not_found",
+            DeleteError::FileDeleted => "file_deleted",
+            DeleteError::CantDelete => "cant_delete",
+            DeleteError::NotAuthed => "not_authed",
+            DeleteError::InvalidAuth => "invalid_auth",
+            DeleteError::AccountInactive => "account_inactive",
+            DeleteError::InvalidArgName => "invalid_arg_name",
+            DeleteError::InvalidArrayArg => "invalid_array_arg",
+            DeleteError::InvalidCharset => "invalid_charset",
+            DeleteError::InvalidFormData => "invalid_form_data",
+            DeleteError::InvalidPostType => "invalid_post_type",
+            DeleteError::MissingPostType => "missing_post_type",
+            DeleteError::TeamAddedToOrg => "team_added_to_org",
+            DeleteError::RequestTimeout => "request_timeout",
+            DeleteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            DeleteError::Unknown(code) => return ::Error::from_code("files.comments.delete", &code),
+            DeleteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.comments.delete", code)
+    }
+}
+
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -328,20 +418,28 @@ pub fn edit<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("file", request.file)),
-                      Some(("id", request.id)),
-                      Some(("comment", request.comment))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("file".to_owned(), request.file.into());
+    body.insert("id".to_owned(), request.id.into());
+    body.insert("comment".to_owned(), request.comment.into());
     let url = ::get_slack_url_for_method("files.comments.edit");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| EditError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<EditResponse>(&result)
-                            .map_err(|e| EditError::MalformedResponse(e))
+                      parse_edit_response(result.body.as_bytes()).map_err(|err| err.into_error(EditError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_edit_response(body: &[u8]) -> Result<EditResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<EditResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -354,12 +452,25 @@ pub struct EditRequest<'a> {
     pub comment: &'a str,
 }
 
+impl<'a> EditRequest<'a> {
+    pub fn new(file: &'a str, id: &'a str, comment: &'a str) -> Self {
+        EditRequest {
+            file: file,
+            id: id,
+            comment: comment,
+            ..EditRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct EditResponse {
     pub comment: Option<::FileComment>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -442,6 +553,33 @@ impl<'a, E: Error> From<&'a str> for EditError<E> {
     }
 }
 
+impl<E: Error> From<EditError<E>> for ::Error<E> {
+    fn from(err: EditError<E>) -> Self {
+        let code = match err {
+            EditError::FileNotFound => "file_not_found",
+            EditError::FileDeleted => "file_deleted",
+            EditError::NoComment => "no_comment",
+            EditError::EditWindowClosed => "edit_window_closed",
+            EditError::CantEdit => "cant_edit",
+            EditError::NotAuthed => "not_authed",
+            EditError::InvalidAuth => "invalid_auth",
+            EditError::AccountInactive => "account_inactive",
+            EditError::InvalidArgName => "invalid_arg_name",
+            EditError::InvalidArrayArg => "invalid_array_arg",
+            EditError::InvalidCharset => "invalid_charset",
+            EditError::InvalidFormData => "invalid_form_data",
+            EditError::InvalidPostType => "invalid_post_type",
+            EditError::MissingPostType => "missing_post_type",
+            EditError::TeamAddedToOrg => "team_added_to_org",
+            EditError::RequestTimeout => "request_timeout",
+            EditError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            EditError::Unknown(code) => return ::Error::from_code("files.comments.edit", &code),
+            EditError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("files.comments.edit", code)
+    }
+}
+
 impl<E: Error> fmt::Display for EditError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -481,3 +619,35 @@ impl<E: Error> Error for EditError<E> {
         }
     }
 }
+
+/// The `files.comments.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::files_comments`](../client/struct.SlackClient.html#method.files_comments).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Add a comment to an existing file. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("files.comments.add"), request)
+    }
+
+    /// Deletes an existing comment on a file. See [`delete`](fn.delete.html).
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<R::Error>> {
+        delete(self.client, self.token.for_method("files.comments.delete"), request)
+    }
+
+    /// Edit an existing file comment. See [`edit`](fn.edit.html).
+    pub fn edit(&self, request: &EditRequest) -> Result<EditResponse, EditError<R::Error>> {
+        edit(self.client, self.token.for_method("files.comments.edit"), request)
+    }
+}
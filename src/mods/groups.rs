@@ -1,4 +1,7 @@
 //! Get info on your team's private channels.
+//!
+//! Slack has retired these methods, so their calls are sent to the `conversations` methods that
+//! replaced them, and the responses translated back.
 
 
 #[allow(unused_imports)]
@@ -15,6 +18,7 @@ use requests::SlackWebRequestSender;
 ///
 /// Wraps https://api.slack.com/methods/groups.archive
 
+#[deprecated(note = "groups.archive was retired by Slack: use conversations::archive")]
 pub fn archive<R>(client: &R,
                   token: &str,
                   request: &ArchiveRequest)
@@ -22,17 +26,21 @@ pub fn archive<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.archive");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "groups.archive", &params[..])
         .map_err(|err| ArchiveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ArchiveResponse>(&result)
-                            .map_err(|e| ArchiveError::MalformedResponse(e))
+                      parse_archive_response(&result).map_err(|err| err.into_error(ArchiveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_archive_response(body: &[u8]) -> Result<ArchiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ArchiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -41,11 +49,22 @@ pub struct ArchiveRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> ArchiveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        ArchiveRequest {
+            channel: channel,
+            ..ArchiveRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ArchiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -131,6 +150,34 @@ impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
     }
 }
 
+impl<E: Error> From<ArchiveError<E>> for ::Error<E> {
+    fn from(err: ArchiveError<E>) -> Self {
+        let code = match err {
+            ArchiveError::ChannelNotFound => "channel_not_found",
+            ArchiveError::AlreadyArchived => "already_archived",
+            ArchiveError::GroupContainsOthers => "group_contains_others",
+            ArchiveError::RestrictedAction => "restricted_action",
+            ArchiveError::NotAuthed => "not_authed",
+            ArchiveError::InvalidAuth => "invalid_auth",
+            ArchiveError::AccountInactive => "account_inactive",
+            ArchiveError::UserIsBot => "user_is_bot",
+            ArchiveError::UserIsUltraRestricted => "user_is_ultra_restricted",
+            ArchiveError::InvalidArgName => "invalid_arg_name",
+            ArchiveError::InvalidArrayArg => "invalid_array_arg",
+            ArchiveError::InvalidCharset => "invalid_charset",
+            ArchiveError::InvalidFormData => "invalid_form_data",
+            ArchiveError::InvalidPostType => "invalid_post_type",
+            ArchiveError::MissingPostType => "missing_post_type",
+            ArchiveError::TeamAddedToOrg => "team_added_to_org",
+            ArchiveError::RequestTimeout => "request_timeout",
+            ArchiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ArchiveError::Unknown(code) => return ::Error::from_code("groups.archive", &code),
+            ArchiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.archive", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ArchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -176,6 +223,7 @@ impl<E: Error> Error for ArchiveError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.close
 
+#[deprecated(note = "groups.close was retired by Slack: use conversations::close")]
 pub fn close<R>(client: &R,
                 token: &str,
                 request: &CloseRequest)
@@ -183,17 +231,21 @@ pub fn close<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.close");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "groups.close", &params[..])
         .map_err(|err| CloseError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<CloseResponse>(&result)
-                            .map_err(|e| CloseError::MalformedResponse(e))
+                      parse_close_response(&result).map_err(|err| err.into_error(CloseError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_close_response(body: &[u8]) -> Result<CloseResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CloseResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -202,11 +254,22 @@ pub struct CloseRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> CloseRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        CloseRequest {
+            channel: channel,
+            ..CloseRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CloseResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -277,6 +340,29 @@ impl<'a, E: Error> From<&'a str> for CloseError<E> {
     }
 }
 
+impl<E: Error> From<CloseError<E>> for ::Error<E> {
+    fn from(err: CloseError<E>) -> Self {
+        let code = match err {
+            CloseError::ChannelNotFound => "channel_not_found",
+            CloseError::NotAuthed => "not_authed",
+            CloseError::InvalidAuth => "invalid_auth",
+            CloseError::AccountInactive => "account_inactive",
+            CloseError::InvalidArgName => "invalid_arg_name",
+            CloseError::InvalidArrayArg => "invalid_array_arg",
+            CloseError::InvalidCharset => "invalid_charset",
+            CloseError::InvalidFormData => "invalid_form_data",
+            CloseError::InvalidPostType => "invalid_post_type",
+            CloseError::MissingPostType => "missing_post_type",
+            CloseError::TeamAddedToOrg => "team_added_to_org",
+            CloseError::RequestTimeout => "request_timeout",
+            CloseError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CloseError::Unknown(code) => return ::Error::from_code("groups.close", &code),
+            CloseError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.close", code)
+    }
+}
+
 impl<E: Error> fmt::Display for CloseError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -317,6 +403,7 @@ impl<E: Error> Error for CloseError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.create
 
+#[deprecated(note = "groups.create was retired by Slack: use conversations::create")]
 pub fn create<R>(client: &R,
                  token: &str,
                  request: &CreateRequest)
@@ -324,21 +411,26 @@ pub fn create<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("name", request.name)),
-                      request
-                          .validate
-                          .map(|validate| ("validate", if validate { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.create");
-    client
-        .send(&url, &params[..])
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    params.push(("name", request.name));
+    if let Some(validate) = request.validate {
+        params.push(("validate", if validate { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "groups.create", &params[..])
         .map_err(|err| CreateError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<CreateResponse>(&result)
-                            .map_err(|e| CreateError::MalformedResponse(e))
+                      parse_create_response(&result).map_err(|err| err.into_error(CreateError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_create_response(body: &[u8]) -> Result<CreateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CreateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -349,12 +441,28 @@ pub struct CreateRequest<'a> {
     pub validate: Option<bool>,
 }
 
+impl<'a> CreateRequest<'a> {
+    pub fn new(name: &'a str) -> Self {
+        CreateRequest {
+            name: name,
+            ..CreateRequest::default()
+        }
+    }
+
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateResponse {
     error: Option<String>,
     pub group: Option<::Group>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -452,6 +560,38 @@ impl<'a, E: Error> From<&'a str> for CreateError<E> {
     }
 }
 
+impl<E: Error> From<CreateError<E>> for ::Error<E> {
+    fn from(err: CreateError<E>) -> Self {
+        let code = match err {
+            CreateError::NoChannel => "no_channel",
+            CreateError::RestrictedAction => "restricted_action",
+            CreateError::NameTaken => "name_taken",
+            CreateError::InvalidNameRequired => "invalid_name_required",
+            CreateError::InvalidNamePunctuation => "invalid_name_punctuation",
+            CreateError::InvalidNameMaxlength => "invalid_name_maxlength",
+            CreateError::InvalidNameSpecials => "invalid_name_specials",
+            CreateError::InvalidName => "invalid_name",
+            CreateError::NotAuthed => "not_authed",
+            CreateError::InvalidAuth => "invalid_auth",
+            CreateError::AccountInactive => "account_inactive",
+            CreateError::UserIsBot => "user_is_bot",
+            CreateError::UserIsUltraRestricted => "user_is_ultra_restricted",
+            CreateError::InvalidArgName => "invalid_arg_name",
+            CreateError::InvalidArrayArg => "invalid_array_arg",
+            CreateError::InvalidCharset => "invalid_charset",
+            CreateError::InvalidFormData => "invalid_form_data",
+            CreateError::InvalidPostType => "invalid_post_type",
+            CreateError::MissingPostType => "missing_post_type",
+            CreateError::TeamAddedToOrg => "team_added_to_org",
+            CreateError::RequestTimeout => "request_timeout",
+            CreateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CreateError::Unknown(code) => return ::Error::from_code("groups.create", &code),
+            CreateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.create", code)
+    }
+}
+
 impl<E: Error> fmt::Display for CreateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -501,6 +641,7 @@ impl<E: Error> Error for CreateError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.createChild
 
+#[deprecated(note = "groups.createChild was retired by Slack and has no replacement")]
 pub fn create_child<R>(client: &R,
                        token: &str,
                        request: &CreateChildRequest)
@@ -508,17 +649,21 @@ pub fn create_child<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.createChild");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "groups.createChild", &params[..])
         .map_err(|err| CreateChildError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<CreateChildResponse>(&result)
-                            .map_err(|e| CreateChildError::MalformedResponse(e))
+                      parse_create_child_response(&result).map_err(|err| err.into_error(CreateChildError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_create_child_response(body: &[u8]) -> Result<CreateChildResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CreateChildResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -527,12 +672,23 @@ pub struct CreateChildRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> CreateChildRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        CreateChildRequest {
+            channel: channel,
+            ..CreateChildRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateChildResponse {
     error: Option<String>,
     pub group: Option<::Group>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -615,6 +771,33 @@ impl<'a, E: Error> From<&'a str> for CreateChildError<E> {
     }
 }
 
+impl<E: Error> From<CreateChildError<E>> for ::Error<E> {
+    fn from(err: CreateChildError<E>) -> Self {
+        let code = match err {
+            CreateChildError::ChannelNotFound => "channel_not_found",
+            CreateChildError::AlreadyArchived => "already_archived",
+            CreateChildError::RestrictedAction => "restricted_action",
+            CreateChildError::NotAuthed => "not_authed",
+            CreateChildError::InvalidAuth => "invalid_auth",
+            CreateChildError::AccountInactive => "account_inactive",
+            CreateChildError::UserIsBot => "user_is_bot",
+            CreateChildError::UserIsUltraRestricted => "user_is_ultra_restricted",
+            CreateChildError::InvalidArgName => "invalid_arg_name",
+            CreateChildError::InvalidArrayArg => "invalid_array_arg",
+            CreateChildError::InvalidCharset => "invalid_charset",
+            CreateChildError::InvalidFormData => "invalid_form_data",
+            CreateChildError::InvalidPostType => "invalid_post_type",
+            CreateChildError::MissingPostType => "missing_post_type",
+            CreateChildError::TeamAddedToOrg => "team_added_to_org",
+            CreateChildError::RequestTimeout => "request_timeout",
+            CreateChildError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CreateChildError::Unknown(code) => return ::Error::from_code("groups.createChild", &code),
+            CreateChildError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.createChild", code)
+    }
+}
+
 impl<E: Error> fmt::Display for CreateChildError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -659,34 +842,48 @@ impl<E: Error> Error for CreateChildError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.history
 
+#[deprecated(note = "groups.history was retired by Slack: use conversations::history")]
 pub fn history<R>(client: &R,
                   token: &str,
                   request: &HistoryRequest)
                   -> Result<HistoryResponse, HistoryError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      request.latest.map(|latest| ("latest", latest)),
-                      request.oldest.map(|oldest| ("oldest", oldest)),
-                      request
-                          .inclusive
-                          .map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      request
-                          .unreads
-                          .map(|unreads| ("unreads", if unreads { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.history");
-    client
-        .send(&url, &params[..])
+    let count = request.count.map(::IntParam::new);
+    let mut params = Vec::with_capacity(7);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    if let Some(latest) = request.latest {
+        params.push(("latest", latest));
+    }
+    if let Some(oldest) = request.oldest {
+        params.push(("oldest", oldest));
+    }
+    if let Some(inclusive) = request.inclusive {
+        params.push(("inclusive", if inclusive { "1" } else { "0" }));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(unreads) = request.unreads {
+        params.push(("unreads", if unreads { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "groups.history", &params[..])
         .map_err(|err| HistoryError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<HistoryResponse>(&result)
-                            .map_err(|e| HistoryError::MalformedResponse(e))
+                      parse_history_response(&result).map_err(|err| err.into_error(HistoryError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_history_response(body: &[u8]) -> Result<HistoryResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<HistoryResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -705,6 +902,40 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
+impl<'a> HistoryRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        HistoryRequest {
+            channel: channel,
+            ..HistoryRequest::default()
+        }
+    }
+
+    pub fn latest(mut self, latest: &'a str) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: &'a str) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct HistoryResponse {
     error: Option<String>,
@@ -713,6 +944,10 @@ pub struct HistoryResponse {
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -789,6 +1024,31 @@ impl<'a, E: Error> From<&'a str> for HistoryError<E> {
     }
 }
 
+impl<E: Error> From<HistoryError<E>> for ::Error<E> {
+    fn from(err: HistoryError<E>) -> Self {
+        let code = match err {
+            HistoryError::ChannelNotFound => "channel_not_found",
+            HistoryError::InvalidTsLatest => "invalid_ts_latest",
+            HistoryError::InvalidTsOldest => "invalid_ts_oldest",
+            HistoryError::NotAuthed => "not_authed",
+            HistoryError::InvalidAuth => "invalid_auth",
+            HistoryError::AccountInactive => "account_inactive",
+            HistoryError::InvalidArgName => "invalid_arg_name",
+            HistoryError::InvalidArrayArg => "invalid_array_arg",
+            HistoryError::InvalidCharset => "invalid_charset",
+            HistoryError::InvalidFormData => "invalid_form_data",
+            HistoryError::InvalidPostType => "invalid_post_type",
+            HistoryError::MissingPostType => "missing_post_type",
+            HistoryError::TeamAddedToOrg => "team_added_to_org",
+            HistoryError::RequestTimeout => "request_timeout",
+            HistoryError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            HistoryError::Unknown(code) => return ::Error::from_code("groups.history", &code),
+            HistoryError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.history", code)
+    }
+}
+
 impl<E: Error> fmt::Display for HistoryError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -831,6 +1091,7 @@ impl<E: Error> Error for HistoryError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.info
 
+#[deprecated(note = "groups.info was retired by Slack: use conversations::info")]
 pub fn info<R>(client: &R,
                token: &str,
                request: &InfoRequest)
@@ -838,17 +1099,21 @@ pub fn info<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.info");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "groups.info", &params[..])
         .map_err(|err| InfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InfoResponse>(&result)
-                            .map_err(|e| InfoError::MalformedResponse(e))
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -857,12 +1122,23 @@ pub struct InfoRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> InfoRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        InfoRequest {
+            channel: channel,
+            ..InfoRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InfoResponse {
     error: Option<String>,
     pub group: Option<::Group>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -933,6 +1209,29 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::ChannelNotFound => "channel_not_found",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("groups.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.info", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -973,6 +1272,7 @@ impl<E: Error> Error for InfoError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.invite
 
+#[deprecated(note = "groups.invite was retired by Slack: use conversations::invite")]
 pub fn invite<R>(client: &R,
                  token: &str,
                  request: &InviteRequest)
@@ -980,19 +1280,21 @@ pub fn invite<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.invite");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("user", request.user)];
+    ::legacy::send_bytes(client, "groups.invite", &params[..])
         .map_err(|err| InviteError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InviteResponse>(&result)
-                            .map_err(|e| InviteError::MalformedResponse(e))
+                      parse_invite_response(&result).map_err(|err| err.into_error(InviteError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_invite_response(body: &[u8]) -> Result<InviteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InviteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1003,12 +1305,24 @@ pub struct InviteRequest<'a> {
     pub user: &'a str,
 }
 
+impl<'a> InviteRequest<'a> {
+    pub fn new(channel: &'a str, user: &'a str) -> Self {
+        InviteRequest {
+            channel: channel,
+            user: user,
+            ..InviteRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InviteResponse {
     error: Option<String>,
     pub group: Option<::Group>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1100,6 +1414,36 @@ impl<'a, E: Error> From<&'a str> for InviteError<E> {
     }
 }
 
+impl<E: Error> From<InviteError<E>> for ::Error<E> {
+    fn from(err: InviteError<E>) -> Self {
+        let code = match err {
+            InviteError::ChannelNotFound => "channel_not_found",
+            InviteError::UserNotFound => "user_not_found",
+            InviteError::CantInviteSelf => "cant_invite_self",
+            InviteError::IsArchived => "is_archived",
+            InviteError::CantInvite => "cant_invite",
+            InviteError::UraMaxChannels => "ura_max_channels",
+            InviteError::NotAuthed => "not_authed",
+            InviteError::InvalidAuth => "invalid_auth",
+            InviteError::AccountInactive => "account_inactive",
+            InviteError::UserIsBot => "user_is_bot",
+            InviteError::UserIsUltraRestricted => "user_is_ultra_restricted",
+            InviteError::InvalidArgName => "invalid_arg_name",
+            InviteError::InvalidArrayArg => "invalid_array_arg",
+            InviteError::InvalidCharset => "invalid_charset",
+            InviteError::InvalidFormData => "invalid_form_data",
+            InviteError::InvalidPostType => "invalid_post_type",
+            InviteError::MissingPostType => "missing_post_type",
+            InviteError::TeamAddedToOrg => "team_added_to_org",
+            InviteError::RequestTimeout => "request_timeout",
+            InviteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InviteError::Unknown(code) => return ::Error::from_code("groups.invite", &code),
+            InviteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.invite", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InviteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1147,6 +1491,7 @@ impl<E: Error> Error for InviteError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.kick
 
+#[deprecated(note = "groups.kick was retired by Slack: use conversations::kick")]
 pub fn kick<R>(client: &R,
                token: &str,
                request: &KickRequest)
@@ -1154,19 +1499,21 @@ pub fn kick<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.kick");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("user", request.user)];
+    ::legacy::send_bytes(client, "groups.kick", &params[..])
         .map_err(|err| KickError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<KickResponse>(&result)
-                            .map_err(|e| KickError::MalformedResponse(e))
+                      parse_kick_response(&result).map_err(|err| err.into_error(KickError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_kick_response(body: &[u8]) -> Result<KickResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<KickResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1177,11 +1524,23 @@ pub struct KickRequest<'a> {
     pub user: &'a str,
 }
 
+impl<'a> KickRequest<'a> {
+    pub fn new(channel: &'a str, user: &'a str) -> Self {
+        KickRequest {
+            channel: channel,
+            user: user,
+            ..KickRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct KickResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1270,6 +1629,35 @@ impl<'a, E: Error> From<&'a str> for KickError<E> {
     }
 }
 
+impl<E: Error> From<KickError<E>> for ::Error<E> {
+    fn from(err: KickError<E>) -> Self {
+        let code = match err {
+            KickError::ChannelNotFound => "channel_not_found",
+            KickError::UserNotFound => "user_not_found",
+            KickError::CantKickSelf => "cant_kick_self",
+            KickError::NotInGroup => "not_in_group",
+            KickError::RestrictedAction => "restricted_action",
+            KickError::NotAuthed => "not_authed",
+            KickError::InvalidAuth => "invalid_auth",
+            KickError::AccountInactive => "account_inactive",
+            KickError::UserIsBot => "user_is_bot",
+            KickError::UserIsRestricted => "user_is_restricted",
+            KickError::InvalidArgName => "invalid_arg_name",
+            KickError::InvalidArrayArg => "invalid_array_arg",
+            KickError::InvalidCharset => "invalid_charset",
+            KickError::InvalidFormData => "invalid_form_data",
+            KickError::InvalidPostType => "invalid_post_type",
+            KickError::MissingPostType => "missing_post_type",
+            KickError::TeamAddedToOrg => "team_added_to_org",
+            KickError::RequestTimeout => "request_timeout",
+            KickError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            KickError::Unknown(code) => return ::Error::from_code("groups.kick", &code),
+            KickError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.kick", code)
+    }
+}
+
 impl<E: Error> fmt::Display for KickError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1316,6 +1704,7 @@ impl<E: Error> Error for KickError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.leave
 
+#[deprecated(note = "groups.leave was retired by Slack: use conversations::leave")]
 pub fn leave<R>(client: &R,
                 token: &str,
                 request: &LeaveRequest)
@@ -1323,17 +1712,21 @@ pub fn leave<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.leave");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "groups.leave", &params[..])
         .map_err(|err| LeaveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<LeaveResponse>(&result)
-                            .map_err(|e| LeaveError::MalformedResponse(e))
+                      parse_leave_response(&result).map_err(|err| err.into_error(LeaveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_leave_response(body: &[u8]) -> Result<LeaveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<LeaveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1342,11 +1735,22 @@ pub struct LeaveRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> LeaveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        LeaveRequest {
+            channel: channel,
+            ..LeaveRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct LeaveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1426,6 +1830,32 @@ impl<'a, E: Error> From<&'a str> for LeaveError<E> {
     }
 }
 
+impl<E: Error> From<LeaveError<E>> for ::Error<E> {
+    fn from(err: LeaveError<E>) -> Self {
+        let code = match err {
+            LeaveError::ChannelNotFound => "channel_not_found",
+            LeaveError::IsArchived => "is_archived",
+            LeaveError::NotAuthed => "not_authed",
+            LeaveError::InvalidAuth => "invalid_auth",
+            LeaveError::AccountInactive => "account_inactive",
+            LeaveError::UserIsBot => "user_is_bot",
+            LeaveError::UserIsUltraRestricted => "user_is_ultra_restricted",
+            LeaveError::InvalidArgName => "invalid_arg_name",
+            LeaveError::InvalidArrayArg => "invalid_array_arg",
+            LeaveError::InvalidCharset => "invalid_charset",
+            LeaveError::InvalidFormData => "invalid_form_data",
+            LeaveError::InvalidPostType => "invalid_post_type",
+            LeaveError::MissingPostType => "missing_post_type",
+            LeaveError::TeamAddedToOrg => "team_added_to_org",
+            LeaveError::RequestTimeout => "request_timeout",
+            LeaveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            LeaveError::Unknown(code) => return ::Error::from_code("groups.leave", &code),
+            LeaveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.leave", code)
+    }
+}
+
 impl<E: Error> fmt::Display for LeaveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1469,6 +1899,7 @@ impl<E: Error> Error for LeaveError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.list
 
+#[deprecated(note = "groups.list was retired by Slack: use conversations::list")]
 pub fn list<R>(client: &R,
                token: &str,
                request: &ListRequest)
@@ -1476,22 +1907,27 @@ pub fn list<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request
-                          .exclude_archived
-                          .map(|exclude_archived| {
-                                   ("exclude_archived", if exclude_archived { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.list");
-    client
-        .send(&url, &params[..])
+    let mut params = Vec::with_capacity(2);
+    params.push(("token", token));
+    if let Some(exclude_archived) = request.exclude_archived {
+        params.push(("exclude_archived", if exclude_archived { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "groups.list", &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<ListResponse>(body, &[("groups", ::item_error::<::Group>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1500,12 +1936,27 @@ pub struct ListRequest {
     pub exclude_archived: Option<bool>,
 }
 
+impl ListRequest {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub groups: Option<Vec<::Group>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1573,6 +2024,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("groups.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1612,6 +2085,7 @@ impl<E: Error> Error for ListError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.mark
 
+#[deprecated(note = "groups.mark was retired by Slack: use conversations::mark")]
 pub fn mark<R>(client: &R,
                token: &str,
                request: &MarkRequest)
@@ -1619,19 +2093,21 @@ pub fn mark<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("ts", request.ts))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.mark");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("ts", request.ts)];
+    ::legacy::send_bytes(client, "groups.mark", &params[..])
         .map_err(|err| MarkError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<MarkResponse>(&result)
-                            .map_err(|e| MarkError::MalformedResponse(e))
+                      parse_mark_response(&result).map_err(|err| err.into_error(MarkError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_mark_response(body: &[u8]) -> Result<MarkResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<MarkResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1642,11 +2118,23 @@ pub struct MarkRequest<'a> {
     pub ts: &'a str,
 }
 
+impl<'a> MarkRequest<'a> {
+    pub fn new(channel: &'a str, ts: &'a str) -> Self {
+        MarkRequest {
+            channel: channel,
+            ts: ts,
+            ..MarkRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MarkResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1720,6 +2208,30 @@ impl<'a, E: Error> From<&'a str> for MarkError<E> {
     }
 }
 
+impl<E: Error> From<MarkError<E>> for ::Error<E> {
+    fn from(err: MarkError<E>) -> Self {
+        let code = match err {
+            MarkError::ChannelNotFound => "channel_not_found",
+            MarkError::InvalidTimestamp => "invalid_timestamp",
+            MarkError::NotAuthed => "not_authed",
+            MarkError::InvalidAuth => "invalid_auth",
+            MarkError::AccountInactive => "account_inactive",
+            MarkError::InvalidArgName => "invalid_arg_name",
+            MarkError::InvalidArrayArg => "invalid_array_arg",
+            MarkError::InvalidCharset => "invalid_charset",
+            MarkError::InvalidFormData => "invalid_form_data",
+            MarkError::InvalidPostType => "invalid_post_type",
+            MarkError::MissingPostType => "missing_post_type",
+            MarkError::TeamAddedToOrg => "team_added_to_org",
+            MarkError::RequestTimeout => "request_timeout",
+            MarkError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            MarkError::Unknown(code) => return ::Error::from_code("groups.mark", &code),
+            MarkError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.mark", code)
+    }
+}
+
 impl<E: Error> fmt::Display for MarkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1761,6 +2273,7 @@ impl<E: Error> Error for MarkError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.open
 
+#[deprecated(note = "groups.open was retired by Slack: use conversations::open")]
 pub fn open<R>(client: &R,
                token: &str,
                request: &OpenRequest)
@@ -1768,17 +2281,21 @@ pub fn open<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.open");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "groups.open", &params[..])
         .map_err(|err| OpenError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<OpenResponse>(&result)
-                            .map_err(|e| OpenError::MalformedResponse(e))
+                      parse_open_response(&result).map_err(|err| err.into_error(OpenError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_open_response(body: &[u8]) -> Result<OpenResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<OpenResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1787,11 +2304,22 @@ pub struct OpenRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> OpenRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        OpenRequest {
+            channel: channel,
+            ..OpenRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct OpenResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -1862,6 +2390,29 @@ impl<'a, E: Error> From<&'a str> for OpenError<E> {
     }
 }
 
+impl<E: Error> From<OpenError<E>> for ::Error<E> {
+    fn from(err: OpenError<E>) -> Self {
+        let code = match err {
+            OpenError::ChannelNotFound => "channel_not_found",
+            OpenError::NotAuthed => "not_authed",
+            OpenError::InvalidAuth => "invalid_auth",
+            OpenError::AccountInactive => "account_inactive",
+            OpenError::InvalidArgName => "invalid_arg_name",
+            OpenError::InvalidArrayArg => "invalid_array_arg",
+            OpenError::InvalidCharset => "invalid_charset",
+            OpenError::InvalidFormData => "invalid_form_data",
+            OpenError::InvalidPostType => "invalid_post_type",
+            OpenError::MissingPostType => "missing_post_type",
+            OpenError::TeamAddedToOrg => "team_added_to_org",
+            OpenError::RequestTimeout => "request_timeout",
+            OpenError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            OpenError::Unknown(code) => return ::Error::from_code("groups.open", &code),
+            OpenError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.open", code)
+    }
+}
+
 impl<E: Error> fmt::Display for OpenError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1902,6 +2453,7 @@ impl<E: Error> Error for OpenError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.rename
 
+#[deprecated(note = "groups.rename was retired by Slack: use conversations::rename")]
 pub fn rename<R>(client: &R,
                  token: &str,
                  request: &RenameRequest)
@@ -1909,22 +2461,27 @@ pub fn rename<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("name", request.name)),
-                      request
-                          .validate
-                          .map(|validate| ("validate", if validate { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.rename");
-    client
-        .send(&url, &params[..])
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    params.push(("name", request.name));
+    if let Some(validate) = request.validate {
+        params.push(("validate", if validate { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "groups.rename", &params[..])
         .map_err(|err| RenameError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RenameResponse>(&result)
-                            .map_err(|e| RenameError::MalformedResponse(e))
+                      parse_rename_response(&result).map_err(|err| err.into_error(RenameError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_rename_response(body: &[u8]) -> Result<RenameResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RenameResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -1937,12 +2494,29 @@ pub struct RenameRequest<'a> {
     pub validate: Option<bool>,
 }
 
+impl<'a> RenameRequest<'a> {
+    pub fn new(channel: &'a str, name: &'a str) -> Self {
+        RenameRequest {
+            channel: channel,
+            name: name,
+            ..RenameRequest::default()
+        }
+    }
+
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RenameResponse {
     pub channel: Option<RenameResponseChannel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -2045,6 +2619,37 @@ impl<'a, E: Error> From<&'a str> for RenameError<E> {
     }
 }
 
+impl<E: Error> From<RenameError<E>> for ::Error<E> {
+    fn from(err: RenameError<E>) -> Self {
+        let code = match err {
+            RenameError::ChannelNotFound => "channel_not_found",
+            RenameError::InvalidName => "invalid_name",
+            RenameError::NameTaken => "name_taken",
+            RenameError::InvalidNameRequired => "invalid_name_required",
+            RenameError::InvalidNamePunctuation => "invalid_name_punctuation",
+            RenameError::InvalidNameMaxlength => "invalid_name_maxlength",
+            RenameError::InvalidNameSpecials => "invalid_name_specials",
+            RenameError::NotAuthed => "not_authed",
+            RenameError::InvalidAuth => "invalid_auth",
+            RenameError::AccountInactive => "account_inactive",
+            RenameError::UserIsBot => "user_is_bot",
+            RenameError::UserIsRestricted => "user_is_restricted",
+            RenameError::InvalidArgName => "invalid_arg_name",
+            RenameError::InvalidArrayArg => "invalid_array_arg",
+            RenameError::InvalidCharset => "invalid_charset",
+            RenameError::InvalidFormData => "invalid_form_data",
+            RenameError::InvalidPostType => "invalid_post_type",
+            RenameError::MissingPostType => "missing_post_type",
+            RenameError::TeamAddedToOrg => "team_added_to_org",
+            RenameError::RequestTimeout => "request_timeout",
+            RenameError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RenameError::Unknown(code) => return ::Error::from_code("groups.rename", &code),
+            RenameError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.rename", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RenameError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -2093,6 +2698,7 @@ impl<E: Error> Error for RenameError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.replies
 
+#[deprecated(note = "groups.replies was retired by Slack: use conversations::replies")]
 pub fn replies<R>(client: &R,
                   token: &str,
                   request: &RepliesRequest)
@@ -2100,19 +2706,23 @@ pub fn replies<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("thread_ts", request.thread_ts))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.replies");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("thread_ts", request.thread_ts)];
+    ::legacy::send_bytes(client, "groups.replies", &params[..])
         .map_err(|err| RepliesError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RepliesResponse>(&result)
-                            .map_err(|e| RepliesError::MalformedResponse(e))
+                      parse_replies_response(&result).map_err(|err| err.into_error(RepliesError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_replies_response(body: &[u8]) -> Result<RepliesResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<RepliesResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -2123,13 +2733,27 @@ pub struct RepliesRequest<'a> {
     pub thread_ts: &'a str,
 }
 
+impl<'a> RepliesRequest<'a> {
+    pub fn new(channel: &'a str, thread_ts: &'a str) -> Self {
+        RepliesRequest {
+            channel: channel,
+            thread_ts: thread_ts,
+            ..RepliesRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RepliesResponse {
     error: Option<String>,
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub thread_info: Option<::ThreadInfo>,
+    pub warning: Option<String>,
 }
 
 
@@ -2206,6 +2830,31 @@ impl<'a, E: Error> From<&'a str> for RepliesError<E> {
     }
 }
 
+impl<E: Error> From<RepliesError<E>> for ::Error<E> {
+    fn from(err: RepliesError<E>) -> Self {
+        let code = match err {
+            RepliesError::ChannelNotFound => "channel_not_found",
+            RepliesError::ThreadNotFound => "thread_not_found",
+            RepliesError::NotAuthed => "not_authed",
+            RepliesError::InvalidAuth => "invalid_auth",
+            RepliesError::AccountInactive => "account_inactive",
+            RepliesError::UserIsBot => "user_is_bot",
+            RepliesError::InvalidArgName => "invalid_arg_name",
+            RepliesError::InvalidArrayArg => "invalid_array_arg",
+            RepliesError::InvalidCharset => "invalid_charset",
+            RepliesError::InvalidFormData => "invalid_form_data",
+            RepliesError::InvalidPostType => "invalid_post_type",
+            RepliesError::MissingPostType => "missing_post_type",
+            RepliesError::TeamAddedToOrg => "team_added_to_org",
+            RepliesError::RequestTimeout => "request_timeout",
+            RepliesError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RepliesError::Unknown(code) => return ::Error::from_code("groups.replies", &code),
+            RepliesError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.replies", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RepliesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -2248,6 +2897,7 @@ impl<E: Error> Error for RepliesError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.setPurpose
 
+#[deprecated(note = "groups.setPurpose was retired by Slack: use conversations::set_purpose")]
 pub fn set_purpose<R>(client: &R,
                       token: &str,
                       request: &SetPurposeRequest)
@@ -2255,19 +2905,21 @@ pub fn set_purpose<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("purpose", request.purpose))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.setPurpose");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("purpose", request.purpose)];
+    ::legacy::send_bytes(client, "groups.setPurpose", &params[..])
         .map_err(|err| SetPurposeError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SetPurposeResponse>(&result)
-                            .map_err(|e| SetPurposeError::MalformedResponse(e))
+                      parse_set_purpose_response(&result).map_err(|err| err.into_error(SetPurposeError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_set_purpose_response(body: &[u8]) -> Result<SetPurposeResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetPurposeResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -2278,12 +2930,24 @@ pub struct SetPurposeRequest<'a> {
     pub purpose: &'a str,
 }
 
+impl<'a> SetPurposeRequest<'a> {
+    pub fn new(channel: &'a str, purpose: &'a str) -> Self {
+        SetPurposeRequest {
+            channel: channel,
+            purpose: purpose,
+            ..SetPurposeRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SetPurposeResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub purpose: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -2363,6 +3027,32 @@ impl<'a, E: Error> From<&'a str> for SetPurposeError<E> {
     }
 }
 
+impl<E: Error> From<SetPurposeError<E>> for ::Error<E> {
+    fn from(err: SetPurposeError<E>) -> Self {
+        let code = match err {
+            SetPurposeError::ChannelNotFound => "channel_not_found",
+            SetPurposeError::IsArchived => "is_archived",
+            SetPurposeError::TooLong => "too_long",
+            SetPurposeError::UserIsRestricted => "user_is_restricted",
+            SetPurposeError::NotAuthed => "not_authed",
+            SetPurposeError::InvalidAuth => "invalid_auth",
+            SetPurposeError::AccountInactive => "account_inactive",
+            SetPurposeError::InvalidArgName => "invalid_arg_name",
+            SetPurposeError::InvalidArrayArg => "invalid_array_arg",
+            SetPurposeError::InvalidCharset => "invalid_charset",
+            SetPurposeError::InvalidFormData => "invalid_form_data",
+            SetPurposeError::InvalidPostType => "invalid_post_type",
+            SetPurposeError::MissingPostType => "missing_post_type",
+            SetPurposeError::TeamAddedToOrg => "team_added_to_org",
+            SetPurposeError::RequestTimeout => "request_timeout",
+            SetPurposeError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetPurposeError::Unknown(code) => return ::Error::from_code("groups.setPurpose", &code),
+            SetPurposeError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.setPurpose", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SetPurposeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -2406,6 +3096,7 @@ impl<E: Error> Error for SetPurposeError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.setTopic
 
+#[deprecated(note = "groups.setTopic was retired by Slack: use conversations::set_topic")]
 pub fn set_topic<R>(client: &R,
                     token: &str,
                     request: &SetTopicRequest)
@@ -2413,19 +3104,21 @@ pub fn set_topic<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("topic", request.topic))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.setTopic");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("topic", request.topic)];
+    ::legacy::send_bytes(client, "groups.setTopic", &params[..])
         .map_err(|err| SetTopicError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SetTopicResponse>(&result)
-                            .map_err(|e| SetTopicError::MalformedResponse(e))
+                      parse_set_topic_response(&result).map_err(|err| err.into_error(SetTopicError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_set_topic_response(body: &[u8]) -> Result<SetTopicResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetTopicResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -2436,12 +3129,24 @@ pub struct SetTopicRequest<'a> {
     pub topic: &'a str,
 }
 
+impl<'a> SetTopicRequest<'a> {
+    pub fn new(channel: &'a str, topic: &'a str) -> Self {
+        SetTopicRequest {
+            channel: channel,
+            topic: topic,
+            ..SetTopicRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SetTopicResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub topic: Option<String>,
+    pub warning: Option<String>,
 }
 
 
@@ -2521,6 +3226,32 @@ impl<'a, E: Error> From<&'a str> for SetTopicError<E> {
     }
 }
 
+impl<E: Error> From<SetTopicError<E>> for ::Error<E> {
+    fn from(err: SetTopicError<E>) -> Self {
+        let code = match err {
+            SetTopicError::ChannelNotFound => "channel_not_found",
+            SetTopicError::IsArchived => "is_archived",
+            SetTopicError::TooLong => "too_long",
+            SetTopicError::UserIsRestricted => "user_is_restricted",
+            SetTopicError::NotAuthed => "not_authed",
+            SetTopicError::InvalidAuth => "invalid_auth",
+            SetTopicError::AccountInactive => "account_inactive",
+            SetTopicError::InvalidArgName => "invalid_arg_name",
+            SetTopicError::InvalidArrayArg => "invalid_array_arg",
+            SetTopicError::InvalidCharset => "invalid_charset",
+            SetTopicError::InvalidFormData => "invalid_form_data",
+            SetTopicError::InvalidPostType => "invalid_post_type",
+            SetTopicError::MissingPostType => "missing_post_type",
+            SetTopicError::TeamAddedToOrg => "team_added_to_org",
+            SetTopicError::RequestTimeout => "request_timeout",
+            SetTopicError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetTopicError::Unknown(code) => return ::Error::from_code("groups.setTopic", &code),
+            SetTopicError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.setTopic", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SetTopicError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -2564,6 +3295,7 @@ impl<E: Error> Error for SetTopicError<E> {
 ///
 /// Wraps https://api.slack.com/methods/groups.unarchive
 
+#[deprecated(note = "groups.unarchive was retired by Slack: use conversations::unarchive")]
 pub fn unarchive<R>(client: &R,
                     token: &str,
                     request: &UnarchiveRequest)
@@ -2571,17 +3303,21 @@ pub fn unarchive<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("groups.unarchive");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "groups.unarchive", &params[..])
         .map_err(|err| UnarchiveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<UnarchiveResponse>(&result)
-                            .map_err(|e| UnarchiveError::MalformedResponse(e))
+                      parse_unarchive_response(&result).map_err(|err| err.into_error(UnarchiveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_unarchive_response(body: &[u8]) -> Result<UnarchiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UnarchiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -2590,11 +3326,22 @@ pub struct UnarchiveRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> UnarchiveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        UnarchiveRequest {
+            channel: channel,
+            ..UnarchiveRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UnarchiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -2674,6 +3421,32 @@ impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
     }
 }
 
+impl<E: Error> From<UnarchiveError<E>> for ::Error<E> {
+    fn from(err: UnarchiveError<E>) -> Self {
+        let code = match err {
+            UnarchiveError::ChannelNotFound => "channel_not_found",
+            UnarchiveError::NotArchived => "not_archived",
+            UnarchiveError::NotAuthed => "not_authed",
+            UnarchiveError::InvalidAuth => "invalid_auth",
+            UnarchiveError::AccountInactive => "account_inactive",
+            UnarchiveError::UserIsBot => "user_is_bot",
+            UnarchiveError::UserIsRestricted => "user_is_restricted",
+            UnarchiveError::InvalidArgName => "invalid_arg_name",
+            UnarchiveError::InvalidArrayArg => "invalid_array_arg",
+            UnarchiveError::InvalidCharset => "invalid_charset",
+            UnarchiveError::InvalidFormData => "invalid_form_data",
+            UnarchiveError::InvalidPostType => "invalid_post_type",
+            UnarchiveError::MissingPostType => "missing_post_type",
+            UnarchiveError::TeamAddedToOrg => "team_added_to_org",
+            UnarchiveError::RequestTimeout => "request_timeout",
+            UnarchiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UnarchiveError::Unknown(code) => return ::Error::from_code("groups.unarchive", &code),
+            UnarchiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("groups.unarchive", code)
+    }
+}
+
 impl<E: Error> fmt::Display for UnarchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
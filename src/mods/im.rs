@@ -1,4 +1,7 @@
 //! Get info on your direct messages.
+//!
+//! Slack has retired these methods, so their calls are sent to the `conversations` methods that
+//! replaced them, and the responses translated back.
 
 
 #[allow(unused_imports)]
@@ -15,6 +18,7 @@ use requests::SlackWebRequestSender;
 ///
 /// Wraps https://api.slack.com/methods/im.close
 
+#[deprecated(note = "im.close was retired by Slack: use conversations::close")]
 pub fn close<R>(client: &R,
                 token: &str,
                 request: &CloseRequest)
@@ -22,17 +26,21 @@ pub fn close<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.close");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "im.close", &params[..])
         .map_err(|err| CloseError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<CloseResponse>(&result)
-                            .map_err(|e| CloseError::MalformedResponse(e))
+                      parse_close_response(&result).map_err(|err| err.into_error(CloseError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_close_response(body: &[u8]) -> Result<CloseResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CloseResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -41,11 +49,22 @@ pub struct CloseRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> CloseRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        CloseRequest {
+            channel: channel,
+            ..CloseRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CloseResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -119,6 +138,30 @@ impl<'a, E: Error> From<&'a str> for CloseError<E> {
     }
 }
 
+impl<E: Error> From<CloseError<E>> for ::Error<E> {
+    fn from(err: CloseError<E>) -> Self {
+        let code = match err {
+            CloseError::ChannelNotFound => "channel_not_found",
+            CloseError::UserDoesNotOwnChannel => "user_does_not_own_channel",
+            CloseError::NotAuthed => "not_authed",
+            CloseError::InvalidAuth => "invalid_auth",
+            CloseError::AccountInactive => "account_inactive",
+            CloseError::InvalidArgName => "invalid_arg_name",
+            CloseError::InvalidArrayArg => "invalid_array_arg",
+            CloseError::InvalidCharset => "invalid_charset",
+            CloseError::InvalidFormData => "invalid_form_data",
+            CloseError::InvalidPostType => "invalid_post_type",
+            CloseError::MissingPostType => "missing_post_type",
+            CloseError::TeamAddedToOrg => "team_added_to_org",
+            CloseError::RequestTimeout => "request_timeout",
+            CloseError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CloseError::Unknown(code) => return ::Error::from_code("im.close", &code),
+            CloseError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("im.close", code)
+    }
+}
+
 impl<E: Error> fmt::Display for CloseError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -160,34 +203,48 @@ impl<E: Error> Error for CloseError<E> {
 ///
 /// Wraps https://api.slack.com/methods/im.history
 
+#[deprecated(note = "im.history was retired by Slack: use conversations::history")]
 pub fn history<R>(client: &R,
                   token: &str,
                   request: &HistoryRequest)
                   -> Result<HistoryResponse, HistoryError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      request.latest.map(|latest| ("latest", latest)),
-                      request.oldest.map(|oldest| ("oldest", oldest)),
-                      request
-                          .inclusive
-                          .map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      request
-                          .unreads
-                          .map(|unreads| ("unreads", if unreads { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.history");
-    client
-        .send(&url, &params[..])
+    let count = request.count.map(::IntParam::new);
+    let mut params = Vec::with_capacity(7);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    if let Some(latest) = request.latest {
+        params.push(("latest", latest));
+    }
+    if let Some(oldest) = request.oldest {
+        params.push(("oldest", oldest));
+    }
+    if let Some(inclusive) = request.inclusive {
+        params.push(("inclusive", if inclusive { "1" } else { "0" }));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(unreads) = request.unreads {
+        params.push(("unreads", if unreads { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "im.history", &params[..])
         .map_err(|err| HistoryError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<HistoryResponse>(&result)
-                            .map_err(|e| HistoryError::MalformedResponse(e))
+                      parse_history_response(&result).map_err(|err| err.into_error(HistoryError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_history_response(body: &[u8]) -> Result<HistoryResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<HistoryResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -206,6 +263,40 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
+impl<'a> HistoryRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        HistoryRequest {
+            channel: channel,
+            ..HistoryRequest::default()
+        }
+    }
+
+    pub fn latest(mut self, latest: &'a str) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: &'a str) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct HistoryResponse {
     error: Option<String>,
@@ -214,6 +305,10 @@ pub struct HistoryResponse {
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -290,6 +385,31 @@ impl<'a, E: Error> From<&'a str> for HistoryError<E> {
     }
 }
 
+impl<E: Error> From<HistoryError<E>> for ::Error<E> {
+    fn from(err: HistoryError<E>) -> Self {
+        let code = match err {
+            HistoryError::ChannelNotFound => "channel_not_found",
+            HistoryError::InvalidTsLatest => "invalid_ts_latest",
+            HistoryError::InvalidTsOldest => "invalid_ts_oldest",
+            HistoryError::NotAuthed => "not_authed",
+            HistoryError::InvalidAuth => "invalid_auth",
+            HistoryError::AccountInactive => "account_inactive",
+            HistoryError::InvalidArgName => "invalid_arg_name",
+            HistoryError::InvalidArrayArg => "invalid_array_arg",
+            HistoryError::InvalidCharset => "invalid_charset",
+            HistoryError::InvalidFormData => "invalid_form_data",
+            HistoryError::InvalidPostType => "invalid_post_type",
+            HistoryError::MissingPostType => "missing_post_type",
+            HistoryError::TeamAddedToOrg => "team_added_to_org",
+            HistoryError::RequestTimeout => "request_timeout",
+            HistoryError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            HistoryError::Unknown(code) => return ::Error::from_code("im.history", &code),
+            HistoryError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("im.history", code)
+    }
+}
+
 impl<E: Error> fmt::Display for HistoryError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -332,19 +452,27 @@ impl<E: Error> Error for HistoryError<E> {
 ///
 /// Wraps https://api.slack.com/methods/im.list
 
+#[deprecated(note = "im.list was retired by Slack: use conversations::list")]
 pub fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
     where R: SlackWebRequestSender
 {
     let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("im.list");
-    client
-        .send(&url, &params[..])
+    ::legacy::send_bytes(client, "im.list", &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<ListResponse>(body, &[("ims", ::item_error::<::Im>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -353,6 +481,10 @@ pub struct ListResponse {
     pub ims: Option<Vec<::Im>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -420,6 +552,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("im.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("im.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -459,6 +613,7 @@ impl<E: Error> Error for ListError<E> {
 ///
 /// Wraps https://api.slack.com/methods/im.mark
 
+#[deprecated(note = "im.mark was retired by Slack: use conversations::mark")]
 pub fn mark<R>(client: &R,
                token: &str,
                request: &MarkRequest)
@@ -466,19 +621,21 @@ pub fn mark<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("ts", request.ts))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.mark");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("ts", request.ts)];
+    ::legacy::send_bytes(client, "im.mark", &params[..])
         .map_err(|err| MarkError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<MarkResponse>(&result)
-                            .map_err(|e| MarkError::MalformedResponse(e))
+                      parse_mark_response(&result).map_err(|err| err.into_error(MarkError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_mark_response(body: &[u8]) -> Result<MarkResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<MarkResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -489,11 +646,23 @@ pub struct MarkRequest<'a> {
     pub ts: &'a str,
 }
 
+impl<'a> MarkRequest<'a> {
+    pub fn new(channel: &'a str, ts: &'a str) -> Self {
+        MarkRequest {
+            channel: channel,
+            ts: ts,
+            ..MarkRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MarkResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -570,6 +739,31 @@ impl<'a, E: Error> From<&'a str> for MarkError<E> {
     }
 }
 
+impl<E: Error> From<MarkError<E>> for ::Error<E> {
+    fn from(err: MarkError<E>) -> Self {
+        let code = match err {
+            MarkError::ChannelNotFound => "channel_not_found",
+            MarkError::InvalidTimestamp => "invalid_timestamp",
+            MarkError::NotInChannel => "not_in_channel",
+            MarkError::NotAuthed => "not_authed",
+            MarkError::InvalidAuth => "invalid_auth",
+            MarkError::AccountInactive => "account_inactive",
+            MarkError::InvalidArgName => "invalid_arg_name",
+            MarkError::InvalidArrayArg => "invalid_array_arg",
+            MarkError::InvalidCharset => "invalid_charset",
+            MarkError::InvalidFormData => "invalid_form_data",
+            MarkError::InvalidPostType => "invalid_post_type",
+            MarkError::MissingPostType => "missing_post_type",
+            MarkError::TeamAddedToOrg => "team_added_to_org",
+            MarkError::RequestTimeout => "request_timeout",
+            MarkError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            MarkError::Unknown(code) => return ::Error::from_code("im.mark", &code),
+            MarkError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("im.mark", code)
+    }
+}
+
 impl<E: Error> fmt::Display for MarkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -612,6 +806,7 @@ impl<E: Error> Error for MarkError<E> {
 ///
 /// Wraps https://api.slack.com/methods/im.open
 
+#[deprecated(note = "im.open was retired by Slack: use conversations::open")]
 pub fn open<R>(client: &R,
                token: &str,
                request: &OpenRequest)
@@ -619,21 +814,26 @@ pub fn open<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("user", request.user)),
-                      request
-                          .return_im
-                          .map(|return_im| ("return_im", if return_im { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.open");
-    client
-        .send(&url, &params[..])
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    params.push(("user", request.user));
+    if let Some(return_im) = request.return_im {
+        params.push(("return_im", if return_im { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "im.open", &params[..])
         .map_err(|err| OpenError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<OpenResponse>(&result)
-                            .map_err(|e| OpenError::MalformedResponse(e))
+                      parse_open_response(&result).map_err(|err| err.into_error(OpenError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_open_response(body: &[u8]) -> Result<OpenResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<OpenResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -644,12 +844,28 @@ pub struct OpenRequest<'a> {
     pub return_im: Option<bool>,
 }
 
+impl<'a> OpenRequest<'a> {
+    pub fn new(user: &'a str) -> Self {
+        OpenRequest {
+            user: user,
+            ..OpenRequest::default()
+        }
+    }
+
+    pub fn return_im(mut self, return_im: bool) -> Self {
+        self.return_im = Some(return_im);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct OpenResponse {
     pub channel: Option<::Im>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -726,6 +942,31 @@ impl<'a, E: Error> From<&'a str> for OpenError<E> {
     }
 }
 
+impl<E: Error> From<OpenError<E>> for ::Error<E> {
+    fn from(err: OpenError<E>) -> Self {
+        let code = match err {
+            OpenError::UserNotFound => "user_not_found",
+            OpenError::UserNotVisible => "user_not_visible",
+            OpenError::UserDisabled => "user_disabled",
+            OpenError::NotAuthed => "not_authed",
+            OpenError::InvalidAuth => "invalid_auth",
+            OpenError::AccountInactive => "account_inactive",
+            OpenError::InvalidArgName => "invalid_arg_name",
+            OpenError::InvalidArrayArg => "invalid_array_arg",
+            OpenError::InvalidCharset => "invalid_charset",
+            OpenError::InvalidFormData => "invalid_form_data",
+            OpenError::InvalidPostType => "invalid_post_type",
+            OpenError::MissingPostType => "missing_post_type",
+            OpenError::TeamAddedToOrg => "team_added_to_org",
+            OpenError::RequestTimeout => "request_timeout",
+            OpenError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            OpenError::Unknown(code) => return ::Error::from_code("im.open", &code),
+            OpenError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("im.open", code)
+    }
+}
+
 impl<E: Error> fmt::Display for OpenError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -768,6 +1009,7 @@ impl<E: Error> Error for OpenError<E> {
 ///
 /// Wraps https://api.slack.com/methods/im.replies
 
+#[deprecated(note = "im.replies was retired by Slack: use conversations::replies")]
 pub fn replies<R>(client: &R,
                   token: &str,
                   request: &RepliesRequest)
@@ -775,19 +1017,23 @@ pub fn replies<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("thread_ts", request.thread_ts))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.replies");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("thread_ts", request.thread_ts)];
+    ::legacy::send_bytes(client, "im.replies", &params[..])
         .map_err(|err| RepliesError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RepliesResponse>(&result)
-                            .map_err(|e| RepliesError::MalformedResponse(e))
+                      parse_replies_response(&result).map_err(|err| err.into_error(RepliesError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_replies_response(body: &[u8]) -> Result<RepliesResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<RepliesResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -798,13 +1044,27 @@ pub struct RepliesRequest<'a> {
     pub thread_ts: &'a str,
 }
 
+impl<'a> RepliesRequest<'a> {
+    pub fn new(channel: &'a str, thread_ts: &'a str) -> Self {
+        RepliesRequest {
+            channel: channel,
+            thread_ts: thread_ts,
+            ..RepliesRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RepliesResponse {
     error: Option<String>,
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub thread_info: Option<::ThreadInfo>,
+    pub warning: Option<String>,
 }
 
 
@@ -881,6 +1141,31 @@ impl<'a, E: Error> From<&'a str> for RepliesError<E> {
     }
 }
 
+impl<E: Error> From<RepliesError<E>> for ::Error<E> {
+    fn from(err: RepliesError<E>) -> Self {
+        let code = match err {
+            RepliesError::ChannelNotFound => "channel_not_found",
+            RepliesError::ThreadNotFound => "thread_not_found",
+            RepliesError::NotAuthed => "not_authed",
+            RepliesError::InvalidAuth => "invalid_auth",
+            RepliesError::AccountInactive => "account_inactive",
+            RepliesError::UserIsBot => "user_is_bot",
+            RepliesError::InvalidArgName => "invalid_arg_name",
+            RepliesError::InvalidArrayArg => "invalid_array_arg",
+            RepliesError::InvalidCharset => "invalid_charset",
+            RepliesError::InvalidFormData => "invalid_form_data",
+            RepliesError::InvalidPostType => "invalid_post_type",
+            RepliesError::MissingPostType => "missing_post_type",
+            RepliesError::TeamAddedToOrg => "team_added_to_org",
+            RepliesError::RequestTimeout => "request_timeout",
+            RepliesError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RepliesError::Unknown(code) => return ::Error::from_code("im.replies", &code),
+            RepliesError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("im.replies", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RepliesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -1,8 +1,20 @@
+pub mod admin_apps;
+pub mod admin_conversations;
+pub mod admin_emoji;
+pub mod admin_teams;
+pub mod admin_users;
 pub mod api;
+pub mod apps_connections;
+pub mod apps_event_authorizations;
 pub mod auth;
+pub mod bookmarks;
 pub mod bots;
+pub mod calls;
+pub mod calls_participants;
 pub mod channels;
 pub mod chat;
+pub mod chat_scheduled_messages;
+pub mod conversations;
 pub mod dnd;
 pub mod emoji;
 pub mod files_comments;
@@ -11,6 +23,8 @@ pub mod groups;
 pub mod im;
 pub mod mpim;
 pub mod oauth;
+pub mod oauth_v2;
+pub mod openid_connect;
 pub mod pins;
 pub mod reactions;
 pub mod reminders;
@@ -18,8 +32,10 @@ pub mod rtm;
 pub mod search;
 pub mod stars;
 pub mod team;
+pub mod team_billing;
 pub mod team_profile;
 pub mod usergroups;
 pub mod usergroups_users;
 pub mod users;
-pub mod users_profile;
\ No newline at end of file
+pub mod users_profile;
+pub mod views;
\ No newline at end of file
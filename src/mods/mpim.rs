@@ -1,4 +1,7 @@
 //! Get info on your multiparty direct messages.
+//!
+//! Slack has retired these methods, so their calls are sent to the `conversations` methods that
+//! replaced them, and the responses translated back.
 
 
 #[allow(unused_imports)]
@@ -15,6 +18,7 @@ use requests::SlackWebRequestSender;
 ///
 /// Wraps https://api.slack.com/methods/mpim.close
 
+#[deprecated(note = "mpim.close was retired by Slack: use conversations::close")]
 pub fn close<R>(client: &R,
                 token: &str,
                 request: &CloseRequest)
@@ -22,17 +26,21 @@ pub fn close<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("mpim.close");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel)];
+    ::legacy::send_bytes(client, "mpim.close", &params[..])
         .map_err(|err| CloseError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<CloseResponse>(&result)
-                            .map_err(|e| CloseError::MalformedResponse(e))
+                      parse_close_response(&result).map_err(|err| err.into_error(CloseError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_close_response(body: &[u8]) -> Result<CloseResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CloseResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -41,11 +49,22 @@ pub struct CloseRequest<'a> {
     pub channel: &'a str,
 }
 
+impl<'a> CloseRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        CloseRequest {
+            channel: channel,
+            ..CloseRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CloseResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -116,6 +135,29 @@ impl<'a, E: Error> From<&'a str> for CloseError<E> {
     }
 }
 
+impl<E: Error> From<CloseError<E>> for ::Error<E> {
+    fn from(err: CloseError<E>) -> Self {
+        let code = match err {
+            CloseError::ChannelNotFound => "channel_not_found",
+            CloseError::NotAuthed => "not_authed",
+            CloseError::InvalidAuth => "invalid_auth",
+            CloseError::AccountInactive => "account_inactive",
+            CloseError::InvalidArgName => "invalid_arg_name",
+            CloseError::InvalidArrayArg => "invalid_array_arg",
+            CloseError::InvalidCharset => "invalid_charset",
+            CloseError::InvalidFormData => "invalid_form_data",
+            CloseError::InvalidPostType => "invalid_post_type",
+            CloseError::MissingPostType => "missing_post_type",
+            CloseError::TeamAddedToOrg => "team_added_to_org",
+            CloseError::RequestTimeout => "request_timeout",
+            CloseError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CloseError::Unknown(code) => return ::Error::from_code("mpim.close", &code),
+            CloseError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("mpim.close", code)
+    }
+}
+
 impl<E: Error> fmt::Display for CloseError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -156,34 +198,48 @@ impl<E: Error> Error for CloseError<E> {
 ///
 /// Wraps https://api.slack.com/methods/mpim.history
 
+#[deprecated(note = "mpim.history was retired by Slack: use conversations::history")]
 pub fn history<R>(client: &R,
                   token: &str,
                   request: &HistoryRequest)
                   -> Result<HistoryResponse, HistoryError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      request.latest.map(|latest| ("latest", latest)),
-                      request.oldest.map(|oldest| ("oldest", oldest)),
-                      request
-                          .inclusive
-                          .map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      request
-                          .unreads
-                          .map(|unreads| ("unreads", if unreads { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("mpim.history");
-    client
-        .send(&url, &params[..])
+    let count = request.count.map(::IntParam::new);
+    let mut params = Vec::with_capacity(7);
+    params.push(("token", token));
+    params.push(("channel", request.channel));
+    if let Some(latest) = request.latest {
+        params.push(("latest", latest));
+    }
+    if let Some(oldest) = request.oldest {
+        params.push(("oldest", oldest));
+    }
+    if let Some(inclusive) = request.inclusive {
+        params.push(("inclusive", if inclusive { "1" } else { "0" }));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(unreads) = request.unreads {
+        params.push(("unreads", if unreads { "1" } else { "0" }));
+    }
+    ::legacy::send_bytes(client, "mpim.history", &params[..])
         .map_err(|err| HistoryError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<HistoryResponse>(&result)
-                            .map_err(|e| HistoryError::MalformedResponse(e))
+                      parse_history_response(&result).map_err(|err| err.into_error(HistoryError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_history_response(body: &[u8]) -> Result<HistoryResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<HistoryResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -202,6 +258,40 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
+impl<'a> HistoryRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        HistoryRequest {
+            channel: channel,
+            ..HistoryRequest::default()
+        }
+    }
+
+    pub fn latest(mut self, latest: &'a str) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: &'a str) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct HistoryResponse {
     error: Option<String>,
@@ -210,6 +300,10 @@ pub struct HistoryResponse {
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -286,6 +380,31 @@ impl<'a, E: Error> From<&'a str> for HistoryError<E> {
     }
 }
 
+impl<E: Error> From<HistoryError<E>> for ::Error<E> {
+    fn from(err: HistoryError<E>) -> Self {
+        let code = match err {
+            HistoryError::ChannelNotFound => "channel_not_found",
+            HistoryError::InvalidTsLatest => "invalid_ts_latest",
+            HistoryError::InvalidTsOldest => "invalid_ts_oldest",
+            HistoryError::NotAuthed => "not_authed",
+            HistoryError::InvalidAuth => "invalid_auth",
+            HistoryError::AccountInactive => "account_inactive",
+            HistoryError::InvalidArgName => "invalid_arg_name",
+            HistoryError::InvalidArrayArg => "invalid_array_arg",
+            HistoryError::InvalidCharset => "invalid_charset",
+            HistoryError::InvalidFormData => "invalid_form_data",
+            HistoryError::InvalidPostType => "invalid_post_type",
+            HistoryError::MissingPostType => "missing_post_type",
+            HistoryError::TeamAddedToOrg => "team_added_to_org",
+            HistoryError::RequestTimeout => "request_timeout",
+            HistoryError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            HistoryError::Unknown(code) => return ::Error::from_code("mpim.history", &code),
+            HistoryError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("mpim.history", code)
+    }
+}
+
 impl<E: Error> fmt::Display for HistoryError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -328,19 +447,27 @@ impl<E: Error> Error for HistoryError<E> {
 ///
 /// Wraps https://api.slack.com/methods/mpim.list
 
+#[deprecated(note = "mpim.list was retired by Slack: use conversations::list")]
 pub fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
     where R: SlackWebRequestSender
 {
     let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("mpim.list");
-    client
-        .send(&url, &params[..])
+    ::legacy::send_bytes(client, "mpim.list", &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<ListResponse>(body, &[("groups", ::item_error::<::Mpim>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -349,6 +476,10 @@ pub struct ListResponse {
     pub groups: Option<Vec<::Mpim>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -416,6 +547,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("mpim.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("mpim.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -455,6 +608,7 @@ impl<E: Error> Error for ListError<E> {
 ///
 /// Wraps https://api.slack.com/methods/mpim.mark
 
+#[deprecated(note = "mpim.mark was retired by Slack: use conversations::mark")]
 pub fn mark<R>(client: &R,
                token: &str,
                request: &MarkRequest)
@@ -462,19 +616,21 @@ pub fn mark<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("ts", request.ts))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("mpim.mark");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("ts", request.ts)];
+    ::legacy::send_bytes(client, "mpim.mark", &params[..])
         .map_err(|err| MarkError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<MarkResponse>(&result)
-                            .map_err(|e| MarkError::MalformedResponse(e))
+                      parse_mark_response(&result).map_err(|err| err.into_error(MarkError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_mark_response(body: &[u8]) -> Result<MarkResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<MarkResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -485,11 +641,23 @@ pub struct MarkRequest<'a> {
     pub ts: &'a str,
 }
 
+impl<'a> MarkRequest<'a> {
+    pub fn new(channel: &'a str, ts: &'a str) -> Self {
+        MarkRequest {
+            channel: channel,
+            ts: ts,
+            ..MarkRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MarkResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -563,6 +731,30 @@ impl<'a, E: Error> From<&'a str> for MarkError<E> {
     }
 }
 
+impl<E: Error> From<MarkError<E>> for ::Error<E> {
+    fn from(err: MarkError<E>) -> Self {
+        let code = match err {
+            MarkError::ChannelNotFound => "channel_not_found",
+            MarkError::InvalidTimestamp => "invalid_timestamp",
+            MarkError::NotAuthed => "not_authed",
+            MarkError::InvalidAuth => "invalid_auth",
+            MarkError::AccountInactive => "account_inactive",
+            MarkError::InvalidArgName => "invalid_arg_name",
+            MarkError::InvalidArrayArg => "invalid_array_arg",
+            MarkError::InvalidCharset => "invalid_charset",
+            MarkError::InvalidFormData => "invalid_form_data",
+            MarkError::InvalidPostType => "invalid_post_type",
+            MarkError::MissingPostType => "missing_post_type",
+            MarkError::TeamAddedToOrg => "team_added_to_org",
+            MarkError::RequestTimeout => "request_timeout",
+            MarkError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            MarkError::Unknown(code) => return ::Error::from_code("mpim.mark", &code),
+            MarkError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("mpim.mark", code)
+    }
+}
+
 impl<E: Error> fmt::Display for MarkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -604,6 +796,7 @@ impl<E: Error> Error for MarkError<E> {
 ///
 /// Wraps https://api.slack.com/methods/mpim.open
 
+#[deprecated(note = "mpim.open was retired by Slack: use conversations::open")]
 pub fn open<R>(client: &R,
                token: &str,
                request: &OpenRequest)
@@ -611,17 +804,21 @@ pub fn open<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("users", request.users))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("mpim.open");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("users", request.users)];
+    ::legacy::send_bytes(client, "mpim.open", &params[..])
         .map_err(|err| OpenError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<OpenResponse>(&result)
-                            .map_err(|e| OpenError::MalformedResponse(e))
+                      parse_open_response(&result).map_err(|err| err.into_error(OpenError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_open_response(body: &[u8]) -> Result<OpenResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<OpenResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -630,12 +827,23 @@ pub struct OpenRequest<'a> {
     pub users: &'a str,
 }
 
+impl<'a> OpenRequest<'a> {
+    pub fn new(users: &'a str) -> Self {
+        OpenRequest {
+            users: users,
+            ..OpenRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct OpenResponse {
     error: Option<String>,
     pub group: Option<::Mpim>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -712,6 +920,31 @@ impl<'a, E: Error> From<&'a str> for OpenError<E> {
     }
 }
 
+impl<E: Error> From<OpenError<E>> for ::Error<E> {
+    fn from(err: OpenError<E>) -> Self {
+        let code = match err {
+            OpenError::UsersListNotSupplied => "users_list_not_supplied",
+            OpenError::NotEnoughUsers => "not_enough_users",
+            OpenError::TooManyUsers => "too_many_users",
+            OpenError::NotAuthed => "not_authed",
+            OpenError::InvalidAuth => "invalid_auth",
+            OpenError::AccountInactive => "account_inactive",
+            OpenError::InvalidArgName => "invalid_arg_name",
+            OpenError::InvalidArrayArg => "invalid_array_arg",
+            OpenError::InvalidCharset => "invalid_charset",
+            OpenError::InvalidFormData => "invalid_form_data",
+            OpenError::InvalidPostType => "invalid_post_type",
+            OpenError::MissingPostType => "missing_post_type",
+            OpenError::TeamAddedToOrg => "team_added_to_org",
+            OpenError::RequestTimeout => "request_timeout",
+            OpenError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            OpenError::Unknown(code) => return ::Error::from_code("mpim.open", &code),
+            OpenError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("mpim.open", code)
+    }
+}
+
 impl<E: Error> fmt::Display for OpenError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -754,6 +987,7 @@ impl<E: Error> Error for OpenError<E> {
 ///
 /// Wraps https://api.slack.com/methods/mpim.replies
 
+#[deprecated(note = "mpim.replies was retired by Slack: use conversations::replies")]
 pub fn replies<R>(client: &R,
                   token: &str,
                   request: &RepliesRequest)
@@ -761,19 +995,23 @@ pub fn replies<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      Some(("thread_ts", request.thread_ts))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("mpim.replies");
-    client
-        .send(&url, &params[..])
+    let params = [("token", token), ("channel", request.channel), ("thread_ts", request.thread_ts)];
+    ::legacy::send_bytes(client, "mpim.replies", &params[..])
         .map_err(|err| RepliesError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RepliesResponse>(&result)
-                            .map_err(|e| RepliesError::MalformedResponse(e))
+                      parse_replies_response(&result).map_err(|err| err.into_error(RepliesError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_replies_response(body: &[u8]) -> Result<RepliesResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<RepliesResponse>(body, &[("messages", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -784,13 +1022,27 @@ pub struct RepliesRequest<'a> {
     pub thread_ts: &'a str,
 }
 
+impl<'a> RepliesRequest<'a> {
+    pub fn new(channel: &'a str, thread_ts: &'a str) -> Self {
+        RepliesRequest {
+            channel: channel,
+            thread_ts: thread_ts,
+            ..RepliesRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RepliesResponse {
     error: Option<String>,
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub thread_info: Option<::ThreadInfo>,
+    pub warning: Option<String>,
 }
 
 
@@ -867,6 +1119,31 @@ impl<'a, E: Error> From<&'a str> for RepliesError<E> {
     }
 }
 
+impl<E: Error> From<RepliesError<E>> for ::Error<E> {
+    fn from(err: RepliesError<E>) -> Self {
+        let code = match err {
+            RepliesError::ChannelNotFound => "channel_not_found",
+            RepliesError::ThreadNotFound => "thread_not_found",
+            RepliesError::NotAuthed => "not_authed",
+            RepliesError::InvalidAuth => "invalid_auth",
+            RepliesError::AccountInactive => "account_inactive",
+            RepliesError::UserIsBot => "user_is_bot",
+            RepliesError::InvalidArgName => "invalid_arg_name",
+            RepliesError::InvalidArrayArg => "invalid_array_arg",
+            RepliesError::InvalidCharset => "invalid_charset",
+            RepliesError::InvalidFormData => "invalid_form_data",
+            RepliesError::InvalidPostType => "invalid_post_type",
+            RepliesError::MissingPostType => "missing_post_type",
+            RepliesError::TeamAddedToOrg => "team_added_to_org",
+            RepliesError::RequestTimeout => "request_timeout",
+            RepliesError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RepliesError::Unknown(code) => return ::Error::from_code("mpim.replies", &code),
+            RepliesError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("mpim.replies", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RepliesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
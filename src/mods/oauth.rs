@@ -20,24 +20,27 @@ pub fn access<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("client_id", request.client_id)),
-                      Some(("client_secret", request.client_secret)),
-                      Some(("code", request.code)),
-                      request
-                          .redirect_uri
-                          .map(|redirect_uri| ("redirect_uri", redirect_uri))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(4);
+    params.push(("client_id", request.client_id));
+    params.push(("client_secret", request.client_secret));
+    params.push(("code", request.code));
+    if let Some(redirect_uri) = request.redirect_uri {
+        params.push(("redirect_uri", redirect_uri));
+    }
     let url = ::get_slack_url_for_method("oauth.access");
     client
-        .send(&url, &params[..])
+        .send_form(&url, &params[..])
         .map_err(|err| AccessError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<AccessResponse>(&result)
-                            .map_err(|e| AccessError::MalformedResponse(e))
+                      parse_access_response(result.body.as_bytes()).map_err(|err| err.into_error(AccessError::MalformedResponse))
                   })
 }
 
-#[derive(Clone, Default, Debug)]
+fn parse_access_response(body: &[u8]) -> Result<AccessResponse, ::ResponseError> {
+    serde_json::from_slice(body).map_err(::ResponseError::Malformed)
+}
+
+#[derive(Clone, Default)]
 pub struct AccessRequest<'a> {
     /// Issued when you created your application.
     pub client_id: &'a str,
@@ -49,6 +52,33 @@ pub struct AccessRequest<'a> {
     pub redirect_uri: Option<&'a str>,
 }
 
+impl<'a> AccessRequest<'a> {
+    pub fn new(client_id: &'a str, client_secret: &'a str, code: &'a str) -> Self {
+        AccessRequest {
+            client_id: client_id,
+            client_secret: client_secret,
+            code: code,
+            ..AccessRequest::default()
+        }
+    }
+
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+}
+
+impl<'a> fmt::Debug for AccessRequest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccessRequest")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &::secret::Secret::new(self.client_secret))
+            .field("code", &::secret::Secret::new(self.code))
+            .field("redirect_uri", &self.redirect_uri)
+            .finish()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AccessResponse {
     pub access_token: Option<String>,
@@ -111,6 +141,29 @@ impl<'a, E: Error> From<&'a str> for AccessError<E> {
     }
 }
 
+impl<E: Error> From<AccessError<E>> for ::Error<E> {
+    fn from(err: AccessError<E>) -> Self {
+        let code = match err {
+            AccessError::InvalidClientId => "invalid_client_id",
+            AccessError::BadClientSecret => "bad_client_secret",
+            AccessError::InvalidCode => "invalid_code",
+            AccessError::BadRedirectUri => "bad_redirect_uri",
+            AccessError::InvalidArgName => "invalid_arg_name",
+            AccessError::InvalidArrayArg => "invalid_array_arg",
+            AccessError::InvalidCharset => "invalid_charset",
+            AccessError::InvalidFormData => "invalid_form_data",
+            AccessError::InvalidPostType => "invalid_post_type",
+            AccessError::MissingPostType => "missing_post_type",
+            AccessError::TeamAddedToOrg => "team_added_to_org",
+            AccessError::RequestTimeout => "request_timeout",
+            AccessError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AccessError::Unknown(code) => return ::Error::from_code("oauth.access", &code),
+            AccessError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("oauth.access", code)
+    }
+}
+
 impl<E: Error> fmt::Display for AccessError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -0,0 +1,582 @@
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Exchanges a temporary OAuth verifier code for an access token.
+///
+/// Wraps https://api.slack.com/methods/oauth.v2.access
+
+pub fn access<R>(client: &R,
+                 request: &AccessRequest)
+                 -> Result<AccessResponse, AccessError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut params = Vec::with_capacity(6);
+    params.push(("client_id", request.client_id));
+    params.push(("client_secret", request.client_secret));
+    if let Some(code) = request.code {
+        params.push(("code", code));
+    }
+    if let Some(grant_type) = request.grant_type {
+        params.push(("grant_type", grant_type));
+    }
+    if let Some(redirect_uri) = request.redirect_uri {
+        params.push(("redirect_uri", redirect_uri));
+    }
+    if let Some(refresh_token) = request.refresh_token {
+        params.push(("refresh_token", refresh_token));
+    }
+    let url = ::get_slack_url_for_method("oauth.v2.access");
+    client
+        .send_form(&url, &params[..])
+        .map_err(|err| AccessError::Client(err))
+        .and_then(|result| {
+                      parse_access_response(result.body.as_bytes()).map_err(|err| err.into_error(AccessError::MalformedResponse))
+                  })
+}
+
+fn parse_access_response(body: &[u8]) -> Result<AccessResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AccessResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct AccessRequest<'a> {
+    /// Issued when you created your application.
+    pub client_id: &'a str,
+    /// Issued when you created your application.
+    pub client_secret: &'a str,
+    /// The code param returned via the OAuth callback.
+    pub code: Option<&'a str>,
+    /// The grant_type param as described in the OAuth spec. Use refresh_token to exchange a refresh token for a new access token.
+    pub grant_type: Option<&'a str>,
+    /// This must match the originally submitted URI (if one was sent).
+    pub redirect_uri: Option<&'a str>,
+    /// The refresh_token param as described in the OAuth spec.
+    pub refresh_token: Option<&'a str>,
+}
+
+impl<'a> AccessRequest<'a> {
+    pub fn new(client_id: &'a str, client_secret: &'a str) -> Self {
+        AccessRequest {
+            client_id: client_id,
+            client_secret: client_secret,
+            ..AccessRequest::default()
+        }
+    }
+
+    pub fn code(mut self, code: &'a str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn grant_type(mut self, grant_type: &'a str) -> Self {
+        self.grant_type = Some(grant_type);
+        self
+    }
+
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    pub fn refresh_token(mut self, refresh_token: &'a str) -> Self {
+        self.refresh_token = Some(refresh_token);
+        self
+    }
+}
+
+impl<'a> fmt::Debug for AccessRequest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccessRequest")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &::secret::Secret::new(self.client_secret))
+            .field("code", &self.code.map(::secret::Secret::new))
+            .field("grant_type", &self.grant_type)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("refresh_token", &self.refresh_token.map(::secret::Secret::new))
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponse {
+    pub access_token: Option<String>,
+    pub app_id: Option<String>,
+    pub authed_user: Option<AccessResponseAuthedUser>,
+    pub bot_user_id: Option<String>,
+    pub enterprise: Option<AccessResponseEnterprise>,
+    error: Option<String>,
+    pub expires_in: Option<i32>,
+    pub incoming_webhook: Option<AccessResponseIncomingWebhook>,
+    pub is_enterprise_install: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    pub refresh_token: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub scope: Option<String>,
+    pub team: Option<AccessResponseTeam>,
+    pub token_type: Option<String>,
+    pub warning: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseAuthedUser {
+    pub access_token: Option<String>,
+    pub expires_in: Option<i32>,
+    pub id: Option<String>,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+    pub token_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseEnterprise {
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseIncomingWebhook {
+    pub channel: Option<String>,
+    pub channel_id: Option<String>,
+    pub configuration_url: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseTeam {
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<AccessResponse, AccessError<E>>> for AccessResponse {
+    fn into(self) -> Result<AccessResponse, AccessError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AccessError<E: Error> {
+    /// Value passed for client_id was invalid.
+    InvalidClientId,
+    /// Value passed for client_secret was invalid.
+    BadClientSecret,
+    /// Value passed for code was invalid.
+    InvalidCode,
+    /// Value passed for redirect_uri did not match the redirect_uri in the original request.
+    BadRedirectUri,
+    /// Value passed for grant_type was invalid.
+    InvalidGrantType,
+    /// The given refresh token is invalid.
+    InvalidRefreshToken,
+    /// The OAuth flow was initiated on an incorrect version of the authorization url. The flow must be initiated via /oauth/v2/authorize.
+    OauthAuthorizationUrlMismatch,
+    /// Returned when the app is already installed on the org and cannot be installed on a workspace.
+    CannotInstallAnOrgInstalledApp,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AccessError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_client_id" => AccessError::InvalidClientId,
+            "bad_client_secret" => AccessError::BadClientSecret,
+            "invalid_code" => AccessError::InvalidCode,
+            "bad_redirect_uri" => AccessError::BadRedirectUri,
+            "invalid_grant_type" => AccessError::InvalidGrantType,
+            "invalid_refresh_token" => AccessError::InvalidRefreshToken,
+            "oauth_authorization_url_mismatch" => AccessError::OauthAuthorizationUrlMismatch,
+            "cannot_install_an_org_installed_app" => AccessError::CannotInstallAnOrgInstalledApp,
+            "invalid_arg_name" => AccessError::InvalidArgName,
+            "invalid_array_arg" => AccessError::InvalidArrayArg,
+            "invalid_charset" => AccessError::InvalidCharset,
+            "invalid_form_data" => AccessError::InvalidFormData,
+            "invalid_post_type" => AccessError::InvalidPostType,
+            "missing_post_type" => AccessError::MissingPostType,
+            "team_added_to_org" => AccessError::TeamAddedToOrg,
+            "request_timeout" => AccessError::RequestTimeout,
+            _ => AccessError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<AccessError<E>> for ::Error<E> {
+    fn from(err: AccessError<E>) -> Self {
+        let code = match err {
+            AccessError::InvalidClientId => "invalid_client_id",
+            AccessError::BadClientSecret => "bad_client_secret",
+            AccessError::InvalidCode => "invalid_code",
+            AccessError::BadRedirectUri => "bad_redirect_uri",
+            AccessError::InvalidGrantType => "invalid_grant_type",
+            AccessError::InvalidRefreshToken => "invalid_refresh_token",
+            AccessError::OauthAuthorizationUrlMismatch => "oauth_authorization_url_mismatch",
+            AccessError::CannotInstallAnOrgInstalledApp => "cannot_install_an_org_installed_app",
+            AccessError::InvalidArgName => "invalid_arg_name",
+            AccessError::InvalidArrayArg => "invalid_array_arg",
+            AccessError::InvalidCharset => "invalid_charset",
+            AccessError::InvalidFormData => "invalid_form_data",
+            AccessError::InvalidPostType => "invalid_post_type",
+            AccessError::MissingPostType => "missing_post_type",
+            AccessError::TeamAddedToOrg => "team_added_to_org",
+            AccessError::RequestTimeout => "request_timeout",
+            AccessError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AccessError::Unknown(code) => return ::Error::from_code("oauth.v2.access", &code),
+            AccessError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("oauth.v2.access", code)
+    }
+}
+
+impl<E: Error> fmt::Display for AccessError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AccessError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &AccessError::InvalidClientId => "invalid_client_id: Value passed for client_id was invalid.",
+            &AccessError::BadClientSecret => "bad_client_secret: Value passed for client_secret was invalid.",
+            &AccessError::InvalidCode => "invalid_code: Value passed for code was invalid.",
+            &AccessError::BadRedirectUri => "bad_redirect_uri: Value passed for redirect_uri did not match the redirect_uri in the original request.",
+            &AccessError::InvalidGrantType => "invalid_grant_type: Value passed for grant_type was invalid.",
+            &AccessError::InvalidRefreshToken => "invalid_refresh_token: The given refresh token is invalid.",
+            &AccessError::OauthAuthorizationUrlMismatch => "oauth_authorization_url_mismatch: The OAuth flow was initiated on an incorrect version of the authorization url. The flow must be initiated via /oauth/v2/authorize.",
+            &AccessError::CannotInstallAnOrgInstalledApp => "cannot_install_an_org_installed_app: Returned when the app is already installed on the org and cannot be installed on a workspace.",
+            &AccessError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &AccessError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &AccessError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &AccessError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &AccessError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &AccessError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &AccessError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &AccessError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &AccessError::MalformedResponse(ref e) => e.description(),
+            &AccessError::Unknown(ref s) => s,
+            &AccessError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &AccessError::MalformedResponse(ref e) => Some(e),
+            &AccessError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Exchanges a legacy access token for a new expiring access token and refresh token
+///
+/// Wraps https://api.slack.com/methods/oauth.v2.exchange
+
+pub fn exchange<R>(client: &R,
+                   token: &str,
+                   request: &ExchangeRequest)
+                   -> Result<ExchangeResponse, ExchangeError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("client_id".to_owned(), request.client_id.into());
+    body.insert("client_secret".to_owned(), request.client_secret.into());
+    let url = ::get_slack_url_for_method("oauth.v2.exchange");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| ExchangeError::Client(err))
+        .and_then(|result| {
+                      parse_exchange_response(result.body.as_bytes()).map_err(|err| err.into_error(ExchangeError::MalformedResponse))
+                  })
+}
+
+fn parse_exchange_response(body: &[u8]) -> Result<ExchangeResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ExchangeResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct ExchangeRequest<'a> {
+    /// Issued when you created your application.
+    pub client_id: &'a str,
+    /// Issued when you created your application.
+    pub client_secret: &'a str,
+}
+
+impl<'a> ExchangeRequest<'a> {
+    pub fn new(client_id: &'a str, client_secret: &'a str) -> Self {
+        ExchangeRequest {
+            client_id: client_id,
+            client_secret: client_secret,
+            ..ExchangeRequest::default()
+        }
+    }
+}
+
+impl<'a> fmt::Debug for ExchangeRequest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExchangeRequest")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &::secret::Secret::new(self.client_secret))
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExchangeResponse {
+    pub access_token: Option<String>,
+    pub app_id: Option<String>,
+    pub authed_user: Option<ExchangeResponseAuthedUser>,
+    pub bot_user_id: Option<String>,
+    pub enterprise: Option<ExchangeResponseEnterprise>,
+    error: Option<String>,
+    pub expires_in: Option<i32>,
+    pub is_enterprise_install: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    pub refresh_token: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub scope: Option<String>,
+    pub team: Option<ExchangeResponseTeam>,
+    pub token_type: Option<String>,
+    pub warning: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExchangeResponseAuthedUser {
+    pub id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExchangeResponseEnterprise {
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExchangeResponseTeam {
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<ExchangeResponse, ExchangeError<E>>> for ExchangeResponse {
+    fn into(self) -> Result<ExchangeResponse, ExchangeError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ExchangeError<E: Error> {
+    /// Value passed for client_id was invalid.
+    InvalidClientId,
+    /// Value passed for client_secret was invalid.
+    BadClientSecret,
+    /// Token rotation is not enabled for the app.
+    TokenRotationNotEnabled,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ExchangeError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_client_id" => ExchangeError::InvalidClientId,
+            "bad_client_secret" => ExchangeError::BadClientSecret,
+            "token_rotation_not_enabled" => ExchangeError::TokenRotationNotEnabled,
+            "missing_scope" => ExchangeError::MissingScope,
+            "not_authed" => ExchangeError::NotAuthed,
+            "invalid_auth" => ExchangeError::InvalidAuth,
+            "account_inactive" => ExchangeError::AccountInactive,
+            "invalid_arg_name" => ExchangeError::InvalidArgName,
+            "invalid_array_arg" => ExchangeError::InvalidArrayArg,
+            "invalid_charset" => ExchangeError::InvalidCharset,
+            "invalid_form_data" => ExchangeError::InvalidFormData,
+            "invalid_post_type" => ExchangeError::InvalidPostType,
+            "missing_post_type" => ExchangeError::MissingPostType,
+            "team_added_to_org" => ExchangeError::TeamAddedToOrg,
+            "request_timeout" => ExchangeError::RequestTimeout,
+            _ => ExchangeError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<ExchangeError<E>> for ::Error<E> {
+    fn from(err: ExchangeError<E>) -> Self {
+        let code = match err {
+            ExchangeError::InvalidClientId => "invalid_client_id",
+            ExchangeError::BadClientSecret => "bad_client_secret",
+            ExchangeError::TokenRotationNotEnabled => "token_rotation_not_enabled",
+            ExchangeError::MissingScope => "missing_scope",
+            ExchangeError::NotAuthed => "not_authed",
+            ExchangeError::InvalidAuth => "invalid_auth",
+            ExchangeError::AccountInactive => "account_inactive",
+            ExchangeError::InvalidArgName => "invalid_arg_name",
+            ExchangeError::InvalidArrayArg => "invalid_array_arg",
+            ExchangeError::InvalidCharset => "invalid_charset",
+            ExchangeError::InvalidFormData => "invalid_form_data",
+            ExchangeError::InvalidPostType => "invalid_post_type",
+            ExchangeError::MissingPostType => "missing_post_type",
+            ExchangeError::TeamAddedToOrg => "team_added_to_org",
+            ExchangeError::RequestTimeout => "request_timeout",
+            ExchangeError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ExchangeError::Unknown(code) => return ::Error::from_code("oauth.v2.exchange", &code),
+            ExchangeError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("oauth.v2.exchange", code)
+    }
+}
+
+impl<E: Error> fmt::Display for ExchangeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ExchangeError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ExchangeError::InvalidClientId => "invalid_client_id: Value passed for client_id was invalid.",
+            &ExchangeError::BadClientSecret => "bad_client_secret: Value passed for client_secret was invalid.",
+            &ExchangeError::TokenRotationNotEnabled => "token_rotation_not_enabled: Token rotation is not enabled for the app.",
+            &ExchangeError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+            &ExchangeError::NotAuthed => "not_authed: No authentication token provided.",
+            &ExchangeError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &ExchangeError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &ExchangeError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &ExchangeError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &ExchangeError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &ExchangeError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &ExchangeError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &ExchangeError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &ExchangeError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &ExchangeError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &ExchangeError::MalformedResponse(ref e) => e.description(),
+            &ExchangeError::Unknown(ref s) => s,
+            &ExchangeError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ExchangeError::MalformedResponse(ref e) => Some(e),
+            &ExchangeError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `oauth.v2.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::oauth_v2`](../client/struct.SlackClient.html#method.oauth_v2).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Exchanges a temporary OAuth verifier code for an access token. See
+    /// [`access`](fn.access.html).
+    pub fn access(&self, request: &AccessRequest) -> Result<AccessResponse, AccessError<R::Error>> {
+        access(self.client, request)
+    }
+
+    /// Exchanges a legacy access token for a new expiring access token and refresh token. See
+    /// [`exchange`](fn.exchange.html).
+    pub fn exchange(&self, request: &ExchangeRequest) -> Result<ExchangeResponse, ExchangeError<R::Error>> {
+        exchange(self.client, self.token.for_method("oauth.v2.exchange"), request)
+    }
+}
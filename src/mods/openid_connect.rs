@@ -0,0 +1,466 @@
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Exchanges a temporary OAuth verifier code for an access token for Sign in with Slack.
+///
+/// Wraps https://api.slack.com/methods/openid.connect.token
+
+pub fn token<R>(client: &R, request: &TokenRequest) -> Result<TokenResponse, TokenError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut params = Vec::with_capacity(6);
+    params.push(("client_id", request.client_id));
+    params.push(("client_secret", request.client_secret));
+    if let Some(code) = request.code {
+        params.push(("code", code));
+    }
+    if let Some(grant_type) = request.grant_type {
+        params.push(("grant_type", grant_type));
+    }
+    if let Some(redirect_uri) = request.redirect_uri {
+        params.push(("redirect_uri", redirect_uri));
+    }
+    if let Some(refresh_token) = request.refresh_token {
+        params.push(("refresh_token", refresh_token));
+    }
+    let url = ::get_slack_url_for_method("openid.connect.token");
+    client
+        .send_form(&url, &params[..])
+        .map_err(|err| TokenError::Client(err))
+        .and_then(|result| {
+                      parse_token_response(result.body.as_bytes()).map_err(|err| err.into_error(TokenError::MalformedResponse))
+                  })
+}
+
+fn parse_token_response(body: &[u8]) -> Result<TokenResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<TokenResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct TokenRequest<'a> {
+    /// Issued when you created your application.
+    pub client_id: &'a str,
+    /// Issued when you created your application.
+    pub client_secret: &'a str,
+    /// The code param returned via the OAuth callback.
+    pub code: Option<&'a str>,
+    /// The grant_type param as described in the OAuth spec.
+    pub grant_type: Option<&'a str>,
+    /// This must match the originally submitted URI (if one was sent).
+    pub redirect_uri: Option<&'a str>,
+    /// The refresh_token param as described in the OAuth spec.
+    pub refresh_token: Option<&'a str>,
+}
+
+impl<'a> TokenRequest<'a> {
+    pub fn new(client_id: &'a str, client_secret: &'a str) -> Self {
+        TokenRequest {
+            client_id: client_id,
+            client_secret: client_secret,
+            ..TokenRequest::default()
+        }
+    }
+
+    pub fn code(mut self, code: &'a str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn grant_type(mut self, grant_type: &'a str) -> Self {
+        self.grant_type = Some(grant_type);
+        self
+    }
+
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    pub fn refresh_token(mut self, refresh_token: &'a str) -> Self {
+        self.refresh_token = Some(refresh_token);
+        self
+    }
+}
+
+impl<'a> fmt::Debug for TokenRequest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TokenRequest")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &::secret::Secret::new(self.client_secret))
+            .field("code", &self.code.map(::secret::Secret::new))
+            .field("grant_type", &self.grant_type)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("refresh_token", &self.refresh_token.map(::secret::Secret::new))
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: Option<String>,
+    error: Option<String>,
+    pub expires_in: Option<i32>,
+    pub id_token: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub refresh_token: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub token_type: Option<String>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<TokenResponse, TokenError<E>>> for TokenResponse {
+    fn into(self) -> Result<TokenResponse, TokenError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum TokenError<E: Error> {
+    /// Value passed for client_id was invalid.
+    InvalidClientId,
+    /// Value passed for client_secret was invalid.
+    BadClientSecret,
+    /// Value passed for code was invalid.
+    InvalidCode,
+    /// Value passed for redirect_uri did not match the redirect_uri in the original request.
+    BadRedirectUri,
+    /// Value passed for grant_type was invalid.
+    InvalidGrantType,
+    /// The given refresh token is invalid.
+    InvalidRefreshToken,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for TokenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_client_id" => TokenError::InvalidClientId,
+            "bad_client_secret" => TokenError::BadClientSecret,
+            "invalid_code" => TokenError::InvalidCode,
+            "bad_redirect_uri" => TokenError::BadRedirectUri,
+            "invalid_grant_type" => TokenError::InvalidGrantType,
+            "invalid_refresh_token" => TokenError::InvalidRefreshToken,
+            "invalid_arg_name" => TokenError::InvalidArgName,
+            "invalid_array_arg" => TokenError::InvalidArrayArg,
+            "invalid_charset" => TokenError::InvalidCharset,
+            "invalid_form_data" => TokenError::InvalidFormData,
+            "invalid_post_type" => TokenError::InvalidPostType,
+            "missing_post_type" => TokenError::MissingPostType,
+            "team_added_to_org" => TokenError::TeamAddedToOrg,
+            "request_timeout" => TokenError::RequestTimeout,
+            _ => TokenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<TokenError<E>> for ::Error<E> {
+    fn from(err: TokenError<E>) -> Self {
+        let code = match err {
+            TokenError::InvalidClientId => "invalid_client_id",
+            TokenError::BadClientSecret => "bad_client_secret",
+            TokenError::InvalidCode => "invalid_code",
+            TokenError::BadRedirectUri => "bad_redirect_uri",
+            TokenError::InvalidGrantType => "invalid_grant_type",
+            TokenError::InvalidRefreshToken => "invalid_refresh_token",
+            TokenError::InvalidArgName => "invalid_arg_name",
+            TokenError::InvalidArrayArg => "invalid_array_arg",
+            TokenError::InvalidCharset => "invalid_charset",
+            TokenError::InvalidFormData => "invalid_form_data",
+            TokenError::InvalidPostType => "invalid_post_type",
+            TokenError::MissingPostType => "missing_post_type",
+            TokenError::TeamAddedToOrg => "team_added_to_org",
+            TokenError::RequestTimeout => "request_timeout",
+            TokenError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            TokenError::Unknown(code) => return ::Error::from_code("openid.connect.token", &code),
+            TokenError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("openid.connect.token", code)
+    }
+}
+
+impl<E: Error> fmt::Display for TokenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for TokenError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &TokenError::InvalidClientId => "invalid_client_id: Value passed for client_id was invalid.",
+            &TokenError::BadClientSecret => "bad_client_secret: Value passed for client_secret was invalid.",
+            &TokenError::InvalidCode => "invalid_code: Value passed for code was invalid.",
+            &TokenError::BadRedirectUri => "bad_redirect_uri: Value passed for redirect_uri did not match the redirect_uri in the original request.",
+            &TokenError::InvalidGrantType => "invalid_grant_type: Value passed for grant_type was invalid.",
+            &TokenError::InvalidRefreshToken => "invalid_refresh_token: The given refresh token is invalid.",
+            &TokenError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &TokenError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &TokenError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &TokenError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &TokenError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &TokenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &TokenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &TokenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &TokenError::MalformedResponse(ref e) => e.description(),
+            &TokenError::Unknown(ref s) => s,
+            &TokenError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &TokenError::MalformedResponse(ref e) => Some(e),
+            &TokenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Get the identity of a user who has authorized Sign in with Slack.
+///
+/// Wraps https://api.slack.com/methods/openid.connect.userInfo
+
+pub fn user_info<R>(client: &R, token: &str) -> Result<UserInfoResponse, UserInfoError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let params = &[("token", token)];
+    let url = ::get_slack_url_for_method("openid.connect.userInfo");
+    client
+        .send_bytes(&url, &params[..])
+        .map_err(|err| UserInfoError::Client(err))
+        .and_then(|result| {
+                      parse_user_info_response(&result).map_err(|err| err.into_error(UserInfoError::MalformedResponse))
+                  })
+}
+
+fn parse_user_info_response(body: &[u8]) -> Result<UserInfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UserInfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UserInfoResponse {
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    error: Option<String>,
+    pub family_name: Option<String>,
+    pub given_name: Option<String>,
+    pub locale: Option<String>,
+    pub name: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub picture: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub sub: Option<String>,
+    #[serde(rename = "https://slack.com/team_domain")]
+    pub team_domain: Option<String>,
+    #[serde(rename = "https://slack.com/team_id")]
+    pub team_id: Option<String>,
+    #[serde(rename = "https://slack.com/team_name")]
+    pub team_name: Option<String>,
+    #[serde(rename = "https://slack.com/user_id")]
+    pub user_id: Option<String>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<UserInfoResponse, UserInfoError<E>>> for UserInfoResponse {
+    fn into(self) -> Result<UserInfoResponse, UserInfoError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UserInfoError<E: Error> {
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UserInfoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "not_authed" => UserInfoError::NotAuthed,
+            "invalid_auth" => UserInfoError::InvalidAuth,
+            "account_inactive" => UserInfoError::AccountInactive,
+            "invalid_arg_name" => UserInfoError::InvalidArgName,
+            "invalid_array_arg" => UserInfoError::InvalidArrayArg,
+            "invalid_charset" => UserInfoError::InvalidCharset,
+            "invalid_form_data" => UserInfoError::InvalidFormData,
+            "invalid_post_type" => UserInfoError::InvalidPostType,
+            "missing_post_type" => UserInfoError::MissingPostType,
+            "team_added_to_org" => UserInfoError::TeamAddedToOrg,
+            "request_timeout" => UserInfoError::RequestTimeout,
+            _ => UserInfoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<UserInfoError<E>> for ::Error<E> {
+    fn from(err: UserInfoError<E>) -> Self {
+        let code = match err {
+            UserInfoError::NotAuthed => "not_authed",
+            UserInfoError::InvalidAuth => "invalid_auth",
+            UserInfoError::AccountInactive => "account_inactive",
+            UserInfoError::InvalidArgName => "invalid_arg_name",
+            UserInfoError::InvalidArrayArg => "invalid_array_arg",
+            UserInfoError::InvalidCharset => "invalid_charset",
+            UserInfoError::InvalidFormData => "invalid_form_data",
+            UserInfoError::InvalidPostType => "invalid_post_type",
+            UserInfoError::MissingPostType => "missing_post_type",
+            UserInfoError::TeamAddedToOrg => "team_added_to_org",
+            UserInfoError::RequestTimeout => "request_timeout",
+            UserInfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UserInfoError::Unknown(code) => return ::Error::from_code("openid.connect.userInfo", &code),
+            UserInfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("openid.connect.userInfo", code)
+    }
+}
+
+impl<E: Error> fmt::Display for UserInfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UserInfoError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &UserInfoError::NotAuthed => "not_authed: No authentication token provided.",
+            &UserInfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &UserInfoError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &UserInfoError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &UserInfoError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &UserInfoError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &UserInfoError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &UserInfoError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &UserInfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &UserInfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &UserInfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &UserInfoError::MalformedResponse(ref e) => e.description(),
+            &UserInfoError::Unknown(ref s) => s,
+            &UserInfoError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &UserInfoError::MalformedResponse(ref e) => Some(e),
+            &UserInfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `openid.connect.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::openid_connect`](../client/struct.SlackClient.html#method.openid_connect).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Exchanges a temporary OAuth verifier code for an access token for Sign in with Slack. See
+    /// [`token`](fn.token.html).
+    pub fn token(&self, request: &TokenRequest) -> Result<TokenResponse, TokenError<R::Error>> {
+        token(self.client, request)
+    }
+
+    /// Get the identity of a user who has authorized Sign in with Slack. See
+    /// [`user_info`](fn.user_info.html).
+    pub fn user_info(&self) -> Result<UserInfoResponse, UserInfoError<R::Error>> {
+        user_info(self.client, self.token.for_method("openid.connect.userInfo"))
+    }
+}
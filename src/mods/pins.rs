@@ -21,25 +21,35 @@ pub fn add<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      request.file.map(|file| ("file", file)),
-                      request
-                          .file_comment
-                          .map(|file_comment| ("file_comment", file_comment)),
-                      request
-                          .timestamp
-                          .map(|timestamp| ("timestamp", timestamp))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    if let Some(file) = request.file {
+        body.insert("file".to_owned(), file.into());
+    }
+    if let Some(file_comment) = request.file_comment {
+        body.insert("file_comment".to_owned(), file_comment.into());
+    }
+    if let Some(timestamp) = request.timestamp {
+        body.insert("timestamp".to_owned(), timestamp.into());
+    }
     let url = ::get_slack_url_for_method("pins.add");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| AddError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<AddResponse>(&result)
-                            .map_err(|e| AddError::MalformedResponse(e))
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -54,11 +64,37 @@ pub struct AddRequest<'a> {
     pub timestamp: Option<&'a str>,
 }
 
+impl<'a> AddRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        AddRequest {
+            channel: channel,
+            ..AddRequest::default()
+        }
+    }
+
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.file_comment = Some(file_comment);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -153,6 +189,37 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::BadTimestamp => "bad_timestamp",
+            AddError::FileNotFound => "file_not_found",
+            AddError::FileCommentNotFound => "file_comment_not_found",
+            AddError::MessageNotFound => "message_not_found",
+            AddError::ChannelNotFound => "channel_not_found",
+            AddError::NoItemSpecified => "no_item_specified",
+            AddError::AlreadyPinned => "already_pinned",
+            AddError::PermissionDenied => "permission_denied",
+            AddError::FileNotShared => "file_not_shared",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("pins.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("pins.add", code)
+    }
+}
+
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -208,17 +275,23 @@ pub fn list<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [("token", token), ("channel", request.channel)];
     let url = ::get_slack_url_for_method("pins.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -227,91 +300,25 @@ pub struct ListRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponse {
-    error: Option<String>,
-    pub items: Option<Vec<ListResponseItem>>,
-    #[serde(default)]
-    ok: bool,
-}
-
-#[derive(Clone, Debug)]
-pub enum ListResponseItem {
-    Message(ListResponseItemMessage),
-    File(ListResponseItemFile),
-    FileComment(ListResponseItemFileComment),
-}
-
-impl ::serde::Deserialize for ListResponseItem {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where D: ::serde::Deserializer
-    {
-        use serde::de::Error as SerdeError;
-
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
-                            .map(|obj| ListResponseItem::Message(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<ListResponseItemFile>(value.clone())
-                            .map(|obj| ListResponseItem::File(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
-                            .map(|obj| ListResponseItem::FileComment(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &"a string"))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
+impl<'a> ListRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        ListRequest {
+            channel: channel,
+            ..ListRequest::default()
         }
     }
 }
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFile {
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFileComment {
-    pub comment: ::FileComment,
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemMessage {
-    pub channel: String,
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub message: ::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
+pub struct ListResponse {
+    error: Option<String>,
+    pub items: Option<Vec<::Item>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
-
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
     fn into(self) -> Result<ListResponse, ListError<E>> {
         if self.ok {
@@ -379,6 +386,29 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::ChannelNotFound => "channel_not_found",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("pins.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("pins.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -426,25 +456,35 @@ pub fn remove<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("channel", request.channel)),
-                      request.file.map(|file| ("file", file)),
-                      request
-                          .file_comment
-                          .map(|file_comment| ("file_comment", file_comment)),
-                      request
-                          .timestamp
-                          .map(|timestamp| ("timestamp", timestamp))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("channel".to_owned(), request.channel.into());
+    if let Some(file) = request.file {
+        body.insert("file".to_owned(), file.into());
+    }
+    if let Some(file_comment) = request.file_comment {
+        body.insert("file_comment".to_owned(), file_comment.into());
+    }
+    if let Some(timestamp) = request.timestamp {
+        body.insert("timestamp".to_owned(), timestamp.into());
+    }
     let url = ::get_slack_url_for_method("pins.remove");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| RemoveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RemoveResponse>(&result)
-                            .map_err(|e| RemoveError::MalformedResponse(e))
+                      parse_remove_response(result.body.as_bytes()).map_err(|err| err.into_error(RemoveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_remove_response(body: &[u8]) -> Result<RemoveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RemoveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -459,11 +499,37 @@ pub struct RemoveRequest<'a> {
     pub timestamp: Option<&'a str>,
 }
 
+impl<'a> RemoveRequest<'a> {
+    pub fn new(channel: &'a str) -> Self {
+        RemoveRequest {
+            channel: channel,
+            ..RemoveRequest::default()
+        }
+    }
+
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.file_comment = Some(file_comment);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RemoveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -552,6 +618,35 @@ impl<'a, E: Error> From<&'a str> for RemoveError<E> {
     }
 }
 
+impl<E: Error> From<RemoveError<E>> for ::Error<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        let code = match err {
+            RemoveError::BadTimestamp => "bad_timestamp",
+            RemoveError::FileNotFound => "file_not_found",
+            RemoveError::FileCommentNotFound => "file_comment_not_found",
+            RemoveError::MessageNotFound => "message_not_found",
+            RemoveError::NoItemSpecified => "no_item_specified",
+            RemoveError::NotPinned => "not_pinned",
+            RemoveError::PermissionDenied => "permission_denied",
+            RemoveError::NotAuthed => "not_authed",
+            RemoveError::InvalidAuth => "invalid_auth",
+            RemoveError::AccountInactive => "account_inactive",
+            RemoveError::InvalidArgName => "invalid_arg_name",
+            RemoveError::InvalidArrayArg => "invalid_array_arg",
+            RemoveError::InvalidCharset => "invalid_charset",
+            RemoveError::InvalidFormData => "invalid_form_data",
+            RemoveError::InvalidPostType => "invalid_post_type",
+            RemoveError::MissingPostType => "missing_post_type",
+            RemoveError::TeamAddedToOrg => "team_added_to_org",
+            RemoveError::RequestTimeout => "request_timeout",
+            RemoveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RemoveError::Unknown(code) => return ::Error::from_code("pins.remove", &code),
+            RemoveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("pins.remove", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -593,3 +688,35 @@ impl<E: Error> Error for RemoveError<E> {
         }
     }
 }
+
+/// The `pins.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::pins`](../client/struct.SlackClient.html#method.pins).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Pins an item to a channel. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("pins.add"), request)
+    }
+
+    /// Lists items pinned to a channel. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("pins.list"), request)
+    }
+
+    /// Un-pins an item from a channel. See [`remove`](fn.remove.html).
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<R::Error>> {
+        remove(self.client, self.token.for_method("pins.remove"), request)
+    }
+}
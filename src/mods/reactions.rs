@@ -21,26 +21,38 @@ pub fn add<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("name", request.name)),
-                      request.file.map(|file| ("file", file)),
-                      request
-                          .file_comment
-                          .map(|file_comment| ("file_comment", file_comment)),
-                      request.channel.map(|channel| ("channel", channel)),
-                      request
-                          .timestamp
-                          .map(|timestamp| ("timestamp", timestamp))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("name".to_owned(), request.name.into());
+    if let Some(file) = request.file {
+        body.insert("file".to_owned(), file.into());
+    }
+    if let Some(file_comment) = request.file_comment {
+        body.insert("file_comment".to_owned(), file_comment.into());
+    }
+    if let Some(channel) = request.channel {
+        body.insert("channel".to_owned(), channel.into());
+    }
+    if let Some(timestamp) = request.timestamp {
+        body.insert("timestamp".to_owned(), timestamp.into());
+    }
     let url = ::get_slack_url_for_method("reactions.add");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| AddError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<AddResponse>(&result)
-                            .map_err(|e| AddError::MalformedResponse(e))
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -57,11 +69,42 @@ pub struct AddRequest<'a> {
     pub timestamp: Option<&'a str>,
 }
 
+impl<'a> AddRequest<'a> {
+    pub fn new(name: &'a str) -> Self {
+        AddRequest {
+            name: name,
+            ..AddRequest::default()
+        }
+    }
+
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.file_comment = Some(file_comment);
+        self
+    }
+
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -156,6 +199,37 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::BadTimestamp => "bad_timestamp",
+            AddError::FileNotFound => "file_not_found",
+            AddError::FileCommentNotFound => "file_comment_not_found",
+            AddError::MessageNotFound => "message_not_found",
+            AddError::NoItemSpecified => "no_item_specified",
+            AddError::InvalidName => "invalid_name",
+            AddError::AlreadyReacted => "already_reacted",
+            AddError::TooManyEmoji => "too_many_emoji",
+            AddError::TooManyReactions => "too_many_reactions",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("reactions.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reactions.add", code)
+    }
+}
+
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -211,28 +285,39 @@ pub fn get<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request.file.map(|file| ("file", file)),
-                      request
-                          .file_comment
-                          .map(|file_comment| ("file_comment", file_comment)),
-                      request.channel.map(|channel| ("channel", channel)),
-                      request
-                          .timestamp
-                          .map(|timestamp| ("timestamp", timestamp)),
-                      request
-                          .full
-                          .map(|full| ("full", if full { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(6);
+    params.push(("token", token));
+    if let Some(file) = request.file {
+        params.push(("file", file));
+    }
+    if let Some(file_comment) = request.file_comment {
+        params.push(("file_comment", file_comment));
+    }
+    if let Some(channel) = request.channel {
+        params.push(("channel", channel));
+    }
+    if let Some(timestamp) = request.timestamp {
+        params.push(("timestamp", timestamp));
+    }
+    if let Some(full) = request.full {
+        params.push(("full", if full { "1" } else { "0" }));
+    }
     let url = ::get_slack_url_for_method("reactions.get");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| GetError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<GetResponse>(&result)
-                            .map_err(|e| GetError::MalformedResponse(e))
+                      parse_get_response(&result).map_err(|err| err.into_error(GetError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_get_response(body: &[u8]) -> Result<GetResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<GetResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -249,133 +334,80 @@ pub struct GetRequest<'a> {
     pub full: Option<bool>,
 }
 
-#[derive(Clone, Debug)]
-pub enum GetResponse {
-    Message(GetResponseMessage),
-    File(GetResponseFile),
-    FileComment(GetResponseFileComment),
-}
+impl<'a> GetRequest<'a> {
+    pub fn new() -> Self {
+        GetRequest::default()
+    }
 
-impl ::serde::Deserialize for GetResponse {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where D: ::serde::Deserializer
-    {
-        use serde::de::Error as SerdeError;
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
 
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<GetResponseMessage>(value.clone())
-                            .map(|obj| GetResponse::Message(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<GetResponseFile>(value.clone())
-                            .map(|obj| GetResponse::File(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<GetResponseFileComment>(value.clone())
-                            .map(|obj| GetResponse::FileComment(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &"a string"))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
-        }
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.file_comment = Some(file_comment);
+        self
     }
-}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseFile {
-    error: Option<String>,
-    pub file: ::File,
-    #[serde(default)]
-    ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
 
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseFileComment {
-    pub comment: ::FileComment,
-    error: Option<String>,
-    pub file: ::File,
-    #[serde(default)]
-    ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
 }
 
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseMessage {
-    pub channel: String,
+#[derive(Clone, Debug)]
+pub struct GetResponse {
     error: Option<String>,
-    pub message: ::Message,
-    #[serde(default)]
+    pub item: Option<::Item>,
     ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
+// The item is not in a field of the response: its fields are next to `ok`.
+impl ::serde::Deserialize for GetResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::Deserializer
+    {
+        use serde::de::Error as SerdeError;
 
-impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
-    fn into(self) -> Result<GetResponse, GetError<E>> {
-        match self {
-            GetResponse::Message(inner) => {
-                let x: Result<GetResponseMessage, GetError<E>> = inner.into();
-                x.map(|r| GetResponse::Message(r))
-            }
-            GetResponse::File(inner) => {
-                let x: Result<GetResponseFile, GetError<E>> = inner.into();
-                x.map(|r| GetResponse::File(r))
-            }
-            GetResponse::FileComment(inner) => {
-                let x: Result<GetResponseFileComment, GetError<E>> = inner.into();
-                x.map(|r| GetResponse::FileComment(r))
+        let mut value = ::serde_json::Value::deserialize(deserializer)?;
+        let outcome = ::serde_json::from_value::<::requests::Outcome>(value.clone())
+            .map_err(|e| D::Error::custom(&format!("{}", e)))?;
+        if let Some(object) = value.as_object_mut() {
+            for field in &["ok", "error", "warning", "response_metadata"] {
+                object.remove(*field);
             }
         }
-    }
-}
-
-impl<E: Error> Into<Result<GetResponseMessage, GetError<E>>> for GetResponseMessage {
-    fn into(self) -> Result<GetResponseMessage, GetError<E>> {
-        if self.ok {
-            Ok(self)
+        let item = if outcome.ok {
+            ::serde_json::from_value::<::Item>(value)
+                .map(Some)
+                .map_err(|e| D::Error::custom(&format!("{}", e)))?
         } else {
-            Err(self.error
-                    .as_ref()
-                    .map(String::as_ref)
-                    .unwrap_or("")
-                    .into())
-        }
+            None
+        };
+        Ok(GetResponse {
+            error: outcome.error,
+            item: item,
+            ok: outcome.ok,
+            response_metadata: outcome.response_metadata,
+            warning: outcome.warning,
+        })
     }
 }
-impl<E: Error> Into<Result<GetResponseFile, GetError<E>>> for GetResponseFile {
-    fn into(self) -> Result<GetResponseFile, GetError<E>> {
-        if self.ok {
-            Ok(self)
-        } else {
-            Err(self.error
-                    .as_ref()
-                    .map(String::as_ref)
-                    .unwrap_or("")
-                    .into())
-        }
-    }
-}
-impl<E: Error> Into<Result<GetResponseFileComment, GetError<E>>> for GetResponseFileComment {
-    fn into(self) -> Result<GetResponseFileComment, GetError<E>> {
+
+impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
+    fn into(self) -> Result<GetResponse, GetError<E>> {
         if self.ok {
             Ok(self)
         } else {
@@ -453,6 +485,33 @@ impl<'a, E: Error> From<&'a str> for GetError<E> {
     }
 }
 
+impl<E: Error> From<GetError<E>> for ::Error<E> {
+    fn from(err: GetError<E>) -> Self {
+        let code = match err {
+            GetError::BadTimestamp => "bad_timestamp",
+            GetError::FileNotFound => "file_not_found",
+            GetError::FileCommentNotFound => "file_comment_not_found",
+            GetError::MessageNotFound => "message_not_found",
+            GetError::NoItemSpecified => "no_item_specified",
+            GetError::NotAuthed => "not_authed",
+            GetError::InvalidAuth => "invalid_auth",
+            GetError::AccountInactive => "account_inactive",
+            GetError::InvalidArgName => "invalid_arg_name",
+            GetError::InvalidArrayArg => "invalid_array_arg",
+            GetError::InvalidCharset => "invalid_charset",
+            GetError::InvalidFormData => "invalid_form_data",
+            GetError::InvalidPostType => "invalid_post_type",
+            GetError::MissingPostType => "missing_post_type",
+            GetError::TeamAddedToOrg => "team_added_to_org",
+            GetError::RequestTimeout => "request_timeout",
+            GetError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            GetError::Unknown(code) => return ::Error::from_code("reactions.get", &code),
+            GetError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reactions.get", code)
+    }
+}
+
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -503,25 +562,38 @@ pub fn list<R>(client: &R,
                -> Result<ListResponse, ListError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let params = vec![Some(("token", token)),
-                      request.user.map(|user| ("user", user)),
-                      request
-                          .full
-                          .map(|full| ("full", if full { "1" } else { "0" })),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let mut params = Vec::with_capacity(5);
+    params.push(("token", token));
+    if let Some(user) = request.user {
+        params.push(("user", user));
+    }
+    if let Some(full) = request.full {
+        params.push(("full", if full { "1" } else { "0" }));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
     let url = ::get_slack_url_for_method("reactions.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -536,86 +608,43 @@ pub struct ListRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponse {
-    error: Option<String>,
-    pub items: Option<Vec<ListResponseItem>>,
-    #[serde(default)]
-    ok: bool,
-    pub paging: Option<::Paging>,
-}
-
-#[derive(Clone, Debug)]
-pub enum ListResponseItem {
-    Message(ListResponseItemMessage),
-    File(ListResponseItemFile),
-    FileComment(ListResponseItemFileComment),
-}
-
-impl ::serde::Deserialize for ListResponseItem {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where D: ::serde::Deserializer
-    {
-        use serde::de::Error as SerdeError;
+impl<'a> ListRequest<'a> {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
 
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
-                            .map(|obj| ListResponseItem::Message(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<ListResponseItemFile>(value.clone())
-                            .map(|obj| ListResponseItem::File(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
-                            .map(|obj| ListResponseItem::FileComment(obj))
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &"a string"))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
-        }
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
     }
-}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFile {
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
 
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFileComment {
-    pub comment: ::FileComment,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
 }
 
-
 #[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemMessage {
-    pub channel: String,
-    pub message: ::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
+pub struct ListResponse {
+    error: Option<String>,
+    pub items: Option<Vec<::Item>>,
+    #[serde(default)]
+    ok: bool,
+    pub paging: Option<::Paging>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
-
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
     fn into(self) -> Result<ListResponse, ListError<E>> {
         if self.ok {
@@ -683,6 +712,29 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::UserNotFound => "user_not_found",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("reactions.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reactions.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -730,26 +782,38 @@ pub fn remove<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("name", request.name)),
-                      request.file.map(|file| ("file", file)),
-                      request
-                          .file_comment
-                          .map(|file_comment| ("file_comment", file_comment)),
-                      request.channel.map(|channel| ("channel", channel)),
-                      request
-                          .timestamp
-                          .map(|timestamp| ("timestamp", timestamp))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("name".to_owned(), request.name.into());
+    if let Some(file) = request.file {
+        body.insert("file".to_owned(), file.into());
+    }
+    if let Some(file_comment) = request.file_comment {
+        body.insert("file_comment".to_owned(), file_comment.into());
+    }
+    if let Some(channel) = request.channel {
+        body.insert("channel".to_owned(), channel.into());
+    }
+    if let Some(timestamp) = request.timestamp {
+        body.insert("timestamp".to_owned(), timestamp.into());
+    }
     let url = ::get_slack_url_for_method("reactions.remove");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| RemoveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RemoveResponse>(&result)
-                            .map_err(|e| RemoveError::MalformedResponse(e))
+                      parse_remove_response(result.body.as_bytes()).map_err(|err| err.into_error(RemoveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_remove_response(body: &[u8]) -> Result<RemoveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RemoveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -766,11 +830,42 @@ pub struct RemoveRequest<'a> {
     pub timestamp: Option<&'a str>,
 }
 
+impl<'a> RemoveRequest<'a> {
+    pub fn new(name: &'a str) -> Self {
+        RemoveRequest {
+            name: name,
+            ..RemoveRequest::default()
+        }
+    }
+
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.file_comment = Some(file_comment);
+        self
+    }
+
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RemoveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -859,6 +954,35 @@ impl<'a, E: Error> From<&'a str> for RemoveError<E> {
     }
 }
 
+impl<E: Error> From<RemoveError<E>> for ::Error<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        let code = match err {
+            RemoveError::BadTimestamp => "bad_timestamp",
+            RemoveError::FileNotFound => "file_not_found",
+            RemoveError::FileCommentNotFound => "file_comment_not_found",
+            RemoveError::MessageNotFound => "message_not_found",
+            RemoveError::NoItemSpecified => "no_item_specified",
+            RemoveError::InvalidName => "invalid_name",
+            RemoveError::NoReaction => "no_reaction",
+            RemoveError::NotAuthed => "not_authed",
+            RemoveError::InvalidAuth => "invalid_auth",
+            RemoveError::AccountInactive => "account_inactive",
+            RemoveError::InvalidArgName => "invalid_arg_name",
+            RemoveError::InvalidArrayArg => "invalid_array_arg",
+            RemoveError::InvalidCharset => "invalid_charset",
+            RemoveError::InvalidFormData => "invalid_form_data",
+            RemoveError::InvalidPostType => "invalid_post_type",
+            RemoveError::MissingPostType => "missing_post_type",
+            RemoveError::TeamAddedToOrg => "team_added_to_org",
+            RemoveError::RequestTimeout => "request_timeout",
+            RemoveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RemoveError::Unknown(code) => return ::Error::from_code("reactions.remove", &code),
+            RemoveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reactions.remove", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -900,3 +1024,40 @@ impl<E: Error> Error for RemoveError<E> {
         }
     }
 }
+
+/// The `reactions.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::reactions`](../client/struct.SlackClient.html#method.reactions).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Adds a reaction to an item. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("reactions.add"), request)
+    }
+
+    /// Gets reactions for an item. See [`get`](fn.get.html).
+    pub fn get(&self, request: &GetRequest) -> Result<GetResponse, GetError<R::Error>> {
+        get(self.client, self.token.for_method("reactions.get"), request)
+    }
+
+    /// Lists reactions made by a user. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("reactions.list"), request)
+    }
+
+    /// Removes a reaction from an item. See [`remove`](fn.remove.html).
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<R::Error>> {
+        remove(self.client, self.token.for_method("reactions.remove"), request)
+    }
+}
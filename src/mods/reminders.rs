@@ -20,21 +20,31 @@ pub fn add<R>(client: &R,
               -> Result<AddResponse, AddError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let time = request.time.to_string();
-    let params = vec![Some(("token", token)),
-                      Some(("text", request.text)),
-                      Some(("time", &time[..])),
-                      request.user.map(|user| ("user", user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+
+    let mut body = ::serde_json::Map::new();
+    body.insert("text".to_owned(), request.text.into());
+    body.insert("time".to_owned(), request.time.into());
+    if let Some(user) = request.user {
+        body.insert("user".to_owned(), user.into());
+    }
     let url = ::get_slack_url_for_method("reminders.add");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| AddError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<AddResponse>(&result)
-                            .map_err(|e| AddError::MalformedResponse(e))
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -47,12 +57,29 @@ pub struct AddRequest<'a> {
     pub user: Option<&'a str>,
 }
 
+impl<'a> AddRequest<'a> {
+    pub fn new(text: &'a str, time: u32) -> Self {
+        AddRequest {
+            text: text,
+            time: time,
+            ..AddRequest::default()
+        }
+    }
+
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub reminder: Option<::Reminder>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -141,6 +168,35 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::CannotParse => "cannot_parse",
+            AddError::UserNotFound => "user_not_found",
+            AddError::CannotAddBot => "cannot_add_bot",
+            AddError::CannotAddSlackbot => "cannot_add_slackbot",
+            AddError::CannotAddOthers => "cannot_add_others",
+            AddError::CannotAddOthersRecurring => "cannot_add_others_recurring",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::UserIsBot => "user_is_bot",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("reminders.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reminders.add", code)
+    }
+}
+
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -194,17 +250,26 @@ pub fn complete<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("reminder".to_owned(), request.reminder.into());
     let url = ::get_slack_url_for_method("reminders.complete");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| CompleteError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<CompleteResponse>(&result)
-                            .map_err(|e| CompleteError::MalformedResponse(e))
+                      parse_complete_response(result.body.as_bytes()).map_err(|err| err.into_error(CompleteError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_complete_response(body: &[u8]) -> Result<CompleteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CompleteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -213,11 +278,22 @@ pub struct CompleteRequest<'a> {
     pub reminder: &'a str,
 }
 
+impl<'a> CompleteRequest<'a> {
+    pub fn new(reminder: &'a str) -> Self {
+        CompleteRequest {
+            reminder: reminder,
+            ..CompleteRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CompleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -297,6 +373,32 @@ impl<'a, E: Error> From<&'a str> for CompleteError<E> {
     }
 }
 
+impl<E: Error> From<CompleteError<E>> for ::Error<E> {
+    fn from(err: CompleteError<E>) -> Self {
+        let code = match err {
+            CompleteError::NotFound => "not_found",
+            CompleteError::CannotCompleteRecurring => "cannot_complete_recurring",
+            CompleteError::CannotCompleteOthers => "cannot_complete_others",
+            CompleteError::NotAuthed => "not_authed",
+            CompleteError::InvalidAuth => "invalid_auth",
+            CompleteError::AccountInactive => "account_inactive",
+            CompleteError::UserIsBot => "user_is_bot",
+            CompleteError::InvalidArgName => "invalid_arg_name",
+            CompleteError::InvalidArrayArg => "invalid_array_arg",
+            CompleteError::InvalidCharset => "invalid_charset",
+            CompleteError::InvalidFormData => "invalid_form_data",
+            CompleteError::InvalidPostType => "invalid_post_type",
+            CompleteError::MissingPostType => "missing_post_type",
+            CompleteError::TeamAddedToOrg => "team_added_to_org",
+            CompleteError::RequestTimeout => "request_timeout",
+            CompleteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CompleteError::Unknown(code) => return ::Error::from_code("reminders.complete", &code),
+            CompleteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reminders.complete", code)
+    }
+}
+
 impl<E: Error> fmt::Display for CompleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -347,17 +449,26 @@ pub fn delete<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("reminder".to_owned(), request.reminder.into());
     let url = ::get_slack_url_for_method("reminders.delete");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| DeleteError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<DeleteResponse>(&result)
-                            .map_err(|e| DeleteError::MalformedResponse(e))
+                      parse_delete_response(result.body.as_bytes()).map_err(|err| err.into_error(DeleteError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_delete_response(body: &[u8]) -> Result<DeleteResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<DeleteResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -366,11 +477,22 @@ pub struct DeleteRequest<'a> {
     pub reminder: &'a str,
 }
 
+impl<'a> DeleteRequest<'a> {
+    pub fn new(reminder: &'a str) -> Self {
+        DeleteRequest {
+            reminder: reminder,
+            ..DeleteRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -444,6 +566,30 @@ impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     }
 }
 
+impl<E: Error> From<DeleteError<E>> for ::Error<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        let code = match err {
+            DeleteError::NotFound => "not_found",
+            DeleteError::NotAuthed => "not_authed",
+            DeleteError::InvalidAuth => "invalid_auth",
+            DeleteError::AccountInactive => "account_inactive",
+            DeleteError::UserIsBot => "user_is_bot",
+            DeleteError::InvalidArgName => "invalid_arg_name",
+            DeleteError::InvalidArrayArg => "invalid_array_arg",
+            DeleteError::InvalidCharset => "invalid_charset",
+            DeleteError::InvalidFormData => "invalid_form_data",
+            DeleteError::InvalidPostType => "invalid_post_type",
+            DeleteError::MissingPostType => "missing_post_type",
+            DeleteError::TeamAddedToOrg => "team_added_to_org",
+            DeleteError::RequestTimeout => "request_timeout",
+            DeleteError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            DeleteError::Unknown(code) => return ::Error::from_code("reminders.delete", &code),
+            DeleteError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reminders.delete", code)
+    }
+}
+
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -492,17 +638,23 @@ pub fn info<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [("token", token), ("reminder", request.reminder)];
     let url = ::get_slack_url_for_method("reminders.info");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| InfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InfoResponse>(&result)
-                            .map_err(|e| InfoError::MalformedResponse(e))
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -511,12 +663,23 @@ pub struct InfoRequest<'a> {
     pub reminder: &'a str,
 }
 
+impl<'a> InfoRequest<'a> {
+    pub fn new(reminder: &'a str) -> Self {
+        InfoRequest {
+            reminder: reminder,
+            ..InfoRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InfoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub reminder: Option<::Reminder>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -590,6 +753,30 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::NotFound => "not_found",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::UserIsBot => "user_is_bot",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("reminders.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reminders.info", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -637,13 +824,20 @@ pub fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Err
     let params = &[("token", token)];
     let url = ::get_slack_url_for_method("reminders.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -652,6 +846,8 @@ pub struct ListResponse {
     #[serde(default)]
     ok: bool,
     pub reminders: Option<Vec<::Reminder>>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -722,6 +918,29 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::UserIsBot => "user_is_bot",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("reminders.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("reminders.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -757,3 +976,45 @@ impl<E: Error> Error for ListError<E> {
         }
     }
 }
+
+/// The `reminders.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::reminders`](../client/struct.SlackClient.html#method.reminders).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Creates a reminder. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("reminders.add"), request)
+    }
+
+    /// Marks a reminder as complete. See [`complete`](fn.complete.html).
+    pub fn complete(&self, request: &CompleteRequest) -> Result<CompleteResponse, CompleteError<R::Error>> {
+        complete(self.client, self.token.for_method("reminders.complete"), request)
+    }
+
+    /// Deletes a reminder. See [`delete`](fn.delete.html).
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<R::Error>> {
+        delete(self.client, self.token.for_method("reminders.delete"), request)
+    }
+
+    /// Gets information about a reminder. See [`info`](fn.info.html).
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<R::Error>> {
+        info(self.client, self.token.for_method("reminders.info"), request)
+    }
+
+    /// Lists all reminders created by or for a given user. See [`list`](fn.list.html).
+    pub fn list(&self) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("reminders.list"))
+    }
+}
@@ -20,13 +20,20 @@ pub fn connect<R>(client: &R, token: &str) -> Result<ConnectResponse, ConnectErr
     let params = &[("token", token)];
     let url = ::get_slack_url_for_method("rtm.connect");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ConnectError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ConnectResponse>(&result)
-                            .map_err(|e| ConnectError::MalformedResponse(e))
+                      parse_connect_response(&result).map_err(|err| err.into_error(ConnectError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_connect_response(body: &[u8]) -> Result<ConnectResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ConnectResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -34,10 +41,12 @@ pub struct ConnectResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     #[serde(rename = "self")]
     pub slf: Option<ConnectResponseSelf>,
     pub team: Option<ConnectResponseTeam>,
     pub url: Option<String>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -121,6 +130,28 @@ impl<'a, E: Error> From<&'a str> for ConnectError<E> {
     }
 }
 
+impl<E: Error> From<ConnectError<E>> for ::Error<E> {
+    fn from(err: ConnectError<E>) -> Self {
+        let code = match err {
+            ConnectError::NotAuthed => "not_authed",
+            ConnectError::InvalidAuth => "invalid_auth",
+            ConnectError::AccountInactive => "account_inactive",
+            ConnectError::InvalidArgName => "invalid_arg_name",
+            ConnectError::InvalidArrayArg => "invalid_array_arg",
+            ConnectError::InvalidCharset => "invalid_charset",
+            ConnectError::InvalidFormData => "invalid_form_data",
+            ConnectError::InvalidPostType => "invalid_post_type",
+            ConnectError::MissingPostType => "missing_post_type",
+            ConnectError::TeamAddedToOrg => "team_added_to_org",
+            ConnectError::RequestTimeout => "request_timeout",
+            ConnectError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ConnectError::Unknown(code) => return ::Error::from_code("rtm.connect", &code),
+            ConnectError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("rtm.connect", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ConnectError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -167,27 +198,35 @@ pub fn start<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params =
-        vec![Some(("token", token)),
-             request
-                 .no_unreads
-                 .map(|no_unreads| ("no_unreads", if no_unreads { "1" } else { "0" })),
-             request
-                 .mpim_aware
-                 .map(|mpim_aware| ("mpim_aware", if mpim_aware { "1" } else { "0" })),
-             request
-                 .no_latest
-                 .map(|no_latest| ("no_latest", if no_latest { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    if let Some(no_unreads) = request.no_unreads {
+        params.push(("no_unreads", if no_unreads { "1" } else { "0" }));
+    }
+    if let Some(mpim_aware) = request.mpim_aware {
+        params.push(("mpim_aware", if mpim_aware { "1" } else { "0" }));
+    }
+    if let Some(no_latest) = request.no_latest {
+        params.push(("no_latest", if no_latest { "1" } else { "0" }));
+    }
     let url = ::get_slack_url_for_method("rtm.start");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| StartError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<StartResponse>(&result)
-                            .map_err(|e| StartError::MalformedResponse(e))
+                      parse_start_response(&result).map_err(|err| err.into_error(StartError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_start_response(body: &[u8]) -> Result<StartResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<StartResponse>(body, &[("channels", ::item_error::<::Channel>), ("groups", ::item_error::<::Group>), ("ims", ::item_error::<::Im>), ("mpims", ::item_error::<::Mpim>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -200,6 +239,27 @@ pub struct StartRequest {
     pub no_latest: Option<bool>,
 }
 
+impl StartRequest {
+    pub fn new() -> Self {
+        StartRequest::default()
+    }
+
+    pub fn no_unreads(mut self, no_unreads: bool) -> Self {
+        self.no_unreads = Some(no_unreads);
+        self
+    }
+
+    pub fn mpim_aware(mut self, mpim_aware: bool) -> Self {
+        self.mpim_aware = Some(mpim_aware);
+        self
+    }
+
+    pub fn no_latest(mut self, no_latest: bool) -> Self {
+        self.no_latest = Some(no_latest);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct StartResponse {
     pub bots: Option<Vec<::Bot>>,
@@ -210,11 +270,15 @@ pub struct StartResponse {
     pub mpims: Option<Vec<::Mpim>>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     #[serde(rename = "self")]
     pub slf: Option<::User>,
     pub team: Option<::Team>,
     pub url: Option<String>,
     pub users: Option<Vec<::User>>,
+    pub warning: Option<String>,
 }
 
 
@@ -285,6 +349,29 @@ impl<'a, E: Error> From<&'a str> for StartError<E> {
     }
 }
 
+impl<E: Error> From<StartError<E>> for ::Error<E> {
+    fn from(err: StartError<E>) -> Self {
+        let code = match err {
+            StartError::MigrationInProgress => "migration_in_progress",
+            StartError::NotAuthed => "not_authed",
+            StartError::InvalidAuth => "invalid_auth",
+            StartError::AccountInactive => "account_inactive",
+            StartError::InvalidArgName => "invalid_arg_name",
+            StartError::InvalidArrayArg => "invalid_array_arg",
+            StartError::InvalidCharset => "invalid_charset",
+            StartError::InvalidFormData => "invalid_form_data",
+            StartError::InvalidPostType => "invalid_post_type",
+            StartError::MissingPostType => "missing_post_type",
+            StartError::TeamAddedToOrg => "team_added_to_org",
+            StartError::RequestTimeout => "request_timeout",
+            StartError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            StartError::Unknown(code) => return ::Error::from_code("rtm.start", &code),
+            StartError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("rtm.start", code)
+    }
+}
+
 impl<E: Error> fmt::Display for StartError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -320,3 +407,30 @@ impl<E: Error> Error for StartError<E> {
         }
     }
 }
+
+/// The `rtm.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::rtm`](../client/struct.SlackClient.html#method.rtm).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Starts a Real Time Messaging session. See [`connect`](fn.connect.html).
+    pub fn connect(&self) -> Result<ConnectResponse, ConnectError<R::Error>> {
+        connect(self.client, self.token.for_method("rtm.connect"))
+    }
+
+    /// Starts a Real Time Messaging session. See [`start`](fn.start.html).
+    pub fn start(&self, request: &StartRequest) -> Result<StartResponse, StartError<R::Error>> {
+        start(self.client, self.token.for_method("rtm.start"), request)
+    }
+}
@@ -21,27 +21,44 @@ pub fn all<R>(client: &R,
               -> Result<AllResponse, AllError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let params = vec![Some(("token", token)),
-                      Some(("query", request.query)),
-                      request.sort.map(|sort| ("sort", sort)),
-                      request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
-                      request
-                          .highlight
-                          .map(|highlight| ("highlight", if highlight { "1" } else { "0" })),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let mut params = Vec::with_capacity(7);
+    params.push(("token", token));
+    params.push(("query", request.query));
+    if let Some(sort) = request.sort {
+        params.push(("sort", sort));
+    }
+    if let Some(sort_dir) = request.sort_dir {
+        params.push(("sort_dir", sort_dir));
+    }
+    if let Some(highlight) = request.highlight {
+        params.push(("highlight", if highlight { "1" } else { "0" }));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
     let url = ::get_slack_url_for_method("search.all");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| AllError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<AllResponse>(&result)
-                            .map_err(|e| AllError::MalformedResponse(e))
+                      parse_all_response(&result).map_err(|err| err.into_error(AllError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_all_response(body: &[u8]) -> Result<AllResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<AllResponse>(body, &[("messages.matches", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -60,6 +77,40 @@ pub struct AllRequest<'a> {
     pub page: Option<u32>,
 }
 
+impl<'a> AllRequest<'a> {
+    pub fn new(query: &'a str) -> Self {
+        AllRequest {
+            query: query,
+            ..AllRequest::default()
+        }
+    }
+
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: &'a str) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AllResponse {
     error: Option<String>,
@@ -67,7 +118,11 @@ pub struct AllResponse {
     pub messages: Option<AllResponseMessages>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
     pub query: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -151,6 +206,29 @@ impl<'a, E: Error> From<&'a str> for AllError<E> {
     }
 }
 
+impl<E: Error> From<AllError<E>> for ::Error<E> {
+    fn from(err: AllError<E>) -> Self {
+        let code = match err {
+            AllError::NotAuthed => "not_authed",
+            AllError::InvalidAuth => "invalid_auth",
+            AllError::AccountInactive => "account_inactive",
+            AllError::UserIsBot => "user_is_bot",
+            AllError::InvalidArgName => "invalid_arg_name",
+            AllError::InvalidArrayArg => "invalid_array_arg",
+            AllError::InvalidCharset => "invalid_charset",
+            AllError::InvalidFormData => "invalid_form_data",
+            AllError::InvalidPostType => "invalid_post_type",
+            AllError::MissingPostType => "missing_post_type",
+            AllError::TeamAddedToOrg => "team_added_to_org",
+            AllError::RequestTimeout => "request_timeout",
+            AllError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AllError::Unknown(code) => return ::Error::from_code("search.all", &code),
+            AllError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("search.all", code)
+    }
+}
+
 impl<E: Error> fmt::Display for AllError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -197,27 +275,42 @@ pub fn files<R>(client: &R,
                 -> Result<FilesResponse, FilesError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let params = vec![Some(("token", token)),
-                      Some(("query", request.query)),
-                      request.sort.map(|sort| ("sort", sort)),
-                      request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
-                      request
-                          .highlight
-                          .map(|highlight| ("highlight", if highlight { "1" } else { "0" })),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let mut params = Vec::with_capacity(7);
+    params.push(("token", token));
+    params.push(("query", request.query));
+    if let Some(sort) = request.sort {
+        params.push(("sort", sort));
+    }
+    if let Some(sort_dir) = request.sort_dir {
+        params.push(("sort_dir", sort_dir));
+    }
+    if let Some(highlight) = request.highlight {
+        params.push(("highlight", if highlight { "1" } else { "0" }));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
     let url = ::get_slack_url_for_method("search.files");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| FilesError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<FilesResponse>(&result)
-                            .map_err(|e| FilesError::MalformedResponse(e))
+                      parse_files_response(&result).map_err(|err| err.into_error(FilesError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_files_response(body: &[u8]) -> Result<FilesResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<FilesResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -236,6 +329,40 @@ pub struct FilesRequest<'a> {
     pub page: Option<u32>,
 }
 
+impl<'a> FilesRequest<'a> {
+    pub fn new(query: &'a str) -> Self {
+        FilesRequest {
+            query: query,
+            ..FilesRequest::default()
+        }
+    }
+
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: &'a str) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct FilesResponse {
     error: Option<String>,
@@ -243,6 +370,8 @@ pub struct FilesResponse {
     #[serde(default)]
     ok: bool,
     pub query: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -320,6 +449,29 @@ impl<'a, E: Error> From<&'a str> for FilesError<E> {
     }
 }
 
+impl<E: Error> From<FilesError<E>> for ::Error<E> {
+    fn from(err: FilesError<E>) -> Self {
+        let code = match err {
+            FilesError::NotAuthed => "not_authed",
+            FilesError::InvalidAuth => "invalid_auth",
+            FilesError::AccountInactive => "account_inactive",
+            FilesError::UserIsBot => "user_is_bot",
+            FilesError::InvalidArgName => "invalid_arg_name",
+            FilesError::InvalidArrayArg => "invalid_array_arg",
+            FilesError::InvalidCharset => "invalid_charset",
+            FilesError::InvalidFormData => "invalid_form_data",
+            FilesError::InvalidPostType => "invalid_post_type",
+            FilesError::MissingPostType => "missing_post_type",
+            FilesError::TeamAddedToOrg => "team_added_to_org",
+            FilesError::RequestTimeout => "request_timeout",
+            FilesError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            FilesError::Unknown(code) => return ::Error::from_code("search.files", &code),
+            FilesError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("search.files", code)
+    }
+}
+
 impl<E: Error> fmt::Display for FilesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -366,27 +518,44 @@ pub fn messages<R>(client: &R,
                    -> Result<MessagesResponse, MessagesError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let params = vec![Some(("token", token)),
-                      Some(("query", request.query)),
-                      request.sort.map(|sort| ("sort", sort)),
-                      request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
-                      request
-                          .highlight
-                          .map(|highlight| ("highlight", if highlight { "1" } else { "0" })),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let mut params = Vec::with_capacity(7);
+    params.push(("token", token));
+    params.push(("query", request.query));
+    if let Some(sort) = request.sort {
+        params.push(("sort", sort));
+    }
+    if let Some(sort_dir) = request.sort_dir {
+        params.push(("sort_dir", sort_dir));
+    }
+    if let Some(highlight) = request.highlight {
+        params.push(("highlight", if highlight { "1" } else { "0" }));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
     let url = ::get_slack_url_for_method("search.messages");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| MessagesError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<MessagesResponse>(&result)
-                            .map_err(|e| MessagesError::MalformedResponse(e))
+                      parse_messages_response(&result).map_err(|err| err.into_error(MessagesError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_messages_response(body: &[u8]) -> Result<MessagesResponse, ::ResponseError> {
+    let (mut response, parse_warnings) = ::from_slice_skipping_invalid::<MessagesResponse>(body, &[("messages.matches", ::item_error::<::Message>)])
+        .map_err(::ResponseError::Malformed)?;
+    response.parse_warnings = parse_warnings;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -405,13 +574,51 @@ pub struct MessagesRequest<'a> {
     pub page: Option<u32>,
 }
 
+impl<'a> MessagesRequest<'a> {
+    pub fn new(query: &'a str) -> Self {
+        MessagesRequest {
+            query: query,
+            ..MessagesRequest::default()
+        }
+    }
+
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: &'a str) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessagesResponse {
     error: Option<String>,
     pub messages: Option<MessagesResponseMessages>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_deserializing)]
+    pub parse_warnings: Vec<::requests::ParseWarning>,
     pub query: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -489,6 +696,29 @@ impl<'a, E: Error> From<&'a str> for MessagesError<E> {
     }
 }
 
+impl<E: Error> From<MessagesError<E>> for ::Error<E> {
+    fn from(err: MessagesError<E>) -> Self {
+        let code = match err {
+            MessagesError::NotAuthed => "not_authed",
+            MessagesError::InvalidAuth => "invalid_auth",
+            MessagesError::AccountInactive => "account_inactive",
+            MessagesError::UserIsBot => "user_is_bot",
+            MessagesError::InvalidArgName => "invalid_arg_name",
+            MessagesError::InvalidArrayArg => "invalid_array_arg",
+            MessagesError::InvalidCharset => "invalid_charset",
+            MessagesError::InvalidFormData => "invalid_form_data",
+            MessagesError::InvalidPostType => "invalid_post_type",
+            MessagesError::MissingPostType => "missing_post_type",
+            MessagesError::TeamAddedToOrg => "team_added_to_org",
+            MessagesError::RequestTimeout => "request_timeout",
+            MessagesError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            MessagesError::Unknown(code) => return ::Error::from_code("search.messages", &code),
+            MessagesError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("search.messages", code)
+    }
+}
+
 impl<E: Error> fmt::Display for MessagesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -524,3 +754,35 @@ impl<E: Error> Error for MessagesError<E> {
         }
     }
 }
+
+/// The `search.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::search`](../client/struct.SlackClient.html#method.search).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Searches for messages and files matching a query. See [`all`](fn.all.html).
+    pub fn all(&self, request: &AllRequest) -> Result<AllResponse, AllError<R::Error>> {
+        all(self.client, self.token.for_method("search.all"), request)
+    }
+
+    /// Searches for files matching a query. See [`files`](fn.files.html).
+    pub fn files(&self, request: &FilesRequest) -> Result<FilesResponse, FilesError<R::Error>> {
+        files(self.client, self.token.for_method("search.files"), request)
+    }
+
+    /// Searches for messages matching a query. See [`messages`](fn.messages.html).
+    pub fn messages(&self, request: &MessagesRequest) -> Result<MessagesResponse, MessagesError<R::Error>> {
+        messages(self.client, self.token.for_method("search.messages"), request)
+    }
+}
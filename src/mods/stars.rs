@@ -21,25 +21,37 @@ pub fn add<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request.file.map(|file| ("file", file)),
-                      request
-                          .file_comment
-                          .map(|file_comment| ("file_comment", file_comment)),
-                      request.channel.map(|channel| ("channel", channel)),
-                      request
-                          .timestamp
-                          .map(|timestamp| ("timestamp", timestamp))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    if let Some(file) = request.file {
+        body.insert("file".to_owned(), file.into());
+    }
+    if let Some(file_comment) = request.file_comment {
+        body.insert("file_comment".to_owned(), file_comment.into());
+    }
+    if let Some(channel) = request.channel {
+        body.insert("channel".to_owned(), channel.into());
+    }
+    if let Some(timestamp) = request.timestamp {
+        body.insert("timestamp".to_owned(), timestamp.into());
+    }
     let url = ::get_slack_url_for_method("stars.add");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| AddError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<AddResponse>(&result)
-                            .map_err(|e| AddError::MalformedResponse(e))
+                      parse_add_response(result.body.as_bytes()).map_err(|err| err.into_error(AddError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_add_response(body: &[u8]) -> Result<AddResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AddResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -54,11 +66,39 @@ pub struct AddRequest<'a> {
     pub timestamp: Option<&'a str>,
 }
 
+impl<'a> AddRequest<'a> {
+    pub fn new() -> Self {
+        AddRequest::default()
+    }
+
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.file_comment = Some(file_comment);
+        self
+    }
+
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -147,6 +187,35 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for ::Error<E> {
+    fn from(err: AddError<E>) -> Self {
+        let code = match err {
+            AddError::BadTimestamp => "bad_timestamp",
+            AddError::MessageNotFound => "message_not_found",
+            AddError::FileNotFound => "file_not_found",
+            AddError::FileCommentNotFound => "file_comment_not_found",
+            AddError::ChannelNotFound => "channel_not_found",
+            AddError::NoItemSpecified => "no_item_specified",
+            AddError::AlreadyStarred => "already_starred",
+            AddError::NotAuthed => "not_authed",
+            AddError::InvalidAuth => "invalid_auth",
+            AddError::AccountInactive => "account_inactive",
+            AddError::InvalidArgName => "invalid_arg_name",
+            AddError::InvalidArrayArg => "invalid_array_arg",
+            AddError::InvalidCharset => "invalid_charset",
+            AddError::InvalidFormData => "invalid_form_data",
+            AddError::InvalidPostType => "invalid_post_type",
+            AddError::MissingPostType => "missing_post_type",
+            AddError::TeamAddedToOrg => "team_added_to_org",
+            AddError::RequestTimeout => "request_timeout",
+            AddError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AddError::Unknown(code) => return ::Error::from_code("stars.add", &code),
+            AddError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("stars.add", code)
+    }
+}
+
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -199,21 +268,32 @@ pub fn list<R>(client: &R,
                -> Result<ListResponse, ListError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let params = vec![Some(("token", token)),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
     let url = ::get_slack_url_for_method("stars.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -224,6 +304,22 @@ pub struct ListRequest {
     pub page: Option<u32>,
 }
 
+impl ListRequest {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
@@ -231,6 +327,8 @@ pub struct ListResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -420,6 +518,31 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::UserNotFound => "user_not_found",
+            ListError::UserNotVisible => "user_not_visible",
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::UserIsBot => "user_is_bot",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("stars.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("stars.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -469,25 +592,37 @@ pub fn remove<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request.file.map(|file| ("file", file)),
-                      request
-                          .file_comment
-                          .map(|file_comment| ("file_comment", file_comment)),
-                      request.channel.map(|channel| ("channel", channel)),
-                      request
-                          .timestamp
-                          .map(|timestamp| ("timestamp", timestamp))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    if let Some(file) = request.file {
+        body.insert("file".to_owned(), file.into());
+    }
+    if let Some(file_comment) = request.file_comment {
+        body.insert("file_comment".to_owned(), file_comment.into());
+    }
+    if let Some(channel) = request.channel {
+        body.insert("channel".to_owned(), channel.into());
+    }
+    if let Some(timestamp) = request.timestamp {
+        body.insert("timestamp".to_owned(), timestamp.into());
+    }
     let url = ::get_slack_url_for_method("stars.remove");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| RemoveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<RemoveResponse>(&result)
-                            .map_err(|e| RemoveError::MalformedResponse(e))
+                      parse_remove_response(result.body.as_bytes()).map_err(|err| err.into_error(RemoveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_remove_response(body: &[u8]) -> Result<RemoveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<RemoveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -502,11 +637,39 @@ pub struct RemoveRequest<'a> {
     pub timestamp: Option<&'a str>,
 }
 
+impl<'a> RemoveRequest<'a> {
+    pub fn new() -> Self {
+        RemoveRequest::default()
+    }
+
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.file_comment = Some(file_comment);
+        self
+    }
+
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RemoveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -595,6 +758,35 @@ impl<'a, E: Error> From<&'a str> for RemoveError<E> {
     }
 }
 
+impl<E: Error> From<RemoveError<E>> for ::Error<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        let code = match err {
+            RemoveError::BadTimestamp => "bad_timestamp",
+            RemoveError::MessageNotFound => "message_not_found",
+            RemoveError::FileNotFound => "file_not_found",
+            RemoveError::FileCommentNotFound => "file_comment_not_found",
+            RemoveError::ChannelNotFound => "channel_not_found",
+            RemoveError::NoItemSpecified => "no_item_specified",
+            RemoveError::NotStarred => "not_starred",
+            RemoveError::NotAuthed => "not_authed",
+            RemoveError::InvalidAuth => "invalid_auth",
+            RemoveError::AccountInactive => "account_inactive",
+            RemoveError::InvalidArgName => "invalid_arg_name",
+            RemoveError::InvalidArrayArg => "invalid_array_arg",
+            RemoveError::InvalidCharset => "invalid_charset",
+            RemoveError::InvalidFormData => "invalid_form_data",
+            RemoveError::InvalidPostType => "invalid_post_type",
+            RemoveError::MissingPostType => "missing_post_type",
+            RemoveError::TeamAddedToOrg => "team_added_to_org",
+            RemoveError::RequestTimeout => "request_timeout",
+            RemoveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            RemoveError::Unknown(code) => return ::Error::from_code("stars.remove", &code),
+            RemoveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("stars.remove", code)
+    }
+}
+
 impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -636,3 +828,35 @@ impl<E: Error> Error for RemoveError<E> {
         }
     }
 }
+
+/// The `stars.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::stars`](../client/struct.SlackClient.html#method.stars).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Adds a star to an item. See [`add`](fn.add.html).
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<R::Error>> {
+        add(self.client, self.token.for_method("stars.add"), request)
+    }
+
+    /// Lists stars for a user. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("stars.list"), request)
+    }
+
+    /// Removes a star from an item. See [`remove`](fn.remove.html).
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<R::Error>> {
+        remove(self.client, self.token.for_method("stars.remove"), request)
+    }
+}
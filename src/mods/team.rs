@@ -20,23 +20,36 @@ pub fn access_logs<R>(client: &R,
                       -> Result<AccessLogsResponse, AccessLogsError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let before = request.before.map(|before| before.to_string());
-    let params = vec![Some(("token", token)),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..])),
-                      before.as_ref().map(|before| ("before", &before[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let before = request.before.map(::IntParam::new);
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
+    if let Some(ref before) = before {
+        params.push(("before", before.as_str()));
+    }
     let url = ::get_slack_url_for_method("team.accessLogs");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| AccessLogsError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<AccessLogsResponse>(&result)
-                            .map_err(|e| AccessLogsError::MalformedResponse(e))
+                      parse_access_logs_response(&result).map_err(|err| err.into_error(AccessLogsError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_access_logs_response(body: &[u8]) -> Result<AccessLogsResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<AccessLogsResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -49,6 +62,27 @@ pub struct AccessLogsRequest {
     pub before: Option<u32>,
 }
 
+impl AccessLogsRequest {
+    pub fn new() -> Self {
+        AccessLogsRequest::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn before(mut self, before: u32) -> Self {
+        self.before = Some(before);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AccessLogsResponse {
     error: Option<String>,
@@ -56,6 +90,8 @@ pub struct AccessLogsResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -146,6 +182,31 @@ impl<'a, E: Error> From<&'a str> for AccessLogsError<E> {
     }
 }
 
+impl<E: Error> From<AccessLogsError<E>> for ::Error<E> {
+    fn from(err: AccessLogsError<E>) -> Self {
+        let code = match err {
+            AccessLogsError::PaidOnly => "paid_only",
+            AccessLogsError::OverPaginationLimit => "over_pagination_limit",
+            AccessLogsError::NotAuthed => "not_authed",
+            AccessLogsError::InvalidAuth => "invalid_auth",
+            AccessLogsError::AccountInactive => "account_inactive",
+            AccessLogsError::UserIsBot => "user_is_bot",
+            AccessLogsError::InvalidArgName => "invalid_arg_name",
+            AccessLogsError::InvalidArrayArg => "invalid_array_arg",
+            AccessLogsError::InvalidCharset => "invalid_charset",
+            AccessLogsError::InvalidFormData => "invalid_form_data",
+            AccessLogsError::InvalidPostType => "invalid_post_type",
+            AccessLogsError::MissingPostType => "missing_post_type",
+            AccessLogsError::TeamAddedToOrg => "team_added_to_org",
+            AccessLogsError::RequestTimeout => "request_timeout",
+            AccessLogsError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            AccessLogsError::Unknown(code) => return ::Error::from_code("team.accessLogs", &code),
+            AccessLogsError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("team.accessLogs", code)
+    }
+}
+
 impl<E: Error> fmt::Display for AccessLogsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -195,18 +256,27 @@ pub fn billable_info<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request.user.map(|user| ("user", user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(2);
+    params.push(("token", token));
+    if let Some(user) = request.user {
+        params.push(("user", user));
+    }
     let url = ::get_slack_url_for_method("team.billableInfo");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| BillableInfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<BillableInfoResponse>(&result)
-                            .map_err(|e| BillableInfoError::MalformedResponse(e))
+                      parse_billable_info_response(&result).map_err(|err| err.into_error(BillableInfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_billable_info_response(body: &[u8]) -> Result<BillableInfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<BillableInfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -215,12 +285,25 @@ pub struct BillableInfoRequest<'a> {
     pub user: Option<&'a str>,
 }
 
+impl<'a> BillableInfoRequest<'a> {
+    pub fn new() -> Self {
+        BillableInfoRequest::default()
+    }
+
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct BillableInfoResponse {
     pub billable_info: Option<HashMap<String, bool>>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -294,6 +377,30 @@ impl<'a, E: Error> From<&'a str> for BillableInfoError<E> {
     }
 }
 
+impl<E: Error> From<BillableInfoError<E>> for ::Error<E> {
+    fn from(err: BillableInfoError<E>) -> Self {
+        let code = match err {
+            BillableInfoError::UserNotFound => "user_not_found",
+            BillableInfoError::NotAuthed => "not_authed",
+            BillableInfoError::InvalidAuth => "invalid_auth",
+            BillableInfoError::AccountInactive => "account_inactive",
+            BillableInfoError::UserIsBot => "user_is_bot",
+            BillableInfoError::InvalidArgName => "invalid_arg_name",
+            BillableInfoError::InvalidArrayArg => "invalid_array_arg",
+            BillableInfoError::InvalidCharset => "invalid_charset",
+            BillableInfoError::InvalidFormData => "invalid_form_data",
+            BillableInfoError::InvalidPostType => "invalid_post_type",
+            BillableInfoError::MissingPostType => "missing_post_type",
+            BillableInfoError::TeamAddedToOrg => "team_added_to_org",
+            BillableInfoError::RequestTimeout => "request_timeout",
+            BillableInfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            BillableInfoError::Unknown(code) => return ::Error::from_code("team.billableInfo", &code),
+            BillableInfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("team.billableInfo", code)
+    }
+}
+
 impl<E: Error> fmt::Display for BillableInfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -341,13 +448,20 @@ pub fn info<R>(client: &R, token: &str) -> Result<InfoResponse, InfoError<R::Err
     let params = &[("token", token)];
     let url = ::get_slack_url_for_method("team.info");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| InfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InfoResponse>(&result)
-                            .map_err(|e| InfoError::MalformedResponse(e))
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -355,7 +469,9 @@ pub struct InfoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub team: Option<::Team>,
+    pub warning: Option<String>,
 }
 
 
@@ -423,6 +539,28 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("team.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("team.info", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -468,29 +606,44 @@ pub fn integration_logs<R>(client: &R,
                            -> Result<IntegrationLogsResponse, IntegrationLogsError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let count = request.count.map(|count| count.to_string());
-    let page = request.page.map(|page| page.to_string());
-    let params = vec![Some(("token", token)),
-                      request
-                          .service_id
-                          .map(|service_id| ("service_id", service_id)),
-                      request.app_id.map(|app_id| ("app_id", app_id)),
-                      request.user.map(|user| ("user", user)),
-                      request
-                          .change_type
-                          .map(|change_type| ("change_type", change_type)),
-                      count.as_ref().map(|count| ("count", &count[..])),
-                      page.as_ref().map(|page| ("page", &page[..]))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let count = request.count.map(::IntParam::new);
+    let page = request.page.map(::IntParam::new);
+    let mut params = Vec::with_capacity(7);
+    params.push(("token", token));
+    if let Some(service_id) = request.service_id {
+        params.push(("service_id", service_id));
+    }
+    if let Some(app_id) = request.app_id {
+        params.push(("app_id", app_id));
+    }
+    if let Some(user) = request.user {
+        params.push(("user", user));
+    }
+    if let Some(change_type) = request.change_type {
+        params.push(("change_type", change_type));
+    }
+    if let Some(ref count) = count {
+        params.push(("count", count.as_str()));
+    }
+    if let Some(ref page) = page {
+        params.push(("page", page.as_str()));
+    }
     let url = ::get_slack_url_for_method("team.integrationLogs");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| IntegrationLogsError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<IntegrationLogsResponse>(&result)
-                            .map_err(|e| IntegrationLogsError::MalformedResponse(e))
+                      parse_integration_logs_response(&result).map_err(|err| err.into_error(IntegrationLogsError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_integration_logs_response(body: &[u8]) -> Result<IntegrationLogsResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<IntegrationLogsResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -509,6 +662,42 @@ pub struct IntegrationLogsRequest<'a> {
     pub page: Option<u32>,
 }
 
+impl<'a> IntegrationLogsRequest<'a> {
+    pub fn new() -> Self {
+        IntegrationLogsRequest::default()
+    }
+
+    pub fn service_id(mut self, service_id: &'a str) -> Self {
+        self.service_id = Some(service_id);
+        self
+    }
+
+    pub fn app_id(mut self, app_id: &'a str) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    pub fn change_type(mut self, change_type: &'a str) -> Self {
+        self.change_type = Some(change_type);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct IntegrationLogsResponse {
     error: Option<String>,
@@ -516,6 +705,8 @@ pub struct IntegrationLogsResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -602,6 +793,29 @@ impl<'a, E: Error> From<&'a str> for IntegrationLogsError<E> {
     }
 }
 
+impl<E: Error> From<IntegrationLogsError<E>> for ::Error<E> {
+    fn from(err: IntegrationLogsError<E>) -> Self {
+        let code = match err {
+            IntegrationLogsError::NotAuthed => "not_authed",
+            IntegrationLogsError::InvalidAuth => "invalid_auth",
+            IntegrationLogsError::AccountInactive => "account_inactive",
+            IntegrationLogsError::UserIsBot => "user_is_bot",
+            IntegrationLogsError::InvalidArgName => "invalid_arg_name",
+            IntegrationLogsError::InvalidArrayArg => "invalid_array_arg",
+            IntegrationLogsError::InvalidCharset => "invalid_charset",
+            IntegrationLogsError::InvalidFormData => "invalid_form_data",
+            IntegrationLogsError::InvalidPostType => "invalid_post_type",
+            IntegrationLogsError::MissingPostType => "missing_post_type",
+            IntegrationLogsError::TeamAddedToOrg => "team_added_to_org",
+            IntegrationLogsError::RequestTimeout => "request_timeout",
+            IntegrationLogsError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            IntegrationLogsError::Unknown(code) => return ::Error::from_code("team.integrationLogs", &code),
+            IntegrationLogsError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("team.integrationLogs", code)
+    }
+}
+
 impl<E: Error> fmt::Display for IntegrationLogsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -637,3 +851,44 @@ impl<E: Error> Error for IntegrationLogsError<E> {
         }
     }
 }
+
+/// The `team.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::team`](../client/struct.SlackClient.html#method.team).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Gets the access logs for the current team. See [`access_logs`](fn.access_logs.html).
+    pub fn access_logs(&self, request: &AccessLogsRequest) -> Result<AccessLogsResponse, AccessLogsError<R::Error>> {
+        access_logs(self.client, self.token.for_method("team.accessLogs"), request)
+    }
+
+    /// Gets billable users information for the current team. See
+    /// [`billable_info`](fn.billable_info.html).
+    pub fn billable_info(&self, request: &BillableInfoRequest)
+                         -> Result<BillableInfoResponse, BillableInfoError<R::Error>> {
+        billable_info(self.client, self.token.for_method("team.billableInfo"), request)
+    }
+
+    /// Gets information about the current team. See [`info`](fn.info.html).
+    pub fn info(&self) -> Result<InfoResponse, InfoError<R::Error>> {
+        info(self.client, self.token.for_method("team.info"))
+    }
+
+    /// Gets the integration logs for the current team. See
+    /// [`integration_logs`](fn.integration_logs.html).
+    pub fn integration_logs(&self, request: &IntegrationLogsRequest)
+                            -> Result<IntegrationLogsResponse, IntegrationLogsError<R::Error>> {
+        integration_logs(self.client, self.token.for_method("team.integrationLogs"), request)
+    }
+}
@@ -21,20 +21,27 @@ pub fn get<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request
-                          .visibility
-                          .map(|visibility| ("visibility", visibility))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(2);
+    params.push(("token", token));
+    if let Some(visibility) = request.visibility {
+        params.push(("visibility", visibility));
+    }
     let url = ::get_slack_url_for_method("team.profile.get");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| GetError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<GetResponse>(&result)
-                            .map_err(|e| GetError::MalformedResponse(e))
+                      parse_get_response(&result).map_err(|err| err.into_error(GetError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_get_response(body: &[u8]) -> Result<GetResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<GetResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -43,12 +50,25 @@ pub struct GetRequest<'a> {
     pub visibility: Option<&'a str>,
 }
 
+impl<'a> GetRequest<'a> {
+    pub fn new() -> Self {
+        GetRequest::default()
+    }
+
+    pub fn visibility(mut self, visibility: &'a str) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct GetResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub profile: Option<GetResponseProfile>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -137,6 +157,29 @@ impl<'a, E: Error> From<&'a str> for GetError<E> {
     }
 }
 
+impl<E: Error> From<GetError<E>> for ::Error<E> {
+    fn from(err: GetError<E>) -> Self {
+        let code = match err {
+            GetError::NotAuthed => "not_authed",
+            GetError::InvalidAuth => "invalid_auth",
+            GetError::AccountInactive => "account_inactive",
+            GetError::UserIsBot => "user_is_bot",
+            GetError::InvalidArgName => "invalid_arg_name",
+            GetError::InvalidArrayArg => "invalid_array_arg",
+            GetError::InvalidCharset => "invalid_charset",
+            GetError::InvalidFormData => "invalid_form_data",
+            GetError::InvalidPostType => "invalid_post_type",
+            GetError::MissingPostType => "missing_post_type",
+            GetError::TeamAddedToOrg => "team_added_to_org",
+            GetError::RequestTimeout => "request_timeout",
+            GetError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            GetError::Unknown(code) => return ::Error::from_code("team.profile.get", &code),
+            GetError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("team.profile.get", code)
+    }
+}
+
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -172,3 +215,25 @@ impl<E: Error> Error for GetError<E> {
         }
     }
 }
+
+/// The `team.profile.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::team_profile`](../client/struct.SlackClient.html#method.team_profile).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Retrieve a team's profile. See [`get`](fn.get.html).
+    pub fn get(&self, request: &GetRequest) -> Result<GetResponse, GetError<R::Error>> {
+        get(self.client, self.token.for_method("team.profile.get"), request)
+    }
+}
@@ -22,28 +22,38 @@ pub fn create<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("name", request.name)),
-                      request.handle.map(|handle| ("handle", handle)),
-                      request
-                          .description
-                          .map(|description| ("description", description)),
-                      request.channels.map(|channels| ("channels", channels)),
-                      request
-                          .include_count
-                          .map(|include_count| {
-                                   ("include_count", if include_count { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("name".to_owned(), request.name.into());
+    if let Some(handle) = request.handle {
+        body.insert("handle".to_owned(), handle.into());
+    }
+    if let Some(description) = request.description {
+        body.insert("description".to_owned(), description.into());
+    }
+    if let Some(channels) = request.channels {
+        body.insert("channels".to_owned(), channels.into());
+    }
+    if let Some(include_count) = request.include_count {
+        body.insert("include_count".to_owned(), include_count.into());
+    }
     let url = ::get_slack_url_for_method("usergroups.create");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| CreateError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<CreateResponse>(&result)
-                            .map_err(|e| CreateError::MalformedResponse(e))
+                      parse_create_response(result.body.as_bytes()).map_err(|err| err.into_error(CreateError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_create_response(body: &[u8]) -> Result<CreateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<CreateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -60,12 +70,43 @@ pub struct CreateRequest<'a> {
     pub include_count: Option<bool>,
 }
 
+impl<'a> CreateRequest<'a> {
+    pub fn new(name: &'a str) -> Self {
+        CreateRequest {
+            name: name,
+            ..CreateRequest::default()
+        }
+    }
+
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn channels(mut self, channels: &'a str) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub usergroup: Option<::Usergroup>,
+    pub warning: Option<String>,
 }
 
 
@@ -139,6 +180,30 @@ impl<'a, E: Error> From<&'a str> for CreateError<E> {
     }
 }
 
+impl<E: Error> From<CreateError<E>> for ::Error<E> {
+    fn from(err: CreateError<E>) -> Self {
+        let code = match err {
+            CreateError::NotAuthed => "not_authed",
+            CreateError::InvalidAuth => "invalid_auth",
+            CreateError::AccountInactive => "account_inactive",
+            CreateError::UserIsBot => "user_is_bot",
+            CreateError::UserIsRestricted => "user_is_restricted",
+            CreateError::InvalidArgName => "invalid_arg_name",
+            CreateError::InvalidArrayArg => "invalid_array_arg",
+            CreateError::InvalidCharset => "invalid_charset",
+            CreateError::InvalidFormData => "invalid_form_data",
+            CreateError::InvalidPostType => "invalid_post_type",
+            CreateError::MissingPostType => "missing_post_type",
+            CreateError::TeamAddedToOrg => "team_added_to_org",
+            CreateError::RequestTimeout => "request_timeout",
+            CreateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            CreateError::Unknown(code) => return ::Error::from_code("usergroups.create", &code),
+            CreateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("usergroups.create", code)
+    }
+}
+
 impl<E: Error> fmt::Display for CreateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -187,23 +252,29 @@ pub fn disable<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("usergroup", request.usergroup)),
-                      request
-                          .include_count
-                          .map(|include_count| {
-                                   ("include_count", if include_count { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("usergroup".to_owned(), request.usergroup.into());
+    if let Some(include_count) = request.include_count {
+        body.insert("include_count".to_owned(), include_count.into());
+    }
     let url = ::get_slack_url_for_method("usergroups.disable");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| DisableError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<DisableResponse>(&result)
-                            .map_err(|e| DisableError::MalformedResponse(e))
+                      parse_disable_response(result.body.as_bytes()).map_err(|err| err.into_error(DisableError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_disable_response(body: &[u8]) -> Result<DisableResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<DisableResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -214,12 +285,28 @@ pub struct DisableRequest<'a> {
     pub include_count: Option<bool>,
 }
 
+impl<'a> DisableRequest<'a> {
+    pub fn new(usergroup: &'a str) -> Self {
+        DisableRequest {
+            usergroup: usergroup,
+            ..DisableRequest::default()
+        }
+    }
+
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct DisableResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub usergroup: Option<::Usergroup>,
+    pub warning: Option<String>,
 }
 
 
@@ -293,6 +380,30 @@ impl<'a, E: Error> From<&'a str> for DisableError<E> {
     }
 }
 
+impl<E: Error> From<DisableError<E>> for ::Error<E> {
+    fn from(err: DisableError<E>) -> Self {
+        let code = match err {
+            DisableError::NotAuthed => "not_authed",
+            DisableError::InvalidAuth => "invalid_auth",
+            DisableError::AccountInactive => "account_inactive",
+            DisableError::UserIsBot => "user_is_bot",
+            DisableError::UserIsRestricted => "user_is_restricted",
+            DisableError::InvalidArgName => "invalid_arg_name",
+            DisableError::InvalidArrayArg => "invalid_array_arg",
+            DisableError::InvalidCharset => "invalid_charset",
+            DisableError::InvalidFormData => "invalid_form_data",
+            DisableError::InvalidPostType => "invalid_post_type",
+            DisableError::MissingPostType => "missing_post_type",
+            DisableError::TeamAddedToOrg => "team_added_to_org",
+            DisableError::RequestTimeout => "request_timeout",
+            DisableError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            DisableError::Unknown(code) => return ::Error::from_code("usergroups.disable", &code),
+            DisableError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("usergroups.disable", code)
+    }
+}
+
 impl<E: Error> fmt::Display for DisableError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -341,23 +452,29 @@ pub fn enable<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("usergroup", request.usergroup)),
-                      request
-                          .include_count
-                          .map(|include_count| {
-                                   ("include_count", if include_count { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("usergroup".to_owned(), request.usergroup.into());
+    if let Some(include_count) = request.include_count {
+        body.insert("include_count".to_owned(), include_count.into());
+    }
     let url = ::get_slack_url_for_method("usergroups.enable");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| EnableError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<EnableResponse>(&result)
-                            .map_err(|e| EnableError::MalformedResponse(e))
+                      parse_enable_response(result.body.as_bytes()).map_err(|err| err.into_error(EnableError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_enable_response(body: &[u8]) -> Result<EnableResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<EnableResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -368,12 +485,28 @@ pub struct EnableRequest<'a> {
     pub include_count: Option<bool>,
 }
 
+impl<'a> EnableRequest<'a> {
+    pub fn new(usergroup: &'a str) -> Self {
+        EnableRequest {
+            usergroup: usergroup,
+            ..EnableRequest::default()
+        }
+    }
+
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct EnableResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub usergroup: Option<::Usergroup>,
+    pub warning: Option<String>,
 }
 
 
@@ -447,6 +580,30 @@ impl<'a, E: Error> From<&'a str> for EnableError<E> {
     }
 }
 
+impl<E: Error> From<EnableError<E>> for ::Error<E> {
+    fn from(err: EnableError<E>) -> Self {
+        let code = match err {
+            EnableError::NotAuthed => "not_authed",
+            EnableError::InvalidAuth => "invalid_auth",
+            EnableError::AccountInactive => "account_inactive",
+            EnableError::UserIsBot => "user_is_bot",
+            EnableError::UserIsRestricted => "user_is_restricted",
+            EnableError::InvalidArgName => "invalid_arg_name",
+            EnableError::InvalidArrayArg => "invalid_array_arg",
+            EnableError::InvalidCharset => "invalid_charset",
+            EnableError::InvalidFormData => "invalid_form_data",
+            EnableError::InvalidPostType => "invalid_post_type",
+            EnableError::MissingPostType => "missing_post_type",
+            EnableError::TeamAddedToOrg => "team_added_to_org",
+            EnableError::RequestTimeout => "request_timeout",
+            EnableError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            EnableError::Unknown(code) => return ::Error::from_code("usergroups.enable", &code),
+            EnableError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("usergroups.enable", code)
+    }
+}
+
 impl<E: Error> fmt::Display for EnableError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -495,32 +652,33 @@ pub fn list<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request
-                          .include_disabled
-                          .map(|include_disabled| {
-                                   ("include_disabled", if include_disabled { "1" } else { "0" })
-                               }),
-                      request
-                          .include_count
-                          .map(|include_count| {
-                                   ("include_count", if include_count { "1" } else { "0" })
-                               }),
-                      request
-                          .include_users
-                          .map(|include_users| {
-                                   ("include_users", if include_users { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    if let Some(include_disabled) = request.include_disabled {
+        params.push(("include_disabled", if include_disabled { "1" } else { "0" }));
+    }
+    if let Some(include_count) = request.include_count {
+        params.push(("include_count", if include_count { "1" } else { "0" }));
+    }
+    if let Some(include_users) = request.include_users {
+        params.push(("include_users", if include_users { "1" } else { "0" }));
+    }
     let url = ::get_slack_url_for_method("usergroups.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -533,12 +691,35 @@ pub struct ListRequest {
     pub include_users: Option<bool>,
 }
 
+impl ListRequest {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.include_users = Some(include_users);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub usergroups: Option<Vec<::Usergroup>>,
+    pub warning: Option<String>,
 }
 
 
@@ -612,6 +793,30 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::UserIsBot => "user_is_bot",
+            ListError::UserIsRestricted => "user_is_restricted",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("usergroups.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("usergroups.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -660,29 +865,41 @@ pub fn update<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("usergroup", request.usergroup)),
-                      request.name.map(|name| ("name", name)),
-                      request.handle.map(|handle| ("handle", handle)),
-                      request
-                          .description
-                          .map(|description| ("description", description)),
-                      request.channels.map(|channels| ("channels", channels)),
-                      request
-                          .include_count
-                          .map(|include_count| {
-                                   ("include_count", if include_count { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("usergroup".to_owned(), request.usergroup.into());
+    if let Some(name) = request.name {
+        body.insert("name".to_owned(), name.into());
+    }
+    if let Some(handle) = request.handle {
+        body.insert("handle".to_owned(), handle.into());
+    }
+    if let Some(description) = request.description {
+        body.insert("description".to_owned(), description.into());
+    }
+    if let Some(channels) = request.channels {
+        body.insert("channels".to_owned(), channels.into());
+    }
+    if let Some(include_count) = request.include_count {
+        body.insert("include_count".to_owned(), include_count.into());
+    }
     let url = ::get_slack_url_for_method("usergroups.update");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| UpdateError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<UpdateResponse>(&result)
-                            .map_err(|e| UpdateError::MalformedResponse(e))
+                      parse_update_response(result.body.as_bytes()).map_err(|err| err.into_error(UpdateError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_update_response(body: &[u8]) -> Result<UpdateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UpdateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -701,12 +918,48 @@ pub struct UpdateRequest<'a> {
     pub include_count: Option<bool>,
 }
 
+impl<'a> UpdateRequest<'a> {
+    pub fn new(usergroup: &'a str) -> Self {
+        UpdateRequest {
+            usergroup: usergroup,
+            ..UpdateRequest::default()
+        }
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn channels(mut self, channels: &'a str) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UpdateResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub usergroup: Option<::Usergroup>,
+    pub warning: Option<String>,
 }
 
 
@@ -780,6 +1033,30 @@ impl<'a, E: Error> From<&'a str> for UpdateError<E> {
     }
 }
 
+impl<E: Error> From<UpdateError<E>> for ::Error<E> {
+    fn from(err: UpdateError<E>) -> Self {
+        let code = match err {
+            UpdateError::NotAuthed => "not_authed",
+            UpdateError::InvalidAuth => "invalid_auth",
+            UpdateError::AccountInactive => "account_inactive",
+            UpdateError::UserIsBot => "user_is_bot",
+            UpdateError::UserIsRestricted => "user_is_restricted",
+            UpdateError::InvalidArgName => "invalid_arg_name",
+            UpdateError::InvalidArrayArg => "invalid_array_arg",
+            UpdateError::InvalidCharset => "invalid_charset",
+            UpdateError::InvalidFormData => "invalid_form_data",
+            UpdateError::InvalidPostType => "invalid_post_type",
+            UpdateError::MissingPostType => "missing_post_type",
+            UpdateError::TeamAddedToOrg => "team_added_to_org",
+            UpdateError::RequestTimeout => "request_timeout",
+            UpdateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UpdateError::Unknown(code) => return ::Error::from_code("usergroups.update", &code),
+            UpdateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("usergroups.update", code)
+    }
+}
+
 impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -816,3 +1093,45 @@ impl<E: Error> Error for UpdateError<E> {
         }
     }
 }
+
+/// The `usergroups.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::usergroups`](../client/struct.SlackClient.html#method.usergroups).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Create a User Group. See [`create`](fn.create.html).
+    pub fn create(&self, request: &CreateRequest) -> Result<CreateResponse, CreateError<R::Error>> {
+        create(self.client, self.token.for_method("usergroups.create"), request)
+    }
+
+    /// Disable an existing User Group. See [`disable`](fn.disable.html).
+    pub fn disable(&self, request: &DisableRequest) -> Result<DisableResponse, DisableError<R::Error>> {
+        disable(self.client, self.token.for_method("usergroups.disable"), request)
+    }
+
+    /// Enable a User Group. See [`enable`](fn.enable.html).
+    pub fn enable(&self, request: &EnableRequest) -> Result<EnableResponse, EnableError<R::Error>> {
+        enable(self.client, self.token.for_method("usergroups.enable"), request)
+    }
+
+    /// List all User Groups for a team. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("usergroups.list"), request)
+    }
+
+    /// Update an existing User Group. See [`update`](fn.update.html).
+    pub fn update(&self, request: &UpdateRequest) -> Result<UpdateResponse, UpdateError<R::Error>> {
+        update(self.client, self.token.for_method("usergroups.update"), request)
+    }
+}
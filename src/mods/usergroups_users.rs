@@ -21,23 +21,28 @@ pub fn list<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("usergroup", request.usergroup)),
-                      request
-                          .include_disabled
-                          .map(|include_disabled| {
-                                   ("include_disabled", if include_disabled { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    params.push(("usergroup", request.usergroup));
+    if let Some(include_disabled) = request.include_disabled {
+        params.push(("include_disabled", if include_disabled { "1" } else { "0" }));
+    }
     let url = ::get_slack_url_for_method("usergroups.users.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -48,12 +53,28 @@ pub struct ListRequest<'a> {
     pub include_disabled: Option<bool>,
 }
 
+impl<'a> ListRequest<'a> {
+    pub fn new(usergroup: &'a str) -> Self {
+        ListRequest {
+            usergroup: usergroup,
+            ..ListRequest::default()
+        }
+    }
+
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub users: Option<Vec<String>>,
+    pub warning: Option<String>,
 }
 
 
@@ -127,6 +148,30 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::UserIsBot => "user_is_bot",
+            ListError::UserIsRestricted => "user_is_restricted",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("usergroups.users.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("usergroups.users.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -175,24 +220,30 @@ pub fn update<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      Some(("usergroup", request.usergroup)),
-                      Some(("users", request.users)),
-                      request
-                          .include_count
-                          .map(|include_count| {
-                                   ("include_count", if include_count { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("usergroup".to_owned(), request.usergroup.into());
+    body.insert("users".to_owned(), request.users.into());
+    if let Some(include_count) = request.include_count {
+        body.insert("include_count".to_owned(), include_count.into());
+    }
     let url = ::get_slack_url_for_method("usergroups.users.update");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| UpdateError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<UpdateResponse>(&result)
-                            .map_err(|e| UpdateError::MalformedResponse(e))
+                      parse_update_response(result.body.as_bytes()).map_err(|err| err.into_error(UpdateError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_update_response(body: &[u8]) -> Result<UpdateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UpdateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -205,12 +256,29 @@ pub struct UpdateRequest<'a> {
     pub include_count: Option<bool>,
 }
 
+impl<'a> UpdateRequest<'a> {
+    pub fn new(usergroup: &'a str, users: &'a str) -> Self {
+        UpdateRequest {
+            usergroup: usergroup,
+            users: users,
+            ..UpdateRequest::default()
+        }
+    }
+
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UpdateResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub usergroup: Option<::Usergroup>,
+    pub warning: Option<String>,
 }
 
 
@@ -284,6 +352,30 @@ impl<'a, E: Error> From<&'a str> for UpdateError<E> {
     }
 }
 
+impl<E: Error> From<UpdateError<E>> for ::Error<E> {
+    fn from(err: UpdateError<E>) -> Self {
+        let code = match err {
+            UpdateError::NotAuthed => "not_authed",
+            UpdateError::InvalidAuth => "invalid_auth",
+            UpdateError::AccountInactive => "account_inactive",
+            UpdateError::UserIsBot => "user_is_bot",
+            UpdateError::UserIsRestricted => "user_is_restricted",
+            UpdateError::InvalidArgName => "invalid_arg_name",
+            UpdateError::InvalidArrayArg => "invalid_array_arg",
+            UpdateError::InvalidCharset => "invalid_charset",
+            UpdateError::InvalidFormData => "invalid_form_data",
+            UpdateError::InvalidPostType => "invalid_post_type",
+            UpdateError::MissingPostType => "missing_post_type",
+            UpdateError::TeamAddedToOrg => "team_added_to_org",
+            UpdateError::RequestTimeout => "request_timeout",
+            UpdateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UpdateError::Unknown(code) => return ::Error::from_code("usergroups.users.update", &code),
+            UpdateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("usergroups.users.update", code)
+    }
+}
+
 impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -320,3 +412,30 @@ impl<E: Error> Error for UpdateError<E> {
         }
     }
 }
+
+/// The `usergroups.users.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::usergroups_users`](../client/struct.SlackClient.html#method.usergroups_users).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// List all users in a User Group. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("usergroups.users.list"), request)
+    }
+
+    /// Update the list of users for a User Group. See [`update`](fn.update.html).
+    pub fn update(&self, request: &UpdateRequest) -> Result<UpdateResponse, UpdateError<R::Error>> {
+        update(self.client, self.token.for_method("usergroups.users.update"), request)
+    }
+}
@@ -9,7 +9,7 @@ use std::fmt;
 
 use serde_json;
 
-use requests::SlackWebRequestSender;
+use requests::{SlackMultipartSender, SlackWebRequestSender};
 
 /// Delete the user profile photo
 ///
@@ -20,16 +20,23 @@ pub fn delete_photo<R>(client: &R,
                        -> Result<DeletePhotoResponse, DeletePhotoError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let params = &[("token", token)];
+
     let url = ::get_slack_url_for_method("users.deletePhoto");
     client
-        .send(&url, &params[..])
+        .send_json(&url, token, "{}")
         .map_err(|err| DeletePhotoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<DeletePhotoResponse>(&result)
-                            .map_err(|e| DeletePhotoError::MalformedResponse(e))
+                      parse_delete_photo_response(result.body.as_bytes()).map_err(|err| err.into_error(DeletePhotoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_delete_photo_response(body: &[u8]) -> Result<DeletePhotoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<DeletePhotoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -37,6 +44,8 @@ pub struct DeletePhotoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -107,6 +116,29 @@ impl<'a, E: Error> From<&'a str> for DeletePhotoError<E> {
     }
 }
 
+impl<E: Error> From<DeletePhotoError<E>> for ::Error<E> {
+    fn from(err: DeletePhotoError<E>) -> Self {
+        let code = match err {
+            DeletePhotoError::NotAuthed => "not_authed",
+            DeletePhotoError::InvalidAuth => "invalid_auth",
+            DeletePhotoError::AccountInactive => "account_inactive",
+            DeletePhotoError::UserIsBot => "user_is_bot",
+            DeletePhotoError::InvalidArgName => "invalid_arg_name",
+            DeletePhotoError::InvalidArrayArg => "invalid_array_arg",
+            DeletePhotoError::InvalidCharset => "invalid_charset",
+            DeletePhotoError::InvalidFormData => "invalid_form_data",
+            DeletePhotoError::InvalidPostType => "invalid_post_type",
+            DeletePhotoError::MissingPostType => "missing_post_type",
+            DeletePhotoError::TeamAddedToOrg => "team_added_to_org",
+            DeletePhotoError::RequestTimeout => "request_timeout",
+            DeletePhotoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            DeletePhotoError::Unknown(code) => return ::Error::from_code("users.deletePhoto", &code),
+            DeletePhotoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.deletePhoto", code)
+    }
+}
+
 impl<E: Error> fmt::Display for DeletePhotoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -154,17 +186,23 @@ pub fn get_presence<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [("token", token), ("user", request.user)];
     let url = ::get_slack_url_for_method("users.getPresence");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| GetPresenceError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<GetPresenceResponse>(&result)
-                            .map_err(|e| GetPresenceError::MalformedResponse(e))
+                      parse_get_presence_response(&result).map_err(|err| err.into_error(GetPresenceError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_get_presence_response(body: &[u8]) -> Result<GetPresenceResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<GetPresenceResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -173,12 +211,23 @@ pub struct GetPresenceRequest<'a> {
     pub user: &'a str,
 }
 
+impl<'a> GetPresenceRequest<'a> {
+    pub fn new(user: &'a str) -> Self {
+        GetPresenceRequest {
+            user: user,
+            ..GetPresenceRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct GetPresenceResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub presence: Option<String>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -246,6 +295,28 @@ impl<'a, E: Error> From<&'a str> for GetPresenceError<E> {
     }
 }
 
+impl<E: Error> From<GetPresenceError<E>> for ::Error<E> {
+    fn from(err: GetPresenceError<E>) -> Self {
+        let code = match err {
+            GetPresenceError::NotAuthed => "not_authed",
+            GetPresenceError::InvalidAuth => "invalid_auth",
+            GetPresenceError::AccountInactive => "account_inactive",
+            GetPresenceError::InvalidArgName => "invalid_arg_name",
+            GetPresenceError::InvalidArrayArg => "invalid_array_arg",
+            GetPresenceError::InvalidCharset => "invalid_charset",
+            GetPresenceError::InvalidFormData => "invalid_form_data",
+            GetPresenceError::InvalidPostType => "invalid_post_type",
+            GetPresenceError::MissingPostType => "missing_post_type",
+            GetPresenceError::TeamAddedToOrg => "team_added_to_org",
+            GetPresenceError::RequestTimeout => "request_timeout",
+            GetPresenceError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            GetPresenceError::Unknown(code) => return ::Error::from_code("users.getPresence", &code),
+            GetPresenceError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.getPresence", code)
+    }
+}
+
 impl<E: Error> fmt::Display for GetPresenceError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -291,13 +362,20 @@ pub fn identity<R>(client: &R, token: &str) -> Result<IdentityResponse, Identity
     let params = &[("token", token)];
     let url = ::get_slack_url_for_method("users.identity");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| IdentityError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<IdentityResponse>(&result)
-                            .map_err(|e| IdentityError::MalformedResponse(e))
+                      parse_identity_response(&result).map_err(|err| err.into_error(IdentityError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_identity_response(body: &[u8]) -> Result<IdentityResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<IdentityResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -305,8 +383,10 @@ pub struct IdentityResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub team: Option<::Team>,
     pub user: Option<::User>,
+    pub warning: Option<String>,
 }
 
 
@@ -377,6 +457,29 @@ impl<'a, E: Error> From<&'a str> for IdentityError<E> {
     }
 }
 
+impl<E: Error> From<IdentityError<E>> for ::Error<E> {
+    fn from(err: IdentityError<E>) -> Self {
+        let code = match err {
+            IdentityError::NotAuthed => "not_authed",
+            IdentityError::InvalidAuth => "invalid_auth",
+            IdentityError::AccountInactive => "account_inactive",
+            IdentityError::UserIsBot => "user_is_bot",
+            IdentityError::InvalidArgName => "invalid_arg_name",
+            IdentityError::InvalidArrayArg => "invalid_array_arg",
+            IdentityError::InvalidCharset => "invalid_charset",
+            IdentityError::InvalidFormData => "invalid_form_data",
+            IdentityError::InvalidPostType => "invalid_post_type",
+            IdentityError::MissingPostType => "missing_post_type",
+            IdentityError::TeamAddedToOrg => "team_added_to_org",
+            IdentityError::RequestTimeout => "request_timeout",
+            IdentityError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            IdentityError::Unknown(code) => return ::Error::from_code("users.identity", &code),
+            IdentityError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.identity", code)
+    }
+}
+
 impl<E: Error> fmt::Display for IdentityError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -424,17 +527,23 @@ pub fn info<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [("token", token), ("user", request.user)];
     let url = ::get_slack_url_for_method("users.info");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| InfoError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<InfoResponse>(&result)
-                            .map_err(|e| InfoError::MalformedResponse(e))
+                      parse_info_response(&result).map_err(|err| err.into_error(InfoError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_info_response(body: &[u8]) -> Result<InfoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<InfoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -443,12 +552,23 @@ pub struct InfoRequest<'a> {
     pub user: &'a str,
 }
 
+impl<'a> InfoRequest<'a> {
+    pub fn new(user: &'a str) -> Self {
+        InfoRequest {
+            user: user,
+            ..InfoRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct InfoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
     pub user: Option<::User>,
+    pub warning: Option<String>,
 }
 
 
@@ -522,6 +642,30 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for ::Error<E> {
+    fn from(err: InfoError<E>) -> Self {
+        let code = match err {
+            InfoError::UserNotFound => "user_not_found",
+            InfoError::UserNotVisible => "user_not_visible",
+            InfoError::NotAuthed => "not_authed",
+            InfoError::InvalidAuth => "invalid_auth",
+            InfoError::AccountInactive => "account_inactive",
+            InfoError::InvalidArgName => "invalid_arg_name",
+            InfoError::InvalidArrayArg => "invalid_array_arg",
+            InfoError::InvalidCharset => "invalid_charset",
+            InfoError::InvalidFormData => "invalid_form_data",
+            InfoError::InvalidPostType => "invalid_post_type",
+            InfoError::MissingPostType => "missing_post_type",
+            InfoError::TeamAddedToOrg => "team_added_to_org",
+            InfoError::RequestTimeout => "request_timeout",
+            InfoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            InfoError::Unknown(code) => return ::Error::from_code("users.info", &code),
+            InfoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.info", code)
+    }
+}
+
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -569,35 +713,92 @@ pub fn list<R>(client: &R,
                -> Result<ListResponse, ListError<R::Error>>
     where R: SlackWebRequestSender
 {
-
-    let params = vec![Some(("token", token)),
-                      request
-                          .presence
-                          .map(|presence| ("presence", if presence { "1" } else { "0" }))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let limit = request.limit.map(::IntParam::new);
+    let mut params = Vec::with_capacity(4);
+    params.push(("token", token));
+    if let Some(cursor) = request.cursor {
+        params.push(("cursor", cursor));
+    }
+    if let Some(ref limit) = limit {
+        params.push(("limit", limit.as_str()));
+    }
+    if let Some(presence) = request.presence {
+        params.push(("presence", if presence { "1" } else { "0" }));
+    }
     let url = ::get_slack_url_for_method("users.list");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| ListError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<ListResponse>(&result)
-                            .map_err(|e| ListError::MalformedResponse(e))
+                      parse_list_response(&result).map_err(|err| err.into_error(ListError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+/// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+/// yielding the `members` of every page in turn.
+pub fn list_all<'a, R>(client: &'a R,
+                       token: &'a str,
+                       request: &ListRequest<'a>)
+                       -> ::cursor::Items<'a, ::User, ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let request = request.clone();
+    ::cursor::Items::new(move |cursor| {
+        let request = ListRequest { cursor: cursor.or(request.cursor), ..request.clone() };
+        list(client, token, &request).map(|response| {
+            (response.members.unwrap_or_default(), response.response_metadata.and_then(|m| m.next_cursor))
+        })
+    })
+}
+
+fn parse_list_response(body: &[u8]) -> Result<ListResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<ListResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+pub struct ListRequest<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<&'a str>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
     /// Whether to include presence data in the output
     pub presence: Option<bool>,
 }
 
+impl<'a> ListRequest<'a> {
+    pub fn new() -> Self {
+        ListRequest::default()
+    }
+
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn presence(mut self, presence: bool) -> Self {
+        self.presence = Some(presence);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub members: Option<Vec<::User>>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -665,6 +866,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for ::Error<E> {
+    fn from(err: ListError<E>) -> Self {
+        let code = match err {
+            ListError::NotAuthed => "not_authed",
+            ListError::InvalidAuth => "invalid_auth",
+            ListError::AccountInactive => "account_inactive",
+            ListError::InvalidArgName => "invalid_arg_name",
+            ListError::InvalidArrayArg => "invalid_array_arg",
+            ListError::InvalidCharset => "invalid_charset",
+            ListError::InvalidFormData => "invalid_form_data",
+            ListError::InvalidPostType => "invalid_post_type",
+            ListError::MissingPostType => "missing_post_type",
+            ListError::TeamAddedToOrg => "team_added_to_org",
+            ListError::RequestTimeout => "request_timeout",
+            ListError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            ListError::Unknown(code) => return ::Error::from_code("users.list", &code),
+            ListError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.list", code)
+    }
+}
+
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -707,16 +930,23 @@ impl<E: Error> Error for ListError<E> {
 pub fn set_active<R>(client: &R, token: &str) -> Result<SetActiveResponse, SetActiveError<R::Error>>
     where R: SlackWebRequestSender
 {
-    let params = &[("token", token)];
+
     let url = ::get_slack_url_for_method("users.setActive");
     client
-        .send(&url, &params[..])
+        .send_json(&url, token, "{}")
         .map_err(|err| SetActiveError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SetActiveResponse>(&result)
-                            .map_err(|e| SetActiveError::MalformedResponse(e))
+                      parse_set_active_response(result.body.as_bytes()).map_err(|err| err.into_error(SetActiveError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_set_active_response(body: &[u8]) -> Result<SetActiveResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetActiveResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -724,6 +954,8 @@ pub struct SetActiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -791,6 +1023,28 @@ impl<'a, E: Error> From<&'a str> for SetActiveError<E> {
     }
 }
 
+impl<E: Error> From<SetActiveError<E>> for ::Error<E> {
+    fn from(err: SetActiveError<E>) -> Self {
+        let code = match err {
+            SetActiveError::NotAuthed => "not_authed",
+            SetActiveError::InvalidAuth => "invalid_auth",
+            SetActiveError::AccountInactive => "account_inactive",
+            SetActiveError::InvalidArgName => "invalid_arg_name",
+            SetActiveError::InvalidArrayArg => "invalid_array_arg",
+            SetActiveError::InvalidCharset => "invalid_charset",
+            SetActiveError::InvalidFormData => "invalid_form_data",
+            SetActiveError::InvalidPostType => "invalid_post_type",
+            SetActiveError::MissingPostType => "missing_post_type",
+            SetActiveError::TeamAddedToOrg => "team_added_to_org",
+            SetActiveError::RequestTimeout => "request_timeout",
+            SetActiveError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetActiveError::Unknown(code) => return ::Error::from_code("users.setActive", &code),
+            SetActiveError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.setActive", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SetActiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -827,6 +1081,240 @@ impl<E: Error> Error for SetActiveError<E> {
 }
 
 
+/// Set the user profile photo
+///
+/// Wraps https://api.slack.com/methods/users.setPhoto
+
+pub fn set_photo<R>(client: &R,
+                    token: &str,
+                    request: &SetPhotoRequest)
+                    -> Result<SetPhotoResponse, SetPhotoError<R::Error>>
+    where R: SlackMultipartSender
+{
+    let crop_w = request.crop_w.map(::IntParam::new);
+    let crop_x = request.crop_x.map(::IntParam::new);
+    let crop_y = request.crop_y.map(::IntParam::new);
+    let mut params = Vec::with_capacity(3);
+    if let Some(ref crop_w) = crop_w {
+        params.push(("crop_w", crop_w.as_str()));
+    }
+    if let Some(ref crop_x) = crop_x {
+        params.push(("crop_x", crop_x.as_str()));
+    }
+    if let Some(ref crop_y) = crop_y {
+        params.push(("crop_y", crop_y.as_str()));
+    }
+    let file = Some(::requests::FilePart {
+                        name: "image",
+                        filename: "image",
+                        content: request.image,
+                    });
+    let url = ::get_slack_url_for_method("users.setPhoto");
+    client
+        .send_multipart(&url, token, &params[..], file.as_ref())
+        .map_err(|err| SetPhotoError::Client(err))
+        .and_then(|result| {
+                      parse_set_photo_response(result.body.as_bytes()).map_err(|err| err.into_error(SetPhotoError::MalformedResponse))
+                  })
+}
+
+fn parse_set_photo_response(body: &[u8]) -> Result<SetPhotoResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetPhotoResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetPhotoRequest<'a> {
+    /// File contents via `multipart/form-data`.
+    pub image: &'a [u8],
+    /// Width/height of crop box (always square)
+    pub crop_w: Option<u32>,
+    /// X coordinate of top-left corner of crop box
+    pub crop_x: Option<u32>,
+    /// Y coordinate of top-left corner of crop box
+    pub crop_y: Option<u32>,
+}
+
+impl<'a> SetPhotoRequest<'a> {
+    pub fn new(image: &'a [u8]) -> Self {
+        SetPhotoRequest {
+            image: image,
+            ..SetPhotoRequest::default()
+        }
+    }
+
+    pub fn crop_w(mut self, crop_w: u32) -> Self {
+        self.crop_w = Some(crop_w);
+        self
+    }
+
+    pub fn crop_x(mut self, crop_x: u32) -> Self {
+        self.crop_x = Some(crop_x);
+        self
+    }
+
+    pub fn crop_y(mut self, crop_y: u32) -> Self {
+        self.crop_y = Some(crop_y);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetPhotoResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<SetPhotoResponse, SetPhotoError<E>>> for SetPhotoResponse {
+    fn into(self) -> Result<SetPhotoResponse, SetPhotoError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetPhotoError<E: Error> {
+    /// The uploaded image could not be processed. Try another image.
+    BadImage,
+    /// The uploaded image had excessive dimensions.
+    TooLarge,
+    /// An animated GIF with too many frames was uploaded.
+    TooManyFrames,
+    /// This method cannot be called by a bot user.
+    UserIsBot,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetPhotoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "bad_image" => SetPhotoError::BadImage,
+            "too_large" => SetPhotoError::TooLarge,
+            "too_many_frames" => SetPhotoError::TooManyFrames,
+            "user_is_bot" => SetPhotoError::UserIsBot,
+            "not_authed" => SetPhotoError::NotAuthed,
+            "invalid_auth" => SetPhotoError::InvalidAuth,
+            "account_inactive" => SetPhotoError::AccountInactive,
+            "invalid_arg_name" => SetPhotoError::InvalidArgName,
+            "invalid_array_arg" => SetPhotoError::InvalidArrayArg,
+            "invalid_charset" => SetPhotoError::InvalidCharset,
+            "invalid_form_data" => SetPhotoError::InvalidFormData,
+            "invalid_post_type" => SetPhotoError::InvalidPostType,
+            "missing_post_type" => SetPhotoError::MissingPostType,
+            "team_added_to_org" => SetPhotoError::TeamAddedToOrg,
+            "request_timeout" => SetPhotoError::RequestTimeout,
+            _ => SetPhotoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<SetPhotoError<E>> for ::Error<E> {
+    fn from(err: SetPhotoError<E>) -> Self {
+        let code = match err {
+            SetPhotoError::BadImage => "bad_image",
+            SetPhotoError::TooLarge => "too_large",
+            SetPhotoError::TooManyFrames => "too_many_frames",
+            SetPhotoError::UserIsBot => "user_is_bot",
+            SetPhotoError::NotAuthed => "not_authed",
+            SetPhotoError::InvalidAuth => "invalid_auth",
+            SetPhotoError::AccountInactive => "account_inactive",
+            SetPhotoError::InvalidArgName => "invalid_arg_name",
+            SetPhotoError::InvalidArrayArg => "invalid_array_arg",
+            SetPhotoError::InvalidCharset => "invalid_charset",
+            SetPhotoError::InvalidFormData => "invalid_form_data",
+            SetPhotoError::InvalidPostType => "invalid_post_type",
+            SetPhotoError::MissingPostType => "missing_post_type",
+            SetPhotoError::TeamAddedToOrg => "team_added_to_org",
+            SetPhotoError::RequestTimeout => "request_timeout",
+            SetPhotoError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetPhotoError::Unknown(code) => return ::Error::from_code("users.setPhoto", &code),
+            SetPhotoError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.setPhoto", code)
+    }
+}
+
+impl<E: Error> fmt::Display for SetPhotoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetPhotoError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SetPhotoError::BadImage => "bad_image: The uploaded image could not be processed. Try another image.",
+            &SetPhotoError::TooLarge => "too_large: The uploaded image had excessive dimensions.",
+            &SetPhotoError::TooManyFrames => "too_many_frames: An animated GIF with too many frames was uploaded.",
+            &SetPhotoError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
+            &SetPhotoError::NotAuthed => "not_authed: No authentication token provided.",
+            &SetPhotoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &SetPhotoError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &SetPhotoError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &SetPhotoError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &SetPhotoError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &SetPhotoError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &SetPhotoError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &SetPhotoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &SetPhotoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &SetPhotoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &SetPhotoError::MalformedResponse(ref e) => e.description(),
+            &SetPhotoError::Unknown(ref s) => s,
+            &SetPhotoError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SetPhotoError::MalformedResponse(ref e) => Some(e),
+            &SetPhotoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 /// Manually sets user presence.
 ///
 /// Wraps https://api.slack.com/methods/users.setPresence
@@ -838,17 +1326,26 @@ pub fn set_presence<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)), Some(("presence", request.presence))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    body.insert("presence".to_owned(), request.presence.into());
     let url = ::get_slack_url_for_method("users.setPresence");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| SetPresenceError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SetPresenceResponse>(&result)
-                            .map_err(|e| SetPresenceError::MalformedResponse(e))
+                      parse_set_presence_response(result.body.as_bytes()).map_err(|err| err.into_error(SetPresenceError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_set_presence_response(body: &[u8]) -> Result<SetPresenceResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetPresenceResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -857,11 +1354,22 @@ pub struct SetPresenceRequest<'a> {
     pub presence: &'a str,
 }
 
+impl<'a> SetPresenceRequest<'a> {
+    pub fn new(presence: &'a str) -> Self {
+        SetPresenceRequest {
+            presence: presence,
+            ..SetPresenceRequest::default()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SetPresenceResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -932,6 +1440,29 @@ impl<'a, E: Error> From<&'a str> for SetPresenceError<E> {
     }
 }
 
+impl<E: Error> From<SetPresenceError<E>> for ::Error<E> {
+    fn from(err: SetPresenceError<E>) -> Self {
+        let code = match err {
+            SetPresenceError::InvalidPresence => "invalid_presence",
+            SetPresenceError::NotAuthed => "not_authed",
+            SetPresenceError::InvalidAuth => "invalid_auth",
+            SetPresenceError::AccountInactive => "account_inactive",
+            SetPresenceError::InvalidArgName => "invalid_arg_name",
+            SetPresenceError::InvalidArrayArg => "invalid_array_arg",
+            SetPresenceError::InvalidCharset => "invalid_charset",
+            SetPresenceError::InvalidFormData => "invalid_form_data",
+            SetPresenceError::InvalidPostType => "invalid_post_type",
+            SetPresenceError::MissingPostType => "missing_post_type",
+            SetPresenceError::TeamAddedToOrg => "team_added_to_org",
+            SetPresenceError::RequestTimeout => "request_timeout",
+            SetPresenceError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetPresenceError::Unknown(code) => return ::Error::from_code("users.setPresence", &code),
+            SetPresenceError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.setPresence", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SetPresenceError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -967,3 +1498,63 @@ impl<E: Error> Error for SetPresenceError<E> {
         }
     }
 }
+
+/// The `users.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::users`](../client/struct.SlackClient.html#method.users).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Delete the user profile photo. See [`delete_photo`](fn.delete_photo.html).
+    pub fn delete_photo(&self) -> Result<DeletePhotoResponse, DeletePhotoError<R::Error>> {
+        delete_photo(self.client, self.token.for_method("users.deletePhoto"))
+    }
+
+    /// Gets user presence information. See [`get_presence`](fn.get_presence.html).
+    pub fn get_presence(&self, request: &GetPresenceRequest)
+                        -> Result<GetPresenceResponse, GetPresenceError<R::Error>> {
+        get_presence(self.client, self.token.for_method("users.getPresence"), request)
+    }
+
+    /// Get a user's identity. See [`identity`](fn.identity.html).
+    pub fn identity(&self) -> Result<IdentityResponse, IdentityError<R::Error>> {
+        identity(self.client, self.token.for_method("users.identity"))
+    }
+
+    /// Gets information about a user. See [`info`](fn.info.html).
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<R::Error>> {
+        info(self.client, self.token.for_method("users.info"), request)
+    }
+
+    /// Lists all users in a Slack team. See [`list`](fn.list.html).
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<R::Error>> {
+        list(self.client, self.token.for_method("users.list"), request)
+    }
+
+    /// Like [`list`](fn.list.html), but follows `response_metadata.next_cursor` from page to page,
+    /// yielding the `members` of every page in turn.
+    pub fn list_all(&self, request: &ListRequest<'a>) -> ::cursor::Items<'a, ::User, ListError<R::Error>> {
+        list_all(self.client, self.token.for_method("users.list"), request)
+    }
+
+    /// Marks a user as active. See [`set_active`](fn.set_active.html).
+    pub fn set_active(&self) -> Result<SetActiveResponse, SetActiveError<R::Error>> {
+        set_active(self.client, self.token.for_method("users.setActive"))
+    }
+
+    /// Manually sets user presence. See [`set_presence`](fn.set_presence.html).
+    pub fn set_presence(&self, request: &SetPresenceRequest)
+                        -> Result<SetPresenceResponse, SetPresenceError<R::Error>> {
+        set_presence(self.client, self.token.for_method("users.setPresence"), request)
+    }
+}
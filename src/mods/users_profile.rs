@@ -21,23 +21,30 @@ pub fn get<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request.user.map(|user| ("user", user)),
-                      request
-                          .include_labels
-                          .map(|include_labels| {
-                                   ("include_labels", if include_labels { "1" } else { "0" })
-                               })];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut params = Vec::with_capacity(3);
+    params.push(("token", token));
+    if let Some(user) = request.user {
+        params.push(("user", user));
+    }
+    if let Some(include_labels) = request.include_labels {
+        params.push(("include_labels", if include_labels { "1" } else { "0" }));
+    }
     let url = ::get_slack_url_for_method("users.profile.get");
     client
-        .send(&url, &params[..])
+        .send_bytes(&url, &params[..])
         .map_err(|err| GetError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<GetResponse>(&result)
-                            .map_err(|e| GetError::MalformedResponse(e))
+                      parse_get_response(&result).map_err(|err| err.into_error(GetError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_get_response(body: &[u8]) -> Result<GetResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<GetResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -48,12 +55,30 @@ pub struct GetRequest<'a> {
     pub include_labels: Option<bool>,
 }
 
+impl<'a> GetRequest<'a> {
+    pub fn new() -> Self {
+        GetRequest::default()
+    }
+
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    pub fn include_labels(mut self, include_labels: bool) -> Self {
+        self.include_labels = Some(include_labels);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct GetResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub profile: Option<::UserProfile>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -127,6 +152,30 @@ impl<'a, E: Error> From<&'a str> for GetError<E> {
     }
 }
 
+impl<E: Error> From<GetError<E>> for ::Error<E> {
+    fn from(err: GetError<E>) -> Self {
+        let code = match err {
+            GetError::UserNotFound => "user_not_found",
+            GetError::NotAuthed => "not_authed",
+            GetError::InvalidAuth => "invalid_auth",
+            GetError::AccountInactive => "account_inactive",
+            GetError::UserIsBot => "user_is_bot",
+            GetError::InvalidArgName => "invalid_arg_name",
+            GetError::InvalidArrayArg => "invalid_array_arg",
+            GetError::InvalidCharset => "invalid_charset",
+            GetError::InvalidFormData => "invalid_form_data",
+            GetError::InvalidPostType => "invalid_post_type",
+            GetError::MissingPostType => "missing_post_type",
+            GetError::TeamAddedToOrg => "team_added_to_org",
+            GetError::RequestTimeout => "request_timeout",
+            GetError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            GetError::Unknown(code) => return ::Error::from_code("users.profile.get", &code),
+            GetError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.profile.get", code)
+    }
+}
+
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -175,21 +224,37 @@ pub fn set<R>(client: &R,
     where R: SlackWebRequestSender
 {
 
-    let params = vec![Some(("token", token)),
-                      request.user.map(|user| ("user", user)),
-                      request.profile.map(|profile| ("profile", profile)),
-                      request.name.map(|name| ("name", name)),
-                      request.value.map(|value| ("value", value))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let mut body = ::serde_json::Map::new();
+    if let Some(user) = request.user {
+        body.insert("user".to_owned(), user.into());
+    }
+    if let Some(profile) = request.profile {
+        body.insert("profile".to_owned(), ::json_param(profile));
+    }
+    if let Some(name) = request.name {
+        body.insert("name".to_owned(), name.into());
+    }
+    if let Some(value) = request.value {
+        body.insert("value".to_owned(), value.into());
+    }
     let url = ::get_slack_url_for_method("users.profile.set");
-    client
-        .send(&url, &params[..])
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
         .map_err(|err| SetError::Client(err))
         .and_then(|result| {
-                      serde_json::from_str::<SetResponse>(&result)
-                            .map_err(|e| SetError::MalformedResponse(e))
+                      parse_set_response(result.body.as_bytes()).map_err(|err| err.into_error(SetError::MalformedResponse))
                   })
-        .and_then(|o| o.into())
+}
+
+fn parse_set_response(body: &[u8]) -> Result<SetResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<SetResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -204,12 +269,40 @@ pub struct SetRequest<'a> {
     pub value: Option<&'a str>,
 }
 
+impl<'a> SetRequest<'a> {
+    pub fn new() -> Self {
+        SetRequest::default()
+    }
+
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    pub fn profile(mut self, profile: &'a str) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.value = Some(value);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SetResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub profile: Option<::UserProfile>,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub warning: Option<String>,
 }
 
 
@@ -298,6 +391,35 @@ impl<'a, E: Error> From<&'a str> for SetError<E> {
     }
 }
 
+impl<E: Error> From<SetError<E>> for ::Error<E> {
+    fn from(err: SetError<E>) -> Self {
+        let code = match err {
+            SetError::ReservedName => "reserved_name",
+            SetError::InvalidProfile => "invalid_profile",
+            SetError::ProfileSetFailed => "profile_set_failed",
+            SetError::NotAdmin => "not_admin",
+            SetError::NotAppAdmin => "not_app_admin",
+            SetError::CannotUpdateAdminUser => "cannot_update_admin_user",
+            SetError::NotAuthed => "not_authed",
+            SetError::InvalidAuth => "invalid_auth",
+            SetError::AccountInactive => "account_inactive",
+            SetError::UserIsBot => "user_is_bot",
+            SetError::InvalidArgName => "invalid_arg_name",
+            SetError::InvalidArrayArg => "invalid_array_arg",
+            SetError::InvalidCharset => "invalid_charset",
+            SetError::InvalidFormData => "invalid_form_data",
+            SetError::InvalidPostType => "invalid_post_type",
+            SetError::MissingPostType => "missing_post_type",
+            SetError::TeamAddedToOrg => "team_added_to_org",
+            SetError::RequestTimeout => "request_timeout",
+            SetError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            SetError::Unknown(code) => return ::Error::from_code("users.profile.set", &code),
+            SetError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("users.profile.set", code)
+    }
+}
+
 impl<E: Error> fmt::Display for SetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
@@ -339,3 +461,30 @@ impl<E: Error> Error for SetError<E> {
         }
     }
 }
+
+/// The `users.profile.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::users_profile`](../client/struct.SlackClient.html#method.users_profile).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Retrieves a user's profile information. See [`get`](fn.get.html).
+    pub fn get(&self, request: &GetRequest) -> Result<GetResponse, GetError<R::Error>> {
+        get(self.client, self.token.for_method("users.profile.get"), request)
+    }
+
+    /// Set the profile information for a user. See [`set`](fn.set.html).
+    pub fn set(&self, request: &SetRequest) -> Result<SetResponse, SetError<R::Error>> {
+        set(self.client, self.token.for_method("users.profile.set"), request)
+    }
+}
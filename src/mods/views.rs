@@ -0,0 +1,967 @@
+//! Open, update, push and publish the modals and Home tabs of an app.
+
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Open a view for a user.
+///
+/// Wraps https://api.slack.com/methods/views.open
+
+pub fn open<R>(client: &R,
+               token: &str,
+               request: &OpenRequest)
+               -> Result<OpenResponse, OpenError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(interactivity_pointer) = request.interactivity_pointer {
+        body.insert("interactivity_pointer".to_owned(), interactivity_pointer.into());
+    }
+    if let Some(trigger_id) = request.trigger_id {
+        body.insert("trigger_id".to_owned(), trigger_id.into());
+    }
+    body.insert("view".to_owned(), ::json_param(request.view));
+    let url = ::get_slack_url_for_method("views.open");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| OpenError::Client(err))
+        .and_then(|result| {
+                      parse_open_response(result.body.as_bytes()).map_err(|err| err.into_error(OpenError::MalformedResponse))
+                  })
+}
+
+fn parse_open_response(body: &[u8]) -> Result<OpenResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<OpenResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct OpenRequest<'a> {
+    /// Exchange an interactivity pointer for the view, instead of a trigger_id.
+    pub interactivity_pointer: Option<&'a str>,
+    /// Exchange a trigger to post to the user, from an interaction payload. Either this or interactivity_pointer is required.
+    pub trigger_id: Option<&'a str>,
+    /// A view payload, as JSON. See blocks::View for building one.
+    pub view: &'a str,
+}
+
+impl<'a> OpenRequest<'a> {
+    pub fn new(view: &'a str) -> Self {
+        OpenRequest {
+            view: view,
+            ..OpenRequest::default()
+        }
+    }
+
+    pub fn interactivity_pointer(mut self, interactivity_pointer: &'a str) -> Self {
+        self.interactivity_pointer = Some(interactivity_pointer);
+        self
+    }
+
+    pub fn trigger_id(mut self, trigger_id: &'a str) -> Self {
+        self.trigger_id = Some(trigger_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OpenResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub view: Option<::View>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
+    fn into(self) -> Result<OpenResponse, OpenError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum OpenError<E: Error> {
+    /// The trigger_id was used more than three seconds after the interaction.
+    ExpiredTriggerId,
+    /// The trigger_id was already exchanged for a view.
+    ExchangedTriggerId,
+    /// The trigger_id is not valid.
+    InvalidTriggerId,
+    /// The view was not valid, e.g. a required field is missing or a block is not allowed in this view.
+    InvalidArguments,
+    /// The view is larger than 250kb.
+    ViewTooLarge,
+    /// The external_id is already used by another view of the app.
+    DuplicateExternalId,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for OpenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "expired_trigger_id" => OpenError::ExpiredTriggerId,
+            "exchanged_trigger_id" => OpenError::ExchangedTriggerId,
+            "invalid_trigger_id" => OpenError::InvalidTriggerId,
+            "invalid_arguments" => OpenError::InvalidArguments,
+            "view_too_large" => OpenError::ViewTooLarge,
+            "duplicate_external_id" => OpenError::DuplicateExternalId,
+            "not_authed" => OpenError::NotAuthed,
+            "invalid_auth" => OpenError::InvalidAuth,
+            "account_inactive" => OpenError::AccountInactive,
+            "invalid_arg_name" => OpenError::InvalidArgName,
+            "invalid_array_arg" => OpenError::InvalidArrayArg,
+            "invalid_charset" => OpenError::InvalidCharset,
+            "invalid_form_data" => OpenError::InvalidFormData,
+            "invalid_post_type" => OpenError::InvalidPostType,
+            "missing_post_type" => OpenError::MissingPostType,
+            "team_added_to_org" => OpenError::TeamAddedToOrg,
+            "request_timeout" => OpenError::RequestTimeout,
+            _ => OpenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<OpenError<E>> for ::Error<E> {
+    fn from(err: OpenError<E>) -> Self {
+        let code = match err {
+            OpenError::ExpiredTriggerId => "expired_trigger_id",
+            OpenError::ExchangedTriggerId => "exchanged_trigger_id",
+            OpenError::InvalidTriggerId => "invalid_trigger_id",
+            OpenError::InvalidArguments => "invalid_arguments",
+            OpenError::ViewTooLarge => "view_too_large",
+            OpenError::DuplicateExternalId => "duplicate_external_id",
+            OpenError::NotAuthed => "not_authed",
+            OpenError::InvalidAuth => "invalid_auth",
+            OpenError::AccountInactive => "account_inactive",
+            OpenError::InvalidArgName => "invalid_arg_name",
+            OpenError::InvalidArrayArg => "invalid_array_arg",
+            OpenError::InvalidCharset => "invalid_charset",
+            OpenError::InvalidFormData => "invalid_form_data",
+            OpenError::InvalidPostType => "invalid_post_type",
+            OpenError::MissingPostType => "missing_post_type",
+            OpenError::TeamAddedToOrg => "team_added_to_org",
+            OpenError::RequestTimeout => "request_timeout",
+            OpenError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            OpenError::Unknown(code) => return ::Error::from_code("views.open", &code),
+            OpenError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("views.open", code)
+    }
+}
+
+impl<E: Error> fmt::Display for OpenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for OpenError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &OpenError::ExpiredTriggerId => "expired_trigger_id: The trigger_id was used more than three seconds after the interaction.",
+            &OpenError::ExchangedTriggerId => "exchanged_trigger_id: The trigger_id was already exchanged for a view.",
+            &OpenError::InvalidTriggerId => "invalid_trigger_id: The trigger_id is not valid.",
+            &OpenError::InvalidArguments => "invalid_arguments: The view was not valid, e.g. a required field is missing or a block is not allowed in this view.",
+            &OpenError::ViewTooLarge => "view_too_large: The view is larger than 250kb.",
+            &OpenError::DuplicateExternalId => "duplicate_external_id: The external_id is already used by another view of the app.",
+            &OpenError::NotAuthed => "not_authed: No authentication token provided.",
+            &OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &OpenError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &OpenError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &OpenError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &OpenError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &OpenError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &OpenError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &OpenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &OpenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &OpenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &OpenError::MalformedResponse(ref e) => e.description(),
+            &OpenError::Unknown(ref s) => s,
+            &OpenError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &OpenError::MalformedResponse(ref e) => Some(e),
+            &OpenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Publish a static view for a User.
+///
+/// Wraps https://api.slack.com/methods/views.publish
+
+pub fn publish<R>(client: &R,
+                  token: &str,
+                  request: &PublishRequest)
+                  -> Result<PublishResponse, PublishError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(hash) = request.hash {
+        body.insert("hash".to_owned(), hash.into());
+    }
+    body.insert("user_id".to_owned(), request.user_id.into());
+    body.insert("view".to_owned(), ::json_param(request.view));
+    let url = ::get_slack_url_for_method("views.publish");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| PublishError::Client(err))
+        .and_then(|result| {
+                      parse_publish_response(result.body.as_bytes()).map_err(|err| err.into_error(PublishError::MalformedResponse))
+                  })
+}
+
+fn parse_publish_response(body: &[u8]) -> Result<PublishResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<PublishResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct PublishRequest<'a> {
+    /// A string that represents view state to protect against possible race conditions.
+    pub hash: Option<&'a str>,
+    /// id of the user you want publish a view to.
+    pub user_id: &'a str,
+    /// A view payload, as JSON. See blocks::View for building one.
+    pub view: &'a str,
+}
+
+impl<'a> PublishRequest<'a> {
+    pub fn new(user_id: &'a str, view: &'a str) -> Self {
+        PublishRequest {
+            user_id: user_id,
+            view: view,
+            ..PublishRequest::default()
+        }
+    }
+
+    pub fn hash(mut self, hash: &'a str) -> Self {
+        self.hash = Some(hash);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PublishResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub view: Option<::View>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<PublishResponse, PublishError<E>>> for PublishResponse {
+    fn into(self) -> Result<PublishResponse, PublishError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PublishError<E: Error> {
+    /// The view was not valid, e.g. a required field is missing or a block is not allowed in this view.
+    InvalidArguments,
+    /// The view is larger than 250kb.
+    ViewTooLarge,
+    /// The view was updated since the hash was read.
+    HashConflict,
+    /// The external_id is already used by another view of the app.
+    DuplicateExternalId,
+    /// The Home tab is not enabled for the app.
+    NotEnabled,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PublishError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_arguments" => PublishError::InvalidArguments,
+            "view_too_large" => PublishError::ViewTooLarge,
+            "hash_conflict" => PublishError::HashConflict,
+            "duplicate_external_id" => PublishError::DuplicateExternalId,
+            "not_enabled" => PublishError::NotEnabled,
+            "not_authed" => PublishError::NotAuthed,
+            "invalid_auth" => PublishError::InvalidAuth,
+            "account_inactive" => PublishError::AccountInactive,
+            "invalid_arg_name" => PublishError::InvalidArgName,
+            "invalid_array_arg" => PublishError::InvalidArrayArg,
+            "invalid_charset" => PublishError::InvalidCharset,
+            "invalid_form_data" => PublishError::InvalidFormData,
+            "invalid_post_type" => PublishError::InvalidPostType,
+            "missing_post_type" => PublishError::MissingPostType,
+            "team_added_to_org" => PublishError::TeamAddedToOrg,
+            "request_timeout" => PublishError::RequestTimeout,
+            _ => PublishError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<PublishError<E>> for ::Error<E> {
+    fn from(err: PublishError<E>) -> Self {
+        let code = match err {
+            PublishError::InvalidArguments => "invalid_arguments",
+            PublishError::ViewTooLarge => "view_too_large",
+            PublishError::HashConflict => "hash_conflict",
+            PublishError::DuplicateExternalId => "duplicate_external_id",
+            PublishError::NotEnabled => "not_enabled",
+            PublishError::NotAuthed => "not_authed",
+            PublishError::InvalidAuth => "invalid_auth",
+            PublishError::AccountInactive => "account_inactive",
+            PublishError::InvalidArgName => "invalid_arg_name",
+            PublishError::InvalidArrayArg => "invalid_array_arg",
+            PublishError::InvalidCharset => "invalid_charset",
+            PublishError::InvalidFormData => "invalid_form_data",
+            PublishError::InvalidPostType => "invalid_post_type",
+            PublishError::MissingPostType => "missing_post_type",
+            PublishError::TeamAddedToOrg => "team_added_to_org",
+            PublishError::RequestTimeout => "request_timeout",
+            PublishError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            PublishError::Unknown(code) => return ::Error::from_code("views.publish", &code),
+            PublishError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("views.publish", code)
+    }
+}
+
+impl<E: Error> fmt::Display for PublishError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for PublishError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &PublishError::InvalidArguments => "invalid_arguments: The view was not valid, e.g. a required field is missing or a block is not allowed in this view.",
+            &PublishError::ViewTooLarge => "view_too_large: The view is larger than 250kb.",
+            &PublishError::HashConflict => "hash_conflict: The view was updated since the hash was read.",
+            &PublishError::DuplicateExternalId => "duplicate_external_id: The external_id is already used by another view of the app.",
+            &PublishError::NotEnabled => "not_enabled: The Home tab is not enabled for the app.",
+            &PublishError::NotAuthed => "not_authed: No authentication token provided.",
+            &PublishError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &PublishError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &PublishError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &PublishError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &PublishError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &PublishError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &PublishError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &PublishError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &PublishError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &PublishError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &PublishError::MalformedResponse(ref e) => e.description(),
+            &PublishError::Unknown(ref s) => s,
+            &PublishError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &PublishError::MalformedResponse(ref e) => Some(e),
+            &PublishError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Push a view onto the stack of a root view.
+///
+/// Wraps https://api.slack.com/methods/views.push
+
+pub fn push<R>(client: &R,
+               token: &str,
+               request: &PushRequest)
+               -> Result<PushResponse, PushError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(interactivity_pointer) = request.interactivity_pointer {
+        body.insert("interactivity_pointer".to_owned(), interactivity_pointer.into());
+    }
+    if let Some(trigger_id) = request.trigger_id {
+        body.insert("trigger_id".to_owned(), trigger_id.into());
+    }
+    body.insert("view".to_owned(), ::json_param(request.view));
+    let url = ::get_slack_url_for_method("views.push");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| PushError::Client(err))
+        .and_then(|result| {
+                      parse_push_response(result.body.as_bytes()).map_err(|err| err.into_error(PushError::MalformedResponse))
+                  })
+}
+
+fn parse_push_response(body: &[u8]) -> Result<PushResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<PushResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct PushRequest<'a> {
+    /// Exchange an interactivity pointer for the view, instead of a trigger_id.
+    pub interactivity_pointer: Option<&'a str>,
+    /// Exchange a trigger to post to the user, from an interaction payload. Either this or interactivity_pointer is required.
+    pub trigger_id: Option<&'a str>,
+    /// A view payload, as JSON. See blocks::View for building one.
+    pub view: &'a str,
+}
+
+impl<'a> PushRequest<'a> {
+    pub fn new(view: &'a str) -> Self {
+        PushRequest {
+            view: view,
+            ..PushRequest::default()
+        }
+    }
+
+    pub fn interactivity_pointer(mut self, interactivity_pointer: &'a str) -> Self {
+        self.interactivity_pointer = Some(interactivity_pointer);
+        self
+    }
+
+    pub fn trigger_id(mut self, trigger_id: &'a str) -> Self {
+        self.trigger_id = Some(trigger_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PushResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub view: Option<::View>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<PushResponse, PushError<E>>> for PushResponse {
+    fn into(self) -> Result<PushResponse, PushError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PushError<E: Error> {
+    /// The trigger_id was used more than three seconds after the interaction.
+    ExpiredTriggerId,
+    /// The trigger_id was already exchanged for a view.
+    ExchangedTriggerId,
+    /// The trigger_id is not valid.
+    InvalidTriggerId,
+    /// The view was not valid, e.g. a required field is missing or a block is not allowed in this view.
+    InvalidArguments,
+    /// The view is larger than 250kb.
+    ViewTooLarge,
+    /// The external_id is already used by another view of the app.
+    DuplicateExternalId,
+    /// There are already three views on the stack.
+    PushLimitReached,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PushError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "expired_trigger_id" => PushError::ExpiredTriggerId,
+            "exchanged_trigger_id" => PushError::ExchangedTriggerId,
+            "invalid_trigger_id" => PushError::InvalidTriggerId,
+            "invalid_arguments" => PushError::InvalidArguments,
+            "view_too_large" => PushError::ViewTooLarge,
+            "duplicate_external_id" => PushError::DuplicateExternalId,
+            "push_limit_reached" => PushError::PushLimitReached,
+            "not_authed" => PushError::NotAuthed,
+            "invalid_auth" => PushError::InvalidAuth,
+            "account_inactive" => PushError::AccountInactive,
+            "invalid_arg_name" => PushError::InvalidArgName,
+            "invalid_array_arg" => PushError::InvalidArrayArg,
+            "invalid_charset" => PushError::InvalidCharset,
+            "invalid_form_data" => PushError::InvalidFormData,
+            "invalid_post_type" => PushError::InvalidPostType,
+            "missing_post_type" => PushError::MissingPostType,
+            "team_added_to_org" => PushError::TeamAddedToOrg,
+            "request_timeout" => PushError::RequestTimeout,
+            _ => PushError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<PushError<E>> for ::Error<E> {
+    fn from(err: PushError<E>) -> Self {
+        let code = match err {
+            PushError::ExpiredTriggerId => "expired_trigger_id",
+            PushError::ExchangedTriggerId => "exchanged_trigger_id",
+            PushError::InvalidTriggerId => "invalid_trigger_id",
+            PushError::InvalidArguments => "invalid_arguments",
+            PushError::ViewTooLarge => "view_too_large",
+            PushError::DuplicateExternalId => "duplicate_external_id",
+            PushError::PushLimitReached => "push_limit_reached",
+            PushError::NotAuthed => "not_authed",
+            PushError::InvalidAuth => "invalid_auth",
+            PushError::AccountInactive => "account_inactive",
+            PushError::InvalidArgName => "invalid_arg_name",
+            PushError::InvalidArrayArg => "invalid_array_arg",
+            PushError::InvalidCharset => "invalid_charset",
+            PushError::InvalidFormData => "invalid_form_data",
+            PushError::InvalidPostType => "invalid_post_type",
+            PushError::MissingPostType => "missing_post_type",
+            PushError::TeamAddedToOrg => "team_added_to_org",
+            PushError::RequestTimeout => "request_timeout",
+            PushError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            PushError::Unknown(code) => return ::Error::from_code("views.push", &code),
+            PushError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("views.push", code)
+    }
+}
+
+impl<E: Error> fmt::Display for PushError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for PushError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &PushError::ExpiredTriggerId => "expired_trigger_id: The trigger_id was used more than three seconds after the interaction.",
+            &PushError::ExchangedTriggerId => "exchanged_trigger_id: The trigger_id was already exchanged for a view.",
+            &PushError::InvalidTriggerId => "invalid_trigger_id: The trigger_id is not valid.",
+            &PushError::InvalidArguments => "invalid_arguments: The view was not valid, e.g. a required field is missing or a block is not allowed in this view.",
+            &PushError::ViewTooLarge => "view_too_large: The view is larger than 250kb.",
+            &PushError::DuplicateExternalId => "duplicate_external_id: The external_id is already used by another view of the app.",
+            &PushError::PushLimitReached => "push_limit_reached: There are already three views on the stack.",
+            &PushError::NotAuthed => "not_authed: No authentication token provided.",
+            &PushError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &PushError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &PushError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &PushError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &PushError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &PushError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &PushError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &PushError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &PushError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &PushError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &PushError::MalformedResponse(ref e) => e.description(),
+            &PushError::Unknown(ref s) => s,
+            &PushError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &PushError::MalformedResponse(ref e) => Some(e),
+            &PushError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Update an existing view.
+///
+/// Wraps https://api.slack.com/methods/views.update
+
+pub fn update<R>(client: &R,
+                 token: &str,
+                 request: &UpdateRequest)
+                 -> Result<UpdateResponse, UpdateError<R::Error>>
+    where R: SlackWebRequestSender
+{
+
+    let mut body = ::serde_json::Map::new();
+    if let Some(external_id) = request.external_id {
+        body.insert("external_id".to_owned(), external_id.into());
+    }
+    if let Some(hash) = request.hash {
+        body.insert("hash".to_owned(), hash.into());
+    }
+    body.insert("view".to_owned(), ::json_param(request.view));
+    if let Some(view_id) = request.view_id {
+        body.insert("view_id".to_owned(), view_id.into());
+    }
+    let url = ::get_slack_url_for_method("views.update");
+    ::requests::with_buffer(|buffer| {
+            let body = buffer.json(&body).expect("a JSON object always serializes");
+            client.send_json(&url, token, body)
+        })
+        .map_err(|err| UpdateError::Client(err))
+        .and_then(|result| {
+                      parse_update_response(result.body.as_bytes()).map_err(|err| err.into_error(UpdateError::MalformedResponse))
+                  })
+}
+
+fn parse_update_response(body: &[u8]) -> Result<UpdateResponse, ::ResponseError> {
+    let response = serde_json::from_slice::<UpdateResponse>(body).map_err(::ResponseError::Malformed)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(::ResponseError::Api(response.error.unwrap_or_default()))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UpdateRequest<'a> {
+    /// A unique identifier of the view set by the developer. Either view_id or external_id is required.
+    pub external_id: Option<&'a str>,
+    /// A string that represents view state to protect against possible race conditions.
+    pub hash: Option<&'a str>,
+    /// A view payload, as JSON. See blocks::View for building one.
+    pub view: &'a str,
+    /// A unique identifier of the view to be updated. Either view_id or external_id is required.
+    pub view_id: Option<&'a str>,
+}
+
+impl<'a> UpdateRequest<'a> {
+    pub fn new(view: &'a str) -> Self {
+        UpdateRequest {
+            view: view,
+            ..UpdateRequest::default()
+        }
+    }
+
+    pub fn external_id(mut self, external_id: &'a str) -> Self {
+        self.external_id = Some(external_id);
+        self
+    }
+
+    pub fn hash(mut self, hash: &'a str) -> Self {
+        self.hash = Some(hash);
+        self
+    }
+
+    pub fn view_id(mut self, view_id: &'a str) -> Self {
+        self.view_id = Some(view_id);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::requests::ResponseMetadata>,
+    pub view: Option<::View>,
+    pub warning: Option<String>,
+}
+
+
+
+impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
+    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error
+                    .as_ref()
+                    .map(String::as_ref)
+                    .unwrap_or("")
+                    .into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UpdateError<E: Error> {
+    /// The view was not valid, e.g. a required field is missing or a block is not allowed in this view.
+    InvalidArguments,
+    /// The view is larger than 250kb.
+    ViewTooLarge,
+    /// The view was updated since the hash was read.
+    HashConflict,
+    /// No view with the given view_id or external_id exists.
+    NotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UpdateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_arguments" => UpdateError::InvalidArguments,
+            "view_too_large" => UpdateError::ViewTooLarge,
+            "hash_conflict" => UpdateError::HashConflict,
+            "not_found" => UpdateError::NotFound,
+            "not_authed" => UpdateError::NotAuthed,
+            "invalid_auth" => UpdateError::InvalidAuth,
+            "account_inactive" => UpdateError::AccountInactive,
+            "invalid_arg_name" => UpdateError::InvalidArgName,
+            "invalid_array_arg" => UpdateError::InvalidArrayArg,
+            "invalid_charset" => UpdateError::InvalidCharset,
+            "invalid_form_data" => UpdateError::InvalidFormData,
+            "invalid_post_type" => UpdateError::InvalidPostType,
+            "missing_post_type" => UpdateError::MissingPostType,
+            "team_added_to_org" => UpdateError::TeamAddedToOrg,
+            "request_timeout" => UpdateError::RequestTimeout,
+            _ => UpdateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> From<UpdateError<E>> for ::Error<E> {
+    fn from(err: UpdateError<E>) -> Self {
+        let code = match err {
+            UpdateError::InvalidArguments => "invalid_arguments",
+            UpdateError::ViewTooLarge => "view_too_large",
+            UpdateError::HashConflict => "hash_conflict",
+            UpdateError::NotFound => "not_found",
+            UpdateError::NotAuthed => "not_authed",
+            UpdateError::InvalidAuth => "invalid_auth",
+            UpdateError::AccountInactive => "account_inactive",
+            UpdateError::InvalidArgName => "invalid_arg_name",
+            UpdateError::InvalidArrayArg => "invalid_array_arg",
+            UpdateError::InvalidCharset => "invalid_charset",
+            UpdateError::InvalidFormData => "invalid_form_data",
+            UpdateError::InvalidPostType => "invalid_post_type",
+            UpdateError::MissingPostType => "missing_post_type",
+            UpdateError::TeamAddedToOrg => "team_added_to_org",
+            UpdateError::RequestTimeout => "request_timeout",
+            UpdateError::MalformedResponse(e) => return ::Error::MalformedResponse(e),
+            UpdateError::Unknown(code) => return ::Error::from_code("views.update", &code),
+            UpdateError::Client(inner) => return ::Error::Client(inner),
+        };
+        ::Error::from_code("views.update", code)
+    }
+}
+
+impl<E: Error> fmt::Display for UpdateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UpdateError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &UpdateError::InvalidArguments => "invalid_arguments: The view was not valid, e.g. a required field is missing or a block is not allowed in this view.",
+            &UpdateError::ViewTooLarge => "view_too_large: The view is larger than 250kb.",
+            &UpdateError::HashConflict => "hash_conflict: The view was updated since the hash was read.",
+            &UpdateError::NotFound => "not_found: No view with the given view_id or external_id exists.",
+            &UpdateError::NotAuthed => "not_authed: No authentication token provided.",
+            &UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            &UpdateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+            &UpdateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+            &UpdateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+            &UpdateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+            &UpdateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+            &UpdateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+            &UpdateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+            &UpdateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+            &UpdateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+            &UpdateError::MalformedResponse(ref e) => e.description(),
+            &UpdateError::Unknown(ref s) => s,
+            &UpdateError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &UpdateError::MalformedResponse(ref e) => Some(e),
+            &UpdateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `views.*` methods, bound to a client and a token, as returned by
+/// [`SlackClient::views`](../client/struct.SlackClient.html#method.views).
+#[derive(Debug)]
+pub struct Methods<'a, R: 'a> {
+    client: &'a R,
+    token: ::tokens::MethodToken<'a>,
+}
+
+impl<'a, R: SlackWebRequestSender> Methods<'a, R> {
+    pub fn new<T: Into<::tokens::MethodToken<'a>>>(client: &'a R, token: T) -> Self {
+        Methods {
+            client: client,
+            token: token.into(),
+        }
+    }
+
+    /// Open a view for a user. See [`open`](fn.open.html).
+    pub fn open(&self, request: &OpenRequest) -> Result<OpenResponse, OpenError<R::Error>> {
+        open(self.client, self.token.for_method("views.open"), request)
+    }
+
+    /// Publish a static view for a User. See [`publish`](fn.publish.html).
+    pub fn publish(&self, request: &PublishRequest) -> Result<PublishResponse, PublishError<R::Error>> {
+        publish(self.client, self.token.for_method("views.publish"), request)
+    }
+
+    /// Push a view onto the stack of a root view. See [`push`](fn.push.html).
+    pub fn push(&self, request: &PushRequest) -> Result<PushResponse, PushError<R::Error>> {
+        push(self.client, self.token.for_method("views.push"), request)
+    }
+
+    /// Update an existing view. See [`update`](fn.update.html).
+    pub fn update(&self, request: &UpdateRequest) -> Result<UpdateResponse, UpdateError<R::Error>> {
+        update(self.client, self.token.for_method("views.update"), request)
+    }
+}
@@ -0,0 +1,132 @@
+//! Signed, expiring `state` parameters for OAuth flows.
+//!
+//! The `state` parameter is what ties an OAuth redirect back to the browser that started the
+//! flow. A [`StateSigner`] produces values of the form `<timestamp>.<nonce>.<signature>`, where
+//! the signature is an HMAC-SHA256 over the timestamp and nonce, so they can be validated on the
+//! redirect without any server-side storage.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use base64;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Generates and validates OAuth `state` parameters.
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::oauth_state::StateSigner;
+/// let signer = StateSigner::new("signing secret");
+///
+/// let state = signer.generate();
+/// // ... redirect to Slack with `state`, and on the way back:
+/// assert!(signer.validate(&state).is_ok());
+/// ```
+#[derive(Clone)]
+pub struct StateSigner {
+    secret: Vec<u8>,
+    max_age: Duration,
+}
+
+impl StateSigner {
+    pub fn new<S: AsRef<[u8]>>(secret: S) -> Self {
+        StateSigner {
+            secret: secret.as_ref().to_vec(),
+            max_age: Duration::from_secs(10 * 60),
+        }
+    }
+
+    /// How long a generated state stays valid. Defaults to ten minutes.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Generates a new state parameter.
+    pub fn generate(&self) -> String {
+        self.generate_at(::unix_timestamp())
+    }
+
+    fn generate_at(&self, timestamp: u64) -> String {
+        let payload = format!("{}.{}", timestamp, ::random_string(16));
+        let signature = base64::encode_config(&self.mac(&payload).finalize().into_bytes(), base64::URL_SAFE_NO_PAD);
+        format!("{}.{}", payload, signature)
+    }
+
+    /// Checks that `state` was generated by this signer and has not expired.
+    pub fn validate(&self, state: &str) -> Result<(), StateError> {
+        let split = state.rfind('.').ok_or(StateError::Malformed)?;
+        let (payload, signature) = (&state[..split], &state[split + 1..]);
+        let signature = base64::decode_config(signature, base64::URL_SAFE_NO_PAD).map_err(|_| StateError::Malformed)?;
+        self.mac(payload).verify_slice(&signature).map_err(|_| StateError::BadSignature)?;
+
+        let timestamp = payload.split('.')
+            .next()
+            .and_then(|ts| ts.parse::<u64>().ok())
+            .ok_or(StateError::Malformed)?;
+        if timestamp + self.max_age.as_secs() < ::unix_timestamp() {
+            return Err(StateError::Expired);
+        }
+        Ok(())
+    }
+
+    fn mac(&self, payload: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+impl fmt::Debug for StateSigner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StateSigner").field("max_age", &self.max_age).finish()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum StateError {
+    /// The state was not in the format produced by [`StateSigner::generate`].
+    Malformed,
+    /// The state was not signed with this signer's secret, or was tampered with.
+    BadSignature,
+    /// The state is older than the signer's maximum age.
+    Expired,
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for StateError {
+    fn description(&self) -> &str {
+        match self {
+            &StateError::Malformed => "the OAuth state parameter is malformed",
+            &StateError::BadSignature => "the OAuth state parameter has an invalid signature",
+            &StateError::Expired => "the OAuth state parameter has expired",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_validation() {
+        let signer = StateSigner::new("secret");
+        let state = signer.generate();
+        assert_eq!(Ok(()), signer.validate(&state));
+
+        assert_eq!(Err(StateError::BadSignature), StateSigner::new("other").validate(&state));
+        assert_eq!(Err(StateError::BadSignature), signer.validate(&state.replacen('.', "1.", 1)));
+        assert_eq!(Err(StateError::Malformed), signer.validate("garbage"));
+
+        let stale = signer.generate_at(::unix_timestamp() - 11 * 60);
+        assert_eq!(Err(StateError::Expired), signer.validate(&stale));
+    }
+}
@@ -0,0 +1,462 @@
+//! Queueing messages while Slack cannot be reached, for bots that must not drop any.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use serde_json;
+
+use chat;
+use requests::SlackWebRequestSender;
+use resolve::Resolver;
+
+/// A message waiting in an [`Outbox`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QueuedMessage {
+    /// Identifies the message: a message is not queued again while one with the same key is
+    /// waiting. It is stamped in the metadata of the posted message, as by
+    /// [`idempotent::stamp`](../idempotent/fn.stamp.html).
+    pub key: String,
+    pub channel: String,
+    pub text: String,
+    pub attachments: Option<String>,
+    pub blocks: Option<String>,
+    pub thread_ts: Option<String>,
+    /// When the message was queued, in seconds since the Unix epoch.
+    pub queued_at: u64,
+    /// Whether an attempt to post it failed in a way that may have posted it anyway, e.g. the
+    /// connection dropped before Slack answered.
+    pub maybe_posted: bool,
+}
+
+impl QueuedMessage {
+    /// A message with a random key.
+    pub fn new<S: Into<String>, T: Into<String>>(channel: S, text: T) -> Self {
+        QueuedMessage {
+            key: ::random_string(16),
+            channel: channel.into(),
+            text: text.into(),
+            attachments: None,
+            blocks: None,
+            thread_ts: None,
+            queued_at: ::unix_timestamp(),
+            maybe_posted: false,
+        }
+    }
+
+    /// Sets the key, e.g. to the ID of the alert the message is about, so that an alert firing
+    /// again during an outage is only posted once.
+    pub fn key<S: Into<String>>(mut self, key: S) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    pub fn attachments<S: Into<String>>(mut self, attachments: S) -> Self {
+        self.attachments = Some(attachments.into());
+        self
+    }
+
+    pub fn blocks<S: Into<String>>(mut self, blocks: S) -> Self {
+        self.blocks = Some(blocks.into());
+        self
+    }
+
+    pub fn thread_ts<S: Into<String>>(mut self, thread_ts: S) -> Self {
+        self.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    /// The request posting the message, with its key stamped in `metadata`.
+    fn request<'a>(&'a self, metadata: &'a str) -> chat::PostMessageRequest<'a> {
+        chat::PostMessageRequest {
+            channel: &self.channel,
+            text: &self.text,
+            attachments: self.attachments.as_ref().map(String::as_str),
+            blocks: self.blocks.as_ref().map(String::as_str),
+            thread_ts: self.thread_ts.as_ref().map(String::as_str),
+            metadata: Some(metadata),
+            ..chat::PostMessageRequest::default()
+        }
+    }
+}
+
+/// Persistence for the queue of an [`Outbox`].
+///
+/// Implement this over your database to keep the queue elsewhere than in a file, which
+/// [`FileOutboxStore`] does.
+pub trait OutboxStore {
+    type Error: Error;
+
+    /// The queued messages, oldest first.
+    fn load(&self) -> Result<Vec<QueuedMessage>, Self::Error>;
+
+    /// Replaces the queue with `queue`. It must be saved durably before this returns, or messages
+    /// may be lost or posted twice.
+    fn save(&self, queue: &[QueuedMessage]) -> Result<(), Self::Error>;
+}
+
+/// Keeps the queue in a file, one JSON object per line.
+///
+/// The file is replaced atomically on every change, by writing the new queue next to it and
+/// renaming it over the old one.
+#[derive(Clone, Debug)]
+pub struct FileOutboxStore {
+    path: PathBuf,
+}
+
+impl FileOutboxStore {
+    /// The file need not exist yet: a missing file is an empty queue.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        FileOutboxStore { path: path.into() }
+    }
+}
+
+impl OutboxStore for FileOutboxStore {
+    type Error = io::Error;
+
+    fn load(&self) -> Result<Vec<QueuedMessage>, Self::Error> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut queue = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                queue.push(serde_json::from_str(&line).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?);
+            }
+        }
+        Ok(queue)
+    }
+
+    fn save(&self, queue: &[QueuedMessage]) -> Result<(), Self::Error> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        let temporary = PathBuf::from(temporary);
+        {
+            let mut file = File::create(&temporary)?;
+            for message in queue {
+                serde_json::to_writer(&mut file, message).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+                file.write_all(b"\n")?;
+            }
+            file.sync_all()?;
+        }
+        fs::rename(&temporary, &self.path)
+    }
+}
+
+impl<'a, S: OutboxStore> OutboxStore for &'a S {
+    type Error = S::Error;
+
+    fn load(&self) -> Result<Vec<QueuedMessage>, Self::Error> {
+        (**self).load()
+    }
+
+    fn save(&self, queue: &[QueuedMessage]) -> Result<(), Self::Error> {
+        (**self).save(queue)
+    }
+}
+
+/// What [`Outbox::post`] did with a message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Posted {
+    /// The message was posted, with this `ts`.
+    Sent(String),
+    /// The message was queued, either because Slack could not be reached or because older messages
+    /// are still waiting, and will be posted by [`Outbox::flush`].
+    Queued,
+}
+
+/// What [`Outbox::flush`] did.
+#[derive(Debug)]
+pub struct FlushReport<E: Error> {
+    /// How many queued messages were posted.
+    pub sent: usize,
+    /// The queued messages Slack refused, e.g. because their channel was archived in the
+    /// meantime. They are no longer queued.
+    pub rejected: Vec<(QueuedMessage, chat::PostMessageError<E>)>,
+    /// How many messages are still queued, because Slack still could not be reached.
+    pub remaining: usize,
+}
+
+/// Posts messages with `chat.postMessage`, queueing them in its [`OutboxStore`] when Slack cannot
+/// be reached instead of dropping them.
+///
+/// A message is queued when the call fails in a way that is likely to pass: the request did not
+/// get through, Slack answered with something that was not JSON, or it rate limited the call or
+/// reported an error of its own. Messages Slack refuses, e.g. for a channel that does not exist,
+/// are not queued but returned as errors.
+///
+/// Queued messages are posted by [`flush`](#method.flush), which should be called regularly, e.g.
+/// before every post and on a timer. They are posted in order, paced, and a message whose earlier
+/// attempt may have been posted after all is first looked for in its channel by its key, so that
+/// it is not posted twice.
+///
+/// The channel names it looks up to do so are cached, so an outbox should only be used with the
+/// tokens of one workspace.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::outbox::{FileOutboxStore, Outbox, QueuedMessage};
+/// let client = slack_api::requests::default_client().unwrap();
+/// let outbox = Outbox::new(FileOutboxStore::new("/var/lib/alertbot/outbox.jsonl"));
+///
+/// let message = QueuedMessage::new("C024BE91L", "Disk full on db-1").key("alert-4821");
+/// outbox.post(&client, "xoxb-...", message).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Outbox<S> {
+    store: S,
+    pace: Duration,
+    lock: Mutex<()>,
+    resolver: Resolver,
+}
+
+impl<S: OutboxStore> Outbox<S> {
+    pub fn new(store: S) -> Self {
+        Outbox {
+            store: store,
+            pace: Duration::from_secs(1),
+            lock: Mutex::new(()),
+            resolver: Resolver::new(),
+        }
+    }
+
+    /// How long to wait between two queued messages when flushing. Defaults to a second, which
+    /// keeps a flush within `chat.postMessage`'s rate limit.
+    pub fn pace(mut self, pace: Duration) -> Self {
+        self.pace = pace;
+        self
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Posts `message`, or queues it if Slack cannot be reached or older messages are waiting,
+    /// so that messages are posted in order.
+    pub fn post<R>(&self,
+                   client: &R,
+                   token: &str,
+                   mut message: QueuedMessage)
+                   -> Result<Posted, OutboxError<R::Error, S::Error>>
+        where R: SlackWebRequestSender
+    {
+        let _lock = self.lock.lock().unwrap();
+        let mut queue = self.store.load().map_err(OutboxError::Store)?;
+        if !queue.is_empty() {
+            if !queue.iter().any(|queued| queued.key == message.key) {
+                queue.push(message);
+                self.store.save(&queue).map_err(OutboxError::Store)?;
+            }
+            return Ok(Posted::Queued);
+        }
+
+        let metadata = ::idempotent::stamp(None, &message.key).expect("stamping no metadata always succeeds");
+        match chat::post_message(client, token, &message.request(&metadata)) {
+            Ok(response) => Ok(Posted::Sent(response.ts.unwrap_or_default())),
+            Err(err) => {
+                match failure(&err) {
+                    Failure::Refused => Err(OutboxError::Rejected(err)),
+                    failure => {
+                        message.maybe_posted = failure == Failure::MaybePosted;
+                        self.store.save(&[message]).map_err(OutboxError::Store)?;
+                        Ok(Posted::Queued)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Posts the queued messages, oldest first, until the queue is empty or Slack still cannot be
+    /// reached.
+    pub fn flush<R>(&self, client: &R, token: &str) -> Result<FlushReport<R::Error>, S::Error>
+        where R: SlackWebRequestSender
+    {
+        let _lock = self.lock.lock().unwrap();
+        let mut queue = self.store.load()?;
+        let mut report = FlushReport {
+            sent: 0,
+            rejected: Vec::new(),
+            remaining: 0,
+        };
+        let mut attempts = 0;
+        while !queue.is_empty() {
+            if attempts > 0 {
+                thread::sleep(self.pace);
+            }
+            attempts += 1;
+
+            if queue[0].maybe_posted {
+                match already_posted(client, token, &self.resolver, &queue[0]) {
+                    Some(true) => {
+                        queue.remove(0);
+                        self.store.save(&queue)?;
+                        report.sent += 1;
+                        continue;
+                    }
+                    Some(false) => {}
+                    None => break,
+                }
+            }
+
+            let metadata = ::idempotent::stamp(None, &queue[0].key).expect("stamping no metadata always succeeds");
+            match chat::post_message(client, token, &queue[0].request(&metadata)) {
+                Ok(_) => {
+                    queue.remove(0);
+                    report.sent += 1;
+                }
+                Err(err) => {
+                    match failure(&err) {
+                        Failure::Refused => report.rejected.push((queue.remove(0), err)),
+                        Failure::MaybePosted => {
+                            queue[0].maybe_posted = true;
+                            self.store.save(&queue)?;
+                            break;
+                        }
+                        Failure::NotPosted => break,
+                    }
+                }
+            }
+            self.store.save(&queue)?;
+        }
+        report.remaining = queue.len();
+        Ok(report)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Failure {
+    /// Slack refused the message: posting it again would fail again.
+    Refused,
+    /// The call failed before Slack posted the message.
+    NotPosted,
+    /// The call failed without knowing whether Slack posted the message.
+    MaybePosted,
+}
+
+fn failure<E: Error>(err: &chat::PostMessageError<E>) -> Failure {
+    if err.may_have_posted() {
+        Failure::MaybePosted
+    } else if err.is_transient() {
+        Failure::NotPosted
+    } else {
+        Failure::Refused
+    }
+}
+
+/// Whether `message` is in its channel already, or `None` if that could not be checked.
+///
+/// A channel name that is not found is taken for a message that was not posted: posting it again
+/// gets it rejected.
+fn already_posted<R>(client: &R, token: &str, resolver: &Resolver, message: &QueuedMessage) -> Option<bool>
+    where R: SlackWebRequestSender
+{
+    let channel = match ::idempotent::conversation_id(client, token, resolver, &message.channel) {
+        Ok(Some(channel)) => channel,
+        Ok(None) => return Some(false),
+        Err(_) => return None,
+    };
+    let thread_ts = message.thread_ts.as_ref().map(String::as_str);
+    ::idempotent::find(client, token, &channel, thread_ts, &message.key, message.queued_at)
+        .ok()
+        .map(|found| found.is_some())
+}
+
+#[derive(Debug)]
+pub enum OutboxError<E: Error, S: Error> {
+    /// Slack refused the message, so it was not queued.
+    Rejected(chat::PostMessageError<E>),
+    /// Reading or saving the queue failed.
+    Store(S),
+}
+
+impl<E: Error, S: Error> fmt::Display for OutboxError<E, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error, S: Error> Error for OutboxError<E, S> {
+    fn description(&self) -> &str {
+        match self {
+            &OutboxError::Rejected(ref inner) => inner.description(),
+            &OutboxError::Store(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &OutboxError::Rejected(ref inner) => Some(inner),
+            &OutboxError::Store(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_queue_and_flush() {
+        let path = env::temp_dir().join(format!("slack-api-outbox-{}.jsonl", ::random_string(8)));
+        let outbox = Outbox::new(FileOutboxStore::new(path.clone())).pace(Duration::from_millis(0));
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").param("text", "one").fails("connection reset").times(1))
+            .expect(Expectation::new("chat.postMessage").param("text", "gone").returns_error("is_archived"))
+            .expect(Expectation::new("conversations.history").param("channel", "C1")
+                .returns(r#"{"ok": true, "messages": [{"type": "message", "ts": "2.0", "text": "one",
+                    "metadata": {"event_type": "slack_api_idempotency", "event_payload": {"idempotency_key": "alert-1"}}}]}"#))
+            .expect(Expectation::new("chat.postMessage").returns(r#"{"ok": true, "ts": "3.0"}"#));
+
+        assert_eq!(Posted::Queued, outbox.post(&mock, "xoxb-1", QueuedMessage::new("C1", "one").key("alert-1")).unwrap());
+        assert_eq!(Posted::Queued, outbox.post(&mock, "xoxb-1", QueuedMessage::new("C2", "gone")).unwrap());
+        let two = QueuedMessage::new("C1", "two").key("alert-2");
+        assert_eq!(Posted::Queued, outbox.post(&mock, "xoxb-1", two.clone()).unwrap());
+        assert_eq!(Posted::Queued, outbox.post(&mock, "xoxb-1", two).unwrap());
+        assert_eq!(3, outbox.store().load().unwrap().len());
+
+        // "one" may have been posted when the connection dropped, and it was.
+        let report = outbox.flush(&mock, "xoxb-1").unwrap();
+        assert_eq!((2, 1, 0), (report.sent, report.rejected.len(), report.remaining));
+        assert_eq!("gone", report.rejected[0].0.text);
+        let posted: Vec<_> = mock.calls_of("chat.postMessage").iter().map(|c| c.param("text").unwrap().to_owned()).collect();
+        assert_eq!(vec!["one", "gone", "two"], posted);
+        assert!(outbox.store().load().unwrap().is_empty());
+
+        assert_eq!(Posted::Sent("3.0".to_owned()), outbox.post(&mock, "xoxb-1", QueuedMessage::new("C1", "three")).unwrap());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_flush_finds_message_posted_to_channel_name() {
+        let path = env::temp_dir().join(format!("slack-api-outbox-{}.jsonl", ::random_string(8)));
+        let outbox = Outbox::new(FileOutboxStore::new(path.clone())).pace(Duration::from_millis(0));
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").fails("connection reset").times(1))
+            .expect(Expectation::new("conversations.list")
+                .returns(r#"{"ok": true, "channels": [{"id": "C9", "name": "alerts"}]}"#))
+            .expect(Expectation::new("conversations.history").param("channel", "C9")
+                .returns(r#"{"ok": true, "messages": [{"type": "message", "ts": "2.0", "text": "disk full",
+                    "metadata": {"event_type": "slack_api_idempotency", "event_payload": {"idempotency_key": "alert-1"}}}]}"#));
+
+        let message = QueuedMessage::new("#alerts", "disk full").key("alert-1");
+        assert_eq!(Posted::Queued, outbox.post(&mock, "xoxb-1", message).unwrap());
+        let report = outbox.flush(&mock, "xoxb-1").unwrap();
+        assert_eq!((1, 0), (report.sent, report.remaining));
+        assert_eq!(1, mock.calls_of("chat.postMessage").len());
+        assert!(outbox.store().load().unwrap().is_empty());
+        fs::remove_file(&path).unwrap();
+    }
+}
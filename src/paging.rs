@@ -0,0 +1,284 @@
+//! Following the numbered pages of the older methods, which page with `page` and `count` and
+//! describe where they are in a `paging` object rather than with a cursor.
+
+use files;
+use reactions;
+use search;
+use stars;
+use team;
+use {File, FileComment, Item, Message, Paging};
+
+impl Paging {
+    /// The number of the page after this one, or `None` if this is the last.
+    pub fn next_page(&self) -> Option<u32> {
+        let page = self.page.unwrap_or(1);
+        match self.pages {
+            Some(pages) if page < pages && page > 0 => Some(page as u32 + 1),
+            _ => None,
+        }
+    }
+}
+
+/// A request for one of the numbered pages of a method's results.
+pub trait PageRequest {
+    /// Asks for the page numbered `page`, counting from 1.
+    fn set_page(&mut self, page: u32);
+}
+
+/// A numbered page of a method's results.
+pub trait PageResponse {
+    type Item;
+
+    /// Where the page is among the others.
+    fn paging(&self) -> Option<&Paging>;
+
+    /// The results on the page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// The pages of a method's results, as returned by [`pages`].
+pub struct Pages<Q, F> {
+    request: Q,
+    fetch: F,
+    done: bool,
+}
+
+/// Fetches the pages of the results of `request` one after the other, from `request.page` (the
+/// first one by default) to the last one, calling `fetch` for each.
+///
+/// The pages end after the first error.
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+/// let request = slack_api::files::ListRequest { count: Some(100), ..Default::default() };
+///
+/// for page in slack_api::paging::pages(&request, |r| slack_api::files::list(&client, "xoxp-...", r)) {
+///     for file in page.unwrap().files.unwrap_or_default() {
+///         println!("{:?}", file.name);
+///     }
+/// }
+/// ```
+pub fn pages<Q, F, T, E>(request: &Q, fetch: F) -> Pages<Q, F>
+    where Q: PageRequest + Clone,
+          F: FnMut(&Q) -> Result<T, E>,
+          T: PageResponse
+{
+    Pages {
+        request: request.clone(),
+        fetch: fetch,
+        done: false,
+    }
+}
+
+/// Fetches every page of the results of `request`, and returns their results together.
+pub fn all<Q, F, T, E>(request: &Q, fetch: F) -> Result<Vec<T::Item>, E>
+    where Q: PageRequest + Clone,
+          F: FnMut(&Q) -> Result<T, E>,
+          T: PageResponse
+{
+    let mut items = Vec::new();
+    for page in pages(request, fetch) {
+        items.extend(page?.into_items());
+    }
+    Ok(items)
+}
+
+impl<Q, F, T, E> Iterator for Pages<Q, F>
+    where Q: PageRequest,
+          F: FnMut(&Q) -> Result<T, E>,
+          T: PageResponse
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let page = (self.fetch)(&self.request);
+        match page.as_ref().ok().and_then(|page| page.paging()).and_then(Paging::next_page) {
+            Some(next) => self.request.set_page(next),
+            None => self.done = true,
+        }
+        Some(page)
+    }
+}
+
+impl<'a> PageRequest for files::InfoRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl PageResponse for files::InfoResponse {
+    type Item = FileComment;
+
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<FileComment> {
+        self.comments.unwrap_or_default()
+    }
+}
+
+impl<'a> PageRequest for files::ListRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl PageResponse for files::ListResponse {
+    type Item = File;
+
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<File> {
+        self.files.unwrap_or_default()
+    }
+}
+
+impl<'a> PageRequest for reactions::ListRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl PageResponse for reactions::ListResponse {
+    type Item = Item;
+
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<Item> {
+        self.items.unwrap_or_default()
+    }
+}
+
+impl<'a> PageRequest for search::FilesRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl PageResponse for search::FilesResponse {
+    type Item = File;
+
+    fn paging(&self) -> Option<&Paging> {
+        self.files.as_ref().and_then(|files| files.paging.as_ref())
+    }
+
+    fn into_items(self) -> Vec<File> {
+        self.files.and_then(|files| files.matches).unwrap_or_default()
+    }
+}
+
+impl<'a> PageRequest for search::MessagesRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl PageResponse for search::MessagesResponse {
+    type Item = Message;
+
+    fn paging(&self) -> Option<&Paging> {
+        self.messages.as_ref().and_then(|messages| messages.paging.as_ref())
+    }
+
+    fn into_items(self) -> Vec<Message> {
+        self.messages.and_then(|messages| messages.matches).unwrap_or_default()
+    }
+}
+
+impl PageRequest for stars::ListRequest {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl PageResponse for stars::ListResponse {
+    type Item = stars::ListResponseItem;
+
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<stars::ListResponseItem> {
+        self.items.unwrap_or_default()
+    }
+}
+
+impl PageRequest for team::AccessLogsRequest {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl PageResponse for team::AccessLogsResponse {
+    type Item = team::AccessLogsResponseLogin;
+
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<team::AccessLogsResponseLogin> {
+        self.logins.unwrap_or_default()
+    }
+}
+
+impl<'a> PageRequest for team::IntegrationLogsRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl PageResponse for team::IntegrationLogsResponse {
+    type Item = team::IntegrationLogsResponseLog;
+
+    fn paging(&self) -> Option<&Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<team::IntegrationLogsResponseLog> {
+        self.logs.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_all_pages() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("files.list").param("page", "2")
+                .returns(r#"{"ok": true, "files": [{"id": "F3"}], "paging": {"count": 2, "total": 3, "page": 2, "pages": 2}}"#))
+            .expect(Expectation::new("files.list")
+                .returns(r#"{"ok": true, "files": [{"id": "F1"}, {"id": "F2"}],
+                    "paging": {"count": 2, "total": 3, "page": 1, "pages": 2}}"#));
+        let request = files::ListRequest { count: Some(2), ..Default::default() };
+
+        let files = all(&request, |r| files::list(&mock, "xoxp-1", r)).unwrap();
+
+        let ids: Vec<_> = files.iter().map(|f| f.id.as_ref().unwrap().as_str()).collect();
+        assert_eq!(vec!["F1", "F2", "F3"], ids);
+        assert_eq!(vec![None, Some("2")], mock.calls().iter().map(|c| c.param("page")).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_pages_end_after_error() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("team.accessLogs").returns_error("paid_only"));
+
+        let mut pages = pages(&team::AccessLogsRequest::default(), |r| team::access_logs(&mock, "xoxp-1", r));
+        assert!(pages.next().unwrap().is_err());
+        assert!(pages.next().is_none());
+    }
+}
@@ -0,0 +1,189 @@
+//! Finding the message a permalink points to.
+
+use std::error::Error;
+use std::fmt;
+
+use url::Url;
+
+use conversations;
+use requests::SlackWebRequestSender;
+use Message;
+
+/// Where a message is, as given by its permalink, e.g.
+/// `https://example.slack.com/archives/C024BE91L/p1503435956000247`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Permalink {
+    pub channel: String,
+    pub ts: String,
+    /// The timestamp of the thread's parent, if the message is a reply in a thread.
+    pub thread_ts: Option<String>,
+}
+
+impl Permalink {
+    /// Reads the channel and timestamp from a permalink, as copied from Slack or returned by
+    /// `chat.getPermalink`. Returns `None` if `link` is not a permalink to a message.
+    pub fn parse(link: &str) -> Option<Permalink> {
+        let url = Url::parse(link.trim().trim_start_matches('<').trim_end_matches('>')).ok()?;
+        let mut segments = url.path_segments()?.filter(|segment| !segment.is_empty());
+        if segments.next() != Some("archives") {
+            return None;
+        }
+        let channel = segments.next()?;
+        let ts = ts_from_path(segments.next()?)?;
+        if segments.next().is_some() {
+            return None;
+        }
+        let thread_ts = url.query_pairs()
+            .find(|&(ref name, _)| name == "thread_ts")
+            .map(|(_, value)| value.into_owned())
+            .filter(|thread_ts| *thread_ts != ts);
+        Some(Permalink {
+                 channel: channel.to_owned(),
+                 ts: ts,
+                 thread_ts: thread_ts,
+             })
+    }
+
+    /// Fetches the message, or `None` if it is not there anymore.
+    ///
+    /// Replies are fetched with `conversations.replies`, other messages with
+    /// `conversations.history`, asking for the message's timestamp only.
+    pub fn fetch<R>(&self, client: &R, token: &str) -> Result<Option<Message>, PermalinkError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let messages = match self.thread_ts {
+            Some(ref thread_ts) => {
+                let request = conversations::RepliesRequest {
+                    channel: &self.channel,
+                    ts: thread_ts,
+                    latest: Some(&self.ts),
+                    oldest: Some(&self.ts),
+                    inclusive: Some(true),
+                    ..conversations::RepliesRequest::default()
+                };
+                conversations::replies(client, token, &request).map_err(PermalinkError::Replies)?.messages
+            }
+            None => {
+                let request = conversations::HistoryRequest {
+                    channel: &self.channel,
+                    latest: Some(&self.ts),
+                    oldest: Some(&self.ts),
+                    inclusive: Some(true),
+                    limit: Some(1),
+                    ..conversations::HistoryRequest::default()
+                };
+                conversations::history(client, token, &request).map_err(PermalinkError::History)?.messages
+            }
+        };
+        // Replies always start with the thread's parent, which may not be the message linked to.
+        Ok(messages.unwrap_or_default().into_iter().find(|message| message.ts() == Some(&self.ts[..])))
+    }
+}
+
+/// The `ts` of a permalink's last segment, `p` followed by the timestamp without its dot.
+fn ts_from_path(segment: &str) -> Option<String> {
+    if !segment.starts_with('p') {
+        return None;
+    }
+    let digits = &segment[1..];
+    if digits.len() <= 6 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (seconds, micros) = digits.split_at(digits.len() - 6);
+    Some(format!("{}.{}", seconds, micros))
+}
+
+/// Fetches the message `link` points to, or `None` if it is not there anymore.
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+/// let link = "https://example.slack.com/archives/C024BE91L/p1503435956000247";
+///
+/// if let Some(message) = slack_api::permalink::resolve(&client, "xoxb-...", link).unwrap() {
+///     println!("{:?}", message);
+/// }
+/// ```
+pub fn resolve<R>(client: &R, token: &str, link: &str) -> Result<Option<Message>, PermalinkError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    Permalink::parse(link).ok_or(PermalinkError::InvalidLink)?.fetch(client, token)
+}
+
+#[derive(Debug)]
+pub enum PermalinkError<E: Error> {
+    /// The link is not a permalink to a message.
+    InvalidLink,
+    /// Fetching a message outside a thread failed.
+    History(conversations::HistoryError<E>),
+    /// Fetching a reply in a thread failed.
+    Replies(conversations::RepliesError<E>),
+}
+
+impl<E: Error> fmt::Display for PermalinkError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for PermalinkError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &PermalinkError::InvalidLink => "The link is not a permalink to a message.",
+            &PermalinkError::History(ref inner) => inner.description(),
+            &PermalinkError::Replies(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &PermalinkError::InvalidLink => None,
+            &PermalinkError::History(ref inner) => Some(inner),
+            &PermalinkError::Replies(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Some(Permalink {
+                            channel: "C1".to_owned(),
+                            ts: "1503435956.000247".to_owned(),
+                            thread_ts: None,
+                        }),
+                   Permalink::parse("https://example.slack.com/archives/C1/p1503435956000247"));
+        let reply = Permalink::parse("<https://example.slack.com/archives/C1/p1503435957000100?thread_ts=1503435956.000247&cid=C1>");
+        assert_eq!(Some("1503435956.000247"), reply.unwrap().thread_ts.as_ref().map(String::as_str));
+        let parent = Permalink::parse("https://example.slack.com/archives/C1/p1503435956000247?thread_ts=1503435956.000247");
+        assert_eq!(None, parent.unwrap().thread_ts);
+        assert_eq!(None, Permalink::parse("https://example.slack.com/archives/C1"));
+        assert_eq!(None, Permalink::parse("https://example.slack.com/files/U1/F1/report.pdf"));
+        assert_eq!(None, Permalink::parse("not a link"));
+    }
+
+    #[test]
+    fn test_resolve_reply() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.replies").param("ts", "1.000001").param("latest", "2.000002")
+            .returns(r#"{"ok": true, "messages": [
+                {"type": "message", "ts": "1.000001", "text": "parent"},
+                {"type": "message", "ts": "2.000002", "text": "reply"}]}"#));
+
+        let message = resolve(&mock, "xoxb-1", "https://x.slack.com/archives/C1/p2000002?thread_ts=1.000001").unwrap();
+        match message {
+            Some(Message::Standard(ref m)) => assert_eq!(Some("reply"), m.text.as_ref().map(String::as_str)),
+            other => panic!("expected the reply, got {:?}", other),
+        }
+        match resolve(&mock, "xoxb-1", "https://x.slack.com/messages/C1") {
+            Err(PermalinkError::InvalidLink) => {}
+            other => panic!("expected an invalid link, got {:?}", other),
+        }
+    }
+}
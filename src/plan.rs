@@ -0,0 +1,164 @@
+//! Workspace plans, and the API methods that need a paid one.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+
+use team_billing;
+
+/// A workspace's plan, as reported by `team.billing.info`.
+///
+/// Plans are ordered by what they include, so `plan >= Plan::Pro` checks for a paid plan. A plan
+/// this library does not know is not ordered against the others.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Plan {
+    Free,
+    Pro,
+    BusinessPlus,
+    /// Business+ with the compliance add-ons.
+    Compliance,
+    /// Enterprise Grid.
+    Enterprise,
+    Other(String),
+}
+
+impl Plan {
+    /// The name `team.billing.info` uses for the plan.
+    pub fn name(&self) -> &str {
+        match self {
+            &Plan::Free => "",
+            &Plan::Pro => "std",
+            &Plan::BusinessPlus => "plus",
+            &Plan::Compliance => "compliance",
+            &Plan::Enterprise => "enterprise",
+            &Plan::Other(ref name) => name,
+        }
+    }
+
+    pub fn is_paid(&self) -> bool {
+        self != &Plan::Free
+    }
+
+    /// Whether the plan includes `method`, as far as this library knows. Unknown plans are
+    /// assumed to include everything.
+    pub fn allows(&self, method: &str) -> bool {
+        required_plan(method).map(|required| self.partial_cmp(&required) != Some(Ordering::Less)).unwrap_or(true)
+    }
+
+    fn rank(&self) -> Option<u8> {
+        match self {
+            &Plan::Free => Some(0),
+            &Plan::Pro => Some(1),
+            &Plan::BusinessPlus => Some(2),
+            &Plan::Compliance => Some(3),
+            &Plan::Enterprise => Some(4),
+            &Plan::Other(_) => None,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Plan {
+    fn from(name: &'a str) -> Self {
+        match name {
+            "" | "free" => Plan::Free,
+            "std" => Plan::Pro,
+            "plus" => Plan::BusinessPlus,
+            "compliance" => Plan::Compliance,
+            "enterprise" => Plan::Enterprise,
+            _ => Plan::Other(name.to_owned()),
+        }
+    }
+}
+
+impl PartialOrd for Plan {
+    fn partial_cmp(&self, other: &Plan) -> Option<Ordering> {
+        match (self.rank(), other.rank()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            _ if self == other => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Plan::Free => write!(f, "Free"),
+            &Plan::Pro => write!(f, "Pro"),
+            &Plan::BusinessPlus => write!(f, "Business+"),
+            &Plan::Compliance => write!(f, "Business+ with compliance"),
+            &Plan::Enterprise => write!(f, "Enterprise Grid"),
+            &Plan::Other(ref name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Method name prefixes, and the least plan that includes the methods starting with them.
+const REQUIRED_PLANS: &'static [(&'static str, Plan)] = &[("admin.", Plan::Enterprise),
+                                                          ("conversations.acceptSharedInvite", Plan::Pro),
+                                                          ("conversations.inviteShared", Plan::Pro),
+                                                          ("discovery.", Plan::Enterprise),
+                                                          ("team.accessLogs", Plan::Pro),
+                                                          ("usergroups.", Plan::Pro)];
+
+/// The least plan that includes `method`, like `"usergroups.create"`, or `None` if every plan
+/// does, as far as this library knows.
+pub fn required_plan(method: &str) -> Option<Plan> {
+    REQUIRED_PLANS.iter().find(|&&(prefix, _)| method.starts_with(prefix)).map(|&(_, ref plan)| plan.clone())
+}
+
+/// Why [`SlackClient::check_plan`](../client/struct.SlackClient.html#method.check_plan) failed.
+#[derive(Debug)]
+pub enum PlanError<E: Error> {
+    /// `team.billing.info` failed.
+    Info(team_billing::InfoError<E>),
+    /// The workspace's plan does not include the method.
+    NotIncluded {
+        method: String,
+        plan: Plan,
+        required: Plan,
+    },
+}
+
+impl<E: Error> fmt::Display for PlanError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &PlanError::NotIncluded { ref method, ref plan, ref required } => {
+                write!(f, "{} needs the {} plan or better, but the workspace is on {}", method, required, plan)
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: Error> Error for PlanError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &PlanError::Info(ref inner) => inner.description(),
+            &PlanError::NotIncluded { .. } => "the workspace's plan does not include the method",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &PlanError::Info(ref inner) => Some(inner),
+            &PlanError::NotIncluded { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        assert!(!Plan::Free.allows("usergroups.create"));
+        assert!(Plan::Free.allows("chat.postMessage"));
+        assert!(Plan::Pro.allows("usergroups.create"));
+        assert!(!Plan::BusinessPlus.allows("admin.emoji.add"));
+        assert!(Plan::Enterprise.allows("admin.emoji.add"));
+        assert!(Plan::from("something_new").allows("admin.emoji.add"));
+        assert!(Plan::from("plus") > Plan::from("std"));
+    }
+}
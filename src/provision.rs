@@ -0,0 +1,162 @@
+//! Idempotent setup steps, for scripts that provision a workspace and may run more than once.
+
+use std::error::Error;
+use std::fmt;
+
+use serde;
+
+use conversations;
+use requests::SlackWebRequestSender;
+use Conversation;
+
+/// Makes sure the channel called `name` exists, is not archived and has the bot in it, and
+/// returns it.
+///
+/// An existing channel is unarchived if needed; a missing one is created, private if
+/// `is_private` is set. Running this again with the same name does nothing but look the channel
+/// up.
+///
+/// Private channels the bot is not in cannot be seen, so for those creating fails with
+/// [`conversations::CreateError::NameTaken`].
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+/// let channel = slack_api::provision::ensure_channel(&client, "xoxb-...", "#incidents", false).unwrap();
+/// ```
+pub fn ensure_channel<R>(client: &R,
+                         token: &str,
+                         name: &str,
+                         is_private: bool)
+                         -> Result<Conversation, EnsureChannelError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let name = name.trim_start_matches('#');
+    let mut channel = match find_channel(client, token, name).map_err(EnsureChannelError::List)? {
+        Some(channel) => channel,
+        None => {
+            let request = conversations::CreateRequest {
+                name: name,
+                is_private: Some(is_private),
+                ..conversations::CreateRequest::default()
+            };
+            let response = conversations::create(client, token, &request).map_err(EnsureChannelError::Create)?;
+            // The creator is always a member of the new channel.
+            return response.channel.ok_or(EnsureChannelError::Create(conversations::CreateError::MalformedResponse(
+                serde::de::Error::missing_field("channel"))));
+        }
+    };
+    let id = channel.id.clone().unwrap_or_default();
+
+    if channel.is_archived == Some(true) {
+        let request = conversations::UnarchiveRequest { channel: &id };
+        conversations::unarchive(client, token, &request).map_err(EnsureChannelError::Unarchive)?;
+        channel.is_archived = Some(false);
+    }
+    if channel.is_member != Some(true) {
+        let request = conversations::JoinRequest { channel: &id };
+        let response = conversations::join(client, token, &request).map_err(EnsureChannelError::Join)?;
+        channel = response.channel.unwrap_or(channel);
+        channel.is_member = Some(true);
+    }
+    Ok(channel)
+}
+
+/// The channel called `name` among all the public and private channels the token can see,
+/// archived or not.
+fn find_channel<R>(client: &R,
+                   token: &str,
+                   name: &str)
+                   -> Result<Option<Conversation>, conversations::ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut cursor = None;
+    loop {
+        let request = conversations::ListRequest {
+            cursor: cursor.as_ref().map(String::as_str),
+            exclude_archived: Some(false),
+            limit: Some(1000),
+            types: Some("public_channel,private_channel"),
+            ..conversations::ListRequest::default()
+        };
+        let response = conversations::list(client, token, &request)?;
+        let found = response.channels
+            .unwrap_or_default()
+            .into_iter()
+            .find(|channel| channel.name.as_ref().map(String::as_str) == Some(name));
+        if found.is_some() {
+            return Ok(found);
+        }
+        cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            return Ok(None);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum EnsureChannelError<E: Error> {
+    /// Listing the channels to look for an existing one failed.
+    List(conversations::ListError<E>),
+    /// There was no channel by this name and it could not be created.
+    Create(conversations::CreateError<E>),
+    /// The channel was archived and could not be unarchived.
+    Unarchive(conversations::UnarchiveError<E>),
+    /// The bot was not in the channel and could not join it.
+    Join(conversations::JoinError<E>),
+}
+
+impl<E: Error> fmt::Display for EnsureChannelError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for EnsureChannelError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &EnsureChannelError::List(ref inner) => inner.description(),
+            &EnsureChannelError::Create(ref inner) => inner.description(),
+            &EnsureChannelError::Unarchive(ref inner) => inner.description(),
+            &EnsureChannelError::Join(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &EnsureChannelError::List(ref inner) => Some(inner),
+            &EnsureChannelError::Create(ref inner) => Some(inner),
+            &EnsureChannelError::Unarchive(ref inner) => Some(inner),
+            &EnsureChannelError::Join(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_ensure_channel() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list")
+                .returns(r#"{"ok": true, "channels": [{"id": "C1", "name": "incidents", "is_archived": true}]}"#))
+            .expect(Expectation::new("conversations.unarchive").param("channel", "C1").times(1))
+            .expect(Expectation::new("conversations.join")
+                .param("channel", "C1")
+                .returns(r#"{"ok": true, "channel": {"id": "C1", "name": "incidents", "is_archived": false}}"#)
+                .times(1))
+            .expect(Expectation::new("conversations.create")
+                .param("name", "new")
+                .returns(r#"{"ok": true, "channel": {"id": "C2", "name": "new", "is_member": true}}"#)
+                .times(1));
+
+        let channel = ensure_channel(&mock, "xoxb-1", "#incidents", false).unwrap();
+        assert_eq!((Some(false), Some(true)), (channel.is_archived, channel.is_member));
+        assert_eq!(Some("C2".into()), ensure_channel(&mock, "xoxb-1", "new", false).unwrap().id);
+        mock.verify();
+    }
+}
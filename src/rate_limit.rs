@@ -0,0 +1,324 @@
+//! Pacing calls to stay within Slack's rate limits, and reporting where each method stands.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use metrics::Metrics;
+use requests::{SlackWebRequestSender, SlackWebResponse};
+
+/// How long to back off after an HTTP 429 without a `Retry-After` header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// A change in the rate limiting of a method, as reported to the [`Metrics`] of a [`RateLimiter`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum RateLimitEvent<'a> {
+    /// Slack rate limited a call to `method`, so calls to it wait out `retry_after`.
+    BackoffStarted { method: &'a str, retry_after: Duration },
+    /// The backoff of `method` is over. This is reported by the first call after it.
+    BackoffEnded { method: &'a str },
+    /// The budget of `method` ran out, so its next call waits `wait`.
+    BudgetExhausted { method: &'a str, wait: Duration },
+}
+
+/// Where a method stands with a [`RateLimiter`], as returned by [`RateLimiter::limit`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodLimit {
+    pub method: String,
+    /// How many calls the method may make a minute.
+    pub per_minute: u32,
+    /// How many calls it may make right away.
+    pub remaining: u32,
+    /// How much longer it backs off after Slack rate limited it, if it does.
+    pub backoff: Option<Duration>,
+    /// How long its next call would wait. Zero if it may be made right away.
+    pub next_call_in: Duration,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Calls that may be made right away. Negative when calls are waiting for their turn.
+    tokens: f64,
+    refilled_at: Instant,
+    backoff_until: Option<Instant>,
+}
+
+/// Wraps a request sender, pacing the calls to each method so that they stay within its rate
+/// limit, and backing off when Slack rate limits one anyway.
+///
+/// Each method has a budget of calls a minute, which it may spend in a burst: [Slack's
+/// tiers](https://api.slack.com/docs/rate-limits) allow 1, 20, 50 and 100. Methods get 50 by
+/// default, and `chat.postMessage` 60, about the one message a second Slack allows per channel.
+/// Once a method's budget runs out, its calls wait for it to refill. When Slack answers a call
+/// with an HTTP 429, calls to its method wait for as long as the `Retry-After` header says,
+/// whatever the budget.
+///
+/// Where each method stands can be read with [`limit`](#method.limit) and
+/// [`limits`](#method.limits), and every change is reported to the [`Metrics`] as a
+/// [`RateLimitEvent`]. The time each call waited is reported in its response's `rate_limit_wait`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::metrics::NoMetrics;
+/// # use slack_api::rate_limit::RateLimiter;
+/// let client = RateLimiter::new(slack_api::requests::default_client().unwrap(), NoMetrics)
+///     .per_minute("conversations.list", 20);
+///
+/// let response = slack_api::conversations::list(&client, "xoxb-...", &Default::default());
+/// let limit = client.limit("conversations.list");
+/// println!("{} calls left, next in {:?}", limit.remaining, limit.next_call_in);
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter<R, M> {
+    sender: R,
+    metrics: M,
+    default_per_minute: u32,
+    per_minute: HashMap<String, u32>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl<R: SlackWebRequestSender, M: Metrics> RateLimiter<R, M> {
+    pub fn new(sender: R, metrics: M) -> Self {
+        let mut per_minute = HashMap::new();
+        per_minute.insert("chat.postMessage".to_owned(), 60);
+        RateLimiter {
+            sender: sender,
+            metrics: metrics,
+            default_per_minute: 50,
+            per_minute: per_minute,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// How many calls a minute `method` may make.
+    pub fn per_minute<S: Into<String>>(mut self, method: S, calls: u32) -> Self {
+        self.per_minute.insert(method.into(), calls.max(1));
+        self
+    }
+
+    /// How many calls a minute the methods without a budget of their own may make. Defaults to
+    /// 50, Slack's third tier.
+    pub fn default_per_minute(mut self, calls: u32) -> Self {
+        self.default_per_minute = calls.max(1);
+        self
+    }
+
+    /// The wrapped request sender.
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+
+    /// Where `method` stands now.
+    pub fn limit(&self, method: &str) -> MethodLimit {
+        let mut buckets = self.buckets.lock().unwrap();
+        let now = Instant::now();
+        match buckets.get_mut(method) {
+            Some(bucket) => self.describe(method, bucket, now),
+            None => {
+                let per_minute = self.budget(method);
+                MethodLimit {
+                    method: method.to_owned(),
+                    per_minute: per_minute,
+                    remaining: per_minute,
+                    backoff: None,
+                    next_call_in: Duration::from_secs(0),
+                }
+            }
+        }
+    }
+
+    /// Where each method called so far stands now, by method name.
+    pub fn limits(&self) -> Vec<MethodLimit> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let now = Instant::now();
+        let mut limits: Vec<_> = buckets.iter_mut().map(|(method, bucket)| self.describe(method, bucket, now)).collect();
+        limits.sort_by(|a, b| a.method.cmp(&b.method));
+        limits
+    }
+
+    fn budget(&self, method: &str) -> u32 {
+        self.per_minute.get(method).cloned().unwrap_or(self.default_per_minute)
+    }
+
+    /// Adds the calls `bucket` earned since it was last refilled.
+    fn refill(&self, method: &str, bucket: &mut Bucket, now: Instant) {
+        let per_minute = self.budget(method) as f64;
+        let earned = duration_secs(now.duration_since(bucket.refilled_at)) * per_minute / 60.0;
+        bucket.tokens = (bucket.tokens + earned).min(per_minute);
+        bucket.refilled_at = now;
+    }
+
+    /// How long a call would wait for a turn in `bucket`.
+    fn wait(&self, method: &str, bucket: &Bucket, now: Instant) -> Duration {
+        let budget_wait = if bucket.tokens >= 1.0 {
+            Duration::from_secs(0)
+        } else {
+            secs_duration((1.0 - bucket.tokens) * 60.0 / self.budget(method) as f64)
+        };
+        let backoff_wait = bucket.backoff_until.map(|until| until.saturating_duration_since(now)).unwrap_or_default();
+        budget_wait.max(backoff_wait)
+    }
+
+    fn describe(&self, method: &str, bucket: &mut Bucket, now: Instant) -> MethodLimit {
+        self.refill(method, bucket, now);
+        MethodLimit {
+            method: method.to_owned(),
+            per_minute: self.budget(method),
+            remaining: bucket.tokens.max(0.0) as u32,
+            backoff: bucket.backoff_until.filter(|&until| until > now).map(|until| until - now),
+            next_call_in: self.wait(method, bucket, now),
+        }
+    }
+
+    /// Takes a turn for a call to `method`, returning how long the call must wait for it.
+    fn reserve(&self, method: &str) -> Duration {
+        let mut buckets = self.buckets.lock().unwrap();
+        let now = Instant::now();
+        let per_minute = self.budget(method) as f64;
+        let bucket = buckets.entry(method.to_owned()).or_insert(Bucket {
+                                                                    tokens: per_minute,
+                                                                    refilled_at: now,
+                                                                    backoff_until: None,
+                                                                });
+        self.refill(method, bucket, now);
+        if bucket.backoff_until.map(|until| until <= now).unwrap_or(false) {
+            bucket.backoff_until = None;
+            self.metrics.rate_limit(&RateLimitEvent::BackoffEnded { method: method });
+        }
+        let wait = self.wait(method, bucket, now);
+        bucket.tokens -= 1.0;
+        if bucket.tokens < 1.0 && bucket.backoff_until.is_none() {
+            self.metrics.rate_limit(&RateLimitEvent::BudgetExhausted {
+                                        method: method,
+                                        wait: self.wait(method, bucket, now),
+                                    });
+        }
+        wait
+    }
+
+    fn back_off(&self, method: &str, retry_after: Duration) {
+        let mut buckets = self.buckets.lock().unwrap();
+        if let Some(bucket) = buckets.get_mut(method) {
+            bucket.backoff_until = Some(Instant::now() + retry_after);
+            // The budget was overestimated, so it is spent.
+            bucket.tokens = bucket.tokens.min(0.0);
+        }
+        self.metrics.rate_limit(&RateLimitEvent::BackoffStarted {
+                                    method: method,
+                                    retry_after: retry_after,
+                                });
+    }
+}
+
+impl<R: SlackWebRequestSender, M: Metrics> SlackWebRequestSender for RateLimiter<R, M> {
+    type Error = R::Error;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.pace(method_url, || self.sender.send_raw(method_url, params))
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.pace(method_url, || self.sender.send_json(method_url, token, body))
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.pace(method_url, || self.sender.send_form(method_url, params))
+    }
+}
+
+impl<R: SlackWebRequestSender, M: Metrics> RateLimiter<R, M> {
+    fn pace<F>(&self, method_url: &str, send: F) -> Result<SlackWebResponse, R::Error>
+        where F: FnOnce() -> Result<SlackWebResponse, R::Error>
+    {
+        let method = method_url.rsplit('/').next().unwrap_or(method_url);
+        let method = method.split('?').next().unwrap_or(method);
+        let wait = self.reserve(method);
+        if wait > Duration::from_secs(0) {
+            thread::sleep(wait);
+        }
+
+        let mut response = send()?;
+        if response.status == Some(429) {
+            let retry_after = response.header("retry-after")
+                .and_then(|secs| secs.trim().parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_RETRY_AFTER);
+            self.back_off(method, retry_after);
+        }
+        response.rate_limit_wait += wait;
+        Ok(response)
+    }
+}
+
+fn duration_secs(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1e9
+}
+
+fn secs_duration(secs: f64) -> Duration {
+    Duration::new(secs as u64, (secs.fract() * 1e9) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use conversations;
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Events(RefCell<Vec<String>>);
+
+    impl Metrics for Events {
+        fn rate_limit(&self, event: &RateLimitEvent) {
+            self.0.borrow_mut().push(format!("{:?}", event));
+        }
+    }
+
+    #[test]
+    fn test_budget() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.info").returns(r#"{"ok": true, "channel": {"id": "C1"}}"#));
+        let events = Events::default();
+        let client = RateLimiter::new(mock, &events).per_minute("conversations.info", 2);
+
+        let request = conversations::InfoRequest { channel: "C1", ..Default::default() };
+        conversations::info(&client, "xoxb-1", &request).unwrap();
+        assert_eq!(1, client.limit("conversations.info").remaining);
+        conversations::info(&client, "xoxb-1", &request).unwrap();
+
+        let limit = client.limit("conversations.info");
+        assert_eq!((2, 0, None), (limit.per_minute, limit.remaining, limit.backoff));
+        assert!(limit.next_call_in > Duration::from_secs(25), "{:?}", limit.next_call_in);
+        let methods: Vec<_> = client.limits().into_iter().map(|limit| limit.method).collect();
+        assert_eq!(vec!["conversations.info"], methods);
+        assert_eq!(50, client.limit("users.info").per_minute);
+        assert_eq!(1, events.0.borrow().len());
+        assert!(events.0.borrow()[0].starts_with("BudgetExhausted"));
+    }
+
+    #[test]
+    fn test_backoff() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("users.info").rate_limited(Duration::from_secs(30)));
+        let events = Events::default();
+        let client = RateLimiter::new(mock, &events);
+
+        let response = client.send_raw("https://slack.com/api/users.info", &[("user", "U1")]).unwrap();
+        assert_eq!(Some(429), response.status);
+
+        let limit = client.limit("users.info");
+        assert_eq!(0, limit.remaining);
+        assert!(limit.backoff.unwrap() > Duration::from_secs(29), "{:?}", limit.backoff);
+        assert!(limit.next_call_in > Duration::from_secs(29));
+        assert_eq!(vec![r#"BackoffStarted { method: "users.info", retry_after: 30s }"#.to_owned()],
+                   *events.0.borrow());
+    }
+}
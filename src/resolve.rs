@@ -0,0 +1,319 @@
+//! Resolving the human-friendly names of things to their IDs.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use conversations;
+use requests::SlackWebRequestSender;
+use usergroups;
+use users;
+
+#[derive(Debug, Default)]
+struct Names {
+    ids: RwLock<Option<HashMap<String, String>>>,
+    /// The names that were still unknown after listing, and when they were looked up.
+    misses: Mutex<HashMap<String, Instant>>,
+}
+
+/// Maps names to IDs, listing the workspace only when it sees a name it does not know yet.
+///
+/// A name that is still unknown after listing is remembered as such for a while, a minute by
+/// default, so that looking up a misspelt name again and again does not list the whole
+/// workspace every time.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::resolve::Resolver;
+/// let client = slack_api::requests::default_client().unwrap();
+/// let resolver = Resolver::new();
+///
+/// let deploys = resolver.channel_id(&client, "xoxb-...", "#deploys").unwrap();
+/// let alice = resolver.user_id_by_email(&client, "xoxb-...", "alice@example.com").unwrap();
+/// let oncall = resolver.usergroup_id(&client, "xoxb-...", "@oncall").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Resolver {
+    channels: Names,
+    emails: Names,
+    display_names: Names,
+    usergroups: Names,
+    dm_channels: Names,
+    retry_unknown_after: Duration,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Resolver {
+            channels: Names::default(),
+            emails: Names::default(),
+            display_names: Names::default(),
+            usergroups: Names::default(),
+            dm_channels: Names::default(),
+            retry_unknown_after: Duration::from_secs(60),
+        }
+    }
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        Resolver::default()
+    }
+
+    /// How long a name that was not found is answered with `None` without listing the workspace
+    /// again. Defaults to a minute.
+    pub fn retry_unknown_after(mut self, after: Duration) -> Self {
+        self.retry_unknown_after = after;
+        self
+    }
+
+    /// The ID of the public or private channel called `name`, with or without its leading `#`.
+    ///
+    /// The channels are listed on first use, and again whenever `name` is not among them, unless
+    /// it was not found shortly before.
+    pub fn channel_id<R>(&self,
+                         client: &R,
+                         token: &str,
+                         name: &str)
+                         -> Result<Option<String>, conversations::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        lookup(self.retry_unknown_after,
+               &self.channels,
+               name.trim_start_matches('#'),
+               || self.refresh_channels(client, token))
+    }
+
+    /// The ID of the user with this email address, ignoring case.
+    ///
+    /// Reading email addresses needs the `users:read.email` scope.
+    pub fn user_id_by_email<R>(&self,
+                               client: &R,
+                               token: &str,
+                               email: &str)
+                               -> Result<Option<String>, users::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        lookup(self.retry_unknown_after,
+               &self.emails,
+               &email.to_lowercase(),
+               || self.refresh_users(client, token))
+    }
+
+    /// The ID of the user shown as `name`, with or without its leading `@`.
+    ///
+    /// This is the user's display name, or their username if they have not set one. Display names
+    /// are not unique; if several users share one, any of them may be returned.
+    pub fn user_id_by_name<R>(&self,
+                              client: &R,
+                              token: &str,
+                              name: &str)
+                              -> Result<Option<String>, users::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        lookup(self.retry_unknown_after,
+               &self.display_names,
+               name.trim_start_matches('@'),
+               || self.refresh_users(client, token))
+    }
+
+    /// The ID of the user group with this handle, with or without its leading `@`.
+    pub fn usergroup_id<R>(&self,
+                           client: &R,
+                           token: &str,
+                           handle: &str)
+                           -> Result<Option<String>, usergroups::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        lookup(self.retry_unknown_after,
+               &self.usergroups,
+               handle.trim_start_matches('@'),
+               || self.refresh_usergroups(client, token))
+    }
+
+    /// The ID of the direct message conversation with the user `user_id`, opening it the first
+    /// time it is asked for.
+    pub fn dm_channel_id<R>(&self,
+                            client: &R,
+                            token: &str,
+                            user_id: &str)
+                            -> Result<Option<String>, conversations::OpenError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        lookup(self.retry_unknown_after, &self.dm_channels, user_id, || {
+            let request = conversations::OpenRequest { users: Some(user_id), ..conversations::OpenRequest::default() };
+            let response = conversations::open(client, token, &request)?;
+            if let Some(id) = response.channel.and_then(|c| c.id) {
+                self.dm_channels.ids.write().unwrap().get_or_insert_with(HashMap::new).insert(user_id.to_owned(), id.into());
+            }
+            Ok(())
+        })
+    }
+
+    /// Lists the channels again, replacing the cached names.
+    pub fn refresh_channels<R>(&self, client: &R, token: &str) -> Result<(), conversations::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let mut channels = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let request = conversations::ListRequest {
+                cursor: cursor.as_ref().map(String::as_str),
+                exclude_archived: Some(true),
+                limit: Some(1000),
+                types: Some("public_channel,private_channel"),
+                ..conversations::ListRequest::default()
+            };
+            let response = conversations::list(client, token, &request)?;
+            for channel in response.channels.unwrap_or_default() {
+                if let (Some(name), Some(id)) = (channel.name, channel.id) {
+                    channels.insert(name, id.into());
+                }
+            }
+            cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+            if cursor.is_none() {
+                break;
+            }
+        }
+        *self.channels.ids.write().unwrap() = Some(channels);
+        Ok(())
+    }
+
+    /// Lists the users again, replacing the cached email addresses and display names.
+    ///
+    /// Deactivated users are left out.
+    pub fn refresh_users<R>(&self, client: &R, token: &str) -> Result<(), users::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let mut emails = HashMap::new();
+        let mut display_names = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let request = users::ListRequest {
+                cursor: cursor.as_ref().map(String::as_str),
+                limit: Some(1000),
+                ..users::ListRequest::default()
+            };
+            let response = users::list(client, token, &request)?;
+            for user in response.members.unwrap_or_default() {
+                let id = match user.id {
+                    Some(ref id) if user.deleted != Some(true) => id.clone(),
+                    _ => continue,
+                };
+                let profile = user.profile.as_ref();
+                if let Some(email) = profile.and_then(|p| p.email.as_ref()) {
+                    emails.insert(email.to_lowercase(), id.to_string());
+                }
+                let display_name = profile.and_then(|p| p.display_name.as_ref()).filter(|n| !n.is_empty());
+                if let Some(name) = display_name.or(user.name.as_ref()) {
+                    display_names.insert(name.clone(), id.into());
+                }
+            }
+            cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+            if cursor.is_none() {
+                break;
+            }
+        }
+        *self.emails.ids.write().unwrap() = Some(emails);
+        *self.display_names.ids.write().unwrap() = Some(display_names);
+        Ok(())
+    }
+
+    /// Lists the user groups again, replacing the cached handles.
+    pub fn refresh_usergroups<R>(&self, client: &R, token: &str) -> Result<(), usergroups::ListError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let response = usergroups::list(client, token, &usergroups::ListRequest::default())?;
+        let usergroups = response.usergroups
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|group| match (group.handle, group.id) {
+                (Some(handle), Some(id)) => Some((handle, id)),
+                _ => None,
+            })
+            .collect();
+        *self.usergroups.ids.write().unwrap() = Some(usergroups);
+        Ok(())
+    }
+
+    /// Forgets everything cached, so the next lookups list the workspace again.
+    pub fn invalidate(&self) {
+        for names in &[&self.channels, &self.emails, &self.display_names, &self.usergroups, &self.dm_channels] {
+            *names.ids.write().unwrap() = None;
+            names.misses.lock().unwrap().clear();
+        }
+    }
+}
+
+/// Looks `key` up in `names`, refreshing them first if they have not been listed yet or do not
+/// contain it, unless `key` was missing from them less than `retry_after` ago.
+fn lookup<F, E>(retry_after: Duration, names: &Names, key: &str, refresh: F) -> Result<Option<String>, E>
+    where F: FnOnce() -> Result<(), E>
+{
+    if let Some(id) = cached(names, key) {
+        return Ok(Some(id));
+    }
+    if names.misses.lock().unwrap().get(key).map(|at| at.elapsed() < retry_after).unwrap_or(false) {
+        return Ok(None);
+    }
+    refresh()?;
+    let id = cached(names, key);
+    if id.is_none() {
+        let mut misses = names.misses.lock().unwrap();
+        misses.retain(|_, at| at.elapsed() < retry_after);
+        misses.insert(key.to_owned(), Instant::now());
+    }
+    Ok(id)
+}
+
+fn cached(names: &Names, key: &str) -> Option<String> {
+    names.ids.read().unwrap().as_ref().and_then(|names| names.get(key).cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_users_are_listed_once() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("users.list")
+            .returns(r#"{"ok": true, "members": [
+                {"id": "U1", "name": "alice", "profile": {"email": "Alice@Example.com", "display_name": "Al"}},
+                {"id": "U2", "name": "bob", "profile": {"display_name": ""}},
+                {"id": "U3", "name": "carol", "deleted": true, "profile": {"email": "carol@example.com"}}
+            ]}"#)
+            .times(1));
+        let resolver = Resolver::new();
+
+        assert_eq!(Some("U1".to_owned()), resolver.user_id_by_email(&mock, "xoxb-1", "alice@example.com").unwrap());
+        assert_eq!(Some("U1".to_owned()), resolver.user_id_by_name(&mock, "xoxb-1", "@Al").unwrap());
+        assert_eq!(Some("U2".to_owned()), resolver.user_id_by_name(&mock, "xoxb-1", "bob").unwrap());
+        mock.verify();
+
+        mock.expect(Expectation::new("users.list").returns(r#"{"ok": true, "members": []}"#));
+        assert_eq!(None, resolver.user_id_by_email(&mock, "xoxb-1", "carol@example.com").unwrap());
+        assert_eq!(2, mock.calls_of("users.list").len());
+    }
+
+    #[test]
+    fn test_unknown_names_are_not_listed_again() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list")
+            .returns(r#"{"ok": true, "channels": [{"id": "C1", "name": "general"}]}"#));
+        let resolver = Resolver::new();
+
+        for _ in 0..3 {
+            assert_eq!(None, resolver.channel_id(&mock, "xoxb-1", "#genral").unwrap());
+        }
+        assert_eq!(1, mock.calls_of("conversations.list").len());
+        assert_eq!(Some("C1".to_owned()), resolver.channel_id(&mock, "xoxb-1", "#general").unwrap());
+
+        let resolver = resolver.retry_unknown_after(Duration::from_secs(0));
+        assert_eq!(None, resolver.channel_id(&mock, "xoxb-1", "#genral").unwrap());
+        assert_eq!(2, mock.calls_of("conversations.list").len());
+    }
+}
@@ -0,0 +1,284 @@
+//! Retrying calls that Slack rate limits, after waiting as long as it asks.
+
+use std::error::Error;
+use std::fmt;
+use std::thread;
+use std::time::Duration;
+
+use requests::{FilePart, SlackJsonSender, SlackMultipartSender, SlackUploadSender, SlackWebRequestSender, SlackWebResponse};
+
+/// How a [`Retrying`] sender retries rate limited calls.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use slack_api::retry::RetryPolicy;
+/// let policy = RetryPolicy::new().max_retries(5).max_wait(Duration::from_secs(120));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    max_wait: Duration,
+    default_retry_after: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            max_wait: Duration::from_secs(60),
+            default_retry_after: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        RetryPolicy::default()
+    }
+
+    /// How many times one call is retried at most. Defaults to three.
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// The longest `Retry-After` worth waiting for: a call Slack asks to hold off for longer is
+    /// not retried. Defaults to a minute.
+    pub fn max_wait(mut self, wait: Duration) -> Self {
+        self.max_wait = wait;
+        self
+    }
+
+    /// How long to wait after an HTTP 429 without a `Retry-After` header. Defaults to a second.
+    pub fn default_retry_after(mut self, wait: Duration) -> Self {
+        self.default_retry_after = wait;
+        self
+    }
+}
+
+/// Wraps a request sender, retrying the calls Slack answers with an HTTP 429 once the
+/// `Retry-After` header says they may be made again.
+///
+/// A call still rate limited after the policy's last retry, or asked to wait longer than its
+/// `max_wait`, fails with [`RetryError::RateLimited`], which tells how long Slack asked to wait,
+/// for callers that would rather schedule the call themselves. The retries and waits of the calls
+/// that succeed are reported in their response's `retries` and `rate_limit_wait`.
+///
+/// Unlike the [`RateLimiter`](../rate_limit/struct.RateLimiter.html), this does not pace calls;
+/// the two can be combined, the limiter inside, so that its backoff sees every 429.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::retry::{RetryError, RetryPolicy, Retrying};
+/// let client = Retrying::new(slack_api::requests::default_client().unwrap(), RetryPolicy::new().max_retries(5));
+///
+/// match slack_api::conversations::list(&client, "xoxb-...", &Default::default()) {
+///     Err(slack_api::conversations::ListError::Client(RetryError::RateLimited(limited))) => {
+///         println!("still rate limited, try again in {:?}", limited.retry_after)
+///     }
+///     other => println!("{:?}", other),
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Retrying<R> {
+    sender: R,
+    policy: RetryPolicy,
+}
+
+impl<R: SlackWebRequestSender> Retrying<R> {
+    pub fn new(sender: R, policy: RetryPolicy) -> Self {
+        Retrying {
+            sender: sender,
+            policy: policy,
+        }
+    }
+
+    /// The wrapped request sender.
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    fn retry<F>(&self, method_url: &str, mut send: F) -> Result<SlackWebResponse, RetryError<R::Error>>
+        where F: FnMut() -> Result<SlackWebResponse, R::Error>
+    {
+        let mut retries = 0;
+        let mut waited = Duration::from_secs(0);
+        loop {
+            let mut response = send().map_err(RetryError::Client)?;
+            if response.status != Some(429) {
+                response.retries += retries;
+                response.rate_limit_wait += waited;
+                return Ok(response);
+            }
+            let retry_after = response.header("retry-after")
+                .and_then(|secs| secs.trim().parse().ok())
+                .map(Duration::from_secs);
+            let wait = retry_after.unwrap_or(self.policy.default_retry_after);
+            if retries >= self.policy.max_retries || wait > self.policy.max_wait {
+                let method = method_url.rsplit('/').next().unwrap_or(method_url);
+                return Err(RetryError::RateLimited(RateLimited {
+                    method: method.split('?').next().unwrap_or(method).to_owned(),
+                    retry_after: retry_after,
+                    retries: retries,
+                    waited: waited,
+                    request_id: response.header("x-slack-req-id").map(str::to_owned),
+                }));
+            }
+            thread::sleep(wait);
+            waited += wait;
+            retries += 1;
+        }
+    }
+}
+
+impl<R: SlackWebRequestSender> SlackWebRequestSender for Retrying<R> {
+    type Error = RetryError<R::Error>;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.retry(method_url, || self.sender.send_raw(method_url, params))
+    }
+
+    // Only `send_raw` reports the status, so `send_bytes` goes through it.
+    fn send_bytes(&self, method_url: &str, params: &[(&str, &str)]) -> Result<Vec<u8>, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body.into_bytes())
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.retry(method_url, || self.sender.send_json(method_url, token, body))
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.retry(method_url, || self.sender.send_form(method_url, params))
+    }
+}
+
+impl<R: SlackUploadSender> SlackUploadSender for Retrying<R> {
+    fn upload(&self, url: &str, content: &[u8]) -> Result<SlackWebResponse, Self::Error> {
+        self.retry(url, || self.sender.upload(url, content))
+    }
+}
+
+impl<R: SlackJsonSender> SlackJsonSender for Retrying<R> {
+    fn post_json(&self, url: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.retry(url, || self.sender.post_json(url, body))
+    }
+}
+
+impl<R: SlackMultipartSender> SlackMultipartSender for Retrying<R> {
+    fn send_multipart(&self,
+                      method_url: &str,
+                      token: &str,
+                      fields: &[(&str, &str)],
+                      file: Option<&FilePart>)
+                      -> Result<SlackWebResponse, Self::Error> {
+        self.retry(method_url, || self.sender.send_multipart(method_url, token, fields, file))
+    }
+}
+
+/// A call that was still rate limited when a [`Retrying`] sender gave up on it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimited {
+    /// The API method, e.g. `chat.postMessage`.
+    pub method: String,
+    /// How long Slack asked to wait before calling again, from the `Retry-After` header.
+    pub retry_after: Option<Duration>,
+    /// How many times the call was retried before giving up.
+    pub retries: u32,
+    /// How long was spent waiting between the retries.
+    pub waited: Duration,
+    /// The ID Slack gave the last request, from the `X-Slack-Req-Id` header, for support requests.
+    pub request_id: Option<String>,
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Slack rate limited {} after {} retries", self.method, self.retries)?;
+        if let Some(retry_after) = self.retry_after {
+            write!(f, ", retry after {}s", retry_after.as_secs())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum RetryError<E: Error> {
+    /// Slack kept rate limiting the call.
+    RateLimited(RateLimited),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for RetryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &RetryError::RateLimited(ref limited) => write!(f, "{}", limited),
+            &RetryError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error> Error for RetryError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RetryError::RateLimited(_) => "Slack kept rate limiting the call",
+            &RetryError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RetryError::RateLimited(_) => None,
+            &RetryError::Client(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chat;
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_retries_after_429() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").rate_limited(Duration::from_secs(0)).times(2))
+            .expect(Expectation::new("chat.postMessage").returns(r#"{"ok": true, "ts": "1.0"}"#));
+        let client = Retrying::new(mock, RetryPolicy::new());
+        let request = chat::PostMessageRequest { channel: "C1", text: "hi", ..Default::default() };
+
+        assert_eq!(Some("1.0".to_owned()), chat::post_message(&client, "xoxb-1", &request).unwrap().ts);
+        assert_eq!(3, client.sender().calls().len());
+        let response = client.send_raw("https://slack.com/api/chat.postMessage", &[]).unwrap();
+        assert_eq!(0, response.retries);
+    }
+
+    #[test]
+    fn test_gives_up_with_retry_after() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.postMessage").rate_limited(Duration::from_secs(0)).times(2))
+            .expect(Expectation::new("chat.postMessage").rate_limited(Duration::from_secs(600)));
+        let client = Retrying::new(mock, RetryPolicy::new().max_retries(5));
+        let request = chat::PostMessageRequest { channel: "C1", text: "hi", ..Default::default() };
+
+        match chat::post_message(&client, "xoxb-1", &request) {
+            Err(chat::PostMessageError::Client(RetryError::RateLimited(limited))) => {
+                assert_eq!(("chat.postMessage", Some(Duration::from_secs(600)), 2),
+                           (&limited.method[..], limited.retry_after, limited.retries));
+            }
+            other => panic!("expected the call to be rate limited, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Scheduling messages for later, enabled by the `chrono` feature.
+
+extern crate chrono;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Mutex;
+
+use self::chrono::{DateTime, Duration, TimeZone, Utc};
+
+use chat;
+use requests::SlackWebRequestSender;
+
+/// How far ahead Slack lets messages be scheduled.
+pub const MAX_AHEAD_DAYS: i64 = 120;
+
+/// How many messages Slack lets be scheduled in one channel within five minutes.
+pub const MAX_PER_WINDOW: usize = 30;
+
+/// Schedules messages with `chat.scheduleMessage`, checking Slack's limits before calling it.
+///
+/// The scheduler remembers what it scheduled in each channel, so it can refuse a message that
+/// would be one too many within five minutes without a round trip. It does not know about
+/// messages scheduled by other means, or cancelled through a handle.
+///
+/// # Examples
+///
+/// ```no_run
+/// # extern crate chrono;
+/// # extern crate slack_api;
+/// # use chrono::{Duration, Utc};
+/// # use slack_api::schedule::Scheduler;
+/// # fn main() {
+/// let client = slack_api::requests::default_client().unwrap();
+/// let scheduler = Scheduler::new();
+///
+/// let request = slack_api::chat::ScheduleMessageRequest { channel: "C1", text: "Standup!", ..Default::default() };
+/// let scheduled = scheduler.schedule(&client, "xoxb-...", &(Utc::now() + Duration::hours(1)), &request).unwrap();
+///
+/// // Changed our minds
+/// scheduled.cancel(&client, "xoxb-...").unwrap();
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Scheduler {
+    scheduled: Mutex<HashMap<String, Vec<i64>>>,
+}
+
+/// A message scheduled by a [`Scheduler`].
+#[derive(Clone, Debug)]
+pub struct ScheduledMessage {
+    pub channel: String,
+    pub scheduled_message_id: String,
+    pub post_at: DateTime<Utc>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Scheduler::default()
+    }
+
+    /// Schedules `request` to be posted at `post_at`, which overrides its own `post_at`.
+    pub fn schedule<R, Tz>(&self,
+                           client: &R,
+                           token: &str,
+                           post_at: &DateTime<Tz>,
+                           request: &chat::ScheduleMessageRequest)
+                           -> Result<ScheduledMessage, ScheduleError<R::Error>>
+        where R: SlackWebRequestSender,
+              Tz: TimeZone
+    {
+        let post_at = post_at.with_timezone(&Utc);
+        let now = Utc::now();
+        if post_at <= now {
+            return Err(ScheduleError::InPast);
+        }
+        if post_at > now + Duration::days(MAX_AHEAD_DAYS) {
+            return Err(ScheduleError::TooFar);
+        }
+        let timestamp = post_at.timestamp();
+        if self.nearby(request.channel, timestamp, now.timestamp()) >= MAX_PER_WINDOW {
+            return Err(ScheduleError::TooMany);
+        }
+
+        let request = chat::ScheduleMessageRequest { post_at: timestamp as u32, ..request.clone() };
+        let response = chat::schedule_message(client, token, &request).map_err(ScheduleError::Schedule)?;
+        // Slack resolves channel names to IDs, and the ID is what deleting needs.
+        let channel = response.channel.unwrap_or_else(|| request.channel.to_owned());
+        self.scheduled.lock().unwrap().entry(request.channel.to_owned()).or_insert_with(Vec::new).push(timestamp);
+        Ok(ScheduledMessage {
+            channel: channel,
+            scheduled_message_id: response.scheduled_message_id.unwrap_or_default(),
+            post_at: post_at,
+        })
+    }
+
+    /// How many messages this scheduler has scheduled in `channel` within five minutes of
+    /// `timestamp`, forgetting the ones that have been posted by `now`.
+    fn nearby(&self, channel: &str, timestamp: i64, now: i64) -> usize {
+        let mut scheduled = self.scheduled.lock().unwrap();
+        let times = scheduled.entry(channel.to_owned()).or_insert_with(Vec::new);
+        times.retain(|&t| t > now);
+        times.iter().filter(|&&t| (t - timestamp).abs() < 5 * 60).count()
+    }
+}
+
+impl ScheduledMessage {
+    /// Deletes the message before it is posted.
+    pub fn cancel<R>(&self,
+                     client: &R,
+                     token: &str)
+                     -> Result<chat::DeleteScheduledMessageResponse, chat::DeleteScheduledMessageError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let request = chat::DeleteScheduledMessageRequest {
+            channel: &self.channel,
+            scheduled_message_id: &self.scheduled_message_id,
+            ..chat::DeleteScheduledMessageRequest::default()
+        };
+        chat::delete_scheduled_message(client, token, &request)
+    }
+}
+
+#[derive(Debug)]
+pub enum ScheduleError<E: Error> {
+    /// The time to post at has already passed.
+    InPast,
+    /// The time to post at is more than [`MAX_AHEAD_DAYS`] days away.
+    TooFar,
+    /// [`MAX_PER_WINDOW`] messages are scheduled in the channel within five minutes of the time
+    /// to post at already.
+    TooMany,
+    /// `chat.scheduleMessage` failed.
+    Schedule(chat::ScheduleMessageError<E>),
+}
+
+impl<E: Error> fmt::Display for ScheduleError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ScheduleError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ScheduleError::InPast => "the time to post at has passed",
+            &ScheduleError::TooFar => "the time to post at is more than 120 days away",
+            &ScheduleError::TooMany => "too many messages are scheduled in the channel around that time",
+            &ScheduleError::Schedule(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ScheduleError::Schedule(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_schedule_limits() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("chat.scheduleMessage")
+                .returns(r#"{"ok": true, "channel": "C1", "scheduled_message_id": "Q1"}"#))
+            .expect(Expectation::new("chat.deleteScheduledMessage").param("scheduled_message_id", "Q1").times(1));
+        let scheduler = Scheduler::new();
+        let request = chat::ScheduleMessageRequest { channel: "C1", text: "hi", ..Default::default() };
+        let soon = Utc::now() + Duration::hours(1);
+
+        for _ in 0..MAX_PER_WINDOW {
+            scheduler.schedule(&mock, "xoxb-1", &(soon + Duration::seconds(10)), &request).unwrap();
+        }
+        match scheduler.schedule(&mock, "xoxb-1", &soon, &request) {
+            Err(ScheduleError::TooMany) => {}
+            other => panic!("expected too many messages, got {:?}", other),
+        }
+        match scheduler.schedule(&mock, "xoxb-1", &(Utc::now() + Duration::days(121)), &request) {
+            Err(ScheduleError::TooFar) => {}
+            other => panic!("expected the time to be too far off, got {:?}", other),
+        }
+        let later = scheduler.schedule(&mock, "xoxb-1", &(soon + Duration::minutes(10)), &request).unwrap();
+        assert_eq!(Some(&*(soon + Duration::minutes(10)).timestamp().to_string()),
+                   mock.calls().last().unwrap().param("post_at"));
+
+        later.cancel(&mock, "xoxb-1").unwrap();
+        mock.verify();
+    }
+}
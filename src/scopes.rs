@@ -0,0 +1,519 @@
+//! OAuth scopes, the scopes each API method needs, and a sender that checks calls against the
+//! scopes a token was granted before making them.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::RwLock;
+
+use requests::{SlackWebRequestSender, SlackWebResponse};
+use secret::Secret;
+use tokens::TokenType;
+
+pub const ADMIN: &'static str = "admin";
+pub const ADMIN_APPS_WRITE: &'static str = "admin.apps:write";
+pub const ADMIN_CONVERSATIONS_READ: &'static str = "admin.conversations:read";
+pub const ADMIN_CONVERSATIONS_WRITE: &'static str = "admin.conversations:write";
+pub const ADMIN_TEAMS_READ: &'static str = "admin.teams:read";
+pub const ADMIN_TEAMS_WRITE: &'static str = "admin.teams:write";
+pub const ADMIN_USERS_READ: &'static str = "admin.users:read";
+pub const ADMIN_USERS_WRITE: &'static str = "admin.users:write";
+pub const AUTHORIZATIONS_READ: &'static str = "authorizations:read";
+pub const BOOKMARKS_READ: &'static str = "bookmarks:read";
+pub const BOOKMARKS_WRITE: &'static str = "bookmarks:write";
+pub const BOT: &'static str = "bot";
+pub const CALLS_READ: &'static str = "calls:read";
+pub const CALLS_WRITE: &'static str = "calls:write";
+pub const CHANNELS_HISTORY: &'static str = "channels:history";
+pub const CHANNELS_JOIN: &'static str = "channels:join";
+pub const CHANNELS_MANAGE: &'static str = "channels:manage";
+pub const CHANNELS_READ: &'static str = "channels:read";
+pub const CHANNELS_WRITE: &'static str = "channels:write";
+pub const CHAT_WRITE: &'static str = "chat:write";
+pub const CHAT_WRITE_CUSTOMIZE: &'static str = "chat:write.customize";
+pub const CHAT_WRITE_PUBLIC: &'static str = "chat:write.public";
+pub const CLIENT: &'static str = "client";
+pub const COMMANDS: &'static str = "commands";
+pub const CONNECTIONS_WRITE: &'static str = "connections:write";
+pub const DND_READ: &'static str = "dnd:read";
+pub const DND_WRITE: &'static str = "dnd:write";
+pub const EMAIL: &'static str = "email";
+pub const EMOJI_READ: &'static str = "emoji:read";
+pub const FILES_READ: &'static str = "files:read";
+pub const FILES_WRITE: &'static str = "files:write";
+pub const GROUPS_HISTORY: &'static str = "groups:history";
+pub const GROUPS_READ: &'static str = "groups:read";
+pub const GROUPS_WRITE: &'static str = "groups:write";
+pub const IDENTITY_AVATAR: &'static str = "identity.avatar";
+pub const IDENTITY_BASIC: &'static str = "identity.basic";
+pub const IDENTITY_EMAIL: &'static str = "identity.email";
+pub const IDENTITY_TEAM: &'static str = "identity.team";
+pub const IM_HISTORY: &'static str = "im:history";
+pub const IM_READ: &'static str = "im:read";
+pub const IM_WRITE: &'static str = "im:write";
+pub const INCOMING_WEBHOOK: &'static str = "incoming-webhook";
+pub const LINKS_WRITE: &'static str = "links:write";
+pub const MPIM_HISTORY: &'static str = "mpim:history";
+pub const MPIM_READ: &'static str = "mpim:read";
+pub const MPIM_WRITE: &'static str = "mpim:write";
+pub const OPENID: &'static str = "openid";
+pub const PINS_READ: &'static str = "pins:read";
+pub const PINS_WRITE: &'static str = "pins:write";
+pub const PROFILE: &'static str = "profile";
+pub const REACTIONS_READ: &'static str = "reactions:read";
+pub const REACTIONS_WRITE: &'static str = "reactions:write";
+pub const REMINDERS_READ: &'static str = "reminders:read";
+pub const REMINDERS_WRITE: &'static str = "reminders:write";
+pub const SEARCH_READ: &'static str = "search:read";
+pub const STARS_READ: &'static str = "stars:read";
+pub const STARS_WRITE: &'static str = "stars:write";
+pub const TEAM_BILLING_READ: &'static str = "team.billing:read";
+pub const TEAM_READ: &'static str = "team:read";
+pub const USERGROUPS_READ: &'static str = "usergroups:read";
+pub const USERGROUPS_WRITE: &'static str = "usergroups:write";
+pub const USERS_PROFILE_READ: &'static str = "users.profile:read";
+pub const USERS_PROFILE_WRITE: &'static str = "users.profile:write";
+pub const USERS_READ: &'static str = "users:read";
+pub const USERS_READ_EMAIL: &'static str = "users:read.email";
+pub const USERS_WRITE: &'static str = "users:write";
+
+const READ_CONVERSATIONS: &'static [&'static str] = &[CHANNELS_READ, GROUPS_READ, IM_READ, MPIM_READ];
+const CONVERSATION_HISTORY: &'static [&'static str] = &[CHANNELS_HISTORY,
+                                                         GROUPS_HISTORY,
+                                                         IM_HISTORY,
+                                                         MPIM_HISTORY];
+const MANAGE_CONVERSATIONS: &'static [&'static str] = &[CHANNELS_MANAGE, GROUPS_WRITE, IM_WRITE, MPIM_WRITE];
+
+type Scopes = Option<&'static [&'static str]>;
+
+/// Each method's scopes with a bot token and with a user token, sorted by method. Methods that
+/// neither can call are called with an app-level token.
+const METHOD_SCOPES: &'static [(&'static str, Scopes, Scopes)] = &[
+    ("admin.apps.approve", None, Some(&[ADMIN_APPS_WRITE])),
+    ("admin.apps.clearResolution", None, Some(&[ADMIN_APPS_WRITE])),
+    ("admin.apps.restrict", None, Some(&[ADMIN_APPS_WRITE])),
+    ("admin.apps.uninstall", None, Some(&[ADMIN_APPS_WRITE])),
+    ("admin.conversations.archive", None, Some(&[ADMIN_CONVERSATIONS_WRITE])),
+    ("admin.conversations.create", None, Some(&[ADMIN_CONVERSATIONS_WRITE])),
+    ("admin.conversations.delete", None, Some(&[ADMIN_CONVERSATIONS_WRITE])),
+    ("admin.conversations.getTeams", None, Some(&[ADMIN_CONVERSATIONS_READ])),
+    ("admin.conversations.invite", None, Some(&[ADMIN_CONVERSATIONS_WRITE])),
+    ("admin.conversations.rename", None, Some(&[ADMIN_CONVERSATIONS_WRITE])),
+    ("admin.conversations.search", None, Some(&[ADMIN_CONVERSATIONS_READ])),
+    ("admin.conversations.setTeams", None, Some(&[ADMIN_CONVERSATIONS_WRITE])),
+    ("admin.conversations.unarchive", None, Some(&[ADMIN_CONVERSATIONS_WRITE])),
+    ("admin.emoji.add", None, Some(&[ADMIN_TEAMS_WRITE])),
+    ("admin.emoji.addAlias", None, Some(&[ADMIN_TEAMS_WRITE])),
+    ("admin.emoji.list", None, Some(&[ADMIN_TEAMS_READ])),
+    ("admin.emoji.remove", None, Some(&[ADMIN_TEAMS_WRITE])),
+    ("admin.emoji.rename", None, Some(&[ADMIN_TEAMS_WRITE])),
+    ("admin.teams.create", None, Some(&[ADMIN_TEAMS_WRITE])),
+    ("admin.teams.list", None, Some(&[ADMIN_TEAMS_READ])),
+    ("admin.users.assign", None, Some(&[ADMIN_USERS_WRITE])),
+    ("admin.users.invite", None, Some(&[ADMIN_USERS_WRITE])),
+    ("admin.users.list", None, Some(&[ADMIN_USERS_READ])),
+    ("admin.users.remove", None, Some(&[ADMIN_USERS_WRITE])),
+    ("admin.users.setAdmin", None, Some(&[ADMIN_USERS_WRITE])),
+    ("admin.users.setExpiration", None, Some(&[ADMIN_USERS_WRITE])),
+    ("admin.users.setOwner", None, Some(&[ADMIN_USERS_WRITE])),
+    ("admin.users.setRegular", None, Some(&[ADMIN_USERS_WRITE])),
+    ("api.test", Some(&[]), Some(&[])),
+    ("apps.connections.open", None, None),
+    ("apps.event.authorizations.list", None, None),
+    ("auth.revoke", Some(&[]), Some(&[])),
+    ("auth.test", Some(&[]), Some(&[])),
+    ("bookmarks.add", Some(&[BOOKMARKS_WRITE]), Some(&[BOOKMARKS_WRITE])),
+    ("bookmarks.edit", Some(&[BOOKMARKS_WRITE]), Some(&[BOOKMARKS_WRITE])),
+    ("bookmarks.list", Some(&[BOOKMARKS_READ]), Some(&[BOOKMARKS_READ])),
+    ("bookmarks.remove", Some(&[BOOKMARKS_WRITE]), Some(&[BOOKMARKS_WRITE])),
+    ("bots.info", Some(&[USERS_READ]), Some(&[USERS_READ])),
+    ("calls.add", Some(&[CALLS_WRITE]), Some(&[CALLS_WRITE])),
+    ("calls.end", Some(&[CALLS_WRITE]), Some(&[CALLS_WRITE])),
+    ("calls.info", Some(&[CALLS_READ]), Some(&[CALLS_READ])),
+    ("calls.participants.add", Some(&[CALLS_WRITE]), Some(&[CALLS_WRITE])),
+    ("calls.participants.remove", Some(&[CALLS_WRITE]), Some(&[CALLS_WRITE])),
+    ("calls.update", Some(&[CALLS_WRITE]), Some(&[CALLS_WRITE])),
+    ("channels.archive", None, Some(&[CHANNELS_WRITE])),
+    ("channels.create", None, Some(&[CHANNELS_WRITE])),
+    ("channels.history", None, Some(&[CHANNELS_HISTORY])),
+    ("channels.info", None, Some(&[CHANNELS_READ])),
+    ("channels.invite", None, Some(&[CHANNELS_WRITE])),
+    ("channels.join", None, Some(&[CHANNELS_WRITE])),
+    ("channels.kick", None, Some(&[CHANNELS_WRITE])),
+    ("channels.leave", None, Some(&[CHANNELS_WRITE])),
+    ("channels.list", None, Some(&[CHANNELS_READ])),
+    ("channels.mark", None, Some(&[CHANNELS_WRITE])),
+    ("channels.rename", None, Some(&[CHANNELS_WRITE])),
+    ("channels.replies", None, Some(&[CHANNELS_HISTORY])),
+    ("channels.setPurpose", None, Some(&[CHANNELS_WRITE])),
+    ("channels.setTopic", None, Some(&[CHANNELS_WRITE])),
+    ("channels.unarchive", None, Some(&[CHANNELS_WRITE])),
+    ("chat.delete", Some(&[CHAT_WRITE]), Some(&[CHAT_WRITE])),
+    ("chat.deleteScheduledMessage", Some(&[CHAT_WRITE]), Some(&[CHAT_WRITE])),
+    ("chat.meMessage", Some(&[CHAT_WRITE]), Some(&[CHAT_WRITE])),
+    ("chat.postEphemeral", Some(&[CHAT_WRITE]), Some(&[CHAT_WRITE])),
+    ("chat.postMessage", Some(&[CHAT_WRITE]), Some(&[CHAT_WRITE])),
+    ("chat.scheduleMessage", Some(&[CHAT_WRITE]), Some(&[CHAT_WRITE])),
+    ("chat.scheduledMessages.list", Some(&[]), Some(&[])),
+    ("chat.unfurl", Some(&[LINKS_WRITE]), Some(&[LINKS_WRITE])),
+    ("chat.update", Some(&[CHAT_WRITE]), Some(&[CHAT_WRITE])),
+    ("conversations.archive", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("conversations.close", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("conversations.create", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("conversations.history", Some(CONVERSATION_HISTORY), Some(CONVERSATION_HISTORY)),
+    ("conversations.info", Some(READ_CONVERSATIONS), Some(READ_CONVERSATIONS)),
+    ("conversations.invite", Some(&[CHANNELS_MANAGE, GROUPS_WRITE]), Some(&[CHANNELS_WRITE, GROUPS_WRITE])),
+    ("conversations.join", Some(&[CHANNELS_JOIN]), Some(&[CHANNELS_WRITE])),
+    ("conversations.kick", Some(&[CHANNELS_MANAGE, GROUPS_WRITE]), Some(&[CHANNELS_WRITE, GROUPS_WRITE])),
+    ("conversations.leave", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("conversations.list", Some(READ_CONVERSATIONS), Some(READ_CONVERSATIONS)),
+    ("conversations.mark", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("conversations.members", Some(READ_CONVERSATIONS), Some(READ_CONVERSATIONS)),
+    ("conversations.open", Some(&[IM_WRITE, MPIM_WRITE]), Some(&[IM_WRITE, MPIM_WRITE])),
+    ("conversations.rename", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("conversations.replies", Some(CONVERSATION_HISTORY), Some(CONVERSATION_HISTORY)),
+    ("conversations.setPurpose", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("conversations.setTopic", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("conversations.unarchive", Some(MANAGE_CONVERSATIONS), Some(MANAGE_CONVERSATIONS)),
+    ("dnd.endDnd", None, Some(&[DND_WRITE])),
+    ("dnd.endSnooze", None, Some(&[DND_WRITE])),
+    ("dnd.info", Some(&[DND_READ]), Some(&[DND_READ])),
+    ("dnd.setSnooze", None, Some(&[DND_WRITE])),
+    ("dnd.teamInfo", Some(&[DND_READ]), Some(&[DND_READ])),
+    ("emoji.list", Some(&[EMOJI_READ]), Some(&[EMOJI_READ])),
+    ("files.comments.add", Some(&[FILES_WRITE]), Some(&[FILES_WRITE])),
+    ("files.comments.delete", Some(&[FILES_WRITE]), Some(&[FILES_WRITE])),
+    ("files.comments.edit", Some(&[FILES_WRITE]), Some(&[FILES_WRITE])),
+    ("files.completeUploadExternal", Some(&[FILES_WRITE]), Some(&[FILES_WRITE])),
+    ("files.delete", Some(&[FILES_WRITE]), Some(&[FILES_WRITE])),
+    ("files.getUploadURLExternal", Some(&[FILES_WRITE]), Some(&[FILES_WRITE])),
+    ("files.info", Some(&[FILES_READ]), Some(&[FILES_READ])),
+    ("files.list", Some(&[FILES_READ]), Some(&[FILES_READ])),
+    ("files.revokePublicURL", None, Some(&[FILES_WRITE])),
+    ("files.sharedPublicURL", None, Some(&[FILES_WRITE])),
+    ("files.upload", Some(&[FILES_WRITE]), Some(&[FILES_WRITE])),
+    ("groups.archive", None, Some(&[GROUPS_WRITE])),
+    ("groups.close", None, Some(&[GROUPS_WRITE])),
+    ("groups.create", None, Some(&[GROUPS_WRITE])),
+    ("groups.createChild", None, Some(&[GROUPS_WRITE])),
+    ("groups.history", None, Some(&[GROUPS_HISTORY])),
+    ("groups.info", None, Some(&[GROUPS_READ])),
+    ("groups.invite", None, Some(&[GROUPS_WRITE])),
+    ("groups.kick", None, Some(&[GROUPS_WRITE])),
+    ("groups.leave", None, Some(&[GROUPS_WRITE])),
+    ("groups.list", None, Some(&[GROUPS_READ])),
+    ("groups.mark", None, Some(&[GROUPS_WRITE])),
+    ("groups.open", None, Some(&[GROUPS_WRITE])),
+    ("groups.rename", None, Some(&[GROUPS_WRITE])),
+    ("groups.replies", None, Some(&[GROUPS_HISTORY])),
+    ("groups.setPurpose", None, Some(&[GROUPS_WRITE])),
+    ("groups.setTopic", None, Some(&[GROUPS_WRITE])),
+    ("groups.unarchive", None, Some(&[GROUPS_WRITE])),
+    ("im.close", None, Some(&[IM_WRITE])),
+    ("im.history", None, Some(&[IM_HISTORY])),
+    ("im.list", None, Some(&[IM_READ])),
+    ("im.mark", None, Some(&[IM_WRITE])),
+    ("im.open", None, Some(&[IM_WRITE])),
+    ("im.replies", None, Some(&[IM_HISTORY])),
+    ("mpim.close", None, Some(&[MPIM_WRITE])),
+    ("mpim.history", None, Some(&[MPIM_HISTORY])),
+    ("mpim.list", None, Some(&[MPIM_READ])),
+    ("mpim.mark", None, Some(&[MPIM_WRITE])),
+    ("mpim.open", None, Some(&[MPIM_WRITE])),
+    ("mpim.replies", None, Some(&[MPIM_HISTORY])),
+    ("oauth.access", Some(&[]), Some(&[])),
+    ("oauth.v2.access", Some(&[]), Some(&[])),
+    ("oauth.v2.exchange", Some(&[]), Some(&[])),
+    ("openid.connect.token", Some(&[]), Some(&[])),
+    ("openid.connect.userInfo", None, Some(&[OPENID])),
+    ("pins.add", Some(&[PINS_WRITE]), Some(&[PINS_WRITE])),
+    ("pins.list", Some(&[PINS_READ]), Some(&[PINS_READ])),
+    ("pins.remove", Some(&[PINS_WRITE]), Some(&[PINS_WRITE])),
+    ("reactions.add", Some(&[REACTIONS_WRITE]), Some(&[REACTIONS_WRITE])),
+    ("reactions.get", Some(&[REACTIONS_READ]), Some(&[REACTIONS_READ])),
+    ("reactions.list", Some(&[REACTIONS_READ]), Some(&[REACTIONS_READ])),
+    ("reactions.remove", Some(&[REACTIONS_WRITE]), Some(&[REACTIONS_WRITE])),
+    ("reminders.add", None, Some(&[REMINDERS_WRITE])),
+    ("reminders.complete", None, Some(&[REMINDERS_WRITE])),
+    ("reminders.delete", None, Some(&[REMINDERS_WRITE])),
+    ("reminders.info", None, Some(&[REMINDERS_READ])),
+    ("reminders.list", None, Some(&[REMINDERS_READ])),
+    ("rtm.connect", Some(&[]), Some(&[CLIENT])),
+    ("rtm.start", Some(&[]), Some(&[CLIENT])),
+    ("search.all", None, Some(&[SEARCH_READ])),
+    ("search.files", None, Some(&[SEARCH_READ])),
+    ("search.messages", None, Some(&[SEARCH_READ])),
+    ("stars.add", None, Some(&[STARS_WRITE])),
+    ("stars.list", None, Some(&[STARS_READ])),
+    ("stars.remove", None, Some(&[STARS_WRITE])),
+    ("team.accessLogs", None, Some(&[ADMIN])),
+    ("team.billableInfo", None, Some(&[ADMIN])),
+    ("team.billing.info", Some(&[TEAM_BILLING_READ]), Some(&[TEAM_BILLING_READ])),
+    ("team.info", Some(&[TEAM_READ]), Some(&[TEAM_READ])),
+    ("team.integrationLogs", None, Some(&[ADMIN])),
+    ("team.profile.get", Some(&[USERS_PROFILE_READ]), Some(&[USERS_PROFILE_READ])),
+    ("usergroups.create", Some(&[USERGROUPS_WRITE]), Some(&[USERGROUPS_WRITE])),
+    ("usergroups.disable", Some(&[USERGROUPS_WRITE]), Some(&[USERGROUPS_WRITE])),
+    ("usergroups.enable", Some(&[USERGROUPS_WRITE]), Some(&[USERGROUPS_WRITE])),
+    ("usergroups.list", Some(&[USERGROUPS_READ]), Some(&[USERGROUPS_READ])),
+    ("usergroups.update", Some(&[USERGROUPS_WRITE]), Some(&[USERGROUPS_WRITE])),
+    ("usergroups.users.list", Some(&[USERGROUPS_READ]), Some(&[USERGROUPS_READ])),
+    ("usergroups.users.update", Some(&[USERGROUPS_WRITE]), Some(&[USERGROUPS_WRITE])),
+    ("users.deletePhoto", None, Some(&[USERS_PROFILE_WRITE])),
+    ("users.getPresence", Some(&[USERS_READ]), Some(&[USERS_READ])),
+    ("users.identity", None, Some(&[IDENTITY_BASIC])),
+    ("users.info", Some(&[USERS_READ]), Some(&[USERS_READ])),
+    ("users.list", Some(&[USERS_READ]), Some(&[USERS_READ])),
+    ("users.profile.get", Some(&[USERS_PROFILE_READ]), Some(&[USERS_PROFILE_READ])),
+    ("users.profile.set", None, Some(&[USERS_PROFILE_WRITE])),
+    ("users.setActive", Some(&[USERS_WRITE]), Some(&[USERS_WRITE])),
+    ("users.setPhoto", None, Some(&[USERS_PROFILE_WRITE])),
+    ("users.setPresence", Some(&[USERS_WRITE]), Some(&[USERS_WRITE])),
+    ("views.open", Some(&[]), Some(&[])),
+    ("views.publish", Some(&[]), None),
+    ("views.push", Some(&[]), Some(&[])),
+    ("views.update", Some(&[]), Some(&[])),
+];
+
+/// The scopes an API method needs. Any one of them is enough, e.g. `conversations.history` needs
+/// `channels:history` for public channels and `im:history` for direct messages.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MethodScopes {
+    /// The scopes with a bot token, or `None` if bot tokens cannot call the method.
+    pub bot: Option<&'static [&'static str]>,
+    /// The scopes with a user token, or `None` if user tokens cannot call the method.
+    pub user: Option<&'static [&'static str]>,
+}
+
+impl MethodScopes {
+    /// The scopes with a token of type `token_type`, or `None` if it cannot call the method.
+    pub fn with(&self, token_type: TokenType) -> Option<&'static [&'static str]> {
+        match token_type {
+            TokenType::Bot => self.bot,
+            TokenType::User => self.user,
+            _ => Some(&[]),
+        }
+    }
+}
+
+/// The scopes `method`, like `"chat.postMessage"`, needs, or `None` if this library does not know.
+pub fn method_scopes(method: &str) -> Option<MethodScopes> {
+    METHOD_SCOPES.binary_search_by(|&(m, _, _)| m.cmp(method)).ok().map(|i| {
+        let (_, bot, user) = METHOD_SCOPES[i];
+        MethodScopes {
+            bot: bot,
+            user: user,
+        }
+    })
+}
+
+/// Checks that a token of type `token_type` that was granted `granted` can call `method`.
+///
+/// Methods this library does not know and tokens other than bot and user tokens pass, and so do
+/// tokens with the legacy `bot` or `client` scopes, which cover most methods.
+pub fn check(method: &str, token_type: TokenType, granted: &[String]) -> Result<(), MissingScope> {
+    if granted.iter().any(|s| s == BOT || s == CLIENT) {
+        return Ok(());
+    }
+    let needed = match method_scopes(method) {
+        Some(scopes) => scopes.with(token_type),
+        None => return Ok(()),
+    };
+    match needed {
+        Some(needed) if needed.is_empty() || needed.iter().any(|n| granted.iter().any(|g| g == n)) => Ok(()),
+        _ => {
+            Err(MissingScope {
+                method: method.to_owned(),
+                token_type: token_type,
+                needed: needed.unwrap_or(&[]),
+            })
+        }
+    }
+}
+
+/// A call a token is not allowed to make.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissingScope {
+    pub method: String,
+    pub token_type: TokenType,
+    /// The scopes one of which the token needs, or none if tokens of its type cannot call the
+    /// method at all.
+    pub needed: &'static [&'static str],
+}
+
+impl fmt::Display for MissingScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.needed.is_empty() {
+            write!(f, "{} cannot be called with a {:?} token", self.method, self.token_type)
+        } else {
+            write!(f, "{} needs one of the scopes {}", self.method, self.needed.join(", "))
+        }
+    }
+}
+
+impl Error for MissingScope {
+    fn description(&self) -> &str {
+        "the token is missing a scope the method needs"
+    }
+}
+
+/// Wraps a request sender, failing calls the token is not allowed to make before they are sent.
+///
+/// The scopes granted to each token are learnt from the `X-OAuth-Scopes` header of its responses,
+/// so the first call with a token is always sent. To check that one too, seed the scopes from
+/// [`introspect`](../tokens/fn.introspect.html) with [`ScopeChecked::set_granted`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::scopes::{ScopeChecked, ScopeError};
+/// let client = ScopeChecked::new(slack_api::requests::default_client().unwrap());
+/// let identity = slack_api::tokens::introspect(&client, "xoxb-...").unwrap();
+/// client.set_granted("xoxb-...", identity.scopes);
+///
+/// match slack_api::search::messages(&client, "xoxb-...", &Default::default()) {
+///     Err(slack_api::search::MessagesError::Client(ScopeError::Missing(missing))) => println!("{}", missing),
+///     other => println!("{:?}", other),
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ScopeChecked<R> {
+    sender: R,
+    granted: RwLock<HashMap<Secret, Vec<String>>>,
+}
+
+impl<R: SlackWebRequestSender> ScopeChecked<R> {
+    pub fn new(sender: R) -> Self {
+        ScopeChecked {
+            sender: sender,
+            granted: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// The underlying request sender.
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+
+    /// The scopes granted to `token`, if known.
+    pub fn granted(&self, token: &str) -> Option<Vec<String>> {
+        self.granted.read().unwrap().get(&Secret::from(token)).cloned()
+    }
+
+    /// Sets the scopes granted to `token`.
+    pub fn set_granted(&self, token: &str, scopes: Vec<String>) {
+        self.granted.write().unwrap().insert(Secret::from(token), scopes);
+    }
+}
+
+impl<R: SlackWebRequestSender> SlackWebRequestSender for ScopeChecked<R> {
+    type Error = ScopeError<R::Error>;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        let token = params.iter().find(|&&(name, _)| name == "token").map(|&(_, value)| value);
+        self.check_scopes(method_url, token, || self.sender.send_raw(method_url, params))
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.check_scopes(method_url, Some(token), || self.sender.send_json(method_url, token, body))
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        let token = params.iter().find(|&&(name, _)| name == "token").map(|&(_, value)| value);
+        self.check_scopes(method_url, token, || self.sender.send_form(method_url, params))
+    }
+}
+
+impl<R: SlackWebRequestSender> ScopeChecked<R> {
+    fn check_scopes<F>(&self, method_url: &str, token: Option<&str>, send: F) -> Result<SlackWebResponse, ScopeError<R::Error>>
+        where F: FnOnce() -> Result<SlackWebResponse, R::Error>
+    {
+        if let Some(token) = token {
+            if let Some(granted) = self.granted(token) {
+                let method = method_url.rsplit('/').next().unwrap_or(method_url);
+                check(method, TokenType::of(token), &granted).map_err(ScopeError::Missing)?;
+            }
+        }
+
+        let response = send().map_err(ScopeError::Client)?;
+        if let (Some(token), Some(scopes)) = (token, response.header("x-oauth-scopes")) {
+            let scopes = scopes.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_owned).collect();
+            self.set_granted(token, scopes);
+        }
+        Ok(response)
+    }
+}
+
+#[derive(Debug)]
+pub enum ScopeError<E: Error> {
+    /// The token is not allowed to make the call, which was not sent.
+    Missing(MissingScope),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for ScopeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &ScopeError::Missing(ref missing) => write!(f, "{}", missing),
+            &ScopeError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error> Error for ScopeError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &ScopeError::Missing(ref missing) => missing.description(),
+            &ScopeError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &ScopeError::Missing(ref missing) => Some(missing),
+            &ScopeError::Client(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_method_scopes_are_sorted() {
+        assert!(METHOD_SCOPES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(Some(&[CHAT_WRITE][..]), method_scopes("chat.postMessage").unwrap().bot);
+        assert_eq!(None, method_scopes("search.messages").unwrap().bot);
+        assert_eq!(Some(&[ADMIN_TEAMS_READ][..]), method_scopes("admin.emoji.list").unwrap().user);
+    }
+
+    #[test]
+    fn test_scope_checked() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("auth.test").returns(r#"{"ok": true}"#))
+            .expect(Expectation::new("chat.postMessage").returns(r#"{"ok": true}"#).times(1));
+        let client = ScopeChecked::new(mock);
+        client.set_granted("xoxb-1", vec![CHAT_WRITE.to_owned(), USERS_READ.to_owned()]);
+
+        client.send("https://slack.com/api/chat.postMessage", &[("token", "xoxb-1")]).unwrap();
+        match client.send("https://slack.com/api/reactions.add", &[("token", "xoxb-1")]) {
+            Err(ScopeError::Missing(missing)) => assert_eq!(&[REACTIONS_WRITE], missing.needed),
+            other => panic!("expected reactions:write to be missing, got {:?}", other),
+        }
+        match client.send("https://slack.com/api/search.messages", &[("token", "xoxb-1")]) {
+            Err(ScopeError::Missing(missing)) => assert!(missing.needed.is_empty()),
+            other => panic!("expected bots not to be able to search, got {:?}", other),
+        }
+        // Unknown tokens are sent as they are.
+        client.send("https://slack.com/api/auth.test", &[("token", "xoxb-2")]).unwrap();
+        client.sender().verify();
+    }
+}
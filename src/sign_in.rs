@@ -0,0 +1,265 @@
+//! Sign in with Slack, built on the `openid.connect.*` methods.
+//!
+//! The flow has two steps: send the user to the URL from [`SignInFlow::authorize`], remembering
+//! the nonce, then hand the `code` from the redirect to [`SignInFlow::exchange`].
+
+use std::error::Error;
+use std::fmt;
+
+use base64;
+use serde_json;
+use url::form_urlencoded;
+
+use openid_connect;
+use requests::SlackWebRequestSender;
+use secret::Secret;
+
+const AUTHORIZE_URL: &'static str = "https://slack.com/openid/connect/authorize";
+const ISSUER: &'static str = "https://slack.com";
+
+/// The app credentials and settings used for Sign in with Slack.
+#[derive(Clone, Debug)]
+pub struct SignInFlow<'a> {
+    /// Issued when you created your application.
+    pub client_id: &'a str,
+    /// Issued when you created your application.
+    pub client_secret: Secret<&'a str>,
+    /// Where Slack sends the user after they approve the sign in.
+    pub redirect_uri: &'a str,
+    /// The OpenID scopes to request. `openid` is required; `email` and `profile` are optional.
+    pub scopes: &'a [&'a str],
+    /// Sends users straight to this workspace's sign in page.
+    pub team: Option<&'a str>,
+}
+
+/// Where to send the user to sign in.
+#[derive(Clone, Debug)]
+pub struct AuthorizeRedirect {
+    pub url: String,
+    /// Must be kept (e.g. in the user's session) and passed to [`SignInFlow::exchange`].
+    pub nonce: String,
+}
+
+/// A user who signed in with Slack.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SlackIdentity {
+    pub user_id: String,
+    pub team_id: String,
+    pub team_name: Option<String>,
+    pub team_domain: Option<String>,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub name: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub picture: Option<String>,
+    pub locale: Option<String>,
+}
+
+/// The claims of an `id_token` issued by Slack.
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub aud: String,
+    pub exp: u64,
+    pub iat: Option<u64>,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub name: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub picture: Option<String>,
+    pub locale: Option<String>,
+    #[serde(rename = "https://slack.com/user_id")]
+    pub user_id: String,
+    #[serde(rename = "https://slack.com/team_id")]
+    pub team_id: String,
+    #[serde(rename = "https://slack.com/team_name")]
+    pub team_name: Option<String>,
+    #[serde(rename = "https://slack.com/team_domain")]
+    pub team_domain: Option<String>,
+}
+
+impl<'a> SignInFlow<'a> {
+    pub fn new(client_id: &'a str, client_secret: &'a str, redirect_uri: &'a str) -> Self {
+        SignInFlow {
+            client_id: client_id,
+            client_secret: Secret::new(client_secret),
+            redirect_uri: redirect_uri,
+            scopes: &["openid", "email", "profile"],
+            team: None,
+        }
+    }
+
+    /// Builds the URL to send the user to, with a freshly generated nonce.
+    ///
+    /// `state` is passed back untouched on the redirect and should be used to guard against CSRF.
+    pub fn authorize(&self, state: &str) -> AuthorizeRedirect {
+        let nonce = ::random_string(24);
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        query.append_pair("response_type", "code")
+            .append_pair("scope", &self.scopes.join(" "))
+            .append_pair("client_id", self.client_id)
+            .append_pair("state", state)
+            .append_pair("nonce", &nonce)
+            .append_pair("redirect_uri", self.redirect_uri);
+        if let Some(team) = self.team {
+            query.append_pair("team", team);
+        }
+        AuthorizeRedirect {
+            url: format!("{}?{}", AUTHORIZE_URL, query.finish()),
+            nonce: nonce,
+        }
+    }
+
+    /// Exchanges the `code` from the redirect for the identity of the user who signed in.
+    ///
+    /// The `id_token` is received directly from Slack over TLS, so rather than checking its
+    /// signature this validates its issuer, audience, expiry and nonce.
+    pub fn exchange<R>(&self, client: &R, code: &str, nonce: &str) -> Result<SlackIdentity, SignInError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let request = openid_connect::TokenRequest {
+            client_id: self.client_id,
+            client_secret: self.client_secret.expose(),
+            code: Some(code),
+            redirect_uri: Some(self.redirect_uri),
+            ..openid_connect::TokenRequest::default()
+        };
+        let response = openid_connect::token(client, &request).map_err(SignInError::Token)?;
+        let id_token = response.id_token.ok_or(SignInError::MissingIdToken)?;
+        let claims = decode_id_token(&id_token)?;
+        self.validate(&claims, nonce)?;
+
+        Ok(SlackIdentity {
+            user_id: claims.user_id,
+            team_id: claims.team_id,
+            team_name: claims.team_name,
+            team_domain: claims.team_domain,
+            email: claims.email,
+            email_verified: claims.email_verified,
+            name: claims.name,
+            given_name: claims.given_name,
+            family_name: claims.family_name,
+            picture: claims.picture,
+            locale: claims.locale,
+        })
+    }
+
+    fn validate<E: Error>(&self, claims: &IdTokenClaims, nonce: &str) -> Result<(), SignInError<E>> {
+        if claims.iss != ISSUER {
+            Err(SignInError::InvalidIssuer)
+        } else if claims.aud != self.client_id {
+            Err(SignInError::InvalidAudience)
+        } else if claims.exp <= ::unix_timestamp() {
+            Err(SignInError::Expired)
+        } else if claims.nonce.as_ref().map(String::as_str) != Some(nonce) {
+            Err(SignInError::NonceMismatch)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Decodes the claims of an `id_token` without checking its signature.
+pub fn decode_id_token<E: Error>(id_token: &str) -> Result<IdTokenClaims, SignInError<E>> {
+    let payload = id_token.split('.').nth(1).ok_or(SignInError::MalformedIdToken)?;
+    let payload = base64::decode_config(payload, base64::URL_SAFE_NO_PAD).map_err(|_| SignInError::MalformedIdToken)?;
+    serde_json::from_slice(&payload).map_err(|_| SignInError::MalformedIdToken)
+}
+
+#[derive(Debug)]
+pub enum SignInError<E: Error> {
+    /// `openid.connect.token` failed.
+    Token(openid_connect::TokenError<E>),
+    /// The token response did not include an `id_token`.
+    MissingIdToken,
+    /// The `id_token` was not a JWT with the expected claims.
+    MalformedIdToken,
+    /// The `id_token` was not issued by Slack.
+    InvalidIssuer,
+    /// The `id_token` was issued to a different app.
+    InvalidAudience,
+    /// The `id_token` has expired.
+    Expired,
+    /// The `id_token` nonce did not match the one the flow was started with.
+    NonceMismatch,
+}
+
+impl<E: Error> fmt::Display for SignInError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SignInError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SignInError::Token(ref inner) => inner.description(),
+            &SignInError::MissingIdToken => "the token response did not contain an id_token",
+            &SignInError::MalformedIdToken => "the id_token could not be decoded",
+            &SignInError::InvalidIssuer => "the id_token was not issued by Slack",
+            &SignInError::InvalidAudience => "the id_token was issued to a different client",
+            &SignInError::Expired => "the id_token has expired",
+            &SignInError::NonceMismatch => "the id_token nonce does not match",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SignInError::Token(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use base64;
+
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    fn id_token(claims: &str) -> String {
+        format!("eyJhbGciOiJSUzI1NiJ9.{}.c2lnbmF0dXJl",
+                base64::encode_config(claims.as_bytes(), base64::URL_SAFE_NO_PAD))
+    }
+
+    #[test]
+    fn test_id_token_validation() {
+        let flow = SignInFlow::new("123.456", "secret", "https://example.com/callback");
+        let token = id_token(r#"{"iss": "https://slack.com", "sub": "U1", "aud": "123.456", "exp": 99999999999,
+                                 "nonce": "abc", "https://slack.com/user_id": "U1",
+                                 "https://slack.com/team_id": "T1", "email": "a@example.com"}"#);
+        let claims = decode_id_token::<io::Error>(&token).unwrap();
+        assert_eq!("T1", claims.team_id);
+        assert_eq!(Some("a@example.com"), claims.email.as_ref().map(String::as_str));
+        assert!(flow.validate::<io::Error>(&claims, "abc").is_ok());
+        match flow.validate::<io::Error>(&claims, "other") {
+            Err(SignInError::NonceMismatch) => {}
+            other => panic!("expected a nonce mismatch, got {:?}", other),
+        }
+
+        assert!(decode_id_token::<io::Error>("not-a-jwt").is_err());
+    }
+    #[test]
+    fn test_exchange_posts_a_form() {
+        let flow = SignInFlow::new("123.456", "secret", "https://example.com/callback");
+        let token = id_token(r#"{"iss": "https://slack.com", "sub": "U1", "aud": "123.456", "exp": 99999999999,
+                                 "nonce": "abc", "https://slack.com/user_id": "U1",
+                                 "https://slack.com/team_id": "T1"}"#);
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("openid.connect.token")
+            .returns(format!(r#"{{"ok": true, "access_token": "xoxp-1", "id_token": "{}"}}"#, token)));
+
+        assert_eq!("U1", flow.exchange(&mock, "c1", "abc").unwrap().user_id);
+        let call = &mock.calls_of("openid.connect.token")[0];
+        assert!(call.form);
+        assert_eq!((Some("secret"), Some("c1")), (call.param("client_secret"), call.param("code")));
+    }
+}
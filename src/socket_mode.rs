@@ -0,0 +1,641 @@
+//! Receiving events over [Socket Mode](https://api.slack.com/apis/connections/socket), a
+//! WebSocket the app opens to Slack, instead of at a public Request URL.
+//!
+//! [`SocketMode::run`] asks `apps.connections.open` for a URL, connects to it and passes each
+//! envelope Slack sends to a [`SocketModeHandler`], acknowledging it with what the handler returns.
+//! When Slack asks the app to reconnect, which it does every few hours, a new URL is asked for and
+//! connected to. Apps that would rather pull envelopes can use a [`Connection`] directly.
+//!
+//! The WebSocket itself is spoken over a stream from a [`Connector`]. Slack's URLs are `wss://`
+//! ones, which [`TlsConnector`] connects to with the platform's TLS implementation, the one the
+//! `reqwest` feature uses. Apps can implement `Connector` with another TLS crate instead;
+//! [`TcpConnector`] only connects to plain `ws://` URLs.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use slack_api::dispatch::Dispatcher;
+//! # use slack_api::socket_mode::{SocketMode, TlsConnector};
+//! let client = slack_api::requests::default_client().unwrap();
+//! let connector = TlsConnector::new().unwrap();
+//! let dispatcher = Dispatcher::new()
+//!     .on_app_mention(|mention, _| println!("mentioned by {:?}: {:?}", mention.user, mention.text));
+//!
+//! let error = SocketMode::new(&client, "xapp-...", connector).run(&dispatcher);
+//! println!("stopped: {}", error);
+//! ```
+
+#[cfg(feature = "reqwest")]
+extern crate native_tls;
+
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+
+use base64;
+use rand;
+use serde::Deserialize;
+use serde_json::{self, Map, Value};
+use url::Url;
+
+use apps_connections::{self, OpenError};
+use dispatch::Dispatcher;
+use events::EventPayload;
+use interaction::{Interaction, SlashCommand};
+use requests::SlackWebRequestSender;
+
+/// How long to wait before reconnecting after a connection was lost, rather than closed by Slack,
+/// or before trying again to connect after a failure, by default.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// The longest wait between two failed attempts to connect.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(5 * 60);
+
+/// A message Slack sends over a Socket Mode connection, told apart by its `type`.
+#[derive(Clone, Debug)]
+pub enum Envelope {
+    /// Sent once the connection is ready.
+    Hello(Hello),
+    /// Slack is about to close the connection, and the app should open a new one.
+    Disconnect(Disconnect),
+    /// An Events API payload, as it would be sent to a Request URL.
+    EventsApi(Request),
+    /// An interaction with a block, a shortcut, a modal or a message action.
+    Interactive(Request),
+    /// A slash command.
+    SlashCommands(Request),
+    /// An envelope of a type this crate does not know of.
+    Other(Value),
+}
+
+impl Envelope {
+    /// The ID to acknowledge the envelope with, for the envelopes that carry a request.
+    pub fn envelope_id(&self) -> Option<&str> {
+        match self {
+            &Envelope::EventsApi(ref request) |
+            &Envelope::Interactive(ref request) |
+            &Envelope::SlashCommands(ref request) => Some(&request.envelope_id),
+            &Envelope::Other(ref value) => value.get("envelope_id").and_then(Value::as_str),
+            _ => None,
+        }
+    }
+}
+
+impl Deserialize for Envelope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::Deserializer
+    {
+        let value = Value::deserialize(deserializer)?;
+        let ty = value.get("type").and_then(Value::as_str).unwrap_or("").to_owned();
+        match &ty[..] {
+            "hello" => from_value(value, Envelope::Hello),
+            "disconnect" => from_value(value, Envelope::Disconnect),
+            "events_api" => from_value(value, Envelope::EventsApi),
+            "interactive" => from_value(value, Envelope::Interactive),
+            "slash_commands" => from_value(value, Envelope::SlashCommands),
+            _ => Ok(Envelope::Other(value)),
+        }
+    }
+}
+
+fn from_value<T, F, E>(value: Value, variant: F) -> Result<Envelope, E>
+    where T: Deserialize,
+          F: FnOnce(T) -> Envelope,
+          E: ::serde::de::Error
+{
+    serde_json::from_value(value).map(variant).map_err(|e| E::custom(&format!("{}", e)))
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Hello {
+    /// How many connections the app has open, this one included.
+    pub num_connections: Option<u32>,
+    pub connection_info: Option<ConnectionInfo>,
+    pub debug_info: Option<Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConnectionInfo {
+    pub app_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Disconnect {
+    /// Why, like `"warning"` shortly before a `"refresh_requested"`, or `"link_disabled"` when
+    /// Socket Mode was turned off for the app.
+    pub reason: Option<String>,
+    pub debug_info: Option<Value>,
+}
+
+/// A request delivered in an envelope, to be acknowledged with its `envelope_id`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Request {
+    pub envelope_id: String,
+    /// The payload, in the shape it would have been sent to a Request URL.
+    pub payload: Value,
+    /// Whether the acknowledgement can carry a payload, e.g. the reply to a slash command.
+    #[serde(default)]
+    pub accepts_response_payload: bool,
+    /// How many times this delivery was tried before, for Events API payloads.
+    pub retry_attempt: Option<u32>,
+    pub retry_reason: Option<String>,
+}
+
+impl Request {
+    /// The payload of an `events_api` envelope, as the types of the [`events`](../events/index.html)
+    /// module.
+    pub fn event_payload(&self) -> Result<EventPayload, serde_json::Error> {
+        serde_json::from_value(self.payload.clone())
+    }
+
+    /// The payload of an `interactive` envelope, as the types of the
+    /// [`interaction`](../interaction/index.html) module.
+    pub fn interaction(&self) -> Result<Interaction, serde_json::Error> {
+        serde_json::from_value(self.payload.clone())
+    }
+
+    /// The payload of a `slash_commands` envelope.
+    pub fn slash_command(&self) -> Result<SlashCommand, serde_json::Error> {
+        serde_json::from_value(self.payload.clone())
+    }
+}
+
+/// What is called with the envelopes received over Socket Mode.
+///
+/// It is implemented for closures, and for [`Dispatcher`], which handles the `events_api`
+/// envelopes.
+pub trait SocketModeHandler {
+    /// Handles `envelope`, returning the payload to acknowledge it with, if any. `hello` and
+    /// `disconnect` envelopes are handled by the connection and not passed on.
+    ///
+    /// Slack expects an acknowledgement within three seconds, so a handler with slow work to do
+    /// should hand it off to another thread.
+    fn handle(&self, envelope: &Envelope) -> Option<Value>;
+}
+
+impl<F: Fn(&Envelope) -> Option<Value>> SocketModeHandler for F {
+    fn handle(&self, envelope: &Envelope) -> Option<Value> {
+        self(envelope)
+    }
+}
+
+impl SocketModeHandler for Dispatcher {
+    fn handle(&self, envelope: &Envelope) -> Option<Value> {
+        if let &Envelope::EventsApi(ref request) = envelope {
+            self.dispatch(&request.payload);
+        }
+        None
+    }
+}
+
+/// The acknowledgement of the envelope `envelope_id`, to be sent back over the connection.
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::socket_mode::acknowledgement;
+/// assert_eq!(r#"{"envelope_id":"57d6a792-4d35-4d0b-b6aa-3361493e1caf"}"#,
+///            acknowledgement("57d6a792-4d35-4d0b-b6aa-3361493e1caf", None));
+/// ```
+pub fn acknowledgement(envelope_id: &str, payload: Option<&Value>) -> String {
+    let mut ack = Map::new();
+    ack.insert("envelope_id".to_owned(), envelope_id.into());
+    if let Some(payload) = payload {
+        ack.insert("payload".to_owned(), payload.clone());
+    }
+    Value::Object(ack).to_string()
+}
+
+/// Opens the streams the WebSocket is spoken over.
+pub trait Connector {
+    type Stream: Read + Write;
+
+    /// Connects to the host and port of `url`, over TLS for a `wss://` URL.
+    fn connect(&self, url: &Url) -> io::Result<Self::Stream>;
+}
+
+/// Connects to plain `ws://` URLs over TCP, e.g. to a proxy that takes care of TLS, or in tests.
+#[derive(Clone, Copy, Debug)]
+pub struct TcpConnector;
+
+impl Connector for TcpConnector {
+    type Stream = TcpStream;
+
+    fn connect(&self, url: &Url) -> io::Result<TcpStream> {
+        if url.scheme() != "ws" {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "a wss:// URL needs a connector that speaks TLS"));
+        }
+        let host = url.host_str().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "the URL has no host"))?;
+        TcpStream::connect((host, url.port_or_known_default().unwrap_or(80)))
+    }
+}
+
+/// Connects to `wss://` URLs over TLS, with the platform's TLS implementation: SChannel on
+/// Windows, Secure Transport on macOS and OpenSSL elsewhere. Enabled by the `reqwest` feature,
+/// whose client uses the same.
+#[cfg(feature = "reqwest")]
+#[derive(Clone)]
+pub struct TlsConnector {
+    connector: native_tls::TlsConnector,
+}
+
+#[cfg(feature = "reqwest")]
+impl TlsConnector {
+    /// A connector that checks certificates against the platform's root certificates.
+    pub fn new() -> io::Result<Self> {
+        let connector = native_tls::TlsConnector::builder()
+            .and_then(|builder| builder.build())
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+        Ok(TlsConnector { connector: connector })
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl fmt::Debug for TlsConnector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("TlsConnector")
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Connector for TlsConnector {
+    type Stream = native_tls::TlsStream<TcpStream>;
+
+    fn connect(&self, url: &Url) -> io::Result<Self::Stream> {
+        if url.scheme() != "wss" {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "a ws:// URL needs a TcpConnector"));
+        }
+        let host = url.host_str().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "the URL has no host"))?;
+        let stream = TcpStream::connect((host, url.port_or_known_default().unwrap_or(443)))?;
+        self.connector
+            .connect(host, stream)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))
+    }
+}
+
+/// Receives envelopes over Socket Mode, reconnecting whenever Slack asks to.
+#[derive(Debug)]
+pub struct SocketMode<'a, R: 'a, C> {
+    client: &'a R,
+    app_token: &'a str,
+    connector: C,
+    reconnect_delay: Duration,
+}
+
+impl<'a, R: SlackWebRequestSender, C: Connector> SocketMode<'a, R, C> {
+    /// Uses `client` to call `apps.connections.open` with `app_token`, an app-level token with
+    /// the `connections:write` scope, and `connector` to connect to the URLs it returns.
+    pub fn new(client: &'a R, app_token: &'a str, connector: C) -> Self {
+        SocketMode {
+            client: client,
+            app_token: app_token,
+            connector: connector,
+            reconnect_delay: RECONNECT_DELAY,
+        }
+    }
+
+    /// How long [`run`](#method.run) waits before reconnecting after a connection was lost, and
+    /// before its first retry after failing to connect. The wait doubles with every failure that
+    /// follows, up to five minutes. Defaults to a second.
+    pub fn reconnect_delay(mut self, delay: Duration) -> Self {
+        self.reconnect_delay = delay;
+        self
+    }
+
+    /// Opens a new connection, at a URL fresh from `apps.connections.open`.
+    pub fn connect(&self) -> Result<Connection<C::Stream>, SocketModeError<R::Error>> {
+        let url = apps_connections::open(self.client, self.app_token).map_err(SocketModeError::Open)?.url;
+        let url = url.and_then(|url| Url::parse(&url).ok())
+            .ok_or_else(|| SocketModeError::Open(OpenError::Unknown("no URL".to_owned())))?;
+        let stream = self.connector.connect(&url).map_err(SocketModeError::Connect)?;
+        Connection::handshake(stream, &url).map_err(SocketModeError::Connect)
+    }
+
+    /// Passes every envelope Slack sends to `handler`, over one connection after the other.
+    ///
+    /// Failures to connect that are likely to pass, like Slack or the network being down, are
+    /// retried with backoff, so this only returns when a new connection cannot be opened at all,
+    /// e.g. because the token was revoked, with the reason why.
+    pub fn run<H: SocketModeHandler>(&self, handler: &H) -> SocketModeError<R::Error> {
+        let mut delay = self.reconnect_delay;
+        loop {
+            let mut connection = match self.connect() {
+                Ok(connection) => connection,
+                Err(ref err) if err.is_transient() => {
+                    thread::sleep(delay);
+                    delay = cmp::min(delay * 2, MAX_RECONNECT_DELAY);
+                    continue;
+                }
+                Err(err) => return err,
+            };
+            delay = self.reconnect_delay;
+            if connection.serve(handler).is_err() {
+                thread::sleep(delay);
+            }
+        }
+    }
+}
+
+/// One Socket Mode connection, over which envelopes are read and acknowledged.
+///
+/// Pings are answered while envelopes are read.
+#[derive(Debug)]
+pub struct Connection<S> {
+    stream: S,
+}
+
+impl<S: Read + Write> Connection<S> {
+    /// Opens the WebSocket to `url` over `stream`, which is connected to its host.
+    pub fn handshake(mut stream: S, url: &Url) -> io::Result<Self> {
+        let key = base64::encode(&rand::random::<[u8; 16]>());
+        let host = url.host_str().unwrap_or("");
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_owned(),
+        };
+        let target = &url[::url::Position::BeforePath..::url::Position::AfterQuery];
+        write!(stream,
+               "GET {} HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+                Sec-WebSocket-Key: {}\r\nSec-WebSocket-Version: 13\r\n\r\n",
+               target,
+               host,
+               key)?;
+        stream.flush()?;
+
+        // Read a byte at a time, so that no frame that follows the headers is read with them.
+        let mut head = Vec::new();
+        while !head.ends_with(b"\r\n\r\n") {
+            let mut byte = [0];
+            stream.read_exact(&mut byte)?;
+            head.push(byte[0]);
+        }
+        let head = String::from_utf8_lossy(&head);
+        if head.split_whitespace().nth(1) != Some("101") {
+            let status = head.lines().next().unwrap_or("").to_owned();
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("the WebSocket was refused: {}", status)));
+        }
+        Ok(Connection { stream: stream })
+    }
+
+    /// Reads the next envelope, or `None` once the connection is closed.
+    pub fn next_envelope(&mut self) -> io::Result<Option<Envelope>> {
+        let mut message = Vec::new();
+        loop {
+            let frame = read_frame(&mut self.stream)?;
+            match frame.opcode {
+                OPCODE_TEXT | OPCODE_CONTINUATION => {
+                    message.extend_from_slice(&frame.payload);
+                    if frame.fin {
+                        return serde_json::from_slice(&message)
+                            .map(Some)
+                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+                    }
+                }
+                OPCODE_PING => write_frame(&mut self.stream, OPCODE_PONG, &frame.payload, Some(rand::random()))?,
+                OPCODE_CLOSE => {
+                    // Closing the socket without echoing the close is fine if the echo fails.
+                    let _ = write_frame(&mut self.stream, OPCODE_CLOSE, &frame.payload, Some(rand::random()));
+                    return Ok(None);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Acknowledges the envelope `envelope_id`, with `payload` if it accepts one.
+    pub fn acknowledge(&mut self, envelope_id: &str, payload: Option<&Value>) -> io::Result<()> {
+        let ack = acknowledgement(envelope_id, payload);
+        write_frame(&mut self.stream, OPCODE_TEXT, ack.as_bytes(), Some(rand::random()))
+    }
+
+    /// Passes the envelopes read to `handler` and acknowledges them, until Slack asks the app to
+    /// reconnect or closes the connection.
+    pub fn serve<H: SocketModeHandler>(&mut self, handler: &H) -> io::Result<()> {
+        loop {
+            let envelope = match self.next_envelope()? {
+                Some(Envelope::Hello(_)) => continue,
+                Some(Envelope::Disconnect(_)) | None => return Ok(()),
+                Some(envelope) => envelope,
+            };
+            let payload = handler.handle(&envelope);
+            if let Some(envelope_id) = envelope.envelope_id() {
+                self.acknowledge(envelope_id, payload.as_ref())?;
+            }
+        }
+    }
+}
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+struct Frame {
+    fin: bool,
+    opcode: u8,
+    payload: Vec<u8>,
+}
+
+fn read_frame<S: Read>(stream: &mut S) -> io::Result<Frame> {
+    let mut head = [0; 2];
+    stream.read_exact(&mut head)?;
+    let len = match head[1] & 0x7F {
+        126 => {
+            let mut len = [0; 2];
+            stream.read_exact(&mut len)?;
+            u64::from(len[0]) << 8 | u64::from(len[1])
+        }
+        127 => {
+            let mut len = [0; 8];
+            stream.read_exact(&mut len)?;
+            len.iter().fold(0, |len, &byte| len << 8 | u64::from(byte))
+        }
+        len => u64::from(len),
+    };
+    let mut mask = [0; 4];
+    if head[1] & 0x80 != 0 {
+        stream.read_exact(&mut mask)?;
+    }
+    let mut payload = Vec::new();
+    stream.take(len).read_to_end(&mut payload)?;
+    if (payload.len() as u64) < len {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "the connection closed in the middle of a frame"));
+    }
+    for (i, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[i % 4];
+    }
+    Ok(Frame {
+        fin: head[0] & 0x80 != 0,
+        opcode: head[0] & 0x0F,
+        payload: payload,
+    })
+}
+
+/// Writes a final frame holding `payload`. Clients mask the frames they send; servers do not.
+fn write_frame<S: Write>(stream: &mut S, opcode: u8, payload: &[u8], mask: Option<[u8; 4]>) -> io::Result<()> {
+    let masked = if mask.is_some() { 0x80 } else { 0 };
+    let mut frame = vec![0x80 | opcode];
+    match payload.len() {
+        len if len < 126 => frame.push(masked | len as u8),
+        len if len <= 0xFFFF => {
+            frame.push(masked | 126);
+            frame.extend_from_slice(&[(len >> 8) as u8, len as u8]);
+        }
+        len => {
+            frame.push(masked | 127);
+            frame.extend((0..8).rev().map(|i| (len as u64 >> (8 * i)) as u8));
+        }
+    }
+    match mask {
+        Some(mask) => {
+            frame.extend_from_slice(&mask);
+            frame.extend(payload.iter().enumerate().map(|(i, byte)| byte ^ mask[i % 4]));
+        }
+        None => frame.extend_from_slice(payload),
+    }
+    stream.write_all(&frame)?;
+    stream.flush()
+}
+
+#[derive(Debug)]
+pub enum SocketModeError<E: Error> {
+    /// `apps.connections.open` would not give out a URL.
+    Open(OpenError<E>),
+    /// The URL could not be connected to, or the WebSocket could not be opened over it.
+    Connect(io::Error),
+}
+
+impl<E: Error> SocketModeError<E> {
+    /// Whether connecting could succeed later: Slack or the URL it gave could not be reached, or
+    /// `apps.connections.open` failed on Slack's side, rather than refusing the token.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            &SocketModeError::Connect(_) => true,
+            &SocketModeError::Open(OpenError::Client(_)) |
+            &SocketModeError::Open(OpenError::MalformedResponse(_)) |
+            &SocketModeError::Open(OpenError::RequestTimeout) |
+            &SocketModeError::Open(OpenError::TeamAddedToOrg) => true,
+            &SocketModeError::Open(OpenError::Unknown(ref code)) => {
+                ["ratelimited", "internal_error", "fatal_error", "service_unavailable"].contains(&&code[..])
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SocketModeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &SocketModeError::Open(ref inner) => write!(f, "{}", inner),
+            &SocketModeError::Connect(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error> Error for SocketModeError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SocketModeError::Open(ref inner) => inner.description(),
+            &SocketModeError::Connect(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SocketModeError::Open(ref inner) => Some(inner),
+            &SocketModeError::Connect(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::sync::Mutex;
+
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_serve() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}/link/?ticket=t1", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                let mut byte = [0];
+                stream.read_exact(&mut byte).unwrap();
+                head.push(byte[0]);
+            }
+            assert!(String::from_utf8(head).unwrap().starts_with("GET /link/?ticket=t1 HTTP/1.1\r\n"));
+            stream.write_all(b"HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\r\n").unwrap();
+
+            let hello = br#"{"type": "hello", "num_connections": 1}"#;
+            write_frame(&mut stream, OPCODE_TEXT, hello, None).unwrap();
+            write_frame(&mut stream, OPCODE_PING, b"p", None).unwrap();
+            let command = br#"{"type": "slash_commands", "envelope_id": "e1", "accepts_response_payload": true,
+                               "payload": {"command": "/deploy", "text": "main"}}"#;
+            write_frame(&mut stream, OPCODE_TEXT, command, None).unwrap();
+            let pong = read_frame(&mut stream).unwrap();
+            let ack = read_frame(&mut stream).unwrap();
+            write_frame(&mut stream, OPCODE_TEXT, br#"{"type": "disconnect", "reason": "refresh_requested"}"#, None)
+                .unwrap();
+            (pong.opcode, pong.payload, String::from_utf8(ack.payload).unwrap())
+        });
+
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("apps.connections.open").returns(&format!(r#"{{"ok": true, "url": "{}"}}"#, url)));
+        let handled = Mutex::new(Vec::new());
+        let handler = |envelope: &Envelope| {
+            handled.lock().unwrap().push(envelope.envelope_id().map(str::to_owned));
+            Some(json_value(r#"{"text": "Deploying main"}"#))
+        };
+        let mut connection = SocketMode::new(&mock, "xapp-1", TcpConnector).connect().unwrap();
+        connection.serve(&handler).unwrap();
+
+        assert_eq!(vec![Some("e1".to_owned())], *handled.lock().unwrap());
+        assert_eq!((OPCODE_PONG, b"p".to_vec(),
+                    r#"{"envelope_id":"e1","payload":{"text":"Deploying main"}}"#.to_owned()),
+                   server.join().unwrap());
+        assert_eq!(Some("xapp-1"), mock.calls_of("apps.connections.open")[0].param("token"));
+    }
+
+    #[test]
+    fn test_run_retries_transient_connect_failures() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("apps.connections.open").fails("connection refused").times(2))
+            .expect(Expectation::new("apps.connections.open").returns(r#"{"ok": true, "url": "ws://127.0.0.1:1/link"}"#)
+                .times(1))
+            .expect(Expectation::new("apps.connections.open").returns_error("invalid_auth"));
+        let handler = |_: &Envelope| None;
+
+        match SocketMode::new(&mock, "xapp-1", TcpConnector).reconnect_delay(Duration::from_millis(1)).run(&handler) {
+            SocketModeError::Open(OpenError::InvalidAuth) => {}
+            other => panic!("expected run to stop at invalid_auth, got {:?}", other),
+        }
+        assert_eq!(4, mock.calls_of("apps.connections.open").len());
+    }
+
+    #[test]
+    fn test_long_frames() {
+        let payload = vec![b'x'; 70000];
+        for &len in &[125, 126, 65535, 70000] {
+            let mut frame = Vec::new();
+            write_frame(&mut frame, OPCODE_TEXT, &payload[..len], Some([1, 2, 3, 4])).unwrap();
+            let read = read_frame(&mut &frame[..]).unwrap();
+            assert_eq!((true, OPCODE_TEXT, len), (read.fin, read.opcode, read.payload.len()));
+            assert!(read.payload.iter().all(|&byte| byte == b'x'));
+        }
+    }
+
+    fn json_value(json: &str) -> Value {
+        serde_json::from_str(json).unwrap()
+    }
+}
@@ -0,0 +1,233 @@
+//! Checking Slack's own health with the [Slack Status API](https://api.slack.com/docs/slack-status),
+//! e.g. before alerting on messages that could not be delivered.
+
+use std::error::Error;
+use std::fmt;
+
+use serde;
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// The base URL of the Slack Status API.
+pub const STATUS_API_URL: &'static str = "https://status.slack.com/api/v2.0.0/";
+
+/// Slack's health right now, as returned by [`current`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct Status {
+    pub active_incidents: Vec<Incident>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub status: Health,
+}
+
+impl Status {
+    /// Whether no incidents are active.
+    pub fn is_ok(&self) -> bool {
+        self.status == Health::Ok
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Health {
+    /// Everything works.
+    Ok,
+    /// There are active incidents.
+    Active,
+    /// The Status API itself is having trouble.
+    Broken,
+    Other(String),
+}
+
+/// An incident, outage or maintenance notice.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Incident {
+    /// When the incident started, in ISO 8601.
+    pub date_created: Option<String>,
+    /// When the incident was last updated, in ISO 8601.
+    pub date_updated: Option<String>,
+    pub id: i64,
+    #[serde(default)]
+    pub notes: Vec<IncidentNote>,
+    /// The affected services, like `"Messaging"` or `"Calls"`.
+    #[serde(default)]
+    pub services: Vec<String>,
+    pub status: IncidentStatus,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub ty: IncidentType,
+    /// The incident's page on status.slack.com.
+    pub url: Option<String>,
+}
+
+/// An update posted to an [`Incident`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct IncidentNote {
+    pub body: String,
+    pub date_created: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum IncidentStatus {
+    Active,
+    Resolved,
+    Other(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum IncidentType {
+    /// Something works worse than it should.
+    Incident,
+    /// Planned maintenance or other information.
+    Notice,
+    /// Something does not work at all.
+    Outage,
+    Other(String),
+}
+
+impl serde::Deserialize for Health {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match &value[..] {
+            "ok" => Health::Ok,
+            "active" => Health::Active,
+            "broken" => Health::Broken,
+            _ => Health::Other(value),
+        })
+    }
+}
+
+impl serde::Deserialize for IncidentStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match &value[..] {
+            "active" => IncidentStatus::Active,
+            "resolved" => IncidentStatus::Resolved,
+            _ => IncidentStatus::Other(value),
+        })
+    }
+}
+
+impl serde::Deserialize for IncidentType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match &value[..] {
+            "incident" => IncidentType::Incident,
+            "notice" => IncidentType::Notice,
+            "outage" => IncidentType::Outage,
+            _ => IncidentType::Other(value),
+        })
+    }
+}
+
+/// Returns Slack's health right now, with the incidents that are still active.
+///
+/// The Status API needs no token.
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+///
+/// let status = slack_api::status::current(&client).unwrap();
+/// for incident in &status.active_incidents {
+///     println!("{}: {}", incident.title, incident.services.join(", "));
+/// }
+/// ```
+pub fn current<R>(client: &R) -> Result<Status, StatusError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    fetch(client, "current")
+}
+
+/// Returns the incidents of the past few months, newest first.
+pub fn history<R>(client: &R) -> Result<Vec<Incident>, StatusError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    fetch(client, "history")
+}
+
+fn fetch<R, T>(client: &R, endpoint: &str) -> Result<T, StatusError<R::Error>>
+    where R: SlackWebRequestSender,
+          T: serde::Deserialize
+{
+    let url = format!("{}{}", STATUS_API_URL, endpoint);
+    client.send_bytes(&url, &[])
+        .map_err(StatusError::Client)
+        .and_then(|result| serde_json::from_slice(&result).map_err(StatusError::MalformedResponse))
+}
+
+#[derive(Debug)]
+pub enum StatusError<E: Error> {
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::Error),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for StatusError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for StatusError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &StatusError::MalformedResponse(ref e) => e.description(),
+            &StatusError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &StatusError::MalformedResponse(ref e) => Some(e),
+            &StatusError::Client(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_current_and_history() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("current").returns(r#"{
+                "status": "active",
+                "date_created": "2018-09-07T18:34:15-07:00",
+                "date_updated": "2018-09-07T18:34:15-07:00",
+                "active_incidents": [{
+                    "id": 546, "date_created": "2018-09-07T14:35:00-07:00",
+                    "date_updated": "2018-09-07T18:34:15-07:00",
+                    "title": "Slack's performance is degraded", "type": "incident", "status": "active",
+                    "url": "https://status.slack.com/2018-09/7dea1cd14cd0f657",
+                    "services": ["Messaging", "Connectivity"],
+                    "notes": [{"date_created": "2018-09-07T18:34:15-07:00", "body": "We're investigating."}]
+                }]
+            }"#))
+            .expect(Expectation::new("history").returns(r#"[
+                {"id": 545, "title": "Planned maintenance", "type": "notice", "status": "resolved", "services": []},
+                {"id": 544, "title": "Calls are down", "type": "rollback", "status": "resolved", "services": ["Calls"]}
+            ]"#));
+
+        let status = current(&mock).unwrap();
+        assert!(!status.is_ok());
+        assert_eq!(IncidentType::Incident, status.active_incidents[0].ty);
+        assert_eq!("We're investigating.", status.active_incidents[0].notes[0].body);
+
+        let incidents = history(&mock).unwrap();
+        assert_eq!(IncidentType::Notice, incidents[0].ty);
+        assert_eq!(IncidentType::Other("rollback".to_owned()), incidents[1].ty);
+        assert_eq!(IncidentStatus::Resolved, incidents[1].status);
+    }
+}
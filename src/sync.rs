@@ -0,0 +1,517 @@
+//! Mirroring state kept elsewhere, like an identity provider's groups, into Slack.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use admin_emoji;
+use requests::SlackWebRequestSender;
+use usergroups_users;
+
+/// What [`usergroup_members`] changed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemberChanges {
+    /// The users added to the group, sorted.
+    pub added: Vec<String>,
+    /// The users removed from the group, sorted.
+    pub removed: Vec<String>,
+}
+
+impl MemberChanges {
+    /// Whether the group already had exactly the wanted members.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Makes the members of `usergroup` exactly `members`, a list of user IDs.
+///
+/// The current members are listed first, and `usergroups.users.update` is only called if they
+/// differ.
+///
+/// Slack does not let a usergroup have no members, so an empty `members` is refused with
+/// [`SyncError::NoMembers`] before anything is sent. Disable the group with `usergroups.disable`
+/// instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// let client = slack_api::requests::default_client().unwrap();
+/// let oncall = ["U024BE7LH", "U0G9QF9C6"];
+///
+/// let changes = slack_api::sync::usergroup_members(&client, "xoxp-...", "S0614TZR7", &oncall).unwrap();
+/// println!("added {:?}, removed {:?}", changes.added, changes.removed);
+/// ```
+pub fn usergroup_members<R>(client: &R,
+                            token: &str,
+                            usergroup: &str,
+                            members: &[&str])
+                            -> Result<MemberChanges, SyncError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    if members.is_empty() {
+        return Err(SyncError::NoMembers);
+    }
+    let request = usergroups_users::ListRequest {
+        usergroup: usergroup,
+        include_disabled: Some(true),
+    };
+    let current = usergroups_users::list(client, token, &request).map_err(SyncError::List)?;
+    let current: BTreeSet<String> = current.users.unwrap_or_default().into_iter().collect();
+    let wanted: BTreeSet<String> = members.iter().map(|&m| m.to_owned()).collect();
+
+    let changes = MemberChanges {
+        added: wanted.difference(&current).cloned().collect(),
+        removed: current.difference(&wanted).cloned().collect(),
+    };
+    if changes.is_empty() {
+        return Ok(changes);
+    }
+
+    let users = wanted.into_iter().collect::<Vec<_>>().join(",");
+    let request = usergroups_users::UpdateRequest {
+        usergroup: usergroup,
+        users: &users,
+        ..usergroups_users::UpdateRequest::default()
+    };
+    usergroups_users::update(client, token, &request).map_err(SyncError::Update)?;
+    Ok(changes)
+}
+
+#[derive(Debug)]
+pub enum SyncError<E: Error> {
+    /// No members were given, which would leave the usergroup empty.
+    NoMembers,
+    /// Listing the current members failed.
+    List(usergroups_users::ListError<E>),
+    /// Updating the members failed.
+    Update(usergroups_users::UpdateError<E>),
+}
+
+impl<E: Error> fmt::Display for SyncError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SyncError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &SyncError::NoMembers => "a usergroup cannot be left without members",
+            &SyncError::List(ref inner) => inner.description(),
+            &SyncError::Update(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SyncError::NoMembers => None,
+            &SyncError::List(ref inner) => Some(inner),
+            &SyncError::Update(ref inner) => Some(inner),
+        }
+    }
+}
+
+/// What an emoji in an [`EmojiSync`] shows.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EmojiSource {
+    /// The image at a URL that Slack can fetch.
+    Image(String),
+    /// The same as the emoji with this name.
+    Alias(String),
+}
+
+impl EmojiSource {
+    /// Parses a value as `emoji.list` returns it: an image URL, or `alias:` and a name.
+    fn parse(value: &str) -> Self {
+        if value.starts_with("alias:") {
+            EmojiSource::Alias(value["alias:".len()..].to_owned())
+        } else {
+            EmojiSource::Image(value.to_owned())
+        }
+    }
+}
+
+/// The custom emoji a workspace should have, and how [`emoji`] applies them.
+///
+/// An emoji that exists with the wanted name is kept as it is, since Slack re-hosts the images,
+/// so they cannot be compared to the wanted ones; an alias pointing at the wrong emoji is
+/// replaced.
+///
+/// # Examples
+///
+/// Copying the emoji of one workspace to another:
+///
+/// ```no_run
+/// # use slack_api::sync::{self, EmojiSync};
+/// let client = slack_api::requests::default_client().unwrap();
+///
+/// let source = slack_api::emoji::list(&client, "xoxp-old...").unwrap();
+/// let wanted = EmojiSync::from_list(&source.emoji.unwrap_or_default()).dry_run(true);
+/// let report = sync::emoji(&client, "xoxp-new...", &wanted).unwrap();
+/// for change in &report.planned {
+///     println!("would {:?}", change);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct EmojiSync {
+    emoji: BTreeMap<String, EmojiSource>,
+    renames: Vec<(String, String)>,
+    remove_unlisted: bool,
+    pace: Duration,
+    dry_run: bool,
+}
+
+impl Default for EmojiSync {
+    fn default() -> Self {
+        EmojiSync {
+            emoji: BTreeMap::new(),
+            renames: Vec::new(),
+            remove_unlisted: false,
+            pace: Duration::from_secs(3),
+            dry_run: false,
+        }
+    }
+}
+
+impl EmojiSync {
+    pub fn new() -> Self {
+        EmojiSync::default()
+    }
+
+    /// The emoji in `list`, which maps names to values like the `emoji` of `emoji.list`
+    /// responses: image URLs, or `alias:` and a name.
+    pub fn from_list(list: &HashMap<String, String>) -> Self {
+        let mut sync = EmojiSync::new();
+        for (name, value) in list {
+            sync.emoji.insert(name.clone(), EmojiSource::parse(value));
+        }
+        sync
+    }
+
+    /// An emoji for every PNG, GIF or JPEG image in `dir`, named like the file without its
+    /// extension. Slack fetches the images from `base_url` followed by the file name, so the
+    /// directory has to be served there.
+    pub fn from_dir<P: AsRef<Path>>(dir: P, base_url: &str) -> io::Result<Self> {
+        let mut sync = EmojiSync::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let is_image = path.extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ["png", "gif", "jpg", "jpeg"].contains(&&*ext.to_lowercase()))
+                .unwrap_or(false);
+            if let (true, Some(name), Some(file)) = (is_image,
+                                                     path.file_stem().and_then(|n| n.to_str()),
+                                                     path.file_name().and_then(|n| n.to_str())) {
+                let url = format!("{}/{}", base_url.trim_end_matches('/'), file);
+                sync.emoji.insert(name.to_lowercase(), EmojiSource::Image(url));
+            }
+        }
+        Ok(sync)
+    }
+
+    /// Wants an emoji `name` showing the image at `url`.
+    pub fn image<S: Into<String>, U: Into<String>>(mut self, name: S, url: U) -> Self {
+        self.emoji.insert(name.into(), EmojiSource::Image(url.into()));
+        self
+    }
+
+    /// Wants an emoji `name` that is an alias for `alias_for`.
+    pub fn alias<S: Into<String>, A: Into<String>>(mut self, name: S, alias_for: A) -> Self {
+        self.emoji.insert(name.into(), EmojiSource::Alias(alias_for.into()));
+        self
+    }
+
+    /// Renames the emoji `from` to `to` if it exists and `to` does not, before anything else.
+    pub fn rename<S: Into<String>, T: Into<String>>(mut self, from: S, to: T) -> Self {
+        self.renames.push((from.into(), to.into()));
+        self
+    }
+
+    /// Removes the emoji that are not wanted. Off by default.
+    pub fn remove_unlisted(mut self, remove_unlisted: bool) -> Self {
+        self.remove_unlisted = remove_unlisted;
+        self
+    }
+
+    /// How long to wait between two changes. Defaults to three seconds, which keeps a sync
+    /// within the `admin.emoji` methods' rate limit.
+    pub fn pace(mut self, pace: Duration) -> Self {
+        self.pace = pace;
+        self
+    }
+
+    /// Only reports the changes, without making them. Off by default.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// The changes that turn the `current` emoji into the wanted ones, in the order in which to
+    /// make them.
+    fn plan(&self, mut current: BTreeMap<String, EmojiSource>) -> Vec<EmojiChange> {
+        let mut changes = Vec::new();
+        for &(ref from, ref to) in &self.renames {
+            if !current.contains_key(to) {
+                if let Some(source) = current.remove(from) {
+                    changes.push(EmojiChange::Rename {
+                        name: from.clone(),
+                        new_name: to.clone(),
+                    });
+                    current.insert(to.clone(), source);
+                }
+            }
+        }
+        // Images first, so that the aliases for them can be added.
+        let (images, aliases): (Vec<_>, Vec<_>) = self.emoji.iter().partition(|&(_, source)| match source {
+            &EmojiSource::Image(_) => true,
+            &EmojiSource::Alias(_) => false,
+        });
+        for (name, source) in images.into_iter().chain(aliases) {
+            let keep = match (current.get(name), source) {
+                (None, _) => false,
+                (Some(&EmojiSource::Image(_)), &EmojiSource::Image(_)) => true,
+                (Some(existing), wanted) => existing == wanted,
+            };
+            if keep {
+                continue;
+            }
+            if current.contains_key(name) {
+                changes.push(EmojiChange::Remove { name: name.clone() });
+            }
+            changes.push(match source {
+                &EmojiSource::Image(ref url) => {
+                    EmojiChange::Add {
+                        name: name.clone(),
+                        url: url.clone(),
+                    }
+                }
+                &EmojiSource::Alias(ref alias_for) => {
+                    EmojiChange::AddAlias {
+                        name: name.clone(),
+                        alias_for: alias_for.clone(),
+                    }
+                }
+            });
+        }
+        if self.remove_unlisted {
+            for name in current.keys().filter(|name| !self.emoji.contains_key(*name)) {
+                changes.push(EmojiChange::Remove { name: name.clone() });
+            }
+        }
+        changes
+    }
+}
+
+/// A change [`emoji`] makes to a workspace's emoji.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EmojiChange {
+    Add { name: String, url: String },
+    AddAlias { name: String, alias_for: String },
+    Rename { name: String, new_name: String },
+    Remove { name: String },
+}
+
+/// What [`emoji`] planned and did.
+#[derive(Debug)]
+pub struct EmojiReport<E: Error> {
+    /// Every change needed, in order.
+    pub planned: Vec<EmojiChange>,
+    /// The changes that were made. Empty in a dry run.
+    pub applied: Vec<EmojiChange>,
+    /// The changes that failed, and why.
+    pub failed: Vec<(EmojiChange, EmojiFailure<E>)>,
+}
+
+/// Why [`emoji`] could not make one change.
+#[derive(Debug)]
+pub enum EmojiFailure<E: Error> {
+    Add(admin_emoji::AddError<E>),
+    AddAlias(admin_emoji::AddAliasError<E>),
+    Rename(admin_emoji::RenameError<E>),
+    Remove(admin_emoji::RemoveError<E>),
+}
+
+/// Makes the custom emoji of the workspace those of `sync`, returning a report of the changes.
+///
+/// The current emoji are listed with `admin.emoji.list`, so this needs an admin's user token
+/// with the `admin.teams:read` scope, and `admin.teams:write` for the changes. A failed change is reported and does not stop the sync;
+/// only a failure to list the emoji does.
+pub fn emoji<R>(client: &R,
+                token: &str,
+                sync: &EmojiSync)
+                -> Result<EmojiReport<R::Error>, admin_emoji::ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut report = EmojiReport {
+        planned: sync.plan(list_emoji(client, token)?),
+        applied: Vec::new(),
+        failed: Vec::new(),
+    };
+    if sync.dry_run {
+        return Ok(report);
+    }
+    for (i, change) in report.planned.iter().enumerate() {
+        if i > 0 {
+            thread::sleep(sync.pace);
+        }
+        let result = match change {
+            &EmojiChange::Add { ref name, ref url } => {
+                let request = admin_emoji::AddRequest { name: name, url: url };
+                admin_emoji::add(client, token, &request).map(|_| ()).map_err(EmojiFailure::Add)
+            }
+            &EmojiChange::AddAlias { ref name, ref alias_for } => {
+                let request = admin_emoji::AddAliasRequest {
+                    alias_for: alias_for,
+                    name: name,
+                };
+                admin_emoji::add_alias(client, token, &request).map(|_| ()).map_err(EmojiFailure::AddAlias)
+            }
+            &EmojiChange::Rename { ref name, ref new_name } => {
+                let request = admin_emoji::RenameRequest {
+                    name: name,
+                    new_name: new_name,
+                };
+                admin_emoji::rename(client, token, &request).map(|_| ()).map_err(EmojiFailure::Rename)
+            }
+            &EmojiChange::Remove { ref name } => {
+                let request = admin_emoji::RemoveRequest { name: name };
+                admin_emoji::remove(client, token, &request).map(|_| ()).map_err(EmojiFailure::Remove)
+            }
+        };
+        match result {
+            Ok(()) => report.applied.push(change.clone()),
+            Err(err) => report.failed.push((change.clone(), err)),
+        }
+    }
+    Ok(report)
+}
+
+fn list_emoji<R>(client: &R,
+                 token: &str)
+                 -> Result<BTreeMap<String, EmojiSource>, admin_emoji::ListError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut emoji = BTreeMap::new();
+    let mut cursor = None;
+    loop {
+        let request = admin_emoji::ListRequest {
+            cursor: cursor.as_ref().map(String::as_str),
+            limit: Some(1000),
+        };
+        let response = admin_emoji::list(client, token, &request)?;
+        for (name, listed) in response.emoji.unwrap_or_default() {
+            if let Some(url) = listed.url {
+                emoji.insert(name, EmojiSource::parse(&url));
+            }
+        }
+        cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            return Ok(emoji);
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for EmojiFailure<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for EmojiFailure<E> {
+    fn description(&self) -> &str {
+        match self {
+            &EmojiFailure::Add(ref inner) => inner.description(),
+            &EmojiFailure::AddAlias(ref inner) => inner.description(),
+            &EmojiFailure::Rename(ref inner) => inner.description(),
+            &EmojiFailure::Remove(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &EmojiFailure::Add(ref inner) => Some(inner),
+            &EmojiFailure::AddAlias(ref inner) => Some(inner),
+            &EmojiFailure::Rename(ref inner) => Some(inner),
+            &EmojiFailure::Remove(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_usergroup_members() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("usergroups.users.list").returns(r#"{"ok": true, "users": ["U1", "U2"]}"#))
+            .expect(Expectation::new("usergroups.users.update").param("users", "U2,U3").times(1));
+
+        let changes = usergroup_members(&mock, "xoxp-1", "S1", &["U3", "U2"]).unwrap();
+        assert_eq!(MemberChanges { added: vec!["U3".to_owned()], removed: vec!["U1".to_owned()] }, changes);
+
+        assert!(usergroup_members(&mock, "xoxp-1", "S1", &["U1", "U2"]).unwrap().is_empty());
+        mock.verify();
+    }
+
+    #[test]
+    fn test_usergroup_members_refuses_no_members() {
+        let mock = MockSender::new();
+        match usergroup_members(&mock, "xoxp-1", "S1", &[]) {
+            Err(SyncError::NoMembers) => {}
+            other => panic!("expected no members to be refused, got {:?}", other),
+        }
+        assert!(mock.calls().is_empty());
+    }
+
+    #[test]
+    fn test_emoji() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("admin.emoji.list").returns(r#"{"ok": true, "emoji": {
+                "partyparrot": {"url": "https://emoji.slack-edge.com/T1/partyparrot/1.gif"},
+                "shipit": {"url": "alias:squirrel"},
+                "squirrel": {"url": "https://emoji.slack-edge.com/T1/squirrel/2.png"},
+                "old-logo": {"url": "https://emoji.slack-edge.com/T1/old-logo/3.png"},
+                "unused": {"url": "https://emoji.slack-edge.com/T1/unused/4.png"}
+            }}"#))
+            .expect(Expectation::new("admin.emoji.rename").param("new_name", "logo").times(1))
+            .expect(Expectation::new("admin.emoji.add").param("name", "ship").times(1))
+            .expect(Expectation::new("admin.emoji.remove").param("name", "shipit").times(1))
+            .expect(Expectation::new("admin.emoji.addAlias").param("alias_for", "ship").times(1))
+            .expect(Expectation::new("admin.emoji.remove").param("name", "unused").returns_error("emoji_not_found"));
+        let sync = EmojiSync::new()
+            .image("partyparrot", "https://example.com/emoji/partyparrot.gif")
+            .image("squirrel", "https://example.com/emoji/squirrel.png")
+            .image("ship", "https://example.com/emoji/ship.png")
+            .image("logo", "https://example.com/emoji/logo.png")
+            .alias("shipit", "ship")
+            .rename("old-logo", "logo")
+            .remove_unlisted(true)
+            .pace(Duration::from_millis(0));
+
+        let report = emoji(&mock, "xoxp-1", &sync).unwrap();
+
+        assert_eq!(vec![EmojiChange::Rename { name: "old-logo".to_owned(), new_name: "logo".to_owned() },
+                        EmojiChange::Add { name: "ship".to_owned(), url: "https://example.com/emoji/ship.png".to_owned() },
+                        EmojiChange::Remove { name: "shipit".to_owned() },
+                        EmojiChange::AddAlias { name: "shipit".to_owned(), alias_for: "ship".to_owned() },
+                        EmojiChange::Remove { name: "unused".to_owned() }],
+                   report.planned);
+        assert_eq!(4, report.applied.len());
+        match report.failed[..] {
+            [(EmojiChange::Remove { ref name }, EmojiFailure::Remove(admin_emoji::RemoveError::EmojiNotFound))] => {
+                assert_eq!("unused", name)
+            }
+            ref other => panic!("expected removing unused to fail, got {:?}", other),
+        }
+        mock.verify();
+    }
+}
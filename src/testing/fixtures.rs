@@ -0,0 +1,64 @@
+//! Real-world Slack payloads for testing code that handles Slack data.
+//!
+//! The payloads were captured from Slack and anonymised. Between them they cover every block
+//! type, the common message subtypes, the user profile variants (regular, owner, guest, bot,
+//! Slackbot, deactivated and Enterprise Grid users) and the common Events API events.
+//!
+//! # Examples
+//!
+//! ```
+//! # use slack_api::testing::fixtures;
+//! let history: slack_api::channels::HistoryResponse = fixtures::load(fixtures::CONVERSATIONS_HISTORY);
+//! assert!(history.messages.unwrap().len() > 1);
+//!
+//! let mention = &fixtures::events_of_type("app_mention")[0];
+//! assert_eq!(Some("<@U0BOTPAGER> status"), mention["event"]["text"].as_str());
+//! ```
+
+use serde::Deserialize;
+use serde_json::{self, Value};
+
+/// A `conversations.history` response with messages of several subtypes, threads, attachments,
+/// files and every block type.
+pub const CONVERSATIONS_HISTORY: &'static str = include_str!("fixtures/conversations_history.json");
+
+/// A `users.list` response with users of every profile variant.
+pub const USERS_LIST: &'static str = include_str!("fixtures/users_list.json");
+
+/// A JSON array of Events API payloads: a `url_verification` challenge, an `app_rate_limited`
+/// notice and `event_callback` envelopes for the common event types.
+pub const EVENTS: &'static str = include_str!("fixtures/events.json");
+
+/// Parses a fixture, panicking if it does not deserialize as `T`.
+pub fn load<T: Deserialize>(fixture: &str) -> T {
+    serde_json::from_str(fixture).unwrap_or_else(|e| panic!("fixture did not deserialize: {}", e))
+}
+
+/// Every payload in [`EVENTS`].
+pub fn events() -> Vec<Value> {
+    load(EVENTS)
+}
+
+/// The payloads in [`EVENTS`] whose inner event (or, for payloads without one, the payload
+/// itself) has type `ty`.
+pub fn events_of_type(ty: &str) -> Vec<Value> {
+    events()
+        .into_iter()
+        .filter(|payload| payload.get("event").unwrap_or(payload).get("type").and_then(Value::as_str) == Some(ty))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixtures_deserialize() {
+        let history: ::channels::HistoryResponse = load(CONVERSATIONS_HISTORY);
+        assert_eq!(8, history.messages.unwrap().len());
+        let users: ::users::ListResponse = load(USERS_LIST);
+        assert_eq!(7, users.members.unwrap().len());
+        assert_eq!(1, events_of_type("url_verification").len());
+        assert_eq!(2, events_of_type("message").len());
+    }
+}
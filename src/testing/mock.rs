@@ -0,0 +1,342 @@
+//! A request sender that never touches the network.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use requests::{FilePart, SlackJsonSender, SlackMultipartSender, SlackUploadSender, SlackWebRequestSender, SlackWebResponse};
+
+/// A request sender that answers from a list of programmed [`Expectation`]s and records every
+/// call made through it.
+///
+/// Each call is answered by the first expectation that matches its method and parameters and has
+/// not been used up. Calls that match no expectation fail with [`MockError::Unexpected`].
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::testing::{Expectation, MockSender};
+/// let mock = MockSender::new();
+/// mock.expect(Expectation::new("chat.postMessage")
+///     .param("channel", "C1")
+///     .returns(r#"{"ok": true, "channel": "C1", "ts": "1.2"}"#)
+///     .times(1));
+///
+/// let request = slack_api::chat::PostMessageRequest { channel: "C1", text: "hi", ..Default::default() };
+/// let response = slack_api::chat::post_message(&mock, "xoxb-...", &request).unwrap();
+///
+/// assert_eq!(Some("1.2".to_owned()), response.ts);
+/// assert_eq!(Some("hi"), mock.calls()[0].param("text"));
+/// mock.verify();
+/// ```
+#[derive(Debug, Default)]
+pub struct MockSender {
+    expectations: Mutex<Vec<Expectation>>,
+    calls: Mutex<Vec<Call>>,
+}
+
+/// A programmed answer to calls of one method.
+#[derive(Clone, Debug)]
+pub struct Expectation {
+    method: String,
+    params: Vec<(String, String)>,
+    response: MockResponse,
+    times: Option<usize>,
+    used: usize,
+}
+
+#[derive(Clone, Debug)]
+enum MockResponse {
+    Body(String),
+    RateLimited(Duration),
+    Fail(String),
+}
+
+/// A call made through a [`MockSender`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    /// The API method, e.g. `chat.postMessage`.
+    pub method: String,
+    pub params: Vec<(String, String)>,
+    /// Whether the parameters were POSTed as a form body, with `send_form`.
+    pub form: bool,
+}
+
+impl Call {
+    /// The value of the parameter `name`, if it was sent.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|&&(ref n, _)| n == name).map(|&(_, ref v)| &v[..])
+    }
+}
+
+impl Expectation {
+    /// Expects calls of `method`, answering them with `{"ok": true}` by default.
+    pub fn new<S: Into<String>>(method: S) -> Self {
+        Expectation {
+            method: method.into(),
+            params: Vec::new(),
+            response: MockResponse::Body(r#"{"ok": true}"#.to_owned()),
+            times: None,
+            used: 0,
+        }
+    }
+
+    /// Only matches calls that send `name` with exactly this value.
+    pub fn param<S: Into<String>>(mut self, name: S, value: S) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+
+    /// Answers with this JSON body.
+    pub fn returns<S: Into<String>>(mut self, body: S) -> Self {
+        self.response = MockResponse::Body(body.into());
+        self
+    }
+
+    /// Answers with `{"ok": false, "error": <error>}`.
+    pub fn returns_error(self, error: &str) -> Self {
+        self.returns(format!(r#"{{"ok": false, "error": "{}"}}"#, error))
+    }
+
+    /// Answers with an HTTP 429 and a `Retry-After` header, as Slack does when rate limiting.
+    pub fn rate_limited(mut self, retry_after: Duration) -> Self {
+        self.response = MockResponse::RateLimited(retry_after);
+        self
+    }
+
+    /// Fails the call with [`MockError::Simulated`], as if the request never reached Slack.
+    pub fn fails<S: Into<String>>(mut self, message: S) -> Self {
+        self.response = MockResponse::Fail(message.into());
+        self
+    }
+
+    /// Expects exactly `times` matching calls. Unlimited by default.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    fn matches(&self, call: &Call) -> bool {
+        self.method == call.method && self.times.map(|t| self.used < t).unwrap_or(true) &&
+        self.params.iter().all(|&(ref name, ref value)| call.param(name) == Some(&value[..]))
+    }
+}
+
+impl MockSender {
+    pub fn new() -> Self {
+        MockSender::default()
+    }
+
+    /// Adds an expectation. Earlier expectations take precedence over later ones.
+    pub fn expect(&self, expectation: Expectation) -> &Self {
+        self.expectations.lock().unwrap().push(expectation);
+        self
+    }
+
+    /// Every call made so far, in order.
+    pub fn calls(&self) -> Vec<Call> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// The calls made so far of `method`, in order.
+    pub fn calls_of(&self, method: &str) -> Vec<Call> {
+        self.calls.lock().unwrap().iter().filter(|c| c.method == method).cloned().collect()
+    }
+
+    /// Panics if an expectation with an expected number of calls was not called that many times.
+    pub fn verify(&self) {
+        for expectation in self.expectations.lock().unwrap().iter() {
+            if let Some(times) = expectation.times {
+                if expectation.used != times {
+                    panic!("expected {} to be called {} times with {:?}, but it was called {} times",
+                           expectation.method,
+                           times,
+                           expectation.params,
+                           expectation.used);
+                }
+            }
+        }
+    }
+}
+
+impl SlackWebRequestSender for MockSender {
+    type Error = MockError;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        let call = Call {
+            method: method_url.rsplit('/').next().unwrap_or(method_url).to_owned(),
+            params: params.iter().map(|&(n, v)| (n.to_owned(), v.to_owned())).collect(),
+            form: false,
+        };
+        self.answer(call)
+    }
+
+    /// Calls with a JSON body are recorded with the parameters
+    /// [`json_params`](../requests/fn.json_params.html) makes of the token and the body.
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        let call = Call {
+            method: method_url.rsplit('/').next().unwrap_or(method_url).to_owned(),
+            params: ::requests::json_params(token, body),
+            form: false,
+        };
+        self.answer(call)
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        let call = Call {
+            method: method_url.rsplit('/').next().unwrap_or(method_url).to_owned(),
+            params: params.iter().map(|&(n, v)| (n.to_owned(), v.to_owned())).collect(),
+            form: true,
+        };
+        self.answer(call)
+    }
+}
+
+/// Uploads are recorded as calls of the method `upload`, with the upload URL and the length of the
+/// content as the parameters `url` and `length`.
+impl SlackUploadSender for MockSender {
+    fn upload(&self, url: &str, content: &[u8]) -> Result<SlackWebResponse, Self::Error> {
+        let call = Call {
+            method: "upload".to_owned(),
+            params: vec![("url".to_owned(), url.to_owned()), ("length".to_owned(), content.len().to_string())],
+            form: false,
+        };
+        self.answer(call)
+    }
+}
+
+/// JSON posts to a Web API method are recorded as calls of that method, and posts to any other URL
+/// (like a `response_url`) with the whole URL as the method. The body is the parameter `body`.
+impl SlackJsonSender for MockSender {
+    fn post_json(&self, url: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        let method = if url.starts_with(::SLACK_API_URL) {
+            &url[::SLACK_API_URL.len()..]
+        } else {
+            url
+        };
+        let call = Call {
+            method: method.to_owned(),
+            params: vec![("body".to_owned(), body.to_owned())],
+            form: false,
+        };
+        self.answer(call)
+    }
+}
+
+/// Multipart calls are recorded with the token and the fields as the parameters, and the length of
+/// the file, if any, as the parameter named after its part.
+impl SlackMultipartSender for MockSender {
+    fn send_multipart(&self,
+                      method_url: &str,
+                      token: &str,
+                      fields: &[(&str, &str)],
+                      file: Option<&FilePart>)
+                      -> Result<SlackWebResponse, Self::Error> {
+        let mut params = vec![("token".to_owned(), token.to_owned())];
+        params.extend(fields.iter().map(|&(n, v)| (n.to_owned(), v.to_owned())));
+        params.extend(file.map(|file| (file.name.to_owned(), file.content.len().to_string())));
+        let call = Call {
+            method: method_url.rsplit('/').next().unwrap_or(method_url).to_owned(),
+            params: params,
+            form: false,
+        };
+        self.answer(call)
+    }
+}
+
+impl MockSender {
+    fn answer(&self, call: Call) -> Result<SlackWebResponse, MockError> {
+        self.calls.lock().unwrap().push(call.clone());
+
+        let mut expectations = self.expectations.lock().unwrap();
+        let expectation = match expectations.iter_mut().find(|e| e.matches(&call)) {
+            Some(expectation) => expectation,
+            None => return Err(MockError::Unexpected(call)),
+        };
+        expectation.used += 1;
+        match expectation.response {
+            MockResponse::Body(ref body) => {
+                Ok(SlackWebResponse {
+                    status: Some(200),
+                    headers: Vec::new(),
+                    body: body.clone(),
+                    ..SlackWebResponse::default()
+                })
+            }
+            MockResponse::RateLimited(retry_after) => {
+                Ok(SlackWebResponse {
+                    status: Some(429),
+                    headers: vec![("Retry-After".to_owned(), retry_after.as_secs().to_string())],
+                    body: r#"{"ok": false, "error": "ratelimited"}"#.to_owned(),
+                    ..SlackWebResponse::default()
+                })
+            }
+            MockResponse::Fail(ref message) => Err(MockError::Simulated(message.clone())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MockError {
+    /// A call matched none of the sender's expectations.
+    Unexpected(Call),
+    /// A call matched an expectation programmed with [`Expectation::fails`].
+    Simulated(String),
+}
+
+impl fmt::Display for MockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &MockError::Unexpected(ref call) => write!(f, "{}: {} {:?}", self.description(), call.method, call.params),
+            &MockError::Simulated(ref message) => write!(f, "{}: {}", self.description(), message),
+        }
+    }
+}
+
+impl Error for MockError {
+    fn description(&self) -> &str {
+        match self {
+            &MockError::Unexpected(_) => "unexpected call",
+            &MockError::Simulated(_) => "simulated failure",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expectations() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("users.info").param("user", "U1").returns_error("user_not_found").times(1))
+            .expect(Expectation::new("users.info").rate_limited(Duration::from_secs(30)));
+
+        assert!(mock.send("https://slack.com/api/users.info", &[("user", "U1")]).unwrap().contains("user_not_found"));
+        let limited = mock.send_raw("https://slack.com/api/users.info", &[("user", "U1")]).unwrap();
+        assert_eq!((Some(429), Some("30")), (limited.status, limited.header("retry-after")));
+        match mock.send("https://slack.com/api/chat.postMessage", &[]) {
+            Err(MockError::Unexpected(call)) => assert_eq!("chat.postMessage", call.method),
+            other => panic!("expected an unexpected call, got {:?}", other),
+        }
+        assert_eq!(2, mock.calls_of("users.info").len());
+        mock.verify();
+    }
+
+    #[test]
+    fn test_multipart_calls() {
+        use users::{self, SetPhotoRequest};
+
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("users.setPhoto").param("image", "4").param("crop_w", "64").returns(r#"{"ok": true}"#));
+        let request = SetPhotoRequest { image: b"\x89PNG", crop_w: Some(64), ..Default::default() };
+        users::set_photo(&mock, "xoxp-1", &request).unwrap();
+        assert_eq!(Some("xoxp-1"), mock.calls()[0].param("token"));
+        mock.verify();
+    }
+}
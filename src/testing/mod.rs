@@ -0,0 +1,14 @@
+//! Test doubles and fixtures for testing code that calls Slack.
+
+mod mock;
+pub use self::mock::*;
+
+mod replay;
+pub use self::replay::*;
+
+#[cfg(feature = "stub-server")]
+mod server;
+#[cfg(feature = "stub-server")]
+pub use self::server::*;
+
+pub mod fixtures;
@@ -0,0 +1,211 @@
+//! Recording real Slack traffic to fixture files and replaying it in tests.
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde_json;
+
+use requests::{SlackWebRequestSender, SlackWebResponse};
+use testing::{Call, MockError};
+
+/// A request and the response Slack gave to it, with credentials redacted.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The API method, e.g. `chat.postMessage`.
+    pub method: String,
+    pub params: Vec<(String, String)>,
+    pub status: Option<u16>,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+/// The call to `method_url`, with its credentials redacted.
+fn sanitized_call(method_url: &str, params: &[(&str, &str)]) -> Call {
+    Call {
+        method: method_url.rsplit('/').next().unwrap_or(method_url).to_owned(),
+        params: params.iter()
+            .map(|&(name, value)| (name.to_owned(), ::secret::redact_param(name, value)))
+            .collect(),
+        form: false,
+    }
+}
+
+/// The call to `method_url` with a JSON body, with its credentials redacted, its parameters being
+/// those the body holds.
+fn json_call(method_url: &str, token: &str, body: &str) -> Call {
+    let params = ::requests::json_params(token, body);
+    sanitized_call(method_url,
+                   &params.iter().map(|&(ref name, ref value)| (&name[..], &value[..])).collect::<Vec<_>>())
+}
+
+/// Wraps a real request sender, recording every successful call so it can be saved to a fixture
+/// file for [`Replayer`].
+///
+/// Tokens and other credentials are redacted from the recorded parameters and bodies.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::testing::Recorder;
+/// let recorder = Recorder::new(slack_api::requests::default_client().unwrap(), "tests/fixtures/channels.json");
+/// let response = slack_api::conversations::list(&recorder, "xoxb-...", &Default::default());
+/// recorder.save().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Recorder<R> {
+    sender: R,
+    path: PathBuf,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl<R: SlackWebRequestSender> Recorder<R> {
+    pub fn new<P: Into<PathBuf>>(sender: R, path: P) -> Self {
+        Recorder {
+            sender: sender,
+            path: path.into(),
+            interactions: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The interactions recorded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().unwrap().clone()
+    }
+
+    /// Writes the recorded interactions to the fixture file, replacing it.
+    pub fn save(&self) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&*self.interactions.lock().unwrap())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        File::create(&self.path)?.write_all(json.as_bytes())
+    }
+}
+
+impl<R: SlackWebRequestSender> SlackWebRequestSender for Recorder<R> {
+    type Error = R::Error;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        let response = self.sender.send_raw(method_url, params)?;
+        Ok(self.record(sanitized_call(method_url, params), response))
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        let response = self.sender.send_json(method_url, token, body)?;
+        Ok(self.record(json_call(method_url, token, body), response))
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        let response = self.sender.send_form(method_url, params)?;
+        Ok(self.record(sanitized_call(method_url, params), response))
+    }
+}
+
+impl<R: SlackWebRequestSender> Recorder<R> {
+    fn record(&self, call: Call, response: SlackWebResponse) -> SlackWebResponse {
+        self.interactions.lock().unwrap().push(Interaction {
+            method: call.method,
+            params: call.params,
+            status: response.status,
+            headers: response.headers.clone(),
+            body: ::secret::redact_json(&response.body),
+        });
+        response
+    }
+}
+
+/// Serves the interactions saved by a [`Recorder`] back, without touching the network.
+///
+/// Each call is answered by the first unused interaction with the same method and parameters
+/// (compared after redaction, so tests may use any token). Calls with no such interaction fail
+/// with [`MockError::Unexpected`].
+#[derive(Debug)]
+pub struct Replayer {
+    interactions: Mutex<Vec<(Interaction, bool)>>,
+}
+
+impl Replayer {
+    pub fn new(interactions: Vec<Interaction>) -> Self {
+        Replayer { interactions: Mutex::new(interactions.into_iter().map(|i| (i, false)).collect()) }
+    }
+
+    /// Loads the interactions from a fixture file written by [`Recorder::save`].
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut json = String::new();
+        File::open(path)?.read_to_string(&mut json)?;
+        serde_json::from_str(&json)
+            .map(Replayer::new)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Whether every interaction has been replayed.
+    pub fn is_exhausted(&self) -> bool {
+        self.interactions.lock().unwrap().iter().all(|&(_, used)| used)
+    }
+}
+
+impl SlackWebRequestSender for Replayer {
+    type Error = MockError;
+
+    fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        self.send_raw(method_url, params).map(|response| response.body)
+    }
+
+    fn send_raw(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.replay(sanitized_call(method_url, params))
+    }
+
+    fn send_json(&self, method_url: &str, token: &str, body: &str) -> Result<SlackWebResponse, Self::Error> {
+        self.replay(json_call(method_url, token, body))
+    }
+
+    fn send_form(&self, method_url: &str, params: &[(&str, &str)]) -> Result<SlackWebResponse, Self::Error> {
+        self.replay(sanitized_call(method_url, params))
+    }
+}
+
+impl Replayer {
+    fn replay(&self, call: Call) -> Result<SlackWebResponse, MockError> {
+        let mut interactions = self.interactions.lock().unwrap();
+        match interactions.iter_mut()
+            .find(|&&mut (ref i, used)| !used && i.method == call.method && i.params == call.params) {
+            Some(&mut (ref interaction, ref mut used)) => {
+                *used = true;
+                Ok(SlackWebResponse {
+                    status: interaction.status,
+                    headers: interaction.headers.clone(),
+                    body: interaction.body.clone(),
+                    ..SlackWebResponse::default()
+                })
+            }
+            None => Err(MockError::Unexpected(call)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_record_and_replay() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("oauth.v2.access").returns(r#"{"ok": true, "access_token": "xoxb-1-secret"}"#));
+        let recorder = Recorder::new(mock, "unused.json");
+        recorder.send("https://slack.com/api/oauth.v2.access", &[("code", "abc"), ("client_id", "1.2")]).unwrap();
+
+        let replayer = Replayer::new(recorder.interactions());
+        let body = replayer.send("https://slack.com/api/oauth.v2.access", &[("code", "def"), ("client_id", "1.2")])
+            .unwrap();
+        assert!(body.contains("xoxb-***") && !body.contains("secret"));
+        assert!(replayer.is_exhausted());
+        assert!(replayer.send("https://slack.com/api/oauth.v2.access", &[("code", "abc"), ("client_id", "1.2")])
+            .is_err());
+    }
+}
@@ -0,0 +1,337 @@
+//! A local HTTP server that imitates the Slack Web API, enabled by the `stub-server` feature.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use base64;
+use serde_json::{self, Map, Value};
+use url::form_urlencoded;
+
+use testing::Call;
+
+/// A Slack Web API stand-in listening on localhost, for exercising pagination and retry logic
+/// end-to-end.
+///
+/// Methods answer with whatever they were programmed with; methods that were not programmed
+/// answer `{"ok": false, "error": "unknown_method"}`. The server shuts down when dropped.
+///
+/// Point a sender at it with [`WithBaseUrl`](../requests/struct.WithBaseUrl.html).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use slack_api::requests::WithBaseUrl;
+/// # use slack_api::testing::StubServer;
+/// let server = StubServer::start().unwrap();
+/// server.rate_limit("users.list", 1, Duration::from_secs(1))
+///     .paginate("users.list", "members", vec![/* ... */], 100);
+///
+/// let client = WithBaseUrl::new(slack_api::requests::default_client().unwrap(), server.base_url());
+/// // ... exercise code that pages through users.list and retries on 429
+/// ```
+pub struct StubServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+#[derive(Default)]
+struct State {
+    routes: HashMap<String, Route>,
+    rate_limits: HashMap<String, (usize, Duration)>,
+    calls: Vec<Call>,
+}
+
+enum Route {
+    Body(String),
+    Pages {
+        key: String,
+        items: Vec<Value>,
+        page_size: usize,
+    },
+}
+
+impl StubServer {
+    /// Starts the server on a free port.
+    pub fn start() -> io::Result<StubServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let server = StubServer {
+            addr: listener.local_addr()?,
+            state: Arc::new(Mutex::new(State::default())),
+            shutdown: Arc::new(AtomicBool::new(false)),
+        };
+
+        let state = server.state.clone();
+        let shutdown = server.shutdown.clone();
+        thread::spawn(move || for stream in listener.incoming() {
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+            if let Ok(stream) = stream {
+                let _ = handle(stream, &state);
+            }
+        });
+        Ok(server)
+    }
+
+    /// The base URL to append method names to, e.g. `http://127.0.0.1:49152/api/`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}/api/", self.addr)
+    }
+
+    /// Answers calls of `method` with this JSON body.
+    pub fn respond(&self, method: &str, body: &str) -> &Self {
+        self.state.lock().unwrap().routes.insert(method.to_owned(), Route::Body(body.to_owned()));
+        self
+    }
+
+    /// Answers calls of `method` with `{"ok": false, "error": <error>}`.
+    pub fn respond_error(&self, method: &str, error: &str) -> &Self {
+        self.respond(method, &format!(r#"{{"ok": false, "error": "{}"}}"#, error))
+    }
+
+    /// Serves `items` under `key` in cursor-paginated pages, honouring the `cursor` and `limit`
+    /// parameters like Slack does. `page_size` is used when no `limit` is sent.
+    pub fn paginate(&self, method: &str, key: &str, items: Vec<Value>, page_size: usize) -> &Self {
+        let route = Route::Pages {
+            key: key.to_owned(),
+            items: items,
+            page_size: page_size,
+        };
+        self.state.lock().unwrap().routes.insert(method.to_owned(), route);
+        self
+    }
+
+    /// Answers the next `times` calls of `method` with an HTTP 429 and a `Retry-After` header.
+    pub fn rate_limit(&self, method: &str, times: usize, retry_after: Duration) -> &Self {
+        self.state.lock().unwrap().rate_limits.insert(method.to_owned(), (times, retry_after));
+        self
+    }
+
+    /// Every call the server has received so far, in order.
+    pub fn calls(&self) -> Vec<Call> {
+        self.state.lock().unwrap().calls.clone()
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake up the accept loop so it notices
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+impl State {
+    fn respond(&mut self, call: &Call) -> (u16, Vec<String>, String) {
+        if let Some(&mut (ref mut remaining, retry_after)) = self.rate_limits.get_mut(&call.method) {
+            if *remaining > 0 {
+                *remaining -= 1;
+                return (429,
+                        vec![format!("Retry-After: {}", retry_after.as_secs())],
+                        r#"{"ok": false, "error": "ratelimited"}"#.to_owned());
+            }
+        }
+
+        let body = match self.routes.get(&call.method) {
+            Some(&Route::Body(ref body)) => body.clone(),
+            Some(&Route::Pages { ref key, ref items, page_size }) => page(call, key, items, page_size),
+            None => r#"{"ok": false, "error": "unknown_method"}"#.to_owned(),
+        };
+        (200, Vec::new(), body)
+    }
+}
+
+fn page(call: &Call, key: &str, items: &[Value], page_size: usize) -> String {
+    let offset = match call.param("cursor") {
+        None | Some("") => Some(0),
+        Some(cursor) => {
+            base64::decode(cursor)
+                .ok()
+                .and_then(|c| String::from_utf8(c).ok())
+                .and_then(|c| c.trim_start_matches("offset:").parse::<usize>().ok())
+        }
+    };
+    let offset = match offset {
+        Some(offset) if offset <= items.len() => offset,
+        _ => return r#"{"ok": false, "error": "invalid_cursor"}"#.to_owned(),
+    };
+    let limit = call.param("limit").and_then(|l| l.parse().ok()).unwrap_or(page_size);
+    let end = ::std::cmp::min(offset + limit, items.len());
+    let next_cursor = if end < items.len() {
+        base64::encode(format!("offset:{}", end).as_bytes())
+    } else {
+        String::new()
+    };
+
+    let mut metadata = Map::new();
+    metadata.insert("next_cursor".to_owned(), Value::String(next_cursor));
+    let mut response = Map::new();
+    response.insert("ok".to_owned(), Value::Bool(true));
+    response.insert(key.to_owned(), Value::Array(items[offset..end].to_vec()));
+    response.insert("response_metadata".to_owned(), Value::Object(metadata));
+    serde_json::to_string(&Value::Object(response)).expect("JSON values always serialize")
+}
+
+fn handle(mut stream: TcpStream, state: &Mutex<State>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let target = request_line.split_whitespace().nth(1).unwrap_or("/").to_owned();
+
+    let mut content_length = 0;
+    let mut content_type = String::new();
+    let mut bearer = None;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim();
+        if line.is_empty() {
+            break;
+        }
+        let lower = line.to_lowercase();
+        if lower.starts_with("content-length:") {
+            content_length = line["content-length:".len()..].trim().parse().unwrap_or(0);
+        } else if lower.starts_with("content-type:") {
+            content_type = lower["content-type:".len()..].trim().to_owned();
+        } else if lower.starts_with("authorization: bearer ") {
+            bearer = Some(line["authorization: bearer ".len()..].trim().to_owned());
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let (path, query) = match target.find('?') {
+        Some(i) => (&target[..i], &target[i + 1..]),
+        None => (&target[..], ""),
+    };
+    let mut params: Vec<(String, String)> = form_urlencoded::parse(query.as_bytes()).into_owned().collect();
+    let token = bearer.unwrap_or_default();
+    if content_type.starts_with("application/json") {
+        // Recorded like the calls the mock sender gets, with the bearer token as `token`.
+        let body = String::from_utf8_lossy(&body);
+        params.extend(::requests::json_params(&token, &body));
+    } else if content_type.starts_with("multipart/form-data") {
+        // The parts are not parsed; such calls are recorded with their token only.
+        params.push(("token".to_owned(), token));
+    } else {
+        params.extend(form_urlencoded::parse(&body).into_owned());
+    }
+    let call = Call {
+        method: path.trim_start_matches("/api/").to_owned(),
+        params: params,
+        form: content_type.starts_with("application/x-www-form-urlencoded"),
+    };
+
+    let (status, headers, body) = {
+        let mut state = state.lock().unwrap();
+        state.calls.push(call.clone());
+        state.respond(&call)
+    };
+    let reason = if status == 429 { "Too Many Requests" } else { "OK" };
+    write!(stream,
+           "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: \
+            close\r\n",
+           status,
+           reason,
+           body.len())?;
+    for header in headers {
+        write!(stream, "{}\r\n", header)?;
+    }
+    write!(stream, "\r\n{}", body)?;
+    stream.flush()
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use requests::{self, SlackWebRequestSender, WithBaseUrl};
+
+    use super::*;
+
+    #[test]
+    fn test_stub_server() {
+        let server = StubServer::start().unwrap();
+        server.paginate("users.list",
+                      "members",
+                      vec![Value::String("U1".into()), Value::String("U2".into()), Value::String("U3".into())],
+                      2)
+            .rate_limit("users.list", 1, Duration::from_secs(3));
+        let client = WithBaseUrl::new(requests::default_client().unwrap(), server.base_url());
+        let url = ::get_slack_url_for_method("users.list");
+
+        let limited = client.send_raw(&url, &[("token", "xoxb-1")]).unwrap();
+        assert_eq!((Some(429), Some("3")), (limited.status, limited.header("Retry-After")));
+
+        let first: Value = serde_json::from_str(&client.send(&url, &[("token", "xoxb-1")]).unwrap()).unwrap();
+        assert_eq!(2, first["members"].as_array().unwrap().len());
+        let cursor = first["response_metadata"]["next_cursor"].as_str().unwrap().to_owned();
+        let second: Value = serde_json::from_str(&client.send(&url, &[("cursor", &cursor)]).unwrap()).unwrap();
+        assert_eq!(Some("U3"), second["members"][0].as_str());
+        assert_eq!(Some(""), second["response_metadata"]["next_cursor"].as_str());
+        assert_eq!(3, server.calls().len());
+    }
+
+    #[test]
+    fn test_json_calls() {
+        let server = StubServer::start().unwrap();
+        server.respond("chat.postMessage", r#"{"ok": true, "ts": "1.0"}"#);
+        let client = WithBaseUrl::new(requests::default_client().unwrap(), server.base_url());
+        let request = ::chat::PostMessageRequest { channel: "C1", text: "hi", as_user: Some(true), ..Default::default() };
+
+        assert_eq!(Some("1.0".to_owned()), ::chat::post_message(&client, "xoxb-1", &request).unwrap().ts);
+        let call = &server.calls()[0];
+        assert_eq!((Some("xoxb-1"), Some("hi"), Some("1")),
+                   (call.param("token"), call.param("text"), call.param("as_user")));
+    }
+
+    #[test]
+    fn test_form_calls() {
+        let server = StubServer::start().unwrap();
+        server.respond("oauth.v2.access", r#"{"ok": true, "access_token": "xoxb-1"}"#);
+        let client = WithBaseUrl::new(requests::default_client().unwrap(), server.base_url());
+        let request = ::oauth_v2::AccessRequest { code: Some("c1"), ..::oauth_v2::AccessRequest::new("1.2", "secret") };
+
+        assert!(::oauth_v2::access(&client, &request).is_ok());
+        let call = &server.calls()[0];
+        assert!(call.form);
+        assert_eq!((Some("secret"), Some("c1")), (call.param("client_secret"), call.param("code")));
+    }
+
+    #[test]
+    fn test_limited_client() {
+        let server = StubServer::start().unwrap();
+        server.respond("users.list", r#"{"ok": true, "members": ["U1", "U2", "U3"]}"#);
+        let url = ::get_slack_url_for_method("users.list");
+
+        let roomy = WithBaseUrl::new(requests::LimitedClient::new(requests::default_client().unwrap(), 1024),
+                                     server.base_url());
+        assert!(roomy.send(&url, &[("token", "xoxb-1")]).is_ok());
+
+        let tight = WithBaseUrl::new(requests::LimitedClient::new(requests::default_client().unwrap(), 16),
+                                     server.base_url());
+        match tight.send(&url, &[("token", "xoxb-1")]) {
+            Err(requests::LimitedError::TooLarge(16)) => {}
+            other => panic!("expected the response to be too large, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_client_builder() {
+        let server = StubServer::start().unwrap();
+        server.respond("chat.postMessage", r#"{"ok": true, "ts": "1.0"}"#)
+            .respond("users.list", r#"{"ok": true, "members": []}"#);
+        let client = requests::Client::builder().base_url(server.base_url()).user_agent("test-bot/1.0").build().unwrap();
+        let request = ::chat::PostMessageRequest { channel: "C1", text: "hi", ..Default::default() };
+
+        assert_eq!(Some("1.0".to_owned()), ::chat::post_message(&client, "xoxb-1", &request).unwrap().ts);
+        assert!(::users::list(&client.clone(), "xoxb-1", &Default::default()).is_ok());
+        let calls = server.calls();
+        assert_eq!(vec!["chat.postMessage", "users.list"], calls.iter().map(|call| &call.method[..]).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,16 @@
+//! Managing the tokens that are passed to the API methods.
+
+mod introspect;
+pub use self::introspect::*;
+
+mod provider;
+pub use self::provider::*;
+
+mod rotation;
+pub use self::rotation::*;
+
+mod store;
+pub use self::store::*;
+
+mod set;
+pub use self::set::*;
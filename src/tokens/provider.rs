@@ -0,0 +1,42 @@
+//! Looking up the token of each workspace, for clients shared by all of them.
+
+use std::error::Error;
+
+use requests::SlackWebRequestSender;
+use tokens::TokenStore;
+
+/// Where the token of a workspace comes from, for apps installed to many workspaces.
+///
+/// Every [`TokenStore`] is one, giving the bot token of the workspace's installation or else of
+/// the org-wide installation of its enterprise, and so is a [`TokenManager`], giving its rotating
+/// token; implement it directly to get tokens from somewhere else, e.g. a secrets manager.
+///
+/// Providers are handed the sender of the client asking, for those that call the API to get a
+/// token, like a [`TokenManager`] refreshing it.
+///
+/// [`TokenManager`]: struct.TokenManager.html
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::testing::MockSender;
+/// # use slack_api::tokens::{MemoryTokenStore, TokenProvider};
+/// let store = MemoryTokenStore::new();
+/// assert_eq!(None, store.token(&MockSender::new(), Some("E0KD4DX88"), "T024BE7LD").unwrap());
+/// ```
+pub trait TokenProvider<R: SlackWebRequestSender> {
+    type Error: Error;
+
+    /// The token to call the API with on behalf of the workspace `team_id`, which belongs to the
+    /// Enterprise Grid organization `enterprise_id` if any, or `None` if the app is not installed
+    /// there.
+    fn token(&self, sender: &R, enterprise_id: Option<&str>, team_id: &str) -> Result<Option<String>, Self::Error>;
+}
+
+impl<R: SlackWebRequestSender, T: TokenStore> TokenProvider<R> for T {
+    type Error = T::Error;
+
+    fn token(&self, _: &R, enterprise_id: Option<&str>, team_id: &str) -> Result<Option<String>, Self::Error> {
+        self.bot_token(enterprise_id, Some(team_id))
+    }
+}
@@ -0,0 +1,275 @@
+//! Token rotation: expiring access tokens that are renewed with a refresh token.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use oauth_v2;
+use requests::SlackWebRequestSender;
+use secret::Secret;
+use tokens::TokenProvider;
+
+/// An access token issued to an app with token rotation enabled.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RotatingToken {
+    pub access_token: Secret,
+    pub refresh_token: Secret,
+    /// When `access_token` stops working, in seconds since the Unix epoch.
+    pub expires_at: u64,
+}
+
+impl RotatingToken {
+    /// Builds a `RotatingToken` from the top-level token of an `oauth.v2.access` response.
+    ///
+    /// Returns `None` if the response did not include an expiring token.
+    pub fn from_response(response: &oauth_v2::AccessResponse) -> Option<RotatingToken> {
+        match (&response.access_token, &response.refresh_token, response.expires_in) {
+            (&Some(ref access_token), &Some(ref refresh_token), Some(expires_in)) => {
+                Some(RotatingToken {
+                    access_token: Secret::new(access_token.clone()),
+                    refresh_token: Secret::new(refresh_token.clone()),
+                    expires_at: ::unix_timestamp() + expires_in.max(0) as u64,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the access token expires within `margin` from now.
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        ::unix_timestamp() + margin.as_secs() >= self.expires_at
+    }
+}
+
+/// Keeps a rotating token alive, refreshing it through `oauth.v2.access` shortly before it
+/// expires.
+///
+/// It is a [`TokenProvider`], giving its token for every workspace, so that a
+/// [`TeamClients`](../client/struct.TeamClients.html) built on it calls the API with a live token.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::client::TeamClients;
+/// # use slack_api::tokens::{RotatingToken, TokenManager};
+/// # let stored = RotatingToken { access_token: "xoxe.xoxb-1".into(), refresh_token: "xoxe-1".into(), expires_at: 0 };
+/// # let team_id = "T024BE7LD";
+/// let client = slack_api::requests::default_client().unwrap();
+/// let manager = TokenManager::new("client_id", "client_secret", stored);
+///
+/// let token = manager.token(&client).unwrap();
+/// let response = slack_api::conversations::list(&client, &token, &Default::default());
+///
+/// // Or let the client ask for the token on every call
+/// let slack = TeamClients::new(client, manager);
+/// let response = slack.team(None, team_id).unwrap().conversations().list(&Default::default());
+/// ```
+#[derive(Debug)]
+pub struct TokenManager {
+    client_id: String,
+    client_secret: Secret,
+    refresh_margin: Duration,
+    current: Mutex<RotatingToken>,
+}
+
+impl TokenManager {
+    pub fn new<S: Into<String>>(client_id: S, client_secret: S, token: RotatingToken) -> Self {
+        TokenManager {
+            client_id: client_id.into(),
+            client_secret: Secret::new(client_secret.into()),
+            refresh_margin: Duration::from_secs(5 * 60),
+            current: Mutex::new(token),
+        }
+    }
+
+    /// How long before expiry the token is refreshed. Defaults to five minutes.
+    pub fn refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// A snapshot of the current token, e.g. to persist it after a refresh.
+    pub fn current(&self) -> RotatingToken {
+        self.current.lock().unwrap().clone()
+    }
+
+    /// Returns an access token that is valid for at least the refresh margin, refreshing it first
+    /// if necessary.
+    ///
+    /// Concurrent callers wait for a single refresh rather than each spending the refresh token.
+    pub fn token<R>(&self, client: &R) -> Result<String, RefreshError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let mut current = self.current.lock().unwrap();
+        if current.expires_within(self.refresh_margin) {
+            *current = self.exchange(client, current.refresh_token.expose())?;
+        }
+        Ok(current.access_token.expose().to_owned())
+    }
+
+    /// Refreshes the token regardless of its expiry, e.g. after Slack answered `token_expired`.
+    pub fn refresh<R>(&self, client: &R) -> Result<RotatingToken, RefreshError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let mut current = self.current.lock().unwrap();
+        *current = self.exchange(client, current.refresh_token.expose())?;
+        Ok(current.clone())
+    }
+
+    fn exchange<R>(&self, client: &R, refresh_token: &str) -> Result<RotatingToken, RefreshError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let request = oauth_v2::AccessRequest {
+            client_id: &self.client_id,
+            client_secret: self.client_secret.expose(),
+            grant_type: Some("refresh_token"),
+            refresh_token: Some(refresh_token),
+            ..oauth_v2::AccessRequest::default()
+        };
+        let response = oauth_v2::access(client, &request).map_err(RefreshError::Access)?;
+        RotatingToken::from_response(&response).ok_or(RefreshError::NotRotating)
+    }
+}
+
+/// The token of the one installation the manager holds, whichever workspace asks.
+impl<R: SlackWebRequestSender> TokenProvider<R> for TokenManager {
+    type Error = RefreshError<R::Error>;
+
+    fn token(&self, sender: &R, _: Option<&str>, _: &str) -> Result<Option<String>, Self::Error> {
+        TokenManager::token(self, sender).map(Some)
+    }
+}
+
+#[derive(Debug)]
+pub enum RefreshError<E: Error> {
+    /// `oauth.v2.access` rejected the refresh.
+    Access(oauth_v2::AccessError<E>),
+    /// The refresh succeeded but did not return an expiring token and a new refresh token.
+    NotRotating,
+}
+
+impl<E: Error> fmt::Display for RefreshError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RefreshError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &RefreshError::Access(ref inner) => inner.description(),
+            &RefreshError::NotRotating => "the refresh response did not contain a rotating token",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &RefreshError::Access(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client::TeamClients;
+    use install::Installation;
+    use testing::{Expectation, MockSender};
+
+    const REFRESHED: &'static str = r#"{"ok": true, "access_token": "xoxe.xoxb-2", "refresh_token": "xoxe-2",
+                                        "expires_in": 43200, "token_type": "bot"}"#;
+
+    fn token(expires_in: u64) -> RotatingToken {
+        RotatingToken {
+            access_token: "xoxe.xoxb-1".into(),
+            refresh_token: "xoxe-1".into(),
+            expires_at: ::unix_timestamp() + expires_in,
+        }
+    }
+
+    #[test]
+    fn test_refreshes_before_expiry() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("oauth.v2.access")
+            .param("grant_type", "refresh_token")
+            .param("refresh_token", "xoxe-1")
+            .returns(REFRESHED)
+            .times(1));
+
+        // Valid for longer than the margin: used as it is.
+        let manager = TokenManager::new("1234.5678", "secret", token(3600));
+        assert_eq!("xoxe.xoxb-1", manager.token(&mock).unwrap());
+        assert!(mock.calls().is_empty());
+
+        // Expiring within the margin: refreshed once, then reused.
+        let manager = manager.refresh_margin(Duration::from_secs(7200));
+        assert_eq!("xoxe.xoxb-2", manager.token(&mock).unwrap());
+        assert_eq!("xoxe.xoxb-2", manager.token(&mock).unwrap());
+        assert_eq!("xoxe-2", manager.current().refresh_token.expose());
+        mock.verify();
+    }
+
+    #[test]
+    fn test_refresh_failure() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("oauth.v2.access").returns_error("invalid_refresh_token").times(1))
+            .expect(Expectation::new("oauth.v2.access").returns(r#"{"ok": true, "access_token": "xoxb-1"}"#));
+        let manager = TokenManager::new("1234.5678", "secret", token(0));
+
+        match manager.token(&mock) {
+            Err(RefreshError::Access(oauth_v2::AccessError::InvalidRefreshToken)) => {}
+            other => panic!("expected the refresh token to be rejected, got {:?}", other),
+        }
+        match manager.refresh(&mock) {
+            Err(RefreshError::NotRotating) => {}
+            other => panic!("expected a token that does not rotate, got {:?}", other),
+        }
+        // The failed refreshes leave the token as it was.
+        assert_eq!("xoxe-1", manager.current().refresh_token.expose());
+    }
+
+    #[test]
+    fn test_installation_bot_rotating_token() {
+        let response = ::serde_json::from_str(REFRESHED).unwrap();
+        let mut installation = Installation::from_response::<::std::io::Error>(::serde_json::from_str(
+                r#"{"ok": true, "access_token": "xoxe.xoxb-1", "refresh_token": "xoxe-1", "expires_in": 43200,
+                    "token_type": "bot", "team": {"id": "T1"}}"#)
+            .unwrap())
+            .unwrap();
+
+        let rotating = installation.bot_rotating_token().unwrap();
+        assert_eq!("xoxe.xoxb-1", rotating.access_token.expose());
+        assert_eq!("xoxe-1", rotating.refresh_token.expose());
+        assert!(!rotating.expires_within(Duration::from_secs(3600)));
+        assert_eq!(Some(rotating.expires_at), RotatingToken::from_response(&response).map(|t| t.expires_at));
+
+        installation.bot_refresh_token = None;
+        assert_eq!(None, installation.bot_rotating_token());
+    }
+
+    #[test]
+    fn test_negative_expiry_has_expired() {
+        let response = ::serde_json::from_str(r#"{"ok": true, "access_token": "xoxe.xoxb-1", "refresh_token": "xoxe-1",
+                                                 "expires_in": -1, "token_type": "bot"}"#)
+            .unwrap();
+        let rotating = RotatingToken::from_response(&response).unwrap();
+        assert!(rotating.expires_within(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn test_team_clients_use_live_token() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("oauth.v2.access").returns(REFRESHED).times(1))
+            .expect(Expectation::new("conversations.list").returns(r#"{"ok": true, "channels": []}"#));
+        let slack = TeamClients::new(mock, TokenManager::new("1234.5678", "secret", token(60)));
+
+        slack.team(None, "T1").unwrap().conversations().list(&Default::default()).unwrap();
+        slack.team(None, "T1").unwrap().conversations().list(&Default::default()).unwrap();
+        let calls = slack.sender().calls_of("conversations.list");
+        assert_eq!(vec![Some("xoxe.xoxb-2"), Some("xoxe.xoxb-2")],
+                   calls.iter().map(|call| call.param("token")).collect::<Vec<_>>());
+        slack.sender().verify();
+    }
+}
@@ -0,0 +1,170 @@
+//! Holding several kinds of token and picking the right one per method.
+
+use std::fmt;
+
+use install::Installation;
+use scopes;
+use secret::Secret;
+use tokens::TokenType;
+
+/// The kind of token `method` must be called with, for methods that only accept one kind, as
+/// told by the scopes it needs with each (see [`scopes::method_scopes`]). Methods that neither bot
+/// nor user tokens can call take an app-level token.
+///
+/// Returns `None` for methods that accept bot or user tokens, and for those this library does not
+/// know.
+///
+/// [`scopes::method_scopes`]: ../scopes/fn.method_scopes.html
+pub fn required_token_type(method: &str) -> Option<TokenType> {
+    scopes::method_scopes(method).and_then(|scopes| match (scopes.bot, scopes.user) {
+        (None, None) => Some(TokenType::AppLevel),
+        (None, Some(_)) => Some(TokenType::User),
+        (Some(_), None) => Some(TokenType::Bot),
+        (Some(_), Some(_)) => None,
+    })
+}
+
+/// The tokens an app holds for one installation.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::tokens::TokenSet;
+/// let client = slack_api::requests::default_client().unwrap();
+/// let tokens = TokenSet {
+///     bot: Some("xoxb-...".into()),
+///     app: Some("xapp-...".into()),
+///     ..TokenSet::default()
+/// };
+///
+/// let socket = slack_api::apps_connections::open(&client, tokens.for_method("apps.connections.open").unwrap());
+/// let channels = slack_api::conversations::list(&client, tokens.for_method("conversations.list").unwrap(), &Default::default());
+///
+/// // Or let a client pick the token of each method
+/// let slack = slack_api::client::SlackClient::from_token_set(client, tokens);
+/// let socket = slack.apps_connections().open();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TokenSet {
+    /// An `xoxb-` bot token.
+    pub bot: Option<Secret>,
+    /// An `xoxp-` user token.
+    pub user: Option<Secret>,
+    /// An `xapp-` app-level token.
+    pub app: Option<Secret>,
+}
+
+impl TokenSet {
+    /// The bot and user tokens of `installation`.
+    pub fn from_installation(installation: &Installation) -> Self {
+        TokenSet {
+            bot: installation.bot_token.clone(),
+            user: installation.user_token.clone(),
+            app: None,
+        }
+    }
+
+    /// The token of the given kind, if held.
+    pub fn get(&self, token_type: TokenType) -> Option<&str> {
+        match token_type {
+            TokenType::Bot => self.bot.as_ref(),
+            TokenType::User => self.user.as_ref(),
+            TokenType::AppLevel => self.app.as_ref(),
+            _ => None,
+        }.map(Secret::expose)
+    }
+
+    /// The token `method` should be called with.
+    ///
+    /// Methods that need an app-level or user token get exactly that; everything else gets the
+    /// bot token, falling back to the user token.
+    pub fn for_method(&self, method: &str) -> Option<&str> {
+        match required_token_type(method) {
+            Some(token_type) => self.get(token_type),
+            None => self.get(TokenType::Bot).or(self.get(TokenType::User)),
+        }
+    }
+}
+
+/// The token the `Methods` of a module call each method with: the same token for all of them, or
+/// the one a [`TokenSet`] holds for each.
+#[derive(Clone, Copy)]
+pub enum MethodToken<'a> {
+    Token(&'a str),
+    Set(&'a TokenSet),
+}
+
+impl<'a> MethodToken<'a> {
+    /// The token to call `method` with.
+    ///
+    /// When a set does not hold the kind of token the method needs, this is any token it does
+    /// hold, so that Slack's answer (`not_allowed_token_type`) tells what went wrong.
+    pub fn for_method(&self, method: &str) -> &'a str {
+        match *self {
+            MethodToken::Token(token) => token,
+            MethodToken::Set(set) => {
+                set.for_method(method)
+                    .or(set.get(TokenType::Bot))
+                    .or(set.get(TokenType::User))
+                    .or(set.get(TokenType::AppLevel))
+                    .unwrap_or("")
+            }
+        }
+    }
+}
+
+impl<'a> fmt::Debug for MethodToken<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MethodToken::Token(token) => write!(f, "Token({:?})", Secret::new(token)),
+            MethodToken::Set(set) => write!(f, "Set({:?})", set),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MethodToken<'a> {
+    fn from(token: &'a str) -> Self {
+        MethodToken::Token(token)
+    }
+}
+
+impl<'a> From<&'a TokenSet> for MethodToken<'a> {
+    fn from(set: &'a TokenSet) -> Self {
+        MethodToken::Set(set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_token_type() {
+        assert_eq!(Some(TokenType::AppLevel), required_token_type("apps.connections.open"));
+        assert_eq!(Some(TokenType::User), required_token_type("search.messages"));
+        assert_eq!(Some(TokenType::User), required_token_type("admin.users.list"));
+        assert_eq!(Some(TokenType::User), required_token_type("users.identity"));
+        assert_eq!(Some(TokenType::Bot), required_token_type("views.publish"));
+        assert_eq!(None, required_token_type("chat.postMessage"));
+        assert_eq!(None, required_token_type("unknown.method"));
+    }
+
+    #[test]
+    fn test_for_method() {
+        let tokens = TokenSet {
+            bot: Some("xoxb-1".into()),
+            user: Some("xoxp-1".into()),
+            app: None,
+        };
+        assert_eq!(Some("xoxb-1"), tokens.for_method("chat.postMessage"));
+        assert_eq!(Some("xoxp-1"), tokens.for_method("admin.conversations.search"));
+        assert_eq!(None, tokens.for_method("apps.connections.open"));
+
+        let token = MethodToken::from(&tokens);
+        assert_eq!("xoxp-1", token.for_method("search.all"));
+        // A missing kind falls back to a token the set holds.
+        assert_eq!("xoxb-1", token.for_method("apps.connections.open"));
+        assert_eq!("xoxp-9", MethodToken::from("xoxp-9").for_method("chat.postMessage"));
+        assert_eq!("Token(xoxp-***)", format!("{:?}", MethodToken::from("xoxp-9")));
+    }
+}
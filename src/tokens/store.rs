@@ -0,0 +1,198 @@
+//! Storing installations for apps that are installed to many workspaces.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::RwLock;
+
+use install::Installation;
+use secret::Secret;
+
+/// Identifies the installation a request is made on behalf of.
+///
+/// Org-wide Enterprise Grid installs are keyed by their enterprise, everything else by the
+/// workspace the app was installed to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstallationKey {
+    Team(String),
+    Enterprise(String),
+}
+
+impl InstallationKey {
+    /// The key an installation is saved under, or `None` if it has neither a team nor an
+    /// enterprise.
+    pub fn for_installation(installation: &Installation) -> Option<Self> {
+        if installation.is_enterprise_install {
+            installation.enterprise.as_ref().map(|e| InstallationKey::Enterprise(e.id.clone()))
+        } else {
+            installation.team.as_ref().map(|t| InstallationKey::Team(t.id.clone()))
+        }
+    }
+}
+
+/// Persistence for [`Installation`]s.
+///
+/// Implement this over your database to share installations between processes; an in-memory
+/// implementation is provided by [`MemoryTokenStore`].
+///
+/// To call the API on behalf of whichever installation a request comes from, hand the store to a
+/// [`TeamClients`](../client/struct.TeamClients.html), which looks the token up on every call.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::client::TeamClients;
+/// # use slack_api::events::EventPayload;
+/// # use slack_api::tokens::MemoryTokenStore;
+/// # let body = b"{}";
+/// let slack = TeamClients::new(slack_api::requests::default_client().unwrap(), MemoryTokenStore::new());
+///
+/// if let Ok(EventPayload::EventCallback(callback)) = EventPayload::from_slice(body) {
+///     for authorization in callback.authorizations.unwrap_or_default() {
+///         let team_id = authorization.team_id.unwrap_or_default();
+///         let team = slack.team(authorization.enterprise_id.as_ref().map(String::as_str), &team_id).unwrap();
+///         let channels = team.conversations().list(&Default::default());
+///     }
+/// }
+/// ```
+pub trait TokenStore {
+    type Error: Error;
+
+    /// Finds the installation for `key`, if any.
+    fn find(&self, key: &InstallationKey) -> Result<Option<Installation>, Self::Error>;
+
+    /// Saves `installation`, replacing any existing installation with the same key.
+    ///
+    /// Installations without a team or enterprise cannot be looked up and are not expected here.
+    fn save(&self, installation: &Installation) -> Result<(), Self::Error>;
+
+    /// Removes the installation for `key`, e.g. after an `app_uninstalled` event.
+    fn delete(&self, key: &InstallationKey) -> Result<(), Self::Error>;
+
+    /// Looks up the installation for a request coming from `team_id` within `enterprise_id`.
+    ///
+    /// This tries the workspace installation first and falls back to an org-wide installation.
+    fn lookup(&self,
+              enterprise_id: Option<&str>,
+              team_id: Option<&str>)
+              -> Result<Option<Installation>, Self::Error> {
+        if let Some(team_id) = team_id {
+            if let Some(installation) = self.find(&InstallationKey::Team(team_id.to_owned()))? {
+                return Ok(Some(installation));
+            }
+        }
+        match enterprise_id {
+            Some(enterprise_id) => self.find(&InstallationKey::Enterprise(enterprise_id.to_owned())),
+            None => Ok(None),
+        }
+    }
+
+    /// The bot token for a request coming from `team_id` within `enterprise_id`.
+    fn bot_token(&self, enterprise_id: Option<&str>, team_id: Option<&str>) -> Result<Option<String>, Self::Error> {
+        self.lookup(enterprise_id, team_id).map(|i| i.and_then(|i| i.bot_token).map(Secret::into_inner))
+    }
+}
+
+/// A [`TokenStore`] that keeps installations in memory.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    installations: RwLock<HashMap<InstallationKey, Installation>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        MemoryTokenStore::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    type Error = MemoryTokenStoreError;
+
+    fn find(&self, key: &InstallationKey) -> Result<Option<Installation>, Self::Error> {
+        Ok(self.installations.read().unwrap().get(key).cloned())
+    }
+
+    fn save(&self, installation: &Installation) -> Result<(), Self::Error> {
+        if let Some(key) = InstallationKey::for_installation(installation) {
+            self.installations.write().unwrap().insert(key, installation.clone());
+        }
+        Ok(())
+    }
+
+    fn delete(&self, key: &InstallationKey) -> Result<(), Self::Error> {
+        self.installations.write().unwrap().remove(key);
+        Ok(())
+    }
+}
+
+/// [`MemoryTokenStore`] never fails; this type only exists to satisfy the trait.
+#[derive(Debug)]
+pub enum MemoryTokenStoreError {}
+
+impl fmt::Display for MemoryTokenStoreError {
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl Error for MemoryTokenStoreError {
+    fn description(&self) -> &str {
+        match *self {}
+    }
+}
+
+impl<'a, T: TokenStore> TokenStore for &'a T {
+    type Error = T::Error;
+
+    fn find(&self, key: &InstallationKey) -> Result<Option<Installation>, Self::Error> {
+        (**self).find(key)
+    }
+
+    fn save(&self, installation: &Installation) -> Result<(), Self::Error> {
+        (**self).save(installation)
+    }
+
+    fn delete(&self, key: &InstallationKey) -> Result<(), Self::Error> {
+        (**self).delete(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use install::{Installation, InstallationEnterprise, InstallationTeam};
+
+    fn installation(team: Option<&str>, enterprise: Option<&str>, token: &str) -> Installation {
+        Installation {
+            app_id: None,
+            team: team.map(|id| InstallationTeam { id: id.to_owned(), name: None }),
+            enterprise: enterprise.map(|id| InstallationEnterprise { id: id.to_owned(), name: None }),
+            is_enterprise_install: team.is_none(),
+            bot_token: Some(token.into()),
+            bot_user_id: None,
+            bot_refresh_token: None,
+            bot_token_expires_at: None,
+            scopes: vec![],
+            user_id: None,
+            user_token: None,
+            user_refresh_token: None,
+            user_token_expires_at: None,
+            user_scopes: vec![],
+            incoming_webhook: None,
+        }
+    }
+
+    #[test]
+    fn test_lookup_prefers_workspace_then_falls_back_to_org() {
+        let store = MemoryTokenStore::new();
+        store.save(&installation(Some("T1"), Some("E1"), "xoxb-team")).unwrap();
+        store.save(&installation(None, Some("E1"), "xoxb-org")).unwrap();
+
+        assert_eq!(Some("xoxb-team".to_owned()), store.bot_token(Some("E1"), Some("T1")).unwrap());
+        assert_eq!(Some("xoxb-org".to_owned()), store.bot_token(Some("E1"), Some("T2")).unwrap());
+        assert_eq!(None, store.bot_token(None, Some("T2")).unwrap());
+
+        store.delete(&InstallationKey::Enterprise("E1".to_owned())).unwrap();
+        assert_eq!(None, store.bot_token(Some("E1"), Some("T2")).unwrap());
+    }
+}
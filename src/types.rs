@@ -1,167 +1,390 @@
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Bot {
-    pub app_id: Option<String>,
-    pub deleted: Option<bool>,
-    pub icons: Option<BotIcons>,
-    pub id: Option<String>,
-    pub name: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct BotIcons {
-    pub image_36: Option<String>,
-    pub image_48: Option<String>,
-    pub image_72: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Channel {
-    pub created: Option<i32>,
-    pub creator: Option<String>,
-    pub id: Option<String>,
-    pub is_archived: Option<bool>,
-    pub is_channel: Option<bool>,
-    pub is_general: Option<bool>,
-    pub is_member: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest: Option<::Message>,
-    pub members: Option<Vec<String>>,
-    pub name: Option<String>,
-    pub purpose: Option<ChannelPurpose>,
-    pub topic: Option<ChannelTopic>,
-    pub unread_count: Option<i32>,
-    pub unread_count_display: Option<i32>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ChannelPurpose {
-    pub creator: Option<String>,
-    pub last_set: Option<i32>,
-    pub value: Option<String>,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ChannelTopic {
-    pub creator: Option<String>,
-    pub last_set: Option<i32>,
-    pub value: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct File {
-    pub channels: Option<Vec<String>>,
-    pub comments_count: Option<i32>,
-    pub created: Option<i32>,
-    pub display_as_bot: Option<bool>,
-    pub edit_link: Option<String>,
-    pub editable: Option<bool>,
-    pub external_type: Option<String>,
-    pub filetype: Option<String>,
-    pub groups: Option<Vec<String>>,
-    pub id: Option<String>,
-    pub ims: Option<Vec<String>>,
-    pub initial_comment: Option<::FileComment>,
-    pub is_external: Option<bool>,
-    pub is_public: Option<bool>,
-    pub is_starred: Option<bool>,
-    pub lines: Option<i32>,
-    pub lines_more: Option<i32>,
-    pub mimetype: Option<String>,
-    pub mode: Option<String>,
-    pub name: Option<String>,
-    pub num_stars: Option<i32>,
-    pub permalink: Option<String>,
-    pub permalink_public: Option<String>,
-    pub pinned_to: Option<Vec<String>>,
-    pub pretty_type: Option<String>,
-    pub preview: Option<String>,
-    pub preview_highlight: Option<String>,
-    pub public_url_shared: Option<bool>,
-    pub reactions: Option<Vec<::Reaction>>,
-    pub size: Option<i32>,
-    pub thumb_160: Option<String>,
-    pub thumb_360: Option<String>,
-    pub thumb_360_gif: Option<String>,
-    pub thumb_360_h: Option<i32>,
-    pub thumb_360_w: Option<i32>,
-    pub thumb_480: Option<String>,
-    pub thumb_480_h: Option<i32>,
-    pub thumb_480_w: Option<i32>,
-    pub thumb_64: Option<String>,
-    pub thumb_80: Option<String>,
-    pub timestamp: Option<i32>,
-    pub title: Option<String>,
-    pub url_private: Option<String>,
-    pub url_private_download: Option<String>,
-    pub user: Option<String>,
-    pub username: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct FileComment {
-    pub comment: Option<String>,
-    pub id: Option<String>,
-    pub reactions: Option<Vec<::Reaction>>,
-    pub timestamp: Option<i32>,
-    pub user: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Group {
-    pub created: Option<i32>,
-    pub creator: Option<String>,
-    pub id: Option<String>,
-    pub is_archived: Option<bool>,
-    pub is_group: Option<bool>,
-    pub is_mpim: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest: Option<::Message>,
-    pub members: Option<Vec<String>>,
-    pub name: Option<String>,
-    pub purpose: Option<GroupPurpose>,
-    pub topic: Option<GroupTopic>,
-    pub unread_count: Option<i32>,
-    pub unread_count_display: Option<i32>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct GroupPurpose {
-    pub creator: Option<String>,
-    pub last_set: Option<i32>,
-    pub value: Option<String>,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct GroupTopic {
-    pub creator: Option<String>,
-    pub last_set: Option<i32>,
-    pub value: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Im {
-    pub created: Option<i32>,
-    pub id: Option<String>,
-    pub is_im: Option<bool>,
-    pub is_user_deleted: Option<bool>,
-    pub user: Option<String>,
+model! {
+    pub struct Bookmark {
+        pub app_id: Option<String>,
+        pub channel_id: Option<::ChannelId>,
+        pub date_created: Option<i32>,
+        pub date_updated: Option<i32>,
+        pub emoji: Option<String>,
+        pub entity_id: Option<String>,
+        pub icon_url: Option<String>,
+        pub id: Option<String>,
+        pub link: Option<String>,
+        pub rank: Option<String>,
+        pub title: Option<String>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+    }
+}
+
+model! {
+    pub struct Bot {
+        pub app_id: Option<String>,
+        pub deleted: Option<bool>,
+        pub icons: Option<BotIcons>,
+        pub id: Option<String>,
+        pub name: Option<String>,
+    }
+}
+
+model! {
+    pub struct BotIcons {
+        pub image_36: Option<String>,
+        pub image_48: Option<String>,
+        pub image_72: Option<String>,
+    }
+}
+
+model! {
+    pub struct BotProfile {
+        pub app_id: Option<String>,
+        pub deleted: Option<bool>,
+        pub icons: Option<BotProfileIcons>,
+        pub id: Option<String>,
+        pub name: Option<String>,
+        pub team_id: Option<::TeamId>,
+        pub updated: Option<i32>,
+    }
+}
+
+model! {
+    pub struct BotProfileIcons {
+        pub image_36: Option<String>,
+        pub image_48: Option<String>,
+        pub image_72: Option<String>,
+    }
+}
+
+model! {
+    pub struct Call {
+        pub date_end: Option<i32>,
+        pub date_start: Option<i32>,
+        pub desktop_app_join_url: Option<String>,
+        pub external_display_id: Option<String>,
+        pub external_unique_id: Option<String>,
+        pub id: Option<String>,
+        pub join_url: Option<String>,
+        pub title: Option<String>,
+        pub users: Option<Vec<CallUsers>>,
+    }
+}
+
+model! {
+    pub struct CallUsers {
+        pub avatar_url: Option<String>,
+        pub display_name: Option<String>,
+        pub external_id: Option<String>,
+        pub slack_id: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct Channel {
+        pub created: Option<i32>,
+        pub creator: Option<::UserId>,
+        pub id: Option<::ChannelId>,
+        pub is_archived: Option<bool>,
+        pub is_channel: Option<bool>,
+        pub is_general: Option<bool>,
+        pub is_member: Option<bool>,
+        pub last_read: Option<::Timestamp>,
+        pub latest: Option<::Message>,
+        pub members: Option<Vec<::UserId>>,
+        pub name: Option<String>,
+        pub purpose: Option<ChannelPurpose>,
+        pub topic: Option<ChannelTopic>,
+        pub unread_count: Option<i32>,
+        pub unread_count_display: Option<i32>,
+    }
+}
+
+model! {
+    pub struct ChannelPurpose {
+        pub creator: Option<::UserId>,
+        pub last_set: Option<i32>,
+        pub value: Option<String>,
+    }
+}
+
+
+model! {
+    pub struct ChannelTopic {
+        pub creator: Option<::UserId>,
+        pub last_set: Option<i32>,
+        pub value: Option<String>,
+    }
+}
+
+model! {
+    pub struct Conversation {
+        pub created: Option<i32>,
+        pub creator: Option<::UserId>,
+        pub id: Option<::ChannelId>,
+        pub is_archived: Option<bool>,
+        pub is_channel: Option<bool>,
+        pub is_ext_shared: Option<bool>,
+        pub is_general: Option<bool>,
+        pub is_group: Option<bool>,
+        pub is_im: Option<bool>,
+        pub is_member: Option<bool>,
+        pub is_mpim: Option<bool>,
+        pub is_org_shared: Option<bool>,
+        pub is_private: Option<bool>,
+        pub is_shared: Option<bool>,
+        pub last_read: Option<::Timestamp>,
+        pub locale: Option<String>,
+        pub name: Option<String>,
+        pub name_normalized: Option<String>,
+        pub num_members: Option<i32>,
+        pub purpose: Option<ConversationPurpose>,
+        pub topic: Option<ConversationTopic>,
+        pub unread_count: Option<i32>,
+        pub unread_count_display: Option<i32>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct ConversationPurpose {
+        pub creator: Option<::UserId>,
+        pub last_set: Option<i32>,
+        pub value: Option<String>,
+    }
+}
+
+
+model! {
+    pub struct ConversationTopic {
+        pub creator: Option<::UserId>,
+        pub last_set: Option<i32>,
+        pub value: Option<String>,
+    }
+}
+
+model! {
+    pub struct File {
+        pub channels: Option<Vec<::ChannelId>>,
+        pub comments_count: Option<i32>,
+        pub created: Option<i32>,
+        pub display_as_bot: Option<bool>,
+        pub edit_link: Option<String>,
+        pub editable: Option<bool>,
+        pub external_type: Option<String>,
+        pub filetype: Option<String>,
+        pub groups: Option<Vec<::ChannelId>>,
+        pub id: Option<String>,
+        pub ims: Option<Vec<::ChannelId>>,
+        pub initial_comment: Option<::FileComment>,
+        pub is_external: Option<bool>,
+        pub is_public: Option<bool>,
+        pub is_starred: Option<bool>,
+        pub lines: Option<i32>,
+        pub lines_more: Option<i32>,
+        pub mimetype: Option<String>,
+        pub mode: Option<String>,
+        pub name: Option<String>,
+        pub num_stars: Option<i32>,
+        pub permalink: Option<String>,
+        pub permalink_public: Option<String>,
+        pub pinned_to: Option<Vec<::ChannelId>>,
+        pub pretty_type: Option<String>,
+        pub preview: Option<String>,
+        pub preview_highlight: Option<String>,
+        pub public_url_shared: Option<bool>,
+        pub reactions: Option<Vec<::Reaction>>,
+        pub shares: Option<FileShares>,
+        pub size: Option<i32>,
+        pub thumb_160: Option<String>,
+        pub thumb_360: Option<String>,
+        pub thumb_360_gif: Option<String>,
+        pub thumb_360_h: Option<i32>,
+        pub thumb_360_w: Option<i32>,
+        pub thumb_480: Option<String>,
+        pub thumb_480_h: Option<i32>,
+        pub thumb_480_w: Option<i32>,
+        pub thumb_64: Option<String>,
+        pub thumb_80: Option<String>,
+        pub timestamp: Option<i32>,
+        pub title: Option<String>,
+        pub url_private: Option<String>,
+        pub url_private_download: Option<String>,
+        pub user: Option<::UserId>,
+        pub username: Option<String>,
+    }
+}
+
+model! {
+    pub struct FileShares {
+        pub private: Option<HashMap<String, Vec<FileSharesShare>>>,
+        pub public: Option<HashMap<String, Vec<FileSharesShare>>>,
+    }
+}
+
+model! {
+    pub struct FileSharesShare {
+        pub channel_name: Option<String>,
+        pub latest_reply: Option<::Timestamp>,
+        pub reply_count: Option<i32>,
+        pub reply_users: Option<Vec<::UserId>>,
+        pub reply_users_count: Option<i32>,
+        pub share_user_id: Option<::UserId>,
+        pub team_id: Option<::TeamId>,
+        pub thread_ts: Option<::Timestamp>,
+        pub ts: Option<::Timestamp>,
+    }
+}
+
+model! {
+    pub struct FileComment {
+        pub comment: Option<String>,
+        pub id: Option<String>,
+        pub reactions: Option<Vec<::Reaction>>,
+        pub timestamp: Option<i32>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct Group {
+        pub created: Option<i32>,
+        pub creator: Option<::UserId>,
+        pub id: Option<::ChannelId>,
+        pub is_archived: Option<bool>,
+        pub is_group: Option<bool>,
+        pub is_mpim: Option<bool>,
+        pub last_read: Option<::Timestamp>,
+        pub latest: Option<::Message>,
+        pub members: Option<Vec<::UserId>>,
+        pub name: Option<String>,
+        pub purpose: Option<GroupPurpose>,
+        pub topic: Option<GroupTopic>,
+        pub unread_count: Option<i32>,
+        pub unread_count_display: Option<i32>,
+    }
+}
+
+model! {
+    pub struct GroupPurpose {
+        pub creator: Option<::UserId>,
+        pub last_set: Option<i32>,
+        pub value: Option<String>,
+    }
+}
+
+
+model! {
+    pub struct GroupTopic {
+        pub creator: Option<::UserId>,
+        pub last_set: Option<i32>,
+        pub value: Option<String>,
+    }
+}
+
+model! {
+    pub struct Im {
+        pub created: Option<i32>,
+        pub id: Option<::ChannelId>,
+        pub is_im: Option<bool>,
+        pub is_user_deleted: Option<bool>,
+        pub user: Option<::UserId>,
+    }
+}
+
+/// Something that can be pinned, reacted to or starred: a message, a file or a comment on a file.
+#[derive(Clone, Debug)]
+pub enum Item {
+    Message(ItemMessage),
+    File(ItemFile),
+    FileComment(ItemFileComment),
+}
+
+impl ::serde::Deserialize for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::Deserializer
+    {
+        use serde::de::Error as SerdeError;
+
+        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
+
+        let value = ::serde_json::Value::deserialize(deserializer)?;
+        if let Some(ty_val) = value.get("type") {
+            if let Some(ty) = ty_val.as_str() {
+                match ty {
+                    "message" => {
+                        ::serde_json::from_value::<ItemMessage>(value.clone())
+                            .map(|obj| Item::Message(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "file" => {
+                        ::serde_json::from_value::<ItemFile>(value.clone())
+                            .map(|obj| Item::File(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "file_comment" => {
+                        ::serde_json::from_value::<ItemFileComment>(value.clone())
+                            .map(|obj| Item::FileComment(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
+                }
+            } else {
+                Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &"a string"))
+            }
+        } else {
+            Err(D::Error::missing_field("type"))
+        }
+    }
+}
+
+model! {
+    pub struct ItemFile {
+        pub created: Option<i32>,
+        pub created_by: Option<::UserId>,
+        pub file: ::File,
+        #[serde(rename = "type")]
+        pub ty: String,
+    }
+}
+
+model! {
+    pub struct ItemFileComment {
+        pub comment: ::FileComment,
+        pub created: Option<i32>,
+        pub created_by: Option<::UserId>,
+        pub file: ::File,
+        #[serde(rename = "type")]
+        pub ty: String,
+    }
+}
+
+model! {
+    pub struct ItemMessage {
+        pub channel: ::ChannelId,
+        pub created: Option<i32>,
+        pub created_by: Option<::UserId>,
+        pub message: ::Message,
+        #[serde(rename = "type")]
+        pub ty: String,
+    }
 }
 
 #[derive(Clone, Debug)]
 pub enum Message {
     Standard(MessageStandard),
+    BotAdd(MessageBotAdd),
     BotMessage(MessageBotMessage),
+    BotRemove(MessageBotRemove),
     ChannelArchive(MessageChannelArchive),
+    ChannelConvertToPrivate(MessageChannelConvertToPrivate),
     ChannelJoin(MessageChannelJoin),
     ChannelLeave(MessageChannelLeave),
     ChannelName(MessageChannelName),
+    ChannelPostingPermissions(MessageChannelPostingPermissions),
     ChannelPurpose(MessageChannelPurpose),
     ChannelTopic(MessageChannelTopic),
     ChannelUnarchive(MessageChannelUnarchive),
+    EkmAccessDenied(MessageEkmAccessDenied),
     FileComment(MessageFileComment),
     FileMention(MessageFileMention),
     FileShare(MessageFileShare),
@@ -172,13 +395,20 @@ pub enum Message {
     GroupPurpose(MessageGroupPurpose),
     GroupTopic(MessageGroupTopic),
     GroupUnarchive(MessageGroupUnarchive),
+    HuddleThread(MessageHuddleThread),
     MeMessage(MessageMeMessage),
     MessageChanged(MessageMessageChanged),
     MessageDeleted(MessageMessageDeleted),
     MessageReplied(MessageMessageReplied),
     PinnedItem(MessagePinnedItem),
+    ReminderAdd(MessageReminderAdd),
     ReplyBroadcast(MessageReplyBroadcast),
+    SlackbotResponse(MessageSlackbotResponse),
+    ThreadBroadcast(MessageThreadBroadcast),
+    Tombstone(MessageTombstone),
     UnpinnedItem(MessageUnpinnedItem),
+    /// A message of a subtype not listed here, kept as it was sent.
+    Unknown(::serde_json::Value),
 }
 
 impl ::serde::Deserialize for Message {
@@ -187,33 +417,6 @@ impl ::serde::Deserialize for Message {
     {
         use serde::de::Error as SerdeError;
 
-        const VARIANTS: &'static [&'static str] = &["standard",
-                                                    "bot_message",
-                                                    "channel_archive",
-                                                    "channel_join",
-                                                    "channel_leave",
-                                                    "channel_name",
-                                                    "channel_purpose",
-                                                    "channel_topic",
-                                                    "channel_unarchive",
-                                                    "file_comment",
-                                                    "file_mention",
-                                                    "file_share",
-                                                    "group_archive",
-                                                    "group_join",
-                                                    "group_leave",
-                                                    "group_name",
-                                                    "group_purpose",
-                                                    "group_topic",
-                                                    "group_unarchive",
-                                                    "me_message",
-                                                    "message_changed",
-                                                    "message_deleted",
-                                                    "message_replied",
-                                                    "pinned_item",
-                                                    "reply_broadcast",
-                                                    "unpinned_item"];
-
         let value = ::serde_json::Value::deserialize(deserializer)?;
         if let Some(ty_val) = value.get("subtype") {
             if let Some(ty) = ty_val.as_str() {
@@ -223,16 +426,31 @@ impl ::serde::Deserialize for Message {
                             .map(|obj| Message::Standard(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
+                    "bot_add" => {
+                        ::serde_json::from_value::<MessageBotAdd>(value.clone())
+                            .map(|obj| Message::BotAdd(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
                     "bot_message" => {
                         ::serde_json::from_value::<MessageBotMessage>(value.clone())
                             .map(|obj| Message::BotMessage(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
+                    "bot_remove" => {
+                        ::serde_json::from_value::<MessageBotRemove>(value.clone())
+                            .map(|obj| Message::BotRemove(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
                     "channel_archive" => {
                         ::serde_json::from_value::<MessageChannelArchive>(value.clone())
                             .map(|obj| Message::ChannelArchive(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
+                    "channel_convert_to_private" => {
+                        ::serde_json::from_value::<MessageChannelConvertToPrivate>(value.clone())
+                            .map(|obj| Message::ChannelConvertToPrivate(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
                     "channel_join" => {
                         ::serde_json::from_value::<MessageChannelJoin>(value.clone())
                             .map(|obj| Message::ChannelJoin(obj))
@@ -248,6 +466,11 @@ impl ::serde::Deserialize for Message {
                             .map(|obj| Message::ChannelName(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
+                    "channel_posting_permissions" => {
+                        ::serde_json::from_value::<MessageChannelPostingPermissions>(value.clone())
+                            .map(|obj| Message::ChannelPostingPermissions(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
                     "channel_purpose" => {
                         ::serde_json::from_value::<MessageChannelPurpose>(value.clone())
                             .map(|obj| Message::ChannelPurpose(obj))
@@ -263,6 +486,11 @@ impl ::serde::Deserialize for Message {
                             .map(|obj| Message::ChannelUnarchive(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
+                    "ekm_access_denied" => {
+                        ::serde_json::from_value::<MessageEkmAccessDenied>(value.clone())
+                            .map(|obj| Message::EkmAccessDenied(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
                     "file_comment" => {
                         ::serde_json::from_value::<MessageFileComment>(value.clone())
                             .map(|obj| Message::FileComment(obj))
@@ -313,6 +541,11 @@ impl ::serde::Deserialize for Message {
                             .map(|obj| Message::GroupUnarchive(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
+                    "huddle_thread" => {
+                        ::serde_json::from_value::<MessageHuddleThread>(value.clone())
+                            .map(|obj| Message::HuddleThread(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
                     "me_message" => {
                         ::serde_json::from_value::<MessageMeMessage>(value.clone())
                             .map(|obj| Message::MeMessage(obj))
@@ -338,17 +571,37 @@ impl ::serde::Deserialize for Message {
                             .map(|obj| Message::PinnedItem(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
+                    "reminder_add" => {
+                        ::serde_json::from_value::<MessageReminderAdd>(value.clone())
+                            .map(|obj| Message::ReminderAdd(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
                     "reply_broadcast" => {
                         ::serde_json::from_value::<MessageReplyBroadcast>(value.clone())
                             .map(|obj| Message::ReplyBroadcast(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
+                    "slackbot_response" => {
+                        ::serde_json::from_value::<MessageSlackbotResponse>(value.clone())
+                            .map(|obj| Message::SlackbotResponse(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "thread_broadcast" => {
+                        ::serde_json::from_value::<MessageThreadBroadcast>(value.clone())
+                            .map(|obj| Message::ThreadBroadcast(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "tombstone" => {
+                        ::serde_json::from_value::<MessageTombstone>(value.clone())
+                            .map(|obj| Message::Tombstone(obj))
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
                     "unpinned_item" => {
                         ::serde_json::from_value::<MessageUnpinnedItem>(value.clone())
                             .map(|obj| Message::UnpinnedItem(obj))
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
+                    _ => Ok(Message::Unknown(value.clone())),
                 }
             } else {
                 Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &"a string"))
@@ -363,550 +616,838 @@ impl ::serde::Deserialize for Message {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageBotMessage {
-    pub bot_id: Option<String>,
-    pub icons: Option<MessageBotMessageIcons>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub username: Option<String>,
+model! {
+    pub struct MessageBotAdd {
+        pub bot_id: Option<String>,
+        pub bot_link: Option<String>,
+        pub channel: Option<::ChannelId>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageBotMessageIcons {
-    pub image_36: Option<String>,
-    pub image_48: Option<String>,
-    pub image_72: Option<String>,
+
+model! {
+    pub struct MessageBotMessage {
+        pub bot_id: Option<String>,
+        pub bot_profile: Option<::BotProfile>,
+        pub edited: Option<MessageBotMessageEdited>,
+        pub icons: Option<MessageBotMessageIcons>,
+        pub latest_reply: Option<::Timestamp>,
+        pub metadata: Option<MessageMetadata>,
+        pub parent_user_id: Option<::UserId>,
+        pub reply_count: Option<i32>,
+        pub reply_users: Option<Vec<::UserId>>,
+        pub reply_users_count: Option<i32>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub thread_ts: Option<::Timestamp>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub username: Option<String>,
+    }
 }
 
+model! {
+    pub struct MessageBotMessageEdited {
+        pub ts: Option<::Timestamp>,
+        pub user: Option<::UserId>,
+    }
+}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageChannelArchive {
-    pub members: Option<Vec<String>>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageBotMessageIcons {
+        pub image_36: Option<String>,
+        pub image_48: Option<String>,
+        pub image_72: Option<String>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageChannelJoin {
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageBotRemove {
+        pub bot_id: Option<String>,
+        pub bot_link: Option<String>,
+        pub channel: Option<::ChannelId>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageChannelLeave {
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageChannelArchive {
+        pub members: Option<Vec<::UserId>>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageChannelName {
-    pub name: Option<String>,
-    pub old_name: Option<String>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageChannelConvertToPrivate {
+        pub channel: Option<::ChannelId>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageChannelPurpose {
-    pub purpose: Option<String>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageChannelJoin {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageChannelTopic {
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub topic: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageChannelLeave {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageChannelUnarchive {
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageChannelName {
+        pub name: Option<String>,
+        pub old_name: Option<String>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageFileComment {
-    pub comment: Option<::FileComment>,
-    pub file: Option<::File>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+model! {
+    pub struct MessageChannelPostingPermissions {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageFileMention {
-    pub file: Option<::File>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageChannelPurpose {
+        pub purpose: Option<String>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageFileShare {
-    pub file: Option<::File>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub upload: Option<bool>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageChannelTopic {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub topic: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageGroupArchive {
-    pub members: Option<Vec<String>>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageChannelUnarchive {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageGroupJoin {
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageEkmAccessDenied {
+        pub hidden: Option<bool>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageGroupLeave {
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageFileComment {
+        pub comment: Option<::FileComment>,
+        pub file: Option<::File>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageGroupName {
-    pub name: Option<String>,
-    pub old_name: Option<String>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageFileMention {
+        pub file: Option<::File>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageGroupPurpose {
-    pub purpose: Option<String>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
+model! {
+    pub struct MessageFileShare {
+        pub file: Option<::File>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub upload: Option<bool>,
+        pub user: Option<::UserId>,
+    }
+}
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageGroupTopic {
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub topic: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
+model! {
+    pub struct MessageGroupArchive {
+        pub members: Option<Vec<::UserId>>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageGroupUnarchive {
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
+model! {
+    pub struct MessageGroupJoin {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
 
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageMeMessage {
-    pub channel: Option<String>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
-
+model! {
+    pub struct MessageGroupLeave {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageMessageChanged {
-    pub channel: Option<String>,
-    pub hidden: Option<bool>,
-    pub message: Option<MessageMessageChangedMessage>,
-    pub subtype: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageMessageChangedMessage {
-    pub edited: Option<MessageMessageChangedMessageEdited>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageMessageChangedMessageEdited {
-    pub ts: Option<String>,
-    pub user: Option<String>,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageMessageDeleted {
-    pub channel: Option<String>,
-    pub deleted_ts: Option<String>,
-    pub hidden: Option<bool>,
-    pub subtype: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageMessageReplied {
-    pub channel: Option<String>,
-    pub event_ts: Option<String>,
-    pub hidden: Option<bool>,
-    pub message: Option<MessageMessageRepliedMessage>,
-    pub subtype: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageMessageRepliedMessage {
-    pub replies: Option<Vec<MessageMessageRepliedMessageReply>>,
-    pub reply_count: Option<i32>,
-    pub text: Option<String>,
-    pub thread_ts: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageMessageRepliedMessageReply {
-    pub ts: Option<String>,
-    pub user: Option<String>,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessagePinnedItem {
-    pub channel: Option<String>,
-    pub item: Option<MessagePinnedItemItem>,
-    pub item_type: Option<String>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessagePinnedItemItem {}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageReplyBroadcast {
-    pub attachments: Option<Vec<MessageReplyBroadcastAttachment>>,
-    pub channel: Option<String>,
-    pub event_ts: Option<String>,
-    pub subtype: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageReplyBroadcastAttachment {
-    pub author_icon: Option<String>,
-    pub author_link: Option<String>,
-    pub author_subname: Option<String>,
-    pub channel_id: Option<String>,
-    pub channel_name: Option<String>,
-    pub fallback: Option<String>,
-    pub footer: Option<String>,
-    pub from_url: Option<String>,
-    pub id: Option<i32>,
-    pub mrkdwn_in: Option<Vec<String>>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageStandard {
-    pub attachments: Option<Vec<MessageStandardAttachment>>,
-    pub channel: Option<String>,
-    pub edited: Option<MessageStandardEdited>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageStandardAttachment {
-    pub author_icon: Option<String>,
-    pub author_link: Option<String>,
-    pub author_name: Option<String>,
-    pub color: Option<String>,
-    pub fallback: Option<String>,
-    pub fields: Option<Vec<MessageStandardAttachmentField>>,
-    pub footer: Option<String>,
-    pub footer_icon: Option<String>,
-    pub image_url: Option<String>,
-    pub pretext: Option<String>,
-    pub text: Option<String>,
-    pub thumb_url: Option<String>,
-    pub title: Option<String>,
-    pub title_link: Option<String>,
-    pub ts: Option<f32>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageStandardAttachmentField {
-    pub short: Option<bool>,
-    pub title: Option<String>,
-    pub value: Option<String>,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageStandardEdited {
-    pub ts: Option<String>,
-    pub user: Option<String>,
-}
-
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageUnpinnedItem {
-    pub channel: Option<String>,
-    pub item: Option<MessageUnpinnedItemItem>,
-    pub item_type: Option<String>,
-    pub subtype: Option<String>,
-    pub text: Option<String>,
-    pub ts: Option<String>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
-    pub user: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageUnpinnedItemItem {}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Mpim {
-    pub created: Option<i32>,
-    pub creator: Option<String>,
-    pub id: Option<String>,
-    pub is_group: Option<bool>,
-    pub is_mpim: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest: Option<::Message>,
-    pub members: Option<Vec<String>>,
-    pub name: Option<String>,
-    pub unread_count: Option<i32>,
-    pub unread_count_display: Option<i32>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Paging {
-    pub count: Option<i32>,
-    pub page: Option<i32>,
-    pub pages: Option<i32>,
-    pub total: Option<i32>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Reaction {
-    pub count: Option<i32>,
-    pub name: Option<String>,
-    pub users: Option<Vec<String>>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Reminder {
-    pub complete_ts: Option<f32>,
-    pub creator: Option<String>,
-    pub id: Option<String>,
-    pub recurring: Option<bool>,
-    pub text: Option<String>,
-    pub time: Option<f32>,
-    pub user: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Team {
-    pub domain: Option<String>,
-    pub email_domain: Option<String>,
-    pub icon: Option<TeamIcon>,
-    pub id: Option<String>,
-    pub name: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct TeamIcon {
-    pub image_102: Option<String>,
-    pub image_132: Option<String>,
-    pub image_34: Option<String>,
-    pub image_44: Option<String>,
-    pub image_68: Option<String>,
-    pub image_88: Option<String>,
-    pub image_default: Option<bool>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ThreadInfo {
-    pub complete: Option<bool>,
-    pub count: Option<i32>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct User {
-    pub color: Option<String>,
-    pub deleted: Option<bool>,
-    pub has_2fa: Option<bool>,
-    pub id: Option<String>,
-    pub is_admin: Option<bool>,
-    pub is_owner: Option<bool>,
-    pub is_primary_owner: Option<bool>,
-    pub is_restricted: Option<bool>,
-    pub is_ultra_restricted: Option<bool>,
-    pub name: Option<String>,
-    pub profile: Option<::UserProfile>,
-    pub two_factor_type: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct Usergroup {
-    pub auto_type: Option<String>,
-    pub created_by: Option<String>,
-    pub date_create: Option<i32>,
-    pub date_delete: Option<i32>,
-    pub date_update: Option<i32>,
-    pub deleted_by: Option<String>,
-    pub description: Option<String>,
-    pub handle: Option<String>,
-    pub id: Option<String>,
-    pub is_external: Option<bool>,
-    pub is_usergroup: Option<bool>,
-    pub name: Option<String>,
-    pub prefs: Option<UsergroupPrefs>,
-    pub team_id: Option<String>,
-    pub updated_by: Option<String>,
-    pub user_count: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct UsergroupPrefs {
-    pub channels: Option<Vec<String>>,
-    pub groups: Option<Vec<String>>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct UserProfile {
-    pub email: Option<String>,
-    #[serde(deserialize_with = "::optional_struct_or_empty_array")]
-    #[serde(default)]
-    pub fields: Option<HashMap<String, UserProfileFields>>,
-    pub first_name: Option<String>,
-    pub image_1024: Option<String>,
-    pub image_192: Option<String>,
-    pub image_24: Option<String>,
-    pub image_32: Option<String>,
-    pub image_48: Option<String>,
-    pub image_512: Option<String>,
-    pub image_72: Option<String>,
-    pub image_original: Option<String>,
-    pub last_name: Option<String>,
-    pub phone: Option<String>,
-    pub skype: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct UserProfileFields {
-    pub alt: Option<String>,
-    pub label: Option<String>,
-    pub value: Option<String>,
+
+model! {
+    pub struct MessageGroupName {
+        pub name: Option<String>,
+        pub old_name: Option<String>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageGroupPurpose {
+        pub purpose: Option<String>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageGroupTopic {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub topic: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageGroupUnarchive {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageHuddleThread {
+        pub channel: Option<::ChannelId>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageMeMessage {
+        pub channel: Option<::ChannelId>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageMessageChanged {
+        pub channel: Option<::ChannelId>,
+        pub hidden: Option<bool>,
+        pub message: Option<MessageMessageChangedMessage>,
+        pub subtype: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+    }
+}
+
+model! {
+    pub struct MessageMessageChangedMessage {
+        pub edited: Option<MessageMessageChangedMessageEdited>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct MessageMessageChangedMessageEdited {
+        pub ts: Option<::Timestamp>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageMessageDeleted {
+        pub channel: Option<::ChannelId>,
+        pub deleted_ts: Option<::Timestamp>,
+        pub hidden: Option<bool>,
+        pub subtype: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+    }
+}
+
+
+model! {
+    pub struct MessageMessageReplied {
+        pub channel: Option<::ChannelId>,
+        pub event_ts: Option<::Timestamp>,
+        pub hidden: Option<bool>,
+        pub message: Option<MessageMessageRepliedMessage>,
+        pub subtype: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+    }
+}
+
+model! {
+    pub struct MessageMessageRepliedMessage {
+        pub replies: Option<Vec<MessageMessageRepliedMessageReply>>,
+        pub reply_count: Option<i32>,
+        pub text: Option<String>,
+        pub thread_ts: Option<::Timestamp>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct MessageMessageRepliedMessageReply {
+        pub ts: Option<::Timestamp>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct MessageMetadata {
+        pub event_payload: Option<::serde_json::Value>,
+        pub event_type: Option<String>,
+    }
+}
+
+
+model! {
+    pub struct MessagePinnedItem {
+        pub channel: Option<::ChannelId>,
+        pub item: Option<MessagePinnedItemItem>,
+        pub item_type: Option<String>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct MessagePinnedItemItem {}
+}
+
+
+model! {
+    pub struct MessageReminderAdd {
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageReplyBroadcast {
+        pub attachments: Option<Vec<MessageReplyBroadcastAttachment>>,
+        pub channel: Option<::ChannelId>,
+        pub event_ts: Option<::Timestamp>,
+        pub subtype: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct MessageReplyBroadcastAttachment {
+        pub author_icon: Option<String>,
+        pub author_link: Option<String>,
+        pub author_subname: Option<String>,
+        pub channel_id: Option<::ChannelId>,
+        pub channel_name: Option<String>,
+        pub fallback: Option<String>,
+        pub footer: Option<String>,
+        pub from_url: Option<String>,
+        pub id: Option<i32>,
+        pub mrkdwn_in: Option<Vec<String>>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+    }
+}
+
+
+model! {
+    pub struct MessageSlackbotResponse {
+        pub channel: Option<::ChannelId>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageStandard {
+        pub attachments: Option<Vec<MessageStandardAttachment>>,
+        pub bot_id: Option<String>,
+        pub bot_profile: Option<::BotProfile>,
+        pub channel: Option<::ChannelId>,
+        pub edited: Option<MessageStandardEdited>,
+        pub latest_reply: Option<::Timestamp>,
+        pub metadata: Option<MessageMetadata>,
+        pub parent_user_id: Option<::UserId>,
+        pub reactions: Option<Vec<::Reaction>>,
+        pub reply_count: Option<i32>,
+        pub reply_users: Option<Vec<::UserId>>,
+        pub reply_users_count: Option<i32>,
+        pub text: Option<String>,
+        pub thread_ts: Option<::Timestamp>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct MessageStandardAttachment {
+        pub author_icon: Option<String>,
+        pub author_link: Option<String>,
+        pub author_name: Option<String>,
+        pub color: Option<String>,
+        pub fallback: Option<String>,
+        pub fields: Option<Vec<MessageStandardAttachmentField>>,
+        pub footer: Option<String>,
+        pub footer_icon: Option<String>,
+        pub image_url: Option<String>,
+        pub pretext: Option<String>,
+        pub text: Option<String>,
+        pub thumb_url: Option<String>,
+        pub title: Option<String>,
+        pub title_link: Option<String>,
+        pub ts: Option<f32>,
+    }
+}
+
+model! {
+    pub struct MessageStandardAttachmentField {
+        pub short: Option<bool>,
+        pub title: Option<String>,
+        pub value: Option<String>,
+    }
+}
+
+
+model! {
+    pub struct MessageStandardEdited {
+        pub ts: Option<::Timestamp>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageThreadBroadcast {
+        pub attachments: Option<Vec<MessageStandardAttachment>>,
+        pub channel: Option<::ChannelId>,
+        pub parent_user_id: Option<::UserId>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub thread_ts: Option<::Timestamp>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageTombstone {
+        pub hidden: Option<bool>,
+        pub latest_reply: Option<::Timestamp>,
+        pub reply_count: Option<i32>,
+        pub reply_users: Option<Vec<::UserId>>,
+        pub reply_users_count: Option<i32>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub thread_ts: Option<::Timestamp>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+
+model! {
+    pub struct MessageUnpinnedItem {
+        pub channel: Option<::ChannelId>,
+        pub item: Option<MessageUnpinnedItemItem>,
+        pub item_type: Option<String>,
+        pub subtype: Option<String>,
+        pub text: Option<String>,
+        pub ts: Option<::Timestamp>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct MessageUnpinnedItemItem {}
+}
+
+model! {
+    pub struct Mpim {
+        pub created: Option<i32>,
+        pub creator: Option<::UserId>,
+        pub id: Option<::ChannelId>,
+        pub is_group: Option<bool>,
+        pub is_mpim: Option<bool>,
+        pub last_read: Option<::Timestamp>,
+        pub latest: Option<::Message>,
+        pub members: Option<Vec<::UserId>>,
+        pub name: Option<String>,
+        pub unread_count: Option<i32>,
+        pub unread_count_display: Option<i32>,
+    }
+}
+
+model! {
+    pub struct Paging {
+        pub count: Option<i32>,
+        pub page: Option<i32>,
+        pub pages: Option<i32>,
+        pub total: Option<i32>,
+    }
+}
+
+model! {
+    pub struct Reaction {
+        pub count: Option<i32>,
+        pub name: Option<String>,
+        pub users: Option<Vec<::UserId>>,
+    }
+}
+
+model! {
+    pub struct Reminder {
+        pub complete_ts: Option<f32>,
+        pub creator: Option<::UserId>,
+        pub id: Option<String>,
+        pub recurring: Option<bool>,
+        pub text: Option<String>,
+        pub time: Option<f32>,
+        pub user: Option<::UserId>,
+    }
+}
+
+model! {
+    pub struct Team {
+        pub domain: Option<String>,
+        pub email_domain: Option<String>,
+        pub icon: Option<TeamIcon>,
+        pub id: Option<::TeamId>,
+        pub name: Option<String>,
+    }
+}
+
+model! {
+    pub struct TeamIcon {
+        pub image_102: Option<String>,
+        pub image_132: Option<String>,
+        pub image_34: Option<String>,
+        pub image_44: Option<String>,
+        pub image_68: Option<String>,
+        pub image_88: Option<String>,
+        pub image_default: Option<bool>,
+    }
+}
+
+model! {
+    pub struct ThreadInfo {
+        pub complete: Option<bool>,
+        pub count: Option<i32>,
+    }
+}
+
+model! {
+    pub struct User {
+        pub color: Option<String>,
+        pub deleted: Option<bool>,
+        pub has_2fa: Option<bool>,
+        pub id: Option<::UserId>,
+        pub is_admin: Option<bool>,
+        pub is_owner: Option<bool>,
+        pub is_primary_owner: Option<bool>,
+        pub is_restricted: Option<bool>,
+        pub is_ultra_restricted: Option<bool>,
+        pub name: Option<String>,
+        pub profile: Option<::UserProfile>,
+        pub two_factor_type: Option<String>,
+    }
+}
+
+model! {
+    pub struct Usergroup {
+        pub auto_type: Option<String>,
+        pub created_by: Option<::UserId>,
+        pub date_create: Option<i32>,
+        pub date_delete: Option<i32>,
+        pub date_update: Option<i32>,
+        pub deleted_by: Option<::UserId>,
+        pub description: Option<String>,
+        pub handle: Option<String>,
+        pub id: Option<String>,
+        pub is_external: Option<bool>,
+        pub is_usergroup: Option<bool>,
+        pub name: Option<String>,
+        pub prefs: Option<UsergroupPrefs>,
+        pub team_id: Option<::TeamId>,
+        pub updated_by: Option<::UserId>,
+        pub user_count: Option<String>,
+        pub users: Option<Vec<::UserId>>,
+    }
+}
+
+model! {
+    pub struct UsergroupPrefs {
+        pub channels: Option<Vec<::ChannelId>>,
+        pub groups: Option<Vec<::ChannelId>>,
+    }
+}
+
+model! {
+    pub struct UserProfile {
+        pub display_name: Option<String>,
+        pub display_name_normalized: Option<String>,
+        pub email: Option<String>,
+        #[serde(deserialize_with = "::optional_struct_or_empty_array")]
+        #[serde(default)]
+        pub fields: Option<HashMap<String, UserProfileFields>>,
+        pub first_name: Option<String>,
+        pub image_1024: Option<String>,
+        pub image_192: Option<String>,
+        pub image_24: Option<String>,
+        pub image_32: Option<String>,
+        pub image_48: Option<String>,
+        pub image_512: Option<String>,
+        pub image_72: Option<String>,
+        pub image_original: Option<String>,
+        pub last_name: Option<String>,
+        pub phone: Option<String>,
+        pub real_name: Option<String>,
+        pub real_name_normalized: Option<String>,
+        pub skype: Option<String>,
+    }
+}
+
+model! {
+    pub struct UserProfileFields {
+        pub alt: Option<String>,
+        pub label: Option<String>,
+        pub value: Option<String>,
+    }
+}
+
+model! {
+    pub struct View {
+        pub app_id: Option<String>,
+        pub app_installed_team_id: Option<::TeamId>,
+        pub blocks: Option<Vec<::serde_json::Value>>,
+        pub bot_id: Option<String>,
+        pub callback_id: Option<String>,
+        pub clear_on_close: Option<bool>,
+        pub close: Option<ViewText>,
+        pub external_id: Option<String>,
+        pub hash: Option<String>,
+        pub id: Option<String>,
+        pub notify_on_close: Option<bool>,
+        pub previous_view_id: Option<String>,
+        pub private_metadata: Option<String>,
+        pub root_view_id: Option<String>,
+        pub state: Option<ViewState>,
+        pub submit: Option<ViewText>,
+        pub team_id: Option<::TeamId>,
+        pub title: Option<ViewText>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+    }
+}
+
+model! {
+    pub struct ViewState {
+        pub values: Option<HashMap<String, HashMap<String, ViewStateValue>>>,
+    }
+}
+
+model! {
+    pub struct ViewStateValue {
+        pub selected_channel: Option<::ChannelId>,
+        pub selected_conversation: Option<::ChannelId>,
+        pub selected_date: Option<String>,
+        pub selected_option: Option<ViewStateValueOption>,
+        pub selected_options: Option<Vec<ViewStateValueOption>>,
+        pub selected_user: Option<::UserId>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+        pub value: Option<String>,
+    }
+}
+
+model! {
+    pub struct ViewStateValueOption {
+        pub text: Option<ViewText>,
+        pub value: Option<String>,
+    }
+}
+
+model! {
+    pub struct ViewText {
+        pub emoji: Option<bool>,
+        pub text: Option<String>,
+        #[serde(rename = "type")]
+        pub ty: Option<String>,
+    }
 }
@@ -0,0 +1,245 @@
+//! Uploading a file and sharing it in one call.
+
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use files;
+use requests::SlackUploadSender;
+use File;
+
+/// A file to upload, and where to share it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::upload::{self, Upload};
+/// let client = slack_api::requests::default_client().unwrap();
+/// let report = std::fs::read("report.csv").unwrap();
+///
+/// let upload = Upload::new("report.csv", &report)
+///     .title("Weekly report")
+///     .channels(&["C024BE91L", "C0G9QF9GZ"])
+///     .initial_comment("Here's this week's numbers");
+/// let uploaded = upload::upload_and_share(&client, "xoxb-...", &upload).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Upload<'a> {
+    filename: &'a str,
+    content: &'a [u8],
+    title: Option<&'a str>,
+    alt_txt: Option<&'a str>,
+    snippet_type: Option<&'a str>,
+    channels: &'a [&'a str],
+    thread: Option<(&'a str, &'a str)>,
+    initial_comment: Option<&'a str>,
+}
+
+impl<'a> Upload<'a> {
+    pub fn new(filename: &'a str, content: &'a [u8]) -> Self {
+        Upload {
+            filename: filename,
+            content: content,
+            title: None,
+            alt_txt: None,
+            snippet_type: None,
+            channels: &[],
+            thread: None,
+            initial_comment: None,
+        }
+    }
+
+    /// The title shown instead of the file name.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// A description of an image, for screen readers.
+    pub fn alt_txt(mut self, alt_txt: &'a str) -> Self {
+        self.alt_txt = Some(alt_txt);
+        self
+    }
+
+    /// The syntax to highlight a snippet with, e.g. `rust`.
+    pub fn snippet_type(mut self, snippet_type: &'a str) -> Self {
+        self.snippet_type = Some(snippet_type);
+        self
+    }
+
+    /// Shares the file to these channels. Without channels or a thread, the file stays private
+    /// to its uploader.
+    pub fn channels(mut self, channels: &'a [&'a str]) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Shares the file as a reply in the thread `thread_ts` of `channel`, instead of to
+    /// [`channels`](#method.channels).
+    pub fn thread(mut self, channel: &'a str, thread_ts: &'a str) -> Self {
+        self.thread = Some((channel, thread_ts));
+        self
+    }
+
+    /// The text of the message sharing the file.
+    pub fn initial_comment(mut self, initial_comment: &'a str) -> Self {
+        self.initial_comment = Some(initial_comment);
+        self
+    }
+}
+
+/// A file uploaded by [`upload_and_share`].
+#[derive(Clone, Debug)]
+pub struct Uploaded {
+    pub file: File,
+    /// The channels the file was shared to, with the `ts` of each sharing message.
+    ///
+    /// Slack shares files in the background, so shares it had not made yet when the upload
+    /// finished are missing.
+    pub shares: Vec<(String, String)>,
+}
+
+#[derive(Serialize)]
+struct FileRef<'a> {
+    id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+}
+
+/// Uploads a file and shares it where `upload` says.
+///
+/// This takes Slack's external upload flow, which replaces `files.upload`: it asks
+/// `files.getUploadURLExternal` for somewhere to upload to, uploads the content there, finishes
+/// with `files.completeUploadExternal` and reads the result back with `files.info`.
+pub fn upload_and_share<R>(client: &R, token: &str, upload: &Upload) -> Result<Uploaded, UploadError<R::Error>>
+    where R: SlackUploadSender
+{
+    let request = files::GetUploadURLExternalRequest {
+        filename: upload.filename,
+        length: upload.content.len() as u32,
+        alt_txt: upload.alt_txt,
+        snippet_type: upload.snippet_type,
+    };
+    let ticket = files::get_upload_url_external(client, token, &request).map_err(UploadError::GetUploadURL)?;
+    let (file_id, upload_url) = match (ticket.file_id, ticket.upload_url) {
+        (Some(file_id), Some(upload_url)) => (file_id, upload_url),
+        _ => return Err(UploadError::GetUploadURL(files::GetUploadURLExternalError::Unknown("no upload URL".to_owned()))),
+    };
+
+    let response = client.upload(&upload_url, upload.content).map_err(UploadError::Client)?;
+    match response.status {
+        Some(status) if status >= 300 => return Err(UploadError::Rejected(status)),
+        _ => {}
+    }
+
+    let file_ref = FileRef {
+        id: &file_id,
+        title: upload.title,
+    };
+    let file_refs = serde_json::to_string(&[file_ref]).expect("file references always serialize");
+    let channels = upload.channels.join(",");
+    let request = files::CompleteUploadExternalRequest {
+        files: &file_refs,
+        channel_id: upload.thread.map(|(channel, _)| channel),
+        channels: if upload.thread.is_none() && !channels.is_empty() {
+            Some(&channels)
+        } else {
+            None
+        },
+        initial_comment: upload.initial_comment,
+        thread_ts: upload.thread.map(|(_, thread_ts)| thread_ts),
+    };
+    files::complete_upload_external(client, token, &request).map_err(UploadError::Complete)?;
+
+    let request = files::InfoRequest { file: &file_id, ..files::InfoRequest::default() };
+    let file = files::info(client, token, &request).map_err(UploadError::Info)?.file;
+    let file = file.ok_or_else(|| UploadError::Info(files::InfoError::FileNotFound))?;
+    let shares = file.shares
+        .iter()
+        .flat_map(|shares| shares.public.iter().chain(shares.private.iter()))
+        .flat_map(|shares| shares.iter())
+        .flat_map(|(channel, shares)| shares.iter().filter_map(move |share| share.ts.clone().map(|ts| (channel.clone(), ts.into()))))
+        .collect();
+    Ok(Uploaded {
+        file: file,
+        shares: shares,
+    })
+}
+
+#[derive(Debug)]
+pub enum UploadError<E: Error> {
+    /// Slack would not hand out an upload URL.
+    GetUploadURL(files::GetUploadURLExternalError<E>),
+    /// Uploading the content failed.
+    Client(E),
+    /// The upload URL answered with this HTTP status.
+    Rejected(u16),
+    /// The content was uploaded, but Slack would not finish the upload.
+    Complete(files::CompleteUploadExternalError<E>),
+    /// The file was uploaded, but could not be read back.
+    Info(files::InfoError<E>),
+}
+
+impl<E: Error> fmt::Display for UploadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &UploadError::Rejected(status) => write!(f, "{}: HTTP {}", self.description(), status),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: Error> Error for UploadError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &UploadError::GetUploadURL(ref inner) => inner.description(),
+            &UploadError::Client(ref inner) => inner.description(),
+            &UploadError::Rejected(_) => "the upload was rejected",
+            &UploadError::Complete(ref inner) => inner.description(),
+            &UploadError::Info(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &UploadError::GetUploadURL(ref inner) => Some(inner),
+            &UploadError::Client(ref inner) => Some(inner),
+            &UploadError::Rejected(_) => None,
+            &UploadError::Complete(ref inner) => Some(inner),
+            &UploadError::Info(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_upload_and_share_in_thread() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("files.getUploadURLExternal")
+                .param("filename", "a.txt")
+                .param("length", "5")
+                .returns(r#"{"ok": true, "file_id": "F1", "upload_url": "https://files.slack.com/upload/v1/abc"}"#))
+            .expect(Expectation::new("upload").param("url", "https://files.slack.com/upload/v1/abc").times(1))
+            .expect(Expectation::new("files.completeUploadExternal")
+                .param("files", r#"[{"id":"F1","title":"A"}]"#)
+                .param("channel_id", "C1")
+                .param("thread_ts", "1.1")
+                .times(1))
+            .expect(Expectation::new("files.info").returns(r#"{"ok": true, "file": {"id": "F1",
+                "shares": {"public": {"C1": [{"ts": "1.2", "thread_ts": "1.1"}]}}}}"#));
+
+        let upload = Upload::new("a.txt", b"hello").title("A").thread("C1", "1.1");
+        let uploaded = upload_and_share(&mock, "xoxb-1", &upload).unwrap();
+
+        assert_eq!(Some("F1".to_owned()), uploaded.file.id);
+        assert_eq!(vec![("C1".to_owned(), "1.2".to_owned())], uploaded.shares);
+        assert_eq!(None, mock.calls_of("files.completeUploadExternal")[0].param("channels"));
+        mock.verify();
+    }
+}
@@ -0,0 +1,209 @@
+//! Posting to [incoming webhooks](https://api.slack.com/messaging/webhooks), which take messages
+//! without a token: the URL itself is the credential.
+
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use blocks::{Attachment, Block};
+use commands::ResponseType;
+use requests::SlackJsonSender;
+
+/// A message to post to an incoming webhook.
+///
+/// # Examples
+///
+/// ```
+/// # use slack_api::blocks::{Block, SectionBlock, Text};
+/// # use slack_api::webhook::WebhookMessage;
+/// let message = WebhookMessage::new("Deploy finished")
+///     .blocks(vec![Block::Section(SectionBlock::text(Text::mrkdwn("*4f2a9c1* is live")))])
+///     .thread_ts("1503435956.000247");
+/// assert_eq!(r#"{"text":"Deploy finished","blocks":[{"type":"section","text":{"type":"mrkdwn","text":"*4f2a9c1* is live"}}],"thread_ts":"1503435956.000247"}"#,
+///            message.to_json());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WebhookMessage {
+    /// The text of the message, or the fallback shown in notifications if it has blocks.
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    /// The message to reply to, to post in its thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    /// Who sees the message, for the webhooks of slash commands and interactions. Incoming
+    /// webhooks always post to their channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_type: Option<ResponseType>,
+}
+
+impl WebhookMessage {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        WebhookMessage { text: text.into(), ..WebhookMessage::default() }
+    }
+
+    pub fn blocks(mut self, blocks: Vec<Block>) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    pub fn attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.attachments = attachments;
+        self
+    }
+
+    pub fn thread_ts<S: Into<String>>(mut self, thread_ts: S) -> Self {
+        self.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    pub fn response_type(mut self, response_type: ResponseType) -> Self {
+        self.response_type = Some(response_type);
+        self
+    }
+
+    /// The message as the webhook takes it.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("webhook messages serialize to JSON")
+    }
+}
+
+/// Posts `message` to the incoming webhook at `url`.
+///
+/// Webhooks answer in plain text rather than JSON: `ok` on success, and otherwise an error code
+/// like `channel_not_found`, with an HTTP error status.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::webhook::{self, WebhookMessage};
+/// let client = slack_api::requests::default_client().unwrap();
+/// webhook::send(&client, "https://hooks.slack.com/services/T000/B000/XXXX", &WebhookMessage::new("Hello!")).unwrap();
+/// ```
+pub fn send<R>(client: &R, url: &str, message: &WebhookMessage) -> Result<(), WebhookError<R::Error>>
+    where R: SlackJsonSender
+{
+    let result = client.post_json(url, &message.to_json()).map_err(WebhookError::Client)?;
+    let body = result.body.trim();
+    match result.status {
+        Some(status) if status >= 300 => Err(body.into()),
+        _ if body == "ok" => Ok(()),
+        _ => Err(body.into()),
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookError<E: Error> {
+    /// The message was not valid JSON, or not a valid message.
+    InvalidPayload,
+    /// The message has neither text nor blocks.
+    NoText,
+    /// The message has more than 100 attachments.
+    TooManyAttachments,
+    /// The webhook's channel no longer exists.
+    ChannelNotFound,
+    /// The webhook's channel has been archived.
+    ChannelIsArchived,
+    /// The workspace does not allow posting to its general channel.
+    PostingToGeneralChannelDenied,
+    /// The workspace does not allow this webhook to post, e.g. in an announcement-only channel.
+    ActionProhibited,
+    /// The webhook has been disabled or removed, or its URL is not valid.
+    NoService,
+    /// The webhook's token has been revoked.
+    InvalidToken,
+    /// The webhook answered with something else than `ok`, which is kept.
+    Unknown(String),
+    /// The message could not be sent.
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for WebhookError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_payload" => WebhookError::InvalidPayload,
+            "no_text" => WebhookError::NoText,
+            "too_many_attachments" => WebhookError::TooManyAttachments,
+            "channel_not_found" => WebhookError::ChannelNotFound,
+            "channel_is_archived" => WebhookError::ChannelIsArchived,
+            "posting_to_general_channel_denied" => WebhookError::PostingToGeneralChannelDenied,
+            "action_prohibited" => WebhookError::ActionProhibited,
+            "no_service" | "no_team" => WebhookError::NoService,
+            "invalid_token" => WebhookError::InvalidToken,
+            _ => WebhookError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for WebhookError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &WebhookError::Unknown(ref body) => write!(f, "{}: {}", self.description(), body),
+            &WebhookError::Client(ref inner) => write!(f, "{}", inner),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: Error> Error for WebhookError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &WebhookError::InvalidPayload => "invalid_payload: The message was not a valid message.",
+            &WebhookError::NoText => "no_text: The message has neither text nor blocks.",
+            &WebhookError::TooManyAttachments => "too_many_attachments: The message has more than 100 attachments.",
+            &WebhookError::ChannelNotFound => "channel_not_found: The webhook's channel no longer exists.",
+            &WebhookError::ChannelIsArchived => "channel_is_archived: The webhook's channel has been archived.",
+            &WebhookError::PostingToGeneralChannelDenied => {
+                "posting_to_general_channel_denied: The workspace does not allow posting to its general channel."
+            }
+            &WebhookError::ActionProhibited => "action_prohibited: The workspace does not allow this webhook to post.",
+            &WebhookError::NoService => "no_service: The webhook has been disabled or removed.",
+            &WebhookError::InvalidToken => "invalid_token: The webhook's token has been revoked.",
+            &WebhookError::Unknown(_) => "the webhook rejected the message",
+            &WebhookError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &WebhookError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use blocks::{Attachment, Field};
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    #[test]
+    fn test_send() {
+        let url = "https://hooks.slack.com/services/T1/B1/abc";
+        let mock = MockSender::new();
+        mock.expect(Expectation::new(url).returns("ok").times(1));
+        let attachment = Attachment { fields: vec![Field::new("Env", "prod", true)], ..Attachment::default() };
+
+        send(&mock, url, &WebhookMessage::new("hi").attachments(vec![attachment]).response_type(ResponseType::InChannel))
+            .unwrap();
+        assert_eq!(Some(r#"{"text":"hi","attachments":[{"fields":[{"title":"Env","value":"prod","short":true}]}],"response_type":"in_channel"}"#),
+                   mock.calls()[0].param("body"));
+        mock.verify();
+
+        mock.expect(Expectation::new(url).returns("channel_is_archived\n"))
+            .expect(Expectation::new("https://hooks.slack.com/services/T1/B1/other").returns("<html>"));
+        match send(&mock, url, &WebhookMessage::new("hi")) {
+            Err(WebhookError::ChannelIsArchived) => {}
+            other => panic!("expected the channel to be archived, got {:?}", other),
+        }
+        match send(&mock, "https://hooks.slack.com/services/T1/B1/other", &WebhookMessage::new("hi")) {
+            Err(WebhookError::Unknown(body)) => assert_eq!("<html>", body),
+            other => panic!("expected an unknown answer, got {:?}", other),
+        }
+    }
+}
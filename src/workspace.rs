@@ -0,0 +1,875 @@
+//! Workspace configuration as code: channels, their bookmarks and usergroups described in a
+//! file, planned against what the workspace has and applied with as few calls as possible.
+
+#[cfg(feature = "toml")]
+extern crate toml;
+
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use serde;
+use serde_json;
+
+use bookmarks;
+use conversations;
+use requests::SlackWebRequestSender;
+use usergroups;
+use usergroups_users;
+use {Bookmark, ChannelId, Conversation, UserId, Usergroup};
+
+/// The channels and usergroups a workspace should have.
+///
+/// Anything the spec leaves out is left as it is: unlisted channels, usergroups and bookmarks
+/// are kept, and so are the topic or purpose of a channel that does not set one.
+///
+/// # Examples
+///
+/// ```
+/// let spec = slack_api::workspace::WorkspaceSpec::from_json(r#"{
+///     "channels": [
+///         {
+///             "name": "incidents",
+///             "topic": "Current incident: none",
+///             "members": ["U024BE7LH"],
+///             "bookmarks": [{"title": "Runbook", "link": "https://wiki.example.com/runbook"}]
+///         },
+///         {"name": "leads", "private": true}
+///     ],
+///     "usergroups": [{"handle": "oncall", "name": "On call", "members": ["U024BE7LH"]}]
+/// }"#).unwrap();
+/// assert_eq!(2, spec.channels.len());
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct WorkspaceSpec {
+    #[serde(default)]
+    pub channels: Vec<ChannelSpec>,
+    #[serde(default)]
+    pub usergroups: Vec<UsergroupSpec>,
+}
+
+/// A channel of a [`WorkspaceSpec`], found by its name.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct ChannelSpec {
+    pub name: String,
+    /// Whether to create the channel private if it does not exist. Existing channels are not
+    /// converted.
+    #[serde(default)]
+    pub private: bool,
+    pub topic: Option<String>,
+    pub purpose: Option<String>,
+    /// User IDs to invite. Members who are not listed are not removed.
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub bookmarks: Vec<BookmarkSpec>,
+}
+
+/// A link bookmarked in a channel, found by its title.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct BookmarkSpec {
+    pub title: String,
+    pub link: String,
+    pub emoji: Option<String>,
+}
+
+/// A usergroup of a [`WorkspaceSpec`], found by its handle.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct UsergroupSpec {
+    pub handle: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// The user IDs of exactly the members the group should have, or `None` to leave them be.
+    /// Slack does not let a usergroup have no members, so [`plan`] refuses an empty list.
+    pub members: Option<Vec<String>>,
+}
+
+impl WorkspaceSpec {
+    pub fn from_json(json: &str) -> Result<Self, SpecError> {
+        serde_json::from_str(json).map_err(SpecError::Json)
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(text: &str) -> Result<Self, SpecError> {
+        let value = text.parse::<toml::Value>().map_err(SpecError::Toml)?;
+        serde_json::from_value(toml_to_json(value)).map_err(SpecError::Json)
+    }
+
+    /// Reads a spec from a file, as TOML if its name ends in `.toml` and as JSON otherwise.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SpecError> {
+        let path = path.as_ref();
+        let mut text = String::new();
+        File::open(path).and_then(|mut file| file.read_to_string(&mut text)).map_err(SpecError::Io)?;
+        if path.extension().and_then(|ext| ext.to_str()) == Some("toml") {
+            WorkspaceSpec::parse_toml(&text)
+        } else {
+            WorkspaceSpec::from_json(&text)
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    fn parse_toml(text: &str) -> Result<Self, SpecError> {
+        WorkspaceSpec::from_toml(text)
+    }
+
+    #[cfg(not(feature = "toml"))]
+    fn parse_toml(_: &str) -> Result<Self, SpecError> {
+        Err(SpecError::TomlDisabled)
+    }
+}
+
+#[cfg(feature = "toml")]
+fn toml_to_json(value: toml::Value) -> ::serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => Value::from(f),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(d) => Value::String(d.to_string()),
+        toml::Value::Array(values) => Value::Array(values.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(table.into_iter().map(|(k, v)| (k, toml_to_json(v))).collect()),
+    }
+}
+
+/// One call a [`WorkspacePlan`] makes. Channels are named by their name and usergroups by their
+/// handle, since the ones the plan creates have no ID yet.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    CreateChannel { name: String, private: bool },
+    UnarchiveChannel { name: String },
+    /// Joins a channel the token is not in, so it can change it.
+    JoinChannel { name: String },
+    SetTopic { channel: String, topic: String },
+    SetPurpose { channel: String, purpose: String },
+    Invite { channel: String, users: Vec<String> },
+    AddBookmark { channel: String, bookmark: BookmarkSpec },
+    /// Changes the link or emoji of the bookmark with the ID `id`.
+    EditBookmark { channel: String, id: String, bookmark: BookmarkSpec },
+    CreateUsergroup { handle: String, name: String, description: Option<String> },
+    EnableUsergroup { handle: String },
+    /// Changes the name or description of a usergroup.
+    UpdateUsergroup { handle: String, name: String, description: Option<String> },
+    SetUsergroupMembers { handle: String, users: Vec<String> },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Change::CreateChannel { ref name, private: true } => write!(f, "create private channel #{}", name),
+            &Change::CreateChannel { ref name, .. } => write!(f, "create channel #{}", name),
+            &Change::UnarchiveChannel { ref name } => write!(f, "unarchive #{}", name),
+            &Change::JoinChannel { ref name } => write!(f, "join #{}", name),
+            &Change::SetTopic { ref channel, ref topic } => write!(f, "set the topic of #{} to {:?}", channel, topic),
+            &Change::SetPurpose { ref channel, ref purpose } => {
+                write!(f, "set the purpose of #{} to {:?}", channel, purpose)
+            }
+            &Change::Invite { ref channel, ref users } => write!(f, "invite {} to #{}", users.join(", "), channel),
+            &Change::AddBookmark { ref channel, ref bookmark } => {
+                write!(f, "bookmark {:?} ({}) in #{}", bookmark.title, bookmark.link, channel)
+            }
+            &Change::EditBookmark { ref channel, ref bookmark, .. } => {
+                write!(f, "point bookmark {:?} in #{} at {}", bookmark.title, channel, bookmark.link)
+            }
+            &Change::CreateUsergroup { ref handle, ref name, .. } => {
+                write!(f, "create usergroup @{} ({})", handle, name)
+            }
+            &Change::EnableUsergroup { ref handle } => write!(f, "enable usergroup @{}", handle),
+            &Change::UpdateUsergroup { ref handle, ref name, .. } => {
+                write!(f, "rename usergroup @{} to {:?} and update its description", handle, name)
+            }
+            &Change::SetUsergroupMembers { ref handle, ref users } => {
+                write!(f, "set the members of @{} to {}", handle, users.join(", "))
+            }
+        }
+    }
+}
+
+/// The changes that make a workspace match a [`WorkspaceSpec`], as returned by [`plan`].
+///
+/// Its `Display` lists the changes one a line, for a dry run.
+#[derive(Clone, Debug)]
+pub struct WorkspacePlan {
+    pub changes: Vec<Change>,
+    /// The IDs of the channels that exist, by name.
+    channels: HashMap<String, String>,
+    /// The IDs of the usergroups that exist, by handle.
+    usergroups: HashMap<String, String>,
+}
+
+impl fmt::Display for WorkspacePlan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares `spec` with the workspace `token` belongs to, returning the changes that would make
+/// them match. Nothing is changed yet.
+///
+/// All the public and private channels the token can see are listed, archived or not, and so are
+/// the members and bookmarks of the ones the spec lists some for, and all the usergroups.
+/// Private channels the token cannot see are taken to be missing, so creating them fails with
+/// [`conversations::CreateError::NameTaken`].
+///
+/// Since the plan is made from what the workspace has, planning again after applying part of a
+/// plan only plans the rest.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use slack_api::workspace::{self, WorkspaceSpec};
+/// let client = slack_api::requests::default_client().unwrap();
+/// let spec = WorkspaceSpec::load("workspace.json").unwrap();
+///
+/// let plan = workspace::plan(&client, "xoxp-...", &spec).unwrap();
+/// print!("{}", plan);
+/// if std::env::args().any(|arg| arg == "--apply") {
+///     plan.apply(&client, "xoxp-...").unwrap();
+/// }
+/// ```
+pub fn plan<R>(client: &R, token: &str, spec: &WorkspaceSpec) -> Result<WorkspacePlan, WorkspaceError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let empty = spec.usergroups.iter().find(|group| group.members.as_ref().map(Vec::is_empty).unwrap_or(false));
+    if let Some(group) = empty {
+        return Err(WorkspaceError::NoUsergroupMembers(group.handle.trim_start_matches('@').to_owned()));
+    }
+
+    let mut plan = WorkspacePlan {
+        changes: Vec::new(),
+        channels: HashMap::new(),
+        usergroups: HashMap::new(),
+    };
+
+    let channels = if spec.channels.is_empty() { Vec::new() } else { list_channels(client, token)? };
+    for wanted in &spec.channels {
+        let name = wanted.name.trim_start_matches('#');
+        let existing = channels.iter().find(|channel| channel.name.as_ref().map(String::as_str) == Some(name));
+        let changes = match existing.and_then(|channel| channel.id.as_ref().map(|id| (channel, id))) {
+            Some((channel, id)) => {
+                plan.channels.insert(name.to_owned(), id.to_string());
+                plan_channel(client, token, wanted, name, Some((channel, id)))?
+            }
+            None => plan_channel(client, token, wanted, name, None)?,
+        };
+        plan.changes.extend(changes);
+    }
+
+    if !spec.usergroups.is_empty() {
+        let request = usergroups::ListRequest {
+            include_disabled: Some(true),
+            include_users: Some(true),
+            ..usergroups::ListRequest::default()
+        };
+        let groups = usergroups::list(client, token, &request).map_err(WorkspaceError::ListUsergroups)?;
+        let groups = groups.usergroups.unwrap_or_default();
+        for wanted in &spec.usergroups {
+            let handle = wanted.handle.trim_start_matches('@');
+            let existing = groups.iter().find(|group| group.handle.as_ref().map(String::as_str) == Some(handle));
+            if let Some(id) = existing.and_then(|group| group.id.as_ref()) {
+                plan.usergroups.insert(handle.to_owned(), id.clone());
+            }
+            plan.changes.extend(plan_usergroup(wanted, handle, existing));
+        }
+    }
+    Ok(plan)
+}
+
+/// The changes to the channel `name`, which is `existing` with its ID if it exists.
+fn plan_channel<R>(client: &R,
+                   token: &str,
+                   wanted: &ChannelSpec,
+                   name: &str,
+                   existing: Option<(&Conversation, &ChannelId)>)
+                   -> Result<Vec<Change>, WorkspaceError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut changes = Vec::new();
+    let (topic, purpose, members, bookmarks) = match existing {
+        Some((channel, id)) => {
+            let members = if wanted.members.is_empty() { BTreeSet::new() } else { list_members(client, token, id)? };
+            let bookmarks = if wanted.bookmarks.is_empty() {
+                Vec::new()
+            } else {
+                let request = bookmarks::ListRequest { channel_id: id };
+                let response = bookmarks::list(client, token, &request).map_err(WorkspaceError::ListBookmarks)?;
+                response.bookmarks.unwrap_or_default()
+            };
+            (channel.topic.as_ref().and_then(|topic| topic.value.clone()).unwrap_or_default(),
+             channel.purpose.as_ref().and_then(|purpose| purpose.value.clone()).unwrap_or_default(),
+             members,
+             bookmarks)
+        }
+        None => (String::new(), String::new(), BTreeSet::new(), Vec::new()),
+    };
+
+    if let Some(ref wanted_topic) = wanted.topic {
+        if *wanted_topic != topic {
+            changes.push(Change::SetTopic {
+                             channel: name.to_owned(),
+                             topic: wanted_topic.clone(),
+                         });
+        }
+    }
+    if let Some(ref wanted_purpose) = wanted.purpose {
+        if *wanted_purpose != purpose {
+            changes.push(Change::SetPurpose {
+                             channel: name.to_owned(),
+                             purpose: wanted_purpose.clone(),
+                         });
+        }
+    }
+    let invite: BTreeSet<&String> = wanted.members.iter().filter(|user| !members.contains(*user)).collect();
+    if !invite.is_empty() {
+        changes.push(Change::Invite {
+                         channel: name.to_owned(),
+                         users: invite.into_iter().cloned().collect(),
+                     });
+    }
+    for bookmark in &wanted.bookmarks {
+        let found = bookmarks.iter().find(|b| b.title.as_ref() == Some(&bookmark.title));
+        match found.and_then(|b| b.id.as_ref().map(|id| (b, id))) {
+            None => {
+                changes.push(Change::AddBookmark {
+                                 channel: name.to_owned(),
+                                 bookmark: bookmark.clone(),
+                             })
+            }
+            Some((found, id)) if bookmark_differs(found, bookmark) => {
+                changes.push(Change::EditBookmark {
+                                 channel: name.to_owned(),
+                                 id: id.clone(),
+                                 bookmark: bookmark.clone(),
+                             })
+            }
+            Some(_) => {}
+        }
+    }
+
+    match existing {
+        None => {
+            changes.insert(0,
+                           Change::CreateChannel {
+                               name: name.to_owned(),
+                               private: wanted.private,
+                           })
+        }
+        Some((channel, _)) if !changes.is_empty() || channel.is_archived == Some(true) => {
+            if channel.is_member != Some(true) {
+                changes.insert(0, Change::JoinChannel { name: name.to_owned() });
+            }
+            if channel.is_archived == Some(true) {
+                changes.insert(0, Change::UnarchiveChannel { name: name.to_owned() });
+            }
+        }
+        Some(_) => {}
+    }
+    Ok(changes)
+}
+
+fn bookmark_differs(found: &Bookmark, wanted: &BookmarkSpec) -> bool {
+    found.link.as_ref() != Some(&wanted.link) || (wanted.emoji.is_some() && found.emoji != wanted.emoji)
+}
+
+/// The changes to the usergroup `handle`, which is `existing` if it exists.
+fn plan_usergroup(wanted: &UsergroupSpec, handle: &str, existing: Option<&Usergroup>) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let members: BTreeSet<&str> = match existing {
+        Some(group) => {
+            if group.date_delete.unwrap_or(0) != 0 {
+                changes.push(Change::EnableUsergroup { handle: handle.to_owned() });
+            }
+            let description_differs = wanted.description.is_some() &&
+                                      group.description.as_ref().map(String::as_str).unwrap_or("") !=
+                                      wanted.description.as_ref().map(String::as_str).unwrap_or("");
+            if group.name.as_ref() != Some(&wanted.name) || description_differs {
+                changes.push(Change::UpdateUsergroup {
+                                 handle: handle.to_owned(),
+                                 name: wanted.name.clone(),
+                                 description: wanted.description.clone(),
+                             });
+            }
+            group.users.as_ref().map(|users| users.iter().map(UserId::as_str).collect()).unwrap_or_default()
+        }
+        None => {
+            changes.push(Change::CreateUsergroup {
+                             handle: handle.to_owned(),
+                             name: wanted.name.clone(),
+                             description: wanted.description.clone(),
+                         });
+            BTreeSet::new()
+        }
+    };
+    if let Some(ref wanted_members) = wanted.members {
+        let wanted_members: BTreeSet<&str> = wanted_members.iter().map(String::as_str).collect();
+        if wanted_members != members {
+            changes.push(Change::SetUsergroupMembers {
+                             handle: handle.to_owned(),
+                             users: wanted_members.into_iter().map(str::to_owned).collect(),
+                         });
+        }
+    }
+    changes
+}
+
+impl WorkspacePlan {
+    /// Whether the workspace already matches the spec.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Makes the changes, in order.
+    ///
+    /// Stops at the first change that fails. The ones before it have been made, so planning again
+    /// plans the rest.
+    pub fn apply<R>(&self, client: &R, token: &str) -> Result<(), WorkspaceError<R::Error>>
+        where R: SlackWebRequestSender
+    {
+        let mut channels = self.channels.clone();
+        let mut groups = self.usergroups.clone();
+        for change in &self.changes {
+            match change {
+                &Change::CreateChannel { ref name, private } => {
+                    let request = conversations::CreateRequest {
+                        name: name,
+                        is_private: Some(private),
+                        ..conversations::CreateRequest::default()
+                    };
+                    let response = conversations::create(client, token, &request)
+                        .map_err(WorkspaceError::CreateChannel)?;
+                    let id = response.channel.and_then(|channel| channel.id);
+                    let id = id.ok_or(WorkspaceError::CreateChannel(conversations::CreateError::MalformedResponse(
+                        serde::de::Error::missing_field("channel"))))?;
+                    channels.insert(name.clone(), id.into());
+                }
+                &Change::UnarchiveChannel { ref name } => {
+                    let request = conversations::UnarchiveRequest { channel: id(&channels, name) };
+                    conversations::unarchive(client, token, &request).map_err(WorkspaceError::Unarchive)?;
+                }
+                &Change::JoinChannel { ref name } => {
+                    let request = conversations::JoinRequest { channel: id(&channels, name) };
+                    conversations::join(client, token, &request).map_err(WorkspaceError::Join)?;
+                }
+                &Change::SetTopic { ref channel, ref topic } => {
+                    let request = conversations::SetTopicRequest {
+                        channel: id(&channels, channel),
+                        topic: topic,
+                    };
+                    conversations::set_topic(client, token, &request).map_err(WorkspaceError::SetTopic)?;
+                }
+                &Change::SetPurpose { ref channel, ref purpose } => {
+                    let request = conversations::SetPurposeRequest {
+                        channel: id(&channels, channel),
+                        purpose: purpose,
+                    };
+                    conversations::set_purpose(client, token, &request).map_err(WorkspaceError::SetPurpose)?;
+                }
+                &Change::Invite { ref channel, ref users } => {
+                    let users = users.join(",");
+                    let request = conversations::InviteRequest {
+                        channel: id(&channels, channel),
+                        users: &users,
+                        ..conversations::InviteRequest::default()
+                    };
+                    conversations::invite(client, token, &request).map_err(WorkspaceError::Invite)?;
+                }
+                &Change::AddBookmark { ref channel, ref bookmark } => {
+                    let request = bookmarks::AddRequest {
+                        channel_id: id(&channels, channel),
+                        title: &bookmark.title,
+                        ty: "link",
+                        link: Some(&bookmark.link),
+                        emoji: bookmark.emoji.as_ref().map(String::as_str),
+                        ..bookmarks::AddRequest::default()
+                    };
+                    bookmarks::add(client, token, &request).map_err(WorkspaceError::AddBookmark)?;
+                }
+                &Change::EditBookmark { ref channel, id: ref bookmark_id, ref bookmark } => {
+                    let request = bookmarks::EditRequest {
+                        bookmark_id: bookmark_id,
+                        channel_id: id(&channels, channel),
+                        title: Some(&bookmark.title),
+                        link: Some(&bookmark.link),
+                        emoji: bookmark.emoji.as_ref().map(String::as_str),
+                    };
+                    bookmarks::edit(client, token, &request).map_err(WorkspaceError::EditBookmark)?;
+                }
+                &Change::CreateUsergroup { ref handle, ref name, ref description } => {
+                    let request = usergroups::CreateRequest {
+                        name: name,
+                        handle: Some(handle),
+                        description: description.as_ref().map(String::as_str),
+                        ..usergroups::CreateRequest::default()
+                    };
+                    let response = usergroups::create(client, token, &request)
+                        .map_err(WorkspaceError::CreateUsergroup)?;
+                    let id = response.usergroup.and_then(|group| group.id);
+                    let id = id.ok_or(WorkspaceError::CreateUsergroup(usergroups::CreateError::MalformedResponse(
+                        serde::de::Error::missing_field("usergroup"))))?;
+                    groups.insert(handle.clone(), id);
+                }
+                &Change::EnableUsergroup { ref handle } => {
+                    let request = usergroups::EnableRequest {
+                        usergroup: id(&groups, handle),
+                        ..usergroups::EnableRequest::default()
+                    };
+                    usergroups::enable(client, token, &request).map_err(WorkspaceError::EnableUsergroup)?;
+                }
+                &Change::UpdateUsergroup { ref handle, ref name, ref description } => {
+                    let request = usergroups::UpdateRequest {
+                        usergroup: id(&groups, handle),
+                        name: Some(name),
+                        description: description.as_ref().map(String::as_str),
+                        ..usergroups::UpdateRequest::default()
+                    };
+                    usergroups::update(client, token, &request).map_err(WorkspaceError::UpdateUsergroup)?;
+                }
+                &Change::SetUsergroupMembers { ref handle, ref users } => {
+                    let users = users.join(",");
+                    let request = usergroups_users::UpdateRequest {
+                        usergroup: id(&groups, handle),
+                        users: &users,
+                        ..usergroups_users::UpdateRequest::default()
+                    };
+                    usergroups_users::update(client, token, &request).map_err(WorkspaceError::SetUsergroupMembers)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The ID of `name` in `ids`. Every change after the one creating a channel or usergroup can find
+/// it here.
+fn id<'a>(ids: &'a HashMap<String, String>, name: &str) -> &'a str {
+    ids.get(name).map(String::as_str).unwrap_or("")
+}
+
+/// All the public and private channels the token can see, archived or not.
+fn list_channels<R>(client: &R, token: &str) -> Result<Vec<Conversation>, WorkspaceError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut channels = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let request = conversations::ListRequest {
+            cursor: cursor.as_ref().map(String::as_str),
+            exclude_archived: Some(false),
+            limit: Some(1000),
+            types: Some("public_channel,private_channel"),
+            ..conversations::ListRequest::default()
+        };
+        let response = conversations::list(client, token, &request).map_err(WorkspaceError::ListChannels)?;
+        channels.extend(response.channels.unwrap_or_default());
+        cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            return Ok(channels);
+        }
+    }
+}
+
+fn list_members<R>(client: &R, token: &str, channel: &str) -> Result<BTreeSet<String>, WorkspaceError<R::Error>>
+    where R: SlackWebRequestSender
+{
+    let mut members = BTreeSet::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let request = conversations::MembersRequest {
+            channel: channel,
+            cursor: cursor.as_ref().map(String::as_str),
+            limit: Some(1000),
+        };
+        let response = conversations::members(client, token, &request).map_err(WorkspaceError::ListMembers)?;
+        members.extend(response.members.unwrap_or_default());
+        cursor = response.response_metadata.and_then(|m| m.next_cursor).filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            return Ok(members);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SpecError {
+    /// The spec file could not be read.
+    Io(io::Error),
+    /// The spec is not valid JSON, or does not describe a workspace.
+    Json(serde_json::Error),
+    /// The spec is not valid TOML.
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+    /// The spec is a TOML file, but TOML support needs the `toml` feature, which is disabled.
+    TomlDisabled,
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &SpecError::Io(ref inner) => write!(f, "{}", inner),
+            &SpecError::Json(ref inner) => write!(f, "{}", inner),
+            #[cfg(feature = "toml")]
+            &SpecError::Toml(ref inner) => write!(f, "{}", inner),
+            &SpecError::TomlDisabled => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for SpecError {
+    fn description(&self) -> &str {
+        match self {
+            &SpecError::Io(ref inner) => inner.description(),
+            &SpecError::Json(ref inner) => inner.description(),
+            #[cfg(feature = "toml")]
+            &SpecError::Toml(ref inner) => inner.description(),
+            &SpecError::TomlDisabled => "reading TOML specs needs the toml feature",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &SpecError::Io(ref inner) => Some(inner),
+            &SpecError::Json(ref inner) => Some(inner),
+            #[cfg(feature = "toml")]
+            &SpecError::Toml(ref inner) => Some(inner),
+            &SpecError::TomlDisabled => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum WorkspaceError<E: Error> {
+    /// Listing the channels failed.
+    ListChannels(conversations::ListError<E>),
+    /// Listing the members of a channel failed.
+    ListMembers(conversations::MembersError<E>),
+    /// Listing the bookmarks of a channel failed.
+    ListBookmarks(bookmarks::ListError<E>),
+    /// Listing the usergroups failed.
+    ListUsergroups(usergroups::ListError<E>),
+    CreateChannel(conversations::CreateError<E>),
+    Unarchive(conversations::UnarchiveError<E>),
+    Join(conversations::JoinError<E>),
+    SetTopic(conversations::SetTopicError<E>),
+    SetPurpose(conversations::SetPurposeError<E>),
+    Invite(conversations::InviteError<E>),
+    AddBookmark(bookmarks::AddError<E>),
+    EditBookmark(bookmarks::EditError<E>),
+    CreateUsergroup(usergroups::CreateError<E>),
+    EnableUsergroup(usergroups::EnableError<E>),
+    UpdateUsergroup(usergroups::UpdateError<E>),
+    SetUsergroupMembers(usergroups_users::UpdateError<E>),
+    /// The spec lists no members for the usergroup with this handle, which Slack refuses.
+    NoUsergroupMembers(String),
+}
+
+impl<E: Error> fmt::Display for WorkspaceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &WorkspaceError::NoUsergroupMembers(ref handle) => write!(f, "usergroup @{} would have no members", handle),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl<E: Error> Error for WorkspaceError<E> {
+    fn description(&self) -> &str {
+        match self {
+            &WorkspaceError::ListChannels(ref inner) => inner.description(),
+            &WorkspaceError::ListMembers(ref inner) => inner.description(),
+            &WorkspaceError::ListBookmarks(ref inner) => inner.description(),
+            &WorkspaceError::ListUsergroups(ref inner) => inner.description(),
+            &WorkspaceError::CreateChannel(ref inner) => inner.description(),
+            &WorkspaceError::Unarchive(ref inner) => inner.description(),
+            &WorkspaceError::Join(ref inner) => inner.description(),
+            &WorkspaceError::SetTopic(ref inner) => inner.description(),
+            &WorkspaceError::SetPurpose(ref inner) => inner.description(),
+            &WorkspaceError::Invite(ref inner) => inner.description(),
+            &WorkspaceError::AddBookmark(ref inner) => inner.description(),
+            &WorkspaceError::EditBookmark(ref inner) => inner.description(),
+            &WorkspaceError::CreateUsergroup(ref inner) => inner.description(),
+            &WorkspaceError::EnableUsergroup(ref inner) => inner.description(),
+            &WorkspaceError::UpdateUsergroup(ref inner) => inner.description(),
+            &WorkspaceError::SetUsergroupMembers(ref inner) => inner.description(),
+            &WorkspaceError::NoUsergroupMembers(_) => "a usergroup would have no members",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match self {
+            &WorkspaceError::ListChannels(ref inner) => Some(inner),
+            &WorkspaceError::ListMembers(ref inner) => Some(inner),
+            &WorkspaceError::ListBookmarks(ref inner) => Some(inner),
+            &WorkspaceError::ListUsergroups(ref inner) => Some(inner),
+            &WorkspaceError::CreateChannel(ref inner) => Some(inner),
+            &WorkspaceError::Unarchive(ref inner) => Some(inner),
+            &WorkspaceError::Join(ref inner) => Some(inner),
+            &WorkspaceError::SetTopic(ref inner) => Some(inner),
+            &WorkspaceError::SetPurpose(ref inner) => Some(inner),
+            &WorkspaceError::Invite(ref inner) => Some(inner),
+            &WorkspaceError::AddBookmark(ref inner) => Some(inner),
+            &WorkspaceError::EditBookmark(ref inner) => Some(inner),
+            &WorkspaceError::CreateUsergroup(ref inner) => Some(inner),
+            &WorkspaceError::EnableUsergroup(ref inner) => Some(inner),
+            &WorkspaceError::UpdateUsergroup(ref inner) => Some(inner),
+            &WorkspaceError::SetUsergroupMembers(ref inner) => Some(inner),
+            &WorkspaceError::NoUsergroupMembers(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::{Expectation, MockSender};
+
+    use super::*;
+
+    fn spec() -> WorkspaceSpec {
+        WorkspaceSpec::from_json(r##"{
+            "channels": [
+                {"name": "#incidents", "topic": "No incident", "members": ["U1", "U2"],
+                    "bookmarks": [{"title": "Runbook", "link": "https://wiki/runbook"},
+                                  {"title": "Status", "link": "https://status"}]},
+                {"name": "leads", "private": true, "purpose": "Team leads", "members": ["U3"]},
+                {"name": "random", "topic": "Anything"}
+            ],
+            "usergroups": [{"handle": "oncall", "name": "On call", "members": ["U2", "U1"]}]
+        }"##)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_plan_and_apply() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("conversations.list").returns(r#"{"ok": true, "channels": [
+                {"id": "C1", "name": "incidents", "is_member": false, "topic": {"value": "Incident ongoing"}},
+                {"id": "C2", "name": "random", "is_member": true, "topic": {"value": "Anything"}}]}"#))
+            .expect(Expectation::new("conversations.members").returns(r#"{"ok": true, "members": ["U1"]}"#))
+            .expect(Expectation::new("bookmarks.list").returns(r#"{"ok": true, "bookmarks": [
+                {"id": "Bk1", "title": "Runbook", "link": "https://wiki/old-runbook"}]}"#))
+            .expect(Expectation::new("usergroups.list").returns(r#"{"ok": true, "usergroups": [
+                {"id": "S1", "handle": "oncall", "name": "On call", "users": ["U1", "U2"]}]}"#))
+            .expect(Expectation::new("conversations.create")
+                .returns(r#"{"ok": true, "channel": {"id": "G1", "name": "leads"}}"#));
+        for method in &["conversations.join",
+                        "conversations.setTopic",
+                        "conversations.setPurpose",
+                        "conversations.invite",
+                        "bookmarks.add",
+                        "bookmarks.edit"] {
+            mock.expect(Expectation::new(*method));
+        }
+
+        let plan = plan(&mock, "xoxp-1", &spec()).unwrap();
+        assert_eq!("join #incidents
+set the topic of #incidents to \"No incident\"
+invite U2 to #incidents
+point bookmark \"Runbook\" in #incidents at https://wiki/runbook
+bookmark \"Status\" (https://status) in #incidents
+create private channel #leads
+set the purpose of #leads to \"Team leads\"
+invite U3 to #leads
+",
+                   plan.to_string());
+        assert_eq!(vec![Some("C1")],
+                   mock.calls_of("conversations.members").iter().map(|c| c.param("channel")).collect::<Vec<_>>());
+
+        plan.apply(&mock, "xoxp-1").unwrap();
+        let invites: Vec<_> = mock.calls_of("conversations.invite")
+            .iter()
+            .map(|c| (c.param("channel").unwrap().to_owned(), c.param("users").unwrap().to_owned()))
+            .collect();
+        assert_eq!(vec![("C1".to_owned(), "U2".to_owned()), ("G1".to_owned(), "U3".to_owned())], invites);
+        let edit = &mock.calls_of("bookmarks.edit")[0];
+        assert_eq!((Some("Bk1"), Some("C1")), (edit.param("bookmark_id"), edit.param("channel_id")));
+        assert_eq!(Some("G1"), mock.calls_of("conversations.setPurpose")[0].param("channel"));
+        assert_eq!(Some("1"), mock.calls_of("conversations.create")[0].param("is_private"));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml() {
+        let spec = WorkspaceSpec::from_toml(r#"
+            [[channels]]
+            name = "incidents"
+            topic = "No incident"
+            members = ["U1"]
+            bookmarks = [{ title = "Runbook", link = "https://wiki/runbook" }]
+
+            [[usergroups]]
+            handle = "oncall"
+            name = "On call"
+        "#)
+            .unwrap();
+        assert_eq!(Some("No incident".to_owned()), spec.channels[0].topic);
+        assert_eq!("https://wiki/runbook", spec.channels[0].bookmarks[0].link);
+        assert_eq!(None, spec.usergroups[0].members);
+    }
+
+    #[test]
+    fn test_usergroups() {
+        let mock = MockSender::new();
+        mock.expect(Expectation::new("usergroups.list").returns(r#"{"ok": true, "usergroups": [
+                {"id": "S1", "handle": "oncall", "name": "Oncall", "date_delete": 1500000000,
+                    "users": ["U1", "U9"]}]}"#))
+            .expect(Expectation::new("usergroups.create")
+                .returns(r#"{"ok": true, "usergroup": {"id": "S2", "handle": "leads"}}"#))
+            .expect(Expectation::new("usergroups.enable"))
+            .expect(Expectation::new("usergroups.update"))
+            .expect(Expectation::new("usergroups.users.update"));
+        let spec = WorkspaceSpec::from_json(r#"{"usergroups": [
+            {"handle": "oncall", "name": "On call", "members": ["U1", "U2"]},
+            {"handle": "@leads", "name": "Leads", "description": "Team leads", "members": ["U3"]}]}"#)
+            .unwrap();
+
+        let plan = plan(&mock, "xoxp-1", &spec).unwrap();
+        assert!(mock.calls_of("conversations.list").is_empty());
+        assert_eq!(vec![Change::EnableUsergroup { handle: "oncall".to_owned() },
+                        Change::UpdateUsergroup {
+                            handle: "oncall".to_owned(),
+                            name: "On call".to_owned(),
+                            description: None,
+                        },
+                        Change::SetUsergroupMembers {
+                            handle: "oncall".to_owned(),
+                            users: vec!["U1".to_owned(), "U2".to_owned()],
+                        },
+                        Change::CreateUsergroup {
+                            handle: "leads".to_owned(),
+                            name: "Leads".to_owned(),
+                            description: Some("Team leads".to_owned()),
+                        },
+                        Change::SetUsergroupMembers {
+                            handle: "leads".to_owned(),
+                            users: vec!["U3".to_owned()],
+                        }],
+                   plan.changes);
+
+        plan.apply(&mock, "xoxp-1").unwrap();
+        let updates: Vec<_> = mock.calls_of("usergroups.users.update")
+            .iter()
+            .map(|c| (c.param("usergroup").unwrap().to_owned(), c.param("users").unwrap().to_owned()))
+            .collect();
+        assert_eq!(vec![("S1".to_owned(), "U1,U2".to_owned()), ("S2".to_owned(), "U3".to_owned())], updates);
+    }
+    #[test]
+    fn test_usergroup_without_members_is_refused() {
+        let mock = MockSender::new();
+        let spec = WorkspaceSpec::from_json(r#"{"usergroups": [{"handle": "@oncall", "name": "On call", "members": []}]}"#)
+            .unwrap();
+
+        match plan(&mock, "xoxp-1", &spec) {
+            Err(WorkspaceError::NoUsergroupMembers(ref handle)) if handle == "oncall" => {}
+            other => panic!("expected a usergroup without members to be refused, got {:?}", other),
+        }
+        assert!(mock.calls().is_empty());
+    }
+}